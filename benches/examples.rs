@@ -0,0 +1,129 @@
+//! 性能示例的基准测试：`cargo run -- bench [group]` 或 `cargo bench --bench examples`
+//!
+//! 每组对比同一件事的几种写法，给讲性能的 lesson 提供实测数字：
+//!
+//! - `iter_vs_loop`：迭代器链与手写 `for` 循环（通常编译成同样快的代码）
+//! - `string_concat`：`push_str`、预留容量、`format!`、`+` 与 `concat` 拼接字符串
+//! - `map_lookup`：HashMap 与 BTreeMap 的查找，键的数量不同时差距也不同
+//!
+//! 结果在 `target/criterion/` 中，带有 HTML 报告；再次运行时会与上一次比较。
+
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Write;
+use std::hint::black_box;
+
+/// 偶数的平方和：迭代器与循环写法
+fn iter_vs_loop(c: &mut Criterion) {
+    let numbers: Vec<u64> = (0..10_000).collect();
+    let mut group = c.benchmark_group("iter_vs_loop");
+    group.bench_function("iterator", |b| {
+        b.iter(|| {
+            black_box(&numbers)
+                .iter()
+                .filter(|&&n| n.is_multiple_of(2))
+                .map(|&n| n * n)
+                .sum::<u64>()
+        })
+    });
+    group.bench_function("for_loop", |b| {
+        b.iter(|| {
+            let mut sum = 0;
+            for &n in black_box(&numbers) {
+                if n.is_multiple_of(2) {
+                    sum += n * n;
+                }
+            }
+            sum
+        })
+    });
+    group.bench_function("index_loop", |b| {
+        b.iter(|| {
+            let numbers = black_box(&numbers);
+            let mut sum = 0;
+            // 故意用下标访问，与上面两种写法对比边界检查的开销
+            #[allow(clippy::needless_range_loop)]
+            for i in 0..numbers.len() {
+                if numbers[i].is_multiple_of(2) {
+                    sum += numbers[i] * numbers[i];
+                }
+            }
+            sum
+        })
+    });
+    group.finish();
+}
+
+/// 把 1000 个单词拼成一个字符串
+fn string_concat(c: &mut Criterion) {
+    let words: Vec<String> = (0..1000).map(|i| format!("word{}", i)).collect();
+    let mut group = c.benchmark_group("string_concat");
+    group.bench_function("push_str", |b| {
+        b.iter(|| {
+            let mut s = String::new();
+            for word in black_box(&words) {
+                s.push_str(word);
+            }
+            s
+        })
+    });
+    group.bench_function("with_capacity", |b| {
+        b.iter(|| {
+            let words = black_box(&words);
+            let mut s = String::with_capacity(words.iter().map(String::len).sum());
+            for word in words {
+                s.push_str(word);
+            }
+            s
+        })
+    });
+    group.bench_function("write_macro", |b| {
+        b.iter(|| {
+            let mut s = String::new();
+            for word in black_box(&words) {
+                write!(s, "{}", word).unwrap();
+            }
+            s
+        })
+    });
+    group.bench_function("format_macro", |b| {
+        b.iter(|| {
+            let mut s = String::new();
+            for word in black_box(&words) {
+                s = format!("{}{}", s, word);
+            }
+            s
+        })
+    });
+    group.bench_function("plus_operator", |b| {
+        b.iter(|| {
+            let mut s = String::new();
+            for word in black_box(&words) {
+                s = s + word;
+            }
+            s
+        })
+    });
+    group.bench_function("concat", |b| b.iter(|| black_box(&words).concat()));
+    group.finish();
+}
+
+/// 在 n 个键中逐个查找：HashMap 与 BTreeMap
+fn map_lookup(c: &mut Criterion) {
+    let mut group = c.benchmark_group("map_lookup");
+    for n in [10, 1_000, 100_000] {
+        let keys: Vec<u64> = (0..n).map(|i| i * 7919 % n).collect();
+        let hash: HashMap<u64, u64> = keys.iter().map(|&k| (k, k)).collect();
+        let btree: BTreeMap<u64, u64> = keys.iter().map(|&k| (k, k)).collect();
+        group.bench_with_input(BenchmarkId::new("HashMap", n), &keys, |b, keys| {
+            b.iter(|| keys.iter().filter_map(|k| hash.get(k)).sum::<u64>())
+        });
+        group.bench_with_input(BenchmarkId::new("BTreeMap", n), &keys, |b, keys| {
+            b.iter(|| keys.iter().filter_map(|k| btree.get(k)).sum::<u64>())
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, iter_vs_loop, string_concat, map_lookup);
+criterion_main!(benches);
@@ -0,0 +1,290 @@
+//! 根据 `curriculum.toml` 生成 lesson 注册表
+//!
+//! 大纲中的每个 `[[lesson]]` 对应 lesson crate 中的一个模块：`feature = "basics"` 的在
+//! `crates/lessons-basics/src/<slug>.rs`，其余在 `crates/lessons-advanced/src/<slug>.rs`：
+//!
+//! ```toml
+//! [[lesson]]
+//! number = 6
+//! slug = "ownership"
+//! aliases = ["own"]
+//! title = "Ownership Basics"
+//! chapter = "Ownership"
+//! difficulty = "Intermediate"
+//! minutes = 25
+//! tags = ["ownership", "memory"]
+//! requires = ["variables", "functions"]
+//! book = ["4.1"]
+//! feature = "basics"
+//! ```
+//!
+//! `aliases` 可选，列出也能选中该 lesson 的其他名字（改名前的 slug、简写等）。
+//! `deprecated = true` 或 `replaced_by = "<slug>"` 把 lesson 标记为已弃用，
+//! 后者同时指明取代它的 lesson。
+//! `book` 可选，列出 lesson 对应的 The Rust Programming Language 章节（`"4.1"` 是一节，`"2"` 是整章），
+//! 只由 `coverage` 读取，这里只检查写法。
+//! `feature` 指定 lesson 所属的 Cargo feature，未启用的 lesson 不会被编译，
+//! 只以 `UNAVAILABLE` 列表的形式保留编号与标题，供 `list` 提示需要的 feature；
+//! 指向未启用 lesson 的前置关系随之省略。
+//!
+//! 生成的 `$OUT_DIR/registry.rs` 是一次 `register_lessons!` 调用，
+//! 由 `src/lessons/mod.rs` 通过 `include!` 引入，各 lesson 通过 `lessons_basics::<slug>`
+//! 这样的路径引用所在 crate 中的模块。
+
+use serde::Deserialize;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// 可以用来划分 lesson 的 Cargo feature
+const FEATURES: &[&str] = &["basics", "advanced"];
+
+/// 存放 lesson 的 crate：目录名与 crate 名
+const CRATES: &[(&str, &str)] = &[
+    ("lessons-basics", "lessons_basics"),
+    ("lessons-advanced", "lessons_advanced"),
+];
+
+/// lesson crate 中不是 lesson 的模块：crate 根与存放各 lesson 练习的 `exercises`
+const NON_LESSON_MODULES: &[&str] = &["lib", "exercises"];
+
+/// lesson 所在的 crate：basics 在 lessons-basics，其余 feature（目前只有 advanced）在 lessons-advanced
+fn lesson_crate(feature: &str) -> (&'static str, &'static str) {
+    if feature == "basics" {
+        CRATES[0]
+    } else {
+        CRATES[1]
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Curriculum {
+    lesson: Vec<Entry>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Entry {
+    number: usize,
+    slug: String,
+    #[serde(default)]
+    aliases: Vec<String>,
+    title: String,
+    chapter: String,
+    difficulty: String,
+    minutes: u32,
+    #[serde(default)]
+    tags: Vec<String>,
+    #[serde(default)]
+    requires: Vec<String>,
+    #[serde(default)]
+    book: Vec<String>,
+    feature: String,
+    #[serde(default)]
+    deprecated: bool,
+    replaced_by: Option<String>,
+}
+
+fn main() {
+    let manifest = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+    let curriculum_path = manifest.join("curriculum.toml");
+    let crates = manifest.join("crates");
+    println!("cargo:rerun-if-changed={}", curriculum_path.display());
+    for (dir, _) in CRATES {
+        println!(
+            "cargo:rerun-if-changed={}",
+            crates.join(dir).join("src").display()
+        );
+    }
+
+    let text = fs::read_to_string(&curriculum_path).expect("read curriculum.toml");
+    let mut curriculum: Curriculum =
+        toml::from_str(&text).unwrap_or_else(|e| panic!("invalid curriculum.toml: {}", e));
+    let lessons = &mut curriculum.lesson;
+    lessons.sort_by_key(|l| l.number);
+    check(lessons, &crates);
+
+    let (lessons, disabled): (Vec<_>, Vec<_>) = curriculum
+        .lesson
+        .into_iter()
+        .partition(|l| feature_enabled(&l.feature));
+    let enabled: Vec<String> = lessons.iter().map(|l| l.slug.clone()).collect();
+
+    let mut out = String::from("register_lessons! {\n");
+    for l in lessons.iter() {
+        out.push_str(&format!(
+            "    {} => {} {{\n        krate: {},\n        aliases: [{}],\n        title: {:?},\n        \
+             chapter: {},\n        difficulty: {},\n        minutes: {},\n        tags: [{}],\n        requires: [{}],\n        \
+             deprecated: {},\n        replaced_by: {},\n    }},\n",
+            l.number,
+            l.slug,
+            lesson_crate(&l.feature).1,
+            l.aliases
+                .iter()
+                .map(|a| format!("{:?}", a))
+                .collect::<Vec<_>>()
+                .join(", "),
+            l.title,
+            l.chapter,
+            l.difficulty,
+            l.minutes,
+            l.tags
+                .iter()
+                .map(|t| format!("{:?}", t))
+                .collect::<Vec<_>>()
+                .join(", "),
+            l.requires
+                .iter()
+                .filter(|req| enabled.contains(req))
+                .cloned()
+                .collect::<Vec<_>>()
+                .join(", "),
+            l.deprecated || l.replaced_by.is_some(),
+            match &l.replaced_by {
+                Some(slug) if enabled.contains(slug) => format!("Some({:?})", slug),
+                _ => String::from("None"),
+            },
+        ));
+    }
+    out.push_str("}\n\n");
+
+    // 未启用的 lesson 不编译，只保留在列表中提示所需的 feature
+    out.push_str("pub const UNAVAILABLE: &[Unavailable] = &[\n");
+    for l in &disabled {
+        out.push_str(&format!(
+            "    Unavailable {{\n        number: {},\n        slug: {:?},\n        aliases: &[{}],\n        \
+             title: {:?},\n        chapter: Chapter::{},\n        feature: {:?},\n    }},\n",
+            l.number,
+            l.slug,
+            l.aliases
+                .iter()
+                .map(|a| format!("{:?}", a))
+                .collect::<Vec<_>>()
+                .join(", "),
+            l.title,
+            l.chapter,
+            l.feature,
+        ));
+    }
+    out.push_str("];\n");
+
+    let dest = PathBuf::from(env::var("OUT_DIR").unwrap()).join("registry.rs");
+    fs::write(dest, out).expect("write registry.rs");
+}
+
+/// 大纲与各 lesson crate 中的文件必须一一对应，编号、slug 与别名唯一，编号从 1 开始连续
+fn check(lessons: &[Entry], crates: &Path) {
+    for pair in lessons.windows(2) {
+        if pair[0].number == pair[1].number {
+            panic!(
+                "curriculum.toml: lessons '{}' and '{}' share number {}",
+                pair[0].slug, pair[1].slug, pair[0].number
+            );
+        }
+    }
+    for (expected, l) in (1..).zip(lessons) {
+        if l.number != expected {
+            panic!(
+                "curriculum.toml: lesson numbers must be contiguous from 1, \
+                 expected {} but lesson '{}' has number {}",
+                expected, l.slug, l.number
+            );
+        }
+    }
+    for (i, l) in lessons.iter().enumerate() {
+        if lessons[..i].iter().any(|other| other.slug == l.slug) {
+            panic!("curriculum.toml: duplicate slug '{}'", l.slug);
+        }
+        for alias in &l.aliases {
+            let taken = lessons.iter().enumerate().any(|(j, other)| {
+                other.slug == *alias || (j != i && other.aliases.contains(alias))
+            });
+            if taken || l.aliases.iter().filter(|a| *a == alias).count() > 1 {
+                panic!(
+                    "curriculum.toml: alias '{}' of lesson '{}' is already in use",
+                    alias, l.slug
+                );
+            }
+            if alias
+                .parse::<usize>()
+                .is_ok_and(|n| lessons.iter().any(|other| other.number == n))
+            {
+                panic!(
+                    "curriculum.toml: alias '{}' of lesson '{}' is the number of another lesson",
+                    alias, l.slug
+                );
+            }
+        }
+        if !FEATURES.contains(&l.feature.as_str()) {
+            panic!(
+                "curriculum.toml: lesson '{}' has unknown feature '{}', expected one of {:?}",
+                l.slug, l.feature, FEATURES
+            );
+        }
+        if let Some(req) = l
+            .requires
+            .iter()
+            .find(|req| !lessons.iter().any(|other| &other.slug == *req))
+        {
+            panic!(
+                "curriculum.toml: lesson '{}' requires unknown lesson '{}'",
+                l.slug, req
+            );
+        }
+        if let Some(section) = l
+            .book
+            .iter()
+            .find(|s| !s.split('.').all(|n| n.parse::<u32>().is_ok()) || s.split('.').count() > 2)
+        {
+            panic!(
+                "curriculum.toml: lesson '{}' has book section '{}', expected a chapter like \"4\" or a section like \"4.2\"",
+                l.slug, section
+            );
+        }
+        if let Some(new) = &l.replaced_by
+            && (new == &l.slug || !lessons.iter().any(|other| &other.slug == new))
+        {
+            panic!(
+                "curriculum.toml: lesson '{}' is replaced by unknown lesson '{}'",
+                l.slug, new
+            );
+        }
+        let dir = lesson_crate(&l.feature).0;
+        if !crates
+            .join(dir)
+            .join("src")
+            .join(format!("{}.rs", l.slug))
+            .is_file()
+        {
+            panic!(
+                "curriculum.toml: lesson '{}' has no file crates/{}/src/{}.rs",
+                l.slug, dir, l.slug
+            );
+        }
+    }
+    for (dir, _) in CRATES {
+        let src = crates.join(dir).join("src");
+        for entry in fs::read_dir(&src).expect("read lesson crate") {
+            let path = entry.expect("read lesson crate entry").path();
+            let Some(stem) = path.file_stem().and_then(|s| s.to_str()) else {
+                continue;
+            };
+            if path.extension().is_some_and(|e| e == "rs")
+                && !NON_LESSON_MODULES.contains(&stem)
+                && !lessons
+                    .iter()
+                    .any(|l| l.slug == stem && lesson_crate(&l.feature).0 == *dir)
+            {
+                panic!(
+                    "crates/{}/src/{}.rs is not listed in curriculum.toml under this crate's features",
+                    dir, stem
+                );
+            }
+        }
+    }
+}
+
+fn feature_enabled(feature: &str) -> bool {
+    env::var_os(format!("CARGO_FEATURE_{}", feature.to_uppercase())).is_some()
+}
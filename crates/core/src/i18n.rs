@@ -0,0 +1,126 @@
+//! 界面与 lesson 文本的翻译
+//!
+//! 每种语言一个 `locales/<code>.toml` 文本包，编译时嵌入。嵌套的表按 `.` 拼成键，
+//! 例如 `[lesson.hello_world]` 下的 `greeting` 对应键 `lesson.hello_world.greeting`。
+//! 文本中的 `{name}` 由 [`tr_with`] 替换为参数。
+//!
+//! 查找顺序：所选语言 → 默认语言（中文）→ 键名本身，因此漏翻的文本不会让程序出错。
+//! lesson 标题是例外：没有译文时使用 `curriculum.toml` 中的标题。
+
+use crate::lesson::{Language, LessonMeta};
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::sync::LazyLock;
+
+type Bundle = BTreeMap<String, String>;
+
+static ZH: LazyLock<Bundle> = LazyLock::new(|| parse(include_str!("../../../locales/zh.toml")));
+static EN: LazyLock<Bundle> = LazyLock::new(|| parse(include_str!("../../../locales/en.toml")));
+
+fn bundle(lang: Language) -> &'static Bundle {
+    match lang {
+        Language::Zh => &ZH,
+        Language::En => &EN,
+    }
+}
+
+/// 解析文本包并把嵌套的表展平成 `a.b.c` 形式的键
+fn parse(text: &str) -> Bundle {
+    fn flatten(prefix: &str, table: &toml::Table, out: &mut Bundle) {
+        for (key, value) in table {
+            let key = if prefix.is_empty() {
+                key.clone()
+            } else {
+                format!("{}.{}", prefix, key)
+            };
+            match value {
+                toml::Value::Table(inner) => flatten(&key, inner, out),
+                toml::Value::String(s) => {
+                    out.insert(key, s.clone());
+                }
+                other => panic!("locale key '{}' must be a string, got {}", key, other),
+            }
+        }
+    }
+
+    let table: toml::Table = toml::from_str(text).expect("invalid locale file");
+    let mut out = Bundle::new();
+    flatten("", &table, &mut out);
+    out
+}
+
+/// 只在所选语言中查找，不回退
+pub fn lookup(lang: Language, key: &str) -> Option<&'static str> {
+    bundle(lang).get(key).map(String::as_str)
+}
+
+/// 所选语言文本包中的全部键与文本，按键排序
+pub fn entries(lang: Language) -> impl Iterator<Item = (&'static str, &'static str)> {
+    bundle(lang).iter().map(|(k, v)| (k.as_str(), v.as_str()))
+}
+
+/// 翻译 `key`，找不到时依次回退到默认语言和键名
+pub fn tr(lang: Language, key: &str) -> Cow<'static, str> {
+    lookup(lang, key)
+        .or_else(|| lookup(Language::default(), key))
+        .map(Cow::Borrowed)
+        .unwrap_or_else(|| Cow::Owned(key.to_string()))
+}
+
+/// 翻译并替换 `{name}` 占位符
+pub fn tr_with(lang: Language, key: &str, args: &[(&str, &str)]) -> String {
+    let mut text = tr(lang, key).into_owned();
+    for (name, value) in args {
+        text = text.replace(&format!("{{{}}}", name), value);
+    }
+    text
+}
+
+/// lesson 标题：`lesson.<slug>.title` 的译文，没有时使用大纲中的标题
+pub fn title(meta: &LessonMeta, lang: Language) -> &'static str {
+    lesson_title(meta.slug, meta.title, lang)
+}
+
+/// 同 [`title`]，用于只知道 slug 与大纲标题的场合（例如未编译的 lesson）
+pub fn lesson_title(slug: &str, fallback: &'static str, lang: Language) -> &'static str {
+    lookup(lang, &format!("lesson.{}.title", slug)).unwrap_or(fallback)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lookup_and_fallback() {
+        assert_eq!(tr(Language::En, "ui.see_also"), "See also: {lessons}");
+        // 英文包没有 lesson 标题，回退到中文
+        assert_eq!(tr(Language::En, "lesson.generics.title"), "泛型");
+        assert_eq!(tr(Language::Zh, "no.such.key"), "no.such.key");
+    }
+
+    #[test]
+    fn test_placeholders() {
+        let text = tr_with(
+            Language::En,
+            "ui.completed",
+            &[("done", "3"), ("total", "19")],
+        );
+        assert_eq!(text, "Completed 3 / 19");
+    }
+
+    #[test]
+    fn test_ui_keys_are_translated() {
+        // 界面文本必须每种语言都有；lesson 正文允许只有中文
+        for lang in [Language::Zh, Language::En] {
+            for key in ZH.keys().filter(|k| k.starts_with("ui.")) {
+                assert!(
+                    lookup(lang, key).is_some(),
+                    "{} missing {}",
+                    lang.code(),
+                    key
+                );
+            }
+        }
+        assert!(EN.keys().all(|k| ZH.contains_key(k)));
+    }
+}
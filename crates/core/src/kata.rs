@@ -0,0 +1,38 @@
+//! 型（kata）：与 lesson 无关的小型 TDD 练习
+//!
+//! 每个 kata 是一道独立的小题（反转单词、用迭代器写 FizzBuzz、解析 CSV 行……），
+//! 学习者补全 `katas` crate 中 `src/drills/<name>.rs` 的函数，测试套件写在同名的 kata 模块里，
+//! 与练习的检查一样逐项运行，以 panic 表示失败。
+
+use crate::lesson::{Check, Difficulty};
+
+/// 一个 kata
+#[derive(Debug, Clone, Copy)]
+pub struct Kata {
+    /// 唯一的名字，同时是练习文件名，例如 `reverse_words`
+    pub name: &'static str,
+    pub title: &'static str,
+    pub difficulty: Difficulty,
+    /// 题目说明，运行前显示
+    pub task: &'static str,
+    /// 测试套件
+    pub tests: &'static [Check],
+}
+
+impl Kata {
+    pub const fn new(
+        name: &'static str,
+        title: &'static str,
+        difficulty: Difficulty,
+        task: &'static str,
+        tests: &'static [Check],
+    ) -> Self {
+        Kata {
+            name,
+            title,
+            difficulty,
+            task,
+            tests,
+        }
+    }
+}
@@ -0,0 +1,939 @@
+//! Lesson 抽象
+//!
+//! 每个 lesson 都实现 [`Lesson`] trait，运行器的注册表以 `Box<dyn Lesson>` 统一保存，
+//! 进程内只创建一次（见 `rust_learn::lessons::all`）。
+//! 元数据、运行上下文与错误处理都围绕这个 trait 展开。
+
+use crate::i18n;
+use crate::utils::deterministic::{self, MapDebug};
+use crate::utils::io::{Input, StdinInput};
+use crate::utils::rng::Rng;
+use serde::Deserialize;
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+use std::io::{self, Write};
+use std::str::FromStr;
+
+/// lesson 运行结果
+pub type Result<T = ()> = std::result::Result<T, LessonError>;
+
+/// lesson 运行失败的原因
+#[derive(Debug)]
+pub enum LessonError {
+    /// 写输出失败
+    Io(io::Error),
+    /// 演示代码得到了不符合预期的结果
+    Failed(String),
+    /// 演示代码中的其他错误，保留原始错误以便追溯原因
+    Other(Box<dyn Error + Send + Sync>),
+}
+
+impl LessonError {
+    pub fn other(err: impl Error + Send + Sync + 'static) -> Self {
+        LessonError::Other(Box::new(err))
+    }
+
+    /// 错误类别，用于结构化展示
+    pub fn kind(&self) -> &'static str {
+        match self {
+            LessonError::Io(_) => "io",
+            LessonError::Failed(_) => "failed",
+            LessonError::Other(_) => "error",
+        }
+    }
+
+    /// 多行报告：错误本身加上逐级的 `caused by`
+    pub fn report(&self) -> String {
+        let mut report = format!("[{}] {}", self.kind(), self);
+        let mut source = self.source();
+        while let Some(cause) = source {
+            report.push_str(&format!("\n  caused by: {}", cause));
+            source = cause.source();
+        }
+        report
+    }
+}
+
+impl fmt::Display for LessonError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LessonError::Io(e) => write!(f, "I/O error: {}", e),
+            LessonError::Failed(reason) => f.write_str(reason),
+            LessonError::Other(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl Error for LessonError {
+    /// 内层错误的消息已经包含在 `Display` 中，这里从它的下一层开始
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            LessonError::Io(e) => e.source(),
+            LessonError::Failed(_) => None,
+            LessonError::Other(e) => e.source(),
+        }
+    }
+}
+
+impl From<io::Error> for LessonError {
+    fn from(err: io::Error) -> Self {
+        LessonError::Io(err)
+    }
+}
+
+impl From<String> for LessonError {
+    fn from(reason: String) -> Self {
+        LessonError::Failed(reason)
+    }
+}
+
+impl From<&str> for LessonError {
+    fn from(reason: &str) -> Self {
+        LessonError::Failed(reason.to_string())
+    }
+}
+
+/// 难度分级
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Difficulty {
+    Beginner,
+    Intermediate,
+    Advanced,
+}
+
+impl Difficulty {
+    pub const ALL: [Difficulty; 3] = [
+        Difficulty::Beginner,
+        Difficulty::Intermediate,
+        Difficulty::Advanced,
+    ];
+
+    /// 星级表示，例如 `★★☆`
+    pub fn stars(self) -> &'static str {
+        match self {
+            Difficulty::Beginner => "★☆☆",
+            Difficulty::Intermediate => "★★☆",
+            Difficulty::Advanced => "★★★",
+        }
+    }
+}
+
+impl fmt::Display for Difficulty {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Difficulty::Beginner => "Beginner",
+            Difficulty::Intermediate => "Intermediate",
+            Difficulty::Advanced => "Advanced",
+        };
+        f.pad(name)
+    }
+}
+
+/// 课程章节：按学习顺序排列
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Chapter {
+    Basics,
+    Ownership,
+    TypesTraits,
+    Collections,
+    ErrorHandling,
+    Advanced,
+}
+
+impl Chapter {
+    pub const ALL: [Chapter; 6] = [
+        Chapter::Basics,
+        Chapter::Ownership,
+        Chapter::TypesTraits,
+        Chapter::Collections,
+        Chapter::ErrorHandling,
+        Chapter::Advanced,
+    ];
+
+    pub fn title(self) -> &'static str {
+        match self {
+            Chapter::Basics => "Basics",
+            Chapter::Ownership => "Ownership",
+            Chapter::TypesTraits => "Types & Traits",
+            Chapter::Collections => "Collections",
+            Chapter::ErrorHandling => "Error Handling",
+            Chapter::Advanced => "Advanced",
+        }
+    }
+}
+
+impl fmt::Display for Chapter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(self.title())
+    }
+}
+
+/// lesson 的静态元数据
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LessonMeta {
+    pub number: usize,
+    pub slug: &'static str,
+    /// 也能选中该 lesson 的其他名字，例如改名前的 slug 或简写
+    pub aliases: &'static [&'static str],
+    pub title: &'static str,
+    pub chapter: Chapter,
+    pub difficulty: Difficulty,
+    /// 预计学习时长（分钟）
+    pub minutes: u32,
+    pub tags: &'static [&'static str],
+    /// 建议先学完的 lesson（slug）
+    pub prerequisites: &'static [&'static str],
+    /// 已弃用：仍可运行，但 `list` 中弱化显示，运行时给出提示
+    pub deprecated: bool,
+    /// 取代本 lesson 的新 lesson（slug）；完成记录会迁移过去
+    pub replaced_by: Option<&'static str>,
+}
+
+impl LessonMeta {
+    /// 带编号的完整标识，例如 `06_ownership`
+    pub fn id(&self) -> String {
+        format!("{:02}_{}", self.number, self.slug)
+    }
+
+    /// slug 与全部别名
+    pub fn names(&self) -> impl Iterator<Item = &'static str> {
+        std::iter::once(self.slug).chain(self.aliases.iter().copied())
+    }
+
+    /// 连续运行多个 lesson 时的分隔标题
+    pub fn header(&self, lang: Language) -> String {
+        crate::utils::fmt::banner(&format!("{} — {}", self.id(), i18n::title(self, lang)))
+    }
+}
+
+/// 输出详细程度
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Verbosity {
+    /// 只输出 lesson 本身的内容，不显示分隔标题与提示
+    Quiet,
+    #[default]
+    Normal,
+    Verbose,
+}
+
+impl FromStr for Verbosity {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "quiet" => Ok(Verbosity::Quiet),
+            "normal" => Ok(Verbosity::Normal),
+            "verbose" => Ok(Verbosity::Verbose),
+            _ => Err(format!(
+                "Invalid verbosity '{}' (quiet, normal, verbose)",
+                s
+            )),
+        }
+    }
+}
+
+/// 讲解语言
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Language {
+    #[default]
+    Zh,
+    En,
+}
+
+impl Language {
+    pub fn code(self) -> &'static str {
+        match self {
+            Language::Zh => "zh",
+            Language::En => "en",
+        }
+    }
+}
+
+impl FromStr for Language {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "zh" => Ok(Language::Zh),
+            "en" => Ok(Language::En),
+            _ => Err(format!("Unsupported language '{}' (zh, en)", s)),
+        }
+    }
+}
+
+/// lesson 运行上下文：由 runner 创建并传给每个 lesson
+///
+/// lesson 通过 `writeln!(ctx, ...)` 输出，输出写到哪里由创建者决定：
+/// 命令行下是标准输出（及 `--output` 记录文件），测试中可以是一个 `Vec<u8>`。
+/// 交互演示用 [`LessonContext::prompt`] 读取输入，默认来自标准输入。
+pub struct LessonContext<'a> {
+    out: Out<'a>,
+    /// 输入来源；`None` 时读标准输入
+    input: Option<&'a mut dyn Input>,
+    /// 是否已经输出过内容，用于决定小节标题前是否空一行
+    started: bool,
+    /// 已经输出过的小节标题
+    sections: Vec<String>,
+    /// 本上下文的随机种子；`None` 时用 [`deterministic::seed`]
+    seed: Option<u64>,
+    /// 第一次调用 [`LessonContext::rng`] 时创建
+    rng: Option<Rng>,
+    pub verbosity: Verbosity,
+    pub language: Language,
+}
+
+/// 能直接接收结构化小节的输出目标，例如 `--format jsonl` 的事件流
+pub trait EventSink: Write {
+    fn section(&mut self, title: &str) -> io::Result<()>;
+}
+
+enum Out<'a> {
+    Text(&'a mut dyn Write),
+    Events(&'a mut dyn EventSink),
+}
+
+impl<'a> LessonContext<'a> {
+    pub fn new(out: &'a mut dyn Write) -> Self {
+        LessonContext::with_out(Out::Text(out))
+    }
+
+    /// 输出到事件流：小节标题交给 `sink` 处理，不再格式化为文本
+    pub fn with_events(sink: &'a mut dyn EventSink) -> Self {
+        LessonContext::with_out(Out::Events(sink))
+    }
+
+    fn with_out(out: Out<'a>) -> Self {
+        LessonContext {
+            out,
+            input: None,
+            started: false,
+            sections: Vec::new(),
+            seed: None,
+            rng: None,
+            verbosity: Verbosity::default(),
+            language: Language::default(),
+        }
+    }
+
+    pub fn with_verbosity(mut self, verbosity: Verbosity) -> Self {
+        self.verbosity = verbosity;
+        self
+    }
+
+    pub fn with_language(mut self, language: Language) -> Self {
+        self.language = language;
+        self
+    }
+
+    /// 更换输入来源，例如测试中用 [`crate::utils::io::Scripted`] 给出预设回答
+    pub fn with_input(mut self, input: &'a mut dyn Input) -> Self {
+        self.input = Some(input);
+        self
+    }
+
+    /// 固定随机种子，并让 [`map`](Self::map) 与 [`entries`](Self::entries) 按键排序：
+    /// 只对这个上下文生效，测试与快照不用开启全局的 `--deterministic`
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self.rng = None;
+        self
+    }
+
+    /// lesson 中使用的随机数。平时每次运行都不同；设定了种子或 `--deterministic` 时可复现
+    pub fn rng(&mut self) -> &mut Rng {
+        self.rng
+            .get_or_insert_with(|| Rng::new(self.seed.unwrap_or_else(deterministic::seed)))
+    }
+
+    /// 输出可复现时（设定了种子或 `--deterministic`）HashMap 按键排序
+    fn sorted(&self) -> bool {
+        self.seed.is_some() || deterministic::is_enabled()
+    }
+
+    /// 用 `{:?}` 输出 HashMap；输出需要可复现时按键排序，平时保持 HashMap 本来的顺序，
+    /// 例如 `writeln!(ctx, "{:?}", ctx.map(&scores))`
+    pub fn map<'m, K, V>(&self, map: &'m HashMap<K, V>) -> MapDebug<'m, K, V> {
+        MapDebug::new(map, self.sorted())
+    }
+
+    /// 遍历 HashMap 的键值对，顺序规则同 [`map`](Self::map)
+    pub fn entries<'m, K: Ord, V>(&self, map: &'m HashMap<K, V>) -> Vec<(&'m K, &'m V)> {
+        deterministic::sorted_entries(map, self.sorted())
+    }
+
+    pub fn is_verbose(&self) -> bool {
+        self.verbosity >= Verbosity::Verbose
+    }
+
+    pub fn is_quiet(&self) -> bool {
+        self.verbosity <= Verbosity::Quiet
+    }
+
+    /// 输出小节标题；不是第一段输出时先空一行
+    pub fn section(&mut self, title: &str) -> io::Result<()> {
+        self.sections.push(title.to_string());
+        if let Out::Events(sink) = &mut self.out {
+            return sink.section(title);
+        }
+        if self.started {
+            writeln!(self)?;
+        }
+        writeln!(self, "{}", crate::utils::fmt::section(title))
+    }
+
+    /// 到目前为止输出过的小节标题，按先后顺序
+    pub fn sections(&self) -> &[String] {
+        &self.sections
+    }
+
+    /// 读取一行输入，输入结束时返回 `None`
+    pub fn read_line(&mut self) -> io::Result<Option<String>> {
+        match &mut self.input {
+            Some(input) => input.read_line(),
+            None => StdinInput.read_line(),
+        }
+    }
+
+    /// 输出提示并读取一行，去掉首尾空白；输入结束时返回 `UnexpectedEof` 错误
+    pub fn prompt(&mut self, msg: &str) -> io::Result<String> {
+        write!(self, "{}", msg)?;
+        self.flush()?;
+        match self.read_line()? {
+            Some(line) => Ok(line.trim().to_string()),
+            None => Err(io::Error::new(io::ErrorKind::UnexpectedEof, "input closed")),
+        }
+    }
+
+    /// 输出两列对齐的键值表，过长的值按终端宽度折行
+    pub fn kv_table(&mut self, rows: &[(&str, &str)]) -> io::Result<()> {
+        write!(self, "{}", crate::utils::fmt::kv_table(rows))
+    }
+
+    /// 按当前语言翻译文本，见 [`crate::i18n`]
+    pub fn tr(&self, key: &str) -> Cow<'static, str> {
+        i18n::tr(self.language, key)
+    }
+
+    /// 翻译并替换 `{name}` 占位符
+    pub fn tr_with(&self, key: &str, args: &[(&str, &str)]) -> String {
+        i18n::tr_with(self.language, key, args)
+    }
+}
+
+impl Write for LessonContext<'_> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.started |= !buf.is_empty();
+        match &mut self.out {
+            Out::Text(out) => out.write(buf),
+            Out::Events(sink) => sink.write(buf),
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        match &mut self.out {
+            Out::Text(out) => out.flush(),
+            Out::Events(sink) => sink.flush(),
+        }
+    }
+}
+
+/// lesson 中可以单独运行的一节演示
+///
+/// 按 `lesson:name` 选择，例如 `16_iterators_closures:closure_capture`。
+#[derive(Debug, Clone, Copy)]
+pub struct Section {
+    /// 选择器中使用的名字
+    pub name: &'static str,
+    /// 小节标题
+    pub title: &'static str,
+    pub run: fn(&mut LessonContext) -> Result,
+}
+
+impl Section {
+    pub const fn new(
+        name: &'static str,
+        title: &'static str,
+        run: fn(&mut LessonContext) -> Result,
+    ) -> Self {
+        Section { name, title, run }
+    }
+
+    /// 输出小节标题后运行本节
+    pub fn run_in(&self, ctx: &mut LessonContext) -> Result {
+        ctx.section(self.title)?;
+        (self.run)(ctx)
+    }
+}
+
+/// 测验中的一道单选题
+///
+/// 每个 lesson 在自己的文件中用 `QUIZ` 给出 3–5 道题，`quiz <lesson>` 依次提问并计分。
+#[derive(Debug, Clone, Copy)]
+pub struct Question {
+    pub prompt: &'static str,
+    pub choices: &'static [&'static str],
+    /// 正确选项在 `choices` 中的下标，从 0 开始
+    pub answer: usize,
+    /// 答题后显示的解释
+    pub explanation: &'static str,
+}
+
+impl Question {
+    /// 正确选项越界时在编译期报错
+    pub const fn new(
+        prompt: &'static str,
+        choices: &'static [&'static str],
+        answer: usize,
+        explanation: &'static str,
+    ) -> Self {
+        assert!(answer < choices.len(), "Question: answer out of range");
+        Question {
+            prompt,
+            choices,
+            answer,
+            explanation,
+        }
+    }
+
+    /// 第 `choice` 个选项（从 0 开始）是否正确
+    pub fn is_correct(&self, choice: usize) -> bool {
+        choice == self.answer
+    }
+}
+
+/// 练习中的一项检查
+///
+/// 练习文件（lesson crate 的 `src/exercises/<slug>.rs`）只有待补全的函数，检查写在 lesson 文件的
+/// `EXERCISE` 中：调用练习函数并断言结果，像 `#[test]` 一样以 panic 表示失败。
+#[derive(Debug, Clone, Copy)]
+pub struct Check {
+    /// 被检查的练习项，通常是练习文件中的函数名
+    pub name: &'static str,
+    pub check: fn(),
+}
+
+impl Check {
+    pub const fn new(name: &'static str, check: fn()) -> Self {
+        Check { name, check }
+    }
+}
+
+/// 填空练习（koan）：一段带空白 `__` 的 Rust 代码
+///
+/// 每个 lesson 在 `KOANS` 中从自己的示例改写出几段 koan，通常各是一个 `#[test]` 函数；
+/// `generate-koans` 把它们写成独立的 cargo 项目，学习者填好空白，让每个文件编译并通过测试。
+#[derive(Debug, Clone, Copy)]
+pub struct Koan {
+    pub name: &'static str,
+    pub code: &'static str,
+    /// 依次填入各个 `__` 的答案，用来检查 koan 本身写得对
+    pub answers: &'static [&'static str],
+}
+
+impl Koan {
+    /// 空白与答案数量不一致时在编译期报错
+    pub const fn new(
+        name: &'static str,
+        code: &'static str,
+        answers: &'static [&'static str],
+    ) -> Self {
+        assert!(
+            blanks(code) == answers.len(),
+            "Koan: every `__` needs exactly one answer"
+        );
+        Koan {
+            name,
+            code,
+            answers,
+        }
+    }
+
+    /// 填上答案后的代码
+    pub fn solved(&self) -> String {
+        let bytes = self.code.as_bytes();
+        let mut answers = self.answers.iter();
+        let (mut out, mut start, mut i) = (String::new(), 0, 0);
+        while i < bytes.len() {
+            if is_blank(bytes, i)
+                && let Some(answer) = answers.next()
+            {
+                out.push_str(&self.code[start..i]);
+                out.push_str(answer);
+                i += Koan::BLANK.len();
+                start = i;
+            } else {
+                i += 1;
+            }
+        }
+        out.push_str(&self.code[start..]);
+        out
+    }
+
+    pub const BLANK: &'static str = "__";
+}
+
+/// `code` 中空白 `__` 的个数，见 [`is_blank`]
+const fn blanks(code: &str) -> usize {
+    let bytes = code.as_bytes();
+    let (mut i, mut n) = (0, 0);
+    while i < bytes.len() {
+        if is_blank(bytes, i) {
+            n += 1;
+            i += Koan::BLANK.len();
+        } else {
+            i += 1;
+        }
+    }
+    n
+}
+
+/// `bytes[i..]` 以一个独立的 `__` 开头：前后都不是标识符字符，
+/// 因此 `___`、`__x` 之类的标识符不算空白，`(__,__)` 算两个
+const fn is_blank(bytes: &[u8], i: usize) -> bool {
+    const fn ident(b: u8) -> bool {
+        b.is_ascii_alphanumeric() || b == b'_'
+    }
+    i + 1 < bytes.len()
+        && bytes[i] == b'_'
+        && bytes[i + 1] == b'_'
+        && (i == 0 || !ident(bytes[i - 1]))
+        && (i + 2 == bytes.len() || !ident(bytes[i + 2]))
+}
+
+/// 常见坑：一段无法通过编译的代码及其原因
+///
+/// 代码放在 lesson crate 的 `pitfalls/<slug>/<name>.rs` 中，用 `include_str!` 引入；
+/// `pitfalls <lesson>` 现场编译它，展示编译器的真实报错，再给出解释。
+/// lesson crate 的 `tests/pitfalls.rs` 用 trybuild 确认每段代码都编译失败、报错与快照一致。
+/// 报错带错误码时用 [`Pitfall::error`] 标明，`explain-error <code>` 据此把错误码关联回 lesson。
+#[derive(Debug, Clone, Copy)]
+pub struct Pitfall {
+    /// 与文件名相同
+    pub name: &'static str,
+    pub code: &'static str,
+    /// 为什么编译不过、应当怎样改
+    pub explanation: &'static str,
+    /// rustc 的错误码，例如 `E0382`；有的报错（例如找不到宏）没有错误码
+    pub error: Option<&'static str>,
+}
+
+impl Pitfall {
+    pub const fn new(name: &'static str, code: &'static str, explanation: &'static str) -> Self {
+        Pitfall {
+            name,
+            code,
+            explanation,
+            error: None,
+        }
+    }
+
+    /// 标明编译器报错的错误码
+    pub const fn error(mut self, code: &'static str) -> Self {
+        self.error = Some(code);
+        self
+    }
+}
+
+/// 依次运行全部小节，分节的 lesson 用它实现 `run`
+pub fn run_sections(ctx: &mut LessonContext, sections: &[Section]) -> Result {
+    sections.iter().try_for_each(|s| s.run_in(ctx))
+}
+
+pub trait Lesson: Send + Sync {
+    fn meta(&self) -> LessonMeta;
+
+    fn run(&self, ctx: &mut LessonContext) -> Result;
+
+    /// 可以单独运行的小节；不分节的 lesson 返回空列表
+    fn sections(&self) -> Vec<Section> {
+        Vec::new()
+    }
+
+    /// 测验题；没有测验的 lesson 返回空列表
+    fn quiz(&self) -> Vec<Question> {
+        Vec::new()
+    }
+
+    /// 练习的检查项；没有练习的 lesson 返回空列表
+    fn exercise(&self) -> Vec<Check> {
+        Vec::new()
+    }
+
+    /// 练习的提示，由浅入深，`hint` 命令逐条给出
+    fn hints(&self) -> Vec<&'static str> {
+        Vec::new()
+    }
+
+    /// 填空练习，`generate-koans` 使用
+    fn koans(&self) -> Vec<Koan> {
+        Vec::new()
+    }
+
+    /// 编译不过的常见坑，`pitfalls` 命令使用
+    fn pitfalls(&self) -> Vec<Pitfall> {
+        Vec::new()
+    }
+}
+
+impl<L: Lesson + ?Sized> Lesson for &L {
+    fn meta(&self) -> LessonMeta {
+        (**self).meta()
+    }
+
+    fn run(&self, ctx: &mut LessonContext) -> Result {
+        (**self).run(ctx)
+    }
+
+    fn sections(&self) -> Vec<Section> {
+        (**self).sections()
+    }
+
+    fn quiz(&self) -> Vec<Question> {
+        (**self).quiz()
+    }
+
+    fn exercise(&self) -> Vec<Check> {
+        (**self).exercise()
+    }
+
+    fn hints(&self) -> Vec<&'static str> {
+        (**self).hints()
+    }
+
+    fn koans(&self) -> Vec<Koan> {
+        (**self).koans()
+    }
+
+    fn pitfalls(&self) -> Vec<Pitfall> {
+        (**self).pitfalls()
+    }
+}
+
+impl<L: Lesson + ?Sized> Lesson for Box<L> {
+    fn meta(&self) -> LessonMeta {
+        (**self).meta()
+    }
+
+    fn run(&self, ctx: &mut LessonContext) -> Result {
+        (**self).run(ctx)
+    }
+
+    fn sections(&self) -> Vec<Section> {
+        (**self).sections()
+    }
+
+    fn quiz(&self) -> Vec<Question> {
+        (**self).quiz()
+    }
+
+    fn exercise(&self) -> Vec<Check> {
+        (**self).exercise()
+    }
+
+    fn hints(&self) -> Vec<&'static str> {
+        (**self).hints()
+    }
+
+    fn koans(&self) -> Vec<Koan> {
+        (**self).koans()
+    }
+
+    fn pitfalls(&self) -> Vec<Pitfall> {
+        (**self).pitfalls()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Demo;
+
+    impl Lesson for Demo {
+        fn meta(&self) -> LessonMeta {
+            LessonMeta {
+                number: 7,
+                slug: "demo",
+                aliases: &[],
+                title: "Demo",
+                chapter: Chapter::Basics,
+                difficulty: Difficulty::Beginner,
+                minutes: 5,
+                tags: &["demo"],
+                prerequisites: &[],
+                deprecated: false,
+                replaced_by: None,
+            }
+        }
+
+        fn run(&self, ctx: &mut LessonContext) -> Result {
+            writeln!(ctx, "demo output")?;
+            Err("boom".into())
+        }
+    }
+
+    #[test]
+    fn test_meta_id() {
+        assert_eq!(Demo.meta().id(), "07_demo");
+    }
+
+    #[test]
+    fn test_difficulty_display() {
+        assert_eq!(Difficulty::Intermediate.to_string(), "Intermediate");
+        assert_eq!(Difficulty::Advanced.stars(), "★★★");
+        assert!(Difficulty::Beginner < Difficulty::Advanced);
+    }
+
+    #[test]
+    fn test_context_settings() {
+        let mut out = Vec::new();
+        let ctx = LessonContext::new(&mut out).with_verbosity(Verbosity::Quiet);
+        assert!(ctx.is_quiet() && !ctx.is_verbose());
+        assert_eq!(ctx.language, Language::Zh);
+        assert_eq!("en".parse::<Language>().unwrap().code(), "en");
+        assert!("fr".parse::<Language>().is_err());
+    }
+
+    #[test]
+    fn test_scripted_prompt() {
+        use crate::utils::io::Scripted;
+
+        let (mut out, mut input) = (Vec::new(), Scripted::new([" 42 "]));
+        let mut ctx = LessonContext::new(&mut out).with_input(&mut input);
+        assert_eq!(ctx.prompt("guess: ").unwrap(), "42");
+        let err = ctx.prompt("again: ").unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+        assert_eq!(String::from_utf8(out).unwrap(), "guess: again: ");
+    }
+
+    #[test]
+    fn test_seeded_context() {
+        let mut out = Vec::new();
+        let mut ctx = LessonContext::new(&mut out).with_seed(42);
+        let rolls: Vec<u64> = (0..3).map(|_| ctx.rng().next_u64()).collect();
+        let mut again = Vec::new();
+        let mut other = LessonContext::new(&mut again).with_seed(42);
+        assert_eq!(
+            rolls,
+            (0..3).map(|_| other.rng().next_u64()).collect::<Vec<_>>()
+        );
+
+        let map: HashMap<_, _> = (0..20).map(|i| (i, i * i)).collect();
+        let sorted: Vec<(i32, i32)> = (0..20).map(|i| (i, i * i)).collect();
+        assert_eq!(
+            ctx.entries(&map)
+                .into_iter()
+                .map(|(&k, &v)| (k, v))
+                .collect::<Vec<_>>(),
+            sorted
+        );
+        writeln!(ctx, "{:?}", ctx.map(&map)).unwrap();
+        let expected = format!(
+            "{:?}\n",
+            sorted
+                .into_iter()
+                .collect::<std::collections::BTreeMap<_, _>>()
+        );
+        assert_eq!(String::from_utf8(out).unwrap(), expected);
+    }
+
+    #[derive(Debug)]
+    struct Outer(io::Error);
+
+    impl fmt::Display for Outer {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("cannot load sample")
+        }
+    }
+
+    impl Error for Outer {
+        fn source(&self) -> Option<&(dyn Error + 'static)> {
+            Some(&self.0)
+        }
+    }
+
+    #[test]
+    fn test_error_report() {
+        let err: LessonError = io::Error::other("pipe closed").into();
+        assert_eq!(err.report(), "[io] I/O error: pipe closed");
+
+        let err = LessonError::other(Outer(io::Error::other("disk full")));
+        assert_eq!(
+            err.report(),
+            "[error] cannot load sample\n  caused by: disk full"
+        );
+    }
+
+    #[test]
+    fn test_chapter_order() {
+        assert_eq!(Chapter::TypesTraits.to_string(), "Types & Traits");
+        assert!(Chapter::ALL.windows(2).all(|w| w[0] < w[1]));
+    }
+
+    #[test]
+    fn test_trait_object_run() {
+        let lesson: Box<dyn Lesson> = Box::new(Demo);
+        let mut out = Vec::new();
+        let err = lesson.run(&mut LessonContext::new(&mut out)).unwrap_err();
+        assert_eq!(err.to_string(), "boom");
+        assert_eq!(err.kind(), "failed");
+        assert_eq!(String::from_utf8(out).unwrap(), "demo output\n");
+        assert!(lesson.sections().is_empty());
+        assert!(lesson.quiz().is_empty());
+    }
+
+    #[test]
+    fn test_question() {
+        const Q: Question = Question::new("1 + 1 = ?", &["1", "2"], 1, "加法");
+        assert!(Q.is_correct(1) && !Q.is_correct(0));
+    }
+
+    #[test]
+    fn test_koan() {
+        const K: Koan = Koan::new("sum", "assert_eq!(__ + 1, __);", &["1", "2"]);
+        assert_eq!(K.solved(), "assert_eq!(1 + 1, 2);");
+        assert_eq!(blanks("let _x = ___;"), 0);
+    }
+
+    #[test]
+    fn test_koan_blank_tokens() {
+        const K: Koan = Koan::new(
+            "tuple",
+            "let ___ = (__,__); let __x = [__; 2];",
+            &["1", "2", "0"],
+        );
+        assert_eq!(K.solved(), "let ___ = (1,2); let __x = [0; 2];");
+        assert_eq!(blanks("__ ____ __"), 2);
+        assert_eq!(blanks("\"__\""), 1);
+    }
+
+    fn first(ctx: &mut LessonContext) -> Result {
+        writeln!(ctx, "one")?;
+        Ok(())
+    }
+
+    fn second(_ctx: &mut LessonContext) -> Result {
+        Err("two failed".into())
+    }
+
+    #[test]
+    fn test_run_sections() {
+        let sections = [
+            Section::new("first", "First", first),
+            Section::new("second", "Second", second),
+        ];
+        let mut out = Vec::new();
+        let mut ctx = LessonContext::new(&mut out);
+        let err = run_sections(&mut ctx, &sections).unwrap_err();
+        assert_eq!(err.to_string(), "two failed");
+        assert_eq!(ctx.sections(), ["First", "Second"]);
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "=== First ===\none\n\n=== Second ===\n"
+        );
+    }
+}
@@ -0,0 +1,9 @@
+//! rust-learn 的公共基础：lesson 抽象、界面翻译与共用工具
+//!
+//! 运行器（workspace 根目录的 `rust-learn-kimi`）与各 lesson crate 都依赖本 crate，
+//! 它本身不依赖任何 lesson，因此修改 lesson 只会重新编译对应的 lesson crate 与运行器。
+
+pub mod i18n;
+pub mod kata;
+pub mod lesson;
+pub mod utils;
@@ -0,0 +1,119 @@
+//! 确定性输出模式（`--deterministic`）
+//!
+//! 开启后：
+//! - HashMap 按键排序输出
+//! - 随机数使用固定种子
+//! - 耗时、内存地址等每次运行都会变化的值用占位符代替
+//! - 换行与截断按 80 列，不随终端宽度变化
+//!
+//! 这样同一 lesson 的输出可以跨运行 diff，也可用于快照测试。
+
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::hash::Hash;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// 确定性模式下使用的固定随机种子
+pub const FIXED_SEED: u64 = 0x5EED;
+
+pub fn enable() {
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// 包装 HashMap，`{:?}` 时按需按键排序输出
+pub struct MapDebug<'a, K, V> {
+    map: &'a HashMap<K, V>,
+    sorted: bool,
+}
+
+impl<'a, K, V> MapDebug<'a, K, V> {
+    pub(crate) fn new(map: &'a HashMap<K, V>, sorted: bool) -> Self {
+        MapDebug { map, sorted }
+    }
+}
+
+/// 确定性模式下按键排序；lesson 中用 [`LessonContext::map`](crate::lesson::LessonContext::map)，
+/// 设定了种子的上下文也会排序
+pub fn map<K, V>(map: &HashMap<K, V>) -> MapDebug<'_, K, V> {
+    MapDebug::new(map, is_enabled())
+}
+
+impl<K: fmt::Debug + Ord + Hash, V: fmt::Debug> fmt::Debug for MapDebug<'_, K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.sorted {
+            // BTreeMap 的 Debug 格式与 HashMap 一致，只是有序
+            fmt::Debug::fmt(&self.map.iter().collect::<BTreeMap<_, _>>(), f)
+        } else {
+            fmt::Debug::fmt(self.map, f)
+        }
+    }
+}
+
+/// 遍历 HashMap 的键值对；确定性模式下按键排序
+pub fn entries<K: Ord, V>(map: &HashMap<K, V>) -> Vec<(&K, &V)> {
+    sorted_entries(map, is_enabled())
+}
+
+pub(crate) fn sorted_entries<K: Ord, V>(map: &HashMap<K, V>, sorted: bool) -> Vec<(&K, &V)> {
+    let mut entries: Vec<_> = map.iter().collect();
+    if sorted {
+        entries.sort_by(|a, b| a.0.cmp(b.0));
+    }
+    entries
+}
+
+/// 随机种子：确定性模式下固定，否则取自当前时间
+pub fn seed() -> u64 {
+    if is_enabled() {
+        return FIXED_SEED;
+    }
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_nanos() as u64)
+        .unwrap_or(FIXED_SEED)
+}
+
+/// 格式化耗时；确定性模式下输出占位符
+pub fn duration(d: Duration) -> String {
+    if is_enabled() {
+        String::from("<duration>")
+    } else {
+        format!("{:?}", d)
+    }
+}
+
+/// 格式化内存地址；确定性模式下输出占位符
+pub fn address<T: ?Sized>(value: &T) -> String {
+    if is_enabled() {
+        String::from("<address>")
+    } else {
+        format!("{:p}", value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 不调用 `enable()`：开关是进程级的，打开后会影响同一测试二进制中的其他测试
+    #[test]
+    fn test_sorted_mode_is_stable() {
+        let map: HashMap<_, _> = [("b", 2), ("c", 3), ("a", 1)].into_iter().collect();
+        assert_eq!(
+            format!("{:?}", MapDebug::new(&map, true)),
+            r#"{"a": 1, "b": 2, "c": 3}"#
+        );
+        assert_eq!(
+            sorted_entries(&map, true),
+            vec![(&"a", &1), (&"b", &2), (&"c", &3)]
+        );
+        assert_eq!(sorted_entries(&map, false).len(), 3);
+    }
+}
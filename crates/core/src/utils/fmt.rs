@@ -0,0 +1,541 @@
+//! 共享的输出格式化辅助函数
+//!
+//! 标题横幅、小节标题、键值表等装饰性输出统一从这里生成。开启 `--ascii` 后，emoji、
+//! 制表符（box-drawing）和排版符号会被替换为纯 ASCII 等价写法，
+//! 适合会把这些字符显示成乱码的终端或 CI 日志。
+//! 中文内容本身没有 ASCII 等价形式，保持不变。
+//!
+//! 开启颜色后，横幅等装饰会带上 ANSI 颜色；lesson 正文不着色。
+//! 横幅与小节标题的字符、颜色由 [`Theme`]（`--theme`）决定。
+//!
+//! 排版按终端宽度进行（[`columns`]）：[`wrap`] 与 [`paragraph`] 折行，[`table`] 与 [`kv_table`] 按显示宽度
+//! 对齐，[`truncate`] 截断放不下的行。输出不是终端时按 80 列排版，重定向与测试中的输出因此保持稳定。
+
+use serde::Deserialize;
+use std::borrow::Cow;
+use std::io::{self, IsTerminal};
+use std::str::FromStr;
+use std::sync::atomic::{AtomicBool, AtomicU8, Ordering};
+
+/// 输出不是终端时的排版宽度
+pub const DEFAULT_COLUMNS: usize = 80;
+
+/// 终端再窄也按这个宽度排版，避免每行只剩几个字
+const MIN_COLUMNS: usize = 40;
+
+static ASCII: AtomicBool = AtomicBool::new(false);
+static COLOR: AtomicBool = AtomicBool::new(false);
+static THEME: AtomicU8 = AtomicU8::new(Theme::Classic as u8);
+
+/// 何时使用颜色（`--color` / 配置项 `color`）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ColorChoice {
+    /// 标准输出是终端时才着色
+    #[default]
+    Auto,
+    Always,
+    Never,
+}
+
+impl ColorChoice {
+    pub fn enabled(self) -> bool {
+        match self {
+            ColorChoice::Auto => io::stdout().is_terminal(),
+            ColorChoice::Always => true,
+            ColorChoice::Never => false,
+        }
+    }
+}
+
+impl FromStr for ColorChoice {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "auto" => Ok(ColorChoice::Auto),
+            "always" => Ok(ColorChoice::Always),
+            "never" => Ok(ColorChoice::Never),
+            _ => Err(format!(
+                "Invalid color choice '{}' (auto, always, never)",
+                s
+            )),
+        }
+    }
+}
+
+/// 输出主题（`--theme` / 配置项 `theme`）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Theme {
+    /// 不着色，装饰与 emoji 都换成 ASCII，适合日志与屏幕阅读器
+    Plain,
+    /// 默认主题
+    #[default]
+    Classic,
+    /// 更醒目的横幅与小节标题
+    Fancy,
+    /// 高对比度：粗体亮色，不使用弱化显示
+    HighContrast,
+}
+
+impl Theme {
+    pub const ALL: [Theme; 4] = [
+        Theme::Plain,
+        Theme::Classic,
+        Theme::Fancy,
+        Theme::HighContrast,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Theme::Plain => "plain",
+            Theme::Classic => "classic",
+            Theme::Fancy => "fancy",
+            Theme::HighContrast => "high-contrast",
+        }
+    }
+
+    /// 横幅：分隔线、标题前缀与 ANSI 颜色
+    fn banner_style(self) -> (&'static str, &'static str, Option<&'static str>) {
+        match self {
+            Theme::Plain => ("========", "", None),
+            Theme::Classic => ("━━━━━━━━", "", Some("1;36")),
+            Theme::Fancy => ("━━━━━━━━", "🦀 ", Some("1;35")),
+            Theme::HighContrast => ("━━━━━━━━", "", Some("1;93")),
+        }
+    }
+
+    /// 小节标题：左右标记与 ANSI 颜色
+    fn section_style(self) -> (&'static str, &'static str, Option<&'static str>) {
+        match self {
+            Theme::Plain => ("=== ", " ===", None),
+            Theme::Classic => ("=== ", " ===", Some("1")),
+            Theme::Fancy => ("─── ", " ───", Some("1;34")),
+            Theme::HighContrast => ("=== ", " ===", Some("1;97")),
+        }
+    }
+
+    /// 弱化显示的颜色；高对比度主题不弱化
+    fn dim_color(self) -> Option<&'static str> {
+        match self {
+            Theme::Plain | Theme::HighContrast => None,
+            Theme::Classic | Theme::Fancy => Some("2"),
+        }
+    }
+
+    /// 是否把 emoji 与制表符换成 ASCII
+    fn ascii(self) -> bool {
+        self == Theme::Plain
+    }
+
+    fn banner(self, title: &str) -> String {
+        let (rule, prefix, color) = self.banner_style();
+        let line = format!("{} {}{} {}", rule, prefix, title, rule);
+        format!("\n{}", self.paint(&line, color))
+    }
+
+    fn section(self, title: &str) -> String {
+        let (left, right, color) = self.section_style();
+        self.paint(&format!("{}{}{}", left, title, right), color)
+    }
+
+    /// 按主题处理 ASCII 替换，开启颜色时再加上 ANSI 颜色
+    fn paint(self, line: &str, color: Option<&str>) -> String {
+        let line = if is_ascii() || self.ascii() {
+            to_ascii(line)
+        } else {
+            line.to_string()
+        };
+        match color {
+            Some(code) if is_color() => format!("\x1b[{}m{}\x1b[0m", code, line),
+            _ => line,
+        }
+    }
+}
+
+impl FromStr for Theme {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Theme::ALL
+            .into_iter()
+            .find(|theme| theme.name() == s)
+            .ok_or_else(|| {
+                format!(
+                    "Unknown theme '{}' (plain, classic, fancy, high-contrast)",
+                    s
+                )
+            })
+    }
+}
+
+pub fn set_theme(theme: Theme) {
+    THEME.store(theme as u8, Ordering::Relaxed);
+}
+
+pub fn theme() -> Theme {
+    let current = THEME.load(Ordering::Relaxed);
+    Theme::ALL
+        .into_iter()
+        .find(|theme| *theme as u8 == current)
+        .unwrap_or_default()
+}
+
+/// 非 ASCII 符号 → ASCII 替代
+const REPLACEMENTS: &[(char, &str)] = &[
+    ('🦀', "(crab)"),
+    ('✓', "[x]"),
+    ('✗', "[ ]"),
+    ('◐', "[~]"),
+    ('★', "*"),
+    ('☆', "."),
+    ('→', "->"),
+    ('—', "-"),
+    ('…', "..."),
+    ('━', "="),
+    ('█', "#"),
+    ('░', "."),
+    ('─', "-"),
+    ('│', "|"),
+    ('┌', "+"),
+    ('┐', "+"),
+    ('└', "+"),
+    ('┘', "+"),
+    ('├', "+"),
+    ('┤', "+"),
+    ('“', "\""),
+    ('”', "\""),
+];
+
+pub fn enable_ascii() {
+    ASCII.store(true, Ordering::Relaxed);
+}
+
+pub fn is_ascii() -> bool {
+    ASCII.load(Ordering::Relaxed)
+}
+
+pub fn enable_color() {
+    COLOR.store(true, Ordering::Relaxed);
+}
+
+pub fn is_color() -> bool {
+    COLOR.load(Ordering::Relaxed)
+}
+
+/// ASCII 模式或 plain 主题下替换 emoji 与制表符；否则原样返回
+pub fn text(s: &str) -> Cow<'_, str> {
+    if is_ascii() || theme().ascii() {
+        Cow::Owned(to_ascii(s))
+    } else {
+        Cow::Borrowed(s)
+    }
+}
+
+fn to_ascii(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match REPLACEMENTS.iter().find(|(from, _)| *from == c) {
+            Some((_, to)) => out.push_str(to),
+            None => out.push(c),
+        }
+    }
+    out
+}
+
+/// 运行多个 lesson 时，每个 lesson 前的横幅
+pub fn banner(title: &str) -> String {
+    theme().banner(title)
+}
+
+/// lesson 内的小节标题，例如 `=== 所有权基础 ===`
+pub fn section(title: &str) -> String {
+    theme().section(title)
+}
+
+/// 两列对齐的键值表，每行缩进两格；键按显示宽度对齐，过长的值在终端宽度内折行并与第一行对齐
+pub fn kv_table(rows: &[(&str, &str)]) -> String {
+    let key_columns = rows.iter().map(|(key, _)| width(key)).max().unwrap_or(0) + 2;
+    rows.iter()
+        .map(|(key, value)| {
+            let first = format!("  {}", pad(key, key_columns));
+            let rest = " ".repeat(key_columns + 2);
+            format!("{}\n", paragraph(value, &first, &rest))
+        })
+        .collect()
+}
+
+/// 标准输出所在终端的列数；不是终端或确定性模式下为 [`DEFAULT_COLUMNS`]
+pub fn columns() -> usize {
+    if super::deterministic::is_enabled() {
+        return DEFAULT_COLUMNS;
+    }
+    terminal_size::terminal_size().map_or(DEFAULT_COLUMNS, |(terminal_size::Width(w), _)| {
+        usize::from(w).max(MIN_COLUMNS)
+    })
+}
+
+/// 按显示宽度对齐的表格，列之间空两格，每行截断到终端宽度。
+/// 每行的最后一格既不补空格，也不参与计算所在列的宽度，因此长短不一的行可以放在一起
+pub fn table<S: AsRef<str>>(rows: &[Vec<S>]) -> Vec<String> {
+    let mut widths: Vec<usize> = Vec::new();
+    for row in rows {
+        for (i, cell) in row.iter().enumerate().take(row.len().saturating_sub(1)) {
+            if widths.len() <= i {
+                widths.push(0);
+            }
+            widths[i] = widths[i].max(width(cell.as_ref()));
+        }
+    }
+    let limit = columns();
+    rows.iter()
+        .map(|row| {
+            let mut line = String::new();
+            for (i, cell) in row.iter().enumerate() {
+                if i + 1 < row.len() {
+                    line.push_str(&pad(cell.as_ref(), widths[i] + 2));
+                } else {
+                    line.push_str(cell.as_ref());
+                }
+            }
+            truncate(line.trim_end(), limit).into_owned()
+        })
+        .collect()
+}
+
+/// 折行到终端宽度：第一行以 `first` 开头，其余各行以 `rest` 开头（通常是与 `first` 等宽的空格）
+pub fn paragraph(text: &str, first: &str, rest: &str) -> String {
+    let indent = width(first).max(width(rest));
+    let lines = wrap(text, columns().saturating_sub(indent).max(MIN_COLUMNS / 2));
+    let mut out = String::from(first);
+    for (i, line) in lines.iter().enumerate() {
+        if i > 0 {
+            out.push('\n');
+            out.push_str(rest);
+        }
+        out.push_str(line);
+    }
+    out
+}
+
+/// 按显示宽度截断，放不下时以 `…` 结尾（ASCII 模式下是 `...`）
+pub fn truncate(s: &str, columns: usize) -> Cow<'_, str> {
+    if width(s) <= columns {
+        return Cow::Borrowed(s);
+    }
+    let ellipsis = text("…");
+    let budget = columns.saturating_sub(width(&ellipsis));
+    let mut out = String::new();
+    let mut used = 0;
+    for c in s.chars() {
+        used += char_width(c);
+        if used > budget {
+            break;
+        }
+        out.push(c);
+    }
+    out.truncate(out.trim_end().len());
+    out.push_str(&ellipsis);
+    Cow::Owned(out)
+}
+
+/// 按显示宽度折行：英文在空白处断开，中文可以在任意字之间断开；
+/// 单个超长的词独占一行
+pub fn wrap(text: &str, columns: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+    for paragraph in text.lines() {
+        let mut line = String::new();
+        let mut line_width = 0;
+        for (spaced, word) in words(paragraph) {
+            let gap = usize::from(spaced && !line.is_empty());
+            if !line.is_empty() && line_width + gap + width(word) > columns {
+                lines.push(std::mem::take(&mut line));
+                line_width = 0;
+            } else if gap == 1 {
+                line.push(' ');
+                line_width += 1;
+            }
+            line.push_str(word);
+            line_width += width(word);
+        }
+        lines.push(line);
+    }
+    lines
+}
+
+/// 拆分为可断行的单元：`(前面是否有空白, 内容)`；每个宽字符单独成为一个单元
+fn words(s: &str) -> Vec<(bool, &str)> {
+    let mut words = Vec::new();
+    let mut start = None;
+    let mut spaced = false;
+    for (i, c) in s.char_indices() {
+        let wide = char_width(c) == 2;
+        if let Some(begin) = start
+            && (c.is_whitespace() || wide)
+        {
+            words.push((spaced, &s[begin..i]));
+            start = None;
+            spaced = false;
+        }
+        if c.is_whitespace() {
+            spaced = true;
+        } else if wide {
+            words.push((spaced, &s[i..i + c.len_utf8()]));
+            spaced = false;
+        } else if start.is_none() {
+            start = Some(i);
+        }
+    }
+    if let Some(begin) = start {
+        words.push((spaced, &s[begin..]));
+    }
+    words
+}
+
+/// 终端显示宽度：中日韩文字与全角符号占两列
+pub fn width(s: &str) -> usize {
+    s.chars().map(char_width).sum()
+}
+
+fn char_width(c: char) -> usize {
+    match c as u32 {
+        0x1100..=0x115F
+        | 0x2E80..=0xA4CF
+        | 0xAC00..=0xD7A3
+        | 0xF900..=0xFAFF
+        | 0xFE30..=0xFE4F
+        | 0xFF00..=0xFF60
+        | 0xFFE0..=0xFFE6
+        | 0x1F300..=0x1FAFF => 2,
+        _ => 1,
+    }
+}
+
+/// 按显示宽度在右侧补空格，对齐含中文的列
+pub fn pad(s: &str, columns: usize) -> String {
+    format!("{}{}", s, " ".repeat(columns.saturating_sub(width(s))))
+}
+
+/// 弱化显示（例如已弃用的条目）；未开启颜色或主题不弱化时原样返回
+pub fn dim(s: &str) -> String {
+    match theme().dim_color() {
+        Some(code) if is_color() => format!("\x1b[{}m{}\x1b[0m", code, s),
+        _ => s.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_ascii_replaces_symbols() {
+        assert_eq!(to_ascii("Hello 🦀 ✓"), "Hello (crab) [x]");
+        assert_eq!(to_ascii("┌─┐"), "+-+");
+        assert_eq!(to_ascii("所有权 — ok"), "所有权 - ok");
+    }
+
+    #[test]
+    fn test_pad_by_display_width() {
+        assert_eq!(width("泛型 Generics"), 13);
+        assert_eq!(pad("泛型", 6), "泛型  ");
+        assert_eq!(pad("abc", 2), "abc");
+    }
+
+    #[test]
+    fn test_section_and_kv_table() {
+        assert_eq!(section("所有权"), "=== 所有权 ===");
+        assert_eq!(
+            kv_table(&[("Tags:", "basics"), ("Chapter:", "Basics")]),
+            "  Tags:     basics\n  Chapter:  Basics\n"
+        );
+        assert_eq!(
+            kv_table(&[("名称", "a"), ("id", "b")]),
+            "  名称  a\n  id    b\n"
+        );
+    }
+
+    #[test]
+    fn test_wrap() {
+        assert_eq!(
+            wrap("the quick brown fox jumps", 10),
+            ["the quick", "brown fox", "jumps"]
+        );
+        assert_eq!(
+            wrap("所有权规则保证内存安全", 8),
+            ["所有权规", "则保证内", "存安全"]
+        );
+        assert_eq!(wrap("使用 Vec 存储", 8), ["使用 Vec", "存储"]);
+        assert_eq!(
+            wrap("supercalifragilistic ok", 5),
+            ["supercalifragilistic", "ok"]
+        );
+        assert_eq!(wrap("a\n\nb", 10), ["a", "", "b"]);
+    }
+
+    #[test]
+    fn test_table_and_truncate() {
+        assert_eq!(truncate("hello", 5), "hello");
+        assert!(to_ascii(&truncate("hello world", 8)).starts_with("hello"));
+        // 省略号的宽度取决于是否开启了 ASCII 模式
+        let cut = truncate("所有权基础", 7);
+        assert!(cut.starts_with("所有") && width(&cut) <= 7);
+        let rows = [
+            vec!["01", "hello", "你好", "5 min"],
+            vec!["10", "generics", "泛型"],
+            vec!["2", "x", "所有权", ""],
+        ];
+        // 最后一格不参与列宽：“泛型”不影响第三列，“所有权”影响
+        assert_eq!(
+            table(&rows),
+            [
+                "01  hello     你好    5 min",
+                "10  generics  泛型",
+                "2   x         所有权",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_paragraph() {
+        assert_eq!(paragraph("", "  1) ", "     "), "  1) ");
+        let text = paragraph(&"word ".repeat(40), "  - ", "    ");
+        let lines: Vec<&str> = text.lines().collect();
+        assert!(lines.len() > 1);
+        assert!(lines[0].starts_with("  - word"));
+        assert!(lines[1].starts_with("    word"));
+        assert!(lines.iter().all(|l| width(l) <= columns()));
+    }
+
+    #[test]
+    fn test_color_choice() {
+        assert_eq!("never".parse(), Ok(ColorChoice::Never));
+        assert!("sometimes".parse::<ColorChoice>().is_err());
+        assert!(ColorChoice::Always.enabled());
+        assert!(!ColorChoice::Never.enabled());
+    }
+
+    #[test]
+    fn test_themes() {
+        assert_eq!(Theme::Classic.section("所有权"), "=== 所有权 ===");
+        // 其他测试可能已打开全局 ASCII 模式，统一换成 ASCII 后再比较
+        assert_eq!(to_ascii(&Theme::Fancy.section("所有权")), "--- 所有权 ---");
+        assert_eq!(
+            Theme::Plain.banner("01 — Hi 🦀"),
+            "\n======== 01 - Hi (crab) ========"
+        );
+        assert!(Theme::Fancy.banner("Hi").contains("Hi"));
+        assert_eq!(Theme::HighContrast.dim_color(), None);
+        assert_eq!("high-contrast".parse(), Ok(Theme::HighContrast));
+        assert!("neon".parse::<Theme>().is_err());
+        assert_eq!(theme(), Theme::Classic);
+    }
+
+    #[test]
+    fn test_banner_ascii() {
+        enable_ascii();
+        assert_eq!(banner("01 — Hi"), "\n======== 01 - Hi ========");
+        assert_eq!(text("🦀"), "(crab)");
+    }
+}
@@ -0,0 +1,206 @@
+//! 终端交互输入
+//!
+//! 菜单、测验、逐步运行等需要读取用户输入的功能共用这里的提问函数。
+//! [`Prompter`] 从任意 `BufRead` 读取、向任意 `Write` 输出提示，
+//! 测试时用内存中的输入代替标准输入；`prompt` 等自由函数则直接使用终端。
+//!
+//! 输入结束（EOF）时，`prompt` 与 `read_number` 返回 `UnexpectedEof` 错误，
+//! `confirm` 视为回答“否”。
+//!
+//! lesson 中的交互演示不直接读标准输入，而是通过 [`Input`] 从
+//! [`LessonContext`](crate::lesson::LessonContext) 读取，测试时换成 [`Scripted`]。
+
+use super::output;
+use std::collections::VecDeque;
+use std::io::{self, BufRead, StdinLock, Write};
+use std::ops::RangeInclusive;
+
+/// lesson 读取用户输入的来源
+pub trait Input {
+    /// 读取一行，去掉行尾换行符；输入结束时返回 `None`
+    fn read_line(&mut self) -> io::Result<Option<String>>;
+}
+
+/// 标准输入，命令行下的默认输入来源
+#[derive(Debug, Default)]
+pub struct StdinInput;
+
+impl Input for StdinInput {
+    fn read_line(&mut self) -> io::Result<Option<String>> {
+        let mut line = String::new();
+        if io::stdin().lock().read_line(&mut line)? == 0 {
+            return Ok(None);
+        }
+        output::record_input(&line);
+        Ok(Some(line.trim_end_matches(['\r', '\n']).to_string()))
+    }
+}
+
+/// 预先写好的输入，按顺序逐行返回，用完后视为输入结束
+#[derive(Debug, Default)]
+pub struct Scripted {
+    lines: VecDeque<String>,
+}
+
+impl Scripted {
+    pub fn new<I>(lines: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        Scripted {
+            lines: lines.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl Input for Scripted {
+    fn read_line(&mut self) -> io::Result<Option<String>> {
+        Ok(self.lines.pop_front())
+    }
+}
+
+/// 提问器：向 `output` 写提示，从 `input` 读回答
+pub struct Prompter<R, W> {
+    input: R,
+    output: W,
+    /// 是否把读到的回答补记到 `--output` 记录与会话记录中
+    record: bool,
+}
+
+impl Prompter<StdinLock<'static>, output::Stdout> {
+    /// 从标准输入读取，提示写到标准输出；提示与回答都会进入 `--output` 记录
+    pub fn stdin() -> Self {
+        Prompter {
+            record: true,
+            ..Prompter::new(io::stdin().lock(), output::Stdout)
+        }
+    }
+}
+
+impl<R: BufRead, W: Write> Prompter<R, W> {
+    pub fn new(input: R, output: W) -> Self {
+        Prompter {
+            input,
+            output,
+            record: false,
+        }
+    }
+
+    /// 输出一行文字，不读取输入，例如题目与选项
+    pub fn say(&mut self, text: &str) -> io::Result<()> {
+        writeln!(self.output, "{}", text)
+    }
+
+    /// 显示提示并读取一行，去掉首尾空白
+    pub fn prompt(&mut self, msg: &str) -> io::Result<String> {
+        write!(self.output, "{}", msg)?;
+        self.output.flush()?;
+        let mut line = String::new();
+        if self.input.read_line(&mut line)? == 0 {
+            return Err(io::Error::new(io::ErrorKind::UnexpectedEof, "input closed"));
+        }
+        if self.record {
+            output::record_input(&line);
+        }
+        Ok(line.trim().to_string())
+    }
+
+    /// 是 / 否提问，默认“否”；无法识别的回答会重新提问
+    pub fn confirm(&mut self, msg: &str) -> io::Result<bool> {
+        loop {
+            let answer = match self.prompt(&format!("{} [y/N] ", msg)) {
+                Ok(answer) => answer.to_lowercase(),
+                Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => return Ok(false),
+                Err(e) => return Err(e),
+            };
+            match answer.as_str() {
+                "y" | "yes" | "是" => return Ok(true),
+                "" | "n" | "no" | "否" => return Ok(false),
+                _ => writeln!(self.output, "请输入 y 或 n")?,
+            }
+        }
+    }
+
+    /// 读取范围内的整数，输入无效时重新提问
+    pub fn read_number(&mut self, msg: &str, range: RangeInclusive<i64>) -> io::Result<i64> {
+        loop {
+            let answer = self.prompt(&format!("{} ({}-{}): ", msg, range.start(), range.end()))?;
+            match answer.parse::<i64>() {
+                Ok(n) if range.contains(&n) => return Ok(n),
+                _ => writeln!(
+                    self.output,
+                    "请输入 {} 到 {} 之间的整数",
+                    range.start(),
+                    range.end()
+                )?,
+            }
+        }
+    }
+}
+
+/// 在终端上提问并读取一行
+pub fn prompt(msg: &str) -> io::Result<String> {
+    Prompter::stdin().prompt(msg)
+}
+
+/// 在终端上进行是 / 否提问
+pub fn confirm(msg: &str) -> io::Result<bool> {
+    Prompter::stdin().confirm(msg)
+}
+
+/// 在终端上读取范围内的整数
+pub fn read_number(msg: &str, range: RangeInclusive<i64>) -> io::Result<i64> {
+    Prompter::stdin().read_number(msg, range)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn prompter(input: &str) -> Prompter<&[u8], Vec<u8>> {
+        Prompter::new(input.as_bytes(), Vec::new())
+    }
+
+    #[test]
+    fn test_prompt() {
+        let mut p = prompter("  hello \n");
+        p.say("hi").unwrap();
+        assert_eq!(p.prompt("name? ").unwrap(), "hello");
+        assert_eq!(p.output, b"hi\nname? ");
+        let err = p.prompt("again? ").unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+    }
+
+    #[test]
+    fn test_confirm() {
+        assert!(prompter("y\n").confirm("ok?").unwrap());
+        assert!(!prompter("\n").confirm("ok?").unwrap());
+        assert!(!prompter("").confirm("ok?").unwrap());
+
+        let mut p = prompter("maybe\nYES\n");
+        assert!(p.confirm("ok?").unwrap());
+        assert!(
+            String::from_utf8(p.output)
+                .unwrap()
+                .contains("请输入 y 或 n")
+        );
+    }
+
+    #[test]
+    fn test_read_number() {
+        let mut p = prompter("abc\n42\n7\n");
+        assert_eq!(p.read_number("pick", 1..=10).unwrap(), 7);
+        let out = String::from_utf8(p.output).unwrap();
+        assert_eq!(out.matches("请输入 1 到 10 之间的整数").count(), 2);
+        assert!(prompter("").read_number("pick", 1..=10).is_err());
+    }
+
+    #[test]
+    fn test_scripted_input() {
+        let mut input = Scripted::new(["7", "quit"]);
+        assert_eq!(input.read_line().unwrap().as_deref(), Some("7"));
+        assert_eq!(input.read_line().unwrap().as_deref(), Some("quit"));
+        assert_eq!(input.read_line().unwrap(), None);
+    }
+}
@@ -0,0 +1,10 @@
+//! 工具模块
+//!
+//! 供运行器与各 lesson 共用的辅助函数
+
+pub mod deterministic;
+pub mod fmt;
+pub mod io;
+pub mod output;
+pub mod rng;
+pub mod time;
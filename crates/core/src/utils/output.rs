@@ -0,0 +1,163 @@
+//! Lesson 输出通道
+//!
+//! 命令行把 [`Stdout`] 作为输出目标交给 lesson 与各个命令，写入的内容经由 [`write_bytes`]
+//! 先写到标准输出，再复制到 `--output` 指定的记录文件与会话记录（`--transcript`，如果有）。
+//! 用户在终端输入的回答由 [`record_input`] 补记。
+//!
+//! 配置了分页程序（`--pager` / 配置项 `pager`）且标准输出是终端时，
+//! 原本写往标准输出的内容改为写入分页程序。
+
+use crate::utils::time::Timestamp;
+use std::fs::{self, File};
+use std::io::{self, IsTerminal, Write};
+use std::path::{Path, PathBuf};
+use std::process::{Child, Command, Stdio};
+use std::sync::Mutex;
+
+static TEE: Mutex<Option<File>> = Mutex::new(None);
+static PAGER: Mutex<Option<Child>> = Mutex::new(None);
+static TRANSCRIPT: Mutex<Option<Vec<u8>>> = Mutex::new(None);
+
+/// 写出原始字节（例如并行运行时子进程的缓冲输出）
+pub fn write_bytes(buf: &[u8]) {
+    // 管道被关闭（例如 `| head`、在分页程序中提前退出）时忽略写入错误，不让 lesson 因此 panic
+    match PAGER
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .as_mut()
+        .and_then(|pager| pager.stdin.as_mut())
+    {
+        Some(stdin) => {
+            let _ = stdin.write_all(buf);
+        }
+        None => {
+            let _ = io::stdout().write_all(buf);
+        }
+    }
+    record(buf);
+}
+
+/// 只写入记录文件与会话记录
+fn record(buf: &[u8]) {
+    if let Some(file) = TEE.lock().unwrap_or_else(|e| e.into_inner()).as_mut() {
+        let _ = file.write_all(buf);
+    }
+    if let Some(transcript) = TRANSCRIPT
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .as_mut()
+    {
+        transcript.extend_from_slice(buf);
+    }
+}
+
+/// 补记用户在终端输入的一行（含换行符）：终端已经回显，不再写到标准输出
+pub fn record_input(line: &str) {
+    record(line.as_bytes());
+}
+
+/// 开始在内存中记录之后的全部输出
+pub fn start_transcript() {
+    *TRANSCRIPT.lock().unwrap_or_else(|e| e.into_inner()) = Some(Vec::new());
+}
+
+/// 取出会话记录并停止记录；没有开始记录时为 `None`
+pub fn take_transcript() -> Option<String> {
+    TRANSCRIPT
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .take()
+        .map(|buf| String::from_utf8_lossy(&buf).into_owned())
+}
+
+/// 把之后的标准输出交给分页程序（例如 `less -R`）；标准输出不是终端时什么也不做
+pub fn start_pager(command: &str) -> io::Result<()> {
+    if !io::stdout().is_terminal() {
+        return Ok(());
+    }
+    let (shell, flag) = if cfg!(windows) {
+        ("cmd", "/C")
+    } else {
+        ("sh", "-c")
+    };
+    let child = Command::new(shell)
+        .args([flag, command])
+        .stdin(Stdio::piped())
+        .spawn()?;
+    *PAGER.lock().unwrap_or_else(|e| e.into_inner()) = Some(child);
+    Ok(())
+}
+
+/// 关闭分页程序的输入并等待用户退出分页程序
+pub fn finish_pager() {
+    let pager = PAGER.lock().unwrap_or_else(|e| e.into_inner()).take();
+    if let Some(mut child) = pager {
+        drop(child.stdin.take());
+        let _ = child.wait();
+    }
+}
+
+/// 经由 [`write_bytes`] 输出的 [`Write`] 实现，命令行下 lesson 与各个命令的输出目标
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Stdout;
+
+impl Write for Stdout {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        write_bytes(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        let _ = io::stdout().flush();
+        Ok(())
+    }
+}
+
+/// 把之后的所有输出同时写入记录文件，返回实际文件路径。
+///
+/// `path` 是目录（已存在，或以 `/` 结尾）时，在其中创建以当前 UTC
+/// 时间命名的 `.log` 文件，例如 `sessions/2024-05-01_13-45-00.log`。
+pub fn tee_to(path: &Path) -> io::Result<PathBuf> {
+    let target = if path.is_dir() || path.as_os_str().to_string_lossy().ends_with('/') {
+        path.join(format!("{}.log", Timestamp::now().file_stamp()))
+    } else {
+        path.to_path_buf()
+    };
+    if let Some(parent) = target.parent().filter(|p| !p.as_os_str().is_empty()) {
+        fs::create_dir_all(parent)?;
+    }
+    let file = File::create(&target)?;
+    *TEE.lock().unwrap_or_else(|e| e.into_inner()) = Some(file);
+    Ok(target)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tee_into_directory() {
+        let dir = std::env::temp_dir().join(format!("rust-learn-tee-{}", std::process::id()));
+        let target = tee_to(&dir.join("sessions/")).unwrap();
+        assert!(target.starts_with(dir.join("sessions")));
+        assert_eq!(target.extension().unwrap(), "log");
+
+        writeln!(Stdout, "tee check {}", 42).unwrap();
+        let logged = fs::read_to_string(&target).unwrap();
+        assert!(logged.contains("tee check 42\n"));
+
+        *TEE.lock().unwrap() = None;
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_transcript() {
+        start_transcript();
+        writeln!(Stdout, "transcript check").unwrap();
+        record_input("42\n");
+        let text = take_transcript().unwrap();
+        assert!(text.contains("transcript check\n"));
+        assert!(text.contains("42\n"));
+        assert!(take_transcript().is_none());
+    }
+}
@@ -0,0 +1,62 @@
+//! 可设定种子的伪随机数
+//!
+//! 测验抽题、打乱选项等只需要“看起来随机”，不值得为此引入依赖。这里实现 SplitMix64：
+//! 同一个种子总是得到同一串数，测试时传固定种子即可复现；命令行下用
+//! [`deterministic::seed`](super::deterministic::seed)，`--deterministic` 时它也是固定的。
+
+/// SplitMix64 伪随机数生成器，不可用于密码学
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// `0..n` 中的一个数；`n` 很小，取模带来的偏差可以忽略
+    pub fn below(&mut self, n: usize) -> usize {
+        assert!(n > 0, "Rng::below: empty range");
+        (self.next_u64() % n as u64) as usize
+    }
+
+    /// 原地打乱（Fisher–Yates）
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_same_seed_same_sequence() {
+        let (mut a, mut b) = (Rng::new(42), Rng::new(42));
+        let first: Vec<u64> = (0..5).map(|_| a.next_u64()).collect();
+        assert_eq!(first, (0..5).map(|_| b.next_u64()).collect::<Vec<_>>());
+        assert_ne!(first[0], Rng::new(43).next_u64());
+    }
+
+    #[test]
+    fn test_shuffle_is_a_permutation() {
+        let mut rng = Rng::new(7);
+        let mut items: Vec<u32> = (0..10).collect();
+        rng.shuffle(&mut items);
+        assert_ne!(items, (0..10).collect::<Vec<_>>());
+        items.sort();
+        assert_eq!(items, (0..10).collect::<Vec<_>>());
+        assert!((0..100).all(|_| rng.below(3) < 3));
+    }
+}
@@ -0,0 +1,194 @@
+//! 时间戳辅助函数
+//!
+//! 标准库没有日历计算，这里用 Howard Hinnant 的 civil-from-days 算法把
+//! Unix 时间换算成 UTC 日期时间，避免为此引入额外依赖。
+//! 序列化为 RFC 3339 字符串（`2024-05-01T13:45:00Z`），便于在进度文件中阅读。
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::str::FromStr;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// UTC 日期时间，精确到秒
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Timestamp {
+    pub year: i64,
+    pub month: u32,
+    pub day: u32,
+    pub hour: u32,
+    pub minute: u32,
+    pub second: u32,
+}
+
+impl Timestamp {
+    pub fn now() -> Timestamp {
+        let secs = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+        Timestamp::from_unix(secs as i64)
+    }
+
+    pub fn from_unix(secs: i64) -> Timestamp {
+        let days = secs.div_euclid(86_400);
+        let rem = secs.rem_euclid(86_400);
+        let (year, month, day) = civil_from_days(days);
+        Timestamp {
+            year,
+            month,
+            day,
+            hour: (rem / 3600) as u32,
+            minute: (rem % 3600 / 60) as u32,
+            second: (rem % 60) as u32,
+        }
+    }
+
+    /// 距 1970-01-01 00:00:00 UTC 的秒数
+    pub fn to_unix(&self) -> i64 {
+        days_from_civil(self.year, self.month, self.day) * 86_400
+            + i64::from(self.hour * 3600 + self.minute * 60 + self.second)
+    }
+
+    /// RFC 3339 格式：`2024-05-01T13:45:00Z`
+    pub fn rfc3339(&self) -> String {
+        format!(
+            "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+            self.year, self.month, self.day, self.hour, self.minute, self.second
+        )
+    }
+
+    /// 适合放进文件名的格式：`2024-05-01_13-45-00`
+    pub fn file_stamp(&self) -> String {
+        format!(
+            "{:04}-{:02}-{:02}_{:02}-{:02}-{:02}",
+            self.year, self.month, self.day, self.hour, self.minute, self.second
+        )
+    }
+}
+
+impl fmt::Display for Timestamp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:04}-{:02}-{:02} {:02}:{:02}:{:02}",
+            self.year, self.month, self.day, self.hour, self.minute, self.second
+        )
+    }
+}
+
+/// 解析 [`Timestamp::rfc3339`] 的输出（只接受 UTC 的 `Z` 后缀）
+impl FromStr for Timestamp {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Timestamp, String> {
+        let invalid = || format!("invalid timestamp '{}', expected YYYY-MM-DDTHH:MM:SSZ", s);
+        let (date, time) = s
+            .strip_suffix('Z')
+            .and_then(|s| s.split_once('T'))
+            .ok_or_else(invalid)?;
+        let fields = |text: &str, sep| -> Option<Vec<i64>> {
+            text.split(sep).map(|n| n.parse().ok()).collect()
+        };
+        let (Some([year, month, day]), Some([hour, minute, second])) = (
+            fields(date, '-').and_then(|v| <[i64; 3]>::try_from(v).ok()),
+            fields(time, ':').and_then(|v| <[i64; 3]>::try_from(v).ok()),
+        ) else {
+            return Err(invalid());
+        };
+        if !(1..=12).contains(&month)
+            || !(1..=31).contains(&day)
+            || !(0..24).contains(&hour)
+            || !(0..60).contains(&minute)
+            || !(0..60).contains(&second)
+        {
+            return Err(invalid());
+        }
+        Ok(Timestamp {
+            year,
+            month: month as u32,
+            day: day as u32,
+            hour: hour as u32,
+            minute: minute as u32,
+            second: second as u32,
+        })
+    }
+}
+
+impl Serialize for Timestamp {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.rfc3339())
+    }
+}
+
+impl<'de> Deserialize<'de> for Timestamp {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Timestamp, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+/// 距 1970-01-01 的天数 → (年, 月, 日)
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+/// (年, 月, 日) → 距 1970-01-01 的天数，`civil_from_days` 的逆运算
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = year - i64::from(month <= 2);
+    let era = year.div_euclid(400);
+    let yoe = year.rem_euclid(400);
+    let mp = i64::from((month + 9) % 12);
+    let doy = (153 * mp + 2) / 5 + i64::from(day) - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_epoch() {
+        assert_eq!(Timestamp::from_unix(0).to_string(), "1970-01-01 00:00:00");
+    }
+
+    #[test]
+    fn test_known_dates() {
+        // 2000-02-29 12:34:56 UTC（闰日）
+        let ts = Timestamp::from_unix(951_827_696);
+        assert_eq!(ts.to_string(), "2000-02-29 12:34:56");
+        assert_eq!(ts.file_stamp(), "2000-02-29_12-34-56");
+        // 2024-12-31 23:59:59 UTC
+        assert_eq!(
+            Timestamp::from_unix(1_735_689_599).to_string(),
+            "2024-12-31 23:59:59"
+        );
+    }
+
+    #[test]
+    fn test_rfc3339_round_trip() {
+        let ts = Timestamp::from_unix(951_827_696);
+        assert_eq!(ts.rfc3339(), "2000-02-29T12:34:56Z");
+        assert_eq!("2000-02-29T12:34:56Z".parse(), Ok(ts));
+        assert_eq!(ts.to_unix(), 951_827_696);
+        assert_eq!(Timestamp::from_unix(-1).to_unix(), -1);
+        for bad in [
+            "2000-02-29 12:34:56",
+            "2000-13-01T00:00:00Z",
+            "2000-02-29T12:34Z",
+            "x",
+        ] {
+            assert!(bad.parse::<Timestamp>().is_err(), "{}", bad);
+        }
+    }
+}
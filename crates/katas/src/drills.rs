@@ -0,0 +1,12 @@
+//! 各 kata 的练习文件：函数体是等待补全的 `todo!()`
+//!
+//! 测试写在同名的 kata 模块中，用 `cargo run -- kata <name>` 运行。
+
+// 补全之前，练习函数的参数都还没有用到
+#![allow(unused_variables)]
+
+pub mod fizzbuzz;
+pub mod parse_csv_line;
+pub mod reverse_words;
+pub mod run_length;
+pub mod word_frequency;
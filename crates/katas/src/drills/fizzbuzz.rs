@@ -0,0 +1,8 @@
+//! # Kata：FizzBuzz with Iterators
+//!
+//! 把 `todo!()` 换成实现，然后运行 `cargo run -- kata fizzbuzz` 检查。
+
+/// 1 到 `n` 的 FizzBuzz
+pub fn fizzbuzz(n: u32) -> Vec<String> {
+    todo!()
+}
@@ -0,0 +1,8 @@
+//! # Kata：Parse a CSV Line
+//!
+//! 把 `todo!()` 换成实现，然后运行 `cargo run -- kata parse_csv_line` 检查。
+
+/// 拆分一行 CSV；引号没有闭合时返回 `None`
+pub fn parse_csv_line(line: &str) -> Option<Vec<String>> {
+    todo!()
+}
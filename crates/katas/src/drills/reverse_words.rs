@@ -0,0 +1,8 @@
+//! # Kata：Reverse Words
+//!
+//! 把 `todo!()` 换成实现，然后运行 `cargo run -- kata reverse_words` 检查。
+
+/// 单词倒序排列，以单个空格连接；任何空白都算分隔符
+pub fn reverse_words(s: &str) -> String {
+    todo!()
+}
@@ -0,0 +1,13 @@
+//! # Kata：Run-Length Encoding
+//!
+//! 把 `todo!()` 换成实现，然后运行 `cargo run -- kata run_length` 检查。
+
+/// 编码：连续 `n` 个（`n > 1`）相同字符写成 `n` 加这个字符
+pub fn encode(s: &str) -> String {
+    todo!()
+}
+
+/// 解码：把 `encode` 的结果还原
+pub fn decode(s: &str) -> String {
+    todo!()
+}
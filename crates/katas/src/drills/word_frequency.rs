@@ -0,0 +1,8 @@
+//! # Kata：Word Frequency
+//!
+//! 把 `todo!()` 换成实现，然后运行 `cargo run -- kata word_frequency` 检查。
+
+/// 出现最多的 `n` 个单词及其次数，次数相同时按字母顺序
+pub fn top_words(text: &str, n: usize) -> Vec<(String, usize)> {
+    todo!()
+}
@@ -0,0 +1,41 @@
+//! # Kata：FizzBuzz with Iterators
+
+use crate::drills::fizzbuzz as drill;
+use crate::lesson::{Check, Difficulty};
+use rust_learn_core::kata::Kata;
+
+pub const KATA: Kata = Kata::new(
+    "fizzbuzz",
+    "FizzBuzz with Iterators",
+    Difficulty::Beginner,
+    "返回 1 到 n 的 FizzBuzz：3 的倍数写 `Fizz`，5 的倍数写 `Buzz`，两者的公倍数写 `FizzBuzz`，\
+     其余写数字本身。试着只用迭代器适配器（`map`、`collect`），不写 `for` 循环和 `push`。",
+    &[
+        Check::new("first_fifteen", check_first_fifteen),
+        Check::new("zero", check_zero),
+        Check::new("counts", check_counts),
+    ],
+);
+
+fn check_first_fifteen() {
+    assert_eq!(
+        drill::fizzbuzz(15),
+        [
+            "1", "2", "Fizz", "4", "Buzz", "Fizz", "7", "8", "Fizz", "Buzz", "11", "Fizz", "13",
+            "14", "FizzBuzz"
+        ]
+    );
+}
+
+fn check_zero() {
+    assert!(drill::fizzbuzz(0).is_empty());
+}
+
+fn check_counts() {
+    let words = drill::fizzbuzz(100);
+    assert_eq!(words.len(), 100);
+    assert_eq!(words.iter().filter(|w| *w == "Fizz").count(), 27);
+    assert_eq!(words.iter().filter(|w| *w == "Buzz").count(), 14);
+    assert_eq!(words.iter().filter(|w| *w == "FizzBuzz").count(), 6);
+    assert_eq!(words[97], "98");
+}
@@ -0,0 +1,45 @@
+//! 型（kata）：独立于 lesson 的小型 TDD 练习，用 `kata <name>` 运行
+//!
+//! 每个模块对应一个 kata，提供 `KATA`（题目与测试套件）；学习者要补全的函数在 `src/drills/` 中，
+//! 与模块同名。[`ALL`] 按难度排列，新增 kata 时在这里登记。
+
+use rust_learn_core::kata::Kata;
+use rust_learn_core::lesson;
+
+// 待补全的练习文件；启用 `solutions` feature 时换成仓库根目录 `solutions/katas/` 中的参考答案
+#[cfg_attr(feature = "solutions", path = "../../../solutions/katas/mod.rs")]
+mod drills;
+
+pub mod fizzbuzz;
+pub mod parse_csv_line;
+pub mod reverse_words;
+pub mod run_length;
+pub mod word_frequency;
+
+/// 全部 kata，由易到难
+pub const ALL: &[Kata] = &[
+    reverse_words::KATA,
+    fizzbuzz::KATA,
+    run_length::KATA,
+    word_frequency::KATA,
+    parse_csv_line::KATA,
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeSet;
+
+    #[test]
+    fn test_registry() {
+        let names: BTreeSet<_> = ALL.iter().map(|k| k.name).collect();
+        assert_eq!(names.len(), ALL.len(), "duplicate kata names");
+        assert!(ALL.windows(2).all(|w| w[0].difficulty <= w[1].difficulty));
+        for kata in ALL {
+            assert!(!kata.tests.is_empty(), "{} has no tests", kata.name);
+            assert!(!kata.task.is_empty(), "{} has no task", kata.name);
+            let file = format!("{}/src/drills/{}.rs", env!("CARGO_MANIFEST_DIR"), kata.name);
+            assert!(std::path::Path::new(&file).exists(), "missing {}", file);
+        }
+    }
+}
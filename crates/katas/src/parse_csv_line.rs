@@ -0,0 +1,48 @@
+//! # Kata：Parse a CSV Line
+
+use crate::drills::parse_csv_line as drill;
+use crate::lesson::{Check, Difficulty};
+use rust_learn_core::kata::Kata;
+
+pub const KATA: Kata = Kata::new(
+    "parse_csv_line",
+    "Parse a CSV Line",
+    Difficulty::Advanced,
+    "把一行 CSV 拆成字段：以逗号分隔，字段可以用双引号括起来，引号中的逗号不算分隔符，\
+     `\"\"` 表示一个双引号；引号没有闭合时返回 `None`。不用考虑换行。",
+    &[
+        Check::new("plain", check_plain),
+        Check::new("quoted", check_quoted),
+        Check::new("escaped_quotes", check_escaped_quotes),
+        Check::new("unterminated", check_unterminated),
+    ],
+);
+
+fn fields(list: &[&str]) -> Option<Vec<String>> {
+    Some(list.iter().map(|s| s.to_string()).collect())
+}
+
+fn check_plain() {
+    assert_eq!(drill::parse_csv_line("a,b,c"), fields(&["a", "b", "c"]));
+    assert_eq!(drill::parse_csv_line("a,,c,"), fields(&["a", "", "c", ""]));
+    assert_eq!(drill::parse_csv_line(""), fields(&[""]));
+}
+
+fn check_quoted() {
+    assert_eq!(
+        drill::parse_csv_line("1,\"Doe, Jane\",42"),
+        fields(&["1", "Doe, Jane", "42"])
+    );
+    assert_eq!(drill::parse_csv_line("\"\",x"), fields(&["", "x"]));
+}
+
+fn check_escaped_quotes() {
+    assert_eq!(
+        drill::parse_csv_line("\"say \"\"hi\"\"\",ok"),
+        fields(&["say \"hi\"", "ok"])
+    );
+}
+
+fn check_unterminated() {
+    assert_eq!(drill::parse_csv_line("a,\"b,c"), None);
+}
@@ -0,0 +1,41 @@
+//! # Kata：Reverse Words
+
+use crate::drills::reverse_words as drill;
+use crate::lesson::{Check, Difficulty};
+use rust_learn_core::kata::Kata;
+
+pub const KATA: Kata = Kata::new(
+    "reverse_words",
+    "Reverse Words",
+    Difficulty::Beginner,
+    "把句子中单词的顺序倒过来，单词之间只留一个空格，首尾不留空白：\
+     `reverse_words(\"  hello   rust world \")` 返回 `\"world rust hello\"`。",
+    &[
+        Check::new("two_words", check_two_words),
+        Check::new("extra_spaces", check_extra_spaces),
+        Check::new("empty", check_empty),
+        Check::new("unicode", check_unicode),
+    ],
+);
+
+fn check_two_words() {
+    assert_eq!(drill::reverse_words("hello world"), "world hello");
+    assert_eq!(drill::reverse_words("single"), "single");
+}
+
+fn check_extra_spaces() {
+    assert_eq!(
+        drill::reverse_words("  hello   rust world "),
+        "world rust hello"
+    );
+    assert_eq!(drill::reverse_words("a\tb\nc"), "c b a");
+}
+
+fn check_empty() {
+    assert_eq!(drill::reverse_words(""), "");
+    assert_eq!(drill::reverse_words("   "), "");
+}
+
+fn check_unicode() {
+    assert_eq!(drill::reverse_words("你好 世界"), "世界 你好");
+}
@@ -0,0 +1,37 @@
+//! # Kata：Run-Length Encoding
+
+use crate::drills::run_length as drill;
+use crate::lesson::{Check, Difficulty};
+use rust_learn_core::kata::Kata;
+
+pub const KATA: Kata = Kata::new(
+    "run_length",
+    "Run-Length Encoding",
+    Difficulty::Intermediate,
+    "游程编码：连续重复的字符写成“次数 + 字符”，只出现一次的不写次数，\
+     `encode(\"aaabcc\")` 得到 `\"3ab2c\"`；`decode` 把它还原。输入不含数字。",
+    &[
+        Check::new("encode", check_encode),
+        Check::new("decode", check_decode),
+        Check::new("round_trip", check_round_trip),
+    ],
+);
+
+fn check_encode() {
+    assert_eq!(drill::encode("aaabcc"), "3ab2c");
+    assert_eq!(drill::encode("abc"), "abc");
+    assert_eq!(drill::encode(""), "");
+    assert_eq!(drill::encode("zzzzzzzzzzzz"), "12z");
+}
+
+fn check_decode() {
+    assert_eq!(drill::decode("3ab2c"), "aaabcc");
+    assert_eq!(drill::decode("12z"), "zzzzzzzzzzzz");
+    assert_eq!(drill::decode(""), "");
+}
+
+fn check_round_trip() {
+    for s in ["  hello  world ", "mississippi", "ééé中中"] {
+        assert_eq!(drill::decode(&drill::encode(s)), s);
+    }
+}
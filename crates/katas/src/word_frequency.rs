@@ -0,0 +1,44 @@
+//! # Kata：Word Frequency
+
+use crate::drills::word_frequency as drill;
+use crate::lesson::{Check, Difficulty};
+use rust_learn_core::kata::Kata;
+
+pub const KATA: Kata = Kata::new(
+    "word_frequency",
+    "Word Frequency",
+    Difficulty::Intermediate,
+    "统计文本中出现最多的 `n` 个单词：不区分大小写（统一转成小写），单词只由字母、数字和 `'` 组成，\
+     其余字符都是分隔符；按次数从多到少排，次数相同时按字母顺序。",
+    &[
+        Check::new("counts", check_counts),
+        Check::new("case_and_punctuation", check_case_and_punctuation),
+        Check::new("ties", check_ties),
+    ],
+);
+
+fn pairs(list: &[(&str, usize)]) -> Vec<(String, usize)> {
+    list.iter().map(|&(w, n)| (w.to_string(), n)).collect()
+}
+
+fn check_counts() {
+    assert_eq!(
+        drill::top_words("one fish two fish red fish blue fish", 2),
+        pairs(&[("fish", 4), ("blue", 1)])
+    );
+    assert!(drill::top_words("", 3).is_empty());
+}
+
+fn check_case_and_punctuation() {
+    assert_eq!(
+        drill::top_words("Go, go, GO! Don't stop.", 3),
+        pairs(&[("go", 3), ("don't", 1), ("stop", 1)])
+    );
+}
+
+fn check_ties() {
+    assert_eq!(
+        drill::top_words("b a c b a c", 10),
+        pairs(&[("a", 2), ("b", 2), ("c", 2)])
+    );
+}
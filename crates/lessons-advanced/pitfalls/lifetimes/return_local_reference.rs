@@ -0,0 +1,8 @@
+fn joined<'a>(x: &'a str, y: &'a str) -> &'a str {
+    let result = format!("{}{}", x, y);
+    result.as_str()
+}
+
+fn main() {
+    println!("{}", joined("foo", "bar"));
+}
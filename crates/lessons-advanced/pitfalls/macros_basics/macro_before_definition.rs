@@ -0,0 +1,9 @@
+fn main() {
+    greet!("world");
+}
+
+macro_rules! greet {
+    ($name:expr) => {
+        println!("Hello, {}!", $name)
+    };
+}
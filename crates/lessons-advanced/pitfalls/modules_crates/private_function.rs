@@ -0,0 +1,11 @@
+mod garden {
+    pub mod vegetables {
+        fn harvest() -> &'static str {
+            "asparagus"
+        }
+    }
+}
+
+fn main() {
+    println!("{}", garden::vegetables::harvest());
+}
@@ -0,0 +1,10 @@
+//! 各 lesson 的练习：每个模块对应一个 lesson，函数体是等待补全的 `todo!()`
+//!
+//! 检查写在 lesson 模块的 `EXERCISE` 中，用 `cargo run -- exercise <lesson>` 运行。
+
+// 补全之前，练习函数的参数都还没有用到
+#![allow(unused_variables, clippy::new_without_default)]
+
+pub mod lifetimes;
+pub mod macros_basics;
+pub mod modules_crates;
@@ -0,0 +1,25 @@
+//! # 练习：Lifetimes Basics
+//!
+//! 把每个 `todo!()` 换成实现，然后运行 `cargo run -- exercise lifetimes` 检查。
+
+/// 较长的字符串（一样长时返回 `a`）
+pub fn longest<'a>(a: &'a str, b: &'a str) -> &'a str {
+    todo!()
+}
+
+/// 第一句话：到第一个 `.` 为止（包含句点）；没有句点时返回整段文本
+pub fn first_sentence(text: &str) -> &str {
+    todo!()
+}
+
+/// 借用一段文本的摘录
+pub struct Excerpt<'a> {
+    pub text: &'a str,
+}
+
+impl<'a> Excerpt<'a> {
+    /// 按空白拆出的单词；返回的切片借用原文本，摘录本身释放后仍然可用
+    pub fn words(&self) -> Vec<&'a str> {
+        todo!()
+    }
+}
@@ -0,0 +1,29 @@
+//! # 练习：Macros Basics
+//!
+//! 把每个 `todo!()` 换成实现，然后运行 `cargo run -- exercise macros_basics` 检查。
+
+/// 求平方：`square!(3)` 得到 9，参数只求值一次
+macro_rules! square {
+    ($x:expr) => {
+        todo!()
+    };
+}
+
+/// 任意多个数中的最大值：`max_of!(1)`、`max_of!(3, 7, 2)`
+macro_rules! max_of {
+    ($x:expr) => {
+        todo!()
+    };
+    ($x:expr, $($rest:expr),+) => {
+        todo!()
+    };
+}
+
+/// 由字符串字面量构造 `Vec<String>`：`strings!["a", "b"]`
+macro_rules! strings {
+    ($($s:expr),* $(,)?) => {
+        todo!()
+    };
+}
+
+pub(crate) use {max_of, square, strings};
@@ -0,0 +1,29 @@
+//! # 练习：Modules / Crates / Paths
+//!
+//! 把每个 `todo!()` 换成实现，然后运行 `cargo run -- exercise modules_crates` 检查。
+
+/// 几何计算
+pub mod geometry {
+    /// 矩形的周长
+    pub fn perimeter(width: u32, height: u32) -> u32 {
+        todo!()
+    }
+
+    /// 正方形的周长：调用同一模块中的 `perimeter`
+    pub fn square_perimeter(side: u32) -> u32 {
+        todo!()
+    }
+}
+
+/// 文本处理
+pub mod text {
+    /// 首字母大写，例如 `"rust"` → `"Rust"`
+    pub fn capitalize(word: &str) -> String {
+        todo!()
+    }
+
+    /// 通过 `super::geometry` 计算周长，例如 `describe_rect(2, 3)` 返回 `"2x3 rectangle, perimeter 10"`
+    pub fn describe_rect(width: u32, height: u32) -> String {
+        todo!()
+    }
+}
@@ -0,0 +1,22 @@
+//! 进阶部分的 lesson：`curriculum.toml` 中 `feature = "advanced"` 的 lesson，
+//! 以及需要额外依赖、由本 crate 的 `net` / `async` feature 控制编译的 lesson
+//!
+//! 每个模块对应一个 lesson，提供 `run`、`SECTIONS` 与测验题 `QUIZ`；编号、标题等元数据写在大纲中，
+//! 由运行器在构建时注册。
+
+// 示例代码为了演示语法会定义一些没有用到的类型、函数与导入
+#![allow(unused_imports, unused_macros, dead_code, non_local_definitions)]
+
+// lesson 代码通过 `crate::lesson`、`crate::utils` 使用公共接口
+use rust_learn_core::{lesson, utils};
+
+// 各 lesson 的练习；启用 `solutions` feature 时换成仓库根目录 `solutions/` 中的参考答案
+#[cfg_attr(
+    feature = "solutions",
+    path = "../../../solutions/lessons-advanced/mod.rs"
+)]
+mod exercises;
+
+pub mod lifetimes;
+pub mod macros_basics;
+pub mod modules_crates;
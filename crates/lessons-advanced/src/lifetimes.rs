@@ -0,0 +1,344 @@
+//! # Lifetimes Basics
+//!
+//! 目标：理解 Rust 中的生命周期（lifetimes）
+//!
+//! ## 要点
+//! - 生命周期是 Rust  borrow checker 的核心机制
+//! - 防止悬空引用：确保引用始终有效
+//! - 每个引用都有生命周期（隐式或显式）
+//! - 函数签名中的生命周期参数：标注输入输出引用的关系
+//! - 生命周期省略规则（lifetime elision）简化代码
+//! - `'static` 生命周期：整个程序的生命周期
+//!
+//! ```rust
+//! // 返回值与两个参数中活得较短的一个一样长
+//! fn longest<'a>(x: &'a str, y: &'a str) -> &'a str {
+//!     if x.len() > y.len() { x } else { y }
+//! }
+//!
+//! // 省略规则：只有一个输入引用时，输出自动借用它，不用标注
+//! fn first_word(s: &str) -> &str {
+//!     s.split(' ').next().unwrap_or(s)
+//! }
+//!
+//! let owned = String::from("borrow checker");
+//! assert_eq!(longest(&owned, "rust"), "borrow checker");
+//! assert_eq!(first_word(&owned), "borrow");
+//!
+//! // 字符串字面值存放在程序的只读数据中，一直有效
+//! let forever: &'static str = "hello";
+//! assert_eq!(forever.len(), 5);
+//! ```
+//!
+//! ## 常见坑
+//! - 返回值引用可能与多个输入引用相关，导致歧义
+//! - 不理解生命周期省略规则
+//! - 试图返回局部变量的引用
+//!
+//! ## 运行
+//! `cargo run -- 14_lifetimes`
+//!
+//! ## 示例
+//!
+//! 由 `cargo run -- update-doctests` 从演示代码中标出的片段生成，`cargo test --doc` 会运行它们。
+//!
+//! `static_lifetime`：
+//!
+//! ```rust
+//! let s: &'static str = "I have a static lifetime.";
+//! println!("'static string: {}", s);
+//!
+//! let num: &'static i32 = &42;
+//! println!("'static number: {}", num);
+//!
+//! // 字符串字面值默认是 'static
+//! let s2 = "This is also 'static";
+//! println!("String literal: {}", s2);
+//! ```
+
+use crate::exercises::lifetimes as exercise;
+use crate::lesson::{self, Check, Koan, LessonContext, Pitfall, Question, Section};
+use std::fmt;
+use std::io::{self, Write};
+
+pub const SECTIONS: &[Section] = &[
+    Section::new("lifetime_basics", "生命周期基础", demo_lifetime_basics),
+    Section::new(
+        "function_lifetimes",
+        "函数中的生命周期",
+        demo_function_lifetimes,
+    ),
+    Section::new(
+        "struct_lifetimes",
+        "结构体中的生命周期",
+        demo_struct_lifetimes,
+    ),
+    Section::new("static_lifetime", "静态生命周期", demo_static_lifetime),
+];
+
+pub const QUIZ: &[Question] = &[
+    Question::new(
+        "`fn longest(x: &str, y: &str) -> &str` 为什么需要标注生命周期？",
+        &[
+            "返回值可能来自 x 或 y，编译器无法推断它借用自谁",
+            "&str 必须总是标注",
+            "因为参数有两个字符串",
+            "不需要，能通过编译",
+        ],
+        0,
+        "省略规则无法确定返回引用与哪个输入相关，需要写成 `fn longest<'a>(x: &'a str, y: &'a str) -> &'a str`。",
+    ),
+    Question::new(
+        "`struct Excerpt<'a> { part: &'a str }` 表示？",
+        &[
+            "Excerpt 实例不能比 part 引用的数据活得更久",
+            "part 是 'static 的",
+            "Excerpt 拥有 part",
+            "Excerpt 只能存在于函数内",
+        ],
+        0,
+        "结构体持有引用时必须标注生命周期，保证引用在实例存续期间有效。",
+    ),
+    Question::new(
+        "关于 `'static`，哪项说法正确？",
+        &[
+            "所有引用默认都是 'static",
+            "字符串字面值的类型是 `&'static str`",
+            "'static 的值一定在堆上",
+            "'static 表示值不能被读取",
+        ],
+        1,
+        "字面值存放在程序的只读数据中，整个运行期间都有效。",
+    ),
+    Question::new(
+        "`fn first_word(s: &str) -> &str` 为什么不用标注生命周期？",
+        &[
+            "生命周期省略规则：只有一个引用参数时，返回值沿用它的生命周期",
+            "返回 `&str` 的函数总是 `'static`",
+            "编译器会在运行时检查引用",
+            "`&str` 不是引用",
+        ],
+        0,
+        "省略规则：只有一个输入生命周期时，它被赋给所有输出生命周期；有多个引用参数时才需要手动标注。",
+    ),
+];
+
+pub const EXERCISE: &[Check] = &[
+    Check::new("longest", check_longest),
+    Check::new("first_sentence", check_first_sentence),
+    Check::new("Excerpt::words", check_excerpt_words),
+];
+
+pub const HINTS: &[&str] = &[
+    "生命周期标注描述返回的引用来自哪个参数；返回值借用参数时，不能返回新创建的字符串。",
+    "`longest` 比较两个参数的 `len()` 后返回其一；`first_sentence` 用 `find('.')` 找到句点并包含它；`words` 中的切片来自 `self.text`，所以带有 `'a`。",
+    "`match text.find('.') { Some(i) => &text[..=i], None => text }`；`self.text.split_whitespace().collect()`。",
+];
+
+pub const KOANS: &[Koan] = &[
+    Koan::new(
+        "longest",
+        r#"fn longest<'a>(x: &'a str, y: &'a str) -> __ {
+    if x.len() > y.len() {
+        x
+    } else {
+        y
+    }
+}
+
+#[test]
+fn longest_string() {
+    let a = String::from("long string is long");
+    assert_eq!(longest(a.as_str(), "xyz"), "long string is long");
+}
+"#,
+        &["&'a str"],
+    ),
+    Koan::new(
+        "struct_lifetimes",
+        r#"struct Excerpt<__> {
+    part: &'a str,
+}
+
+#[test]
+fn struct_lifetimes() {
+    let novel = String::from("Call me Ishmael. Some years ago...");
+    let first = novel.split('.').next().unwrap();
+    let excerpt = Excerpt { part: first };
+    assert_eq!(excerpt.part, __);
+}
+"#,
+        &["'a", "\"Call me Ishmael\""],
+    ),
+];
+
+pub const PITFALLS: &[Pitfall] = &[
+    Pitfall::new(
+        "return_local_reference",
+        include_str!("../pitfalls/lifetimes/return_local_reference.rs"),
+        "`result` 是函数内的局部变量，函数返回时被 drop，它的引用不能带出函数（E0515）。标注生命周期也无济于事：生命周期只描述引用之间的关系，不能延长值的寿命。返回 `String`。",
+    ).error("E0515"),
+    Pitfall::new(
+        "borrow_outlives_owner",
+        include_str!("../pitfalls/lifetimes/borrow_outlives_owner.rs"),
+        "`longest` 的签名要求返回值与两个参数活得一样久，因此 `result` 的寿命受 `inner` 限制；`inner` 在内层块结束时就被 drop，之后使用 `result` 报 E0597。把 `inner` 移到外层，或者在块内用完结果。",
+    ).error("E0597"),
+];
+
+pub fn run(ctx: &mut LessonContext) -> lesson::Result {
+    lesson::run_sections(ctx, SECTIONS)
+}
+
+fn demo_lifetime_basics(ctx: &mut LessonContext) -> lesson::Result {
+    let s1 = String::from("hello");
+    {
+        let s2 = String::from("world");
+        let result = longest(&s1, &s2);
+        writeln!(ctx, "The longest string is '{}'", result)?;
+    }
+    // s2 在这里被 drop，但 result 只在内部作用域有效
+
+    let s3 = String::from("rust");
+    let result = longest(&s1, &s3);
+    writeln!(ctx, "The longest string is '{}'", result)?;
+    Ok(())
+}
+
+fn longest<'a>(x: &'a str, y: &'a str) -> &'a str {
+    if x.len() > y.len() { x } else { y }
+}
+
+fn demo_function_lifetimes(ctx: &mut LessonContext) -> lesson::Result {
+    let string1 = String::from("long string is long");
+    let string2 = String::from("xyz");
+
+    let result = longest(&string1, &string2);
+    writeln!(ctx, "The longest string is '{}'", result)?;
+
+    let result2 = first_word(&string1);
+    writeln!(ctx, "The first word is: {}", result2)?;
+    Ok(())
+}
+
+#[allow(clippy::needless_lifetimes, clippy::redundant_slicing)]
+fn first_word<'a>(s: &'a str) -> &'a str {
+    let bytes = s.as_bytes();
+    for (i, &item) in bytes.iter().enumerate() {
+        if item == b' ' {
+            return &s[0..i];
+        }
+    }
+    &s[..]
+}
+
+fn demo_struct_lifetimes(ctx: &mut LessonContext) -> lesson::Result {
+    let novel = String::from("Call me Ishmael. Some years ago...");
+    let first_sentence = novel.split('.').next().expect("Could not find a '.'");
+    let i = ImportantExcerpt {
+        part: first_sentence,
+    };
+    writeln!(ctx, "Important excerpt: {}", i.part)?;
+    writeln!(ctx, "Level: {}", i.level())?;
+    Ok(())
+}
+
+struct ImportantExcerpt<'a> {
+    part: &'a str,
+}
+
+impl<'a> ImportantExcerpt<'a> {
+    fn level(&self) -> i32 {
+        3
+    }
+
+    fn announce_and_return_part(
+        &self,
+        ctx: &mut LessonContext,
+        announcement: &str,
+    ) -> io::Result<&str> {
+        writeln!(ctx, "Attention please: {}", announcement)?;
+        Ok(self.part)
+    }
+}
+
+fn demo_static_lifetime(ctx: &mut LessonContext) -> lesson::Result {
+    // ANCHOR: static_lifetime
+    let s: &'static str = "I have a static lifetime.";
+    writeln!(ctx, "'static string: {}", s)?;
+
+    let num: &'static i32 = &42;
+    writeln!(ctx, "'static number: {}", num)?;
+
+    // 字符串字面值默认是 'static
+    let s2 = "This is also 'static";
+    writeln!(ctx, "String literal: {}", s2)?;
+    // ANCHOR_END: static_lifetime
+    Ok(())
+}
+
+fn longest_with_an_announcement<'a, T>(
+    ctx: &mut LessonContext,
+    x: &'a str,
+    y: &'a str,
+    ann: T,
+) -> io::Result<&'a str>
+where
+    T: fmt::Display,
+{
+    writeln!(ctx, "Announcement! {}", ann)?;
+    if x.len() > y.len() { Ok(x) } else { Ok(y) }
+}
+
+fn check_longest() {
+    assert_eq!(exercise::longest("long", "longer"), "longer");
+    assert_eq!(exercise::longest("abc", "xyz"), "abc");
+}
+
+fn check_first_sentence() {
+    assert_eq!(
+        exercise::first_sentence("Call me Ishmael. Some years ago"),
+        "Call me Ishmael."
+    );
+    assert_eq!(exercise::first_sentence("no period"), "no period");
+}
+
+fn check_excerpt_words() {
+    let text = String::from("call me  Ishmael");
+    let words = {
+        let excerpt = exercise::Excerpt { text: &text };
+        excerpt.words()
+    };
+    assert_eq!(words, ["call", "me", "Ishmael"]);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_longest() {
+        let s1 = String::from("hello");
+        let s2 = String::from("world!");
+        assert_eq!(longest(&s1, &s2), "world!");
+    }
+
+    #[test]
+    fn test_first_word() {
+        let s = String::from("hello world");
+        assert_eq!(first_word(&s), "hello");
+    }
+
+    #[test]
+    fn test_important_excerpt() {
+        let s = String::from("Important text here.");
+        let excerpt = ImportantExcerpt { part: &s[0..9] };
+        assert_eq!(excerpt.level(), 3);
+        assert_eq!(excerpt.part, "Important");
+    }
+
+    #[test]
+    fn test_static_lifetime() {
+        let s = "Static string";
+        assert_eq!(s.len(), 13);
+    }
+}
@@ -0,0 +1,480 @@
+//! # Macros Basics
+//!
+//! 目标：理解 Rust 中宏的基本概念和使用
+//!
+//! ## 要点
+//! - 宏（Macro）：代码生成的元编程工具
+//! - 声明式宏：`macro_rules!`
+//! - 过程宏：自定义派生、属性宏、函数宏
+//! - 宏在编译时展开，有 hygiene 特性
+//! - 标准库常用宏：`println!`、`vec!`、`assert!` 等
+//!
+//! ```rust
+//! // macro_rules! 按模式匹配参数，编译时展开成代码
+//! macro_rules! square {
+//!     ($x:expr) => {
+//!         $x * $x
+//!     };
+//! }
+//! // $x 作为一个完整的表达式代入，不是文本替换，所以是 3 * 3
+//! assert_eq!(square!(2 + 1), 9);
+//!
+//! // 卫生性：宏里定义的变量不会与调用处的同名变量冲突
+//! macro_rules! shadow_x {
+//!     () => {
+//!         let x = 100;
+//!         let _ = x;
+//!     };
+//! }
+//! let x = 1;
+//! shadow_x!();
+//! assert_eq!(x, 1);
+//!
+//! let v = vec![1, 2, 3];
+//! assert_eq!(v.len(), 3);
+//! ```
+//!
+//! ## 常见坑
+//! - 宏调试困难，错误信息不友好
+//! - 宏可能导致代码膨胀
+//! - 宏的 hygiene 规则复杂
+//!
+//! ## 运行
+//! `cargo run -- 19_macros_basics`
+//!
+//! ## 示例
+//!
+//! 由 `cargo run -- update-doctests` 从演示代码中标出的片段生成，`cargo test --doc` 会运行它们。
+//!
+//! `find_min`：
+//!
+//! ```rust
+//! macro_rules! find_min {
+//!     ($x:expr) => {
+//!         $x
+//!     };
+//!     ($x:expr, $($y:expr),+) => {
+//!         {
+//!             let min_y = find_min!($($y),+);
+//!             if $x < min_y {
+//!                 $x
+//!             } else {
+//!                 min_y
+//!             }
+//!         }
+//!     };
+//! }
+//!
+//! let min = find_min!(10, 5, 8, 3, 15);
+//! println!("Minimum of [10, 5, 8, 3, 15] is {}", min);
+//! ```
+
+use crate::exercises::macros_basics as exercise;
+use crate::lesson::{self, Check, Koan, LessonContext, Pitfall, Question, Section};
+use std::io::{self, Write};
+
+pub const SECTIONS: &[Section] = &[
+    Section::new("builtin_macros", "内置宏", demo_builtin_macros),
+    Section::new("declarative_macros", "声明式宏", demo_declarative_macros),
+    Section::new("custom_macros", "自定义宏", demo_custom_macros),
+    Section::new(
+        "macro_pattern_matching",
+        "宏的模式匹配",
+        demo_macro_pattern_matching,
+    ),
+];
+
+pub const QUIZ: &[Question] = &[
+    Question::new(
+        "宏在什么时候展开？",
+        &["运行时", "编译时", "链接时", "第一次调用时"],
+        1,
+        "宏在编译期展开成代码，因此可以接受可变数量的参数，例如 `println!`。",
+    ),
+    Question::new(
+        "`macro_rules!` 中 `$x:expr` 的 `expr` 表示？",
+        &[
+            "x 必须是变量名",
+            "片段说明符：匹配一个表达式",
+            "x 的类型是 expr",
+            "注释",
+        ],
+        1,
+        "常见的片段说明符还有 `ident`、`ty`、`tt` 等。",
+    ),
+    Question::new(
+        "`#[derive(Debug)]` 属于哪类宏？",
+        &["声明式宏", "派生宏（过程宏的一种）", "属性宏", "不是宏"],
+        1,
+        "过程宏分为派生宏、属性宏和函数式宏，derive 为类型自动生成 trait 实现。",
+    ),
+    Question::new(
+        "宏的卫生性（hygiene）指的是？",
+        &[
+            "宏内部定义的变量不会与调用处的同名变量冲突",
+            "宏不能有副作用",
+            "宏必须有文档",
+            "宏不能递归",
+        ],
+        0,
+        "`macro_rules!` 中新引入的局部变量处在独立的语法上下文中。",
+    ),
+];
+
+pub const EXERCISE: &[Check] = &[
+    Check::new("square", check_square),
+    Check::new("max_of", check_max_of),
+    Check::new("strings", check_strings),
+];
+
+pub const HINTS: &[&str] = &[
+    "宏展开后的代码出现在调用处；`$x:expr` 每出现一次就会把表达式复制一次，`$(...),*` 表示重复。",
+    "`square!` 先 `let x = $x;` 保证只求值一次；`max_of!` 的第二个分支递归调用自己处理其余参数；`strings!` 展开为 `vec![...]`。",
+    "`max_of!` 第二个分支：`{{ let first = $x; let rest = max_of!($($rest),+); if first > rest { first } else { rest } }}`；`strings!` 写 `vec![$($s.to_string()),*]`。",
+];
+
+pub const KOANS: &[Koan] = &[
+    Koan::new(
+        "declarative_macro",
+        r#"macro_rules! square {
+    ($x:expr) => {
+        $x * $x
+    };
+}
+
+#[test]
+fn declarative_macro() {
+    assert_eq!(square!(4), __);
+    // `$x:expr` 匹配的是完整的表达式，展开后仍是一个整体
+    assert_eq!(square!(1 + 2), __);
+}
+"#,
+        &["16", "9"],
+    ),
+    Koan::new(
+        "repetition",
+        r#"macro_rules! sum {
+    ($($x:expr),*) => {
+        0 $(+ $x)*
+    };
+}
+
+#[test]
+fn repetition() {
+    assert_eq!(sum!(1, 2, 3), 6);
+    assert_eq!(sum!(), __);
+}
+"#,
+        &["0"],
+    ),
+];
+
+pub const PITFALLS: &[Pitfall] = &[Pitfall::new(
+    "macro_before_definition",
+    include_str!("../pitfalls/macros_basics/macro_before_definition.rs"),
+    "`macro_rules!` 定义的宏按源码顺序生效，必须先定义再使用，这一点与函数不同。把宏定义移到调用之前；跨模块使用时还需要 `#[macro_use]` 或 `#[macro_export]`。",
+)];
+
+pub fn run(ctx: &mut LessonContext) -> lesson::Result {
+    lesson::run_sections(ctx, SECTIONS)
+}
+
+#[allow(
+    clippy::format_in_format_args,
+    clippy::eq_op,
+    clippy::assertions_on_constants
+)]
+fn demo_builtin_macros(ctx: &mut LessonContext) -> lesson::Result {
+    writeln!(ctx, "writeln! macro")?;
+    writeln!(ctx, "Formatted: {}", format!("Hello, {}!", "Rust"))?;
+    writeln!(ctx, "Debug: {:?}", vec![1, 2, 3])?;
+
+    let v = vec![1, 2, 3, 4, 5];
+    writeln!(ctx, "vec! macro: {:?}", v)?;
+
+    assert_eq!(2 + 2, 4);
+    writeln!(ctx, "assert_eq! passed")?;
+
+    assert!(10 > 5);
+    writeln!(ctx, "assert! passed")?;
+
+    let x = 42;
+    debug_assert_eq!(x, 42);
+    writeln!(ctx, "debug_assert! passed")?;
+
+    let s = stringify!(hello world);
+    writeln!(ctx, "stringify!: {}", s)?;
+    Ok(())
+}
+
+// 声明式宏示例
+macro_rules! create_vec {
+    ($($x:expr),*) => {
+        {
+            let mut temp_vec = Vec::new();
+            $(
+                temp_vec.push($x);
+            )*
+            temp_vec
+        }
+    };
+}
+
+// ANCHOR: find_min
+macro_rules! find_min {
+    ($x:expr) => {
+        $x
+    };
+    ($x:expr, $($y:expr),+) => {
+        {
+            let min_y = find_min!($($y),+);
+            if $x < min_y {
+                $x
+            } else {
+                min_y
+            }
+        }
+    };
+}
+// ANCHOR_END: find_min
+
+macro_rules! hashmap {
+    ($( $key:expr => $val:expr ),* $(,)?) => {
+        {
+            let mut map = ::std::collections::HashMap::new();
+            $(
+                map.insert($key, $val);
+            )*
+            map
+        }
+    };
+}
+
+#[allow(clippy::vec_init_then_push)]
+fn demo_declarative_macros(ctx: &mut LessonContext) -> lesson::Result {
+    let v = create_vec![1, 2, 3, 4, 5];
+    writeln!(ctx, "Created vec: {:?}", v)?;
+
+    // ANCHOR: find_min
+    let min = find_min!(10, 5, 8, 3, 15);
+    writeln!(ctx, "Minimum of [10, 5, 8, 3, 15] is {}", min)?;
+    // ANCHOR_END: find_min
+
+    let map = hashmap! {
+        "one" => 1,
+        "two" => 2,
+        "three" => 3,
+    };
+    writeln!(ctx, "HashMap: {:?}", ctx.map(&map))?;
+    Ok(())
+}
+
+macro_rules! calculate {
+    (add $a:expr, $b:expr) => {
+        $a + $b
+    };
+    (sub $a:expr, $b:expr) => {
+        $a - $b
+    };
+    (mul $a:expr, $b:expr) => {
+        $a * $b
+    };
+    (div $a:expr, $b:expr) => {
+        $a / $b
+    };
+}
+
+macro_rules! repeat {
+    ($val:expr; $count:expr) => {{
+        let mut vec = Vec::new();
+        for _ in 0..$count {
+            vec.push($val);
+        }
+        vec
+    }};
+}
+
+macro_rules! impl_display_for_struct {
+    ($struct_name:ident) => {
+        impl ::std::fmt::Display for $struct_name {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                write!(f, "{}", stringify!($struct_name))
+            }
+        }
+    };
+}
+
+fn demo_custom_macros(ctx: &mut LessonContext) -> lesson::Result {
+    let sum = calculate!(add 5, 3);
+    writeln!(ctx, "5 + 3 = {}", sum)?;
+
+    let diff = calculate!(sub 10, 4);
+    writeln!(ctx, "10 - 4 = {}", diff)?;
+
+    let product = calculate!(mul 6, 7);
+    writeln!(ctx, "6 * 7 = {}", product)?;
+
+    let quotient = calculate!(div 20, 5);
+    writeln!(ctx, "20 / 5 = {}", quotient)?;
+
+    let zeros = repeat!(0; 5);
+    writeln!(ctx, "Zeros: {:?}", zeros)?;
+
+    let ones = repeat!(1; 3);
+    writeln!(ctx, "Ones: {:?}", ones)?;
+
+    struct MyStruct;
+    impl_display_for_struct!(MyStruct);
+    writeln!(ctx, "MyStruct: {}", MyStruct)?;
+    Ok(())
+}
+
+macro_rules! generic_vec {
+    () => {
+        {
+            Vec::new()
+        }
+    };
+    ($x:expr) => {
+        {
+            let mut vec = Vec::new();
+            vec.push($x);
+            vec
+        }
+    };
+    ($($x:expr),+ $(,)?) => {
+        {
+            let mut vec = Vec::new();
+            $(
+                vec.push($x);
+            )+
+            vec
+        }
+    };
+}
+
+macro_rules! create_struct {
+    ($name:ident { $($field:ident : $val:expr),* $(,)? }) => {
+        {
+            struct $name {
+                $(
+                    $field: ::std::string::String,
+                )*
+            }
+
+            $name {
+                $(
+                    $field: $val.to_string(),
+                )*
+            }
+        }
+    };
+}
+
+#[allow(clippy::vec_init_then_push)]
+fn demo_macro_pattern_matching(ctx: &mut LessonContext) -> lesson::Result {
+    let single = generic_vec!(42);
+    writeln!(ctx, "Single element vec: {:?}", single)?;
+
+    let multiple = generic_vec!(1, 2, 3, 4, 5);
+    writeln!(ctx, "Multiple elements vec: {:?}", multiple)?;
+
+    let empty: Vec<i32> = generic_vec!();
+    writeln!(ctx, "Empty vec: {:?}", empty)?;
+    Ok(())
+}
+
+// 使用标准库的宏示例
+fn demo_std_macros(ctx: &mut LessonContext) -> io::Result<()> {
+    let v = vec![1, 2, 3];
+    assert_eq!(v, [1, 2, 3]);
+
+    let s = format!("Formatted string: {}", 42);
+    writeln!(ctx, "{}", s)?;
+
+    let dbg_vec = vec![1, 2, 3];
+    writeln!(ctx, "Debug output: {:?}", dbg_vec)?;
+    Ok(())
+}
+
+#[macro_export]
+macro_rules! test_case {
+    ($name:ident, $expected:expr, $actual:expr) => {
+        #[test]
+        fn $name() {
+            assert_eq!($expected, $actual);
+        }
+    };
+}
+
+test_case!(test_2_plus_2, 4, 2 + 2);
+test_case!(test_3_times_3, 9, 3 * 3);
+
+// 宏还没有补全时展开为 `todo!()`，检查中其后的代码不可达、变量也用不到
+#[allow(unused, clippy::diverging_sub_expression)]
+fn check_square() {
+    use exercise::square;
+    let mut calls = 0;
+    let mut three = || {
+        calls += 1;
+        3
+    };
+    let nine: i32 = square!(three());
+    assert_eq!(nine, 9);
+    assert_eq!(calls, 1, "the argument should be evaluated once");
+}
+
+#[allow(unused, clippy::diverging_sub_expression)]
+fn check_max_of() {
+    use exercise::max_of;
+    let one: i32 = max_of!(1);
+    let seven: i32 = max_of!(3, 7, 2);
+    assert_eq!((one, seven), (1, 7));
+}
+
+#[allow(unused, clippy::diverging_sub_expression)]
+fn check_strings() {
+    use exercise::strings;
+    let words: Vec<String> = strings!["macro", "rules"];
+    assert_eq!(words, ["macro", "rules"]);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[allow(clippy::vec_init_then_push)]
+    fn test_create_vec_macro() {
+        let v = create_vec![1, 2, 3];
+        assert_eq!(v, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_find_min_macro() {
+        let min = find_min!(10, 5, 8, 3, 15);
+        assert_eq!(min, 3);
+    }
+
+    #[test]
+    fn test_calculate_macro() {
+        assert_eq!(calculate!(add 2, 3), 5);
+        assert_eq!(calculate!(mul 4, 5), 20);
+    }
+
+    #[test]
+    fn test_repeat_macro() {
+        let v = repeat!(42; 3);
+        assert_eq!(v, vec![42, 42, 42]);
+    }
+
+    #[test]
+    fn test_hashmap_macro() {
+        let map = hashmap! {
+            "a" => 1,
+            "b" => 2,
+        };
+        assert_eq!(map.get("a"), Some(&1));
+        assert_eq!(map.get("b"), Some(&2));
+    }
+}
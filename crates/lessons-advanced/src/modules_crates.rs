@@ -0,0 +1,545 @@
+//! # Modules / Crates / Paths
+//!
+//! 目标：理解 Rust 的模块系统和包管理
+//!
+//! ## 要点
+//! - 模块（module）：组织代码、控制可见性
+//! - 包（package）：包含一个或多个 crate
+//! - Crate：库或可执行文件
+//! - 路径（path）：引用模块项的方式
+//! - `pub` 关键字控制可见性
+//! - `use` 关键字引入路径
+//! - `mod` 关键字声明模块
+//! - 工作区（workspace）：多个包共用 `Cargo.lock` 与 `target/`；本课程就是一个例子——
+//!   运行器、`rust-learn-core` 与两个 lesson crate，本课所在的正是 `lessons-advanced`
+//!
+//! ```rust
+//! mod shapes {
+//!     pub struct Square {
+//!         pub side: u32,
+//!         area: u32, // 没有 pub：只有 shapes 模块内部能访问
+//!     }
+//!
+//!     impl Square {
+//!         pub fn new(side: u32) -> Self {
+//!             Square { side, area: side * side }
+//!         }
+//!
+//!         pub fn area(&self) -> u32 {
+//!             self.area
+//!         }
+//!     }
+//!
+//!     pub mod units {
+//!         pub fn cm(value: u32) -> String {
+//!             format!("{} cm", value)
+//!         }
+//!     }
+//! }
+//!
+//! use shapes::units::cm; // use 把路径引入作用域
+//!
+//! let square = shapes::Square::new(3); // 完整路径：模块::类型::关联函数
+//! assert_eq!(square.area(), 9);
+//! assert_eq!(cm(square.side), "3 cm");
+//! ```
+//!
+//! ## 常见坑
+//! - 忘记使用 `pub` 导致无法访问
+//! - 混淆模块声明和模块文件
+//! - 循环依赖问题
+//!
+//! ## 运行
+//! `cargo run -- 18_modules_crates`
+//!
+//! ## 示例
+//!
+//! 由 `cargo run -- update-doctests` 从演示代码中标出的片段生成，`cargo test --doc` 会运行它们。
+//!
+//! `paths`：
+//!
+//! ```rust
+//! mod math {
+//!     #[allow(clippy::approx_constant)]
+//!     pub const PI: f64 = 3.14159;
+//!
+//!     pub fn add(a: i32, b: i32) -> i32 {
+//!         a + b
+//!     }
+//!
+//!     pub fn multiply(a: i32, b: i32) -> i32 {
+//!         a * b
+//!     }
+//!
+//!     pub mod advanced {
+//!         pub fn power(base: i32, exp: u32) -> i32 {
+//!             base.pow(exp)
+//!         }
+//!     }
+//! }
+//!
+//! println!("PI = {}", math::PI);
+//! println!("2 + 3 = {}", math::add(2, 3));
+//! println!("4 * 5 = {}", math::multiply(4, 5));
+//! println!("2^3 = {}", math::advanced::power(2, 3));
+//!
+//! use math::add;
+//! use math::advanced::power;
+//!
+//! println!("Using add directly: {}", add(10, 20));
+//! println!("Using power directly: {}", power(3, 2));
+//!
+//! use math::{PI, multiply};
+//! println!("Using multiply: {}", multiply(5, 6));
+//! println!("Using PI: {}", PI);
+//! ```
+
+// 本章在单文件中演示模块系统概念
+
+use crate::exercises::modules_crates as exercise;
+use crate::lesson::{self, Check, Koan, LessonContext, Pitfall, Question, Section};
+use std::io::{self, Write};
+
+pub const SECTIONS: &[Section] = &[
+    Section::new("module_basics", "模块基础", demo_module_basics),
+    Section::new("paths_and_use", "路径和 use", demo_paths_and_use),
+    Section::new("visibility", "可见性控制", demo_visibility),
+    Section::new("nested_modules", "嵌套模块", demo_nested_modules),
+];
+
+pub const QUIZ: &[Question] = &[
+    Question::new(
+        "模块 `front` 中的函数默认对外可见吗？",
+        &[
+            "可见",
+            "不可见，需要 `pub`",
+            "只对同一文件可见",
+            "只对 main 可见",
+        ],
+        1,
+        "模块中的项默认私有，需要 `pub` 才能被外部访问；子模块可以访问父模块的私有项。",
+    ),
+    Question::new(
+        "在 `main.rs` 中写 `mod utils;` 会做什么？",
+        &[
+            "从 crates.io 下载 utils",
+            "声明模块，内容从 `utils.rs` 或 `utils/mod.rs` 读取",
+            "导入 utils 中的全部项",
+            "创建一个新 crate",
+        ],
+        1,
+        "`mod` 声明模块并决定文件位置；`use` 只是为路径创建快捷方式。",
+    ),
+    Question::new(
+        "workspace 的主要作用是？",
+        &[
+            "多个包共用 `Cargo.lock` 与 `target/`",
+            "把多个 crate 合并成一个",
+            "让模块默认 pub",
+            "替代 `mod` 声明",
+        ],
+        0,
+        "本课程就是一个 workspace：lesson 分在不同 crate 中，修改一个只重新编译相关部分。",
+    ),
+    Question::new(
+        "`use crate::utils::math;` 中的 `crate` 指什么？",
+        &[
+            "crates.io 上的依赖",
+            "当前 crate 的根模块",
+            "上一级模块",
+            "标准库",
+        ],
+        1,
+        "`crate::` 从当前 crate 的根开始写绝对路径；上一级模块用 `super::`。",
+    ),
+];
+
+pub const EXERCISE: &[Check] = &[
+    Check::new("geometry::perimeter", check_perimeter),
+    Check::new("geometry::square_perimeter", check_square_perimeter),
+    Check::new("text::capitalize", check_capitalize),
+    Check::new("text::describe_rect", check_describe_rect),
+];
+
+pub const HINTS: &[&str] = &[
+    "同一模块内的函数可以直接调用，其他模块中的要写路径；`super::` 指向父模块。",
+    "`square_perimeter` 调用 `perimeter(side, side)`；`describe_rect` 用 `super::geometry::perimeter` 并 `format!` 出结果；`capitalize` 先取出第一个字符。",
+    "`let mut chars = word.chars();` 然后 `match chars.next() { Some(first) => first.to_uppercase().collect::<String>() + chars.as_str(), None => String::new() }`。",
+];
+
+pub const KOANS: &[Koan] = &[
+    Koan::new(
+        "visibility",
+        r#"mod shapes {
+    pub struct Rect {
+        pub width: u32,
+        height: u32,
+    }
+
+    impl Rect {
+        pub fn square(size: u32) -> Rect {
+            Rect {
+                width: size,
+                height: size,
+            }
+        }
+
+        __ fn area(&self) -> u32 {
+            self.width * self.height
+        }
+    }
+}
+
+#[test]
+fn visibility() {
+    let r = shapes::Rect::square(3);
+    assert_eq!(r.width, 3);
+    assert_eq!(r.area(), 9);
+}
+"#,
+        &["pub"],
+    ),
+    Koan::new(
+        "paths",
+        r#"mod garden {
+    pub mod vegetables {
+        pub fn name() -> &'static str {
+            "asparagus"
+        }
+    }
+
+    pub fn plant() -> String {
+        format!("planting {}", __::name())
+    }
+}
+
+#[test]
+fn paths() {
+    use garden::vegetables;
+    assert_eq!(vegetables::name(), "asparagus");
+    assert_eq!(garden::plant(), "planting asparagus");
+}
+"#,
+        &["self::vegetables"],
+    ),
+];
+
+pub const PITFALLS: &[Pitfall] = &[Pitfall::new(
+    "private_function",
+    include_str!("../pitfalls/modules_crates/private_function.rs"),
+    "模块中的条目默认私有，即使外层模块是 `pub` 的，里面的 `harvest` 在模块外也不可见（E0603）。给需要公开的函数加上 `pub`。",
+).error("E0603")];
+
+pub fn run(ctx: &mut LessonContext) -> lesson::Result {
+    lesson::run_sections(ctx, SECTIONS)
+}
+
+fn demo_module_basics(ctx: &mut LessonContext) -> lesson::Result {
+    mod network {
+        use crate::lesson::LessonContext;
+        use std::io::{self, Write};
+
+        pub fn connect(ctx: &mut LessonContext) -> io::Result<()> {
+            writeln!(ctx, "Connecting to network...")?;
+            Ok(())
+        }
+
+        pub mod server {
+            use super::{LessonContext, Write, io};
+
+            pub fn start(ctx: &mut LessonContext) -> io::Result<()> {
+                writeln!(ctx, "Server starting...")?;
+                super::connect(ctx)?;
+                Ok(())
+            }
+        }
+    }
+
+    network::connect(ctx)?;
+    network::server::start(ctx)?;
+
+    mod config {
+        use crate::lesson::LessonContext;
+        use std::io::{self, Write};
+
+        pub struct Database {
+            pub host: String,
+            pub port: u32,
+        }
+
+        impl Database {
+            pub fn new(host: String, port: u32) -> Self {
+                Database { host, port }
+            }
+
+            pub fn connect(&self, ctx: &mut LessonContext) -> io::Result<()> {
+                writeln!(ctx, "Connecting to {}:{}", self.host, self.port)?;
+                Ok(())
+            }
+        }
+    }
+
+    let db = config::Database::new(String::from("localhost"), 3306);
+    db.connect(ctx)?;
+    Ok(())
+}
+
+fn demo_paths_and_use(ctx: &mut LessonContext) -> lesson::Result {
+    // ANCHOR: paths
+    mod math {
+        #[allow(clippy::approx_constant)]
+        pub const PI: f64 = 3.14159;
+
+        pub fn add(a: i32, b: i32) -> i32 {
+            a + b
+        }
+
+        pub fn multiply(a: i32, b: i32) -> i32 {
+            a * b
+        }
+
+        pub mod advanced {
+            pub fn power(base: i32, exp: u32) -> i32 {
+                base.pow(exp)
+            }
+        }
+    }
+
+    writeln!(ctx, "PI = {}", math::PI)?;
+    writeln!(ctx, "2 + 3 = {}", math::add(2, 3))?;
+    writeln!(ctx, "4 * 5 = {}", math::multiply(4, 5))?;
+    writeln!(ctx, "2^3 = {}", math::advanced::power(2, 3))?;
+
+    use math::add;
+    use math::advanced::power;
+
+    writeln!(ctx, "Using add directly: {}", add(10, 20))?;
+    writeln!(ctx, "Using power directly: {}", power(3, 2))?;
+
+    use math::{PI, multiply};
+    writeln!(ctx, "Using multiply: {}", multiply(5, 6))?;
+    writeln!(ctx, "Using PI: {}", PI)?;
+    // ANCHOR_END: paths
+
+    use std::collections::HashMap;
+    let mut map = HashMap::new();
+    map.insert("key", "value");
+    writeln!(ctx, "HashMap: {:?}", map)?;
+
+    use std::io::{self, Read};
+    writeln!(ctx, "Imported io and Read")?;
+
+    use std::fmt::Result;
+    use std::io::Result as IoResult;
+    // Now we can use both Result types
+    Ok(())
+}
+
+fn demo_visibility(ctx: &mut LessonContext) -> lesson::Result {
+    mod backend {
+        use crate::lesson::LessonContext;
+        use std::io::{self, Write};
+
+        pub struct ApiClient {
+            base_url: String,
+        }
+
+        impl ApiClient {
+            pub fn new(base_url: String) -> Self {
+                ApiClient { base_url }
+            }
+
+            pub fn get(&self, endpoint: &str) -> String {
+                format!("GET {}/{}", self.base_url, endpoint)
+            }
+
+            fn log(&self, ctx: &mut LessonContext, message: &str) -> io::Result<()> {
+                writeln!(ctx, "[LOG] {}", message)?;
+                Ok(())
+            }
+        }
+    }
+
+    let client = backend::ApiClient::new(String::from("https://api.example.com"));
+    writeln!(ctx, "{}", client.get("users"))?;
+
+    mod internal {
+        use crate::lesson::LessonContext;
+        use std::io::{self, Write};
+
+        pub fn public_api(ctx: &mut LessonContext) -> io::Result<()> {
+            writeln!(ctx, "Public API called")?;
+            private_helper(ctx)?;
+            Ok(())
+        }
+
+        fn private_helper(ctx: &mut LessonContext) -> io::Result<()> {
+            writeln!(ctx, "Private helper")?;
+            Ok(())
+        }
+    }
+
+    internal::public_api(ctx)?;
+    Ok(())
+}
+
+fn demo_nested_modules(ctx: &mut LessonContext) -> lesson::Result {
+    mod company {
+        use crate::lesson::LessonContext;
+        use std::io::{self, Write};
+
+        pub mod sales {
+            use super::{LessonContext, Write, io};
+
+            pub fn generate_report(ctx: &mut LessonContext) -> io::Result<()> {
+                writeln!(ctx, "Sales report generated")?;
+                Ok(())
+            }
+        }
+
+        pub mod engineering {
+            use super::{LessonContext, Write, io};
+
+            pub fn deploy(ctx: &mut LessonContext) -> io::Result<()> {
+                writeln!(ctx, "Deployment started")?;
+                super::sales::generate_report(ctx)?;
+                Ok(())
+            }
+
+            pub mod backend {
+                use super::{LessonContext, Write, io};
+
+                pub fn migrate_database(ctx: &mut LessonContext) -> io::Result<()> {
+                    writeln!(ctx, "Database migration started")?;
+                    Ok(())
+                }
+            }
+
+            pub mod frontend {
+                use super::{LessonContext, Write, io};
+
+                pub fn build_ui(ctx: &mut LessonContext) -> io::Result<()> {
+                    writeln!(ctx, "UI build started")?;
+                    super::backend::migrate_database(ctx)?;
+                    Ok(())
+                }
+            }
+        }
+    }
+
+    company::sales::generate_report(ctx)?;
+    company::engineering::deploy(ctx)?;
+    company::engineering::backend::migrate_database(ctx)?;
+    company::engineering::frontend::build_ui(ctx)?;
+
+    let numbers = vec![1, 2, 3, 4, 5];
+    writeln!(ctx, "Average: {}", helpers::math::average(&numbers))?;
+    writeln!(
+        ctx,
+        "Is palindrome 'racecar': {}",
+        helpers::string::is_palindrome("racecar")
+    )?;
+    Ok(())
+}
+
+mod helpers {
+    pub mod math {
+        pub fn average(numbers: &[i32]) -> f64 {
+            if numbers.is_empty() {
+                return 0.0;
+            }
+            let sum: i32 = numbers.iter().sum();
+            sum as f64 / numbers.len() as f64
+        }
+    }
+
+    pub mod string {
+        pub fn is_palindrome(s: &str) -> bool {
+            let cleaned: String = s
+                .chars()
+                .filter(|c| c.is_alphabetic())
+                .map(|c| c.to_ascii_lowercase())
+                .collect();
+            cleaned == cleaned.chars().rev().collect::<String>()
+        }
+    }
+}
+
+fn check_perimeter() {
+    assert_eq!(exercise::geometry::perimeter(2, 3), 10);
+}
+
+fn check_square_perimeter() {
+    assert_eq!(exercise::geometry::square_perimeter(4), 16);
+}
+
+fn check_capitalize() {
+    assert_eq!(exercise::text::capitalize("rust"), "Rust");
+    assert_eq!(exercise::text::capitalize(""), "");
+}
+
+fn check_describe_rect() {
+    assert_eq!(
+        exercise::text::describe_rect(2, 3),
+        "2x3 rectangle, perimeter 10"
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nested_modules() {
+        fn test_fn() {
+            mod test_mod {
+                pub fn test() -> i32 {
+                    42
+                }
+            }
+            assert_eq!(test_mod::test(), 42);
+        }
+        test_fn();
+    }
+
+    #[test]
+    fn test_visibility() {
+        mod test_visibility {
+            pub fn public() -> i32 {
+                private()
+            }
+
+            fn private() -> i32 {
+                100
+            }
+        }
+        assert_eq!(test_visibility::public(), 100);
+    }
+}
+
+#[cfg(test)]
+mod properties {
+    use super::*;
+
+    proptest::proptest! {
+        // 元素控制在小范围内，求和不会溢出；平均值落在最小值与最大值之间
+        #[test]
+        fn prop_average(numbers in proptest::collection::vec(-1000i32..1000, 1..50)) {
+            let average = helpers::math::average(&numbers);
+            let (min, max) = (numbers.iter().min().unwrap(), numbers.iter().max().unwrap());
+            proptest::prop_assert!(f64::from(*min) <= average && average <= f64::from(*max));
+        }
+
+        // 任意字符串接上自己的倒序都是回文；倒过来读不改变是否回文
+        #[test]
+        fn prop_is_palindrome(s in "\\PC{0,20}") {
+            let reversed: String = s.chars().rev().collect();
+            let doubled = s.clone() + &reversed;
+            proptest::prop_assert!(helpers::string::is_palindrome(&doubled));
+            proptest::prop_assert_eq!(
+                helpers::string::is_palindrome(&s),
+                helpers::string::is_palindrome(&reversed)
+            );
+        }
+    }
+}
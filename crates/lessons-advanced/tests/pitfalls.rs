@@ -0,0 +1,8 @@
+//! `pitfalls/<slug>/*.rs` 是各 lesson 的“常见坑”：每段代码都应当编译失败，
+//! 编译器的报错与同名的 `.stderr` 一致。工具链升级导致报错变化时，
+//! 用 `TRYBUILD=overwrite cargo test --test pitfalls` 重新生成 `.stderr`。
+
+#[test]
+fn pitfalls() {
+    trybuild::TestCases::new().compile_fail("pitfalls/*/*.rs");
+}
@@ -0,0 +1,7 @@
+fn main() {
+    let mut s = String::from("hello");
+    let r1 = &s;
+    let r2 = &mut s;
+    r2.push_str(", world");
+    println!("{}", r1);
+}
@@ -0,0 +1,18 @@
+enum Direction {
+    North,
+    South,
+    East,
+    West,
+}
+
+fn name(d: Direction) -> &'static str {
+    match d {
+        Direction::North => "north",
+        Direction::South => "south",
+        Direction::East => "east",
+    }
+}
+
+fn main() {
+    println!("{}", name(Direction::West));
+}
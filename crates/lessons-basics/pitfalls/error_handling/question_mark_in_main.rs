@@ -0,0 +1,6 @@
+use std::fs;
+
+fn main() {
+    let config = fs::read_to_string("config.toml")?;
+    println!("{}", config);
+}
@@ -0,0 +1,7 @@
+fn plus_one(x: i32) -> i32 {
+    x + 1;
+}
+
+fn main() {
+    println!("{}", plus_one(5));
+}
@@ -0,0 +1,6 @@
+fn main() {
+    let names = vec![String::from("ferris"), String::from("corro")];
+    let show = move || println!("{:?}", names);
+    show();
+    println!("{} names", names.len());
+}
@@ -0,0 +1,9 @@
+fn takes_ownership(s: String) -> usize {
+    s.len()
+}
+
+fn main() {
+    let s = String::from("hello");
+    let len = takes_ownership(s);
+    println!("{} has {} bytes", s, len);
+}
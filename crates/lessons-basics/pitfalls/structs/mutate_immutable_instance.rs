@@ -0,0 +1,13 @@
+struct User {
+    name: String,
+    active: bool,
+}
+
+fn main() {
+    let user = User {
+        name: String::from("alice"),
+        active: true,
+    };
+    user.active = false;
+    println!("{} {}", user.name, user.active);
+}
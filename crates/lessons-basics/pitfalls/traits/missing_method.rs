@@ -0,0 +1,21 @@
+trait Summary {
+    fn author(&self) -> String;
+    fn summarize(&self) -> String;
+}
+
+struct Tweet {
+    username: String,
+}
+
+impl Summary for Tweet {
+    fn author(&self) -> String {
+        format!("@{}", self.username)
+    }
+}
+
+fn main() {
+    let tweet = Tweet {
+        username: String::from("rustlang"),
+    };
+    println!("{}", tweet.author());
+}
@@ -0,0 +1,8 @@
+fn square(x: i64) -> i64 {
+    x * x
+}
+
+fn main() {
+    let n: i32 = 7;
+    println!("{}", square(n));
+}
@@ -0,0 +1,312 @@
+//! # Borrowing & References
+//!
+//! 目标：理解 Rust 中的借用和引用机制
+//!
+//! ## 要点
+//! - 借用允许在不获取所有权的情况下使用值
+//! - `&` 创建不可变引用（可同时多个）
+//! - `&mut` 创建可变引用（同一时间只能有一个）
+//! - 核心规则：要么一个可变引用，要么多个不可变引用，不能同时存在
+//! - 引用生命周期不能超过被引用值的生命周期
+//!
+//! ```rust
+//! fn len(s: &String) -> usize {
+//!     s.len() // 只读借用，所有权还在调用者手里
+//! }
+//!
+//! fn append(s: &mut String) {
+//!     s.push_str(", world");
+//! }
+//!
+//! let mut s = String::from("hello");
+//! let (r1, r2) = (&s, &s); // 多个不可变引用可以同时存在
+//! assert_eq!(len(r1), len(r2));
+//! append(&mut s); // r1、r2 之后不再使用，这里才能可变借用
+//! assert_eq!(s, "hello, world");
+//! ```
+//!
+//! ## 常见坑
+//! - 在拥有不可变引用的同时尝试创建可变引用
+//! - 悬空引用：引用了已经 drop 的值
+//! - 忘记解引用 `*` 操作符
+//!
+//! ## 运行
+//! `cargo run -- 07_borrowing`
+//!
+//! ## 示例
+//!
+//! 由 `cargo run -- update-doctests` 从演示代码中标出的片段生成，`cargo test --doc` 会运行它们。
+//!
+//! `multiple_references`：
+//!
+//! ```rust
+//! let s = String::from("hello");
+//!
+//! // 可以同时有多个不可变引用
+//! let r1 = &s;
+//! let r2 = &s;
+//! let r3 = &s;
+//!
+//! println!("{}, {}, and {}", r1, r2, r3);
+//! ```
+
+use crate::exercises::borrowing as exercise;
+use crate::lesson::{self, Check, Koan, LessonContext, Pitfall, Question, Section};
+use std::io::{self, Write};
+
+pub const SECTIONS: &[Section] = &[
+    Section::new(
+        "immutable_reference",
+        "不可变引用",
+        demo_immutable_reference,
+    ),
+    Section::new("mutable_reference", "可变引用", demo_mutable_reference),
+    Section::new(
+        "multiple_references",
+        "多个不可变引用",
+        demo_multiple_references,
+    ),
+    Section::new(
+        "reference_parameters",
+        "引用作为函数参数",
+        demo_reference_parameters,
+    ),
+];
+
+pub const QUIZ: &[Question] = &[
+    Question::new(
+        "`let r1 = &mut s; let r2 = &mut s; println!(\"{} {}\", r1, r2);` 违反了哪条借用规则？",
+        &[
+            "引用必须有显式生命周期",
+            "同一时间只能有一个可变引用",
+            "可变引用不能打印",
+            "不能对 String 取引用",
+        ],
+        1,
+        "同一时间要么一个可变引用，要么多个不可变引用。",
+    ),
+    Question::new(
+        "下面哪种组合可以同时存在？",
+        &[
+            "两个 `&mut s`",
+            "一个 `&s` 和一个 `&mut s`",
+            "多个 `&s`",
+            "以上都不行",
+        ],
+        2,
+        "多个不可变引用可以共存，因为它们都不会修改数据。",
+    ),
+    Question::new(
+        "`fn dangle() -> &String { let s = String::new(); &s }` 的问题是？",
+        &[
+            "返回了局部变量的引用，s 离开函数就被 drop（悬空引用）",
+            "String 不能作为返回值",
+            "缺少 `mut`",
+            "没有问题",
+        ],
+        0,
+        "引用不能比被引用的值活得更久，应当直接返回 `String` 转移所有权。",
+    ),
+    Question::new(
+        "通过 `r: &mut i32` 把值加 1 应该怎么写？",
+        &["`r += 1;`", "`*r += 1;`", "`&r += 1;`", "`r.add(1);`"],
+        1,
+        "修改引用指向的值需要用 `*` 解引用。",
+    ),
+];
+
+pub const EXERCISE: &[Check] = &[
+    Check::new("total_len", check_total_len),
+    Check::new("append_world", check_append_world),
+    Check::new("double_all", check_double_all),
+];
+
+pub const HINTS: &[&str] = &[
+    "`&` 借用只能读取，`&mut` 借用可以修改调用者的数据，函数不需要返回任何东西。",
+    "`total_len` 遍历并累加每个单词的 `len()`；`append_world` 用 `String::push_str`；`double_all` 要用 `iter_mut()` 拿到每个元素的可变引用。",
+    "`words.iter().map(|w| w.len()).sum()`；`for n in numbers.iter_mut() { *n *= 2; }`，注意修改时要用 `*` 解引用。",
+];
+
+pub const KOANS: &[Koan] = &[
+    Koan::new(
+        "immutable_reference",
+        r#"fn calculate_length(s: __) -> usize {
+    s.len()
+}
+
+#[test]
+fn borrow_instead_of_move() {
+    let s1 = String::from("hello");
+    assert_eq!(calculate_length(&s1), 5);
+    assert_eq!(s1, "hello");
+}
+"#,
+        &["&String"],
+    ),
+    Koan::new(
+        "mutable_reference",
+        r#"fn change(s: &mut String) {
+    s.push_str(", world");
+}
+
+#[test]
+fn mutable_reference() {
+    let mut s = String::from("hello");
+    change(__);
+    assert_eq!(s, "hello, world");
+}
+"#,
+        &["&mut s"],
+    ),
+];
+
+pub const PITFALLS: &[Pitfall] = &[
+    Pitfall::new(
+        "mutable_while_shared",
+        include_str!("../pitfalls/borrowing/mutable_while_shared.rs"),
+        "同一时刻要么有多个不可变引用，要么只有一个可变引用（E0502）。`r1` 一直用到最后一行，它存活期间不能再创建 `&mut s`；把对 `r1` 的使用挪到 `&mut s` 之前即可。",
+    ).error("E0502"),
+    Pitfall::new(
+        "dangling_reference",
+        include_str!("../pitfalls/borrowing/dangling_reference.rs"),
+        "`s` 在函数结束时被 drop，返回它的引用就成了悬空引用。编译器从签名就能看出返回的引用没有来源，报“缺少生命周期”（E0106）。直接返回 `String`，把所有权交给调用者。",
+    ).error("E0106"),
+];
+
+pub fn run(ctx: &mut LessonContext) -> lesson::Result {
+    lesson::run_sections(ctx, SECTIONS)
+}
+
+fn demo_immutable_reference(ctx: &mut LessonContext) -> lesson::Result {
+    let s = String::from("hello");
+    let len = calculate_length(&s);
+    writeln!(ctx, "The length of '{}' is {}", s, len)?;
+    // s 仍然有效，因为我们只是借用
+    Ok(())
+}
+
+#[allow(clippy::ptr_arg)]
+fn calculate_length(s: &String) -> usize {
+    s.len()
+    // s 离开作用域，但因为只是引用，不拥有所有权，所以不会 drop
+}
+
+fn demo_mutable_reference(ctx: &mut LessonContext) -> lesson::Result {
+    let mut s = String::from("hello");
+    writeln!(ctx, "Before: {}", s)?;
+    change(&mut s);
+    writeln!(ctx, "After: {}", s)?;
+    Ok(())
+}
+
+fn change(s: &mut String) {
+    s.push_str(", world");
+}
+
+fn demo_multiple_references(ctx: &mut LessonContext) -> lesson::Result {
+    // ANCHOR: multiple_references
+    let s = String::from("hello");
+
+    // 可以同时有多个不可变引用
+    let r1 = &s;
+    let r2 = &s;
+    let r3 = &s;
+
+    writeln!(ctx, "{}, {}, and {}", r1, r2, r3)?;
+    // ANCHOR_END: multiple_references
+    // r1, r2, r3 不再使用后，才能创建可变引用
+
+    // 注意：r1, r2, r3 的最后一次使用在这里，之后才能创建可变引用
+    Ok(())
+}
+
+fn demo_reference_parameters(ctx: &mut LessonContext) -> lesson::Result {
+    let mut arr = vec![1, 2, 3, 4, 5];
+
+    print_array(ctx, &arr)?;
+    add_element(&mut arr, 6);
+    print_array(ctx, &arr)?;
+
+    let first = get_first(&arr);
+    writeln!(ctx, "First element: {:?}", first)?;
+    Ok(())
+}
+
+fn print_array(ctx: &mut LessonContext, arr: &Vec<i32>) -> io::Result<()> {
+    writeln!(ctx, "Array: {:?}", arr)?;
+    Ok(())
+}
+
+fn add_element(arr: &mut Vec<i32>, value: i32) {
+    arr.push(value);
+}
+
+#[allow(clippy::ptr_arg, clippy::get_first)]
+fn get_first(arr: &Vec<i32>) -> Option<&i32> {
+    arr.get(0)
+}
+
+fn check_total_len() {
+    let words = vec![String::from("borrow"), String::from("check")];
+    assert_eq!(exercise::total_len(&words), 11);
+    assert_eq!(exercise::total_len(&[]), 0);
+}
+
+fn check_append_world() {
+    let mut s = String::from("hello");
+    exercise::append_world(&mut s);
+    assert_eq!(s, "hello, world");
+}
+
+fn check_double_all() {
+    let mut numbers = vec![1, -2, 3];
+    exercise::double_all(&mut numbers);
+    assert_eq!(numbers, [2, -4, 6]);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_immutable_reference() {
+        let s = String::from("test");
+        let len = calculate_length(&s);
+        assert_eq!(len, 4);
+        assert_eq!(s, "test");
+    }
+
+    #[test]
+    fn test_mutable_reference() {
+        let mut s = String::from("hello");
+        change(&mut s);
+        assert_eq!(s, "hello, world");
+    }
+
+    #[test]
+    fn test_multiple_immutable_refs() {
+        let s = String::from("value");
+        let r1 = &s;
+        let r2 = &s;
+        let r3 = &s;
+        assert_eq!(*r1, "value");
+        assert_eq!(*r2, "value");
+        assert_eq!(*r3, "value");
+    }
+
+    #[test]
+    fn test_reference_with_array() {
+        let arr = vec![1, 2, 3, 4, 5];
+        let mut out = Vec::new();
+        print_array(&mut LessonContext::new(&mut out), &arr).unwrap();
+        assert_eq!(arr.len(), 5);
+        assert_eq!(String::from_utf8(out).unwrap(), "Array: [1, 2, 3, 4, 5]\n");
+    }
+
+    #[test]
+    fn test_mutable_reference_with_array() {
+        let mut arr = vec![1, 2, 3];
+        add_element(&mut arr, 4);
+        assert_eq!(arr, vec![1, 2, 3, 4]);
+    }
+}
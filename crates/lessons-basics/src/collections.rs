@@ -0,0 +1,370 @@
+//! # Collections: Vec / String / HashMap
+//!
+//! 目标：理解 Rust 中常用的集合类型
+//!
+//! ## 要点
+//! - `Vec<T>`：动态数组，在堆上存储同类型元素
+//! - `String`：可增长的 UTF-8 字符串
+//! - `HashMap<K, V>`：键值对映射
+//! - 使用 `push` 添加元素，`pop` 移除元素
+//! - 迭代器：可以遍历集合中的元素
+//!
+//! ```rust
+//! use std::collections::HashMap;
+//!
+//! let mut v = vec![1, 2];
+//! v.push(3);
+//! assert_eq!(v.pop(), Some(3));
+//! assert_eq!(v, [1, 2]);
+//!
+//! let mut s = String::from("你好");
+//! s.push('!');
+//! assert_eq!(s.len(), 7); // len 是 UTF-8 字节数
+//! assert_eq!(s.chars().count(), 3);
+//!
+//! let mut scores = HashMap::new();
+//! scores.insert("blue", 10);
+//! *scores.entry("blue").or_insert(0) += 5;
+//! assert_eq!(scores["blue"], 15);
+//!
+//! assert_eq!(v.iter().sum::<i32>(), 3);
+//! ```
+//!
+//! ## 常见坑
+//! - 索引 Vec 越界会导致 panic，使用 `get` 更安全
+//! - String 的 `+` 运算符会转移所有权
+//! - HashMap 的键必须实现 `Eq` 和 `Hash` trait
+//!
+//! ## 运行
+//! `cargo run -- 15_collections`
+//!
+//! ## 示例
+//!
+//! 由 `cargo run -- update-doctests` 从演示代码中标出的片段生成，`cargo test --doc` 会运行它们。
+//!
+//! `string`：
+//!
+//! ```rust
+//! let mut s1 = String::new();
+//! s1.push_str("Hello");
+//! println!("s1: {}", s1);
+//!
+//! let mut s2 = String::from("Rust");
+//! s2.push(' ');
+//! s2.push_str("Programming");
+//! println!("s2: {}", s2);
+//!
+//! let s3 = String::from("Hello, ") + &s2;
+//! println!("s3: {}", s3);
+//!
+//! let s4 = format!("{} {}!", s1, s2);
+//! println!("s4: {}", s4);
+//!
+//! for c in s4.chars() {
+//!     print!("[{}]", c);
+//! }
+//! println!();
+//!
+//! for b in s4.bytes() {
+//!     print!("{}", b);
+//! }
+//! println!();
+//! ```
+
+use crate::exercises::collections as exercise;
+use crate::lesson::{self, Check, Koan, LessonContext, Pitfall, Question, Section};
+use std::collections::HashMap;
+use std::io::{self, Write};
+
+pub const SECTIONS: &[Section] = &[
+    Section::new("vector", "Vec 向量", demo_vector),
+    Section::new("string", "String 字符串", demo_string),
+    Section::new("hashmap", "HashMap 哈希映射", demo_hashmap),
+    Section::new("collection_ops", "集合操作", demo_collection_ops),
+];
+
+pub const QUIZ: &[Question] = &[
+    Question::new(
+        "`v[10]`（v 只有 3 个元素）与 `v.get(10)` 的区别？",
+        &[
+            "两者都返回 None",
+            "前者 panic，后者返回 `None`",
+            "前者返回 None，后者 panic",
+            "都编译失败",
+        ],
+        1,
+        "索引越界会 panic；`get` 返回 `Option`，适合可能越界的情况。",
+    ),
+    Question::new(
+        "`let s3 = s1 + &s2;` 之后，哪个变量不能再用？",
+        &["s1", "s2", "都不能", "都能"],
+        0,
+        "`+` 调用 `add(self, &str)`，会获取 s1 的所有权；s2 只是被借用。",
+    ),
+    Question::new(
+        "HashMap 的键类型需要实现什么？",
+        &["`Ord`", "`Eq` 和 `Hash`", "`Copy`", "`Display`"],
+        1,
+        "哈希表根据 `Hash` 定位、用 `Eq` 比较键；`BTreeMap` 则要求 `Ord`。",
+    ),
+    Question::new(
+        "统计单词出现次数最惯用的写法是？",
+        &[
+            "`*map.entry(word).or_insert(0) += 1;`",
+            "先 `contains_key` 再 `insert`",
+            "`map[word] += 1;`",
+            "`map.push(word)`",
+        ],
+        0,
+        "`entry` API 只查找一次，不存在时插入默认值并返回可变引用。",
+    ),
+];
+
+pub const EXERCISE: &[Check] = &[
+    Check::new("word_count", check_word_count),
+    Check::new("unique_sorted", check_unique_sorted),
+    Check::new("most_common_char", check_most_common_char),
+];
+
+pub const HINTS: &[&str] = &[
+    "`HashMap` 的 `entry` API 适合计数；`Vec` 有原地排序与去除相邻重复元素的方法。",
+    "`word_count` 对 `split_whitespace()` 的每个单词执行 `*counts.entry(...).or_insert(0) += 1`；`unique_sorted` 先 `sort()` 再 `dedup()`；`most_common_char` 同样先计数再找最大值。",
+    "找最大值时用 `max_by(|a, b| a.1.cmp(&b.1).then(b.0.cmp(&a.0)))`：先比次数，次数相同时让较小的字符胜出。",
+];
+
+pub const KOANS: &[Koan] = &[
+    Koan::new(
+        "vectors",
+        r#"#[test]
+fn vectors() {
+    let mut v = vec![1, 2, 3];
+    v.push(4);
+    assert_eq!(v.len(), __);
+    assert_eq!(v.get(10), __);
+    let total: i32 = v.iter().sum();
+    assert_eq!(total, 10);
+}
+"#,
+        &["4", "None"],
+    ),
+    Koan::new(
+        "word_count",
+        r#"#[test]
+fn word_count() {
+    use std::collections::HashMap;
+
+    let text = "hello world wonderful world";
+    let mut counts = HashMap::new();
+    for word in text.split_whitespace() {
+        *counts.entry(word).or_insert(__) += 1;
+    }
+    assert_eq!(counts["world"], 2);
+    assert_eq!(counts.len(), __);
+}
+"#,
+        &["0", "3"],
+    ),
+];
+
+pub const PITFALLS: &[Pitfall] = &[Pitfall::new(
+    "push_while_borrowed",
+    include_str!("../pitfalls/collections/push_while_borrowed.rs"),
+    "`first` 引用着 `v` 的元素，而 `push` 可能让 Vec 重新分配内存、把元素搬到别处，旧引用就会悬空，所以借用检查器不允许（E0502）。先复制出需要的值（`let first = v[0];`），或者在 `push` 之后再取引用。",
+).error("E0502")];
+
+pub fn run(ctx: &mut LessonContext) -> lesson::Result {
+    lesson::run_sections(ctx, SECTIONS)
+}
+
+#[allow(clippy::vec_init_then_push)]
+fn demo_vector(ctx: &mut LessonContext) -> lesson::Result {
+    let mut v1 = Vec::new();
+    v1.push(1);
+    v1.push(2);
+    v1.push(3);
+    writeln!(ctx, "Vector v1: {:?}", v1)?;
+
+    let mut v2 = vec![1, 2, 3, 4, 5];
+    writeln!(ctx, "Vector v2: {:?}", v2)?;
+
+    let third = &v2[2];
+    writeln!(ctx, "Third element: {}", third)?;
+
+    match v2.get(10) {
+        Some(value) => writeln!(ctx, "Element at index 10: {}", value)?,
+        None => writeln!(ctx, "No element at index 10")?,
+    }
+
+    for i in &mut v2 {
+        *i += 50;
+    }
+    writeln!(ctx, "Modified v2: {:?}", v2)?;
+
+    v2.pop();
+    writeln!(ctx, "After pop: {:?}", v2)?;
+    Ok(())
+}
+
+fn demo_string(ctx: &mut LessonContext) -> lesson::Result {
+    // ANCHOR: string
+    let mut s1 = String::new();
+    s1.push_str("Hello");
+    writeln!(ctx, "s1: {}", s1)?;
+
+    let mut s2 = String::from("Rust");
+    s2.push(' ');
+    s2.push_str("Programming");
+    writeln!(ctx, "s2: {}", s2)?;
+
+    let s3 = String::from("Hello, ") + &s2;
+    writeln!(ctx, "s3: {}", s3)?;
+
+    let s4 = format!("{} {}!", s1, s2);
+    writeln!(ctx, "s4: {}", s4)?;
+
+    for c in s4.chars() {
+        write!(ctx, "[{}]", c)?;
+    }
+    writeln!(ctx)?;
+
+    for b in s4.bytes() {
+        write!(ctx, "{}", b)?;
+    }
+    writeln!(ctx)?;
+    // ANCHOR_END: string
+    Ok(())
+}
+
+fn demo_hashmap(ctx: &mut LessonContext) -> lesson::Result {
+    let mut scores = HashMap::new();
+    scores.insert(String::from("Blue"), 10);
+    scores.insert(String::from("Yellow"), 50);
+    writeln!(ctx, "Scores: {:?}", ctx.map(&scores))?;
+
+    let team_name = String::from("Blue");
+    let score = scores.get(&team_name).copied().unwrap_or(0);
+    writeln!(ctx, "Blue team score: {}", score)?;
+
+    scores.insert(String::from("Blue"), 25);
+    writeln!(ctx, "Updated scores: {:?}", ctx.map(&scores))?;
+
+    scores.entry(String::from("Red")).or_insert(30);
+    scores.entry(String::from("Blue")).or_insert(100);
+    writeln!(ctx, "After entry: {:?}", ctx.map(&scores))?;
+
+    // HashMap 的遍历顺序不固定；`--deterministic` 或测试中设定了种子时按键排序
+    for (key, value) in ctx.entries(&scores) {
+        writeln!(ctx, "{}: {}", key, value)?;
+    }
+
+    let text = "hello world wonderful world";
+    let mut word_count = HashMap::new();
+    for word in text.split_whitespace() {
+        let count = word_count.entry(word).or_insert(0);
+        *count += 1;
+    }
+    writeln!(ctx, "Word count: {:?}", ctx.map(&word_count))?;
+    Ok(())
+}
+
+fn demo_collection_ops(ctx: &mut LessonContext) -> lesson::Result {
+    let mut numbers = vec![1, 2, 3, 4, 5];
+    numbers.retain(|&x| x % 2 == 0);
+    writeln!(ctx, "Even numbers: {:?}", numbers)?;
+
+    let doubled: Vec<i32> = numbers.iter().map(|x| x * 2).collect();
+    writeln!(ctx, "Doubled: {:?}", doubled)?;
+
+    let sum: i32 = doubled.iter().sum();
+    writeln!(ctx, "Sum: {}", sum)?;
+
+    let filtered: Vec<_> = (1..10).filter(|x| x % 3 == 0).collect();
+    writeln!(ctx, "Multiples of 3: {:?}", filtered)?;
+
+    let mut map = HashMap::new();
+    map.insert("key1", "value1");
+    map.insert("key2", "value2");
+
+    if let Some(value) = map.get("key1") {
+        writeln!(ctx, "Found: {}", value)?;
+    }
+
+    map.remove("key1");
+    writeln!(ctx, "After removal: {:?}", map)?;
+    Ok(())
+}
+
+fn check_word_count() {
+    let counts = exercise::word_count("the cat saw the  dog");
+    assert_eq!(counts.len(), 4);
+    assert_eq!(counts["the"], 2);
+    assert_eq!(counts["dog"], 1);
+}
+
+fn check_unique_sorted() {
+    assert_eq!(exercise::unique_sorted(vec![3, 1, 3, 2, 1]), [1, 2, 3]);
+    assert!(exercise::unique_sorted(Vec::new()).is_empty());
+}
+
+fn check_most_common_char() {
+    assert_eq!(exercise::most_common_char("hello"), Some('l'));
+    assert_eq!(exercise::most_common_char("baab"), Some('a'));
+    assert_eq!(exercise::most_common_char(""), None);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vector_push_pop() {
+        let mut v = Vec::new();
+        v.push(1);
+        v.push(2);
+        v.push(3);
+        assert_eq!(v.pop(), Some(3));
+        assert_eq!(v.len(), 2);
+    }
+
+    #[test]
+    #[allow(clippy::useless_vec)]
+    fn test_vector_get() {
+        let v = vec![1, 2, 3];
+        assert_eq!(v.get(1), Some(&2));
+        assert_eq!(v.get(10), None);
+    }
+
+    #[test]
+    fn test_string_concat() {
+        let mut s = String::from("Hello");
+        s.push_str(" World");
+        assert_eq!(s, "Hello World");
+    }
+
+    #[test]
+    fn test_hashmap_insert_get() {
+        let mut map = HashMap::new();
+        map.insert("key", 42);
+        assert_eq!(map.get("key"), Some(&42));
+    }
+
+    #[test]
+    fn test_hashmap_or_insert() {
+        let mut map = HashMap::new();
+        map.insert("a", 1);
+
+        let count = map.entry("a").or_insert(0);
+        *count += 1;
+        assert_eq!(map.get("a"), Some(&2));
+
+        let count = map.entry("b").or_insert(0);
+        *count += 1;
+        assert_eq!(map.get("b"), Some(&1));
+    }
+
+    #[test]
+    fn test_collect_into_vec() {
+        let v: Vec<i32> = (0..5).collect();
+        assert_eq!(v, vec![0, 1, 2, 3, 4]);
+    }
+}
@@ -0,0 +1,369 @@
+//! # Control Flow: if / loop / while / match
+//!
+//! 目标：掌握 Rust 中的控制流结构
+//!
+//! ## 要点
+//! - `if` 是表达式，可以有返回值
+//! - `loop` 无限循环，可用 `break` 退出并返回值
+//! - `while` 条件循环
+//! - `for` 遍历集合
+//! - `match` 模式匹配，必须穷尽所有可能
+//! - `if let` 简化 match 处理单一模式
+//!
+//! ```rust
+//! let n = 7;
+//! let parity = if n % 2 == 0 { "even" } else { "odd" }; // if 是表达式
+//! assert_eq!(parity, "odd");
+//!
+//! let mut count = 0;
+//! let result = loop {
+//!     count += 1;
+//!     if count == 3 {
+//!         break count * 10; // break 带出 loop 的值
+//!     }
+//! };
+//! assert_eq!(result, 30);
+//!
+//! let mut x = 1;
+//! while x < 100 {
+//!     x *= 2;
+//! }
+//! assert_eq!(x, 128);
+//!
+//! let mut total = 0;
+//! for i in 1..=4 {
+//!     total += i;
+//! }
+//! assert_eq!(total, 10);
+//!
+//! let size = match n {
+//!     0 => "zero",
+//!     1..=9 => "small",
+//!     _ => "large", // 去掉这一行就没有穷尽所有可能，编译失败
+//! };
+//! assert_eq!(size, "small");
+//!
+//! if let Some(first) = [5, 6].first() {
+//!     assert_eq!(*first, 5);
+//! }
+//! ```
+//!
+//! ## 常见坑
+//! - `if` 条件必须是 `bool` 类型，不能隐式转换
+//! - `match` 必须处理所有情况或添加通配符 `_`
+//! - 忘记 `break` 导致无限循环
+//!
+//! ## 运行
+//! `cargo run -- 05_control_flow`
+//!
+//! ## 示例
+//!
+//! 由 `cargo run -- update-doctests` 从演示代码中标出的片段生成，`cargo test --doc` 会运行它们。
+//!
+//! `loop`：
+//!
+//! ```rust
+//! let mut counter = 0;
+//! let result = loop {
+//!     counter += 1;
+//!     println!("Counter: {}", counter);
+//!
+//!     if counter >= 3 {
+//!         break counter * 2; // loop 可以返回值
+//!     }
+//! };
+//! println!("Loop result: {}", result);
+//! ```
+//!
+//! `if_let`：
+//!
+//! ```rust
+//! let some_value = Some(42u32);
+//!
+//! // 使用 if let 简化 match
+//! if let Some(x) = some_value {
+//!     println!("if let matched: {}", x);
+//! } else {
+//!     println!("if let: no value");
+//! }
+//!
+//! let mut stack = vec![1, 2, 3];
+//! while let Some(top) = stack.pop() {
+//!     println!("Popped: {}", top);
+//! }
+//! ```
+
+use crate::exercises::control_flow as exercise;
+use crate::lesson::{self, Check, Koan, LessonContext, Pitfall, Question, Section};
+use std::io::{self, Write};
+
+pub const SECTIONS: &[Section] = &[
+    Section::new("if", "if 表达式", demo_if),
+    Section::new("loop", "loop 循环", demo_loop),
+    Section::new("while", "while 循环", demo_while),
+    Section::new("for", "for 循环", demo_for),
+    Section::new("match", "match 模式匹配", demo_match),
+    Section::new("if_let", "if let", demo_if_let),
+];
+
+pub const QUIZ: &[Question] = &[
+    Question::new(
+        "`if 1 { ... }` 为什么编译失败？",
+        &[
+            "缺少 else",
+            "条件必须是 `bool`，整数不会隐式转换",
+            "条件要加括号",
+            "1 是常量",
+        ],
+        1,
+        "Rust 不会把整数隐式转换为布尔值，条件必须是 `bool`。",
+    ),
+    Question::new(
+        "`let x = loop { break 5; };` 中 `x` 的值是？",
+        &["编译错误", "`()`", "5", "无限循环"],
+        2,
+        "`loop` 是表达式，`break` 后面的值就是整个循环的值。",
+    ),
+    Question::new(
+        "`match n { 1 => \"one\", 2 => \"two\" }`（n: i32）为什么编译失败？",
+        &[
+            "分支必须用花括号",
+            "没有穷尽所有可能，需要 `_` 分支",
+            "字符串不能作为分支的值",
+            "`match` 不能用于整数",
+        ],
+        1,
+        "`match` 必须穷尽所有情况，其余值用通配符 `_` 处理。",
+    ),
+    Question::new(
+        "`for i in 0..3` 中 `i` 依次取哪些值？",
+        &["0、1、2", "0、1、2、3", "1、2、3", "1、2"],
+        0,
+        "`0..3` 是左闭右开区间，不含 3；要包含终点写成 `0..=3`。",
+    ),
+];
+
+pub const EXERCISE: &[Check] = &[
+    Check::new("fizzbuzz", check_fizzbuzz),
+    Check::new("count_down", check_count_down),
+    Check::new("first_negative", check_first_negative),
+];
+
+pub const HINTS: &[&str] = &[
+    "`match` 可以同时匹配一个元组，`while` / `for` 都能写倒数，`return` 可以从循环中提前返回。",
+    "`fizzbuzz` 匹配 `(n % 3, n % 5)`，先写 `(0, 0)` 分支；`count_down` 可以用 `while i > 0` 逐个 `push`，或者把范围反转；`first_negative` 用 `enumerate()` 同时拿到下标。",
+    "`count_down` 一行版：`(1..=n).rev().collect()`；`first_negative` 在 `for (i, &x) in numbers.iter().enumerate()` 中遇到 `x < 0` 就 `return Some(i)`，循环结束后返回 `None`。",
+];
+
+pub const KOANS: &[Koan] = &[
+    Koan::new(
+        "if_expression",
+        r#"#[test]
+fn if_is_an_expression() {
+    let number = 7;
+    let kind = if number % 2 == 0 { "even" } else { __ };
+    assert_eq!(kind, "odd");
+}
+"#,
+        &["\"odd\""],
+    ),
+    Koan::new(
+        "loop_break_value",
+        r#"#[test]
+fn loop_break_value() {
+    let mut counter = 0;
+    let result = loop {
+        counter += 1;
+        if counter == 10 {
+            break __;
+        }
+    };
+    assert_eq!(result, 20);
+}
+"#,
+        &["counter * 2"],
+    ),
+];
+
+pub const PITFALLS: &[Pitfall] = &[Pitfall::new(
+    "non_bool_condition",
+    include_str!("../pitfalls/control_flow/non_bool_condition.rs"),
+    "`if` 的条件必须是 `bool`，整数不会像 C 那样自动当作真假（E0308）。写出要判断的条件，例如 `if number != 0`。",
+).error("E0308")];
+
+pub fn run(ctx: &mut LessonContext) -> lesson::Result {
+    lesson::run_sections(ctx, SECTIONS)
+}
+
+fn demo_if(ctx: &mut LessonContext) -> lesson::Result {
+    let number = 42;
+
+    if number < 0 {
+        writeln!(ctx, "{} is negative", number)?;
+    } else if number > 0 {
+        writeln!(ctx, "{} is positive", number)?;
+    } else {
+        writeln!(ctx, "{} is zero", number)?;
+    }
+
+    // if 作为表达式
+    let result = if number % 2 == 0 { "even" } else { "odd" };
+    writeln!(ctx, "{} is {}", number, result)?;
+    Ok(())
+}
+
+fn demo_loop(ctx: &mut LessonContext) -> lesson::Result {
+    // ANCHOR: loop
+    let mut counter = 0;
+    let result = loop {
+        counter += 1;
+        writeln!(ctx, "Counter: {}", counter)?;
+
+        if counter >= 3 {
+            break counter * 2; // loop 可以返回值
+        }
+    };
+    writeln!(ctx, "Loop result: {}", result)?;
+    // ANCHOR_END: loop
+    Ok(())
+}
+
+fn demo_while(ctx: &mut LessonContext) -> lesson::Result {
+    let mut number = 5;
+
+    while number > 0 {
+        writeln!(ctx, "While countdown: {}", number)?;
+        number -= 1;
+    }
+    writeln!(ctx, "Liftoff!")?;
+    Ok(())
+}
+
+fn demo_for(ctx: &mut LessonContext) -> lesson::Result {
+    let arr = [10, 20, 30, 40, 50];
+
+    writeln!(ctx, "For loop with array:")?;
+    for element in arr.iter() {
+        writeln!(ctx, "Value: {}", element)?;
+    }
+
+    writeln!(ctx, "For loop with range:")?;
+    for i in (1..=3).rev() {
+        writeln!(ctx, "Reverse: {}", i)?;
+    }
+    Ok(())
+}
+
+fn demo_match(ctx: &mut LessonContext) -> lesson::Result {
+    let number = 3;
+
+    match number {
+        1 => writeln!(ctx, "One")?,
+        2 => writeln!(ctx, "Two")?,
+        3 => writeln!(ctx, "Three")?,
+        4 | 5 => writeln!(ctx, "Four or Five")?,
+        6..=10 => writeln!(ctx, "Six through Ten")?,
+        _ => writeln!(ctx, "Something else")?,
+    }
+
+    let opt = Some(5);
+    match opt {
+        Some(x) => writeln!(ctx, "Got a value: {}", x)?,
+        None => writeln!(ctx, "Got nothing")?,
+    }
+    Ok(())
+}
+
+fn demo_if_let(ctx: &mut LessonContext) -> lesson::Result {
+    // ANCHOR: if_let
+    let some_value = Some(42u32);
+
+    // 使用 if let 简化 match
+    if let Some(x) = some_value {
+        writeln!(ctx, "if let matched: {}", x)?;
+    } else {
+        writeln!(ctx, "if let: no value")?;
+    }
+
+    let mut stack = vec![1, 2, 3];
+    while let Some(top) = stack.pop() {
+        writeln!(ctx, "Popped: {}", top)?;
+    }
+    // ANCHOR_END: if_let
+    Ok(())
+}
+
+fn check_fizzbuzz() {
+    assert_eq!(exercise::fizzbuzz(1), "1");
+    assert_eq!(exercise::fizzbuzz(9), "Fizz");
+    assert_eq!(exercise::fizzbuzz(10), "Buzz");
+    assert_eq!(exercise::fizzbuzz(30), "FizzBuzz");
+}
+
+fn check_count_down() {
+    assert_eq!(exercise::count_down(3), [3, 2, 1]);
+    assert!(exercise::count_down(0).is_empty());
+}
+
+fn check_first_negative() {
+    assert_eq!(exercise::first_negative(&[1, 2, -3, -4]), Some(2));
+    assert_eq!(exercise::first_negative(&[1]), None);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_if_expression() {
+        let result = if true { 5 } else { 10 };
+        assert_eq!(result, 5);
+
+        let result = if false { "yes" } else { "no" };
+        assert_eq!(result, "no");
+    }
+
+    #[test]
+    fn test_loop_return() {
+        let mut counter = 0;
+        let result = loop {
+            counter += 1;
+            if counter >= 5 {
+                break counter * 2;
+            }
+        };
+        assert_eq!(result, 10);
+    }
+
+    #[test]
+    fn test_while_loop() {
+        let mut count = 0;
+        let mut i = 5;
+        while i > 0 {
+            count += 1;
+            i -= 1;
+        }
+        assert_eq!(count, 5);
+    }
+
+    #[test]
+    #[allow(clippy::manual_unwrap_or, clippy::manual_unwrap_or_default)]
+    fn test_match_all_cases() {
+        let x = Some(10);
+        let result = match x {
+            Some(v) => v,
+            None => 0,
+        };
+        assert_eq!(result, 10);
+    }
+
+    #[test]
+    fn test_if_let() {
+        let x = Some(5);
+        let mut result = 0;
+        if let Some(v) = x {
+            result = v;
+        }
+        assert_eq!(result, 5);
+    }
+}
@@ -0,0 +1,369 @@
+//! # Enums & Pattern Matching
+//!
+//! 目标：理解 Rust 中的枚举和模式匹配
+//!
+//! ## 要点
+//! - 枚举允许定义一个类型可以是多个变体之一
+//! - 每个变体可以包含不同类型的数据
+//! - `match` 是模式匹配的主要方式，必须穷尽所有情况
+//! - 可以使用 `Option<T>` 和 `Result<T, E>` 处理可能缺失或失败的情况
+//! - `if let` 是 match 的简写，用于只关心一种模式的情况
+//!
+//! ```rust
+//! enum Shape {
+//!     Circle(f64),
+//!     Rect { w: f64, h: f64 },
+//!     Dot,
+//! }
+//!
+//! fn area(shape: &Shape) -> f64 {
+//!     match shape {
+//!         Shape::Circle(r) => 3.0 * r * r,
+//!         Shape::Rect { w, h } => w * h,
+//!         Shape::Dot => 0.0,
+//!     }
+//! }
+//!
+//! assert_eq!(area(&Shape::Rect { w: 2.0, h: 3.0 }), 6.0);
+//! assert_eq!(area(&Shape::Circle(1.0)), 3.0);
+//! assert_eq!(area(&Shape::Dot), 0.0);
+//!
+//! let parsed: Result<i32, _> = "42".parse::<i32>();
+//! if let Ok(n) = parsed {
+//!     assert_eq!(n, 42);
+//! }
+//! let missing: Option<&i32> = [1, 2].get(5);
+//! assert_eq!(missing, None);
+//! ```
+//!
+//! ## 常见坑
+//! - match 必须包含所有情况或使用通配符 `_`
+//! - 忘记处理 `Option::None` 或 `Result::Err`
+//! - 模式匹配的顺序很重要
+//!
+//! ## 运行
+//! `cargo run -- 10_enums_matching`
+//!
+//! ## 示例
+//!
+//! 由 `cargo run -- update-doctests` 从演示代码中标出的片段生成，`cargo test --doc` 会运行它们。
+//!
+//! `multi_branch`：
+//!
+//! ```rust
+//! let value = 42;
+//!
+//! let category = match value {
+//!     0 => "zero",
+//!     1..=10 => "small",
+//!     11..=100 => "medium",
+//!     101..=1000 => "large",
+//!     _ => "huge",
+//! };
+//! println!("{} is {}", value, category);
+//! ```
+
+use crate::exercises::enums_matching as exercise;
+use crate::lesson::{self, Check, Koan, LessonContext, Pitfall, Question, Section};
+use std::io::{self, Write};
+
+#[derive(Debug)]
+enum IpAddrKind {
+    V4,
+    V6,
+}
+
+#[derive(Debug)]
+enum IpAddr {
+    V4(u8, u8, u8, u8),
+    V6(String),
+}
+
+#[derive(Debug)]
+enum Message {
+    Quit,
+    Move { x: i32, y: i32 },
+    Write(String),
+    ChangeColor(i32, i32, i32),
+}
+
+pub const SECTIONS: &[Section] = &[
+    Section::new("basic_enums", "基本枚举", demo_basic_enums),
+    Section::new("enums_with_data", "带数据的枚举", demo_enums_with_data),
+    Section::new("option_enum", "Option 枚举", demo_option_enum),
+    Section::new("pattern_matching", "模式匹配", demo_pattern_matching),
+    Section::new("multi_branch", "多分支匹配", demo_multi_branch),
+];
+
+pub const QUIZ: &[Question] = &[
+    Question::new(
+        "关于枚举变体，哪项说法正确？",
+        &[
+            "所有变体必须携带相同类型的数据",
+            "每个变体可以携带不同类型与数量的数据",
+            "变体不能携带数据",
+            "变体必须是整数",
+        ],
+        1,
+        "例如 `Message::Move { x, y }` 和 `Message::Write(String)` 可以出现在同一个枚举中。",
+    ),
+    Question::new(
+        "只关心 `Some` 的情况时，最简洁的写法是？",
+        &[
+            "`match` 并写满所有分支",
+            "`if let Some(x) = opt { ... }`",
+            "`opt.unwrap()`",
+            "`while opt { ... }`",
+        ],
+        1,
+        "`if let` 是只匹配一种模式的简写，其余情况直接忽略。",
+    ),
+    Question::new(
+        "`match` 中把 `_ => ...` 写在第一个分支会怎样？",
+        &[
+            "它会匹配所有值，后面的分支永远不会执行",
+            "编译器自动把它移到最后",
+            "编译错误",
+            "只匹配剩余的值",
+        ],
+        0,
+        "分支按顺序匹配，通配符放在前面会吞掉所有情况，编译器会给出 unreachable pattern 警告。",
+    ),
+    Question::new(
+        "`Option<T>` 相比空指针的好处是？",
+        &[
+            "会自动转换成 0",
+            "运行更快",
+            "“可能没有值”写在类型里，编译器强制先处理 `None` 再使用值",
+            "可以直接当作 `T` 使用",
+        ],
+        2,
+        "`Option<T>` 与 `T` 是不同的类型，取值前必须用 `match`、`if let` 等处理 `None` 的情况。",
+    ),
+];
+
+pub const EXERCISE: &[Check] = &[
+    Check::new("next", check_next),
+    Check::new("duration", check_duration),
+    Check::new("describe", check_describe),
+];
+
+pub const HINTS: &[&str] = &[
+    "`match` 必须覆盖枚举的每个变体；`Option` 的变体也可以在模式中带上具体的值和守卫条件。",
+    "`next` 与 `duration` 各写一个三分支的 `match light`；`describe` 按 `None`、`Some(0)`、正数、负数的顺序匹配。",
+    "`Some(x) if x > 0 => \"positive\"` 是带守卫的分支，放在它之后的 `Some(_)` 就只剩负数。",
+];
+
+pub const KOANS: &[Koan] = &[
+    Koan::new(
+        "match_enum",
+        r#"enum Coin {
+    Penny,
+    Nickel,
+    Dime,
+    Quarter,
+}
+
+fn value_in_cents(coin: Coin) -> u32 {
+    match coin {
+        Coin::Penny => 1,
+        Coin::Nickel => 5,
+        Coin::Dime => 10,
+        __ => 25,
+    }
+}
+
+#[test]
+fn match_enum() {
+    assert_eq!(value_in_cents(Coin::Dime), 10);
+    assert_eq!(value_in_cents(Coin::Quarter), 25);
+}
+"#,
+        &["Coin::Quarter"],
+    ),
+    Koan::new(
+        "option_match",
+        r#"fn plus_one(x: Option<i32>) -> Option<i32> {
+    match x {
+        None => __,
+        Some(i) => Some(i + 1),
+    }
+}
+
+#[test]
+fn option_match() {
+    assert_eq!(plus_one(Some(5)), Some(6));
+    assert_eq!(plus_one(None), None);
+}
+"#,
+        &["None"],
+    ),
+];
+
+pub const PITFALLS: &[Pitfall] = &[Pitfall::new(
+    "non_exhaustive_match",
+    include_str!("../pitfalls/enums_matching/non_exhaustive_match.rs"),
+    "`match` 必须覆盖所有可能的值，漏掉 `Direction::West` 会报 E0004。补上缺少的分支，或者确实不关心时用 `_` 通配。给枚举新增变体后，编译器会借这条规则指出所有需要更新的 `match`。",
+).error("E0004")];
+
+pub fn run(ctx: &mut LessonContext) -> lesson::Result {
+    lesson::run_sections(ctx, SECTIONS)
+}
+
+fn demo_basic_enums(ctx: &mut LessonContext) -> lesson::Result {
+    let four = IpAddrKind::V4;
+    let six = IpAddrKind::V6;
+
+    writeln!(ctx, "IPv4: {:?}", four)?;
+    writeln!(ctx, "IPv6: {:?}", six)?;
+    Ok(())
+}
+
+fn demo_enums_with_data(ctx: &mut LessonContext) -> lesson::Result {
+    let home = IpAddr::V4(127, 0, 0, 1);
+    let loopback = IpAddr::V6(String::from("::1"));
+
+    writeln!(ctx, "Home: {:?}", home)?;
+    writeln!(ctx, "Loopback: {:?}", loopback)?;
+
+    let msg1 = Message::Write(String::from("hello"));
+    let msg2 = Message::Move { x: 10, y: 20 };
+
+    writeln!(ctx, "Message 1: {:?}", msg1)?;
+    writeln!(ctx, "Message 2: {:?}", msg2)?;
+    Ok(())
+}
+
+#[allow(clippy::unnecessary_literal_unwrap)]
+fn demo_option_enum(ctx: &mut LessonContext) -> lesson::Result {
+    let some_number = Some(5);
+    let some_string = Some(String::from("hello"));
+    let absent_number: Option<i32> = None;
+
+    writeln!(ctx, "Some number: {:?}", some_number)?;
+    writeln!(ctx, "Some string: {:?}", some_string)?;
+    writeln!(ctx, "Absent: {:?}", absent_number)?;
+
+    let x = 5;
+    let y = some_number.unwrap_or(0);
+    writeln!(ctx, "x + y = {}", x + y)?;
+    Ok(())
+}
+
+fn demo_pattern_matching(ctx: &mut LessonContext) -> lesson::Result {
+    let msg = Message::ChangeColor(255, 128, 0);
+
+    match msg {
+        Message::Quit => writeln!(ctx, "Quit message")?,
+        Message::Move { x, y } => writeln!(ctx, "Move to ({}, {})", x, y)?,
+        Message::Write(text) => writeln!(ctx, "Write: {}", text)?,
+        Message::ChangeColor(r, g, b) => writeln!(ctx, "Change color to RGB({}, {}, {})", r, g, b)?,
+    }
+
+    let some_value = Some(42);
+    match some_value {
+        Some(x) if x > 40 => writeln!(ctx, "Large number: {}", x)?,
+        Some(x) => writeln!(ctx, "Number: {}", x)?,
+        None => writeln!(ctx, "No value")?,
+    }
+    Ok(())
+}
+
+fn demo_multi_branch(ctx: &mut LessonContext) -> lesson::Result {
+    // ANCHOR: multi_branch
+    let value = 42;
+
+    let category = match value {
+        0 => "zero",
+        1..=10 => "small",
+        11..=100 => "medium",
+        101..=1000 => "large",
+        _ => "huge",
+    };
+    writeln!(ctx, "{} is {}", value, category)?;
+    // ANCHOR_END: multi_branch
+
+    let ip = IpAddr::V4(192, 168, 1, 1);
+    let kind = match ip {
+        IpAddr::V4(_, _, _, _) => "IPv4",
+        IpAddr::V6(_) => "IPv6",
+    };
+    writeln!(ctx, "IP address is {}", kind)?;
+    Ok(())
+}
+
+fn check_next() {
+    use exercise::Light;
+    assert_eq!(exercise::next(Light::Red), Light::Green);
+    assert_eq!(exercise::next(Light::Green), Light::Yellow);
+    assert_eq!(exercise::next(Light::Yellow), Light::Red);
+}
+
+fn check_duration() {
+    use exercise::Light;
+    let total: u32 = [Light::Red, Light::Yellow, Light::Green]
+        .into_iter()
+        .map(exercise::duration)
+        .sum();
+    assert_eq!(exercise::duration(Light::Yellow), 5);
+    assert_eq!(total, 110);
+}
+
+fn check_describe() {
+    assert_eq!(exercise::describe(None), "none");
+    assert_eq!(exercise::describe(Some(0)), "zero");
+    assert_eq!(exercise::describe(Some(7)), "positive");
+    assert_eq!(exercise::describe(Some(-7)), "negative");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_enum_creation() {
+        let kind = IpAddrKind::V4;
+        assert!(matches!(kind, IpAddrKind::V4));
+    }
+
+    #[test]
+    fn test_enum_with_data() {
+        let ip = IpAddr::V4(127, 0, 0, 1);
+        if let IpAddr::V4(a, b, c, d) = ip {
+            assert_eq!(a, 127);
+            assert_eq!(b, 0);
+            assert_eq!(c, 0);
+            assert_eq!(d, 1);
+        }
+    }
+
+    #[test]
+    #[allow(clippy::unnecessary_literal_unwrap)]
+    fn test_option() {
+        let some_value = Some(10);
+        assert_eq!(some_value.unwrap(), 10);
+
+        let none_value: Option<i32> = None;
+        assert_eq!(none_value.unwrap_or(0), 0);
+    }
+
+    #[test]
+    fn test_pattern_matching() {
+        let msg = Message::Write(String::from("test"));
+        if let Message::Write(text) = msg {
+            assert_eq!(text, "test");
+        } else {
+            panic!("Pattern match failed");
+        }
+    }
+
+    #[test]
+    fn test_match_exhaustive() {
+        let value = Some(42);
+        let result = match value {
+            Some(x) => x * 2,
+            None => 0,
+        };
+        assert_eq!(result, 84);
+    }
+}
@@ -0,0 +1,466 @@
+//! # Error Handling: Result / Option / ? operator
+//!
+//! 目标：掌握 Rust 的错误处理机制
+//!
+//! ## 要点
+//! - Rust 使用 `Result<T, E>` 处理可恢复错误
+//! - `Option<T>` 处理可能为空的值
+//! - `?` 运算符简化错误传播
+//! - 可以使用 `unwrap()`、`expect()` 处理错误，但可能 panic
+//! - 自定义错误类型实现 `Error` trait
+//! - 为外层错误类型实现 `From`，`?` 就能自动完成转换（本课程的 lesson 框架就是这样做的）
+//!
+//! ```rust
+//! use std::fmt;
+//! use std::num::ParseIntError;
+//!
+//! #[derive(Debug)]
+//! enum AppError {
+//!     Parse(ParseIntError),
+//! }
+//!
+//! impl fmt::Display for AppError {
+//!     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+//!         match self {
+//!             AppError::Parse(e) => write!(f, "invalid number: {}", e),
+//!         }
+//!     }
+//! }
+//!
+//! impl std::error::Error for AppError {}
+//!
+//! // 有了 From，`?` 会把 ParseIntError 自动转换成 AppError
+//! impl From<ParseIntError> for AppError {
+//!     fn from(e: ParseIntError) -> Self {
+//!         AppError::Parse(e)
+//!     }
+//! }
+//!
+//! fn double(s: &str) -> Result<i32, AppError> {
+//!     let n: i32 = s.trim().parse()?;
+//!     Ok(n * 2)
+//! }
+//!
+//! assert_eq!(double(" 21 ").unwrap(), 42);
+//! let err = double("x").unwrap_err();
+//! assert_eq!(err.to_string(), "invalid number: invalid digit found in string");
+//! ```
+//!
+//! ## 常见坑
+//! - 在不应 panic 的地方使用 unwrap
+//! - 忘记处理 Err 或 None 情况
+//! - 错误类型转换不当
+//!
+//! ## 运行
+//! `cargo run -- 17_error_handling`
+//!
+//! ## 示例
+//!
+//! 由 `cargo run -- update-doctests` 从演示代码中标出的片段生成，`cargo test --doc` 会运行它们。
+//!
+//! `result`：
+//!
+//! ```rust
+//! fn sqrt(x: f64) -> Result<f64, String> {
+//!     if x >= 0.0 {
+//!         Ok(x.sqrt())
+//!     } else {
+//!         Err(String::from("Cannot take square root of negative number"))
+//!     }
+//! }
+//!
+//! match sqrt(16.0) {
+//!     Ok(result) => println!("sqrt(16) = {}", result),
+//!     Err(e) => println!("Error: {}", e),
+//! }
+//!
+//! match sqrt(-4.0) {
+//!     Ok(result) => println!("sqrt(-4) = {}", result),
+//!     Err(e) => println!("Error: {}", e),
+//! }
+//! ```
+
+use crate::exercises::error_handling as exercise;
+use crate::lesson::{self, Check, Koan, LessonContext, LessonError, Pitfall, Question, Section};
+use std::fs::File;
+use std::io::{self, Read, Write};
+use std::num::ParseIntError;
+use std::{error, fmt};
+
+pub const SECTIONS: &[Section] = &[
+    Section::new("option", "Option 类型", demo_option),
+    Section::new("result", "Result 类型", demo_result),
+    Section::new("question_operator", "? 运算符", demo_question_operator),
+    Section::new("custom_error", "自定义错误类型", demo_custom_error),
+    Section::new(
+        "framework_errors",
+        "在框架中传播错误",
+        demo_framework_errors,
+    ),
+];
+
+pub const QUIZ: &[Question] = &[
+    Question::new(
+        "`?` 运算符遇到 `Err(e)` 时做什么？",
+        &[
+            "panic",
+            "忽略错误继续执行",
+            "提前返回 `Err(From::from(e))`",
+            "返回默认值",
+        ],
+        2,
+        "`?` 会把错误转换成函数的错误类型后提前返回，所以外层类型实现 `From` 就能自动转换。",
+    ),
+    Question::new(
+        "在 `fn main()`（返回 `()`）里使用 `?` 会怎样？",
+        &[
+            "编译错误，函数必须返回 Result 或 Option",
+            "自动 unwrap",
+            "运行时 panic",
+            "错误被忽略",
+        ],
+        0,
+        "可以把 main 声明为 `fn main() -> Result<(), Box<dyn Error>>`。",
+    ),
+    Question::new(
+        "什么时候适合用 `unwrap()`？",
+        &[
+            "处理用户输入时",
+            "读取文件时",
+            "确定不会失败的地方，例如测试或示例代码",
+            "任何时候",
+        ],
+        2,
+        "`unwrap` 失败会 panic；可恢复的错误应当用 `Result` 传播，或用 `expect` 说明原因。",
+    ),
+    Question::new(
+        "`\"42\".parse::<i32>()` 返回什么？",
+        &[
+            "`i32`",
+            "`Option<i32>`",
+            "解析失败时会 panic 的 `i32`",
+            "`Result<i32, ParseIntError>`",
+        ],
+        3,
+        "`parse` 可能失败，所以返回 `Result`，错误类型是 `ParseIntError`，由调用方决定如何处理。",
+    ),
+];
+
+pub const EXERCISE: &[Check] = &[
+    Check::new("parse_sum", check_parse_sum),
+    Check::new("divide", check_divide),
+    Check::new("parse_age", check_parse_age),
+];
+
+pub const HINTS: &[&str] = &[
+    "`?` 在遇到 `Err` 时提前返回它；`map_err` 可以把一种错误转换为另一种，比如 `String`。",
+    "`parse_sum` 对两个参数分别 `trim().parse::<i32>()?` 后相加，再包进 `Ok`；`divide` 先判断除数；`parse_age` 先解析为 `u8`，再检查范围。",
+    "`let age: u8 = s.trim().parse().map_err(|e| format!(\"invalid age '{}': {}\", s, e))?;` 之后 `age > 150` 时返回 `Err`。",
+];
+
+pub const KOANS: &[Koan] = &[
+    Koan::new(
+        "question_mark",
+        r#"fn double(text: &str) -> Result<i32, std::num::ParseIntError> {
+    let n: i32 = text.trim().parse()__;
+    Ok(n * 2)
+}
+
+#[test]
+fn question_mark() {
+    assert_eq!(double(" 21 "), Ok(42));
+    assert!(double("abc").is_err());
+}
+"#,
+        &["?"],
+    ),
+    Koan::new(
+        "option_combinators",
+        r#"#[test]
+fn option_combinators() {
+    let some: Option<i32> = Some(3);
+    let none: Option<i32> = None;
+    assert_eq!(some.map(|x| x * 2), __);
+    assert_eq!(none.unwrap_or(__), 7);
+    assert_eq!("300".parse::<u8>().is_ok(), __);
+}
+"#,
+        &["Some(6)", "7", "false"],
+    ),
+];
+
+pub const PITFALLS: &[Pitfall] = &[Pitfall::new(
+    "question_mark_in_main",
+    include_str!("../pitfalls/error_handling/question_mark_in_main.rs"),
+    "`?` 遇到错误时会把它从当前函数返回，因此只能用在返回 `Result` 或 `Option` 的函数中，返回 `()` 的 `main` 不行（E0277）。让 `main` 返回 `Result<(), Box<dyn Error>>`，末尾写 `Ok(())`。",
+).error("E0277")];
+
+pub fn run(ctx: &mut LessonContext) -> lesson::Result {
+    lesson::run_sections(ctx, SECTIONS)
+}
+
+fn demo_option(ctx: &mut LessonContext) -> lesson::Result {
+    fn divide(numerator: f64, denominator: f64) -> Option<f64> {
+        if denominator == 0.0 {
+            None
+        } else {
+            Some(numerator / denominator)
+        }
+    }
+
+    let result = divide(10.0, 2.0);
+    match result {
+        Some(x) => writeln!(ctx, "10 / 2 = {}", x)?,
+        None => writeln!(ctx, "Cannot divide by zero")?,
+    }
+
+    if let Some(x) = divide(10.0, 0.0) {
+        writeln!(ctx, "Result: {}", x)?;
+    } else {
+        writeln!(ctx, "Division failed")?;
+    }
+
+    let x = result.unwrap_or(0.0);
+    writeln!(ctx, "Unwrap or default: {}", x)?;
+    Ok(())
+}
+
+fn demo_result(ctx: &mut LessonContext) -> lesson::Result {
+    // ANCHOR: result
+    fn sqrt(x: f64) -> Result<f64, String> {
+        if x >= 0.0 {
+            Ok(x.sqrt())
+        } else {
+            Err(String::from("Cannot take square root of negative number"))
+        }
+    }
+
+    match sqrt(16.0) {
+        Ok(result) => writeln!(ctx, "sqrt(16) = {}", result)?,
+        Err(e) => writeln!(ctx, "Error: {}", e)?,
+    }
+
+    match sqrt(-4.0) {
+        Ok(result) => writeln!(ctx, "sqrt(-4) = {}", result)?,
+        Err(e) => writeln!(ctx, "Error: {}", e)?,
+    }
+    // ANCHOR_END: result
+
+    let content = read_file_content("test.txt");
+    match content {
+        Ok(text) => writeln!(
+            ctx,
+            "File content (first 50 chars): {}",
+            &text[..text.len().min(50)]
+        )?,
+        Err(e) => writeln!(ctx, "Failed to read file: {}", e)?,
+    }
+    Ok(())
+}
+
+fn read_file_content(path: &str) -> Result<String, io::Error> {
+    let mut file = File::open(path)?;
+    let mut content = String::new();
+    file.read_to_string(&mut content)?;
+    Ok(content)
+}
+
+fn demo_question_operator(ctx: &mut LessonContext) -> lesson::Result {
+    fn parse_and_double(s: &str) -> Result<i32, ParseIntError> {
+        let num = s.parse::<i32>()?;
+        Ok(num * 2)
+    }
+
+    match parse_and_double("42") {
+        Ok(result) => writeln!(ctx, "Double of 42: {}", result)?,
+        Err(e) => writeln!(ctx, "Parse error: {}", e)?,
+    }
+
+    match parse_and_double("not a number") {
+        Ok(result) => writeln!(ctx, "Result: {}", result)?,
+        Err(e) => writeln!(ctx, "Parse error: {}", e)?,
+    }
+
+    fn read_and_parse() -> Result<i32, Box<dyn error::Error>> {
+        // ? 可以用于不同类型的错误，自动转换
+        let content = read_file_content("test.txt");
+        match content {
+            Ok(s) => {
+                let num = s.trim().parse()?;
+                Ok(num)
+            }
+            Err(_) => Ok(0),
+        }
+    }
+
+    match read_and_parse() {
+        Ok(num) => writeln!(ctx, "Parsed number: {}", num)?,
+        Err(e) => writeln!(ctx, "Error: {}", e)?,
+    }
+    Ok(())
+}
+
+#[derive(Debug)]
+enum AppError {
+    FileNotFound(String),
+    ParseError(String),
+    InvalidInput(String),
+}
+
+impl fmt::Display for AppError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AppError::FileNotFound(path) => write!(f, "File not found: {}", path),
+            AppError::ParseError(msg) => write!(f, "Parse error: {}", msg),
+            AppError::InvalidInput(msg) => write!(f, "Invalid input: {}", msg),
+        }
+    }
+}
+
+impl error::Error for AppError {}
+
+fn demo_custom_error(ctx: &mut LessonContext) -> lesson::Result {
+    fn divide_and_validate(a: i32, b: i32) -> Result<i32, AppError> {
+        if b == 0 {
+            return Err(AppError::InvalidInput(String::from(
+                "Cannot divide by zero",
+            )));
+        }
+        Ok(a / b)
+    }
+
+    fn process_number(s: &str) -> Result<i32, AppError> {
+        s.parse::<i32>()
+            .map_err(|e| AppError::ParseError(e.to_string()))
+            .and_then(|n| divide_and_validate(n, 2))
+    }
+
+    match process_number("42") {
+        Ok(result) => writeln!(ctx, "42 / 2 = {}", result)?,
+        Err(e) => writeln!(ctx, "Error: {}", e)?,
+    }
+
+    match process_number("not a number") {
+        Ok(result) => writeln!(ctx, "Result: {}", result)?,
+        Err(e) => writeln!(ctx, "Error: {}", e)?,
+    }
+
+    match divide_and_validate(10, 0) {
+        Ok(result) => writeln!(ctx, "Result: {}", result)?,
+        Err(e) => writeln!(ctx, "Error: {}", e)?,
+    }
+
+    let result = divide_and_validate(100, 4);
+    if let Ok(value) = result {
+        writeln!(ctx, "100 / 4 = {}", value)?;
+    }
+
+    let result = divide_and_validate(100, 0);
+    if let Err(e) = result {
+        writeln!(ctx, "Error occurred: {}", e)?;
+    }
+    Ok(())
+}
+
+// 有了这个 From 实现，返回 AppError 的函数可以在 lesson 中直接用 `?`
+impl From<AppError> for LessonError {
+    fn from(err: AppError) -> Self {
+        LessonError::other(err)
+    }
+}
+
+/// 每个 lesson 的 `run` 都返回 `Result<(), LessonError>`：
+/// 写输出失败（`io::Error`）和演示中的 `AppError` 都经由 `From` 转换后向上传播，
+/// 最终由 runner 统一报告为 `Lesson '17_error_handling' failed: ...`
+fn demo_framework_errors(ctx: &mut LessonContext) -> lesson::Result {
+    fn parse_positive(s: &str) -> lesson::Result<i32> {
+        let n: i32 = s
+            .trim()
+            .parse()
+            .map_err(|e: ParseIntError| AppError::ParseError(e.to_string()))?;
+        if n <= 0 {
+            return Err(AppError::InvalidInput(format!("{} is not positive", n)).into());
+        }
+        Ok(n)
+    }
+
+    // 成功时 ? 取出值，失败时整个 lesson 会以这个错误结束
+    let minutes = parse_positive("25")?;
+    writeln!(ctx, "Lesson length: {} min", minutes)?;
+
+    // 这里只演示错误的样子，不让 lesson 真的失败
+    for input in ["abc", "-3"] {
+        if let Err(e) = parse_positive(input) {
+            writeln!(ctx, "{:?} -> {}", input, e.report())?;
+        }
+    }
+    Ok(())
+}
+
+fn check_parse_sum() {
+    assert_eq!(exercise::parse_sum("2", " 40"), Ok(42));
+    assert!(exercise::parse_sum("2", "x").is_err());
+}
+
+fn check_divide() {
+    assert_eq!(exercise::divide(7, 2), Ok(3));
+    assert_eq!(
+        exercise::divide(1, 0),
+        Err(String::from("division by zero"))
+    );
+}
+
+fn check_parse_age() {
+    assert_eq!(exercise::parse_age("42"), Ok(42));
+    for bad in ["abc", "-1", "200", "300"] {
+        assert!(exercise::parse_age(bad).is_err(), "parse_age({:?})", bad);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[allow(clippy::unnecessary_literal_unwrap)]
+    fn test_option_some() {
+        let x = Some(5);
+        assert_eq!(x.unwrap(), 5);
+    }
+
+    #[test]
+    #[allow(clippy::unnecessary_literal_unwrap)]
+    fn test_option_none() {
+        let x: Option<i32> = None;
+        assert_eq!(x.unwrap_or(0), 0);
+    }
+
+    #[test]
+    #[allow(clippy::unnecessary_literal_unwrap)]
+    fn test_result_ok() {
+        let x: Result<i32, &str> = Ok(42);
+        assert_eq!(x.unwrap(), 42);
+    }
+
+    #[test]
+    fn test_result_err() {
+        let x: Result<i32, &str> = Err("error");
+        assert!(x.is_err());
+    }
+
+    #[test]
+    fn test_framework_error_propagation() {
+        let mut out = Vec::new();
+        demo_framework_errors(&mut LessonContext::new(&mut out)).unwrap();
+        let out = String::from_utf8(out).unwrap();
+        assert!(out.contains("Lesson length: 25 min"));
+        assert!(out.contains("\"-3\" -> [error] Invalid input: -3 is not positive"));
+    }
+
+    #[test]
+    fn test_custom_error_display() {
+        let err = AppError::FileNotFound(String::from("test.txt"));
+        let err_str = format!("{}", err);
+        assert!(err_str.contains("File not found"));
+    }
+}
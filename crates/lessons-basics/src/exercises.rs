@@ -0,0 +1,23 @@
+//! 各 lesson 的练习：每个模块对应一个 lesson，函数体是等待补全的 `todo!()`
+//!
+//! 检查写在 lesson 模块的 `EXERCISE` 中，用 `cargo run -- exercise <lesson>` 运行。
+
+// 补全之前，练习函数的参数都还没有用到
+#![allow(unused_variables, clippy::new_without_default)]
+
+pub mod borrowing;
+pub mod collections;
+pub mod control_flow;
+pub mod enums_matching;
+pub mod error_handling;
+pub mod functions;
+pub mod generics;
+pub mod hello_world;
+pub mod iterators_closures;
+pub mod methods_assoc_fn;
+pub mod ownership;
+pub mod slices;
+pub mod structs;
+pub mod traits;
+pub mod types;
+pub mod variables;
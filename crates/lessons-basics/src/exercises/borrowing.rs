@@ -0,0 +1,19 @@
+//! # 练习：Borrowing & References
+//!
+//! 把每个 `todo!()` 换成实现，然后运行 `cargo run -- exercise borrowing` 检查。
+
+/// 所有单词的总长度（字节数），只借用 `words`
+pub fn total_len(words: &[String]) -> usize {
+    todo!()
+}
+
+/// 通过可变引用在字符串末尾追加 `", world"`
+#[allow(clippy::ptr_arg)]
+pub fn append_world(s: &mut String) {
+    todo!()
+}
+
+/// 通过可变引用把每个元素翻倍
+pub fn double_all(numbers: &mut [i32]) {
+    todo!()
+}
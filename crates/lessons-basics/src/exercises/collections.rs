@@ -0,0 +1,20 @@
+//! # 练习：Vec / String / HashMap
+//!
+//! 把每个 `todo!()` 换成实现，然后运行 `cargo run -- exercise collections` 检查。
+
+use std::collections::HashMap;
+
+/// 统计每个单词（按空白分隔）出现的次数
+pub fn word_count(text: &str) -> HashMap<String, usize> {
+    todo!()
+}
+
+/// 去掉重复元素并升序排列
+pub fn unique_sorted(numbers: Vec<i32>) -> Vec<i32> {
+    todo!()
+}
+
+/// 出现次数最多的字符，次数相同时取较小的字符；空字符串返回 `None`
+pub fn most_common_char(s: &str) -> Option<char> {
+    todo!()
+}
@@ -0,0 +1,19 @@
+//! # 练习：if / loop / while / match
+//!
+//! 把每个 `todo!()` 换成实现，然后运行 `cargo run -- exercise control_flow` 检查。
+
+/// FizzBuzz：3 的倍数返回 `"Fizz"`，5 的倍数返回 `"Buzz"`，同时是两者的倍数返回 `"FizzBuzz"`，
+/// 否则返回数字本身
+pub fn fizzbuzz(n: u32) -> String {
+    todo!()
+}
+
+/// 从 `n` 倒数到 1，例如 `count_down(3)` 返回 `[3, 2, 1]`
+pub fn count_down(n: u32) -> Vec<u32> {
+    todo!()
+}
+
+/// 第一个负数的下标，没有负数时返回 `None`
+pub fn first_negative(numbers: &[i32]) -> Option<usize> {
+    todo!()
+}
@@ -0,0 +1,26 @@
+//! # 练习：Enums & Pattern Matching
+//!
+//! 把每个 `todo!()` 换成实现，然后运行 `cargo run -- exercise enums_matching` 检查。
+
+/// 交通信号灯
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Light {
+    Red,
+    Yellow,
+    Green,
+}
+
+/// 下一个信号：红 → 绿 → 黄 → 红
+pub fn next(light: Light) -> Light {
+    todo!()
+}
+
+/// 每种信号持续的秒数：红 60、黄 5、绿 45
+pub fn duration(light: Light) -> u32 {
+    todo!()
+}
+
+/// 描述一个可能缺失的数：`"none"`、`"zero"`、`"positive"` 或 `"negative"`
+pub fn describe(n: Option<i32>) -> &'static str {
+    todo!()
+}
@@ -0,0 +1,20 @@
+//! # 练习：Result / Option / ? operator
+//!
+//! 把每个 `todo!()` 换成实现，然后运行 `cargo run -- exercise error_handling` 检查。
+
+use std::num::ParseIntError;
+
+/// 把两个字符串解析为整数后相加，用 `?` 传递解析错误
+pub fn parse_sum(a: &str, b: &str) -> Result<i32, ParseIntError> {
+    todo!()
+}
+
+/// 整数除法，除数为 0 时返回错误 `"division by zero"`
+pub fn divide(a: i32, b: i32) -> Result<i32, String> {
+    todo!()
+}
+
+/// 解析年龄：必须是 0 到 150 之间的整数，否则返回说明原因的错误
+pub fn parse_age(s: &str) -> Result<u8, String> {
+    todo!()
+}
@@ -0,0 +1,18 @@
+//! # 练习：Functions & Parameters
+//!
+//! 把每个 `todo!()` 换成实现，然后运行 `cargo run -- exercise functions` 检查。
+
+/// 返回 `x` 的平方
+pub fn square(x: i32) -> i32 {
+    todo!()
+}
+
+/// 返回三个数中的最大值
+pub fn max_of_three(a: i32, b: i32, c: i32) -> i32 {
+    todo!()
+}
+
+/// `n` 是否为偶数
+pub fn is_even(n: i32) -> bool {
+    todo!()
+}
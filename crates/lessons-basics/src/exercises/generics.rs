@@ -0,0 +1,26 @@
+//! # 练习：Generics
+//!
+//! 把每个 `todo!()` 换成实现，然后运行 `cargo run -- exercise generics` 检查。
+
+/// 切片中的最大值；切片为空时返回 `None`
+pub fn largest<T: PartialOrd + Copy>(items: &[T]) -> Option<T> {
+    todo!()
+}
+
+/// 切片中是否包含 `target`
+pub fn contains<T: PartialEq>(items: &[T], target: &T) -> bool {
+    todo!()
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Pair<T> {
+    pub first: T,
+    pub second: T,
+}
+
+impl<T> Pair<T> {
+    /// 交换两个值
+    pub fn swap(self) -> Pair<T> {
+        todo!()
+    }
+}
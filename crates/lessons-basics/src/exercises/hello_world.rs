@@ -0,0 +1,13 @@
+//! # 练习：Hello, world & Project Layout
+//!
+//! 把每个 `todo!()` 换成实现，然后运行 `cargo run -- exercise hello_world` 检查。
+
+/// 返回问候语，例如 `greeting("Ferris")` 返回 `"Hello, Ferris!"`
+pub fn greeting(name: &str) -> String {
+    todo!()
+}
+
+/// 返回 `lines` 行 `Hello, world!`，行与行之间用 `\n` 分隔，末尾没有换行
+pub fn repeat_hello(lines: usize) -> String {
+    todo!()
+}
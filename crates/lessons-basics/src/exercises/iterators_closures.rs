@@ -0,0 +1,18 @@
+//! # 练习：Iterators & Closures
+//!
+//! 把每个 `todo!()` 换成实现，然后运行 `cargo run -- exercise iterators_closures` 检查。
+
+/// 所有偶数的平方，保持原来的顺序
+pub fn squares_of_evens(numbers: &[i32]) -> Vec<i32> {
+    todo!()
+}
+
+/// 返回一个把参数加上 `n` 的闭包
+pub fn make_adder(n: i32) -> Box<dyn Fn(i32) -> i32> {
+    todo!()
+}
+
+/// 长度（按字符计）不小于 `min_len` 的单词数，单词按空白分隔
+pub fn count_long_words(text: &str, min_len: usize) -> usize {
+    todo!()
+}
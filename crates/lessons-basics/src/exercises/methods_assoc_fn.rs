@@ -0,0 +1,30 @@
+//! # 练习：Methods & Associated Fns
+//!
+//! 把每个 `todo!()` 换成实现，然后运行 `cargo run -- exercise methods_assoc_fn` 检查。
+
+#[derive(Debug)]
+pub struct Counter {
+    count: u32,
+}
+
+impl Counter {
+    /// 关联函数：从 0 开始计数
+    pub fn new() -> Self {
+        todo!()
+    }
+
+    /// 关联函数：从 `start` 开始计数
+    pub fn starting_at(start: u32) -> Self {
+        todo!()
+    }
+
+    /// 计数加一
+    pub fn increment(&mut self) {
+        todo!()
+    }
+
+    /// 当前计数
+    pub fn get(&self) -> u32 {
+        todo!()
+    }
+}
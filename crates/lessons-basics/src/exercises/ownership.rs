@@ -0,0 +1,19 @@
+//! # 练习：Ownership Basics
+//!
+//! 把每个 `todo!()` 换成实现，然后运行 `cargo run -- exercise ownership` 检查。
+
+/// 取得 `s` 的所有权，在末尾加上 `!` 后交还
+pub fn exclaim(s: String) -> String {
+    todo!()
+}
+
+/// 取得两个字符串的所有权，返回较长的那个（一样长时返回 `a`）
+pub fn longer(a: String, b: String) -> String {
+    todo!()
+}
+
+/// 把 `words` 连成一个字符串，单词之间用空格分隔；调用者之后仍然要使用 `words`
+#[allow(clippy::ptr_arg)]
+pub fn join_words(words: &Vec<String>) -> String {
+    todo!()
+}
@@ -0,0 +1,18 @@
+//! # 练习：String & Array Slices
+//!
+//! 把每个 `todo!()` 换成实现，然后运行 `cargo run -- exercise slices` 检查。
+
+/// 第一个单词（以空格分隔）；没有空格时返回整个字符串
+pub fn first_word(s: &str) -> &str {
+    todo!()
+}
+
+/// 切片中所有元素的和
+pub fn sum(numbers: &[i32]) -> i32 {
+    todo!()
+}
+
+/// 去掉首尾各一个元素后的部分；元素少于两个时返回空切片
+pub fn middle(numbers: &[i32]) -> &[i32] {
+    todo!()
+}
@@ -0,0 +1,24 @@
+//! # 练习：Structs & Update Syntax
+//!
+//! 把每个 `todo!()` 换成实现，然后运行 `cargo run -- exercise structs` 检查。
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rectangle {
+    pub width: u32,
+    pub height: u32,
+}
+
+/// 矩形的面积
+pub fn area(rect: &Rectangle) -> u32 {
+    todo!()
+}
+
+/// 边长为 `size` 的正方形
+pub fn square(size: u32) -> Rectangle {
+    todo!()
+}
+
+/// 用结构体更新语法返回一个宽度改为 `width`、高度不变的矩形
+pub fn with_width(rect: Rectangle, width: u32) -> Rectangle {
+    todo!()
+}
@@ -0,0 +1,36 @@
+//! # 练习：Traits & Trait Bounds
+//!
+//! 把每个 `todo!()` 换成实现，然后运行 `cargo run -- exercise traits` 检查。
+
+/// 可以计算面积的形状
+pub trait Shape {
+    fn area(&self) -> f64;
+
+    /// 带默认实现的方法，实现者不必重写
+    fn describe(&self) -> String {
+        format!("shape with area {:.2}", self.area())
+    }
+}
+
+/// 正方形，字段是边长
+pub struct Square(pub f64);
+
+/// 圆，字段是半径
+pub struct Circle(pub f64);
+
+impl Shape for Square {
+    fn area(&self) -> f64 {
+        todo!()
+    }
+}
+
+impl Shape for Circle {
+    fn area(&self) -> f64 {
+        todo!()
+    }
+}
+
+/// 所有形状的面积之和
+pub fn total_area(shapes: &[Box<dyn Shape>]) -> f64 {
+    todo!()
+}
@@ -0,0 +1,18 @@
+//! # 练习：Scalar & Compound Types
+//!
+//! 把每个 `todo!()` 换成实现，然后运行 `cargo run -- exercise types` 检查。
+
+/// 摄氏度转华氏度：F = C × 9 / 5 + 32
+pub fn celsius_to_fahrenheit(celsius: f64) -> f64 {
+    todo!()
+}
+
+/// 两个 `u8` 相加，溢出时返回 `None`
+pub fn add_u8(a: u8, b: u8) -> Option<u8> {
+    todo!()
+}
+
+/// 返回字符串中的字符数（不是字节数）
+pub fn char_count(s: &str) -> usize {
+    todo!()
+}
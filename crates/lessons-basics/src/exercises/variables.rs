@@ -0,0 +1,18 @@
+//! # 练习：Variables & Mutability
+//!
+//! 把每个 `todo!()` 换成实现，然后运行 `cargo run -- exercise variables` 检查。
+
+/// 用可变变量累加，返回 `1 + 2 + ... + n`
+pub fn sum_to(n: u32) -> u32 {
+    todo!()
+}
+
+/// 交换元组中的两个值
+pub fn swap(pair: (i32, i32)) -> (i32, i32) {
+    todo!()
+}
+
+/// 用遮蔽把 `text` 解析为数字后再乘以 2，例如 `"21"` 得到 42；解析失败时返回 0
+pub fn parse_and_double(text: &str) -> i32 {
+    todo!()
+}
@@ -0,0 +1,277 @@
+//! # Functions & Parameters
+//!
+//! 目标：理解 Rust 中的函数定义、参数、返回值、语句与表达式
+//!
+//! ## 要点
+//! - 函数定义使用 `fn` 关键字
+//! - 参数必须声明类型
+//! - 函数体由一系列语句组成，最后一个是可选的表达式作为返回值
+//! - 使用 `return` 或隐式返回（不加分号）
+//! - 多个参数使用逗号分隔
+//!
+//! ```rust
+//! fn add(a: i32, b: i32) -> i32 {
+//!     a + b // 没有分号：最后这个表达式的值就是返回值
+//! }
+//!
+//! fn clamp(x: i32) -> i32 {
+//!     if x < 0 {
+//!         return 0; // 提前返回用 return
+//!     }
+//!     x
+//! }
+//!
+//! assert_eq!(add(2, 3), 5);
+//! assert_eq!(clamp(-5), 0);
+//! assert_eq!(clamp(5), 5);
+//! ```
+//!
+//! ## 常见坑
+//! - 误以为有分号的语句也能返回值
+//! - 忘记在参数后标注类型
+//! - 混淆语句（statement）和表达式（expression）
+//!
+//! ## 运行
+//! `cargo run -- 04_functions`
+//!
+//! ## 示例
+//!
+//! 由 `cargo run -- update-doctests` 从演示代码中标出的片段生成，`cargo test --doc` 会运行它们。
+//!
+//! `rectangle_area`：
+//!
+//! ```rust
+//! println!("Area of 5x3 rectangle: {}", rectangle_area(5, 3));
+//!
+//! fn rectangle_area(width: u32, height: u32) -> u32 {
+//!     width * height
+//! }
+//! ```
+
+use crate::exercises::functions as exercise;
+use crate::lesson::{self, Check, Koan, LessonContext, Pitfall, Question, Section};
+use std::io::{self, Write};
+
+pub const SECTIONS: &[Section] = &[
+    Section::new("basics", "函数基础", demo_basics),
+    Section::new("no_return", "无返回值函数", demo_no_return),
+    Section::new("multiple_params", "多参数函数", demo_multiple_params),
+];
+
+pub const QUIZ: &[Question] = &[
+    Question::new(
+        "`fn add(a: i32, b: i32) -> i32 { a + b; }` 为什么编译失败？",
+        &[
+            "参数名不能叫 a、b",
+            "`a + b;` 加了分号变成语句，函数返回 `()`",
+            "必须写 `return`",
+            "`+` 不能用于 i32",
+        ],
+        1,
+        "函数体最后的表达式作为返回值；加上分号就成了语句，返回值变成 `()`。",
+    ),
+    Question::new(
+        "函数参数为什么必须标注类型？",
+        &[
+            "编译器不支持类型推断",
+            "函数签名是接口契约，Rust 要求显式写出",
+            "只是代码风格约定",
+            "为了运行时检查",
+        ],
+        1,
+        "Rust 在函数内部推断类型，但签名必须显式标注，调用方与实现都依赖它。",
+    ),
+    Question::new(
+        "下面哪个是表达式？",
+        &[
+            "`let y = 6;`",
+            "`fn f() {}`",
+            "`{ let x = 3; x + 1 }`",
+            "`use std::io;`",
+        ],
+        2,
+        "代码块是表达式，值为最后一个不带分号的表达式；`let` 与项声明都是语句。",
+    ),
+    Question::new(
+        "`fn five() -> i32 { 5 }` 为什么不用写 `return`？",
+        &[
+            "函数体最后一个不带分号的表达式就是返回值",
+            "返回 `i32` 的函数可以省略返回值",
+            "`5` 会被忽略，返回默认值 0",
+            "只有 `main` 需要 `return`",
+        ],
+        0,
+        "块的值是它最后一个表达式的值；`return` 用于提前返回。",
+    ),
+];
+
+pub const EXERCISE: &[Check] = &[
+    Check::new("square", check_square),
+    Check::new("max_of_three", check_max_of_three),
+    Check::new("is_even", check_is_even),
+];
+
+pub const HINTS: &[&str] = &[
+    "函数体最后一个没有分号的表达式就是返回值，不需要写 `return`。",
+    "`max_of_three` 可以嵌套 `if`，也可以用整数的 `max` 方法；判断偶数用取余运算 `%`。",
+    "`x * x`、`a.max(b).max(c)`、`n % 2 == 0`（对负数同样成立）。",
+];
+
+pub const KOANS: &[Koan] = &[
+    Koan::new(
+        "expression_return",
+        r#"fn power(base: i32, exp: u32) -> i32 {
+    let mut result = 1;
+    for _ in 0..exp {
+        result *= base;
+    }
+    __
+}
+
+#[test]
+fn expression_return() {
+    assert_eq!(power(2, 10), 1024);
+}
+"#,
+        &["result"],
+    ),
+    Koan::new(
+        "block_expression",
+        r#"#[test]
+fn block_expression() {
+    let y = {
+        let x = 3;
+        x + 1
+    };
+    assert_eq!(y, __);
+
+    let unit = {
+        let x = 3;
+        let _ = x + 1;
+    };
+    assert_eq!(unit, __);
+}
+"#,
+        &["4", "()"],
+    ),
+];
+
+pub const PITFALLS: &[Pitfall] = &[Pitfall::new(
+    "semicolon_return",
+    include_str!("../pitfalls/functions/semicolon_return.rs"),
+    "函数体最后一个表达式就是返回值，加了分号就变成语句，值是 `()`，与声明的返回类型 `i32` 不符（E0308）。去掉分号，或者写 `return x + 1;`。",
+).error("E0308")];
+
+pub fn run(ctx: &mut LessonContext) -> lesson::Result {
+    lesson::run_sections(ctx, SECTIONS)
+}
+
+fn demo_basics(ctx: &mut LessonContext) -> lesson::Result {
+    greet(ctx, "Rust")?;
+    greet(ctx, "World")?;
+
+    let sum = add(5, 10);
+    writeln!(ctx, "5 + 10 = {}", sum)?;
+
+    writeln!(ctx, "5 * 3 = {}", multiply(5, 3))?;
+
+    writeln!(ctx, "2^3 = {}", power(2, 3))?;
+    Ok(())
+}
+
+fn demo_no_return(ctx: &mut LessonContext) -> lesson::Result {
+    print_message(ctx, "Hello from function!")?;
+    Ok(())
+}
+
+fn demo_multiple_params(ctx: &mut LessonContext) -> lesson::Result {
+    // ANCHOR: rectangle_area
+    writeln!(ctx, "Area of 5x3 rectangle: {}", rectangle_area(5, 3))?;
+    // ANCHOR_END: rectangle_area
+    Ok(())
+}
+
+fn greet(ctx: &mut LessonContext, name: &str) -> io::Result<()> {
+    writeln!(ctx, "Hello, {}!", name)?;
+    Ok(())
+}
+
+fn add(a: i32, b: i32) -> i32 {
+    a + b // 隐式返回（最后表达式无分号）
+}
+
+#[allow(clippy::needless_return)]
+fn multiply(x: i32, y: i32) -> i32 {
+    return x * y; // 显式返回
+}
+
+fn power(base: i32, exp: u32) -> i32 {
+    let mut result = 1;
+    for _ in 0..exp {
+        result *= base;
+    }
+    result
+}
+
+fn print_message(ctx: &mut LessonContext, msg: &str) -> io::Result<()> {
+    writeln!(ctx, "Message: {}", msg)?;
+    // 不产生有意义的值：成功时返回 Ok(())，只把写入错误交给调用者
+    Ok(())
+}
+
+// ANCHOR: rectangle_area
+fn rectangle_area(width: u32, height: u32) -> u32 {
+    width * height
+}
+// ANCHOR_END: rectangle_area
+
+fn check_square() {
+    assert_eq!(exercise::square(4), 16);
+    assert_eq!(exercise::square(-3), 9);
+}
+
+fn check_max_of_three() {
+    assert_eq!(exercise::max_of_three(1, 3, 2), 3);
+    assert_eq!(exercise::max_of_three(-1, -5, -2), -1);
+    assert_eq!(exercise::max_of_three(7, 7, 1), 7);
+}
+
+fn check_is_even() {
+    assert!(exercise::is_even(4));
+    assert!(exercise::is_even(0));
+    assert!(!exercise::is_even(-3));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_add() {
+        assert_eq!(add(2, 3), 5);
+        assert_eq!(add(-1, 1), 0);
+        assert_eq!(add(0, 0), 0);
+    }
+
+    #[test]
+    fn test_multiply() {
+        assert_eq!(multiply(3, 4), 12);
+        assert_eq!(multiply(0, 100), 0);
+        assert_eq!(multiply(-2, 5), -10);
+    }
+
+    #[test]
+    fn test_power() {
+        assert_eq!(power(2, 3), 8);
+        assert_eq!(power(5, 2), 25);
+        assert_eq!(power(10, 0), 1);
+        assert_eq!(power(2, 1), 2);
+    }
+
+    #[test]
+    fn test_rectangle_area() {
+        assert_eq!(rectangle_area(5, 3), 15);
+        assert_eq!(rectangle_area(10, 10), 100);
+        assert_eq!(rectangle_area(0, 5), 0);
+    }
+}
@@ -0,0 +1,438 @@
+//! # Generics
+//!
+//! 目标：理解 Rust 中的泛型编程
+//!
+//! ## 要点
+//! - 泛型允许编写可重用的代码，适用于多种类型
+//! - 在函数、结构体、枚举和方法中使用泛型
+//! - 编译器通过单态化（monomorphization）为具体类型生成代码
+//! - 使用 trait bounds 限制泛型类型可以实现的行为
+//!
+//! ```rust
+//! struct Pair<T> {
+//!     a: T,
+//!     b: T,
+//! }
+//!
+//! // trait bound：只有能比较大小、能复制的 T 才有 larger 方法
+//! impl<T: PartialOrd + Copy> Pair<T> {
+//!     fn larger(&self) -> T {
+//!         if self.a > self.b { self.a } else { self.b }
+//!     }
+//! }
+//!
+//! // 同一份代码，编译器分别为 i32 与 char 生成一份（单态化）
+//! assert_eq!(Pair { a: 3, b: 7 }.larger(), 7);
+//! assert_eq!(Pair { a: 'x', b: 'b' }.larger(), 'x');
+//!
+//! let none: Option<&str> = None; // 枚举也可以是泛型的
+//! assert!(none.is_none());
+//! ```
+//!
+//! ## 常见坑
+//! - 忘记添加必要的 trait bounds
+//! - 泛型代码可能导致编译后体积增大
+//! - 复杂的泛型签名难以阅读
+//!
+//! ## 运行
+//! `cargo run -- 12_generics`
+//!
+//! ## 示例
+//!
+//! 由 `cargo run -- update-doctests` 从演示代码中标出的片段生成，`cargo test --doc` 会运行它们。
+//!
+//! `sort_desc`：
+//!
+//! ```rust
+//! let int_list = vec![1, 2, 3, 4, 5];
+//! let float_list = vec![1.1, 2.2, 3.3];
+//!
+//! println!("Sorted ints: {:?}", sort_desc(int_list));
+//! println!("Sorted floats: {:?}", sort_desc(float_list));
+//!
+//! fn sort_desc<T: PartialOrd>(mut list: Vec<T>) -> Vec<T> {
+//!     list.sort_by(|a, b| b.partial_cmp(a).unwrap());
+//!     list
+//! }
+//! ```
+
+use crate::exercises::generics as exercise;
+use crate::lesson::{self, Check, Koan, LessonContext, Pitfall, Question, Section};
+use std::cmp::PartialOrd;
+use std::io::{self, Write};
+
+#[derive(Debug)]
+struct Point<T> {
+    x: T,
+    y: T,
+}
+
+#[derive(Debug)]
+struct Pair<T, U> {
+    first: T,
+    second: U,
+}
+
+#[derive(Debug)]
+enum Option<T> {
+    Some(T),
+    None,
+}
+
+#[derive(Debug)]
+enum Result<T, E> {
+    Ok(T),
+    Err(E),
+}
+
+impl<T> Point<T> {
+    fn x(&self) -> &T {
+        &self.x
+    }
+
+    fn y(&self) -> &T {
+        &self.y
+    }
+}
+
+impl<T, U> Pair<T, U> {
+    fn new(first: T, second: U) -> Self {
+        Pair { first, second }
+    }
+}
+
+pub const SECTIONS: &[Section] = &[
+    Section::new("generic_functions", "泛型函数", demo_generic_functions),
+    Section::new("generic_structs", "泛型结构体", demo_generic_structs),
+    Section::new("generic_methods", "泛型方法", demo_generic_methods),
+    Section::new("generic_enums", "泛型枚举", demo_generic_enums),
+    Section::new("trait_bounds", "Trait Bounds", demo_trait_bounds),
+];
+
+pub const QUIZ: &[Question] = &[
+    Question::new(
+        "`fn largest<T>(list: &[T]) -> &T` 中比较 `item > largest` 报错，缺少什么？",
+        &[
+            "`T: PartialOrd` 约束",
+            "`T: Clone` 约束",
+            "生命周期参数",
+            "把 T 改成 i32",
+        ],
+        0,
+        "泛型默认不具备任何能力，比较大小需要 `PartialOrd`。",
+    ),
+    Question::new(
+        "Rust 的泛型在运行时有额外开销吗？",
+        &[
+            "有，每次调用都动态分发",
+            "没有，编译器单态化为每个具体类型生成代码",
+            "有，需要装箱",
+            "只在 release 模式下没有",
+        ],
+        1,
+        "单态化带来零运行时开销，代价是可能增加编译后的代码体积。",
+    ),
+    Question::new(
+        "`impl Point<f32> { fn distance(&self) -> f32 { ... } }` 表示？",
+        &[
+            "所有 Point<T> 都有 distance",
+            "只有 Point<f32> 有 distance 方法",
+            "语法错误",
+            "把 Point 改成只能存 f32",
+        ],
+        1,
+        "可以只为泛型类型的某个具体实例实现方法。",
+    ),
+    Question::new(
+        "`struct Point<T> { x: T, y: T }`，为什么 `Point { x: 5, y: 4.0 }` 编译失败？",
+        &[
+            "泛型结构体不能有两个字段",
+            "`x` 与 `y` 必须是同一个类型 `T`",
+            "浮点数不能作为泛型参数",
+            "必须写成 `Point::<i32>`",
+        ],
+        1,
+        "一个类型参数只能代表一种类型；允许两个字段类型不同要写成 `Point<T, U>`。",
+    ),
+];
+
+pub const EXERCISE: &[Check] = &[
+    Check::new("largest", check_largest),
+    Check::new("contains", check_contains),
+    Check::new("Pair::swap", check_pair_swap),
+];
+
+pub const HINTS: &[&str] = &[
+    "泛型函数只能使用约束中声明的能力：`PartialOrd` 允许比较大小，`Copy` 允许把值从切片中复制出来，`PartialEq` 允许用 `==` 比较。",
+    "`largest` 先取第一个元素（切片为空时用 `?` 返回 `None`），再逐个比较；`contains` 可以用迭代器的 `any`；`swap` 消耗 `self`，把两个字段对调后构造新的 `Pair`。",
+    "`let mut largest = *items.first()?;` 之后 `for &item in items { if item > largest { largest = item; } }`；`items.iter().any(|item| item == target)`。",
+];
+
+pub const KOANS: &[Koan] = &[
+    Koan::new(
+        "generic_function",
+        r#"fn largest<T: __ + Copy>(list: &[T]) -> T {
+    let mut largest = list[0];
+    for &item in list {
+        if item > largest {
+            largest = item;
+        }
+    }
+    largest
+}
+
+#[test]
+fn generic_function() {
+    assert_eq!(largest(&[34, 50, 25, 100, 65]), 100);
+    assert_eq!(largest(&['y', 'm', 'a', 'q']), 'y');
+}
+"#,
+        &["PartialOrd"],
+    ),
+    Koan::new(
+        "generic_struct",
+        r#"struct Point<T> {
+    x: T,
+    y: T,
+}
+
+impl<T> Point<T> {
+    fn x(&self) -> &T {
+        &self.x
+    }
+}
+
+#[test]
+fn generic_struct() {
+    let p: Point<__> = Point { x: 1.5, y: 4.0 };
+    assert_eq!(*p.x(), 1.5);
+    assert_eq!(p.y, 4.0);
+}
+"#,
+        &["f64"],
+    ),
+];
+
+pub const PITFALLS: &[Pitfall] = &[Pitfall::new(
+    "missing_bound",
+    include_str!("../pitfalls/generics/missing_bound.rs"),
+    "泛型函数体只能使用 trait bound 承诺过的能力。`T` 没有任何约束，编译器不知道它能否比较大小（E0369）。加上 `T: PartialOrd`。",
+).error("E0369")];
+
+pub fn run(ctx: &mut LessonContext) -> lesson::Result {
+    lesson::run_sections(ctx, SECTIONS)
+}
+
+fn demo_generic_functions(ctx: &mut LessonContext) -> lesson::Result {
+    writeln!(
+        ctx,
+        "Largest integer in [1, 2, 3, 4, 5]: {}",
+        largest(&[1, 2, 3, 4, 5])
+    )?;
+    writeln!(
+        ctx,
+        "Largest char in ['a', 'b', 'c']: {}",
+        largest(&['a', 'b', 'c'])
+    )?;
+
+    let mut p1 = (3, 5);
+    let p2 = (10, 20);
+    writeln!(ctx, "P1: {:?}, P2: {:?}", p1, p2)?;
+    swap(&mut p1.0, &mut p1.1);
+    writeln!(ctx, "Swapped P1: {:?}", p1)?;
+    Ok(())
+}
+
+fn largest<T: PartialOrd + Copy>(list: &[T]) -> T {
+    let mut largest = list[0];
+    for &item in list.iter() {
+        if item > largest {
+            largest = item;
+        }
+    }
+    largest
+}
+
+fn swap<T>(x: &mut T, y: &mut T) {
+    std::mem::swap(x, y);
+}
+
+fn demo_generic_structs(ctx: &mut LessonContext) -> lesson::Result {
+    let integer_point = Point { x: 5, y: 10 };
+    let float_point = Point { x: 1.0, y: 4.0 };
+    let int_float_pair = Pair {
+        first: 5,
+        second: "hello",
+    };
+
+    writeln!(ctx, "Integer point: {:?}", integer_point)?;
+    writeln!(ctx, "Float point: {:?}", float_point)?;
+    writeln!(ctx, "Int-Float pair: {:?}", int_float_pair)?;
+
+    let string_int_pair = Pair::new(String::from("test"), 42);
+    writeln!(ctx, "String-Int pair: {:?}", string_int_pair)?;
+
+    let tuple_pair = Pair::new((1, 2), (3, 4));
+    writeln!(ctx, "Tuple pair: {:?}", tuple_pair)?;
+    Ok(())
+}
+
+fn demo_generic_methods(ctx: &mut LessonContext) -> lesson::Result {
+    let p1 = Point { x: 5, y: 10 };
+    let p2 = Point { x: 1.5, y: 4.5 };
+
+    writeln!(ctx, "P1: x={}, y={}", p1.x(), p1.y())?;
+    writeln!(ctx, "P2: x={}, y={}", p2.x(), p2.y())?;
+
+    let distance = p1.distance(&p2);
+    writeln!(ctx, "Distance from P1 to P2: {}", distance)?;
+    Ok(())
+}
+
+impl<T> Point<T>
+where
+    T: PartialOrd + Copy + std::ops::Sub<Output = T> + std::ops::Mul<Output = T> + Into<f64>,
+{
+    fn distance<U>(&self, other: &Point<U>) -> f64
+    where
+        U: Copy + Into<f64>,
+        T: Copy + Into<f64>,
+    {
+        let x1: f64 = self.x.into();
+        let y1: f64 = self.y.into();
+        let x2: f64 = other.x.into();
+        let y2: f64 = other.y.into();
+        ((x2 - x1).powi(2) + (y2 - y1).powi(2)).sqrt()
+    }
+}
+
+fn demo_generic_enums(ctx: &mut LessonContext) -> lesson::Result {
+    let some_number = Option::Some(5);
+    let some_string = Option::Some(String::from("hello"));
+    let absent_number: Option<i32> = Option::None;
+
+    writeln!(ctx, "Some number: {:?}", some_number)?;
+    writeln!(ctx, "Some string: {:?}", some_string)?;
+    writeln!(ctx, "Absent number: {:?}", absent_number)?;
+
+    let success: Result<i32, &str> = Result::Ok(42);
+    let error: Result<i32, &str> = Result::Err("Something went wrong");
+
+    writeln!(ctx, "Success: {:?}", success)?;
+    writeln!(ctx, "Error: {:?}", error)?;
+    Ok(())
+}
+
+fn demo_trait_bounds(ctx: &mut LessonContext) -> lesson::Result {
+    // ANCHOR: sort_desc
+    let int_list = vec![1, 2, 3, 4, 5];
+    let float_list = vec![1.1, 2.2, 3.3];
+
+    writeln!(ctx, "Sorted ints: {:?}", sort_desc(int_list))?;
+    writeln!(ctx, "Sorted floats: {:?}", sort_desc(float_list))?;
+    // ANCHOR_END: sort_desc
+
+    let p1 = Point { x: 3, y: 5 };
+    let p2 = Point { x: 10, y: 20 };
+    writeln!(ctx, "P1 < P2: {}", compare_points(&p1, &p2))?;
+    Ok(())
+}
+
+// ANCHOR: sort_desc
+fn sort_desc<T: PartialOrd>(mut list: Vec<T>) -> Vec<T> {
+    list.sort_by(|a, b| b.partial_cmp(a).unwrap());
+    list
+}
+// ANCHOR_END: sort_desc
+
+fn compare_points<T: PartialOrd + Copy>(p1: &Point<T>, p2: &Point<T>) -> bool {
+    p1.x < p2.x && p1.y < p2.y
+}
+
+#[derive(Debug)]
+struct Container<T> {
+    value: T,
+}
+
+impl<T> Container<T> {
+    fn new(value: T) -> Self {
+        Container { value }
+    }
+}
+
+fn check_largest() {
+    assert_eq!(exercise::largest(&[3, 7, 2]), Some(7));
+    assert_eq!(exercise::largest(&['a', 'z', 'm']), Some('z'));
+    assert_eq!(exercise::largest::<f64>(&[]), None);
+}
+
+fn check_contains() {
+    assert!(exercise::contains(&["a", "b"], &"b"));
+    assert!(!exercise::contains(&[1, 2], &3));
+}
+
+fn check_pair_swap() {
+    let pair = exercise::Pair {
+        first: "x",
+        second: "y",
+    };
+    assert_eq!(
+        pair.swap(),
+        exercise::Pair {
+            first: "y",
+            second: "x"
+        }
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generic_point() {
+        let p: Point<i32> = Point { x: 5, y: 10 };
+        assert_eq!(*p.x(), 5);
+        assert_eq!(*p.y(), 10);
+    }
+
+    #[test]
+    fn test_generic_pair() {
+        let pair: Pair<i32, String> = Pair::new(42, String::from("test"));
+        assert_eq!(pair.first, 42);
+        assert_eq!(pair.second, "test");
+    }
+
+    #[test]
+    fn test_generic_container() {
+        let c1 = Container::new(42);
+        assert_eq!(c1.value, 42);
+
+        let c2 = Container::new("hello");
+        assert_eq!(c2.value, "hello");
+    }
+
+    #[test]
+    fn test_largest() {
+        let numbers = vec![1, 5, 3, 9, 2];
+        assert_eq!(largest(&numbers), 9);
+
+        let chars = vec!['a', 'z', 'm', 'b'];
+        assert_eq!(largest(&chars), 'z');
+    }
+}
+
+#[cfg(test)]
+mod properties {
+    use super::*;
+
+    proptest::proptest! {
+        // 最大值出现在列表中，并且不小于任何一个元素
+        #[test]
+        fn prop_largest(list in proptest::collection::vec(proptest::num::i32::ANY, 1..20)) {
+            let max = largest(&list);
+            proptest::prop_assert!(list.contains(&max));
+            proptest::prop_assert!(list.iter().all(|&x| x <= max));
+        }
+    }
+}
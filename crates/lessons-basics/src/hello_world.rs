@@ -0,0 +1,219 @@
+//! # Hello, world & Project Layout
+//!
+//! 目标：了解 Rust 项目基本结构和 Hello World 程序
+//!
+//! ## 要点
+//! - `main()` 函数是程序入口点
+//! - `println!` 是一个宏（macro），不是函数
+//! - 本课程的 lesson 用 `writeln!(ctx, ...)` 输出：写到 lesson 上下文而不是直接写标准输出，
+//!   这样测试可以捕获并断言输出内容
+//! - 讲解文本用 `ctx.tr("lesson.hello_world.…")` 从 `locales/` 中按 `--lang` 取译文
+//! - Rust 使用 `!` 表示宏调用
+//! - 语句以分号 `;` 结尾
+//!
+//! ```rust
+//! use std::io::Write;
+//!
+//! // writeln! 可以写到任何实现了 Write 的地方；lesson 写到上下文，测试就能检查输出
+//! let mut out = Vec::new();
+//! writeln!(out, "Hello, {}!", "world").unwrap();
+//! assert_eq!(String::from_utf8(out).unwrap(), "Hello, world!\n");
+//!
+//! println!("Hello, world!"); // 宏调用带 !，语句以分号结尾
+//! ```
+//!
+//! ## 常见坑
+//! - 漏写 `!`：`println("hi")` 会被当作调用名为 `println` 的函数而报错
+//! - 格式字符串必须是字面量，不能直接传入 `String` 变量
+//!
+//! ## 运行
+//! `cargo run -- 01_hello_world`
+//!
+//! ## 测试
+//! `cargo test -- --nocapture`
+
+use crate::exercises::hello_world as exercise;
+use crate::lesson::{self, Check, Koan, LessonContext, Pitfall, Question, Section};
+use crate::utils::fmt;
+use std::io::Write;
+
+pub const SECTIONS: &[Section] = &[];
+
+pub const QUIZ: &[Question] = &[
+    Question::new(
+        "`println!(\"hi\")` 中的 `!` 表示什么？",
+        &[
+            "这是一个宏调用",
+            "这是一个会 panic 的函数",
+            "强调输出内容",
+            "这是一个不返回的函数",
+        ],
+        0,
+        "`!` 表示宏调用，宏在编译期展开；漏写 `!` 会被当作普通函数调用而报错。",
+    ),
+    Question::new(
+        "可执行程序从哪里开始运行？",
+        &[
+            "`lib.rs` 中的第一个函数",
+            "`main()` 函数",
+            "`Cargo.toml` 中的 `[package]`",
+            "名为 `start` 的函数",
+        ],
+        1,
+        "二进制 crate 的入口是 `src/main.rs` 中的 `main()`。",
+    ),
+    Question::new(
+        "下面哪种写法能通过编译？",
+        &[
+            "`let f = \"{}\"; println!(f, 1);`",
+            "`println(\"{}\", 1);`",
+            "`println!(\"{}\", 1);`",
+            "`println!(1);`",
+        ],
+        2,
+        "格式字符串必须是字面量，且宏调用要带 `!`。",
+    ),
+    Question::new(
+        "`cargo run` 做了什么？",
+        &[
+            "只检查语法，不生成程序",
+            "把包发布到 crates.io",
+            "按需编译当前包，然后运行生成的程序",
+            "格式化源代码",
+        ],
+        2,
+        "`cargo run` 在源码有改动时先编译再运行；只检查能否编译用 `cargo check`，格式化用 `cargo fmt`。",
+    ),
+];
+
+pub const EXERCISE: &[Check] = &[
+    Check::new("greeting", check_greeting),
+    Check::new("repeat_hello", check_repeat_hello),
+];
+
+pub const HINTS: &[&str] = &[
+    "两个函数都返回 `String`：拼接字符串最方便的是 `format!` 宏。",
+    "`greeting` 用 `format!(\"Hello, {}!\", name)`；`repeat_hello` 可以先得到 `lines` 个 `\"Hello, world!\"`，再用 `\\n` 连起来。",
+    "`vec![\"Hello, world!\"; lines].join(\"\\n\")` 正好满足“行之间有换行、末尾没有”，`lines` 为 0 时得到空字符串。",
+];
+
+pub const KOANS: &[Koan] = &[
+    Koan::new(
+        "format",
+        r#"#[test]
+fn format_macro() {
+    let name = "Rust";
+    let greeting = format!("Hello, {}!", __);
+    assert_eq!(greeting, "Hello, Rust!");
+}
+"#,
+        &["name"],
+    ),
+    Koan::new(
+        "add",
+        r#"fn add(a: i32, b: i32) -> i32 {
+    __
+}
+
+#[test]
+fn add_two_numbers() {
+    assert_eq!(add(2, 3), 5);
+}
+"#,
+        &["a + b"],
+    ),
+];
+
+pub const PITFALLS: &[Pitfall] = &[Pitfall::new(
+    "missing_bang",
+    include_str!("../pitfalls/hello_world/missing_bang.rs"),
+    "`println` 是宏不是函数，调用宏必须带 `!`。没有 `!` 时编译器去找名为 `println` 的函数，找不到就报 E0423，并提示加上 `!`。",
+).error("E0423")];
+
+pub fn run(ctx: &mut LessonContext) -> lesson::Result {
+    let greeting = ctx.tr("lesson.hello_world.greeting");
+    writeln!(ctx, "{}", fmt::text(&greeting))?;
+    writeln!(ctx, "1 + 2 = {}", add(1, 2))?;
+
+    let name = "Rust";
+    let welcome = ctx.tr_with("lesson.hello_world.welcome", &[("name", name)]);
+    writeln!(ctx, "{}", welcome)?;
+
+    // `--verbose` 时额外介绍项目结构
+    if ctx.is_verbose() {
+        let (cargo, main, lib) = (
+            ctx.tr("lesson.hello_world.layout_cargo"),
+            ctx.tr("lesson.hello_world.layout_main"),
+            ctx.tr("lesson.hello_world.layout_lib"),
+        );
+        writeln!(ctx, "\n{}", ctx.tr("lesson.hello_world.layout"))?;
+        ctx.kv_table(&[
+            ("Cargo.toml", &cargo),
+            ("src/main.rs", &main),
+            ("src/lib.rs", &lib),
+        ])?;
+    }
+    Ok(())
+}
+
+fn add(a: i32, b: i32) -> i32 {
+    a + b
+}
+
+fn check_greeting() {
+    assert_eq!(exercise::greeting("Ferris"), "Hello, Ferris!");
+    assert_eq!(exercise::greeting(""), "Hello, !");
+}
+
+fn check_repeat_hello() {
+    assert_eq!(exercise::repeat_hello(2), "Hello, world!\nHello, world!");
+    assert_eq!(exercise::repeat_hello(0), "");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lesson::{Language, Verbosity};
+
+    #[test]
+    fn test_add() {
+        assert_eq!(add(1, 2), 3);
+        assert_eq!(add(-1, 1), 0);
+        assert_eq!(add(0, 0), 0);
+    }
+
+    #[test]
+    fn test_verbose_shows_layout() {
+        let mut out = Vec::new();
+        run(&mut LessonContext::new(&mut out)).unwrap();
+        assert!(!String::from_utf8(out).unwrap().contains("src/main.rs"));
+
+        let mut out = Vec::new();
+        run(&mut LessonContext::new(&mut out).with_verbosity(Verbosity::Verbose)).unwrap();
+        assert!(String::from_utf8(out).unwrap().contains("src/main.rs"));
+    }
+
+    #[test]
+    fn test_output_follows_language() {
+        let mut out = Vec::new();
+        run(&mut LessonContext::new(&mut out).with_language(Language::En)).unwrap();
+        assert!(
+            String::from_utf8(out)
+                .unwrap()
+                .contains("Welcome to Rust programming!")
+        );
+
+        let mut out = Vec::new();
+        run(&mut LessonContext::new(&mut out)).unwrap();
+        assert!(
+            String::from_utf8(out)
+                .unwrap()
+                .contains("欢迎学习 Rust 编程！")
+        );
+    }
+
+    #[test]
+    fn test_add_negative() {
+        assert_eq!(add(-5, -3), -8);
+    }
+}
@@ -0,0 +1,421 @@
+//! # Iterators & Closures
+//!
+//! 目标：理解 Rust 中的迭代器和闭包
+//!
+//! ## 要点
+//! - 迭代器：惰性地遍历集合中的元素
+//! - 迭代器 trait：`next()` 方法返回 `Option<Self::Item>`
+//! - 闭包：匿名函数，可以捕获环境变量
+//! - 闭包有三种类型：`Fn`、`FnMut`、`FnOnce`
+//! - 迭代器适配器：`map`、`filter`、`fold` 等
+//! - 消费适配器：`collect`、`sum`、`for_each` 等
+//!
+//! ```rust
+//! let v = vec![1, 2, 3, 4];
+//! let mut it = v.iter();
+//! assert_eq!(it.next(), Some(&1)); // next 每次取一个，取完后返回 None
+//!
+//! // 适配器是惰性的，collect、sum 这样的消费适配器才真正遍历
+//! let threshold = 2; // 闭包捕获了环境中的 threshold
+//! let big: Vec<i32> = v.iter().filter(|&&x| x > threshold).map(|x| x * 10).collect();
+//! assert_eq!(big, [30, 40]);
+//! assert_eq!(v.iter().fold(0, |acc, x| acc + x), 10);
+//!
+//! let mut counter = 0;
+//! let mut inc = || counter += 1; // 修改了捕获的变量，所以是 FnMut
+//! inc();
+//! inc();
+//! assert_eq!(counter, 2);
+//! ```
+//!
+//! ## 常见坑
+//! - 迭代器是惰性的，需要消费适配器才能执行
+//! - 闭包捕获所有权可能导致后续无法使用变量
+//! - 在迭代过程中修改集合可能导致问题
+//!
+//! ## 运行
+//! `cargo run -- 16_iterators_closures`
+//!
+//! 只运行其中一节：`cargo run -- 16_iterators_closures:closure_capture`
+//!
+//! ## 示例
+//!
+//! 由 `cargo run -- update-doctests` 从演示代码中标出的片段生成，`cargo test --doc` 会运行它们。
+//!
+//! `adapters`：
+//!
+//! ```rust
+//! let numbers = vec![1, 2, 3, 4, 5];
+//!
+//! let doubled: Vec<_> = numbers.iter().map(|x| x * 2).collect();
+//! println!("Doubled: {:?}", doubled);
+//!
+//! let evens: Vec<_> = numbers.iter().filter(|x| *x % 2 == 0).collect();
+//! println!("Evens: {:?}", evens);
+//!
+//! let sum: i32 = numbers.iter().fold(0, |acc, x| acc + x);
+//! println!("Fold sum: {}", sum);
+//!
+//! let product: i32 = numbers.iter().fold(1, |acc, x| acc * x);
+//! println!("Fold product: {}", product);
+//!
+//! let first_even = numbers.iter().find(|x| *x % 2 == 0);
+//! println!("First even: {:?}", first_even);
+//!
+//! let all_positive = numbers.iter().all(|x| *x > 0);
+//! println!("All positive: {}", all_positive);
+//!
+//! let any_negative = numbers.iter().any(|x| *x < 0);
+//! println!("Any negative: {}", any_negative);
+//!
+//! let chained: Vec<_> = numbers.iter().filter(|x| **x > 2).map(|x| x * 3).collect();
+//! println!("Filter > 2 then * 3: {:?}", chained);
+//! ```
+
+use crate::exercises::iterators_closures as exercise;
+use crate::lesson::{self, Check, Koan, LessonContext, Pitfall, Question, Section};
+use std::io::{self, Write};
+
+pub const SECTIONS: &[Section] = &[
+    Section::new("iterator_basics", "迭代器基础", demo_iterator_basics),
+    Section::new("iterator_adapters", "迭代器适配器", demo_iterator_adapters),
+    Section::new("closures", "闭包基础", demo_closures),
+    Section::new("closure_capture", "闭包捕获", demo_closure_capture),
+];
+
+pub const QUIZ: &[Question] = &[
+    Question::new(
+        "`v.iter().map(|x| x * 2);` 之后 v 的元素会被处理吗？",
+        &[
+            "会，map 立即执行",
+            "不会，迭代器是惰性的，需要 collect 等消费",
+            "会处理一半",
+            "编译错误",
+        ],
+        1,
+        "适配器只描述操作，要用 `collect`、`sum`、`for_each` 等消费适配器驱动执行。",
+    ),
+    Question::new(
+        "闭包在调用时修改了捕获的变量，它至少实现了哪个 trait？",
+        &[
+            "`Fn`",
+            "`FnMut`",
+            "`FnOnce` 但不实现 `FnMut`",
+            "不实现任何 trait",
+        ],
+        1,
+        "修改捕获变量的闭包实现 `FnMut`；只读的实现 `Fn`；消耗捕获值的只实现 `FnOnce`。",
+    ),
+    Question::new(
+        "`move` 关键字对闭包的作用是？",
+        &[
+            "闭包获取捕获变量的所有权",
+            "闭包只能调用一次",
+            "闭包在另一个线程中运行",
+            "闭包变成函数指针",
+        ],
+        0,
+        "`move` 让闭包按值捕获，常用于把闭包传给新线程。",
+    ),
+    Question::new(
+        "`Iterator::next` 的返回类型是？",
+        &[
+            "`Self::Item`",
+            "`Option<Self::Item>`",
+            "`Result<Self::Item, ()>`",
+            "`bool`",
+        ],
+        1,
+        "返回 `None` 表示迭代结束。",
+    ),
+];
+
+pub const EXERCISE: &[Check] = &[
+    Check::new("squares_of_evens", check_squares_of_evens),
+    Check::new("make_adder", check_make_adder),
+    Check::new("count_long_words", check_count_long_words),
+];
+
+pub const HINTS: &[&str] = &[
+    "迭代器适配器（`filter`、`map`）是惰性的，最后用 `collect` 或 `count` 消费；闭包要带出函数时需要 `move` 捕获变量。",
+    "`squares_of_evens` 先 `filter` 偶数再 `map` 平方；`make_adder` 返回 `Box::new(...)` 包装的闭包；`count_long_words` 按字符数过滤单词。",
+    "`Box::new(move |x| x + n)`；`text.split_whitespace().filter(|w| w.chars().count() >= min_len).count()`。",
+];
+
+pub const KOANS: &[Koan] = &[
+    Koan::new(
+        "iterator_adapters",
+        r#"#[test]
+fn iterator_adapters() {
+    let v = vec![1, 2, 3, 4, 5, 6];
+    let evens: Vec<i32> = v.iter().filter(|&&x| x % 2 == 0).map(|x| x * 10).__();
+    assert_eq!(evens, [20, 40, 60]);
+}
+"#,
+        &["collect"],
+    ),
+    Koan::new(
+        "closure_capture",
+        r#"#[test]
+fn closure_capture() {
+    let offset = 10;
+    let add_offset = |x: i32| x + __;
+    assert_eq!(add_offset(5), 15);
+
+    let mut count = 0;
+    let mut inc = || count += 1;
+    inc();
+    inc();
+    assert_eq!(count, __);
+}
+"#,
+        &["offset", "2"],
+    ),
+];
+
+pub const PITFALLS: &[Pitfall] = &[Pitfall::new(
+    "use_after_move_closure",
+    include_str!("../pitfalls/iterators_closures/use_after_move_closure.rs"),
+    "`move` 闭包把捕获的 `names` 移进了闭包，之后外面就不能再用它（E0382）。闭包只需要读取时去掉 `move`；必须 `move`（例如交给线程）时先 `clone` 一份。",
+).error("E0382")];
+
+pub fn run(ctx: &mut LessonContext) -> lesson::Result {
+    lesson::run_sections(ctx, SECTIONS)
+}
+
+#[allow(clippy::useless_vec)]
+fn demo_iterator_basics(ctx: &mut LessonContext) -> lesson::Result {
+    let v = vec![1, 2, 3];
+
+    let mut iter = v.iter();
+    writeln!(ctx, "First: {:?}", iter.next())?;
+    writeln!(ctx, "Second: {:?}", iter.next())?;
+    writeln!(ctx, "Third: {:?}", iter.next())?;
+    writeln!(ctx, "Fourth: {:?}", iter.next())?;
+
+    let sum: i32 = v.iter().sum();
+    writeln!(ctx, "Sum: {}", sum)?;
+
+    let collected: Vec<_> = v.iter().collect();
+    writeln!(ctx, "Collected: {:?}", collected)?;
+
+    for val in v.iter() {
+        writeln!(ctx, "Value: {}", val)?;
+    }
+    Ok(())
+}
+
+#[allow(clippy::useless_vec, clippy::unnecessary_fold)]
+fn demo_iterator_adapters(ctx: &mut LessonContext) -> lesson::Result {
+    // ANCHOR: adapters
+    let numbers = vec![1, 2, 3, 4, 5];
+
+    let doubled: Vec<_> = numbers.iter().map(|x| x * 2).collect();
+    writeln!(ctx, "Doubled: {:?}", doubled)?;
+
+    let evens: Vec<_> = numbers.iter().filter(|x| *x % 2 == 0).collect();
+    writeln!(ctx, "Evens: {:?}", evens)?;
+
+    let sum: i32 = numbers.iter().fold(0, |acc, x| acc + x);
+    writeln!(ctx, "Fold sum: {}", sum)?;
+
+    let product: i32 = numbers.iter().fold(1, |acc, x| acc * x);
+    writeln!(ctx, "Fold product: {}", product)?;
+
+    let first_even = numbers.iter().find(|x| *x % 2 == 0);
+    writeln!(ctx, "First even: {:?}", first_even)?;
+
+    let all_positive = numbers.iter().all(|x| *x > 0);
+    writeln!(ctx, "All positive: {}", all_positive)?;
+
+    let any_negative = numbers.iter().any(|x| *x < 0);
+    writeln!(ctx, "Any negative: {}", any_negative)?;
+
+    let chained: Vec<_> = numbers.iter().filter(|x| **x > 2).map(|x| x * 3).collect();
+    writeln!(ctx, "Filter > 2 then * 3: {:?}", chained)?;
+    // ANCHOR_END: adapters
+    Ok(())
+}
+
+#[allow(clippy::useless_vec)]
+fn demo_closures(ctx: &mut LessonContext) -> lesson::Result {
+    let add = |x, y| x + y;
+    writeln!(ctx, "Add: 5 + 3 = {}", add(5, 3))?;
+
+    let square = |x| x * x;
+    writeln!(ctx, "Square: 5^2 = {}", square(5))?;
+
+    let make_greeting = |name| format!("Hello, {}!", name);
+    writeln!(ctx, "{}", make_greeting("Rust"))?;
+
+    let mut count = 0;
+    let mut increment = || {
+        count += 1;
+        count
+    };
+    writeln!(ctx, "Count: {}", increment())?;
+    writeln!(ctx, "Count: {}", increment())?;
+    writeln!(ctx, "Count: {}", increment())?;
+
+    let nums = vec![1, 2, 3, 4, 5];
+    let squares: Vec<_> = nums.iter().map(|x| x * x).collect();
+    writeln!(ctx, "Squares: {:?}", squares)?;
+
+    let even_squares: Vec<_> = nums.iter().filter(|x| *x % 2 == 0).map(|x| x * x).collect();
+    writeln!(ctx, "Even squares: {:?}", even_squares)?;
+    Ok(())
+}
+
+#[allow(clippy::useless_vec)]
+fn demo_closure_capture(ctx: &mut LessonContext) -> lesson::Result {
+    let x = 10;
+    // 闭包同时捕获了 x（不可变借用）和 ctx（可变借用），因此是 FnMut
+    let mut print_x = || writeln!(ctx, "x = {}", x);
+    print_x()?;
+
+    let mut count = 0;
+    let items = vec![1, 2, 3, 4, 5];
+    items.iter().for_each(|_| {
+        count += 1;
+    });
+    writeln!(ctx, "Count after foreach: {}", count)?;
+
+    let mut nums = vec![1, 2, 3, 4, 5];
+    let multiplier = 2;
+    nums.iter_mut().for_each(|n| *n *= multiplier);
+    writeln!(ctx, "Multiplied nums: {:?}", nums)?;
+
+    move_closure(ctx)?;
+    Ok(())
+}
+
+fn move_closure(ctx: &mut LessonContext) -> io::Result<()> {
+    let s = String::from("hello");
+    let mut take_s = move || writeln!(ctx, "Moved: {}", s);
+    take_s()?;
+    // s is moved, cannot use here
+    Ok(())
+}
+
+#[derive(Debug)]
+struct Counter {
+    count: u32,
+}
+
+impl Counter {
+    fn new() -> Counter {
+        Counter { count: 0 }
+    }
+}
+
+impl Iterator for Counter {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.count += 1;
+        if self.count < 6 {
+            Some(self.count)
+        } else {
+            None
+        }
+    }
+}
+
+#[allow(clippy::while_let_on_iterator)]
+fn demo_custom_iterator(ctx: &mut LessonContext) -> io::Result<()> {
+    let mut counter = Counter::new();
+    writeln!(ctx, "Custom iterator:")?;
+    while let Some(num) = counter.next() {
+        writeln!(ctx, "  {}", num)?;
+    }
+
+    // Using iterator methods
+    let sum: u32 = Counter::new().sum();
+    writeln!(ctx, "Sum of counter: {}", sum)?;
+
+    let powers: Vec<u32> = Counter::new().map(|x| x * x).collect();
+    writeln!(ctx, "Counter squares: {:?}", powers)?;
+    Ok(())
+}
+
+fn check_squares_of_evens() {
+    assert_eq!(exercise::squares_of_evens(&[1, 2, 3, 4, -6]), [4, 16, 36]);
+    assert!(exercise::squares_of_evens(&[1, 3]).is_empty());
+}
+
+fn check_make_adder() {
+    let add_five = exercise::make_adder(5);
+    assert_eq!(add_five(1), 6);
+    assert_eq!(add_five(-5), 0);
+}
+
+fn check_count_long_words() {
+    assert_eq!(exercise::count_long_words("an iterator is lazy", 4), 2);
+    assert_eq!(exercise::count_long_words("闭包 捕获 环境", 2), 3);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[allow(clippy::useless_vec)]
+    fn test_iterator_sum() {
+        let v = vec![1, 2, 3, 4, 5];
+        let sum: i32 = v.iter().sum();
+        assert_eq!(sum, 15);
+    }
+
+    #[test]
+    #[allow(clippy::useless_vec)]
+    fn test_map_collect() {
+        let v = vec![1, 2, 3];
+        let doubled: Vec<_> = v.iter().map(|x| x * 2).collect();
+        assert_eq!(doubled, vec![2, 4, 6]);
+    }
+
+    #[test]
+    fn test_filter() {
+        let v = vec![1, 2, 3, 4, 5];
+        let evens: Vec<_> = v.into_iter().filter(|x| x % 2 == 0).collect();
+        assert_eq!(evens, vec![2, 4]);
+    }
+
+    #[test]
+    #[allow(clippy::useless_vec, clippy::unnecessary_fold)]
+    fn test_fold() {
+        let v = vec![1, 2, 3, 4, 5];
+        let sum = v.iter().fold(0, |acc, x| acc + x);
+        assert_eq!(sum, 15);
+    }
+
+    #[test]
+    fn test_closure() {
+        let add = |x, y| x + y;
+        assert_eq!(add(2, 3), 5);
+    }
+
+    #[test]
+    fn test_closure_capture() {
+        let x = 10;
+        let add_x = |y| x + y;
+        assert_eq!(add_x(5), 15);
+    }
+
+    #[test]
+    fn test_custom_iterator() {
+        let mut counter = Counter::new();
+        assert_eq!(counter.next(), Some(1));
+        assert_eq!(counter.next(), Some(2));
+        assert_eq!(counter.next(), Some(3));
+        assert_eq!(counter.next(), Some(4));
+        assert_eq!(counter.next(), Some(5));
+        assert_eq!(counter.next(), None);
+    }
+
+    #[test]
+    fn test_iterator_methods() {
+        let sum: u32 = Counter::new().sum();
+        assert_eq!(sum, 15); // 1+2+3+4+5
+    }
+}
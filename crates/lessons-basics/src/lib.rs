@@ -0,0 +1,34 @@
+//! 基础部分的 lesson：`curriculum.toml` 中 `feature = "basics"` 的全部 lesson
+//!
+//! 每个模块对应一个 lesson，提供 `run`、`SECTIONS` 与测验题 `QUIZ`；编号、标题等元数据写在大纲中，
+//! 由运行器在构建时注册。
+
+// 示例代码为了演示语法会定义一些没有用到的类型、函数与导入
+#![allow(unused_imports, unused_macros, dead_code, non_local_definitions)]
+
+// lesson 代码通过 `crate::lesson`、`crate::utils` 使用公共接口
+use rust_learn_core::{lesson, utils};
+
+// 各 lesson 的练习；启用 `solutions` feature 时换成仓库根目录 `solutions/` 中的参考答案
+#[cfg_attr(
+    feature = "solutions",
+    path = "../../../solutions/lessons-basics/mod.rs"
+)]
+mod exercises;
+
+pub mod borrowing;
+pub mod collections;
+pub mod control_flow;
+pub mod enums_matching;
+pub mod error_handling;
+pub mod functions;
+pub mod generics;
+pub mod hello_world;
+pub mod iterators_closures;
+pub mod methods_assoc_fn;
+pub mod ownership;
+pub mod slices;
+pub mod structs;
+pub mod traits;
+pub mod types;
+pub mod variables;
@@ -0,0 +1,436 @@
+//! # Methods & Associated Functions
+//!
+//! 目标：理解 Rust 中的方法（method）和关联函数（associated function）
+//!
+//! ## 要点
+//! - 方法是定义在结构体、枚举或 trait 对象上的函数，第一个参数是 `self`
+//! - 关联函数（不使用 self）类似于其他语言的静态方法
+//! - `self`、`&self`、`&mut self` 表示不同的所有权关系
+//! - 方法可以访问结构体的私有字段
+//! - 使用 `impl` 块定义方法和关联函数
+//!
+//! ```rust
+//! struct Counter {
+//!     count: u32,
+//! }
+//!
+//! impl Counter {
+//!     // 关联函数：没有 self，用 Counter::new() 调用
+//!     fn new() -> Self {
+//!         Counter { count: 0 }
+//!     }
+//!
+//!     fn get(&self) -> u32 {
+//!         self.count
+//!     }
+//!
+//!     fn bump(&mut self) {
+//!         self.count += 1;
+//!     }
+//!
+//!     // 拿走 self：调用之后 counter 不能再用
+//!     fn into_inner(self) -> u32 {
+//!         self.count
+//!     }
+//! }
+//!
+//! let mut counter = Counter::new();
+//! counter.bump();
+//! counter.bump();
+//! assert_eq!(counter.get(), 2);
+//! assert_eq!(counter.into_inner(), 2);
+//! ```
+//!
+//! ## 常见坑
+//! - 忘记方法会自动借用 self（&self）
+//! - 混淆方法和关联函数的区别
+//! - 在多个 impl 块中定义方法时需要小心
+//!
+//! ## 运行
+//! `cargo run -- 11_methods_assoc_fn`
+//!
+//! ## 示例
+//!
+//! 由 `cargo run -- update-doctests` 从演示代码中标出的片段生成，`cargo test --doc` 会运行它们。
+//!
+//! `point`：
+//!
+//! ```rust
+//! #[derive(Debug)]
+//! struct Point {
+//!     x: f64,
+//!     y: f64,
+//! }
+//!
+//! impl Point {
+//!     fn distance(&self, other: &Point) -> f64 {
+//!         let dx = self.x - other.x;
+//!         let dy = self.y - other.y;
+//!         (dx * dx + dy * dy).sqrt()
+//!     }
+//!
+//!     fn origin() -> Point {
+//!         Point { x: 0.0, y: 0.0 }
+//!     }
+//! }
+//!
+//! let p1 = Point { x: 0.0, y: 0.0 };
+//! let p2 = Point { x: 3.0, y: 4.0 };
+//! println!(
+//!     "Distance from {:?} to {:?}: {}",
+//!     p1,
+//!     p2,
+//!     p1.distance(&p2)
+//! );
+//! ```
+
+use crate::exercises::methods_assoc_fn as exercise;
+use crate::lesson::{self, Check, Koan, LessonContext, Pitfall, Question, Section};
+use std::io::{self, Write};
+
+#[derive(Debug)]
+struct Rectangle {
+    width: u32,
+    height: u32,
+}
+
+#[derive(Debug)]
+struct Circle {
+    radius: f64,
+}
+
+// ANCHOR: point
+#[derive(Debug)]
+struct Point {
+    x: f64,
+    y: f64,
+}
+// ANCHOR_END: point
+
+impl Rectangle {
+    // 方法：通过 &self 借用实例
+    fn area(&self) -> u32 {
+        self.width * self.height
+    }
+
+    fn can_hold(&self, other: &Rectangle) -> bool {
+        self.width > other.width && self.height > other.height
+    }
+
+    // 关联函数：没有 self 参数
+    fn square(size: u32) -> Rectangle {
+        Rectangle {
+            width: size,
+            height: size,
+        }
+    }
+}
+
+impl Circle {
+    fn area(&self) -> f64 {
+        std::f64::consts::PI * self.radius * self.radius
+    }
+
+    fn circumference(&self) -> f64 {
+        2.0 * std::f64::consts::PI * self.radius
+    }
+
+    fn new(radius: f64) -> Circle {
+        Circle { radius }
+    }
+}
+
+// ANCHOR: point
+impl Point {
+    fn distance(&self, other: &Point) -> f64 {
+        let dx = self.x - other.x;
+        let dy = self.y - other.y;
+        (dx * dx + dy * dy).sqrt()
+    }
+
+    fn origin() -> Point {
+        Point { x: 0.0, y: 0.0 }
+    }
+}
+// ANCHOR_END: point
+
+pub const SECTIONS: &[Section] = &[
+    Section::new("methods", "方法调用", demo_methods),
+    Section::new(
+        "associated_functions",
+        "关联函数",
+        demo_associated_functions,
+    ),
+    Section::new("method_chaining", "方法链式调用", demo_method_chaining),
+    Section::new("multiple_impl", "多个 impl 块", demo_multiple_impl),
+];
+
+pub const QUIZ: &[Question] = &[
+    Question::new(
+        "`String::from(\"a\")`、`Rectangle::square(3)` 属于？",
+        &["方法", "关联函数（没有 self 参数）", "宏", "trait 对象"],
+        1,
+        "没有 `self` 参数的关联函数用 `类型::函数` 调用，常用作构造器。",
+    ),
+    Question::new(
+        "只读取字段的方法，第一个参数通常写成？",
+        &["`self`", "`&self`", "`&mut self`", "`Self`"],
+        1,
+        "`&self` 只借用实例；`self` 会获取所有权，`&mut self` 可以修改实例。",
+    ),
+    Question::new(
+        "`rect.area()` 中 `rect` 不是引用，为什么能调用 `fn area(&self)`？",
+        &[
+            "编译器自动引用（auto-referencing）",
+            "`area` 会拷贝 rect",
+            "必须写成 `(&rect).area()`",
+            "因为 rect 是 mut",
+        ],
+        0,
+        "方法调用时编译器会自动加上 `&`、`&mut` 或 `*` 以匹配签名。",
+    ),
+    Question::new(
+        "方法的第一个参数写成 `self`（不带 `&`）意味着？",
+        &[
+            "方法获取实例的所有权，调用后原变量不能再用",
+            "方法可以修改实例但不拿走它",
+            "与 `&self` 完全相同",
+            "只能用于 `Copy` 类型",
+        ],
+        0,
+        "`self` 按值接收，调用时实例被移动进方法，常用于把一个值转换成另一个值。",
+    ),
+];
+
+pub const EXERCISE: &[Check] = &[
+    Check::new("Counter::new", check_counter_new),
+    Check::new("Counter::starting_at", check_counter_starting_at),
+    Check::new("Counter::increment", check_counter_increment),
+];
+
+pub const HINTS: &[&str] = &[
+    "没有 `self` 参数的是关联函数（用 `Counter::new()` 调用），带 `&self` / `&mut self` 的是方法；`Self` 指代 `Counter`。",
+    "`new` 与 `starting_at` 都返回 `Counter { count: ... }`；`increment` 需要修改字段，所以接收 `&mut self`。",
+    "`Counter { count: 0 }`、`Counter { count: start }`、`self.count += 1;`、`self.count`。",
+];
+
+pub const KOANS: &[Koan] = &[
+    Koan::new(
+        "methods",
+        r#"struct Rectangle {
+    width: u32,
+    height: u32,
+}
+
+impl Rectangle {
+    fn area(__) -> u32 {
+        self.width * self.height
+    }
+
+    fn can_hold(&self, other: &Rectangle) -> bool {
+        self.width > other.width && self.height > other.height
+    }
+}
+
+#[test]
+fn methods() {
+    let big = Rectangle { width: 30, height: 50 };
+    let small = Rectangle { width: 10, height: 40 };
+    assert_eq!(big.area(), 1500);
+    assert!(big.can_hold(&small));
+}
+"#,
+        &["&self"],
+    ),
+    Koan::new(
+        "associated_functions",
+        r#"struct Circle {
+    radius: f64,
+}
+
+impl Circle {
+    fn new(radius: f64) -> __ {
+        Circle { radius }
+    }
+
+    fn unit() -> Circle {
+        Circle::new(1.0)
+    }
+}
+
+#[test]
+fn associated_functions() {
+    let c = __::unit();
+    assert_eq!(c.radius, 1.0);
+}
+"#,
+        &["Self", "Circle"],
+    ),
+];
+
+pub const PITFALLS: &[Pitfall] = &[Pitfall::new(
+    "mut_method_on_immutable",
+    include_str!("../pitfalls/methods_assoc_fn/mut_method_on_immutable.rs"),
+    "`increment` 接收 `&mut self`，方法调用会自动借用 `counter`，但不可变的变量不能被可变借用（E0596）。把变量声明为 `let mut counter`。",
+).error("E0596")];
+
+pub fn run(ctx: &mut LessonContext) -> lesson::Result {
+    lesson::run_sections(ctx, SECTIONS)
+}
+
+fn demo_methods(ctx: &mut LessonContext) -> lesson::Result {
+    let rect = Rectangle {
+        width: 30,
+        height: 50,
+    };
+
+    writeln!(ctx, "Rectangle: {:?}", rect)?;
+    writeln!(ctx, "Area: {} square pixels", rect.area())?;
+
+    let rect1 = Rectangle {
+        width: 20,
+        height: 40,
+    };
+
+    let rect2 = Rectangle {
+        width: 40,
+        height: 60,
+    };
+
+    writeln!(ctx, "Can rect hold rect1? {}", rect.can_hold(&rect1))?;
+    writeln!(ctx, "Can rect hold rect2? {}", rect.can_hold(&rect2))?;
+
+    let circle = Circle { radius: 5.0 };
+    writeln!(ctx, "Circle: {:?}", circle)?;
+    writeln!(ctx, "Area: {:.2}", circle.area())?;
+    writeln!(ctx, "Circumference: {:.2}", circle.circumference())?;
+
+    // ANCHOR: point
+    let p1 = Point { x: 0.0, y: 0.0 };
+    let p2 = Point { x: 3.0, y: 4.0 };
+    writeln!(
+        ctx,
+        "Distance from {:?} to {:?}: {}",
+        p1,
+        p2,
+        p1.distance(&p2)
+    )?;
+    // ANCHOR_END: point
+    Ok(())
+}
+
+fn demo_associated_functions(ctx: &mut LessonContext) -> lesson::Result {
+    let square = Rectangle::square(20);
+    writeln!(ctx, "Square: {:?}", square)?;
+    writeln!(ctx, "Square area: {}", square.area())?;
+
+    let circle = Circle::new(10.0);
+    writeln!(ctx, "Circle: {:?}", circle)?;
+    writeln!(ctx, "Circle area: {:.2}", circle.area())?;
+
+    let origin = Point::origin();
+    writeln!(ctx, "Origin: {:?}", origin)?;
+    Ok(())
+}
+
+fn demo_method_chaining(ctx: &mut LessonContext) -> lesson::Result {
+    let area = Rectangle::square(10).area();
+    writeln!(ctx, "Square of 10 area: {}", area)?;
+    Ok(())
+}
+
+fn demo_multiple_impl(ctx: &mut LessonContext) -> lesson::Result {
+    impl Rectangle {
+        fn is_square(&self) -> bool {
+            self.width == self.height
+        }
+    }
+
+    let sq = Rectangle::square(15);
+    writeln!(ctx, "Is square? {}", sq.is_square())?;
+
+    let rect = Rectangle {
+        width: 30,
+        height: 50,
+    };
+    writeln!(ctx, "Is square? {}", rect.is_square())?;
+    Ok(())
+}
+
+fn check_counter_new() {
+    assert_eq!(exercise::Counter::new().get(), 0);
+}
+
+fn check_counter_starting_at() {
+    assert_eq!(exercise::Counter::starting_at(41).get(), 41);
+}
+
+fn check_counter_increment() {
+    let mut counter = exercise::Counter::starting_at(1);
+    counter.increment();
+    counter.increment();
+    assert_eq!(counter.get(), 3);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rectangle_area() {
+        let rect = Rectangle {
+            width: 10,
+            height: 20,
+        };
+        assert_eq!(rect.area(), 200);
+    }
+
+    #[test]
+    fn test_can_hold() {
+        let rect1 = Rectangle {
+            width: 30,
+            height: 30,
+        };
+        let rect2 = Rectangle {
+            width: 10,
+            height: 10,
+        };
+        assert!(rect1.can_hold(&rect2));
+        assert!(!rect2.can_hold(&rect1));
+    }
+
+    #[test]
+    fn test_square_associated_fn() {
+        let square = Rectangle::square(10);
+        assert_eq!(square.width, 10);
+        assert_eq!(square.height, 10);
+        assert!(square.is_square());
+    }
+
+    #[test]
+    fn test_circle_area() {
+        let circle = Circle { radius: 1.0 };
+        let area = circle.area();
+        assert!((area - std::f64::consts::PI).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_circle_new() {
+        let circle = Circle::new(5.0);
+        assert_eq!(circle.radius, 5.0);
+    }
+
+    #[test]
+    fn test_point_distance() {
+        let p1 = Point { x: 0.0, y: 0.0 };
+        let p2 = Point { x: 3.0, y: 4.0 };
+        assert_eq!(p1.distance(&p2), 5.0);
+    }
+
+    #[test]
+    fn test_point_origin() {
+        let origin = Point::origin();
+        assert_eq!(origin.x, 0.0);
+        assert_eq!(origin.y, 0.0);
+    }
+}
@@ -0,0 +1,318 @@
+//! # Ownership Basics
+//!
+//! 目标：理解 Rust 所有权的核心概念
+//!
+//! ## 要点
+//! - Rust 的核心特性是所有权系统，无需垃圾回收即可保证内存安全
+//! - 每个值都有唯一的所有者（owner）
+//! - 当所有者超出作用域，值会被自动丢弃（drop）
+//! - 所有权转移（move）：将值赋给另一个变量时，所有权发生转移
+//! - 数据在栈上（如基本类型）会复制，在堆上（如 String）会移动
+//!
+//! ```rust
+//! use std::cell::Cell;
+//!
+//! let s1 = String::from("hello");
+//! let s2 = s1; // String 的数据在堆上：所有权移动到 s2，s1 不能再用
+//! assert_eq!(s2, "hello");
+//!
+//! let a = 5;
+//! let b = a; // i32 实现了 Copy：复制一份，a 仍然可用
+//! assert_eq!(a + b, 10);
+//!
+//! struct Guard<'a>(&'a Cell<u32>);
+//!
+//! impl Drop for Guard<'_> {
+//!     fn drop(&mut self) {
+//!         self.0.set(self.0.get() + 1);
+//!     }
+//! }
+//!
+//! let drops = Cell::new(0);
+//! {
+//!     let _guard = Guard(&drops);
+//! } // 所有者离开作用域，自动调用 drop
+//! assert_eq!(drops.get(), 1);
+//! ```
+//!
+//! ## 常见坑
+//! - 移动后原变量不能再使用
+//! - 不理解堆和栈的区别
+//! - 忘记所有权规则会导致编译错误
+//!
+//! ## 运行
+//! `cargo run -- 06_ownership`
+//!
+//! ## 示例
+//!
+//! 由 `cargo run -- update-doctests` 从演示代码中标出的片段生成，`cargo test --doc` 会运行它们。
+//!
+//! `copy`：
+//!
+//! ```rust
+//! let x = 5;
+//! let y = x; // x 被复制到 y，x 仍然有效
+//! println!("Stack values: x={}, y={}", x, y);
+//! ```
+//!
+//! `scope`：
+//!
+//! ```rust
+//! {
+//!     let s = String::from("inside scope");
+//!     println!("In scope: {}", s);
+//! } // s 在这里被 drop
+//!
+//! println!("Out of scope (s was dropped)");
+//! ```
+
+use crate::exercises::ownership as exercise;
+use crate::lesson::{self, Check, Koan, LessonContext, Pitfall, Question, Section};
+use std::io::{self, Write};
+
+pub const SECTIONS: &[Section] = &[
+    Section::new("ownership_move", "所有权基础", demo_ownership_move),
+    Section::new("scope_drop", "作用域与丢弃", demo_scope_drop),
+    Section::new("stack_heap", "栈 vs 堆", demo_stack_heap),
+];
+
+pub const QUIZ: &[Question] = &[
+    Question::new(
+        "`let s1 = String::from(\"hi\"); let s2 = s1; println!(\"{}\", s1);` 会怎样？",
+        &[
+            "打印 hi",
+            "编译错误：s1 的值已经移动到 s2",
+            "运行时 panic",
+            "打印空字符串",
+        ],
+        1,
+        "`String` 在堆上分配，赋值会转移所有权，之后 `s1` 不能再使用。",
+    ),
+    Question::new(
+        "为什么 `let a = 5; let b = a;` 之后 `a` 仍然可用？",
+        &[
+            "整数是 `Copy` 类型，赋值时按位复制",
+            "编译器自动调用了 `clone()`",
+            "`a` 是常量",
+            "整数存放在堆上",
+        ],
+        0,
+        "栈上的简单类型实现了 `Copy`，赋值时复制而不是移动。",
+    ),
+    Question::new(
+        "值在什么时候被 drop？",
+        &[
+            "调用 `free` 时",
+            "垃圾回收器运行时",
+            "所有者离开作用域时",
+            "程序结束时才统一释放",
+        ],
+        2,
+        "所有者离开作用域时自动调用 `drop`，无需垃圾回收。",
+    ),
+    Question::new(
+        "把 `String` 传给 `fn takes(s: String)` 之后，调用方的变量会怎样？",
+        &[
+            "仍然可用，函数拿到的是拷贝",
+            "所有权移入函数，调用方不能再使用它",
+            "函数返回时自动还回来",
+            "变成空字符串",
+        ],
+        1,
+        "传参和赋值一样会移动所有权；还想继续使用就传引用（`&str` / `&String`），或者让函数把值返回。",
+    ),
+];
+
+pub const EXERCISE: &[Check] = &[
+    Check::new("exclaim", check_exclaim),
+    Check::new("longer", check_longer),
+    Check::new("join_words", check_join_words),
+];
+
+pub const HINTS: &[&str] = &[
+    "拿到所有权的参数可以重新绑定为可变的再修改，最后把它作为返回值交还；只需要读取的参数用引用。",
+    "`exclaim` 写 `let mut s = s;` 后 `push('!')`；`longer` 比较 `len()` 后直接返回其中一个 `String`（另一个随之释放）；`join_words` 只借用 `words`。",
+    "`if b.len() > a.len() { b } else { a }`；`words.join(\" \")` 返回新的 `String`，不会拿走 `words`。",
+];
+
+pub const KOANS: &[Koan] = &[
+    Koan::new(
+        "clone",
+        r#"#[test]
+fn move_or_clone() {
+    let s1 = String::from("hello");
+    let s2 = s1.__();
+    assert_eq!(s1, "hello");
+    assert_eq!(s2, "hello");
+}
+"#,
+        &["clone"],
+    ),
+    Koan::new(
+        "copy_types",
+        r#"fn take(s: String) -> usize {
+    s.len()
+}
+
+#[test]
+fn copy_types() {
+    let s = String::from("hello");
+    assert_eq!(take(s), __);
+
+    let x = 5;
+    let y = x;
+    assert_eq!(x + y, __);
+}
+"#,
+        &["5", "10"],
+    ),
+];
+
+pub const PITFALLS: &[Pitfall] = &[
+    Pitfall::new(
+        "use_after_move",
+        include_str!("../pitfalls/ownership/use_after_move.rs"),
+        "`String` 没有实现 `Copy`，`let s2 = s1;` 把所有权移给了 `s2`，`s1` 从此失效，再使用就是 E0382。需要两份时用 `s1.clone()`，只是读取时借用 `&s1`。",
+    ).error("E0382"),
+    Pitfall::new(
+        "move_into_function",
+        include_str!("../pitfalls/ownership/move_into_function.rs"),
+        "按值传参同样会移动所有权：`takes_ownership(s)` 之后 `s` 已经不能用了。函数只需要读取时把参数改成 `&str` 并传 `&s`，或者让函数把所有权还回来。",
+    ).error("E0382"),
+];
+
+pub fn run(ctx: &mut LessonContext) -> lesson::Result {
+    lesson::run_sections(ctx, SECTIONS)
+}
+
+fn demo_ownership_move(ctx: &mut LessonContext) -> lesson::Result {
+    // 基本类型（在栈上）：会复制（Copy trait）
+    // ANCHOR: copy
+    let x = 5;
+    let y = x; // x 被复制到 y，x 仍然有效
+    writeln!(ctx, "Stack values: x={}, y={}", x, y)?;
+    // ANCHOR_END: copy
+
+    // String 类型（在堆上）：会发生移动（Move）
+    let s1 = String::from("hello");
+    let s2 = s1; // s1 的所有权移动给 s2，s1 失效
+
+    writeln!(ctx, "Heap values: s2={}", s2)?;
+    // writeln!(ctx, "s1={}", s1)?; // 这会导致编译错误！
+
+    // 克隆可以显式复制
+    let s3 = String::from("world");
+    let s4 = s3.clone();
+    writeln!(ctx, "Cloned: s3={}, s4={}", s3, s4)?;
+    Ok(())
+}
+
+fn demo_scope_drop(ctx: &mut LessonContext) -> lesson::Result {
+    // ANCHOR: scope
+    {
+        let s = String::from("inside scope");
+        writeln!(ctx, "In scope: {}", s)?;
+    } // s 在这里被 drop
+
+    writeln!(ctx, "Out of scope (s was dropped)")?;
+    // ANCHOR_END: scope
+
+    // 函数参数也会发生所有权转移
+    let s = String::from("give away");
+    take_ownership(ctx, s)?; // s 的所有权移动到函数
+    // writeln!(ctx, "s={}", s)?; // 编译错误：s 已被移动
+
+    // 基本类型实现了 Copy trait，不会移动
+    let x = 42;
+    make_copy(ctx, x)?;
+    writeln!(ctx, "x still works: {}", x)?;
+    Ok(())
+}
+
+fn take_ownership(ctx: &mut LessonContext, s: String) -> io::Result<()> {
+    writeln!(ctx, "I took ownership of: {}", s)?;
+    // s 在函数结束时被 drop
+    Ok(())
+}
+
+fn make_copy(ctx: &mut LessonContext, x: i32) -> io::Result<()> {
+    writeln!(ctx, "I made a copy of: {}", x)?;
+    // x 在函数结束时不会 drop，因为是 Copy
+    Ok(())
+}
+
+fn demo_stack_heap(ctx: &mut LessonContext) -> lesson::Result {
+    writeln!(ctx, "Stack types (Copy trait):")?;
+    let a = 10;
+    let b = a;
+    writeln!(ctx, "  Integers: a={}, b={}", a, b)?;
+
+    let c = true;
+    let d = c;
+    writeln!(ctx, "  Booleans: c={}, d={}", c, d)?;
+
+    writeln!(ctx, "Heap types (Move semantics):")?;
+    let s1 = String::from("hello");
+    let s2 = s1;
+    writeln!(ctx, "  String: s2={}", s2)?;
+    Ok(())
+}
+
+fn check_exclaim() {
+    assert_eq!(exercise::exclaim(String::from("hello")), "hello!");
+}
+
+fn check_longer() {
+    let (a, b) = (String::from("owner"), String::from("borrower"));
+    assert_eq!(exercise::longer(a, b), "borrower");
+    assert_eq!(
+        exercise::longer(String::from("ab"), String::from("cd")),
+        "ab"
+    );
+}
+
+fn check_join_words() {
+    let words = vec![String::from("move"), String::from("semantics")];
+    assert_eq!(exercise::join_words(&words), "move semantics");
+    assert_eq!(words.len(), 2);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_copy_trait() {
+        let x = 5;
+        let y = x;
+        assert_eq!(x, 5);
+        assert_eq!(y, 5);
+    }
+
+    #[test]
+    fn test_clone() {
+        let s1 = String::from("test");
+        let s2 = s1.clone();
+        assert_eq!(s1, "test");
+        assert_eq!(s2, "test");
+    }
+
+    #[test]
+    fn test_function_ownership() {
+        let mut out = Vec::new();
+        let mut ctx = LessonContext::new(&mut out);
+        let s = String::from("hello");
+        // s 被移动到函数中
+        take_ownership(&mut ctx, s).unwrap();
+        // s 不能再使用
+
+        let x = 42;
+        make_copy(&mut ctx, x).unwrap();
+        // x 仍然可用
+        assert_eq!(x, 42);
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "I took ownership of: hello\nI made a copy of: 42\n"
+        );
+    }
+}
@@ -0,0 +1,328 @@
+//! # String & Array Slices
+//!
+//! 目标：理解 Rust 中的切片（slice）类型
+//!
+//! ## 要点
+//! - 切片是对集合的部分引用，不拥有所有权
+//! - 字符串切片：`&str` 是对 String 的部分引用
+//! - 数组切片：`&[T]` 是对数组或向量的部分引用
+//! - 切片是胖指针，包含指针和长度信息
+//! - 字符串字面值是切片：`let s = "hello";` 类型是 `&str`
+//!
+//! ```rust
+//! let s = String::from("hello world");
+//! let hello: &str = &s[..5]; // 引用 String 的一部分，不复制数据
+//! assert_eq!(hello, "hello");
+//!
+//! let arr = [1, 2, 3, 4, 5];
+//! let middle: &[i32] = &arr[1..4];
+//! assert_eq!(middle, [2, 3, 4]);
+//!
+//! // 胖指针：地址加长度，是普通引用的两倍大
+//! assert_eq!(std::mem::size_of::<&[i32]>(), 2 * std::mem::size_of::<&i32>());
+//!
+//! let literal: &str = "hello"; // 字面值本身就是 &str
+//! assert_eq!(literal, hello);
+//! ```
+//!
+//! ## 常见坑
+//! - 切片索引越界会导致 panic
+//! - 混淆 String 和 &str
+//! - 忘记切片的生命周期不能超过原数据
+//!
+//! ## 运行
+//! `cargo run -- 08_slices`
+//!
+//! ## 示例
+//!
+//! 由 `cargo run -- update-doctests` 从演示代码中标出的片段生成，`cargo test --doc` 会运行它们。
+//!
+//! `string_slices`：
+//!
+//! ```rust
+//! let s = String::from("hello world");
+//!
+//! let hello = &s[0..5]; // 或 &s[..5]
+//! let world = &s[6..11]; // 或 &s[6..]
+//! let whole = &s[..]; // 整个字符串
+//!
+//! println!("Original: '{}'", s);
+//! println!("hello: '{}'", hello);
+//! println!("world: '{}'", world);
+//! println!("whole: '{}'", whole);
+//!
+//! // 字符串字面值就是切片
+//! let literal: &str = "hello";
+//! println!("String literal: '{}'", literal);
+//! ```
+
+use crate::exercises::slices as exercise;
+use crate::lesson::{self, Check, Koan, LessonContext, Pitfall, Question, Section};
+use std::io::{self, Write};
+
+pub const SECTIONS: &[Section] = &[
+    Section::new("string_slices", "字符串切片", demo_string_slices),
+    Section::new("array_slices", "数组切片", demo_array_slices),
+    Section::new("slices_as_params", "切片作为参数", demo_slices_as_params),
+    Section::new("other_slices", "其他切片类型", demo_other_slices),
+];
+
+pub const QUIZ: &[Question] = &[
+    Question::new(
+        "`let s = \"hello\";` 中 `s` 的类型是？",
+        &["`String`", "`&str`", "`&String`", "`[char; 5]`"],
+        1,
+        "字符串字面值是指向程序只读数据的 `&str` 切片。",
+    ),
+    Question::new(
+        "切片（如 `&[i32]`）在内存中包含什么？",
+        &[
+            "只有指针",
+            "指针和长度",
+            "完整数据的拷贝",
+            "指针、长度和容量",
+        ],
+        1,
+        "切片是胖指针：起始地址加长度；容量是 `Vec` 与 `String` 才有的。",
+    ),
+    Question::new(
+        "`&\"你好\"[0..1]` 会怎样？",
+        &[
+            "得到 \"你\"",
+            "得到空字符串",
+            "运行时 panic：切在了字符中间",
+            "编译错误",
+        ],
+        2,
+        "字符串切片按字节索引，\"你\" 占 3 个字节，边界必须落在字符边界上。",
+    ),
+    Question::new(
+        "参数写成 `s: &str` 比 `s: &String` 好在哪里？",
+        &[
+            "运行更快",
+            "可以修改字符串",
+            "没有区别",
+            "既能接收 `&String`（自动转换），也能接收字符串字面量",
+        ],
+        3,
+        "`&String` 会通过 deref 强制转换变成 `&str`，参数写 `&str` 适用面更广。",
+    ),
+];
+
+pub const EXERCISE: &[Check] = &[
+    Check::new("first_word", check_first_word),
+    Check::new("sum", check_sum),
+    Check::new("middle", check_middle),
+];
+
+pub const HINTS: &[&str] = &[
+    "切片是对原数据一段连续区域的引用，用 `&s[a..b]` 取得；返回的切片借用参数，不需要分配新内存。",
+    "`first_word` 用 `find(' ')` 找到第一个空格的位置；`sum` 可以直接对 `iter()` 求和；`middle` 先处理长度小于 2 的情况。",
+    "`match s.find(' ') { Some(i) => &s[..i], None => s }`；`middle` 返回 `&numbers[1..numbers.len() - 1]`，长度不足时返回 `&[]`。",
+];
+
+pub const KOANS: &[Koan] = &[
+    Koan::new(
+        "string_slices",
+        r#"#[test]
+fn string_slices() {
+    let s = String::from("hello world");
+    let hello = &s[..5];
+    let world = &s[__..];
+    assert_eq!(hello, "hello");
+    assert_eq!(world, "world");
+}
+"#,
+        &["6"],
+    ),
+    Koan::new(
+        "array_slices",
+        r#"#[test]
+fn array_slices() {
+    let a = [1, 2, 3, 4, 5];
+    let slice: &[i32] = &a[1..__];
+    assert_eq!(slice, &[2, 3]);
+    assert_eq!(slice.len(), __);
+}
+"#,
+        &["3", "2"],
+    ),
+];
+
+pub const PITFALLS: &[Pitfall] = &[Pitfall::new(
+    "clear_while_sliced",
+    include_str!("../pitfalls/slices/clear_while_sliced.rs"),
+    "`word` 是借用 `s` 的切片，只要之后还要用 `word`，`s` 就不能被修改；`clear` 需要 `&mut s`（E0502）。编译器在这里阻止了运行时才会出现的悬空切片。先用完 `word` 再修改 `s`。",
+).error("E0502")];
+
+pub fn run(ctx: &mut LessonContext) -> lesson::Result {
+    lesson::run_sections(ctx, SECTIONS)
+}
+
+fn demo_string_slices(ctx: &mut LessonContext) -> lesson::Result {
+    // ANCHOR: string_slices
+    let s = String::from("hello world");
+
+    let hello = &s[0..5]; // 或 &s[..5]
+    let world = &s[6..11]; // 或 &s[6..]
+    let whole = &s[..]; // 整个字符串
+
+    writeln!(ctx, "Original: '{}'", s)?;
+    writeln!(ctx, "hello: '{}'", hello)?;
+    writeln!(ctx, "world: '{}'", world)?;
+    writeln!(ctx, "whole: '{}'", whole)?;
+
+    // 字符串字面值就是切片
+    let literal: &str = "hello";
+    writeln!(ctx, "String literal: '{}'", literal)?;
+    // ANCHOR_END: string_slices
+    Ok(())
+}
+
+fn demo_array_slices(ctx: &mut LessonContext) -> lesson::Result {
+    let arr = [1, 2, 3, 4, 5];
+
+    let slice1 = &arr[1..3];
+    let slice2 = &arr[..3];
+    let slice3 = &arr[2..];
+    let slice4 = &arr[..];
+
+    writeln!(ctx, "Original array: {:?}", arr)?;
+    writeln!(ctx, "arr[1..3]: {:?}", slice1)?;
+    writeln!(ctx, "arr[..3]: {:?}", slice2)?;
+    writeln!(ctx, "arr[2..]: {:?}", slice3)?;
+    writeln!(ctx, "arr[..]: {:?}", slice4)?;
+    Ok(())
+}
+
+fn demo_slices_as_params(ctx: &mut LessonContext) -> lesson::Result {
+    let s = String::from("Rust Programming");
+    let arr = vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+
+    writeln!(ctx, "First word of '{}' is '{}'", s, first_word(&s))?;
+    writeln!(ctx, "First 3 elements: {:?}", first_n(&arr, 3))?;
+    writeln!(ctx, "Second half: {:?}", second_half(&arr))?;
+    Ok(())
+}
+
+#[allow(clippy::redundant_slicing)]
+fn first_word(s: &str) -> &str {
+    let bytes = s.as_bytes();
+    for (i, &item) in bytes.iter().enumerate() {
+        if item == b' ' {
+            return &s[0..i];
+        }
+    }
+    &s[..]
+}
+
+fn first_n(arr: &[i32], n: usize) -> &[i32] {
+    &arr[..n.min(arr.len())]
+}
+
+fn second_half(arr: &[i32]) -> &[i32] {
+    &arr[arr.len() / 2..]
+}
+
+fn demo_other_slices(ctx: &mut LessonContext) -> lesson::Result {
+    let mut v = vec![1, 2, 3, 4, 5];
+
+    // 切片可以修改原数据
+    modify_first_two(&mut v);
+    writeln!(ctx, "After modification: {:?}", v)?;
+
+    // 范围包含和排除
+    writeln!(ctx, "Inclusive range 1..=3 from {:?}: {:?}", v, &v[1..=3])?;
+    writeln!(ctx, "Exclusive range 1..4 from {:?}: {:?}", v, &v[1..4])?;
+    Ok(())
+}
+
+fn modify_first_two(slice: &mut [i32]) {
+    if slice.len() >= 2 {
+        slice[0] = 100;
+        slice[1] = 200;
+    }
+}
+
+fn check_first_word() {
+    assert_eq!(exercise::first_word("hello world"), "hello");
+    assert_eq!(exercise::first_word("single"), "single");
+    assert_eq!(exercise::first_word(""), "");
+}
+
+fn check_sum() {
+    assert_eq!(exercise::sum(&[1, 2, 3, 4]), 10);
+    assert_eq!(exercise::sum(&[]), 0);
+}
+
+fn check_middle() {
+    assert_eq!(exercise::middle(&[1, 2, 3, 4]), [2, 3]);
+    assert!(exercise::middle(&[1]).is_empty());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_string_slice() {
+        let s = String::from("hello");
+        let slice = &s[1..3];
+        assert_eq!(slice, "el");
+    }
+
+    #[test]
+    fn test_array_slice() {
+        let arr = [1, 2, 3, 4, 5];
+        let slice = &arr[1..4];
+        assert_eq!(slice, [2, 3, 4]);
+    }
+
+    #[test]
+    fn test_first_word() {
+        assert_eq!(first_word("hello world"), "hello");
+        assert_eq!(first_word("rust"), "rust");
+        assert_eq!(first_word(""), "");
+    }
+
+    #[test]
+    fn test_first_n() {
+        let arr = [1, 2, 3, 4, 5];
+        assert_eq!(first_n(&arr, 3), [1, 2, 3]);
+        assert_eq!(first_n(&arr, 10), [1, 2, 3, 4, 5]);
+        assert_eq!(first_n(&arr, 0), [] as [i32; 0]);
+    }
+
+    #[test]
+    fn test_modify_slice() {
+        let mut arr = vec![1, 2, 3, 4, 5];
+        modify_first_two(&mut arr);
+        assert_eq!(arr[0], 100);
+        assert_eq!(arr[1], 200);
+        assert_eq!(arr[2], 3);
+    }
+}
+
+#[cfg(test)]
+mod properties {
+    use super::*;
+
+    // 性质测试：不列举具体的例子，而是说明对任意输入都成立的性质，由 proptest 生成输入去验证
+    proptest::proptest! {
+        #[test]
+        fn prop_first_word(s in "[a-z ]{0,20}") {
+            let word = first_word(&s);
+            // 结果是开头的一段，不含空格，后面要么什么也没有，要么紧跟着一个空格
+            proptest::prop_assert!(s.starts_with(word));
+            proptest::prop_assert!(!word.contains(' '));
+            proptest::prop_assert!(s.len() == word.len() || s[word.len()..].starts_with(' '));
+        }
+
+        #[test]
+        fn prop_first_n(arr in proptest::collection::vec(proptest::num::i32::ANY, 0..20), n in 0usize..30) {
+            let head = first_n(&arr, n);
+            proptest::prop_assert_eq!(head.len(), n.min(arr.len()));
+            proptest::prop_assert!(arr.starts_with(head));
+        }
+    }
+}
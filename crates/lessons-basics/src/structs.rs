@@ -0,0 +1,350 @@
+//! # Structs & Update Syntax
+//!
+//! 目标：理解 Rust 中的结构体（struct）定义和使用
+//!
+//! ## 要点
+//! - 结构体是自定义数据类型，可以组合多个值
+//! - 三种结构体：命名字段、元组结构体、unit 结构体
+//! - 字段初始化简写：变量名和字段名相同时可省略
+//! - 结构体更新语法：从其他结构体创建新实例
+//! - Debug trait：使用 `#[derive(Debug)]` 打印调试信息
+//!
+//! ```rust
+//! #[derive(Debug, Clone)]
+//! struct User {
+//!     name: String,
+//!     active: bool,
+//! }
+//!
+//! struct Point(i32, i32); // 元组结构体
+//! struct Marker; // unit 结构体
+//!
+//! let name = String::from("ferris");
+//! let user = User { name, active: true }; // 字段初始化简写
+//! let other = User {
+//!     name: String::from("crab"),
+//!     ..user.clone() // 结构体更新语法：其余字段取自 user
+//! };
+//! assert!(other.active);
+//! assert_eq!(Point(1, 2).0, 1);
+//! let _marker = Marker;
+//! assert_eq!(format!("{:?}", user), r#"User { name: "ferris", active: true }"#);
+//! ```
+//!
+//! ## 常见坑
+//! - 结构体实例默认不可变，需要 `mut` 才能修改字段
+//! - 忘记 `#[derive(Debug)]` 导致无法打印
+//! - 结构体更新语法会移动字段所有权
+//!
+//! ## 运行
+//! `cargo run -- 09_structs`
+//!
+//! ## 示例
+//!
+//! 由 `cargo run -- update-doctests` 从演示代码中标出的片段生成，`cargo test --doc` 会运行它们。
+//!
+//! `tuple_structs`：
+//!
+//! ```rust
+//! #[derive(Debug)]
+//! struct Color(u8, u8, u8);
+//!
+//! let black = Color(0, 0, 0);
+//! let white = Color(255, 255, 255);
+//!
+//! println!("Black: {:?}", black);
+//! println!("White: {:?}", white);
+//! println!("Red component of black: {}", black.0);
+//! ```
+
+use crate::exercises::structs as exercise;
+use crate::lesson::{self, Check, Koan, LessonContext, Pitfall, Question, Section};
+use std::io::{self, Write};
+
+#[derive(Debug)]
+struct User {
+    username: String,
+    email: String,
+    sign_in_count: u64,
+    active: bool,
+}
+
+#[derive(Debug)]
+struct Rectangle {
+    width: u32,
+    height: u32,
+}
+
+// ANCHOR: tuple_structs
+#[derive(Debug)]
+struct Color(u8, u8, u8);
+// ANCHOR_END: tuple_structs
+
+#[derive(Debug)]
+struct AlwaysEqual;
+
+pub const SECTIONS: &[Section] = &[
+    Section::new("named_structs", "命名字段结构体", demo_named_structs),
+    Section::new("tuple_structs", "元组结构体", demo_tuple_structs),
+    Section::new("unit_structs", "Unit 结构体", demo_unit_structs),
+    Section::new("struct_update", "结构体更新语法", demo_struct_update),
+];
+
+pub const QUIZ: &[Question] = &[
+    Question::new(
+        "`let p = Point { x: 1, y: 2 }; p.x = 5;` 为什么编译失败？",
+        &[
+            "字段默认是私有的",
+            "实例默认不可变，需要 `let mut p`",
+            "必须用 setter 方法",
+            "x 是 i32 不能修改",
+        ],
+        1,
+        "可变性属于整个绑定，Rust 不支持只把部分字段标记为可变。",
+    ),
+    Question::new(
+        "`println!(\"{:?}\", p)` 需要什么？",
+        &[
+            "为类型实现 `Display`",
+            "为类型加上 `#[derive(Debug)]`",
+            "字段都是 `pub`",
+            "什么都不用",
+        ],
+        1,
+        "`{:?}` 使用 `Debug` trait，可以用 `#[derive(Debug)]` 自动实现。",
+    ),
+    Question::new(
+        "`let u2 = User { email: e, ..u1 };` 之后，什么情况下 u1 整体不能再用？",
+        &[
+            "总是不能再用",
+            "被移动的字段中有 `String` 这类非 Copy 类型时",
+            "u1 没有加 mut 时",
+            "永远可以继续使用",
+        ],
+        1,
+        "更新语法会移动剩余字段；只要移动了非 `Copy` 字段，u1 就不能再整体使用。",
+    ),
+    Question::new(
+        "元组结构体 `struct Meters(f64);` 的值 `m` 怎么取出里面的数？",
+        &["`m.0`", "`m[0]`", "`m.value`", "`m.get(0)`"],
+        0,
+        "元组结构体的字段没有名字，按位置用 `.0`、`.1` 访问，常用来给已有类型套一层新类型。",
+    ),
+];
+
+pub const EXERCISE: &[Check] = &[
+    Check::new("area", check_area),
+    Check::new("square", check_square),
+    Check::new("with_width", check_with_width),
+];
+
+pub const HINTS: &[&str] = &[
+    "通过 `rect.width` 访问字段；创建结构体要写出所有字段，结构体更新语法 `..other` 可以补上其余字段。",
+    "`square` 返回 `Rectangle { width: size, height: size }`；`with_width` 只需要写出改变的字段。",
+    "`Rectangle { width, ..rect }`：字段初始化简写加上更新语法，`height` 取自 `rect`。",
+];
+
+pub const KOANS: &[Koan] = &[
+    Koan::new(
+        "struct_update",
+        r#"struct User {
+    username: String,
+    email: String,
+    active: bool,
+}
+
+#[test]
+fn struct_update() {
+    let user1 = User {
+        username: String::from("alice"),
+        email: String::from("alice@example.com"),
+        active: true,
+    };
+    let user2 = User {
+        email: String::from("bob@example.com"),
+        __
+    };
+    assert_eq!(user2.username, "alice");
+    assert!(user2.active);
+}
+"#,
+        &["..user1"],
+    ),
+    Koan::new(
+        "tuple_structs",
+        r#"struct Color(u8, u8, u8);
+
+#[test]
+fn tuple_structs() {
+    let black = Color(0, 0, 0);
+    let Color(r, g, b) = Color(255, 128, 0);
+    assert_eq!(black.__, 0);
+    assert_eq!(r as u32 + g as u32 + b as u32, __);
+}
+"#,
+        &["0", "383"],
+    ),
+];
+
+pub const PITFALLS: &[Pitfall] = &[Pitfall::new(
+    "mutate_immutable_instance",
+    include_str!("../pitfalls/structs/mutate_immutable_instance.rs"),
+    "结构体实例与普通变量一样默认不可变，不能单独把某个字段声明为可变（E0594）。需要修改字段时把整个实例声明为 `let mut user`。",
+).error("E0594")];
+
+pub fn run(ctx: &mut LessonContext) -> lesson::Result {
+    lesson::run_sections(ctx, SECTIONS)
+}
+
+fn demo_named_structs(ctx: &mut LessonContext) -> lesson::Result {
+    let mut user = User {
+        email: String::from("user@example.com"),
+        username: String::from("rustacean"),
+        active: true,
+        sign_in_count: 1,
+    };
+
+    writeln!(ctx, "User: {:#?}", user)?;
+
+    user.email = String::from("newemail@example.com");
+    user.sign_in_count += 1;
+
+    writeln!(ctx, "Updated user: {:#?}", user)?;
+
+    let rect = Rectangle {
+        width: 30,
+        height: 50,
+    };
+    writeln!(ctx, "Rectangle: {:?}", rect)?;
+    writeln!(ctx, "Rectangle area: {}", rect.width * rect.height)?;
+    Ok(())
+}
+
+fn demo_tuple_structs(ctx: &mut LessonContext) -> lesson::Result {
+    // ANCHOR: tuple_structs
+    let black = Color(0, 0, 0);
+    let white = Color(255, 255, 255);
+
+    writeln!(ctx, "Black: {:?}", black)?;
+    writeln!(ctx, "White: {:?}", white)?;
+    writeln!(ctx, "Red component of black: {}", black.0)?;
+    // ANCHOR_END: tuple_structs
+    Ok(())
+}
+
+fn demo_unit_structs(ctx: &mut LessonContext) -> lesson::Result {
+    let subject = AlwaysEqual;
+    writeln!(ctx, "Unit struct: {:?}", subject)?;
+    Ok(())
+}
+
+fn demo_struct_update(ctx: &mut LessonContext) -> lesson::Result {
+    let user1 = User {
+        email: String::from("user1@example.com"),
+        username: String::from("user1"),
+        active: true,
+        sign_in_count: 5,
+    };
+
+    let user2 = User {
+        email: String::from("user2@example.com"),
+        username: String::from("user2"),
+        ..user1 // 使用 user1 的 active 和 sign_in_count
+    };
+
+    writeln!(ctx, "User1: {:#?}", user1)?;
+    writeln!(ctx, "User2: {:#?}", user2)?;
+
+    let rect1 = Rectangle {
+        width: 30,
+        height: 50,
+    };
+
+    let rect2 = Rectangle {
+        width: 10,
+        ..rect1 // 使用 rect1 的 height
+    };
+
+    writeln!(ctx, "Rect1: {:?}", rect1)?;
+    writeln!(ctx, "Rect2: {:?}", rect2)?;
+    Ok(())
+}
+
+fn check_area() {
+    let rect = exercise::Rectangle {
+        width: 3,
+        height: 4,
+    };
+    assert_eq!(exercise::area(&rect), 12);
+}
+
+fn check_square() {
+    let square = exercise::square(5);
+    assert_eq!((square.width, square.height), (5, 5));
+}
+
+fn check_with_width() {
+    let rect = exercise::Rectangle {
+        width: 3,
+        height: 4,
+    };
+    let wide = exercise::with_width(rect, 10);
+    assert_eq!((wide.width, wide.height), (10, 4));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_user_creation() {
+        let user = User {
+            email: String::from("test@example.com"),
+            username: String::from("testuser"),
+            active: true,
+            sign_in_count: 1,
+        };
+        assert_eq!(user.email, "test@example.com");
+        assert_eq!(user.username, "testuser");
+        assert!(user.active);
+    }
+
+    #[test]
+    fn test_user_mutation() {
+        let mut user = User {
+            email: String::from("test@example.com"),
+            username: String::from("test"),
+            active: true,
+            sign_in_count: 1,
+        };
+        user.sign_in_count = 2;
+        assert_eq!(user.sign_in_count, 2);
+    }
+
+    #[test]
+    fn test_struct_update() {
+        let user1 = User {
+            email: String::from("user1@example.com"),
+            username: String::from("user1"),
+            active: true,
+            sign_in_count: 5,
+        };
+
+        let user2 = User {
+            email: String::from("user2@example.com"),
+            username: String::from("user2"),
+            ..user1
+        };
+
+        assert_eq!(user2.active, user1.active);
+        assert_eq!(user2.sign_in_count, user1.sign_in_count);
+    }
+
+    #[test]
+    fn test_tuple_struct() {
+        let color = Color(255, 128, 0);
+        assert_eq!(color.0, 255);
+        assert_eq!(color.1, 128);
+        assert_eq!(color.2, 0);
+    }
+}
@@ -0,0 +1,529 @@
+//! # Traits & Trait Bounds
+//!
+//! 目标：理解 Rust 中的 trait 和 trait bounds
+//!
+//! ## 要点
+//! - Trait 定义共享行为，类似于其他语言的接口
+//! - Trait 可以包含方法签名、默认实现、关联类型
+//! - 使用 `impl Trait for Type` 为类型实现 trait
+//! - Trait bounds 限制泛型参数必须实现特定 trait
+//! - 可以使用 `+` 指定多个 trait bounds
+//!
+//! ```rust
+//! use std::fmt;
+//!
+//! trait Describe {
+//!     fn name(&self) -> String;
+//!
+//!     // 默认实现，实现者可以覆盖
+//!     fn describe(&self) -> String {
+//!         format!("This is {}", self.name())
+//!     }
+//! }
+//!
+//! struct Dog;
+//!
+//! impl Describe for Dog {
+//!     fn name(&self) -> String {
+//!         String::from("a dog")
+//!     }
+//! }
+//!
+//! impl fmt::Display for Dog {
+//!     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+//!         write!(f, "Dog")
+//!     }
+//! }
+//!
+//! // 多个 trait bound 用 + 连接
+//! fn show<T: Describe + fmt::Display>(item: &T) -> String {
+//!     format!("{}: {}", item, item.describe())
+//! }
+//!
+//! assert_eq!(Dog.describe(), "This is a dog");
+//! assert_eq!(show(&Dog), "Dog: This is a dog");
+//! ```
+//!
+//! ## 常见坑
+//! - 忘记实现 trait 中的所有必需方法
+//! - trait 对象的动态分发有性能开销
+//! - trait 和类型必须在同一个 crate 中（孤儿规则）
+//!
+//! ## 运行
+//! `cargo run -- 13_traits`
+//!
+//! ## 示例
+//!
+//! 由 `cargo run -- update-doctests` 从演示代码中标出的片段生成，`cargo test --doc` 会运行它们。
+//!
+//! `news_article`：
+//!
+//! ```rust
+//! pub trait Summary {
+//!     fn summarize(&self) -> String {
+//!         String::from("(Read more...)")
+//!     }
+//!
+//!     fn summarize_author(&self) -> String;
+//! }
+//!
+//! pub struct NewsArticle {
+//!     pub headline: String,
+//!     pub location: String,
+//!     pub author: String,
+//!     pub content: String,
+//! }
+//!
+//! impl Summary for NewsArticle {
+//!     fn summarize(&self) -> String {
+//!         format!("{}, by {} ({})", self.headline, self.author, self.location)
+//!     }
+//!
+//!     fn summarize_author(&self) -> String {
+//!         format!("@{}", self.author)
+//!     }
+//! }
+//!
+//! let article = NewsArticle {
+//!     headline: String::from("Penguins win the Stanley Cup Championship!"),
+//!     location: String::from("Pittsburgh"),
+//!     author: String::from("Iceburgh"),
+//!     content: String::from(
+//!         "The Pittsburgh Penguins once again are the best hockey team in the NHL.",
+//!     ),
+//! };
+//!
+//! println!("New article available! {}", article.summarize());
+//! ```
+
+use crate::exercises::traits as exercise;
+use crate::lesson::{self, Check, Koan, LessonContext, Pitfall, Question, Section};
+use std::fmt;
+use std::io::{self, Write};
+
+// ANCHOR: news_article
+pub trait Summary {
+    fn summarize(&self) -> String {
+        String::from("(Read more...)")
+    }
+
+    fn summarize_author(&self) -> String;
+}
+// ANCHOR_END: news_article
+
+pub trait Display {
+    fn display(&self) -> String;
+}
+
+// ANCHOR: news_article
+pub struct NewsArticle {
+    pub headline: String,
+    pub location: String,
+    pub author: String,
+    pub content: String,
+}
+// ANCHOR_END: news_article
+
+pub struct Tweet {
+    pub username: String,
+    pub content: String,
+    pub reply: bool,
+    pub retweet: bool,
+}
+
+// ANCHOR: news_article
+impl Summary for NewsArticle {
+    fn summarize(&self) -> String {
+        format!("{}, by {} ({})", self.headline, self.author, self.location)
+    }
+
+    fn summarize_author(&self) -> String {
+        format!("@{}", self.author)
+    }
+}
+// ANCHOR_END: news_article
+
+impl Summary for Tweet {
+    fn summarize(&self) -> String {
+        format!("{}: {}", self.username, self.content)
+    }
+
+    fn summarize_author(&self) -> String {
+        format!("@{}", self.username)
+    }
+}
+
+impl Display for Tweet {
+    fn display(&self) -> String {
+        format!("Tweet by {}: {}", self.username, self.content)
+    }
+}
+
+struct Point {
+    x: f64,
+    y: f64,
+}
+
+trait Drawable {
+    fn draw(&self, ctx: &mut LessonContext) -> io::Result<()>;
+}
+
+trait Movable {
+    fn move_to(&mut self, x: f64, y: f64);
+}
+
+impl Drawable for Point {
+    fn draw(&self, ctx: &mut LessonContext) -> io::Result<()> {
+        writeln!(ctx, "Drawing point at ({}, {})", self.x, self.y)?;
+        Ok(())
+    }
+}
+
+impl Movable for Point {
+    fn move_to(&mut self, x: f64, y: f64) {
+        self.x = x;
+        self.y = y;
+    }
+}
+
+pub const SECTIONS: &[Section] = &[
+    Section::new(
+        "trait_implementation",
+        "Trait 实现",
+        demo_trait_implementation,
+    ),
+    Section::new(
+        "default_implementation",
+        "默认实现",
+        demo_default_implementation,
+    ),
+    Section::new("trait_bounds", "Trait Bounds", demo_trait_bounds),
+    Section::new("multiple_bounds", "多个 Trait Bounds", demo_multiple_bounds),
+    Section::new("trait_as_param", "Trait 作为参数", demo_trait_as_param),
+];
+
+pub const QUIZ: &[Question] = &[
+    Question::new(
+        "为 `Vec<T>` 实现标准库的 `Display` 为什么不行？",
+        &[
+            "Vec 已经实现了 Display",
+            "孤儿规则：trait 与类型至少有一个定义在当前 crate",
+            "Display 不能为泛型类型实现",
+            "需要 unsafe",
+        ],
+        1,
+        "孤儿规则防止不同 crate 的实现互相冲突；可以用 newtype 包一层。",
+    ),
+    Question::new(
+        "trait 中带默认实现的方法，实现者需要？",
+        &[
+            "必须重新实现",
+            "可以直接使用，也可以覆盖",
+            "不能覆盖",
+            "必须标注 `#[default]`",
+        ],
+        1,
+        "默认实现可以直接继承，需要时再覆盖。",
+    ),
+    Question::new(
+        "`fn notify(item: &(impl Summary + Display))` 中的 `+` 表示？",
+        &[
+            "item 需要同时实现两个 trait",
+            "实现其中一个即可",
+            "合并两个 trait 的方法名",
+            "语法错误",
+        ],
+        0,
+        "`+` 指定多个 trait bound，等价于 `<T: Summary + Display>`。",
+    ),
+    Question::new(
+        "`Box<dyn Trait>` 与泛型 `T: Trait` 的区别是？",
+        &[
+            "完全相同",
+            "dyn 在运行时通过虚表动态分发，泛型在编译期单态化",
+            "dyn 更快",
+            "泛型只能用于结构体",
+        ],
+        1,
+        "trait 对象可以把不同类型放进同一个集合，代价是一次间接调用。",
+    ),
+];
+
+pub const EXERCISE: &[Check] = &[
+    Check::new("Square::area", check_square_area),
+    Check::new("Circle::area", check_circle_area),
+    Check::new("total_area", check_total_area),
+];
+
+pub const HINTS: &[&str] = &[
+    "为类型实现 trait 时，只需要补全没有默认实现的方法；`dyn Shape` 的值只能调用 trait 中的方法。",
+    "正方形面积是边长的平方，圆面积是 π r²（`std::f64::consts::PI`）；`total_area` 遍历 `Box<dyn Shape>` 并累加 `area()`。",
+    "`self.0 * self.0`、`std::f64::consts::PI * self.0 * self.0`、`shapes.iter().map(|s| s.area()).sum()`。",
+];
+
+pub const KOANS: &[Koan] = &[
+    Koan::new(
+        "implement_trait",
+        r#"trait Summary {
+    fn summarize(&self) -> String;
+}
+
+struct Tweet {
+    username: String,
+    content: String,
+}
+
+impl __ for Tweet {
+    fn summarize(&self) -> String {
+        format!("{}: {}", self.username, self.content)
+    }
+}
+
+#[test]
+fn implement_trait() {
+    let tweet = Tweet {
+        username: String::from("rustlang"),
+        content: String::from("Rust 1.0 released"),
+    };
+    assert_eq!(tweet.summarize(), "rustlang: Rust 1.0 released");
+}
+"#,
+        &["Summary"],
+    ),
+    Koan::new(
+        "default_method",
+        r#"trait Greet {
+    fn name(&self) -> String;
+
+    fn greet(&self) -> String {
+        format!("Hello, {}!", self.name())
+    }
+}
+
+struct Ferris;
+
+impl Greet for Ferris {
+    fn name(&self) -> String {
+        String::from("Ferris")
+    }
+}
+
+fn greet_twice(item: &impl __) -> String {
+    format!("{} {}", item.greet(), item.greet())
+}
+
+#[test]
+fn default_method() {
+    assert_eq!(Ferris.greet(), __);
+    assert_eq!(greet_twice(&Ferris), "Hello, Ferris! Hello, Ferris!");
+}
+"#,
+        &["Greet", "\"Hello, Ferris!\""],
+    ),
+];
+
+pub const PITFALLS: &[Pitfall] = &[Pitfall::new(
+    "missing_method",
+    include_str!("../pitfalls/traits/missing_method.rs"),
+    "实现 trait 时必须提供所有没有默认实现的方法，漏掉 `summarize` 会报 E0046。补上实现，或者在 trait 中给它写一个默认实现。",
+).error("E0046")];
+
+pub fn run(ctx: &mut LessonContext) -> lesson::Result {
+    lesson::run_sections(ctx, SECTIONS)
+}
+
+fn demo_trait_implementation(ctx: &mut LessonContext) -> lesson::Result {
+    // ANCHOR: news_article
+    let article = NewsArticle {
+        headline: String::from("Penguins win the Stanley Cup Championship!"),
+        location: String::from("Pittsburgh"),
+        author: String::from("Iceburgh"),
+        content: String::from(
+            "The Pittsburgh Penguins once again are the best hockey team in the NHL.",
+        ),
+    };
+    // ANCHOR_END: news_article
+
+    let tweet = Tweet {
+        username: String::from("horse_ebooks"),
+        content: String::from("content"),
+        reply: false,
+        retweet: false,
+    };
+
+    // ANCHOR: news_article
+    writeln!(ctx, "New article available! {}", article.summarize())?;
+    // ANCHOR_END: news_article
+    writeln!(ctx, "1 new tweet: {}", tweet.summarize())?;
+    Ok(())
+}
+
+fn demo_default_implementation(ctx: &mut LessonContext) -> lesson::Result {
+    struct BlogPost {
+        title: String,
+        author: String,
+    }
+
+    impl Summary for BlogPost {
+        fn summarize_author(&self) -> String {
+            format!("@{}", self.author)
+        }
+    }
+
+    let post = BlogPost {
+        title: String::from("MyPost"),
+        author: String::from("author"),
+    };
+
+    // 使用默认的 summarize 实现
+    writeln!(ctx, "Blog post summary: {}", post.summarize())?;
+    writeln!(ctx, "Author: {}", post.summarize_author())?;
+    Ok(())
+}
+
+fn demo_trait_bounds(ctx: &mut LessonContext) -> lesson::Result {
+    fn notify<T: Summary>(ctx: &mut LessonContext, item: &T) -> io::Result<()> {
+        writeln!(ctx, "Breaking news! {}", item.summarize())?;
+        Ok(())
+    }
+
+    let tweet = Tweet {
+        username: String::from("news"),
+        content: String::from("Big news!"),
+        reply: false,
+        retweet: false,
+    };
+
+    notify(ctx, &tweet)?;
+    Ok(())
+}
+
+fn demo_multiple_bounds(ctx: &mut LessonContext) -> lesson::Result {
+    fn notify_multiple<T: Summary + Display>(ctx: &mut LessonContext, item: &T) -> io::Result<()> {
+        writeln!(ctx, "Summary: {}", item.summarize())?;
+        writeln!(ctx, "Display: {}", item.display())?;
+        Ok(())
+    }
+
+    let tweet = Tweet {
+        username: String::from("multi"),
+        content: String::from("Multiple traits!"),
+        reply: false,
+        retweet: false,
+    };
+
+    notify_multiple(ctx, &tweet)?;
+    Ok(())
+}
+
+fn demo_trait_as_param(ctx: &mut LessonContext) -> lesson::Result {
+    let tweet = Tweet {
+        username: String::from("trait"),
+        content: String::from("Trait object!"),
+        reply: false,
+        retweet: false,
+    };
+
+    writeln!(ctx, "Tweet summary: {}", tweet.summarize())?;
+
+    fn print_summary(ctx: &mut LessonContext, item: &impl Summary) -> io::Result<()> {
+        writeln!(ctx, "Summary from function: {}", item.summarize())?;
+        Ok(())
+    }
+
+    print_summary(ctx, &tweet)?;
+    Ok(())
+}
+
+fn returns_summarizable() -> impl Summary {
+    Tweet {
+        username: String::from("horse_ebooks"),
+        content: String::from("content"),
+        reply: false,
+        retweet: false,
+    }
+}
+
+trait Debug {
+    fn debug(&self) -> String;
+}
+
+struct Calculator;
+
+impl Debug for Calculator {
+    fn debug(&self) -> String {
+        String::from("Debug")
+    }
+}
+
+fn check_square_area() {
+    use exercise::Shape;
+    assert_eq!(exercise::Square(3.0).area(), 9.0);
+    assert_eq!(exercise::Square(1.5).describe(), "shape with area 2.25");
+}
+
+fn check_circle_area() {
+    use exercise::Shape;
+    let area = exercise::Circle(2.0).area();
+    assert!(
+        (area - 4.0 * std::f64::consts::PI).abs() < 1e-9,
+        "area = {}",
+        area
+    );
+}
+
+fn check_total_area() {
+    let shapes: Vec<Box<dyn exercise::Shape>> = vec![
+        Box::new(exercise::Square(2.0)),
+        Box::new(exercise::Square(1.0)),
+    ];
+    assert_eq!(exercise::total_area(&shapes), 5.0);
+    assert_eq!(exercise::total_area(&[]), 0.0);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trait_implementation() {
+        let tweet = Tweet {
+            username: String::from("test"),
+            content: String::from("test"),
+            reply: false,
+            retweet: false,
+        };
+        let expected = format!("@{}", "test");
+        assert_eq!(tweet.summarize_author(), expected);
+    }
+
+    #[test]
+    fn test_trait_bounds() {
+        fn test_notify<T: Summary>(item: &T) -> String {
+            item.summarize()
+        }
+
+        let tweet = Tweet {
+            username: String::from("test"),
+            content: String::from("content"),
+            reply: false,
+            retweet: false,
+        };
+
+        let summary = test_notify(&tweet);
+        assert!(summary.contains("test"));
+    }
+
+    #[test]
+    fn test_default_implementation() {
+        struct TestItem;
+        impl Summary for TestItem {
+            fn summarize_author(&self) -> String {
+                String::from("author")
+            }
+        }
+        let item = TestItem;
+        let expected = "(Read more...)";
+        assert_eq!(item.summarize(), expected);
+    }
+}
@@ -0,0 +1,322 @@
+//! # Scalar & Compound Types
+//!
+//! 目标：理解 Rust 的基本数据类型，包括标量类型和复合类型
+//!
+//! ## 要点
+//! ### 标量类型（Scalar Types）
+//! - 整数类型：`i8`, `i16`, `i32`, `i64`, `i128`, `isize`（有符号）
+//! - 整数类型：`u8`, `u16`, `u32`, `u64`, `u128`, `usize`（无符号）
+//! - 浮点类型：`f32`, `f64`
+//! - 布尔类型：`bool`
+//! - 字符类型：`char`（Unicode 标量值）
+//!
+//! ### 复合类型（Compound Types）
+//! - 元组（Tuple）：可以包含不同类型，固定长度
+//! - 数组（Array）：相同类型，固定长度
+//! - 向量（Vector）：相同类型，可变长度
+//!
+//! ```rust
+//! assert_eq!(i8::MAX, 127);
+//! assert_eq!(u8::MAX, 255);
+//! assert_eq!(255u8.checked_add(1), None); // 溢出可以用 checked_* 检查
+//! assert_ne!(0.1 + 0.2, 0.3); // 浮点数有精度误差
+//!
+//! let c: char = '中'; // char 是 4 字节的 Unicode 标量值
+//! assert_eq!(std::mem::size_of::<char>(), 4);
+//! assert!(c.is_alphabetic());
+//!
+//! let tup: (i32, f64, bool) = (1, 2.5, true);
+//! let (x, _, z) = tup;
+//! assert!(x == 1 && z);
+//!
+//! let arr = [0u8; 4]; // 长度是类型的一部分：[u8; 4]
+//! assert_eq!(arr.len(), 4);
+//! let mut v = vec![1];
+//! v.push(2); // Vec 可以增长
+//! assert_eq!(v.len(), 2);
+//! ```
+//!
+//! ## 常见坑
+//! - 整数溢出（debug 模式会 panic）
+//! - 数组越界访问（会 panic）
+//! - 字符类型使用单引号，字符串使用双引号
+//!
+//! ## 运行
+//! `cargo run -- 03_types`
+//!
+//! ## 示例
+//!
+//! 由 `cargo run -- update-doctests` 从演示代码中标出的片段生成，`cargo test --doc` 会运行它们。
+//!
+//! `compound`：
+//!
+//! ```rust
+//! let tuple: (i32, f64, char) = (42, 3.14, 'A');
+//! let (x, y, z) = tuple;
+//! println!(
+//!     "元组: tuple=({},{},{}), x={}, y={}, z={}",
+//!     tuple.0, tuple.1, tuple.2, x, y, z
+//! );
+//!
+//! // 数组
+//! let arr: [i32; 5] = [1, 2, 3, 4, 5];
+//! let first = arr[0];
+//! let last = arr[4];
+//! println!("数组: arr={:?}, first={}, last={}", arr, first, last);
+//!
+//! // 向量
+//! let mut vec = vec![1, 2, 3];
+//! vec.push(4);
+//! vec.push(5);
+//! println!(
+//!     "向量: vec={:?}, len={}, capacity={}",
+//!     vec,
+//!     vec.len(),
+//!     vec.capacity()
+//! );
+//! ```
+
+use crate::exercises::types as exercise;
+use crate::lesson::{self, Check, Koan, LessonContext, Pitfall, Question, Section};
+use std::io::{self, Write};
+
+pub const SECTIONS: &[Section] = &[];
+
+pub const QUIZ: &[Question] = &[
+    Question::new(
+        "`let c = 'z';` 中 `c` 的类型是？",
+        &["`&str`", "`String`", "`char`", "`u8`"],
+        2,
+        "单引号是 `char`（Unicode 标量值，4 字节），双引号才是字符串。",
+    ),
+    Question::new(
+        "debug 模式下 `let x: u8 = 255; x + 1` 会怎样？",
+        &[
+            "得到 0",
+            "得到 256",
+            "panic：整数溢出",
+            "编译期自动改成 u16",
+        ],
+        2,
+        "debug 构建会检查整数溢出并 panic；release 构建默认回绕，需要时用 `wrapping_add` 等方法明确意图。",
+    ),
+    Question::new(
+        "元组与数组的区别是？",
+        &[
+            "元组长度可变",
+            "元组可以包含不同类型，数组元素类型必须相同",
+            "数组可以包含不同类型",
+            "两者都存放在堆上",
+        ],
+        1,
+        "元组和数组都是固定长度；元组的各元素类型可以不同，数组元素类型相同。",
+    ),
+    Question::new(
+        "`let x = 2.0;` 中 `x` 的类型是？",
+        &["`f32`", "`f64`", "`i32`", "由 CPU 决定"],
+        1,
+        "没有其他约束时，浮点字面量推断为 `f64`，整数字面量推断为 `i32`。",
+    ),
+];
+
+pub const EXERCISE: &[Check] = &[
+    Check::new("celsius_to_fahrenheit", check_celsius_to_fahrenheit),
+    Check::new("add_u8", check_add_u8),
+    Check::new("char_count", check_char_count),
+];
+
+pub const HINTS: &[&str] = &[
+    "浮点数运算要用浮点字面量（`9.0` 而不是 `9`）；整数类型有一组 `checked_*` 方法处理溢出；`str` 既能按字节也能按字符遍历。",
+    "`celsius_to_fahrenheit` 写成 `celsius * 9.0 / 5.0 + 32.0`；`add_u8` 看看 `u8::checked_add`；`char_count` 不能用 `len()`，它返回字节数。",
+    "`a.checked_add(b)` 溢出时返回 `None`；`s.chars().count()` 统计字符数。",
+];
+
+pub const KOANS: &[Koan] = &[
+    Koan::new(
+        "tuples",
+        r#"#[test]
+fn tuples() {
+    let tup: (i32, f64, char) = (500, 6.4, 'z');
+    let (x, _, z) = tup;
+    assert_eq!(x, __);
+    assert_eq!(tup.__, 6.4);
+    assert_eq!(z, __);
+}
+"#,
+        &["500", "1", "'z'"],
+    ),
+    Koan::new(
+        "arrays_and_casts",
+        r#"#[test]
+fn arrays_and_casts() {
+    let a = [3; 5];
+    assert_eq!(a.len(), __);
+    let big: i32 = 300;
+    assert_eq!(big as u8, __);
+}
+"#,
+        &["5", "44"],
+    ),
+];
+
+pub const PITFALLS: &[Pitfall] = &[Pitfall::new(
+    "no_implicit_widening",
+    include_str!("../pitfalls/types/no_implicit_widening.rs"),
+    "Rust 不做隐式数值转换，即使 `i32` 到 `i64` 不会丢失精度也一样（E0308）。用 `i64::from(n)` 或 `n.into()` 显式转换；可能丢失精度的方向用 `try_from` 或 `as`。",
+).error("E0308")];
+
+pub fn run(ctx: &mut LessonContext) -> lesson::Result {
+    demo_scalar_types(ctx)?;
+    demo_compound_types(ctx)?;
+    demo_type_inference(ctx)?;
+    Ok(())
+}
+
+#[allow(clippy::approx_constant)]
+fn demo_scalar_types(ctx: &mut LessonContext) -> io::Result<()> {
+    ctx.section("标量类型")?;
+
+    // 整数
+    let x: i32 = 42;
+    let y: u64 = 100_000;
+    let hex = 0xff;
+    let octal = 0o77;
+    let binary = 0b1111_0000;
+
+    writeln!(
+        ctx,
+        "整数: x={}, y={}, hex={}, octal={}, binary={}",
+        x, y, hex, octal, binary
+    )?;
+
+    // 浮点数
+    let f1: f32 = 3.14;
+    let f2: f64 = 3.14159265359;
+    writeln!(ctx, "浮点数: f32={}, f64={}", f1, f2)?;
+
+    // 布尔值
+    let t = true;
+    let f: bool = false;
+    writeln!(ctx, "布尔值: t={}, f={}", t, f)?;
+
+    // 字符
+    let c1 = 'A';
+    let c2 = '🦀';
+    let c3 = '中';
+    writeln!(ctx, "字符: c1='{}', c2='{}', c3='{}'", c1, c2, c3)?;
+    Ok(())
+}
+
+#[allow(clippy::approx_constant)]
+fn demo_compound_types(ctx: &mut LessonContext) -> io::Result<()> {
+    ctx.section("复合类型")?;
+
+    // 元组
+    // ANCHOR: compound
+    let tuple: (i32, f64, char) = (42, 3.14, 'A');
+    let (x, y, z) = tuple;
+    writeln!(
+        ctx,
+        "元组: tuple=({},{},{}), x={}, y={}, z={}",
+        tuple.0, tuple.1, tuple.2, x, y, z
+    )?;
+
+    // 数组
+    let arr: [i32; 5] = [1, 2, 3, 4, 5];
+    let first = arr[0];
+    let last = arr[4];
+    writeln!(ctx, "数组: arr={:?}, first={}, last={}", arr, first, last)?;
+
+    // 向量
+    let mut vec = vec![1, 2, 3];
+    vec.push(4);
+    vec.push(5);
+    writeln!(
+        ctx,
+        "向量: vec={:?}, len={}, capacity={}",
+        vec,
+        vec.len(),
+        vec.capacity()
+    )?;
+    // ANCHOR_END: compound
+    Ok(())
+}
+
+#[allow(clippy::approx_constant)]
+fn demo_type_inference(ctx: &mut LessonContext) -> io::Result<()> {
+    ctx.section("类型推断")?;
+
+    // Rust 可以自动推断类型
+    let x = 42; // i32
+    let y = 3.14; // f64
+    let z = true; // bool
+    let s = "hello"; // &str
+
+    writeln!(ctx, "推断类型: x={}, y={}, z={}, s={}", x, y, z, s)?;
+
+    // 显示指定类型
+    let explicit: u8 = 255;
+    writeln!(ctx, "显式类型: explicit={}", explicit)?;
+    Ok(())
+}
+
+fn check_celsius_to_fahrenheit() {
+    assert_eq!(exercise::celsius_to_fahrenheit(100.0), 212.0);
+    assert_eq!(exercise::celsius_to_fahrenheit(-40.0), -40.0);
+}
+
+fn check_add_u8() {
+    assert_eq!(exercise::add_u8(200, 55), Some(255));
+    assert_eq!(exercise::add_u8(200, 56), None);
+}
+
+fn check_char_count() {
+    assert_eq!(exercise::char_count("héllo"), 5);
+    assert_eq!(exercise::char_count("你好"), 2);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_integer_types() {
+        let x: i32 = 42;
+        let y: u64 = 100;
+        assert_eq!(x, 42);
+        assert_eq!(y, 100);
+    }
+
+    #[test]
+    #[allow(clippy::approx_constant)]
+    fn test_float_types() {
+        let x: f32 = 3.14;
+        let y: f64 = 2.718;
+        assert!((x - 3.14).abs() < 0.001);
+        assert!((y - 2.718).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_tuple() {
+        let tuple = (1, 2.0, "three");
+        assert_eq!(tuple.0, 1);
+        assert_eq!(tuple.1, 2.0);
+        assert_eq!(tuple.2, "three");
+    }
+
+    #[test]
+    fn test_array() {
+        let arr = [1, 2, 3, 4, 5];
+        assert_eq!(arr.len(), 5);
+        assert_eq!(arr[0], 1);
+        assert_eq!(arr[4], 5);
+    }
+
+    #[test]
+    fn test_vector() {
+        let mut vec = vec![1, 2, 3];
+        vec.push(4);
+        assert_eq!(vec.len(), 4);
+        assert_eq!(vec[3], 4);
+    }
+}
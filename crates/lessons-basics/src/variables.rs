@@ -0,0 +1,245 @@
+//! # Variables & Mutability
+//!
+//! 目标：理解 Rust 中的变量、可变性、常量、遮蔽（shadowing）
+//!
+//! ## 要点
+//! - 默认情况下变量是不可变的（immutable）
+//! - 使用 `mut` 关键字使变量可变
+//! - 常量（`const`）总是不可变，必须标注类型
+//! - 变量可以遮蔽（shadow）之前的同名变量
+//! - 遮蔽可以更改类型和可变性
+//!
+//! ```rust
+//! const MAX_POINTS: u32 = 100_000; // 常量必须标注类型
+//! assert_eq!(MAX_POINTS, 100_000);
+//!
+//! let x = 5; // 默认不可变，再给 x 赋值会编译失败
+//! let mut y = x; // 加上 mut 才能修改
+//! y += 1;
+//! assert_eq!(y, 6);
+//!
+//! let spaces = "   ";
+//! let spaces = spaces.len(); // 遮蔽：同名的新变量，类型可以不同
+//! assert_eq!(spaces, 3);
+//!
+//! let count = 1;
+//! let mut count = count; // 遮蔽还能改变可变性
+//! count += 1;
+//! assert_eq!(count, 2);
+//! ```
+//!
+//! ## 常见坑
+//! - 忘记 `mut` 导致编译错误
+//! - 混淆变量遮蔽和可变赋值
+//! - 常量命名没有使用大写加下划线
+//!
+//! ## 运行
+//! `cargo run -- 02_variables`
+//!
+//! ## 示例
+//!
+//! 由 `cargo run -- update-doctests` 从演示代码中标出的片段生成，`cargo test --doc` 会运行它们。
+//!
+//! `shadowing`：
+//!
+//! ```rust
+//! let z = 5;
+//! let z = z + 1;
+//! let z = z * 2;
+//! println!("The value of z is: {}", z);
+//!
+//! // 类型转换遮蔽
+//! let spaces = "   ";
+//! let spaces = spaces.len();
+//! println!("Number of spaces: {}", spaces);
+//! ```
+
+use crate::exercises::variables as exercise;
+use crate::lesson::{self, Check, Koan, LessonContext, Pitfall, Question, Section};
+use std::io::{self, Write};
+
+pub const SECTIONS: &[Section] = &[];
+
+pub const QUIZ: &[Question] = &[
+    Question::new(
+        "`let x = 5; x = 6;` 为什么编译失败？",
+        &[
+            "5 和 6 类型不同",
+            "变量默认不可变，需要 `let mut x`",
+            "赋值必须用 `:=`",
+            "`x` 已被 drop",
+        ],
+        1,
+        "变量默认不可变，要修改必须声明为 `let mut`。",
+    ),
+    Question::new(
+        "关于遮蔽（shadowing），哪项说法正确？",
+        &[
+            "遮蔽要求新旧变量类型相同",
+            "遮蔽等同于 `mut` 赋值",
+            "`let x = x.len();` 可以把 `x` 换成另一个类型",
+            "遮蔽只能在不同作用域中进行",
+        ],
+        2,
+        "遮蔽用 `let` 创建了一个同名的新变量，可以改变类型和可变性。",
+    ),
+    Question::new(
+        "常量 `const` 与不可变变量的区别是？",
+        &[
+            "`const` 必须标注类型，值在编译期确定",
+            "`const` 可以用 `mut` 修饰",
+            "`const` 只能在函数内声明",
+            "没有区别",
+        ],
+        0,
+        "常量总是不可变，必须标注类型，按惯例用大写加下划线命名。",
+    ),
+    Question::new(
+        "`let x: i32;` 之后直接 `println!(\"{}\", x)` 会怎样？",
+        &[
+            "打印 0",
+            "打印随机的内存内容",
+            "运行时 panic",
+            "编译失败：使用了未初始化的变量",
+        ],
+        3,
+        "Rust 不允许读取未初始化的变量，编译器检查每条路径上变量都已赋值后才能使用。",
+    ),
+];
+
+pub const EXERCISE: &[Check] = &[
+    Check::new("sum_to", check_sum_to),
+    Check::new("swap", check_swap),
+    Check::new("parse_and_double", check_parse_and_double),
+];
+
+pub const HINTS: &[&str] = &[
+    "变量默认不可变，需要修改的累加器要用 `let mut` 声明；元组可以用模式解构。",
+    "`sum_to` 在 `for i in 1..=n` 中累加；`swap` 先 `let (a, b) = pair;`；`parse_and_double` 中 `str::parse` 返回 `Result`，失败时用 0 代替。",
+    "`let text: i32 = text.trim().parse().unwrap_or(0);` 用同名变量遮蔽原来的 `&str`，然后返回 `text * 2`。",
+];
+
+pub const KOANS: &[Koan] = &[
+    Koan::new(
+        "mutability",
+        r#"#[test]
+fn mutability() {
+    let __ y = 5;
+    y = 6;
+    assert_eq!(y, 6);
+}
+"#,
+        &["mut"],
+    ),
+    Koan::new(
+        "shadowing",
+        r#"#[test]
+fn shadowing() {
+    let z = 5;
+    let z = z + 1;
+    let z = z * 2;
+    assert_eq!(z, __);
+
+    let spaces = "   ";
+    let spaces = spaces.len();
+    assert_eq!(spaces, __);
+}
+"#,
+        &["12", "3"],
+    ),
+];
+
+pub const PITFALLS: &[Pitfall] = &[Pitfall::new(
+    "assign_immutable",
+    include_str!("../pitfalls/variables/assign_immutable.rs"),
+    "变量默认不可变，`let x = 5;` 之后不能再给 `x` 赋值（E0384）。需要修改时声明为 `let mut x`；只是想换个值、不需要原地修改时，也可以用 `let x = 6;` 遮蔽。",
+).error("E0384")];
+
+pub fn run(ctx: &mut LessonContext) -> lesson::Result {
+    // 不可变变量
+    let x = 5;
+    writeln!(ctx, "The value of x is: {}", x)?;
+    // x = 6; // 这会导致编译错误！
+
+    // 可变变量
+    let mut y = 5;
+    writeln!(ctx, "The value of y is: {}", y)?;
+    y = 6;
+    writeln!(ctx, "The value of y is: {}", y)?;
+
+    // 常量
+    const MAX_POINTS: u32 = 100_000;
+    writeln!(ctx, "Maximum points: {}", MAX_POINTS)?;
+
+    // 变量遮蔽
+    // ANCHOR: shadowing
+    let z = 5;
+    let z = z + 1;
+    let z = z * 2;
+    writeln!(ctx, "The value of z is: {}", z)?;
+
+    // 类型转换遮蔽
+    let spaces = "   ";
+    let spaces = spaces.len();
+    writeln!(ctx, "Number of spaces: {}", spaces)?;
+    // ANCHOR_END: shadowing
+
+    demo_mutability(ctx)?;
+    Ok(())
+}
+
+fn demo_mutability(ctx: &mut LessonContext) -> io::Result<()> {
+    let mut count = 0;
+    writeln!(ctx, "Initial count: {}", count)?;
+
+    count += 1;
+    writeln!(ctx, "After increment: {}", count)?;
+
+    // 遮蔽可以改变类型
+    let count = "finished";
+    writeln!(ctx, "Count is now: {}", count)?;
+    Ok(())
+}
+
+fn check_sum_to() {
+    assert_eq!(exercise::sum_to(10), 55);
+    assert_eq!(exercise::sum_to(0), 0);
+}
+
+fn check_swap() {
+    assert_eq!(exercise::swap((1, 2)), (2, 1));
+}
+
+fn check_parse_and_double() {
+    assert_eq!(exercise::parse_and_double("21"), 42);
+    assert_eq!(exercise::parse_and_double(" 5 "), 10);
+    assert_eq!(exercise::parse_and_double("abc"), 0);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mutability() {
+        let mut value = 10;
+        assert_eq!(value, 10);
+
+        value = 20;
+        assert_eq!(value, 20);
+    }
+
+    #[test]
+    fn test_shadowing() {
+        let x = 5;
+        let x = x + 1;
+        let x = x * 2;
+        assert_eq!(x, 12);
+    }
+
+    #[test]
+    fn test_constants() {
+        const TEST_VALUE: i32 = 42;
+        assert_eq!(TEST_VALUE, 42);
+    }
+}
@@ -0,0 +1,86 @@
+//! 在浏览器中运行 lesson：编译为 wasm32-unknown-unknown，供 JavaScript 调用
+//!
+//! ```text
+//! rustup target add wasm32-unknown-unknown
+//! wasm-pack build crates/web --target web
+//! ```
+//!
+//! ```js
+//! import init, { list_lessons, run_lesson } from "./pkg/rust_learn_web.js";
+//! await init();
+//! const { lessons } = JSON.parse(list_lessons("en"));
+//! document.querySelector("pre").textContent = run_lesson(lessons[5].id, "en");
+//! ```
+//!
+//! lesson 经由 [`rust_learn::report`] 在内存中运行，输出由捕获层收集后整段返回，不着色。
+//! 浏览器中没有文件系统、子进程与时钟：读写文件的演示会得到 “unsupported” 错误，耗时记为零。
+//! wasm 中 panic 会直接中止，lesson panic 时调用以 JavaScript 异常结束，不能像命令行那样接着运行。
+
+use rust_learn::export;
+use rust_learn::lesson::Language;
+use rust_learn::report::{self, RunOptions};
+use wasm_bindgen::prelude::*;
+
+/// 课程目录，JSON 字符串，格式同 `export --format json`；`lang` 为 `"zh"`（默认）或 `"en"`
+#[wasm_bindgen]
+pub fn list_lessons(lang: Option<String>) -> Result<String, JsError> {
+    catalog(lang.as_deref()).map_err(|e| JsError::new(&e))
+}
+
+/// 运行 lesson 并返回全部输出；`sel` 的写法同命令行，如 `"6"`、`"06_ownership"`、`"16:closure_capture"`。
+/// 选择器无效时抛出异常，lesson 失败时错误信息附在输出末尾
+#[wasm_bindgen]
+pub fn run_lesson(sel: &str, lang: Option<String>) -> Result<String, JsError> {
+    run(sel, lang.as_deref()).map_err(|e| JsError::new(&e))
+}
+
+fn language(lang: Option<&str>) -> Result<Language, String> {
+    lang.map_or(Ok(Language::default()), str::parse)
+}
+
+fn catalog(lang: Option<&str>) -> Result<String, String> {
+    Ok(export::catalog_json(language(lang)?))
+}
+
+fn run(sel: &str, lang: Option<&str>) -> Result<String, String> {
+    let report = report::run(
+        sel,
+        RunOptions {
+            language: language(lang)?,
+            ..RunOptions::default()
+        },
+    )
+    .map_err(|e| e.to_string())?;
+    let mut output = report.output;
+    if let Some(error) = report.error {
+        output.push_str(&format!("Error: {}\n", error));
+    }
+    Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_run() {
+        let output = run("6", None).unwrap();
+        assert!(output.starts_with("=== 所有权基础 ===\n"));
+        assert!(run("06_ownership", Some("en")).unwrap().contains("==="));
+        assert!(
+            run("no_such_lesson", None)
+                .unwrap_err()
+                .contains("not found")
+        );
+        assert_eq!(
+            run("6", Some("fr")).unwrap_err(),
+            "Unsupported language 'fr' (zh, en)"
+        );
+    }
+
+    #[test]
+    fn test_catalog() {
+        let catalog = catalog(Some("en")).unwrap();
+        assert!(catalog.contains("\"id\": \"01_hello_world\""));
+    }
+}
@@ -0,0 +1,54 @@
+//! 课外 lesson 包示例
+//!
+//! 一个独立 crate 只需依赖 `rust_learn`、实现 [`Lesson`] 并用 `lesson_pack!` 注册，
+//! 再在自己的 `main` 中调用 `rust_learn::app::main()`，就能得到带上额外 lesson 的完整命令行：
+//!
+//! ```bash
+//! cargo run --example lesson_pack -- list
+//! cargo run --example lesson_pack -- 20_smart_pointers
+//! ```
+
+use rust_learn::lesson::{self, Chapter, Difficulty, Lesson, LessonContext, LessonMeta};
+use std::io::Write;
+use std::rc::Rc;
+
+struct SmartPointers;
+
+impl Lesson for SmartPointers {
+    fn meta(&self) -> LessonMeta {
+        LessonMeta {
+            number: 20,
+            slug: "smart_pointers",
+            aliases: &["pointers"],
+            title: "Box / Rc / RefCell",
+            chapter: Chapter::Advanced,
+            difficulty: Difficulty::Advanced,
+            minutes: 30,
+            tags: &["memory", "pointers"],
+            prerequisites: &["ownership", "traits"],
+            deprecated: false,
+            replaced_by: None,
+        }
+    }
+
+    fn run(&self, ctx: &mut LessonContext) -> lesson::Result {
+        let boxed = Box::new(5);
+        writeln!(ctx, "Box<i32>: {}", boxed)?;
+
+        let shared = Rc::new(String::from("shared"));
+        let other = Rc::clone(&shared);
+        writeln!(
+            ctx,
+            "Rc<String>: {} (strong count = {})",
+            other,
+            Rc::strong_count(&shared)
+        )?;
+        Ok(())
+    }
+}
+
+rust_learn::lesson_pack!("advanced-extras", [SmartPointers]);
+
+fn main() {
+    rust_learn::app::main();
+}
@@ -0,0 +1,17 @@
+//! 课程大纲 `curriculum.toml` 的解析：格式不对时返回错误而不是 panic，
+//! 解析出的 lesson 按编号排好序，id 与选择器的匹配规则一致。
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use rust_learn::curriculum;
+
+fuzz_target!(|text: &str| {
+    let Ok(entries) = curriculum::parse(text) else {
+        return;
+    };
+    assert!(entries.windows(2).all(|w| w[0].number <= w[1].number));
+    for entry in &entries {
+        assert!(entry.matches(&entry.id()));
+    }
+});
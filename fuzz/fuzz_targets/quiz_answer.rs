@@ -0,0 +1,14 @@
+//! 测验读取答案的方式：任意输入（空行、非数字、超出范围、非 UTF-8）要么被要求重新输入，
+//! 要么在输入结束时返回错误，读到的答案一定在选项范围内。
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use rust_learn::utils::io::Prompter;
+
+fuzz_target!(|input: &[u8]| {
+    let mut prompter = Prompter::new(input, Vec::new());
+    if let Ok(choice) = prompter.read_number("Answer", 1..=4) {
+        assert!((1..=4).contains(&choice));
+    }
+});
@@ -0,0 +1,19 @@
+//! 选择器解析：`3-7,09_structs:update` 这类组合写法，任意输入都不能 panic。
+//! 能解析时，每个选中项的 id 再作为选择器，必须恰好选回它自己。
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use rust_learn::lessons;
+
+fuzz_target!(|sel: &str| {
+    let Ok(selected) = lessons::select(sel) else {
+        return;
+    };
+    for item in &selected {
+        let id = item.id();
+        let again = lessons::select(&id).expect("an id selects itself");
+        assert_eq!(again.len(), 1);
+        assert_eq!(again[0].id(), id);
+    }
+});
@@ -0,0 +1,13 @@
+//! # 参考答案：FizzBuzz with Iterators
+
+/// 1 到 `n` 的 FizzBuzz
+pub fn fizzbuzz(n: u32) -> Vec<String> {
+    (1..=n)
+        .map(|i| match (i % 3, i % 5) {
+            (0, 0) => String::from("FizzBuzz"),
+            (0, _) => String::from("Fizz"),
+            (_, 0) => String::from("Buzz"),
+            _ => i.to_string(),
+        })
+        .collect()
+}
@@ -0,0 +1,10 @@
+//! kata 的参考答案，与 `crates/katas/src/drills/` 中的练习文件一一对应
+//!
+//! 不参与默认构建：启用 `katas` 的 `solutions` feature 时代替练习文件编译，
+//! 用来确认每个 kata 的测试都能被参考答案通过。
+
+pub mod fizzbuzz;
+pub mod parse_csv_line;
+pub mod reverse_words;
+pub mod run_length;
+pub mod word_frequency;
@@ -0,0 +1,6 @@
+//! # 参考答案：Reverse Words
+
+/// 单词倒序排列，以单个空格连接；任何空白都算分隔符
+pub fn reverse_words(s: &str) -> String {
+    s.split_whitespace().rev().collect::<Vec<_>>().join(" ")
+}
@@ -0,0 +1,34 @@
+//! # 参考答案：Run-Length Encoding
+
+/// 编码：连续 `n` 个（`n > 1`）相同字符写成 `n` 加这个字符
+pub fn encode(s: &str) -> String {
+    let mut out = String::new();
+    let mut chars = s.chars().peekable();
+    while let Some(c) = chars.next() {
+        let mut n = 1;
+        while chars.next_if_eq(&c).is_some() {
+            n += 1;
+        }
+        if n > 1 {
+            out.push_str(&n.to_string());
+        }
+        out.push(c);
+    }
+    out
+}
+
+/// 解码：把 `encode` 的结果还原
+pub fn decode(s: &str) -> String {
+    let mut out = String::new();
+    let mut count = String::new();
+    for c in s.chars() {
+        if c.is_ascii_digit() {
+            count.push(c);
+        } else {
+            let n = count.parse().unwrap_or(1);
+            out.extend(std::iter::repeat_n(c, n));
+            count.clear();
+        }
+    }
+    out
+}
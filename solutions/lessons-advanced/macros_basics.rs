@@ -0,0 +1,32 @@
+//! # 参考答案：Macros Basics
+
+/// 求平方：`square!(3)` 得到 9，参数只求值一次
+macro_rules! square {
+    ($x:expr) => {{
+        let x = $x;
+        x * x
+    }};
+}
+
+/// 任意多个数中的最大值：`max_of!(1)`、`max_of!(3, 7, 2)`
+macro_rules! max_of {
+    ($x:expr) => {
+        $x
+    };
+    ($x:expr, $($rest:expr),+) => {
+        {
+            let first = $x;
+            let rest = max_of!($($rest),+);
+            if first > rest { first } else { rest }
+        }
+    };
+}
+
+/// 由字符串字面量构造 `Vec<String>`：`strings!["a", "b"]`
+macro_rules! strings {
+    ($($s:expr),* $(,)?) => {
+        vec![$($s.to_string()),*]
+    };
+}
+
+pub(crate) use {max_of, square, strings};
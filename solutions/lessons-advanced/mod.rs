@@ -0,0 +1,10 @@
+//! 进阶部分练习的参考答案，与 `crates/lessons-advanced/src/exercises/` 中的练习文件一一对应
+//!
+//! 不参与默认构建：启用 `lessons-advanced` 的 `solutions` feature 时代替练习文件编译，
+//! 用来确认每个检查都能被参考答案通过；`solution <lesson>` 命令直接读取这里的源码。
+
+#![allow(clippy::new_without_default)]
+
+pub mod lifetimes;
+pub mod macros_basics;
+pub mod modules_crates;
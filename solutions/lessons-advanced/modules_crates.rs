@@ -0,0 +1,36 @@
+//! # 参考答案：Modules / Crates / Paths
+
+/// 几何计算
+pub mod geometry {
+    /// 矩形的周长
+    pub fn perimeter(width: u32, height: u32) -> u32 {
+        2 * (width + height)
+    }
+
+    /// 正方形的周长：调用同一模块中的 `perimeter`
+    pub fn square_perimeter(side: u32) -> u32 {
+        perimeter(side, side)
+    }
+}
+
+/// 文本处理
+pub mod text {
+    /// 首字母大写，例如 `"rust"` → `"Rust"`
+    pub fn capitalize(word: &str) -> String {
+        let mut chars = word.chars();
+        match chars.next() {
+            Some(first) => first.to_uppercase().collect::<String>() + chars.as_str(),
+            None => String::new(),
+        }
+    }
+
+    /// 通过 `super::geometry` 计算周长，例如 `describe_rect(2, 3)` 返回 `"2x3 rectangle, perimeter 10"`
+    pub fn describe_rect(width: u32, height: u32) -> String {
+        format!(
+            "{}x{} rectangle, perimeter {}",
+            width,
+            height,
+            super::geometry::perimeter(width, height)
+        )
+    }
+}
@@ -0,0 +1,18 @@
+//! # 参考答案：Borrowing & References
+
+/// 所有单词的总长度（字节数），只借用 `words`
+pub fn total_len(words: &[String]) -> usize {
+    words.iter().map(|w| w.len()).sum()
+}
+
+/// 通过可变引用在字符串末尾追加 `", world"`
+pub fn append_world(s: &mut String) {
+    s.push_str(", world");
+}
+
+/// 通过可变引用把每个元素翻倍
+pub fn double_all(numbers: &mut [i32]) {
+    for n in numbers.iter_mut() {
+        *n *= 2;
+    }
+}
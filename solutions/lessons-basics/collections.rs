@@ -0,0 +1,32 @@
+//! # 参考答案：Vec / String / HashMap
+
+use std::collections::HashMap;
+
+/// 统计每个单词（按空白分隔）出现的次数
+pub fn word_count(text: &str) -> HashMap<String, usize> {
+    let mut counts = HashMap::new();
+    for word in text.split_whitespace() {
+        *counts.entry(word.to_string()).or_insert(0) += 1;
+    }
+    counts
+}
+
+/// 去掉重复元素并升序排列
+pub fn unique_sorted(numbers: Vec<i32>) -> Vec<i32> {
+    let mut numbers = numbers;
+    numbers.sort();
+    numbers.dedup();
+    numbers
+}
+
+/// 出现次数最多的字符，次数相同时取较小的字符；空字符串返回 `None`
+pub fn most_common_char(s: &str) -> Option<char> {
+    let mut counts = HashMap::new();
+    for c in s.chars() {
+        *counts.entry(c).or_insert(0) += 1;
+    }
+    counts
+        .into_iter()
+        .max_by(|a, b| a.1.cmp(&b.1).then(b.0.cmp(&a.0)))
+        .map(|(c, _)| c)
+}
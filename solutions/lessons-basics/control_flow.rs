@@ -0,0 +1,33 @@
+//! # 参考答案：if / loop / while / match
+
+/// FizzBuzz：3 的倍数返回 `"Fizz"`，5 的倍数返回 `"Buzz"`，同时是两者的倍数返回 `"FizzBuzz"`，
+/// 否则返回数字本身
+pub fn fizzbuzz(n: u32) -> String {
+    match (n % 3, n % 5) {
+        (0, 0) => String::from("FizzBuzz"),
+        (0, _) => String::from("Fizz"),
+        (_, 0) => String::from("Buzz"),
+        _ => n.to_string(),
+    }
+}
+
+/// 从 `n` 倒数到 1，例如 `count_down(3)` 返回 `[3, 2, 1]`
+pub fn count_down(n: u32) -> Vec<u32> {
+    let mut numbers = Vec::new();
+    let mut i = n;
+    while i > 0 {
+        numbers.push(i);
+        i -= 1;
+    }
+    numbers
+}
+
+/// 第一个负数的下标，没有负数时返回 `None`
+pub fn first_negative(numbers: &[i32]) -> Option<usize> {
+    for (i, &x) in numbers.iter().enumerate() {
+        if x < 0 {
+            return Some(i);
+        }
+    }
+    None
+}
@@ -0,0 +1,37 @@
+//! # 参考答案：Enums & Pattern Matching
+
+/// 交通信号灯
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Light {
+    Red,
+    Yellow,
+    Green,
+}
+
+/// 下一个信号：红 → 绿 → 黄 → 红
+pub fn next(light: Light) -> Light {
+    match light {
+        Light::Red => Light::Green,
+        Light::Green => Light::Yellow,
+        Light::Yellow => Light::Red,
+    }
+}
+
+/// 每种信号持续的秒数：红 60、黄 5、绿 45
+pub fn duration(light: Light) -> u32 {
+    match light {
+        Light::Red => 60,
+        Light::Yellow => 5,
+        Light::Green => 45,
+    }
+}
+
+/// 描述一个可能缺失的数：`"none"`、`"zero"`、`"positive"` 或 `"negative"`
+pub fn describe(n: Option<i32>) -> &'static str {
+    match n {
+        None => "none",
+        Some(0) => "zero",
+        Some(x) if x > 0 => "positive",
+        Some(_) => "negative",
+    }
+}
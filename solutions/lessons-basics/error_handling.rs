@@ -0,0 +1,29 @@
+//! # 参考答案：Result / Option / ? operator
+
+use std::num::ParseIntError;
+
+/// 把两个字符串解析为整数后相加，用 `?` 传递解析错误
+pub fn parse_sum(a: &str, b: &str) -> Result<i32, ParseIntError> {
+    Ok(a.trim().parse::<i32>()? + b.trim().parse::<i32>()?)
+}
+
+/// 整数除法，除数为 0 时返回错误 `"division by zero"`
+pub fn divide(a: i32, b: i32) -> Result<i32, String> {
+    if b == 0 {
+        Err(String::from("division by zero"))
+    } else {
+        Ok(a / b)
+    }
+}
+
+/// 解析年龄：必须是 0 到 150 之间的整数，否则返回说明原因的错误
+pub fn parse_age(s: &str) -> Result<u8, String> {
+    let age: u8 = s
+        .trim()
+        .parse()
+        .map_err(|e| format!("invalid age '{}': {}", s, e))?;
+    if age > 150 {
+        return Err(format!("age {} is out of range", age));
+    }
+    Ok(age)
+}
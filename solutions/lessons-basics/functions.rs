@@ -0,0 +1,16 @@
+//! # 参考答案：Functions & Parameters
+
+/// 返回 `x` 的平方
+pub fn square(x: i32) -> i32 {
+    x * x
+}
+
+/// 返回三个数中的最大值
+pub fn max_of_three(a: i32, b: i32, c: i32) -> i32 {
+    a.max(b).max(c)
+}
+
+/// `n` 是否为偶数
+pub fn is_even(n: i32) -> bool {
+    n % 2 == 0
+}
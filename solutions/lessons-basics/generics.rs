@@ -0,0 +1,33 @@
+//! # 参考答案：Generics
+
+/// 切片中的最大值；切片为空时返回 `None`
+pub fn largest<T: PartialOrd + Copy>(items: &[T]) -> Option<T> {
+    let mut largest = *items.first()?;
+    for &item in items {
+        if item > largest {
+            largest = item;
+        }
+    }
+    Some(largest)
+}
+
+/// 切片中是否包含 `target`
+pub fn contains<T: PartialEq>(items: &[T], target: &T) -> bool {
+    items.iter().any(|item| item == target)
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Pair<T> {
+    pub first: T,
+    pub second: T,
+}
+
+impl<T> Pair<T> {
+    /// 交换两个值
+    pub fn swap(self) -> Pair<T> {
+        Pair {
+            first: self.second,
+            second: self.first,
+        }
+    }
+}
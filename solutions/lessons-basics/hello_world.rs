@@ -0,0 +1,11 @@
+//! # 参考答案：Hello, world & Project Layout
+
+/// 返回问候语，例如 `greeting("Ferris")` 返回 `"Hello, Ferris!"`
+pub fn greeting(name: &str) -> String {
+    format!("Hello, {}!", name)
+}
+
+/// 返回 `lines` 行 `Hello, world!`，行与行之间用 `\n` 分隔，末尾没有换行
+pub fn repeat_hello(lines: usize) -> String {
+    vec!["Hello, world!"; lines].join("\n")
+}
@@ -0,0 +1,22 @@
+//! # 参考答案：Iterators & Closures
+
+/// 所有偶数的平方，保持原来的顺序
+pub fn squares_of_evens(numbers: &[i32]) -> Vec<i32> {
+    numbers
+        .iter()
+        .filter(|&&x| x % 2 == 0)
+        .map(|x| x * x)
+        .collect()
+}
+
+/// 返回一个把参数加上 `n` 的闭包
+pub fn make_adder(n: i32) -> Box<dyn Fn(i32) -> i32> {
+    Box::new(move |x| x + n)
+}
+
+/// 长度（按字符计）不小于 `min_len` 的单词数，单词按空白分隔
+pub fn count_long_words(text: &str, min_len: usize) -> usize {
+    text.split_whitespace()
+        .filter(|w| w.chars().count() >= min_len)
+        .count()
+}
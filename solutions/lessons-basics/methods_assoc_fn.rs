@@ -0,0 +1,28 @@
+//! # 参考答案：Methods & Associated Fns
+
+#[derive(Debug)]
+pub struct Counter {
+    count: u32,
+}
+
+impl Counter {
+    /// 关联函数：从 0 开始计数
+    pub fn new() -> Self {
+        Counter { count: 0 }
+    }
+
+    /// 关联函数：从 `start` 开始计数
+    pub fn starting_at(start: u32) -> Self {
+        Counter { count: start }
+    }
+
+    /// 计数加一
+    pub fn increment(&mut self) {
+        self.count += 1;
+    }
+
+    /// 当前计数
+    pub fn get(&self) -> u32 {
+        self.count
+    }
+}
@@ -0,0 +1,23 @@
+//! 基础部分练习的参考答案，与 `crates/lessons-basics/src/exercises/` 中的练习文件一一对应
+//!
+//! 不参与默认构建：启用 `lessons-basics` 的 `solutions` feature 时代替练习文件编译，
+//! 用来确认每个检查都能被参考答案通过；`solution <lesson>` 命令直接读取这里的源码。
+
+#![allow(clippy::new_without_default)]
+
+pub mod borrowing;
+pub mod collections;
+pub mod control_flow;
+pub mod enums_matching;
+pub mod error_handling;
+pub mod functions;
+pub mod generics;
+pub mod hello_world;
+pub mod iterators_closures;
+pub mod methods_assoc_fn;
+pub mod ownership;
+pub mod slices;
+pub mod structs;
+pub mod traits;
+pub mod types;
+pub mod variables;
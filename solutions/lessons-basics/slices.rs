@@ -0,0 +1,23 @@
+//! # 参考答案：String & Array Slices
+
+/// 第一个单词（以空格分隔）；没有空格时返回整个字符串
+pub fn first_word(s: &str) -> &str {
+    match s.find(' ') {
+        Some(i) => &s[..i],
+        None => s,
+    }
+}
+
+/// 切片中所有元素的和
+pub fn sum(numbers: &[i32]) -> i32 {
+    numbers.iter().sum()
+}
+
+/// 去掉首尾各一个元素后的部分；元素少于两个时返回空切片
+pub fn middle(numbers: &[i32]) -> &[i32] {
+    if numbers.len() < 2 {
+        &[]
+    } else {
+        &numbers[1..numbers.len() - 1]
+    }
+}
@@ -0,0 +1,25 @@
+//! # 参考答案：Structs & Update Syntax
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rectangle {
+    pub width: u32,
+    pub height: u32,
+}
+
+/// 矩形的面积
+pub fn area(rect: &Rectangle) -> u32 {
+    rect.width * rect.height
+}
+
+/// 边长为 `size` 的正方形
+pub fn square(size: u32) -> Rectangle {
+    Rectangle {
+        width: size,
+        height: size,
+    }
+}
+
+/// 用结构体更新语法返回一个宽度改为 `width`、高度不变的矩形
+pub fn with_width(rect: Rectangle, width: u32) -> Rectangle {
+    Rectangle { width, ..rect }
+}
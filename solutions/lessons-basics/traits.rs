@@ -0,0 +1,34 @@
+//! # 参考答案：Traits & Trait Bounds
+
+/// 可以计算面积的形状
+pub trait Shape {
+    fn area(&self) -> f64;
+
+    /// 带默认实现的方法，实现者不必重写
+    fn describe(&self) -> String {
+        format!("shape with area {:.2}", self.area())
+    }
+}
+
+/// 正方形，字段是边长
+pub struct Square(pub f64);
+
+/// 圆，字段是半径
+pub struct Circle(pub f64);
+
+impl Shape for Square {
+    fn area(&self) -> f64 {
+        self.0 * self.0
+    }
+}
+
+impl Shape for Circle {
+    fn area(&self) -> f64 {
+        std::f64::consts::PI * self.0 * self.0
+    }
+}
+
+/// 所有形状的面积之和
+pub fn total_area(shapes: &[Box<dyn Shape>]) -> f64 {
+    shapes.iter().map(|s| s.area()).sum()
+}
@@ -0,0 +1,16 @@
+//! # 参考答案：Scalar & Compound Types
+
+/// 摄氏度转华氏度：F = C × 9 / 5 + 32
+pub fn celsius_to_fahrenheit(celsius: f64) -> f64 {
+    celsius * 9.0 / 5.0 + 32.0
+}
+
+/// 两个 `u8` 相加，溢出时返回 `None`
+pub fn add_u8(a: u8, b: u8) -> Option<u8> {
+    a.checked_add(b)
+}
+
+/// 返回字符串中的字符数（不是字节数）
+pub fn char_count(s: &str) -> usize {
+    s.chars().count()
+}
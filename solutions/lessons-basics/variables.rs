@@ -0,0 +1,22 @@
+//! # 参考答案：Variables & Mutability
+
+/// 用可变变量累加，返回 `1 + 2 + ... + n`
+pub fn sum_to(n: u32) -> u32 {
+    let mut total = 0;
+    for i in 1..=n {
+        total += i;
+    }
+    total
+}
+
+/// 交换元组中的两个值
+pub fn swap(pair: (i32, i32)) -> (i32, i32) {
+    let (a, b) = pair;
+    (b, a)
+}
+
+/// 用遮蔽把 `text` 解析为数字后再乘以 2，例如 `"21"` 得到 42；解析失败时返回 0
+pub fn parse_and_double(text: &str) -> i32 {
+    let text: i32 = text.trim().parse().unwrap_or(0);
+    text * 2
+}
@@ -0,0 +1,354 @@
+//! 命令行应用：解析参数后分派到各个命令
+//!
+//! 放在库里而不是 `main.rs` 中，是为了让链接了额外 lesson 包的可执行文件
+//! （见 [`crate::pack`]）也能直接复用完整的命令行。
+
+use crate::cli::Options;
+use crate::config::{self, Config};
+use crate::error::RunnerError;
+use crate::progress::{self, Progress};
+use crate::utils::time::Timestamp;
+use crate::{
+    badge, bench, bookmark, certificate, challenge, check, classroom, cli, coverage, doctests,
+    exercise, explain_error, export, kata, koans, lessons, lint, logging, manpage, mentor, notes,
+    pitfalls, placement, practice, project, quiz, readme, recommend, record, renumber, repl, reset,
+    runner, scaffold, serve, share, share_output, show, stats, today, transcript, translations,
+    utils,
+};
+use std::env;
+
+/// `prog` 是用户调用本程序的方式，例如 `cargo run --` 或 `cargo learn`
+fn print_help(config: &Config, prog: &str) {
+    eprintln!("Usage:");
+    for usage in cli::COMMANDS {
+        eprintln!("  {}", usage.replace("{prog}", prog));
+    }
+    eprintln!();
+    eprintln!("Options:");
+    for (flags, description) in cli::OPTIONS {
+        eprintln!("  {:<18} {}", flags, description);
+    }
+    eprintln!();
+    eprintln!("Examples:");
+    for (args, description) in cli::EXAMPLES {
+        eprintln!("  {} {:<14} # {}", prog, args, description);
+    }
+    eprintln!();
+    eprintln!("Environment (覆盖配置文件，被命令行选项覆盖):");
+    for (name, description) in config::ENV_VARS {
+        eprintln!("  {:<26} {}", name, description);
+    }
+    if !config.aliases.is_empty() {
+        eprintln!();
+        eprintln!("Aliases:");
+        for (alias, target) in &config.aliases {
+            eprintln!("  {:<12} -> {}", alias, target);
+        }
+    }
+}
+
+/// 按选项打开全局的输出开关（可复现输出、纯 ASCII、主题、着色）
+pub(crate) fn apply_output_flags(opts: &Options) {
+    utils::fmt::set_theme(opts.theme);
+    if opts.deterministic {
+        utils::deterministic::enable();
+    }
+    if opts.ascii {
+        utils::fmt::enable_ascii();
+    }
+    if opts.color.enabled() {
+        utils::fmt::enable_color();
+    }
+}
+
+/// `dev` 命令：修改 lesson 后自动重新编译并重新运行
+#[cfg(feature = "hot-reload")]
+fn dev(sel: &str, opts: &Options) -> Result<(), RunnerError> {
+    crate::dev::run(sel, opts)
+}
+
+#[cfg(not(feature = "hot-reload"))]
+fn dev(_sel: &str, _opts: &Options) -> Result<(), RunnerError> {
+    Err(RunnerError::Usage(String::from(
+        "'dev' requires the hot-reload feature: cargo run --features hot-reload -- dev <lesson>",
+    )))
+}
+
+pub fn main() {
+    logging::init();
+    let config = match Config::load() {
+        Ok(config) => config,
+        Err(e) => {
+            let e = RunnerError::Config(e);
+            eprintln!("Error: {}", e);
+            std::process::exit(e.exit_code());
+        }
+    };
+    lessons::set_aliases(config.aliases.clone());
+
+    let mut raw: Vec<String> = env::args().skip(1).collect();
+    let prog = if cli::strip_subcommand(&mut raw) {
+        "cargo learn"
+    } else {
+        "cargo run --"
+    };
+    let (args, opts) = match cli::parse_with(&raw, config.defaults()) {
+        Ok(parsed) => parsed,
+        Err(e) => {
+            let e = RunnerError::Usage(e);
+            eprintln!("Error: {}", e);
+            print_help(&config, prog);
+            std::process::exit(e.exit_code());
+        }
+    };
+    apply_output_flags(&opts);
+    if let Some(path) = &opts.output {
+        match utils::output::tee_to(path) {
+            Ok(target) => eprintln!("Recording output to {}", target.display()),
+            Err(e) => {
+                eprintln!("Error: cannot open '{}': {}", path.display(), e);
+                std::process::exit(RunnerError::Io(e).exit_code());
+            }
+        }
+    }
+    let started = Timestamp::now();
+    if opts.transcript {
+        transcript::start();
+    }
+    if let Err(e) = lessons::validate() {
+        let e = RunnerError::Config(format!("invalid lesson registry: {}", e));
+        eprintln!("Error: {}", e);
+        std::process::exit(e.exit_code());
+    }
+    if args.is_empty() {
+        print_help(&config, prog);
+        return;
+    }
+
+    let command = config.resolve_alias(&args[0]);
+    if command != args[0] {
+        log::debug!("alias '{}' -> '{}'", args[0], command);
+    }
+    if let Some(pager) = opts.pager.as_deref().filter(|_| {
+        !matches!(
+            command,
+            "repl"
+                | "dev"
+                | "practice"
+                | "quiz"
+                | "challenge"
+                | "solution"
+                | "placement"
+                | "today"
+                | "mentor"
+                | "reset"
+                | "serve"
+        )
+    }) && let Err(e) = utils::output::start_pager(pager)
+    {
+        eprintln!("Warning: cannot start pager '{}': {}", pager, e);
+    }
+    let profile = opts.profile.as_deref().unwrap_or(progress::DEFAULT_PROFILE);
+    if !progress::is_valid_profile(profile) {
+        let e = RunnerError::Usage(format!(
+            "Invalid profile name '{}' (letters, digits, '-' and '_')",
+            profile
+        ));
+        eprintln!("Error: {}", e);
+        std::process::exit(e.exit_code());
+    }
+    let mut progress = Progress::load_profile(profile, opts.progress_file.as_deref());
+    let metas: Vec<_> = lessons::all().iter().map(|l| l.meta()).collect();
+    if let Err(e) = progress.migrate(&metas) {
+        eprintln!("Warning: cannot save progress: {}", e);
+    }
+    log::debug!("options: {:?}", opts);
+    let mut out = utils::output::Stdout;
+    let result = match command {
+        "list" => lessons::list(&progress, opts.language, &mut out),
+        "toc" => lessons::toc(opts.language, &mut out),
+        "path" if opts.adaptive => lessons::adaptive_path(&progress, opts.language, &mut out),
+        "path" => lessons::path(&progress, opts.language, &mut out),
+        "recommend" => recommend::run(&progress, opts.language, &mut out),
+        "today" => today::run(&mut progress, &opts, &mut out),
+        "info" => match args.get(1) {
+            Some(sel) => lessons::info(sel, &progress, opts.language, &mut out),
+            None => Err(RunnerError::Usage(String::from(
+                "Missing lesson for 'info'",
+            ))),
+        },
+        "complete" => match args.get(1) {
+            Some(sel) => lessons::complete(sel, &mut progress, &mut out),
+            None => Err(RunnerError::Usage(String::from(
+                "Missing lesson for 'complete'",
+            ))),
+        },
+        "bookmark" => bookmark::run(&args[1..], &mut progress, &opts, &mut out),
+        "note" => notes::add(&args[1..], &mut progress, &mut out),
+        "notes" => notes::show(
+            args.get(1).map(String::as_str),
+            &progress,
+            opts.language,
+            &mut out,
+        ),
+        "stats" => stats::run(&args[1..], opts.format, &progress, &metas, &mut out),
+        "profiles" => classroom::list(profile, opts.progress_file.as_deref(), &metas, &mut out),
+        "report" => classroom::report(&args[1..], opts.progress_file.as_deref(), &metas, &mut out),
+        "certificate" => certificate::run(&args[1..], profile, &progress, &metas, &mut out),
+        "badge" => badge::run(&args[1..], &progress, &metas, &mut out),
+        "quiz" => match args.get(1) {
+            Some(sel) => quiz::run(sel, &mut progress, opts.language, &mut out),
+            None => Err(RunnerError::Usage(String::from(
+                "Missing lesson for 'quiz'",
+            ))),
+        },
+        "challenge" => challenge::run(&args[1..], &mut progress, &mut out),
+        "mentor" => match args.get(1) {
+            None => mentor::run(opts.language, &mut out),
+            Some(_) => Err(RunnerError::Usage(String::from(
+                "Usage: mentor < snippet.rs",
+            ))),
+        },
+        "explain-error" => match args.get(2) {
+            None => explain_error::run(args.get(1).map(String::as_str), opts.language, &mut out),
+            Some(_) => Err(RunnerError::Usage(String::from(
+                "Usage: explain-error [code]",
+            ))),
+        },
+        "placement" => placement::run(&mut progress, opts.language, &mut out),
+        "reset" => reset::run(&args[1..], &opts.reset, &mut progress, profile, &mut out),
+        "exercise" => match args.get(1) {
+            Some(sel) => exercise::run(sel, &mut progress, opts.language, &mut out),
+            None => Err(RunnerError::Usage(String::from(
+                "Missing lesson for 'exercise'",
+            ))),
+        },
+        "hint" => exercise::hint(&args[1..], &mut progress, &mut out),
+        "practice" => practice::run(&args[1..], &mut progress, &opts, &mut out),
+        "solution" => match args.get(1) {
+            Some(sel) => exercise::solution(sel, &mut progress, &mut out),
+            None => Err(RunnerError::Usage(String::from(
+                "Missing lesson for 'solution'",
+            ))),
+        },
+        "kata" => kata::run(&args[1..], &mut progress, &mut out),
+        "bench" => bench::run(&args[1..], &mut out),
+        "check" => match args.get(1) {
+            Some(sel) => check::run(sel, &mut progress, opts.junit.as_deref(), &mut out),
+            None => Err(RunnerError::Usage(String::from(
+                "Missing lesson for 'check'",
+            ))),
+        },
+        "pitfalls" => match args.get(1) {
+            Some(sel) => pitfalls::run(sel, opts.language, &mut out),
+            None => Err(RunnerError::Usage(String::from(
+                "Missing lesson for 'pitfalls'",
+            ))),
+        },
+        "generate-koans" => match args.get(1) {
+            Some(dir) => koans::run(dir, &mut out),
+            None => Err(RunnerError::Usage(String::from(
+                "Usage: generate-koans <dir>",
+            ))),
+        },
+        "generate-man" => match &args[1..] {
+            [] => manpage::run(None, &mut out),
+            [file] => manpage::run(Some(file), &mut out),
+            _ => Err(RunnerError::Usage(String::from(
+                "Usage: generate-man [file]",
+            ))),
+        },
+        "share" => match args.get(1) {
+            Some(sel) => share::run(sel, opts.language, &mut out),
+            None => Err(RunnerError::Usage(String::from(
+                "Missing lesson for 'share'",
+            ))),
+        },
+        "show" => match args.get(1) {
+            Some(sel) => show::run(sel, opts.copy, opts.language, &mut out),
+            None => Err(RunnerError::Usage(String::from(
+                "Missing lesson for 'show'",
+            ))),
+        },
+        "scaffold" => match (args.get(1), args.get(2)) {
+            (Some(sel), Some(dir)) => project::run(sel, dir, opts.language, &mut out),
+            _ => Err(RunnerError::Usage(String::from(
+                "Usage: scaffold <lesson>[:<section>] <dir>",
+            ))),
+        },
+        "share-output" => match args.get(1) {
+            Some(sel) => share_output::run(sel, &opts, &mut out),
+            None => Err(RunnerError::Usage(String::from(
+                "Missing lesson for 'share-output'",
+            ))),
+        },
+        "export" => export::run(opts.format, &args[1..], opts.language, &mut out),
+        "record" => record::run(&args[1..], &opts, &mut out),
+        "serve" => match &args[1..] {
+            [] => serve::run(None, opts.language, &mut out),
+            [addr] => serve::run(Some(addr), opts.language, &mut out),
+            _ => Err(RunnerError::Usage(String::from("Usage: serve [addr]"))),
+        },
+        "repl" => repl::run(&mut out),
+        "dev" => match args.get(1) {
+            Some(sel) => dev(sel, &opts),
+            None => Err(RunnerError::Usage(String::from("Missing lesson for 'dev'"))),
+        },
+        "new" => match args.get(1) {
+            Some(slug) => scaffold::run(slug, args.get(2).map(String::as_str), &mut out),
+            None => Err(RunnerError::Usage(String::from(
+                "Usage: new <slug> [number]",
+            ))),
+        },
+        "renumber" => match (args.get(1), args.get(2)) {
+            (Some(sel), Some(number)) => renumber::run(sel, number, &mut out),
+            _ => Err(RunnerError::Usage(String::from(
+                "Usage: renumber <lesson> <number>",
+            ))),
+        },
+        "import-translations" => match args.get(1) {
+            Some(file) if args.len() == 2 => translations::import(file, &mut out),
+            _ => Err(RunnerError::Usage(String::from(
+                "Usage: import-translations <file>",
+            ))),
+        },
+        "lint-lessons" => lint::run(&mut out),
+        "update-readme" => match &args[1..] {
+            [] => readme::run(None, &mut out),
+            [file] => readme::run(Some(file), &mut out),
+            _ => Err(RunnerError::Usage(String::from(
+                "Usage: update-readme [file]",
+            ))),
+        },
+        "update-doctests" => match &args[1..] {
+            [] => doctests::run(&mut out),
+            _ => Err(RunnerError::Usage(String::from("Usage: update-doctests"))),
+        },
+        "coverage" => match &args[1..] {
+            [] => coverage::run(&mut out),
+            _ => Err(RunnerError::Usage(String::from("Usage: coverage"))),
+        },
+        "all" => runner::run_all(
+            opts.jobs.unwrap_or_else(runner::default_jobs),
+            &opts,
+            &mut progress,
+            &mut out,
+        ),
+        sel => lessons::run_selected(sel, &mut progress, &opts, &mut out),
+    };
+    utils::output::finish_pager();
+    if opts.transcript {
+        match transcript::finish(&transcript::dir(profile), &args, started) {
+            Ok(Some(path)) => eprintln!("Transcript saved to {}", path.display()),
+            Ok(None) => {}
+            Err(e) => eprintln!("Warning: cannot write transcript: {}", e),
+        }
+    }
+    if let Err(e) = result {
+        eprintln!("Error: {}", e);
+        if e.shows_usage() {
+            print_help(&config, prog);
+        }
+        std::process::exit(e.exit_code());
+    }
+}
@@ -0,0 +1,142 @@
+//! 进度徽章：`badge [file]`
+//!
+//! 按学习进度生成一个 shields.io 风格（flat）的 SVG 徽章，例如“rust-learn | 14/19 lessons”，
+//! 可以放进个人主页的 README。已弃用的 lesson 不计入总数；颜色随完成比例从灰、橙、黄变为绿。
+//! 不给文件时输出到终端。徽章是静态的，进度变了重新生成一次即可。
+
+use crate::error::RunnerError;
+use crate::lesson::LessonMeta;
+use crate::progress::Progress;
+use std::fs;
+use std::io::Write;
+
+const LABEL: &str = "rust-learn";
+
+/// 文字两侧的留白
+const PADDING: u32 = 10;
+
+pub fn run(
+    args: &[String],
+    progress: &Progress,
+    metas: &[LessonMeta],
+    out: &mut dyn Write,
+) -> Result<(), RunnerError> {
+    if args.len() > 1 {
+        return Err(RunnerError::Usage(String::from("Usage: badge [file]")));
+    }
+    let lessons: Vec<&LessonMeta> = metas.iter().filter(|m| !m.deprecated).collect();
+    let done = lessons
+        .iter()
+        .filter(|m| progress.is_complete(&m.id()))
+        .count();
+    let svg = badge(done, lessons.len());
+    match args.first() {
+        Some(file) => {
+            fs::write(file, svg)?;
+            writeln!(
+                out,
+                "Wrote a badge for {}/{} lessons to {}",
+                done,
+                lessons.len(),
+                file
+            )?;
+        }
+        None => write!(out, "{}", svg)?,
+    }
+    Ok(())
+}
+
+fn badge(done: usize, total: usize) -> String {
+    let value = format!("{}/{} lessons", done, total);
+    let (label_width, value_width) = (text_width(LABEL) + PADDING, text_width(&value) + PADDING);
+    let width = label_width + value_width;
+    // 文字坐标放大 10 倍再缩小，与 shields.io 一样避免小数
+    let (label_x, value_x) = (label_width * 5, label_width * 10 + value_width * 5);
+    let (label_len, value_len) = ((label_width - PADDING) * 10, (value_width - PADDING) * 10);
+    format!(
+        r##"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="20" role="img" aria-label="{LABEL}: {value}">
+  <title>{LABEL}: {value}</title>
+  <linearGradient id="s" x2="0" y2="100%">
+    <stop offset="0" stop-color="#bbb" stop-opacity=".1"/>
+    <stop offset="1" stop-opacity=".1"/>
+  </linearGradient>
+  <clipPath id="r">
+    <rect width="{width}" height="20" rx="3" fill="#fff"/>
+  </clipPath>
+  <g clip-path="url(#r)">
+    <rect width="{label_width}" height="20" fill="#555"/>
+    <rect x="{label_width}" width="{value_width}" height="20" fill="{color}"/>
+    <rect width="{width}" height="20" fill="url(#s)"/>
+  </g>
+  <g fill="#fff" text-anchor="middle" font-family="Verdana,Geneva,DejaVu Sans,sans-serif" font-size="110">
+    <text x="{label_x}" y="150" fill="#010101" fill-opacity=".3" transform="scale(.1)" textLength="{label_len}">{LABEL}</text>
+    <text x="{label_x}" y="140" transform="scale(.1)" textLength="{label_len}">{LABEL}</text>
+    <text x="{value_x}" y="150" fill="#010101" fill-opacity=".3" transform="scale(.1)" textLength="{value_len}">{value}</text>
+    <text x="{value_x}" y="140" transform="scale(.1)" textLength="{value_len}">{value}</text>
+  </g>
+</svg>
+"##,
+        color = color(done, total),
+    )
+}
+
+/// 还没开始是灰色，过半之前橙色，全部完成之前黄色，全部完成绿色
+fn color(done: usize, total: usize) -> &'static str {
+    if done == 0 || total == 0 {
+        "#9f9f9f"
+    } else if done >= total {
+        "#4c1"
+    } else if done * 2 >= total {
+        "#dfb317"
+    } else {
+        "#fe7d37"
+    }
+}
+
+/// 11px Verdana 下的大致宽度（像素），足以让徽章不挤不空
+fn text_width(text: &str) -> u32 {
+    text.chars()
+        .map(|c| match c {
+            'i' | 'l' | 'j' | '.' | ',' | ':' | '\'' | '|' => 3,
+            ' ' | 'f' | 'r' | 't' | '/' | '-' | '(' | ')' => 5,
+            'm' | 'w' | 'M' | 'W' => 10,
+            c if c.is_ascii_uppercase() => 8,
+            _ => 7,
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_badge() {
+        let svg = badge(14, 19);
+        assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\" width=\""));
+        assert!(svg.contains("aria-label=\"rust-learn: 14/19 lessons\""));
+        assert!(svg.contains("fill=\"#dfb317\""));
+        assert!(svg.ends_with("</svg>\n"));
+        let width = |svg: &str| -> u32 {
+            let rest = svg.split("width=\"").nth(1).unwrap();
+            rest[..rest.find('"').unwrap()].parse().unwrap()
+        };
+        assert!(width(&badge(14, 19)) > width(&badge(1, 9)));
+    }
+
+    #[test]
+    fn test_color() {
+        assert_eq!(color(0, 19), "#9f9f9f");
+        assert_eq!(color(3, 19), "#fe7d37");
+        assert_eq!(color(10, 19), "#dfb317");
+        assert_eq!(color(19, 19), "#4c1");
+        assert_eq!(color(0, 0), "#9f9f9f");
+    }
+
+    #[test]
+    fn test_text_width() {
+        assert_eq!(text_width(""), 0);
+        assert!(text_width("mmm") > text_width("iii"));
+        assert_eq!(text_width("1/9"), 7 + 5 + 7);
+    }
+}
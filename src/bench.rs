@@ -0,0 +1,77 @@
+//! 运行性能示例的基准测试：`bench [group]`
+//!
+//! 在源码树中运行 `cargo bench --bench examples`（见 `benches/examples.rs`），给出组名时只运行这一组。
+//! 每组用 criterion 对比同一件事的几种写法；输出原样显示，完整的测量结果与 HTML 报告在
+//! `target/criterion/` 中，再次运行时会显示与上一次相比的变化。需要在源码树中运行。
+
+use crate::curriculum;
+use crate::error::RunnerError;
+use crate::utils::fmt;
+use std::env;
+use std::io::{self, Write};
+use std::process::Command;
+
+/// `benches/examples.rs` 中的基准测试组
+pub const GROUPS: &[&str] = &["iter_vs_loop", "string_concat", "map_lookup"];
+
+pub fn run(args: &[String], out: &mut dyn Write) -> Result<(), RunnerError> {
+    let group = match args {
+        [] => None,
+        [group] if GROUPS.contains(&group.as_str()) => Some(group),
+        [group] => {
+            return Err(RunnerError::Usage(format!(
+                "Unknown benchmark group '{}', expected one of: {}",
+                group,
+                GROUPS.join(", ")
+            )));
+        }
+        _ => return Err(RunnerError::Usage(String::from("Usage: bench [group]"))),
+    };
+    let cargo = env::var("CARGO").unwrap_or_else(|_| String::from("cargo"));
+    let mut command = Command::new(cargo);
+    command
+        .current_dir(curriculum::root())
+        .args(["bench", "--bench", "examples"]);
+    if let Some(group) = group {
+        command.args(["--", group]);
+    }
+    writeln!(
+        out,
+        "{}",
+        fmt::dim("Running cargo bench, this takes a few minutes ...")
+    )?;
+    let status = command.status()?;
+    if !status.success() {
+        return Err(io::Error::other(format!("cargo bench exited with {}", status)).into());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_groups_match_benches() {
+        let source = fs::read_to_string(curriculum::root().join("benches/examples.rs")).unwrap();
+        let groups: Vec<&str> = source
+            .split("benchmark_group(\"")
+            .skip(1)
+            .map(|rest| &rest[..rest.find('"').unwrap()])
+            .collect();
+        assert_eq!(groups, GROUPS);
+    }
+
+    #[test]
+    fn test_unknown_group() {
+        assert!(matches!(
+            run(&[String::from("vec_push")], &mut io::sink()),
+            Err(RunnerError::Usage(msg)) if msg.contains("iter_vs_loop")
+        ));
+        assert!(matches!(
+            run(&[String::from("a"), String::from("b")], &mut io::sink()),
+            Err(RunnerError::Usage(_))
+        ));
+    }
+}
@@ -0,0 +1,161 @@
+//! 书签：标记想回头再看的难点
+//!
+//! - `bookmark add <lesson>[:<section>]`：添加书签，选择器写法与运行 lesson 时相同；
+//! - `bookmark list`（或只写 `bookmark`）：按添加顺序列出；
+//! - `bookmark run [n]`：依次运行全部书签，或只运行第 `n` 个；
+//! - `bookmark remove <lesson>[:<section>]`：删除书签。
+//!
+//! 书签与学习进度保存在同一个文件中（见 [`progress`](crate::progress)）。
+
+use crate::cli::Options;
+use crate::error::RunnerError;
+use crate::i18n;
+use crate::lesson::Language;
+use crate::lessons::{self, Selected};
+use crate::progress::{Bookmark, Progress};
+use crate::utils::fmt;
+use std::io::Write;
+
+const USAGE: &str =
+    "Usage: bookmark add|remove <lesson>[:<section>], bookmark list, bookmark run [n]";
+
+/// 分派 `bookmark` 的子命令，`args` 不含 `bookmark` 本身
+pub fn run(
+    args: &[String],
+    progress: &mut Progress,
+    opts: &Options,
+    out: &mut dyn Write,
+) -> Result<(), RunnerError> {
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+    match args.as_slice() {
+        ["add", sel] => add(sel, progress, out),
+        ["remove", sel] => remove(sel, progress, out),
+        [] | ["list"] => list(progress, opts.language, out),
+        ["run"] => {
+            let ids: Vec<_> = progress.bookmarks().iter().map(|b| b.id.clone()).collect();
+            if ids.is_empty() {
+                writeln!(out, "No bookmarks yet")?;
+                return Ok(());
+            }
+            lessons::run_selected(&ids.join(","), progress, opts, out)
+        }
+        ["run", n] => {
+            let id = nth(progress.bookmarks(), n)?.id.clone();
+            lessons::run_selected(&id, progress, opts, out)
+        }
+        _ => Err(RunnerError::Usage(String::from(USAGE))),
+    }
+}
+
+fn add(sel: &str, progress: &mut Progress, out: &mut dyn Write) -> Result<(), RunnerError> {
+    for l in lessons::select(sel)? {
+        if progress.add_bookmark(&l.id())? {
+            writeln!(out, "Bookmarked {}", l.id())?;
+        } else {
+            writeln!(out, "{} is already bookmarked", l.id())?;
+        }
+    }
+    Ok(())
+}
+
+/// 按选择器删除；已失效（lesson 改名或所属 feature 未启用）的书签也可以按原 id 删除
+fn remove(sel: &str, progress: &mut Progress, out: &mut dyn Write) -> Result<(), RunnerError> {
+    let ids = match lessons::select(sel) {
+        Ok(picked) => picked.iter().map(Selected::id).collect(),
+        Err(_) if progress.bookmarks().iter().any(|b| b.id == sel) => vec![sel.to_string()],
+        Err(e) => return Err(e),
+    };
+    for id in ids {
+        if progress.remove_bookmark(&id)? {
+            writeln!(out, "Removed bookmark {}", id)?;
+        } else {
+            writeln!(out, "{} is not bookmarked", id)?;
+        }
+    }
+    Ok(())
+}
+
+fn list(progress: &Progress, lang: Language, out: &mut dyn Write) -> Result<(), RunnerError> {
+    let bookmarks = progress.bookmarks();
+    if bookmarks.is_empty() {
+        writeln!(
+            out,
+            "No bookmarks yet, add one with `bookmark add <lesson>[:<section>]`"
+        )?;
+        return Ok(());
+    }
+    for (i, bookmark) in bookmarks.iter().enumerate() {
+        let mark = if progress.is_complete(&bookmark.id) {
+            "✓"
+        } else {
+            " "
+        };
+        let row = format!(
+            "{:>2}. {} {:<40} {}",
+            i + 1,
+            fmt::text(mark),
+            bookmark.id,
+            fmt::dim(&bookmark.added.to_string()[..10])
+        );
+        match title(&bookmark.id, lang) {
+            Some(title) => writeln!(out, "{}  {}", row, title)?,
+            None => writeln!(out, "{}  {}", row, fmt::dim("(unavailable)"))?,
+        }
+    }
+    Ok(())
+}
+
+/// 书签的标题：lesson 标题，小节书签再加上小节标题
+fn title(id: &str, lang: Language) -> Option<String> {
+    let picked = lessons::select(id).ok()?;
+    let l = picked.first()?;
+    let title = i18n::title(&l.lesson.meta(), lang);
+    Some(match &l.section {
+        Some(section) => format!("{} / {}", title, section.title),
+        None => title.to_string(),
+    })
+}
+
+/// `bookmark run n` 中的第 `n` 个（从 1 开始）
+fn nth<'a>(bookmarks: &'a [Bookmark], n: &str) -> Result<&'a Bookmark, RunnerError> {
+    n.parse::<usize>()
+        .ok()
+        .and_then(|n| bookmarks.get(n.checked_sub(1)?))
+        .ok_or_else(|| {
+            RunnerError::Usage(format!(
+                "Bookmark number must be between 1 and {}",
+                bookmarks.len()
+            ))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::time::Timestamp;
+
+    fn bookmark(id: &str) -> Bookmark {
+        Bookmark {
+            id: id.to_string(),
+            added: Timestamp::from_unix(0),
+        }
+    }
+
+    #[test]
+    fn test_nth() {
+        let bookmarks = [bookmark("13_traits"), bookmark("14_lifetimes")];
+        assert_eq!(nth(&bookmarks, "2").unwrap().id, "14_lifetimes");
+        for bad in ["0", "3", "x"] {
+            assert!(matches!(nth(&bookmarks, bad), Err(RunnerError::Usage(_))));
+        }
+    }
+
+    #[test]
+    fn test_title() {
+        assert_eq!(
+            title("06_ownership:scope_drop", Language::Zh).as_deref(),
+            Some("所有权基础 / 作用域与丢弃")
+        );
+        assert_eq!(title("99_gone", Language::Zh), None);
+    }
+}
@@ -0,0 +1,369 @@
+//! 结业证书：`certificate [track] [markdown|html] [file]`
+//!
+//! 学完一条路线（整门课程 `all`，或 `ownership`、`types-traits` 这样的一个章节）中的全部 lesson 后，
+//! 生成一份结业证书：学习者、完成日期（路线中最后一个 lesson 的完成时间）与每章的平均分和等级
+//! （见 [`grade`](crate::grade)）。已弃用的 lesson 不计入路线。
+//! 学习者名字取学习档案名，使用默认档案时取系统用户名。
+
+use crate::error::RunnerError;
+use crate::grade;
+use crate::lesson::{Chapter, LessonMeta};
+use crate::progress::{self, Progress};
+use crate::utils::time::Timestamp;
+use std::env;
+use std::fs;
+use std::io::Write;
+
+/// 证书对应的学习路线
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Track {
+    Course,
+    Chapter(Chapter),
+}
+
+impl Track {
+    /// `all` 或章节名的小写连字符形式，例如 `error-handling`
+    fn parse(name: &str) -> Option<Track> {
+        if name == "all" {
+            return Some(Track::Course);
+        }
+        Chapter::ALL
+            .into_iter()
+            .find(|c| key(*c) == name)
+            .map(Track::Chapter)
+    }
+
+    fn includes(self, chapter: Chapter) -> bool {
+        self == Track::Course || self == Track::Chapter(chapter)
+    }
+
+    fn title(self) -> String {
+        match self {
+            Track::Course => String::from("the Rust Learn course"),
+            Track::Chapter(chapter) => format!("the {} chapter of Rust Learn", chapter),
+        }
+    }
+}
+
+/// 一章的成绩
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct ChapterScore {
+    chapter: Chapter,
+    lessons: usize,
+    score: Option<u32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Certificate {
+    name: String,
+    track: String,
+    lessons: usize,
+    finished: Timestamp,
+    chapters: Vec<ChapterScore>,
+    overall: Option<u32>,
+}
+
+/// `certificate [track] [markdown|html] [file]`，`args` 不含 `certificate` 本身
+pub fn run(
+    args: &[String],
+    profile: &str,
+    progress: &Progress,
+    metas: &[LessonMeta],
+    out: &mut dyn Write,
+) -> Result<(), RunnerError> {
+    let mut args = args.iter().map(String::as_str).peekable();
+    let track = match args.peek().and_then(|a| Track::parse(a)) {
+        Some(track) => {
+            args.next();
+            track
+        }
+        None => Track::Course,
+    };
+    let format = args.next().unwrap_or("markdown");
+    let file = args.next();
+    if args.next().is_some() || !matches!(format, "markdown" | "md" | "html") {
+        let tracks: Vec<&str> = Chapter::ALL.into_iter().map(key).collect();
+        return Err(RunnerError::Usage(format!(
+            "Usage: certificate [all|{}] [markdown|html] [file]",
+            tracks.join("|")
+        )));
+    }
+    let certificate = match build(&learner(profile), track, progress, metas) {
+        Ok(certificate) => certificate,
+        Err(missing) => {
+            writeln!(
+                out,
+                "{} lessons left before the certificate for {}: {}",
+                missing.len(),
+                track.title(),
+                missing.join(", ")
+            )?;
+            return Ok(());
+        }
+    };
+    let text = if format == "html" {
+        html(&certificate)
+    } else {
+        markdown(&certificate)
+    };
+    match file {
+        Some(file) => {
+            fs::write(file, text)?;
+            writeln!(out, "Congratulations! Certificate written to {}", file)?;
+        }
+        None => write!(out, "{}", text)?,
+    }
+    Ok(())
+}
+
+/// 章节名的小写连字符形式
+fn key(chapter: Chapter) -> &'static str {
+    match chapter {
+        Chapter::Basics => "basics",
+        Chapter::Ownership => "ownership",
+        Chapter::TypesTraits => "types-traits",
+        Chapter::Collections => "collections",
+        Chapter::ErrorHandling => "error-handling",
+        Chapter::Advanced => "advanced",
+    }
+}
+
+/// 证书上的名字：学习档案名，默认档案时取系统用户名
+fn learner(profile: &str) -> String {
+    if profile != progress::DEFAULT_PROFILE {
+        return profile.to_string();
+    }
+    env::var("USER")
+        .or_else(|_| env::var("USERNAME"))
+        .unwrap_or_else(|_| String::from("Rust learner"))
+}
+
+/// 路线全部完成时生成证书，否则返回还没完成的 lesson
+fn build(
+    name: &str,
+    track: Track,
+    progress: &Progress,
+    metas: &[LessonMeta],
+) -> Result<Certificate, Vec<String>> {
+    let metas: Vec<LessonMeta> = metas
+        .iter()
+        .filter(|m| track.includes(m.chapter) && !m.deprecated)
+        .copied()
+        .collect();
+    let missing: Vec<String> = metas
+        .iter()
+        .map(LessonMeta::id)
+        .filter(|id| !progress.is_complete(id))
+        .collect();
+    let finished = metas
+        .iter()
+        .filter_map(|m| progress.completed_at(&m.id()))
+        .max();
+    let Some(finished) = finished.filter(|_| missing.is_empty()) else {
+        return Err(missing);
+    };
+    let grades = grade::lessons(progress, &metas);
+    let chapters = Chapter::ALL
+        .into_iter()
+        .filter_map(|chapter| {
+            let grades: Vec<_> = metas
+                .iter()
+                .zip(&grades)
+                .filter(|(m, _)| m.chapter == chapter)
+                .map(|(_, g)| g.clone())
+                .collect();
+            (!grades.is_empty()).then(|| ChapterScore {
+                chapter,
+                lessons: grades.len(),
+                score: grade::summarize(&grades).average,
+            })
+        })
+        .collect();
+    Ok(Certificate {
+        name: name.to_string(),
+        track: track.title(),
+        lessons: metas.len(),
+        finished,
+        chapters,
+        overall: grade::summarize(&grades).average,
+    })
+}
+
+/// 分数与等级两列，没有成绩时为 `-`
+fn cells(score: Option<u32>) -> (String, String) {
+    match score {
+        Some(score) => (score.to_string(), grade::letter(score).to_string()),
+        None => (String::from("-"), String::from("-")),
+    }
+}
+
+fn markdown(c: &Certificate) -> String {
+    let mut out = format!(
+        "# Certificate of Completion\n\n\
+         **{}** has completed **{}** — {} lessons, finished on {}.\n\n\
+         | Chapter | Lessons | Score | Grade |\n|---|---|---|---|\n",
+        c.name,
+        c.track,
+        c.lessons,
+        &c.finished.to_string()[..10]
+    );
+    for ch in &c.chapters {
+        let (score, letter) = cells(ch.score);
+        out.push_str(&format!(
+            "| {} | {} | {} | {} |\n",
+            ch.chapter, ch.lessons, score, letter
+        ));
+    }
+    let (score, letter) = cells(c.overall);
+    out.push_str(&format!(
+        "| **Overall** | {} | **{}** | **{}** |\n",
+        c.lessons, score, letter
+    ));
+    out
+}
+
+/// 独立的 HTML 页面，样式内联，可以直接打印
+fn html(c: &Certificate) -> String {
+    let mut rows = String::new();
+    for ch in &c.chapters {
+        let (score, letter) = cells(ch.score);
+        rows.push_str(&format!(
+            "<tr><td>{}</td><td>{}</td><td>{}</td><td>{}</td></tr>\n",
+            escape(&ch.chapter.to_string()),
+            ch.lessons,
+            score,
+            letter
+        ));
+    }
+    let (score, letter) = cells(c.overall);
+    format!(
+        r#"<!DOCTYPE html>
+<html lang="en">
+<head>
+<meta charset="utf-8">
+<title>Certificate of Completion — {name}</title>
+<style>
+body {{ font-family: Georgia, serif; background: #f6f1e7; }}
+main {{ max-width: 42em; margin: 3em auto; padding: 3em; background: #fff; border: 6px double #b7410e; text-align: center; }}
+h1 {{ color: #b7410e; letter-spacing: 0.05em; }}
+.name {{ font-size: 2em; margin: 0.5em 0; }}
+table {{ margin: 2em auto 0; border-collapse: collapse; }}
+th, td {{ padding: 0.3em 1em; border-bottom: 1px solid #ddd; }}
+tfoot td {{ font-weight: bold; }}
+</style>
+</head>
+<body>
+<main>
+<h1>Certificate of Completion</h1>
+<p>This certifies that</p>
+<p class="name">{name}</p>
+<p>has completed {track} — {lessons} lessons, finished on {date}.</p>
+<table>
+<thead><tr><th>Chapter</th><th>Lessons</th><th>Score</th><th>Grade</th></tr></thead>
+<tbody>
+{rows}</tbody>
+<tfoot><tr><td>Overall</td><td>{lessons}</td><td>{score}</td><td>{letter}</td></tr></tfoot>
+</table>
+</main>
+</body>
+</html>
+"#,
+        name = escape(&c.name),
+        track = escape(&c.track),
+        lessons = c.lessons,
+        date = &c.finished.to_string()[..10],
+        rows = rows,
+        score = score,
+        letter = letter,
+    )
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lessons;
+
+    fn metas() -> Vec<LessonMeta> {
+        lessons::all().iter().map(|l| l.meta()).collect()
+    }
+
+    #[test]
+    fn test_track_names() {
+        assert_eq!(Track::parse("all"), Some(Track::Course));
+        assert_eq!(
+            Track::parse("types-traits"),
+            Some(Track::Chapter(Chapter::TypesTraits))
+        );
+        assert_eq!(Track::parse("Ownership"), None);
+        assert!(Track::Course.includes(Chapter::Advanced));
+        assert!(!Track::Chapter(Chapter::Basics).includes(Chapter::Advanced));
+    }
+
+    #[test]
+    fn test_build_requires_every_lesson() {
+        let metas = metas();
+        let basics: Vec<_> = metas
+            .iter()
+            .filter(|m| m.chapter == Chapter::Basics)
+            .collect();
+        let mut progress = Progress::default();
+        for meta in &basics[1..] {
+            progress.complete(&meta.id()).unwrap();
+        }
+        let track = Track::Chapter(Chapter::Basics);
+        assert_eq!(
+            build("alice", track, &progress, &metas),
+            Err(vec![basics[0].id()])
+        );
+
+        progress.complete(&basics[0].id()).unwrap();
+        progress.record_quiz(&basics[0].id(), 3, 4).unwrap();
+        let certificate = build("alice", track, &progress, &metas).unwrap();
+        assert_eq!(certificate.lessons, basics.len());
+        assert_eq!(
+            certificate.chapters,
+            [ChapterScore {
+                chapter: Chapter::Basics,
+                lessons: basics.len(),
+                score: Some(38),
+            }]
+        );
+        assert_eq!(certificate.overall, Some(38));
+        assert!(build("alice", Track::Course, &progress, &metas).is_err());
+    }
+
+    #[test]
+    fn test_render() {
+        let certificate = Certificate {
+            name: String::from("alice"),
+            track: Track::Chapter(Chapter::TypesTraits).title(),
+            lessons: 6,
+            finished: Timestamp::from_unix(1_700_000_000),
+            chapters: vec![ChapterScore {
+                chapter: Chapter::TypesTraits,
+                lessons: 6,
+                score: Some(91),
+            }],
+            overall: Some(91),
+        };
+        let text = markdown(&certificate);
+        assert!(text.contains(
+            "**alice** has completed **the Types & Traits chapter of Rust Learn** — 6 lessons, \
+             finished on 2023-11-14."
+        ));
+        assert!(text.contains("| Types & Traits | 6 | 91 | A |\n"));
+        assert!(text.ends_with("| **Overall** | 6 | **91** | **A** |\n"));
+        let page = html(&certificate);
+        assert!(
+            page.contains("<tr><td>Types &amp; Traits</td><td>6</td><td>91</td><td>A</td></tr>")
+        );
+        assert!(!page.contains("Types & Traits"));
+    }
+}
@@ -0,0 +1,210 @@
+//! 计时挑战：`challenge <lesson> [seconds]`
+//!
+//! 在倒计时内答完从 lesson 题库中随机抽出的测验题（与 [`quiz`](crate::quiz) 一样抽题并打乱选项），
+//! 每题之前显示剩余时间，只判对错、不显示解释，适合反复练基本功。
+//! 时限默认每题 [`SECONDS_PER_QUESTION`] 秒；时间到后提交的答案不算，剩下的题不再出。
+//! 按时全部答对时用时记入学习进度，刷新最好用时会特别提示。
+//!
+//! 练习需要修改文件并重新编译，无法在一次运行中计时，挑战只包括测验。
+
+use crate::error::RunnerError;
+use crate::lessons;
+use crate::progress::Progress;
+use crate::quiz::{self, Drawn};
+use crate::utils::io::Prompter;
+use crate::utils::rng::Rng;
+use crate::utils::{deterministic, fmt};
+use std::io::{self, BufRead, Write};
+use std::time::{Duration, Instant};
+
+/// 默认时限：每题的秒数
+pub const SECONDS_PER_QUESTION: u64 = 15;
+
+/// 一次挑战的结果
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Outcome {
+    correct: usize,
+    /// 时间到之前答完的题数
+    answered: usize,
+    elapsed: Duration,
+}
+
+/// `challenge <lesson> [seconds]`，`args` 不含 `challenge` 本身
+pub fn run(
+    args: &[String],
+    progress: &mut Progress,
+    out: &mut dyn Write,
+) -> Result<(), RunnerError> {
+    let usage = || RunnerError::Usage(String::from("Usage: challenge <lesson> [seconds]"));
+    let (Some(sel), None) = (args.first(), args.get(2)) else {
+        return Err(usage());
+    };
+    let seconds = match args.get(1) {
+        Some(s) => Some(s.parse::<u64>().ok().filter(|&n| n > 0).ok_or_else(usage)?),
+        None => None,
+    };
+    let mut prompter = Prompter::stdin();
+    let mut rng = Rng::new(deterministic::seed());
+    for lesson in lessons::resolve(sel)? {
+        let id = lesson.meta().id();
+        let questions = quiz::draw(&lesson.quiz(), quiz::QUIZ_LENGTH, &mut rng);
+        if questions.is_empty() {
+            writeln!(out, "{} has no quiz to race against", id)?;
+            continue;
+        }
+        let limit =
+            Duration::from_secs(seconds.unwrap_or(SECONDS_PER_QUESTION * questions.len() as u64));
+        let best = progress
+            .best_challenge(&id)
+            .map(|best| format!(", best {}", secs(best)))
+            .unwrap_or_default();
+        prompter.prompt(&format!(
+            "{}: {} questions in {}{}. Press Enter to start ",
+            id,
+            questions.len(),
+            secs(limit),
+            best
+        ))?;
+        let start = Instant::now();
+        let outcome = race(&questions, &mut prompter, limit, || start.elapsed())?;
+        writeln!(out)?;
+        if outcome.answered < questions.len() {
+            writeln!(
+                out,
+                "Time's up! {} / {} answered, {} correct",
+                outcome.answered,
+                questions.len(),
+                outcome.correct
+            )?;
+        } else if outcome.correct < questions.len() {
+            writeln!(
+                out,
+                "{} / {} correct in {}, answer every question right to set a time",
+                outcome.correct,
+                questions.len(),
+                secs(outcome.elapsed)
+            )?;
+        } else if progress.record_challenge(&id, outcome.elapsed)? {
+            writeln!(
+                out,
+                "{}",
+                fmt::text(&format!(
+                    "All correct in {}, new best!",
+                    secs(outcome.elapsed)
+                ))
+            )?;
+        } else {
+            writeln!(out, "All correct in {}", secs(outcome.elapsed))?;
+        }
+    }
+    Ok(())
+}
+
+/// 限时逐题提问；`elapsed` 返回开始后经过的时间，测试时可以替换
+fn race<R: BufRead, W: Write>(
+    questions: &[Drawn],
+    prompter: &mut Prompter<R, W>,
+    limit: Duration,
+    elapsed: impl Fn() -> Duration,
+) -> io::Result<Outcome> {
+    let mut outcome = Outcome {
+        correct: 0,
+        answered: 0,
+        elapsed: Duration::ZERO,
+    };
+    for (i, question) in questions.iter().enumerate() {
+        let left = limit.saturating_sub(elapsed());
+        prompter.say(&format!(
+            "\n[{} left] {}/{}: {}",
+            secs(left),
+            i + 1,
+            questions.len(),
+            question.prompt
+        ))?;
+        for (j, choice) in question.choices.iter().enumerate() {
+            prompter.say(&format!("  {}) {}", j + 1, choice))?;
+        }
+        let choice = prompter.read_number("Answer", 1..=question.choices.len() as i64)?;
+        outcome.elapsed = elapsed();
+        if outcome.elapsed > limit {
+            break;
+        }
+        outcome.answered += 1;
+        if question.is_correct(choice as usize - 1) {
+            outcome.correct += 1;
+            prompter.say(&fmt::text("  ✓"))?;
+        } else {
+            prompter.say(&fmt::text(&format!("  ✗ {}", question.answer + 1)))?;
+        }
+    }
+    Ok(outcome)
+}
+
+/// 精确到 0.1 秒，例如 `12.3s`
+fn secs(d: Duration) -> String {
+    format!("{:.1}s", d.as_secs_f64())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    fn quiz() -> Vec<Drawn> {
+        let question = |prompt, choices: &[&'static str], answer, explanation| Drawn {
+            prompt,
+            choices: choices.to_vec(),
+            answer,
+            explanation,
+        };
+        vec![
+            question("1 + 1 = ?", &["1", "2", "3"], 1, "加法"),
+            question("2 * 3 = ?", &["5", "6"], 1, "乘法"),
+        ]
+    }
+
+    /// 每次读取时钟前进 `step` 秒
+    fn clock(step: u64) -> impl Fn() -> Duration {
+        let now = Cell::new(0);
+        move || {
+            now.set(now.get() + step);
+            Duration::from_secs(now.get())
+        }
+    }
+
+    #[test]
+    fn test_race() {
+        let mut output = Vec::new();
+        let mut prompter = Prompter::new("2\n1\n".as_bytes(), &mut output);
+        let outcome = race(&quiz(), &mut prompter, Duration::from_secs(30), clock(5)).unwrap();
+        assert_eq!(
+            outcome,
+            Outcome {
+                correct: 1,
+                answered: 2,
+                elapsed: Duration::from_secs(20),
+            }
+        );
+        let text = String::from_utf8(output).unwrap();
+        assert!(text.contains("[25.0s left] 1/2: 1 + 1 = ?\n  1) 1\n"));
+        assert!(text.contains("[15.0s left] 2/2: 2 * 3 = ?"));
+        assert!(text.contains("  ✗ 2\n"));
+        assert!(!text.contains("加法"));
+    }
+
+    #[test]
+    fn test_race_times_out() {
+        let mut prompter = Prompter::new("2\n2\n".as_bytes(), Vec::new());
+        let outcome = race(&quiz(), &mut prompter, Duration::from_secs(25), clock(10)).unwrap();
+        // 第二题在 40s 时才答完，超过了时限
+        assert_eq!(outcome.answered, 1);
+        assert_eq!(outcome.correct, 1);
+        assert_eq!(outcome.elapsed, Duration::from_secs(40));
+    }
+
+    #[test]
+    fn test_secs() {
+        assert_eq!(secs(Duration::from_millis(12_345)), "12.3s");
+        assert_eq!(secs(Duration::ZERO), "0.0s");
+    }
+}
@@ -0,0 +1,271 @@
+//! 运行 lesson 的单元测试：`check <lesson>`
+//!
+//! 每个 lesson 模块末尾的 `mod tests` 演示并验证该 lesson 的要点。这里在源码树中运行
+//! `cargo test -p <lesson crate> --lib -- <slug>::tests::`，只跑选中 lesson 的测试，
+//! 把 libtest 的输出整理成逐项的 ✓ / ✗ 列表，失败的测试附上断言信息。
+//! 全部通过时记入学习进度，`info` 中可以看到最近一次通过的时间。
+//! `--junit PATH` 时另外写出 JUnit XML 报告，每个 lesson 一个测试套件，见 [`crate::junit`]。
+
+use crate::curriculum;
+use crate::error::RunnerError;
+use crate::junit::{self, Case, Suite};
+use crate::lessons;
+use crate::progress::Progress;
+use crate::utils::fmt;
+use std::env;
+use std::io::Write;
+use std::path::Path;
+use std::process::Command;
+
+/// 一个测试的结果
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Outcome {
+    Passed,
+    Failed,
+    Ignored,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct TestResult {
+    /// 去掉 `<slug>::tests::` 前缀后的测试名
+    name: String,
+    outcome: Outcome,
+    /// 失败时测试的输出（断言信息等）
+    message: String,
+}
+
+/// 运行选中的每个 lesson 的测试；给出 `junit` 时把结果写成 JUnit 报告
+pub fn run(
+    sel: &str,
+    progress: &mut Progress,
+    junit: Option<&Path>,
+    out: &mut dyn Write,
+) -> Result<(), RunnerError> {
+    let mut suites = Vec::new();
+    let result = run_suites(sel, progress, &mut suites, out);
+    if let Some(path) = junit {
+        junit::write(path, &suites)?;
+        eprintln!("JUnit report written to {}", path.display());
+    }
+    result
+}
+
+/// 逐个 lesson 运行测试，结果加进 `suites`；编译失败的 lesson 记为一个失败的 `build` 用例
+fn run_suites(
+    sel: &str,
+    progress: &mut Progress,
+    suites: &mut Vec<Suite>,
+    out: &mut dyn Write,
+) -> Result<(), RunnerError> {
+    let (_, entries) = curriculum::read()?;
+    for lesson in lessons::resolve(sel)? {
+        let id = lesson.meta().id();
+        let entry = entries.iter().find(|e| e.id() == id).ok_or_else(|| {
+            RunnerError::Config(format!("{} is missing from curriculum.toml", id))
+        })?;
+        writeln!(out, "{}", fmt::dim(&format!("Testing {} ...", id)))?;
+        let cargo = env::var("CARGO").unwrap_or_else(|_| String::from("cargo"));
+        let mut command = Command::new(cargo);
+        command
+            .current_dir(curriculum::root())
+            .args(["test", "-p", entry.lesson_crate(), "--lib"]);
+        // net / async 的 lesson 只在 lesson crate 的同名 feature 下编译
+        if !matches!(entry.feature.as_str(), "basics" | "advanced") {
+            command.args(["--features", &entry.feature]);
+        }
+        let output = command
+            .args(["--", &format!("{}::tests::", entry.slug)])
+            .output()?;
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let results = parse(&stdout, &entry.slug);
+        if results.is_empty() {
+            if output.status.success() {
+                writeln!(out, "{} has no unit tests", id)?;
+                continue;
+            }
+            // 没有任何测试结果又失败了：多半是编译错误，原样显示 cargo 的输出
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            eprint!("{}", stderr);
+            suites.push(Suite {
+                name: id.clone(),
+                cases: vec![Case {
+                    name: String::from("build"),
+                    time: None,
+                    outcome: junit::Outcome::Failed(stderr.into_owned()),
+                }],
+            });
+            return Err(RunnerError::Config(format!(
+                "cargo test failed for {} ({})",
+                id, output.status
+            )));
+        }
+        let mut passed = 0;
+        for result in &results {
+            match result.outcome {
+                Outcome::Passed => {
+                    passed += 1;
+                    writeln!(out, "  {} {}", fmt::text("✓"), result.name)?;
+                }
+                Outcome::Ignored => writeln!(out, "  - {} {}", result.name, fmt::dim("(ignored)"))?,
+                Outcome::Failed => {
+                    writeln!(out, "  {} {}", fmt::text("✗"), result.name)?;
+                    for line in result.message.lines() {
+                        writeln!(out, "      {}", line)?;
+                    }
+                }
+            }
+        }
+        suites.push(suite(&id, &results));
+        let failed = results
+            .iter()
+            .filter(|r| r.outcome == Outcome::Failed)
+            .count();
+        if failed == 0 {
+            progress.record_tests_passed(&id)?;
+            writeln!(out, "\nAll {} tests passed", passed)?;
+        } else {
+            writeln!(
+                out,
+                "\n{} / {} tests passed, fix the failures and run `check {}` again",
+                passed,
+                passed + failed,
+                entry.slug
+            )?;
+        }
+    }
+    Ok(())
+}
+
+/// 一个 lesson 的测试结果对应的 JUnit 测试套件
+fn suite(id: &str, results: &[TestResult]) -> Suite {
+    let cases = results
+        .iter()
+        .map(|r| Case {
+            name: r.name.clone(),
+            time: None,
+            outcome: match r.outcome {
+                Outcome::Passed => junit::Outcome::Passed,
+                Outcome::Failed => junit::Outcome::Failed(r.message.clone()),
+                Outcome::Ignored => junit::Outcome::Skipped,
+            },
+        })
+        .collect();
+    Suite {
+        name: id.to_string(),
+        cases,
+    }
+}
+
+/// 从 libtest 的输出中取出 `<slug>::tests::` 下的测试结果，按测试名排序
+///
+/// 结果行形如 `test structs::tests::test_x ... ok`；失败测试的输出在之后
+/// `---- structs::tests::test_x stdout ----` 开头的段落中。
+fn parse(stdout: &str, slug: &str) -> Vec<TestResult> {
+    let prefix = format!("{}::tests::", slug);
+    let mut results: Vec<TestResult> = stdout
+        .lines()
+        .filter_map(|line| {
+            let (name, status) = line.strip_prefix("test ")?.split_once(" ... ")?;
+            let outcome = match status {
+                "ok" => Outcome::Passed,
+                "FAILED" => Outcome::Failed,
+                s if s.starts_with("ignored") => Outcome::Ignored,
+                _ => return None,
+            };
+            Some(TestResult {
+                name: name.strip_prefix(&prefix)?.to_string(),
+                outcome,
+                message: String::new(),
+            })
+        })
+        .collect();
+    let mut current: Option<&mut TestResult> = None;
+    for line in stdout.lines() {
+        if let Some(header) = line.strip_prefix("---- ") {
+            let name = header
+                .strip_suffix(" stdout ----")
+                .and_then(|n| n.strip_prefix(&prefix));
+            current = results.iter_mut().find(|r| Some(r.name.as_str()) == name);
+        } else if line == "failures:" {
+            current = None;
+        } else if let Some(result) = current.as_deref_mut()
+            && !line.starts_with("note: run with `RUST_BACKTRACE")
+        {
+            result.message.push_str(line);
+            result.message.push('\n');
+        }
+    }
+    for result in &mut results {
+        result.message = result.message.trim().to_string();
+    }
+    results.sort_by(|a, b| a.name.cmp(&b.name));
+    results
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const OUTPUT: &str = "
+running 4 tests
+test structs::tests::test_b ... ok
+test exercises::structs::tests::test_c ... ok
+test structs::tests::test_a ... FAILED
+test structs::tests::test_slow ... ignored, too slow
+
+failures:
+
+---- structs::tests::test_a stdout ----
+
+thread 'structs::tests::test_a' panicked at crates/lessons-basics/src/structs.rs:10:5:
+assertion `left == right` failed
+  left: 1
+ right: 2
+note: run with `RUST_BACKTRACE=1` environment variable to display a backtrace
+
+
+failures:
+    structs::tests::test_a
+
+test result: FAILED. 1 passed; 1 failed; 1 ignored; 0 measured; 20 filtered out
+";
+
+    #[test]
+    fn test_parse() {
+        let results = parse(OUTPUT, "structs");
+        let names: Vec<_> = results
+            .iter()
+            .map(|r| (r.name.as_str(), r.outcome))
+            .collect();
+        assert_eq!(
+            names,
+            [
+                ("test_a", Outcome::Failed),
+                ("test_b", Outcome::Passed),
+                ("test_slow", Outcome::Ignored),
+            ]
+        );
+        assert_eq!(
+            results[0].message,
+            "thread 'structs::tests::test_a' panicked at crates/lessons-basics/src/structs.rs:10:5:\n\
+             assertion `left == right` failed\n  left: 1\n right: 2"
+        );
+        assert!(results[1].message.is_empty());
+    }
+
+    #[test]
+    fn test_parse_without_tests() {
+        assert!(parse("\nrunning 0 tests\n\ntest result: ok.\n", "structs").is_empty());
+        assert!(parse(OUTPUT, "enums").is_empty());
+    }
+
+    #[test]
+    fn test_suite() {
+        let suite = suite("09_structs", &parse(OUTPUT, "structs"));
+        assert_eq!(suite.name, "09_structs");
+        let outcomes: Vec<_> = suite.cases.iter().map(|c| &c.outcome).collect();
+        assert!(matches!(outcomes[0], junit::Outcome::Failed(m) if m.contains("left: 1")));
+        assert_eq!(outcomes[1], &junit::Outcome::Passed);
+        assert_eq!(outcomes[2], &junit::Outcome::Skipped);
+    }
+}
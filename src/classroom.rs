@@ -0,0 +1,237 @@
+//! 班级模式：多个学习档案与 `report` 汇总
+//!
+//! 用同一份源码带一个小班时，每个学生用 `--profile <name>`（或配置项 `profile`、环境变量
+//! `RUST_LEARN_PROFILE`）单独记录进度，不指定时使用默认档案。`profiles` 列出已有档案；
+//! `report [markdown|csv] [file]` 把每个档案的完成数、成绩（见 [`grade`](crate::grade)）、
+//! 完成前看过的提示与参考答案、学习时间汇总成一张表，不给文件时输出到标准输出。
+
+use crate::error::RunnerError;
+use crate::grade;
+use crate::lesson::LessonMeta;
+use crate::progress::{self, Progress};
+use crate::stats::hours;
+use crate::utils::time::Timestamp;
+use std::fs;
+use std::io::Write;
+use std::path::Path;
+use std::time::Duration;
+
+/// 一个学生（学习档案）的汇总
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Student {
+    name: String,
+    completed: usize,
+    total: usize,
+    /// 已评分 lesson 的平均分
+    score: Option<u32>,
+    /// 完成前看过的提示条数
+    hints: usize,
+    /// 完成前看过参考答案的练习数
+    revealed: usize,
+    time: Duration,
+    /// 最近一次运行或完成 lesson 的时间
+    last_active: Option<Timestamp>,
+}
+
+/// `profiles`：列出学习档案，当前使用的标上 `*`
+pub fn list(
+    current: &str,
+    default_file: Option<&Path>,
+    metas: &[LessonMeta],
+    out: &mut dyn Write,
+) -> Result<(), RunnerError> {
+    for name in progress::profiles() {
+        let student = summarize(&name, &Progress::load_profile(&name, default_file), metas);
+        writeln!(
+            out,
+            "{} {:<16} {} / {} lessons",
+            if name == current { "*" } else { " " },
+            name,
+            student.completed,
+            student.total
+        )?;
+    }
+    Ok(())
+}
+
+/// `report [markdown|csv] [file]`，`args` 不含 `report` 本身
+pub fn report(
+    args: &[String],
+    default_file: Option<&Path>,
+    metas: &[LessonMeta],
+    out: &mut dyn Write,
+) -> Result<(), RunnerError> {
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+    let (format, file) = match args.as_slice() {
+        [] => ("markdown", None),
+        [format] => (*format, None),
+        [format, file] => (*format, Some(*file)),
+        _ => return Err(usage()),
+    };
+    let students: Vec<Student> = progress::profiles()
+        .iter()
+        .map(|name| summarize(name, &Progress::load_profile(name, default_file), metas))
+        .collect();
+    let text = match format {
+        "markdown" | "md" => markdown(&students, Timestamp::now()),
+        "csv" => csv(&students),
+        _ => return Err(usage()),
+    };
+    match file {
+        Some(file) => {
+            fs::write(file, text)?;
+            writeln!(
+                out,
+                "Wrote report for {} students to {}",
+                students.len(),
+                file
+            )?;
+        }
+        None => write!(out, "{}", text)?,
+    }
+    Ok(())
+}
+
+fn usage() -> RunnerError {
+    RunnerError::Usage(String::from("Usage: report [markdown|csv] [file]"))
+}
+
+fn summarize(name: &str, progress: &Progress, metas: &[LessonMeta]) -> Student {
+    let ids: Vec<String> = metas.iter().map(LessonMeta::id).collect();
+    let exercises = || ids.iter().filter_map(|id| progress.exercise(id));
+    let last_run = progress.runs().iter().map(|r| r.at).max();
+    let last_completed = ids.iter().filter_map(|id| progress.completed_at(id)).max();
+    Student {
+        name: name.to_string(),
+        completed: ids.iter().filter(|id| progress.is_complete(id)).count(),
+        total: ids.len(),
+        score: grade::summarize(&grade::lessons(progress, metas)).average,
+        hints: exercises().map(|s| s.hints).sum(),
+        revealed: exercises().filter(|s| s.revealed).count(),
+        time: Duration::from_millis(progress.runs().iter().map(|r| r.millis).sum()),
+        last_active: last_run.max(last_completed),
+    }
+}
+
+/// 日期部分，没有时为 `-`
+fn date(at: Option<Timestamp>) -> String {
+    at.map_or_else(|| String::from("-"), |at| at.to_string()[..10].to_string())
+}
+
+fn markdown(students: &[Student], now: Timestamp) -> String {
+    let mut out = format!(
+        "# Class report\n\nGenerated {}, {} students.\n\n\
+         | Student | Completed | Score | Grade | Hints | Solutions viewed | Study time | Last active |\n\
+         |---|---|---|---|---|---|---|---|\n",
+        date(Some(now)),
+        students.len()
+    );
+    for s in students {
+        out.push_str(&format!(
+            "| {} | {} / {} | {} | {} | {} | {} | {} | {} |\n",
+            s.name,
+            s.completed,
+            s.total,
+            s.score.map_or_else(|| String::from("-"), |n| n.to_string()),
+            s.score.map_or('-', grade::letter),
+            s.hints,
+            s.revealed,
+            hours(s.time),
+            date(s.last_active)
+        ));
+    }
+    out
+}
+
+/// 每个学生一行；学习时间以分钟计，没有的项留空。档案名只含字母、数字、`-` 与 `_`，不用加引号
+fn csv(students: &[Student]) -> String {
+    let mut out = String::from(
+        "student,completed,total,score,grade,hints,solutions_viewed,minutes,last_active\n",
+    );
+    for s in students {
+        out.push_str(&format!(
+            "{},{},{},{},{},{},{},{},{}\n",
+            s.name,
+            s.completed,
+            s.total,
+            s.score.map(|n| n.to_string()).unwrap_or_default(),
+            s.score
+                .map(|n| grade::letter(n).to_string())
+                .unwrap_or_default(),
+            s.hints,
+            s.revealed,
+            s.time.as_secs() / 60,
+            s.last_active
+                .map(|at| at.to_string()[..10].to_string())
+                .unwrap_or_default()
+        ));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lessons;
+
+    fn students() -> Vec<Student> {
+        vec![
+            Student {
+                name: String::from("alice"),
+                completed: 3,
+                total: 20,
+                score: Some(85),
+                hints: 2,
+                revealed: 0,
+                time: Duration::from_secs(5400),
+                last_active: Some(Timestamp::from_unix(1_700_000_000)),
+            },
+            Student {
+                name: String::from("bob"),
+                completed: 0,
+                total: 20,
+                score: None,
+                hints: 0,
+                revealed: 1,
+                time: Duration::ZERO,
+                last_active: None,
+            },
+        ]
+    }
+
+    #[test]
+    fn test_summarize() {
+        let metas: Vec<_> = lessons::all().iter().map(|l| l.meta()).collect();
+        let id = metas[0].id();
+        let mut progress = Progress::default();
+        progress.complete(&id).unwrap();
+        progress.record_exercise(&id, Vec::new(), 2).unwrap();
+        progress.use_hint(&id, 2).unwrap();
+        progress.record_run(&id, Duration::from_secs(90)).unwrap();
+        let student = summarize("alice", &progress, &metas);
+        assert_eq!(student.completed, 1);
+        assert_eq!(student.total, metas.len());
+        assert_eq!(student.score, Some(0));
+        assert_eq!(student.hints, 2);
+        assert_eq!(student.time, Duration::from_secs(90));
+        assert!(student.last_active.is_some());
+    }
+
+    #[test]
+    fn test_markdown() {
+        let text = markdown(&students(), Timestamp::from_unix(1_700_000_000));
+        assert!(text.starts_with("# Class report\n\nGenerated 2023-11-14, 2 students.\n"));
+        assert!(text.contains("| alice | 3 / 20 | 85 | B | 2 | 0 | 1h 30m | 2023-11-14 |\n"));
+        assert!(text.ends_with("| bob | 0 / 20 | - | - | 0 | 1 | 0s | - |\n"));
+    }
+
+    #[test]
+    fn test_csv() {
+        assert_eq!(
+            csv(&students()),
+            "student,completed,total,score,grade,hints,solutions_viewed,minutes,last_active\n\
+             alice,3,20,85,B,2,0,90,2023-11-14\n\
+             bob,0,20,,,0,1,0,\n"
+        );
+    }
+}
@@ -0,0 +1,554 @@
+//! 命令行参数解析
+//!
+//! 把参数拆成位置参数与选项两部分，选项可以出现在任意位置，
+//! 支持 `--name value` 与 `--name=value` 两种写法。
+//! 配置文件中的默认值先填入 [`Options`]，命令行选项再覆盖它们。
+
+use crate::lesson::{Language, LessonContext, Verbosity};
+use crate::utils::fmt::{ColorChoice, Theme};
+use std::io::Write;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+/// 各命令的用法，`{prog}` 是调用本程序的方式；只能在源码树中运行的命令直接写 `cargo run --`。
+/// 用法说明与 man 页（见 [`crate::manpage`]）都由这里生成
+pub const COMMANDS: &[&str] = &[
+    "{prog} list",
+    "{prog} toc",
+    "{prog} path [--adaptive]",
+    "{prog} recommend",
+    "{prog} today",
+    "{prog} placement",
+    "{prog} all [--jobs N] [--junit PATH]",
+    "{prog} repl",
+    "{prog} info <lesson>",
+    "{prog} complete <lesson>",
+    "{prog} bookmark add|remove <lesson> | list | run [n]",
+    "{prog} note <lesson> <text>",
+    "{prog} notes [lesson]",
+    "{prog} stats [export [file]] | --format csv [file]",
+    "{prog} profiles",
+    "{prog} reset --lesson <lesson> [--quizzes] | --quizzes | --all",
+    "{prog} report [markdown|csv] [file]",
+    "{prog} certificate [all|<chapter>] [markdown|html] [file]",
+    "{prog} badge [file]",
+    "{prog} quiz <lesson>",
+    "{prog} challenge <lesson> [seconds]",
+    "{prog} exercise <lesson>",
+    "{prog} hint <lesson> [n]",
+    "{prog} solution <lesson>",
+    "cargo run -- practice [lesson]",
+    "{prog} kata [list [difficulty]] | <name> | <difficulty>",
+    "cargo run -- check <lesson> [--junit PATH]",
+    "cargo run -- bench [group]",
+    "{prog} pitfalls <lesson>",
+    "{prog} explain-error [code]",
+    "{prog} mentor < snippet.rs",
+    "{prog} generate-koans <dir>",
+    "{prog} generate-man [file]",
+    "{prog} export --format anki|json [file]",
+    "{prog} export <lesson> --format md [file]",
+    "{prog} export --format mdbook|html|ipynb [dir]",
+    "{prog} export --format po [file] [--lang <code>]",
+    "cargo run -- import-translations <file>",
+    "cargo run -- show <lesson>[:<section>] [--copy]",
+    "cargo run -- share <lesson>[:<section>]",
+    "cargo run -- scaffold <lesson>[:<section>] <dir>",
+    "{prog} share-output <lesson>[:<section>] [--yes]",
+    "cargo run -- serve [addr]",
+    "{prog} record <lesson>[:<section>] [file]",
+    "cargo run --features hot-reload -- dev <lesson>",
+    "cargo run -- new <slug> [number]",
+    "cargo run -- renumber <lesson> <number>",
+    "cargo run -- lint-lessons",
+    "cargo run -- update-readme [file]",
+    "cargo run -- update-doctests",
+    "cargo run -- coverage",
+    "{prog} <lesson>[:<section>]",
+];
+
+/// 选项与说明
+pub const OPTIONS: &[(&str, &str)] = &[
+    ("-j, --jobs N", "all 命令的并行线程数（默认 CPU 核数）"),
+    (
+        "--deterministic",
+        "可复现输出：HashMap 排序、固定随机种子、隐藏耗时/地址",
+    ),
+    ("--ascii", "纯 ASCII 输出：替换 emoji 与制表符"),
+    (
+        "--output PATH",
+        "同时把输出记录到文件；PATH 为目录时按时间命名，如 sessions/",
+    ),
+    ("-v, --verbose", "显示更详细的讲解（由各 lesson 决定）"),
+    ("-q, --quiet", "只输出 lesson 内容，不显示标题与提示"),
+    ("--color WHEN", "横幅着色：auto（默认）/ always / never"),
+    (
+        "--theme NAME",
+        "输出主题：plain / classic（默认）/ fancy / high-contrast",
+    ),
+    ("--lang LANG", "讲解语言：zh（默认）/ en"),
+    (
+        "--format FORMAT",
+        "运行 lesson 时的输出格式：text（默认）/ jsonl（每行一个 JSON 事件）；export 用 anki / md / mdbook / html / json / ipynb / po，stats 用 csv",
+    ),
+    (
+        "--pager CMD",
+        "用分页程序显示输出，如 \"less -R\"；--no-pager 关闭",
+    ),
+    ("--no-related", "不显示前置 lesson 提醒与相关 lesson 建议"),
+    (
+        "--profile NAME",
+        "学习档案：多人共用一份源码时各自记录进度（默认 default）",
+    ),
+    ("--step", "逐节运行 lesson，每节之后询问是否继续"),
+    (
+        "--confidence",
+        "运行完 lesson 后自评掌握程度（1-5），用于复习建议与统计",
+    ),
+    (
+        "--junit PATH",
+        "all 与 check 结束后写出 JUnit XML 报告，供 CI 与 IDE 显示",
+    ),
+    (
+        "--adaptive",
+        "path 跳过测验已掌握的 lesson，给出个性化的学习顺序",
+    ),
+    (
+        "--transcript",
+        "退出时把本次输出整理成 Markdown，追加到 sessions/YYYY-MM-DD.md",
+    ),
+    (
+        "--copy",
+        "show 把代码放到剪贴板上而不显示（需要 clipboard feature）",
+    ),
+    ("--yes", "share-output 上传前不再询问确认"),
+    (
+        "--lesson SEL / --quizzes / --all",
+        "reset 清除的范围：选中的 lesson、测验成绩、全部记录",
+    ),
+];
+
+/// 示例：参数与说明
+pub const EXAMPLES: &[(&str, &str)] = &[
+    ("list", "列出所有 lessons"),
+    ("toc", "按章节查看目录"),
+    ("path", "按前置关系排出的推荐学习顺序"),
+    ("01_hello_world", "运行指定 lesson"),
+    ("1", "通过编号运行 lesson"),
+    ("1-5", "按顺序运行第1到第5个 lesson"),
+    ("16:closure_capture", "只运行 lesson 中的一节"),
+    ("complete 1-5", "不运行，直接标记为已完成"),
+    (
+        "bookmark add 14:struct_lifetimes",
+        "给难点加书签，之后用 bookmark run 重看",
+    ),
+    ("all -j 4", "4 个线程并行运行全部 lesson，按顺序输出"),
+    ("exercise slices", "检查补全的练习，逐项报告是否通过"),
+    ("repl", "交互式试验代码片段"),
+];
+
+#[derive(Debug, Default)]
+pub struct Options {
+    /// `--jobs N` / `-j N`：`all` 命令的并行度
+    pub jobs: Option<usize>,
+    /// `--deterministic`：输出可跨运行复现（HashMap 排序、固定随机种子等）
+    pub deterministic: bool,
+    /// `--ascii`：用纯 ASCII 替换 emoji、制表符等装饰字符
+    pub ascii: bool,
+    /// `--output PATH`：把输出同时记录到文件（由父进程统一写入，不透传）
+    pub output: Option<PathBuf>,
+    /// `--verbose` / `--quiet`：输出详细程度
+    pub verbosity: Verbosity,
+    /// `--no-related`：不显示前置 lesson 提醒与相关 lesson 建议
+    pub no_related: bool,
+    /// `--color WHEN`：何时给横幅等装饰着色
+    pub color: ColorChoice,
+    /// `--theme NAME`：横幅、小节标题的样式与配色
+    pub theme: Theme,
+    /// `--lang LANG`：讲解语言
+    pub language: Language,
+    /// `--pager CMD` / `--no-pager`：用于分页显示输出的程序
+    pub pager: Option<String>,
+    /// 进度记录文件位置（只能在配置中指定）
+    pub progress_file: Option<PathBuf>,
+    /// `--profile NAME`：学习档案，多人共用一份源码时各自记录进度
+    pub profile: Option<String>,
+    /// `--format FORMAT`：运行 lesson 时的输出格式
+    pub format: Format,
+    /// `--step`：逐节运行，每节之后询问是否继续（只用于交互式文本输出，不透传）
+    pub step: bool,
+    /// `--confidence`：运行完 lesson 后请学习者自评掌握程度（只用于交互式文本输出，不透传）
+    pub confidence: bool,
+    /// `--adaptive`：`path` 跳过测验已经掌握的 lesson，只排剩下的（不透传）
+    pub adaptive: bool,
+    /// `--transcript`：退出时把本次的输出整理成 Markdown 追加到 `sessions/YYYY-MM-DD.md`（不透传）
+    pub transcript: bool,
+    /// `--junit PATH`：`all` 与 `check` 结束后写出 JUnit XML 报告（由父进程统一写入，不透传）
+    pub junit: Option<PathBuf>,
+    /// `--copy`：`show` 把代码复制到剪贴板（不透传）
+    pub copy: bool,
+    /// `--yes`：`share-output` 上传前不再询问确认（不透传）
+    pub yes: bool,
+    /// `--lesson SEL`、`--quizzes`、`--all`：`reset` 要清除的范围（不透传）
+    pub reset: ResetScope,
+}
+
+/// `reset` 要清除的记录
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ResetScope {
+    /// 只清除这些 lesson 的记录
+    pub lesson: Option<String>,
+    /// 只清除测验成绩
+    pub quizzes: bool,
+    /// 清除全部记录
+    pub all: bool,
+}
+
+/// 运行 lesson 时的输出格式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Format {
+    /// 面向人阅读的文本
+    #[default]
+    Text,
+    /// 每行一个 JSON 事件，见 [`crate::events`]
+    Jsonl,
+    /// Anki 闪卡（TSV），只用于 `export`，见 [`crate::export`]
+    Anki,
+    /// 单个 lesson 的讲义（Markdown），只用于 `export`，见 [`crate::export`]
+    Markdown,
+    /// 整个课程的 mdBook，只用于 `export`
+    MdBook,
+    /// 静态网站，只用于 `export`，见 [`crate::site`]
+    Html,
+    /// 课程目录（JSON），只用于 `export`
+    Json,
+    /// 每个 lesson 一个 Jupyter notebook，只用于 `export`，见 [`crate::notebook`]
+    Ipynb,
+    /// 翻译文件（gettext PO），只用于 `export`，见 [`crate::translations`]
+    Po,
+    /// 每个 lesson 的学习记录（CSV），只用于 `stats`，见 [`crate::stats`]
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "jsonl" => Ok(Format::Jsonl),
+            "anki" => Ok(Format::Anki),
+            "md" | "markdown" => Ok(Format::Markdown),
+            "mdbook" => Ok(Format::MdBook),
+            "html" => Ok(Format::Html),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            "ipynb" => Ok(Format::Ipynb),
+            "po" => Ok(Format::Po),
+            _ => Err(format!(
+                "Invalid format '{}' (text, jsonl, anki, md, mdbook, html, json, csv, ipynb, po)",
+                s
+            )),
+        }
+    }
+}
+
+impl Options {
+    /// 按这些选项为一次 lesson 运行创建上下文
+    pub fn context<'a>(&self, out: &'a mut dyn Write) -> LessonContext<'a> {
+        LessonContext::new(out)
+            .with_verbosity(self.verbosity)
+            .with_language(self.language)
+    }
+
+    /// 需要传给子进程（例如并行 `all`）的选项；子进程总是不显示提醒与相关建议
+    pub fn child_args(&self) -> Vec<String> {
+        let mut args = vec![String::from("--no-related")];
+        if self.deterministic {
+            args.push(String::from("--deterministic"));
+        }
+        if self.ascii {
+            args.push(String::from("--ascii"));
+        }
+        match self.verbosity {
+            Verbosity::Quiet => args.push(String::from("--quiet")),
+            Verbosity::Normal => {}
+            Verbosity::Verbose => args.push(String::from("--verbose")),
+        }
+        if self.theme != Theme::default() {
+            args.push(format!("--theme={}", self.theme.name()));
+        }
+        if self.language != Language::default() {
+            args.push(format!("--lang={}", self.language.code()));
+        }
+        if self.format == Format::Jsonl {
+            args.push(String::from("--format=jsonl"));
+        }
+        // 并行 `all` 的子进程自己记录进度，要写进同一个档案
+        if let Some(profile) = &self.profile {
+            args.push(format!("--profile={}", profile));
+        }
+        args
+    }
+}
+
+/// 作为 cargo 子命令运行（`cargo learn list`）时，cargo 会把子命令名 `learn`
+/// 作为第一个参数传进来；去掉它并返回是否以这种方式运行
+pub fn strip_subcommand(args: &mut Vec<String>) -> bool {
+    let cargo = args.first().is_some_and(|arg| arg == "learn");
+    if cargo {
+        args.remove(0);
+    }
+    cargo
+}
+
+pub fn parse(args: &[String]) -> Result<(Vec<String>, Options), String> {
+    parse_with(args, Options::default())
+}
+
+/// 以 `opts`（通常来自配置文件）为默认值解析命令行
+pub fn parse_with(args: &[String], mut opts: Options) -> Result<(Vec<String>, Options), String> {
+    let mut positional = Vec::new();
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        let (name, inline) = match arg.split_once('=') {
+            Some((name, value)) if name.starts_with("--") => (name, Some(value.to_string())),
+            _ => (arg.as_str(), None),
+        };
+        let mut value = || {
+            inline
+                .clone()
+                .or_else(|| iter.next().cloned())
+                .ok_or_else(|| format!("Option '{}' requires a value", name))
+        };
+        match name {
+            "--jobs" | "-j" => opts.jobs = Some(parse_jobs(&value()?)?),
+            "--deterministic" => opts.deterministic = true,
+            "--ascii" => opts.ascii = true,
+            "--no-related" => opts.no_related = true,
+            "--step" => opts.step = true,
+            "--confidence" => opts.confidence = true,
+            "--adaptive" => opts.adaptive = true,
+            "--transcript" => opts.transcript = true,
+            "--copy" => opts.copy = true,
+            "--yes" => opts.yes = true,
+            "--junit" => opts.junit = Some(PathBuf::from(value()?)),
+            "--lesson" => opts.reset.lesson = Some(value()?),
+            "--quizzes" => opts.reset.quizzes = true,
+            "--all" => opts.reset.all = true,
+            "--verbose" | "-v" => opts.verbosity = Verbosity::Verbose,
+            "--quiet" | "-q" => opts.verbosity = Verbosity::Quiet,
+            "--output" | "-o" => opts.output = Some(PathBuf::from(value()?)),
+            "--color" => opts.color = value()?.parse()?,
+            "--theme" => opts.theme = value()?.parse()?,
+            "--lang" => opts.language = value()?.parse()?,
+            "--format" => opts.format = value()?.parse()?,
+            "--pager" => opts.pager = Some(value()?),
+            "--profile" => opts.profile = Some(value()?),
+            "--no-pager" => opts.pager = None,
+            _ if name.starts_with('-') => return Err(format!("Unknown option '{}'", name)),
+            _ => positional.push(arg.clone()),
+        }
+    }
+    // `reset` 专用的选项用在其他命令上时报错，而不是悄悄忽略
+    let reset_flags = [
+        ("--lesson", opts.reset.lesson.is_some()),
+        ("--quizzes", opts.reset.quizzes),
+        ("--all", opts.reset.all),
+    ];
+    if positional.first().is_none_or(|command| command != "reset")
+        && let Some((flag, _)) = reset_flags.iter().find(|(_, set)| *set)
+    {
+        return Err(format!("Option '{}' is only valid for 'reset'", flag));
+    }
+    Ok((positional, opts))
+}
+
+fn parse_jobs(value: &str) -> Result<usize, String> {
+    match value.parse::<usize>() {
+        Ok(n) if n > 0 => Ok(n),
+        _ => Err(format!("Invalid job count '{}'", value)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(list: &[&str]) -> Vec<String> {
+        list.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_positional_only() {
+        let (pos, opts) = parse(&args(&["1-5"])).unwrap();
+        assert_eq!(pos, vec!["1-5"]);
+        assert_eq!(opts.jobs, None);
+    }
+
+    #[test]
+    fn test_jobs_forms() {
+        let (pos, opts) = parse(&args(&["all", "--jobs", "4"])).unwrap();
+        assert_eq!(pos, vec!["all"]);
+        assert_eq!(opts.jobs, Some(4));
+
+        let (_, opts) = parse(&args(&["-j", "2", "all"])).unwrap();
+        assert_eq!(opts.jobs, Some(2));
+
+        let (_, opts) = parse(&args(&["all", "--jobs=8"])).unwrap();
+        assert_eq!(opts.jobs, Some(8));
+    }
+
+    #[test]
+    fn test_deterministic_is_forwarded() {
+        let (pos, opts) = parse(&args(&["--deterministic", "15"])).unwrap();
+        assert_eq!(pos, vec!["15"]);
+        assert!(opts.deterministic);
+        assert_eq!(opts.child_args(), vec!["--no-related", "--deterministic"]);
+        assert_eq!(Options::default().child_args(), vec!["--no-related"]);
+    }
+
+    #[test]
+    fn test_ascii_flag() {
+        let (_, opts) = parse(&args(&["all", "--ascii", "--deterministic"])).unwrap();
+        assert!(opts.ascii);
+        assert_eq!(
+            opts.child_args(),
+            vec!["--no-related", "--deterministic", "--ascii"]
+        );
+    }
+
+    #[test]
+    fn test_verbosity_flags() {
+        let (_, opts) = parse(&args(&["-q", "3"])).unwrap();
+        assert_eq!(opts.verbosity, Verbosity::Quiet);
+        assert_eq!(opts.child_args(), vec!["--no-related", "--quiet"]);
+
+        let (_, opts) = parse(&args(&["--verbose", "3"])).unwrap();
+        assert_eq!(opts.verbosity, Verbosity::Verbose);
+    }
+
+    #[test]
+    fn test_output_is_not_forwarded() {
+        let (pos, opts) = parse(&args(&["all", "-o", "sessions/"])).unwrap();
+        assert_eq!(pos, vec!["all"]);
+        assert_eq!(opts.output, Some(PathBuf::from("sessions/")));
+        assert_eq!(opts.child_args(), vec!["--no-related"]);
+    }
+
+    #[test]
+    fn test_step_and_confidence_flags() {
+        let (_, opts) = parse(&args(&["16", "--step", "--confidence"])).unwrap();
+        assert!(opts.step && opts.confidence);
+        assert_eq!(opts.child_args(), vec!["--no-related"]);
+    }
+
+    #[test]
+    fn test_adaptive_flag() {
+        let (pos, opts) = parse(&args(&["path", "--adaptive"])).unwrap();
+        assert_eq!(pos, vec!["path"]);
+        assert!(opts.adaptive);
+    }
+
+    #[test]
+    fn test_transcript_flag() {
+        let (_, opts) = parse(&args(&["all", "--transcript"])).unwrap();
+        assert!(opts.transcript);
+        assert_eq!(opts.child_args(), vec!["--no-related"]);
+    }
+
+    #[test]
+    fn test_copy_flag() {
+        let (pos, opts) = parse(&args(&["show", "6", "--copy"])).unwrap();
+        assert_eq!(pos, vec!["show", "6"]);
+        assert!(opts.copy);
+    }
+
+    #[test]
+    fn test_yes_flag() {
+        let (pos, opts) = parse(&args(&["share-output", "6", "--yes"])).unwrap();
+        assert_eq!(pos, vec!["share-output", "6"]);
+        assert!(opts.yes);
+        assert_eq!(opts.child_args(), vec!["--no-related"]);
+    }
+
+    #[test]
+    fn test_reset_flags() {
+        let (pos, opts) = parse(&args(&["reset", "--lesson=6", "--quizzes"])).unwrap();
+        assert_eq!(pos, vec!["reset"]);
+        assert_eq!(opts.reset.lesson.as_deref(), Some("6"));
+        assert!(opts.reset.quizzes && !opts.reset.all);
+        assert_eq!(opts.child_args(), vec!["--no-related"]);
+
+        let (_, opts) = parse(&args(&["--all", "reset"])).unwrap();
+        assert!(opts.reset.all);
+        for list in [
+            &["6", "--all"][..],
+            &["all", "--all"],
+            &["quiz", "--lesson", "6"],
+            &["--quizzes"],
+        ] {
+            let err = parse(&args(list)).unwrap_err();
+            assert!(err.ends_with("is only valid for 'reset'"), "{}", err);
+        }
+    }
+
+    #[test]
+    fn test_junit_flag() {
+        let (_, opts) = parse(&args(&["all", "-j", "4", "--junit", "report.xml"])).unwrap();
+        assert_eq!(opts.junit, Some(PathBuf::from("report.xml")));
+        assert_eq!(opts.child_args(), vec!["--no-related"]);
+    }
+
+    #[test]
+    fn test_cli_overrides_defaults() {
+        let base = Options {
+            pager: Some(String::from("less -R")),
+            verbosity: Verbosity::Verbose,
+            ..Options::default()
+        };
+        let (_, opts) = parse_with(&args(&["1", "--no-pager", "--lang", "en"]), base).unwrap();
+        assert_eq!(opts.pager, None);
+        assert_eq!(opts.verbosity, Verbosity::Verbose);
+        assert_eq!(opts.language, Language::En);
+        assert!(opts.child_args().contains(&String::from("--lang=en")));
+
+        let (_, opts) = parse(&args(&["--color=never", "1", "--format", "jsonl"])).unwrap();
+        assert_eq!(opts.color, ColorChoice::Never);
+        assert_eq!(opts.format, Format::Jsonl);
+        assert!(opts.child_args().contains(&String::from("--format=jsonl")));
+        let (_, opts) = parse(&args(&["export", "--format=anki"])).unwrap();
+        assert_eq!(opts.format, Format::Anki);
+        let (_, opts) = parse(&args(&["export", "13", "--format", "md"])).unwrap();
+        assert_eq!(opts.format, Format::Markdown);
+
+        let (_, opts) = parse(&args(&["all", "--profile", "alice"])).unwrap();
+        assert_eq!(opts.profile.as_deref(), Some("alice"));
+        assert!(opts.child_args().contains(&String::from("--profile=alice")));
+
+        let (_, opts) = parse(&args(&["all", "--theme", "high-contrast"])).unwrap();
+        assert_eq!(opts.theme, Theme::HighContrast);
+        assert!(
+            opts.child_args()
+                .contains(&String::from("--theme=high-contrast"))
+        );
+    }
+
+    #[test]
+    fn test_strip_cargo_subcommand() {
+        let mut list = args(&["learn", "13_traits", "-q"]);
+        assert!(strip_subcommand(&mut list));
+        assert_eq!(list, ["13_traits", "-q"]);
+        assert!(!strip_subcommand(&mut list));
+        assert_eq!(list, ["13_traits", "-q"]);
+        assert!(!strip_subcommand(&mut Vec::new()));
+    }
+
+    #[test]
+    fn test_invalid_options() {
+        assert!(parse(&args(&["all", "--jobs"])).is_err());
+        assert!(parse(&args(&["all", "--jobs", "0"])).is_err());
+        assert!(parse(&args(&["all", "--bogus"])).is_err());
+        assert!(parse(&args(&["1", "--color", "pink"])).is_err());
+    }
+}
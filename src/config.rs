@@ -0,0 +1,304 @@
+//! 配置文件加载
+//!
+//! 依次读取 `~/.config/rust-learn/config.toml` 与 `./rust-learn.toml`，
+//! 后读取的文件覆盖先读取的同名项；命令行选项又覆盖配置文件：
+//!
+//! ```toml
+//! color = "auto"              # auto / always / never
+//! theme = "classic"           # plain / classic / fancy / high-contrast
+//! language = "zh"             # zh / en
+//! verbosity = "normal"        # quiet / normal / verbose
+//! pager = "less -R"
+//! progress_file = "~/notes/rust-progress.json"
+//! profile = "alice"           # 学习档案，见 `profiles` / `report`
+//! confidence = true           # 运行完 lesson 后自评掌握程度
+//! transcript = true           # 每次运行的输出整理进 sessions/YYYY-MM-DD.md
+//!
+//! [aliases]
+//! own = "06_ownership"
+//! basics = "1-5"
+//! ```
+//!
+//! 同一设置的来源优先级从低到高为：
+//!
+//! 1. 内置默认值
+//! 2. `~/.config/rust-learn/config.toml`
+//! 3. `./rust-learn.toml`
+//! 4. 环境变量（见 [`ENV_VARS`]）
+//! 5. 命令行选项
+
+use crate::cli::Options;
+use crate::lesson::{Language, Verbosity};
+use crate::utils::fmt::{ColorChoice, Theme};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::{env, fs};
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// 别名 → 实际选择器（lesson 编号、slug、区间或命令名）
+    pub aliases: BTreeMap<String, String>,
+    pub color: Option<ColorChoice>,
+    pub theme: Option<Theme>,
+    pub language: Option<Language>,
+    pub verbosity: Option<Verbosity>,
+    /// 分页程序，例如 `less -R`
+    pub pager: Option<String>,
+    /// 进度记录文件，支持 `~/` 开头
+    pub progress_file: Option<PathBuf>,
+    /// 默认使用的学习档案
+    pub profile: Option<String>,
+    /// 运行完 lesson 后询问自评
+    pub confidence: Option<bool>,
+    /// 自动记录会话，见 [`transcript`](crate::transcript)
+    pub transcript: Option<bool>,
+}
+
+/// 支持的环境变量及说明
+pub const ENV_VARS: &[(&str, &str)] = &[
+    ("RUST_LEARN_COLOR", "auto / always / never"),
+    (
+        "RUST_LEARN_NO_COLOR",
+        "非空时关闭着色，优先于 RUST_LEARN_COLOR",
+    ),
+    (
+        "RUST_LEARN_THEME",
+        "plain / classic / fancy / high-contrast",
+    ),
+    ("RUST_LEARN_LANG", "zh / en"),
+    ("RUST_LEARN_VERBOSITY", "quiet / normal / verbose"),
+    ("RUST_LEARN_PAGER", "分页程序，空值表示不分页"),
+    ("RUST_LEARN_PROGRESS_FILE", "进度记录文件"),
+    ("RUST_LEARN_PROFILE", "学习档案名"),
+];
+
+impl Config {
+    /// 按优先级从低到高加载所有存在的配置文件，最后叠加环境变量
+    ///
+    /// 任一文件或环境变量无效都返回错误，而不是跳过它改用默认值
+    pub fn load() -> Result<Config, String> {
+        let mut config = Config::default();
+        for path in config_paths() {
+            if path.is_file() {
+                log::debug!("loading config {}", path.display());
+                config.merge(Config::from_file(&path)?);
+            } else {
+                log::trace!("no config at {}", path.display());
+            }
+        }
+        config.merge(Config::from_env(|name| env::var(name).ok())?);
+        log::trace!("effective config: {:?}", config);
+        Ok(config)
+    }
+
+    /// 从环境变量读取设置；`var` 按名字查询变量值，便于测试时替换
+    pub fn from_env(var: impl Fn(&str) -> Option<String>) -> Result<Config, String> {
+        fn parse<T: FromStr<Err = String>>(
+            name: &str,
+            value: Option<String>,
+        ) -> Result<Option<T>, String> {
+            value
+                .map(|v| v.parse().map_err(|e| format!("{}: {}", name, e)))
+                .transpose()
+        }
+
+        let mut config = Config {
+            color: parse("RUST_LEARN_COLOR", var("RUST_LEARN_COLOR"))?,
+            theme: parse("RUST_LEARN_THEME", var("RUST_LEARN_THEME"))?,
+            language: parse("RUST_LEARN_LANG", var("RUST_LEARN_LANG"))?,
+            verbosity: parse("RUST_LEARN_VERBOSITY", var("RUST_LEARN_VERBOSITY"))?,
+            pager: var("RUST_LEARN_PAGER"),
+            progress_file: var("RUST_LEARN_PROGRESS_FILE").map(PathBuf::from),
+            profile: var("RUST_LEARN_PROFILE"),
+            ..Config::default()
+        };
+        if var("RUST_LEARN_NO_COLOR").is_some_and(|v| !v.is_empty()) {
+            config.color = Some(ColorChoice::Never);
+        }
+        Ok(config)
+    }
+
+    pub fn from_file(path: &Path) -> Result<Config, String> {
+        let text = fs::read_to_string(path)
+            .map_err(|e| format!("Cannot read config '{}': {}", path.display(), e))?;
+        Config::parse(&text).map_err(|e| format!("Invalid config '{}': {}", path.display(), e))
+    }
+
+    pub fn parse(text: &str) -> Result<Config, String> {
+        toml::from_str(text).map_err(|e| e.to_string())
+    }
+
+    fn merge(&mut self, other: Config) {
+        self.aliases.extend(other.aliases);
+        self.color = other.color.or(self.color);
+        self.theme = other.theme.or(self.theme);
+        self.language = other.language.or(self.language);
+        self.verbosity = other.verbosity.or(self.verbosity);
+        self.pager = other.pager.or(self.pager.take());
+        self.progress_file = other.progress_file.or(self.progress_file.take());
+        self.profile = other.profile.or(self.profile.take());
+        self.confidence = other.confidence.or(self.confidence);
+        self.transcript = other.transcript.or(self.transcript);
+    }
+
+    /// 配置文件与环境变量给出的默认选项，命令行解析在此基础上覆盖
+    pub fn defaults(&self) -> Options {
+        Options {
+            color: self.color.unwrap_or_default(),
+            theme: self.theme.unwrap_or_default(),
+            language: self.language.unwrap_or_default(),
+            verbosity: self.verbosity.unwrap_or_default(),
+            pager: self.pager.clone().filter(|pager| !pager.is_empty()),
+            progress_file: self.progress_file.as_deref().map(expand_home),
+            profile: self.profile.clone(),
+            confidence: self.confidence.unwrap_or_default(),
+            transcript: self.transcript.unwrap_or_default(),
+            ..Options::default()
+        }
+    }
+
+    /// 展开别名；不是别名时原样返回（只展开一层，避免循环引用）
+    pub fn resolve_alias<'a>(&'a self, sel: &'a str) -> &'a str {
+        self.aliases.get(sel).map(String::as_str).unwrap_or(sel)
+    }
+}
+
+/// 展开开头的 `~/`
+fn expand_home(path: &Path) -> PathBuf {
+    match (path.strip_prefix("~"), env::var_os("HOME")) {
+        (Ok(rest), Some(home)) => PathBuf::from(home).join(rest),
+        _ => path.to_path_buf(),
+    }
+}
+
+/// 配置文件搜索路径：用户级在前，项目级在后
+fn config_paths() -> Vec<PathBuf> {
+    let mut paths = Vec::new();
+    let config_home = env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")));
+    if let Some(dir) = config_home {
+        paths.push(dir.join("rust-learn").join("config.toml"));
+    }
+    paths.push(PathBuf::from("rust-learn.toml"));
+    paths
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_aliases() {
+        let config = Config::parse(
+            r#"
+            [aliases]
+            own = "06_ownership"
+            basics = "1-5"
+            "#,
+        )
+        .unwrap();
+        assert_eq!(config.resolve_alias("own"), "06_ownership");
+        assert_eq!(config.resolve_alias("basics"), "1-5");
+        assert_eq!(config.resolve_alias("13"), "13");
+    }
+
+    #[test]
+    fn test_later_file_overrides() {
+        let mut config = Config::parse("[aliases]\nown = \"6\"\nx = \"1\"").unwrap();
+        config.merge(Config::parse("[aliases]\nown = \"06_ownership\"").unwrap());
+        assert_eq!(config.resolve_alias("own"), "06_ownership");
+        assert_eq!(config.resolve_alias("x"), "1");
+    }
+
+    #[test]
+    fn test_alias_is_not_recursive() {
+        let config = Config::parse("[aliases]\na = \"b\"\nb = \"a\"").unwrap();
+        assert_eq!(config.resolve_alias("a"), "b");
+    }
+
+    #[test]
+    fn test_defaults_from_config() {
+        let mut config = Config::parse(
+            r#"
+            color = "never"
+            theme = "fancy"
+            verbosity = "quiet"
+            pager = "less -R"
+            confidence = true
+            transcript = true
+            "#,
+        )
+        .unwrap();
+        config.merge(Config::parse("language = \"en\"\npager = \"more\"").unwrap());
+        let opts = config.defaults();
+        assert_eq!(opts.color, ColorChoice::Never);
+        assert_eq!(opts.theme, Theme::Fancy);
+        assert_eq!(opts.verbosity, Verbosity::Quiet);
+        assert_eq!(opts.language, Language::En);
+        assert_eq!(opts.pager.as_deref(), Some("more"));
+        assert_eq!(opts.progress_file, None);
+        assert!(opts.confidence);
+        assert!(opts.transcript);
+    }
+
+    #[test]
+    fn test_expand_home() {
+        let home = PathBuf::from(env::var_os("HOME").unwrap());
+        assert_eq!(expand_home(Path::new("~/p.txt")), home.join("p.txt"));
+        assert_eq!(expand_home(Path::new("/tmp/p")), PathBuf::from("/tmp/p"));
+    }
+
+    fn env<'a>(vars: &'a [(&str, &str)]) -> impl Fn(&str) -> Option<String> + 'a {
+        move |name| {
+            vars.iter()
+                .find(|(key, _)| *key == name)
+                .map(|(_, value)| value.to_string())
+        }
+    }
+
+    #[test]
+    fn test_env_overrides_files() {
+        let mut config =
+            Config::parse("language = \"zh\"\ncolor = \"always\"\npager = \"less\"").unwrap();
+        config.merge(
+            Config::from_env(env(&[
+                ("RUST_LEARN_LANG", "en"),
+                ("RUST_LEARN_COLOR", "always"),
+                ("RUST_LEARN_NO_COLOR", "1"),
+                ("RUST_LEARN_PAGER", ""),
+                ("RUST_LEARN_PROGRESS_FILE", "/tmp/progress"),
+                ("RUST_LEARN_PROFILE", "alice"),
+            ]))
+            .unwrap(),
+        );
+        let opts = config.defaults();
+        assert_eq!(opts.language, Language::En);
+        assert_eq!(opts.color, ColorChoice::Never);
+        assert_eq!(opts.pager, None);
+        assert_eq!(opts.progress_file, Some(PathBuf::from("/tmp/progress")));
+        assert_eq!(opts.profile.as_deref(), Some("alice"));
+
+        // 未设置的变量不影响配置文件中的值
+        let mut config = Config::parse("verbosity = \"verbose\"").unwrap();
+        config.merge(Config::from_env(env(&[("RUST_LEARN_NO_COLOR", "")])).unwrap());
+        assert_eq!(config.verbosity, Some(Verbosity::Verbose));
+        assert_eq!(config.color, None);
+    }
+
+    #[test]
+    fn test_invalid_env() {
+        let err = Config::from_env(env(&[("RUST_LEARN_VERBOSITY", "loud")])).unwrap_err();
+        assert!(err.starts_with("RUST_LEARN_VERBOSITY:"));
+    }
+
+    #[test]
+    fn test_invalid_config() {
+        assert!(Config::parse("[aliases]\nown = 6").is_err());
+        assert!(Config::parse("colour = true").is_err());
+        assert!(Config::parse("verbosity = \"loud\"").is_err());
+    }
+}
@@ -0,0 +1,380 @@
+//! 维护命令 `coverage`：课程覆盖了 The Rust Programming Language 的哪些章节
+//!
+//! 每个 lesson 在 `curriculum.toml` 中用 `book` 列出对应的章节（`"4.2"` 是一节，`"2"` 是整章）。
+//! 本命令按书的目录逐章列出覆盖情况：整章或全部小节都有 lesson 对应为“已覆盖”，只有部分小节为“部分覆盖”
+//! （并列出还缺的小节），没有任何 lesson 为“未覆盖”，可以据此决定接下来写哪些 lesson。
+//! 目录按 2024 edition 的在线版编排。需要在源码树中运行。
+
+use crate::curriculum::{self, Entry};
+use crate::error::RunnerError;
+use crate::utils::fmt;
+use std::io::Write;
+
+/// 书的目录：章号、章名与各小节的标题（按顺序，小节号从 1 起）
+const BOOK: &[(u32, &str, &[&str])] = &[
+    (
+        1,
+        "Getting Started",
+        &["Installation", "Hello, World!", "Hello, Cargo!"],
+    ),
+    (2, "Programming a Guessing Game", &[]),
+    (
+        3,
+        "Common Programming Concepts",
+        &[
+            "Variables and Mutability",
+            "Data Types",
+            "Functions",
+            "Comments",
+            "Control Flow",
+        ],
+    ),
+    (
+        4,
+        "Understanding Ownership",
+        &[
+            "What Is Ownership?",
+            "References and Borrowing",
+            "The Slice Type",
+        ],
+    ),
+    (
+        5,
+        "Using Structs",
+        &[
+            "Defining and Instantiating Structs",
+            "An Example Program Using Structs",
+            "Method Syntax",
+        ],
+    ),
+    (
+        6,
+        "Enums and Pattern Matching",
+        &[
+            "Defining an Enum",
+            "The match Control Flow Construct",
+            "if let and let else",
+        ],
+    ),
+    (
+        7,
+        "Packages, Crates, and Modules",
+        &[
+            "Packages and Crates",
+            "Control Scope and Privacy with Modules",
+            "Paths for Referring to an Item",
+            "Bringing Paths Into Scope with use",
+            "Separating Modules into Different Files",
+        ],
+    ),
+    (
+        8,
+        "Common Collections",
+        &["Vectors", "Strings", "Hash Maps"],
+    ),
+    (
+        9,
+        "Error Handling",
+        &[
+            "Unrecoverable Errors with panic!",
+            "Recoverable Errors with Result",
+            "To panic! or Not to panic!",
+        ],
+    ),
+    (
+        10,
+        "Generic Types, Traits, and Lifetimes",
+        &["Generic Data Types", "Traits", "Lifetimes"],
+    ),
+    (
+        11,
+        "Writing Automated Tests",
+        &[
+            "How to Write Tests",
+            "Controlling How Tests Are Run",
+            "Test Organization",
+        ],
+    ),
+    (12, "An I/O Project", &[]),
+    (
+        13,
+        "Iterators and Closures",
+        &[
+            "Closures",
+            "Iterators",
+            "Improving Our I/O Project",
+            "Performance of Loops vs. Iterators",
+        ],
+    ),
+    (
+        14,
+        "More About Cargo and Crates.io",
+        &[
+            "Release Profiles",
+            "Publishing a Crate",
+            "Cargo Workspaces",
+            "cargo install",
+            "Custom Commands",
+        ],
+    ),
+    (
+        15,
+        "Smart Pointers",
+        &[
+            "Box<T>",
+            "Deref",
+            "Drop",
+            "Rc<T>",
+            "RefCell<T> and Interior Mutability",
+            "Reference Cycles",
+        ],
+    ),
+    (
+        16,
+        "Fearless Concurrency",
+        &[
+            "Threads",
+            "Message Passing",
+            "Shared-State Concurrency",
+            "Send and Sync",
+        ],
+    ),
+    (
+        17,
+        "Async and Await",
+        &[
+            "Futures and the Async Syntax",
+            "Applying Concurrency with Async",
+            "Working With Any Number of Futures",
+            "Streams",
+            "The Traits for Async",
+            "Futures, Tasks, and Threads",
+        ],
+    ),
+    (
+        18,
+        "Object-Oriented Programming Features",
+        &[
+            "Characteristics of OO Languages",
+            "Trait Objects",
+            "An OO Design Pattern",
+        ],
+    ),
+    (
+        19,
+        "Patterns and Matching",
+        &[
+            "All the Places Patterns Can Be Used",
+            "Refutability",
+            "Pattern Syntax",
+        ],
+    ),
+    (
+        20,
+        "Advanced Features",
+        &[
+            "Unsafe Rust",
+            "Advanced Traits",
+            "Advanced Types",
+            "Advanced Functions and Closures",
+            "Macros",
+        ],
+    ),
+    (21, "Final Project: A Multithreaded Web Server", &[]),
+];
+
+/// 一章的覆盖情况
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Status {
+    Covered,
+    Partial,
+    Missing,
+}
+
+/// 一章的统计：哪些 lesson 对应这一章，还缺哪些小节
+#[derive(Debug, PartialEq, Eq)]
+struct ChapterCoverage {
+    number: u32,
+    title: &'static str,
+    status: Status,
+    lessons: Vec<String>,
+    missing: Vec<String>,
+}
+
+pub fn run(out: &mut dyn Write) -> Result<(), RunnerError> {
+    let (_, entries) = curriculum::read()?;
+    check(&entries)?;
+    write!(out, "{}", render(&coverage(&entries)))?;
+    Ok(())
+}
+
+/// 大纲中的章节都要在书的目录里
+fn check(entries: &[Entry]) -> Result<(), RunnerError> {
+    for entry in entries {
+        for section in &entry.book {
+            if !known(section) {
+                return Err(RunnerError::Config(format!(
+                    "curriculum.toml: lesson '{}' refers to book section '{}', which is not in the book",
+                    entry.slug, section
+                )));
+            }
+        }
+    }
+    Ok(())
+}
+
+fn known(section: &str) -> bool {
+    let (chapter, part) = match section.split_once('.') {
+        Some((chapter, part)) => (chapter, Some(part)),
+        None => (section, None),
+    };
+    let Some((_, _, sections)) = BOOK.iter().find(|(n, _, _)| n.to_string() == chapter) else {
+        return false;
+    };
+    part.is_none_or(|part| {
+        part.parse()
+            .is_ok_and(|i: usize| (1..=sections.len()).contains(&i))
+    })
+}
+
+fn coverage(entries: &[Entry]) -> Vec<ChapterCoverage> {
+    BOOK.iter()
+        .map(|&(number, title, sections)| {
+            let chapter = number.to_string();
+            let prefix = format!("{}.", number);
+            let lessons: Vec<String> = entries
+                .iter()
+                .filter(|e| {
+                    e.book
+                        .iter()
+                        .any(|s| *s == chapter || s.starts_with(&prefix))
+                })
+                .map(Entry::id)
+                .collect();
+            let whole = entries.iter().any(|e| e.book.contains(&chapter));
+            let missing: Vec<String> = if whole {
+                Vec::new()
+            } else {
+                sections
+                    .iter()
+                    .enumerate()
+                    .map(|(i, title)| (format!("{}.{}", number, i + 1), title))
+                    .filter(|(section, _)| !entries.iter().any(|e| e.book.contains(section)))
+                    .map(|(section, title)| format!("{} {}", section, title))
+                    .collect()
+            };
+            let status = if lessons.is_empty() {
+                Status::Missing
+            } else if missing.is_empty() {
+                Status::Covered
+            } else {
+                Status::Partial
+            };
+            ChapterCoverage {
+                number,
+                title,
+                status,
+                lessons,
+                missing,
+            }
+        })
+        .collect()
+}
+
+fn render(chapters: &[ChapterCoverage]) -> String {
+    let mut out = format!("{}\n", fmt::section("The Rust Programming Language"));
+    let width = chapters
+        .iter()
+        .map(|c| fmt::width(c.title))
+        .max()
+        .unwrap_or(0);
+    for chapter in chapters {
+        let mark = match chapter.status {
+            Status::Covered => "✓",
+            Status::Partial => "◐",
+            Status::Missing => "✗",
+        };
+        let line = format!(
+            "  {} {:>2}  {}  {}",
+            fmt::text(mark),
+            chapter.number,
+            fmt::pad(chapter.title, width),
+            chapter.lessons.join(", ")
+        );
+        out.push_str(&format!("{}\n", line.trim_end()));
+        if chapter.status == Status::Partial {
+            out.push_str(&format!(
+                "{}\n",
+                fmt::dim(&format!("        missing: {}", chapter.missing.join(", ")))
+            ));
+        }
+    }
+    let count = |status| chapters.iter().filter(|c| c.status == status).count();
+    out.push_str(&format!(
+        "\n{} covered, {} partial, {} missing\n",
+        count(Status::Covered),
+        count(Status::Partial),
+        count(Status::Missing)
+    ));
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(number: usize, slug: &str, book: &[&str]) -> Entry {
+        Entry {
+            number,
+            slug: slug.to_string(),
+            aliases: Vec::new(),
+            chapter: String::from("Basics"),
+            book: book.iter().map(|s| s.to_string()).collect(),
+            feature: String::from("basics"),
+        }
+    }
+
+    #[test]
+    fn test_coverage() {
+        let entries = [
+            entry(1, "hello", &["1.1", "1.2", "1.3"]),
+            entry(2, "guess", &["2"]),
+            entry(3, "vars", &["3.1"]),
+            entry(4, "more_vars", &["3.2"]),
+            entry(5, "generics", &["10.1"]),
+        ];
+        let chapters = coverage(&entries);
+        assert_eq!(chapters.len(), BOOK.len());
+        assert_eq!(chapters[0].status, Status::Covered);
+        assert_eq!(chapters[1].status, Status::Covered);
+        assert_eq!(chapters[1].lessons, ["02_guess"]);
+        assert_eq!(chapters[2].status, Status::Partial);
+        assert_eq!(chapters[2].lessons, ["03_vars", "04_more_vars"]);
+        assert_eq!(
+            chapters[2].missing,
+            ["3.3 Functions", "3.4 Comments", "3.5 Control Flow"]
+        );
+        assert_eq!(chapters[3].status, Status::Missing);
+        // 第 1 章不包括 10.x
+        assert_eq!(chapters[0].lessons, ["01_hello"]);
+        assert_eq!(chapters[9].status, Status::Partial);
+        let text = render(&chapters);
+        assert!(text.contains("missing: 3.3 Functions"));
+        assert!(text.ends_with(&format!(
+            "\n2 covered, 2 partial, {} missing\n",
+            BOOK.len() - 4
+        )));
+    }
+
+    #[test]
+    fn test_check() {
+        assert!(known("4") && known("4.3") && known("21"));
+        assert!(!known("4.4") && !known("22") && !known("4.0"));
+        assert!(matches!(
+            check(&[entry(1, "x", &["3.9"])]),
+            Err(RunnerError::Config(_))
+        ));
+        let (_, entries) = curriculum::read().unwrap();
+        check(&entries).unwrap();
+    }
+}
@@ -0,0 +1,128 @@
+//! 源码树中的课程大纲 `curriculum.toml`
+//!
+//! 编译进程序的注册表由 build.rs 在构建时生成；`renumber`、`new` 等维护命令
+//! 则在运行时读取源码树中的大纲。这里只解析这些命令用得到的字段，
+//! 改写时直接编辑原文，保留注释与字段顺序。
+
+use crate::error::RunnerError;
+use serde::Deserialize;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+#[derive(Deserialize)]
+struct Curriculum {
+    lesson: Vec<Entry>,
+}
+
+/// 大纲中的一个 `[[lesson]]`
+#[derive(Debug, Clone, Deserialize)]
+pub struct Entry {
+    pub number: usize,
+    pub slug: String,
+    #[serde(default)]
+    pub aliases: Vec<String>,
+    pub chapter: String,
+    /// 对应的 The Rust Programming Language 章节，如 `"4.2"`，见 [`coverage`](crate::coverage)
+    #[serde(default)]
+    pub book: Vec<String>,
+    pub feature: String,
+}
+
+impl Entry {
+    pub fn id(&self) -> String {
+        format!("{:02}_{}", self.number, self.slug)
+    }
+
+    /// lesson 源码文件，位于所属 lesson crate 的 `src/` 下
+    pub fn file(&self) -> PathBuf {
+        lesson_src(self.lesson_crate()).join(format!("{}.rs", self.slug))
+    }
+
+    /// 练习文件，位于所属 lesson crate 的 `src/exercises/` 下
+    pub fn exercise_file(&self) -> PathBuf {
+        lesson_src(self.lesson_crate())
+            .join("exercises")
+            .join(format!("{}.rs", self.slug))
+    }
+
+    /// 练习的参考答案，在源码树根目录的 `solutions/<lesson crate>/` 下，默认不参与编译
+    pub fn solution_file(&self) -> PathBuf {
+        root()
+            .join("solutions")
+            .join(self.lesson_crate())
+            .join(format!("{}.rs", self.slug))
+    }
+
+    /// 所属的 lesson crate：basics 在 `lessons-basics`，其余 feature 都在 `lessons-advanced`（同 build.rs）
+    pub fn lesson_crate(&self) -> &'static str {
+        if self.feature == "basics" {
+            LESSON_CRATES[0]
+        } else {
+            LESSON_CRATES[1]
+        }
+    }
+
+    /// 是否被选择器指中：编号、id、slug 或别名
+    pub fn matches(&self, sel: &str) -> bool {
+        self.slug == sel
+            || sel.parse() == Ok(self.number)
+            || sel == self.id()
+            || self.aliases.iter().any(|a| a == sel)
+    }
+}
+
+/// 存放 lesson 的 crate（`crates/` 下的目录名）
+pub const LESSON_CRATES: &[&str] = &["lessons-basics", "lessons-advanced"];
+
+/// 源码树的根目录，维护命令都在这里读写文件
+pub fn root() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+}
+
+/// lesson crate 的源码目录
+pub fn lesson_src(name: &str) -> PathBuf {
+    root().join("crates").join(name).join("src")
+}
+
+pub fn path() -> PathBuf {
+    root().join("curriculum.toml")
+}
+
+/// 读取大纲原文与其中的 lesson（按编号排序）
+pub fn read() -> Result<(String, Vec<Entry>), RunnerError> {
+    let text = fs::read_to_string(path())?;
+    let lessons = parse(&text)?;
+    Ok((text, lessons))
+}
+
+pub fn parse(text: &str) -> Result<Vec<Entry>, RunnerError> {
+    let mut curriculum: Curriculum = toml::from_str(text)
+        .map_err(|e| RunnerError::Config(format!("invalid curriculum.toml: {}", e)))?;
+    curriculum.lesson.sort_by_key(|l| l.number);
+    Ok(curriculum.lesson)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_source_curriculum() {
+        let (_, lessons) = read().unwrap();
+        assert_eq!(
+            lessons.len(),
+            crate::lessons::all().len() + crate::lessons::UNAVAILABLE.len()
+        );
+        assert!(lessons.iter().zip(1..).all(|(l, n)| l.number == n));
+        let hello = &lessons[0];
+        assert!(
+            ["1", "01_hello_world", "hello_world", "hello"]
+                .iter()
+                .all(|sel| hello.matches(sel))
+        );
+        assert!(!hello.matches("hell"));
+        assert!(lessons.iter().all(|l| l.file().is_file()));
+        assert!(lessons.iter().all(|l| l.exercise_file().is_file()));
+        assert!(lessons.iter().all(|l| l.solution_file().is_file()));
+    }
+}
@@ -0,0 +1,208 @@
+//! 开发模式：lesson 热重载（`--features hot-reload`）
+//!
+//! `cargo run --features hot-reload -- dev 16` 把本库单独编译为动态库（cdylib），
+//! 用 `libloading` 加载后运行选中的 lesson；之后轮询 `src/`、`crates/`、`locales/` 与
+//! `curriculum.toml`，一有修改就重新编译、重新加载并再次运行，编写 lesson 时无需反复重启。
+//!
+//! 动态库通过 [`rust_learn_dev_run`] 这个 C ABI 入口接收命令行参数，
+//! 全局的输出开关、注册表等都在动态库内部重新初始化，与宿主进程互不影响。
+
+use crate::cli::{self, Options};
+use crate::error::RunnerError;
+use crate::progress::Progress;
+use crate::utils::output;
+use crate::{app, lessons};
+use libloading::{Library, Symbol};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::time::{Duration, SystemTime};
+use std::{env, fs, io, slice, str, thread};
+
+/// 动态库入口的函数签名：参数为以 `\n` 分隔的 UTF-8 命令行参数，返回退出码
+type Entry = unsafe extern "C" fn(*const u8, usize) -> i32;
+
+const ENTRY: &[u8] = b"rust_learn_dev_run";
+const POLL: Duration = Duration::from_millis(300);
+
+/// 反复编译、加载并运行选中的 lesson，直到进程被中断
+pub fn run(sel: &str, opts: &Options) -> Result<(), RunnerError> {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let mut args = opts.child_args();
+    args.push(sel.to_string());
+    let args = encode(&args);
+
+    for generation in 0.. {
+        let seen = latest_change(root)?;
+        match build(root) {
+            Ok(lib) => match load_and_run(&lib, generation, &args) {
+                Ok(0) => {}
+                Ok(code) => eprintln!("[dev] lesson exited with code {}", code),
+                Err(e) => eprintln!("[dev] cannot load {}: {}", lib.display(), e),
+            },
+            Err(e) => eprintln!("[dev] build failed: {}", e),
+        }
+        eprintln!("[dev] watching for changes (Ctrl+C to quit)...");
+        while latest_change(root)? <= seen {
+            thread::sleep(POLL);
+        }
+        eprintln!("[dev] change detected, rebuilding...");
+    }
+    Ok(())
+}
+
+/// 用 `cargo rustc` 把库编译为 cdylib，返回生成的动态库路径
+fn build(root: &Path) -> io::Result<PathBuf> {
+    let cargo = env::var("CARGO").unwrap_or_else(|_| String::from("cargo"));
+    let output = Command::new(cargo)
+        .current_dir(root)
+        .args(["rustc", "--lib", "--crate-type", "cdylib"])
+        .args(["--features", "hot-reload"])
+        .arg("--message-format=json-render-diagnostics")
+        .stderr(Stdio::inherit())
+        .output()?;
+    if !output.status.success() {
+        return Err(io::Error::other(format!(
+            "cargo exited with {}",
+            output.status
+        )));
+    }
+    str::from_utf8(&output.stdout)
+        .map_err(io::Error::other)?
+        .lines()
+        .find_map(cdylib_artifact)
+        .ok_or_else(|| io::Error::other("cargo did not report a cdylib artifact"))
+}
+
+/// 从 cargo 的 JSON 消息中取出 cdylib 文件路径
+fn cdylib_artifact(line: &str) -> Option<PathBuf> {
+    let message: serde_json::Value = serde_json::from_str(line).ok()?;
+    if message["reason"] != "compiler-artifact"
+        || !message["target"]["crate_types"]
+            .as_array()?
+            .iter()
+            .any(|t| t == "cdylib")
+    {
+        return None;
+    }
+    message["filenames"]
+        .as_array()?
+        .iter()
+        .filter_map(|f| f.as_str())
+        .find(|f| !f.ends_with(".rlib") && !f.ends_with(".d"))
+        .map(PathBuf::from)
+}
+
+/// 加载动态库的副本并运行一次
+///
+/// 已卸载的动态库未必真的从进程中移除，用同一路径再次加载可能拿到旧代码，
+/// 因此每一代都复制到新的文件名再加载。
+fn load_and_run(lib: &Path, generation: u64, args: &str) -> io::Result<i32> {
+    let file = lib.file_name().unwrap_or_default().to_string_lossy();
+    let copy = env::temp_dir().join(format!(
+        "rust-learn-dev-{}-{}-{}",
+        std::process::id(),
+        generation,
+        file
+    ));
+    fs::copy(lib, &copy)?;
+    // SAFETY: 动态库由本 crate 在上面编译得到，初始化代码只包含注册表等静态数据；
+    // 入口的签名与下方 `rust_learn_dev_run` 的定义一致，参数在调用期间保持有效。
+    let code = unsafe {
+        let library = Library::new(&copy).map_err(io::Error::other)?;
+        let entry: Symbol<Entry> = library.get(ENTRY).map_err(io::Error::other)?;
+        entry(args.as_ptr(), args.len())
+    };
+    fs::remove_file(&copy)?;
+    Ok(code)
+}
+
+/// 被监视文件的最近修改时间
+fn latest_change(root: &Path) -> io::Result<SystemTime> {
+    fn visit(path: &Path, latest: &mut SystemTime) -> io::Result<()> {
+        let meta = fs::metadata(path)?;
+        if meta.is_dir() {
+            for entry in fs::read_dir(path)? {
+                visit(&entry?.path(), latest)?;
+            }
+        } else {
+            *latest = (*latest).max(meta.modified()?);
+        }
+        Ok(())
+    }
+
+    let mut latest = SystemTime::UNIX_EPOCH;
+    for path in ["src", "crates", "locales", "curriculum.toml", "Cargo.toml"] {
+        let path = root.join(path);
+        if path.exists() {
+            visit(&path, &mut latest)?;
+        }
+    }
+    Ok(latest)
+}
+
+fn encode(args: &[String]) -> String {
+    args.join("\n")
+}
+
+fn decode(args: &str) -> Vec<String> {
+    args.split('\n').map(String::from).collect()
+}
+
+/// 动态库一侧的入口：解析参数并运行选中的 lesson，返回进程退出码
+///
+/// 开发模式下不记录学习进度。
+///
+/// # Safety
+///
+/// `args` 必须指向 `len` 个有效字节。
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn rust_learn_dev_run(args: *const u8, len: usize) -> i32 {
+    // SAFETY: 由调用方保证
+    let bytes = unsafe { slice::from_raw_parts(args, len) };
+    let result = str::from_utf8(bytes)
+        .map_err(|e| RunnerError::Usage(e.to_string()))
+        .and_then(|args| cli::parse(&decode(args)).map_err(RunnerError::Usage))
+        .and_then(|(positional, opts)| {
+            app::apply_output_flags(&opts);
+            let sel = positional.first().map(String::as_str).unwrap_or_default();
+            lessons::run_selected(sel, &mut Progress::default(), &opts, &mut output::Stdout)
+        });
+    match result {
+        Ok(()) => 0,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            e.exit_code()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_args_round_trip() {
+        let args = vec![String::from("--no-related"), String::from("16:closures")];
+        assert_eq!(decode(&encode(&args)), args);
+    }
+
+    #[test]
+    fn test_cdylib_artifact() {
+        let line = r#"{"reason":"compiler-artifact","target":{"crate_types":["cdylib"]},"filenames":["/t/librust_learn.so"]}"#;
+        assert_eq!(
+            cdylib_artifact(line),
+            Some(PathBuf::from("/t/librust_learn.so"))
+        );
+        let rlib = r#"{"reason":"compiler-artifact","target":{"crate_types":["lib"]},"filenames":["/t/librust_learn.rlib"]}"#;
+        assert_eq!(cdylib_artifact(rlib), None);
+        assert_eq!(cdylib_artifact("Compiling..."), None);
+    }
+
+    #[test]
+    fn test_latest_change() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+        let before = latest_change(root).unwrap();
+        assert!(before > SystemTime::UNIX_EPOCH);
+        assert!(before <= SystemTime::now());
+    }
+}
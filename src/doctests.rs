@@ -0,0 +1,363 @@
+//! 维护命令 `update-doctests`：把演示代码中标出的片段写成 lesson 模块注释中的 rustdoc 示例
+//!
+//! 演示函数中用 `// ANCHOR: 名字` 与 `// ANCHOR_END: 名字` 标出几段不依赖其他条目的代码。本命令取出这些片段，
+//! 把写往 `ctx` 的 `writeln!` / `write!` 换成 `println!` / `print!`，写进模块注释最后的 `## 示例` 一节。
+//! 这样 `cargo test --doc` 会编译并运行它们，`cargo doc` 与导出的讲义中每个 lesson 都带有可以原样拷贝运行的示例。
+//! 示例只来自演示代码，不会写出填空练习（`KOANS`）的答案。
+//!
+//! `## 示例` 一节由本命令维护，必须是模块注释的最后一节，其中的内容每次都会整体替换。
+//! 修改标出的片段之后运行一次即可，单元测试会检查源码树中的 lesson 文件是最新的。需要在源码树中运行。
+
+use crate::curriculum;
+use crate::error::RunnerError;
+use crate::lessons;
+use std::fs;
+use std::io::Write;
+
+const HEADING: &str = "//! ## 示例";
+const ANCHOR: &str = "// ANCHOR: ";
+const ANCHOR_END: &str = "// ANCHOR_END: ";
+
+pub fn run(out: &mut dyn Write) -> Result<(), RunnerError> {
+    let (_, entries) = curriculum::read()?;
+    let mut updated = 0;
+    for entry in &entries {
+        // 确认 lesson 编译进来了，否则无从检查生成的示例
+        lessons::select(&entry.id())?;
+        let path = entry.file();
+        let source = fs::read_to_string(&path)?;
+        let examples =
+            examples(&source).map_err(|e| RunnerError::Config(format!("{}: {}", entry.id(), e)))?;
+        let new = update(&source, &section(&examples));
+        if new != source {
+            fs::write(&path, new)?;
+            writeln!(out, "Updated {}", path.display())?;
+            updated += 1;
+        }
+    }
+    if updated == 0 {
+        writeln!(
+            out,
+            "The examples in all {} lessons are up to date",
+            entries.len()
+        )?;
+    } else {
+        writeln!(out, "Updated the examples in {} lessons", updated)?;
+    }
+    Ok(())
+}
+
+/// lesson 源码中标出的全部片段：名字与改写后的代码，按出现顺序
+fn examples(source: &str) -> Result<Vec<(String, String)>, String> {
+    anchors(source)?
+        .into_iter()
+        .map(|(name, code)| {
+            let code = example(&code).map_err(|e| format!("snippet '{}' {}", name, e))?;
+            Ok((name, code))
+        })
+        .collect()
+}
+
+/// `// ANCHOR: 名字` 与 `// ANCHOR_END: 名字` 之间的代码，去掉公共缩进
+///
+/// 同一个名字可以标出多段（例如演示代码与它调用的辅助函数），按出现顺序拼成一个片段。
+fn anchors(source: &str) -> Result<Vec<(String, String)>, String> {
+    let mut snippets: Vec<(String, String)> = Vec::new();
+    let mut open: Option<(String, Vec<&str>)> = None;
+    for line in source.lines() {
+        let trimmed = line.trim();
+        if let Some(name) = trimmed.strip_prefix(ANCHOR) {
+            if let Some((outer, _)) = &open {
+                return Err(format!("snippet '{}' starts inside '{}'", name, outer));
+            }
+            open = Some((name.to_string(), Vec::new()));
+        } else if let Some(name) = trimmed.strip_prefix(ANCHOR_END) {
+            match open.take() {
+                Some((start, lines)) if start == name => {
+                    let code = dedent(&lines);
+                    match snippets.iter_mut().find(|(n, _)| *n == start) {
+                        Some((_, existing)) => {
+                            existing.push('\n');
+                            existing.push_str(&code);
+                        }
+                        None => snippets.push((start, code)),
+                    }
+                }
+                _ => return Err(format!("snippet '{}' ends without a matching start", name)),
+            }
+        } else if let Some((_, lines)) = &mut open {
+            lines.push(line);
+        }
+    }
+    match open {
+        Some((name, _)) => Err(format!("snippet '{}' is never closed", name)),
+        None => Ok(snippets),
+    }
+}
+
+fn dedent(lines: &[&str]) -> String {
+    let indent = lines
+        .iter()
+        .filter(|l| !l.trim().is_empty())
+        .map(|l| l.len() - l.trim_start().len())
+        .min()
+        .unwrap_or(0);
+    lines
+        .iter()
+        .map(|l| format!("{}\n", l.get(indent..).unwrap_or_default()))
+        .collect()
+}
+
+/// 把写往 `ctx` 的输出换成打印到标准输出；片段还用到 `ctx` 时无法独立运行，返回错误
+fn example(code: &str) -> Result<String, String> {
+    let mut out = String::new();
+    let mut rest = code;
+    while let Some(start) = rest.find("write") {
+        let (before, after) = rest.split_at(start);
+        out.push_str(before);
+        // rustfmt 会把较长的调用拆成多行，`ctx` 可能在下一行
+        let Some((mac, args)) = [("writeln!(", "println!("), ("write!(", "print!(")]
+            .iter()
+            .find_map(|(from, to)| {
+                let args = after.strip_prefix(from)?.trim_start().strip_prefix("ctx")?;
+                args.starts_with([',', ')']).then_some((*to, args))
+            })
+        else {
+            out.push_str("write");
+            rest = &after["write".len()..];
+            continue;
+        };
+        let args = args
+            .strip_prefix(',')
+            .unwrap_or(args)
+            .trim_start_matches(' ');
+        let close = closing_paren(args).ok_or("has an unbalanced `writeln!`")?;
+        out.push_str(mac);
+        out.push_str(&args[..=close]);
+        rest = &args[close + 1..];
+        rest = rest.strip_prefix('?').unwrap_or(rest);
+    }
+    out.push_str(rest);
+    if out.contains("ctx") {
+        return Err(String::from("uses `ctx` and cannot run on its own"));
+    }
+    Ok(out)
+}
+
+/// 宏参数（不含左括号）中与之配对的右括号的位置，跳过字符串与字符字面量中的括号
+fn closing_paren(args: &str) -> Option<usize> {
+    let mut depth = 0;
+    let mut chars = args.char_indices();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '"' => {
+                while let Some((_, c)) = chars.next() {
+                    match c {
+                        '\\' => {
+                            chars.next();
+                        }
+                        '"' => break,
+                        _ => {}
+                    }
+                }
+            }
+            '\'' => {
+                // 字符字面量整个跳过；`'a` 之类的生命周期后面没有配对的引号，原样保留
+                let mut ahead = chars.clone();
+                match ahead.next() {
+                    Some((_, '\\')) => {
+                        ahead.next();
+                        ahead.find(|&(_, c)| c == '\'');
+                        chars = ahead;
+                    }
+                    Some(_) if matches!(ahead.next(), Some((_, '\''))) => chars = ahead,
+                    _ => {}
+                }
+            }
+            '(' | '[' | '{' => depth += 1,
+            ')' | ']' | '}' if depth > 0 => depth -= 1,
+            ')' => return Some(i),
+            _ => {}
+        }
+    }
+    None
+}
+
+/// `## 示例` 一节，每行带着 `//!`；没有标出片段的 lesson 不加这一节
+fn section(examples: &[(String, String)]) -> String {
+    if examples.is_empty() {
+        return String::new();
+    }
+    let mut docs = vec![
+        HEADING.to_string(),
+        String::from("//!"),
+        String::from(
+            "//! 由 `cargo run -- update-doctests` 从演示代码中标出的片段生成，`cargo test --doc` 会运行它们。",
+        ),
+    ];
+    for (name, code) in examples {
+        docs.push(String::from("//!"));
+        docs.push(format!("//! `{}`：", name));
+        docs.push(String::from("//!"));
+        docs.push(String::from("//! ```rust"));
+        docs.extend(code.lines().map(|l| {
+            if l.is_empty() {
+                String::from("//!")
+            } else {
+                format!("//! {}", l)
+            }
+        }));
+        docs.push(String::from("//! ```"));
+    }
+    docs.iter().map(|l| format!("{}\n", l)).collect()
+}
+
+/// 把模块注释中的 `## 示例` 一节换成 `section`，没有这一节时加在模块注释末尾，`section` 为空时去掉这一节
+fn update(source: &str, section: &str) -> String {
+    let lines: Vec<&str> = source.lines().collect();
+    let docs = lines.iter().take_while(|l| l.starts_with("//!")).count();
+    let mut keep = lines[..docs]
+        .iter()
+        .position(|l| *l == HEADING)
+        .unwrap_or(docs);
+    while keep > 0 && lines[keep - 1] == "//!" {
+        keep -= 1;
+    }
+    let mut out: String = lines[..keep].iter().map(|l| format!("{}\n", l)).collect();
+    if !section.is_empty() {
+        out.push_str("//!\n");
+        out.push_str(section);
+    }
+    for line in &lines[docs..] {
+        out.push_str(line);
+        out.push('\n');
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SOURCE: &str = "fn demo(ctx: &mut LessonContext) -> lesson::Result {
+    // ANCHOR: sum
+    let v = vec![1, 2, 3];
+    writeln!(ctx, \"sum = {}\", v.iter().sum::<i32>())?;
+    write!(
+        ctx,
+        \"({})\",
+        v.len()
+    )?;
+    // ANCHOR_END: sum
+    writeln!(ctx)?;
+    Ok(())
+}
+";
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(
+            examples(SOURCE).unwrap(),
+            [(
+                String::from("sum"),
+                String::from(
+                    "let v = vec![1, 2, 3];\nprintln!(\"sum = {}\", v.iter().sum::<i32>());\nprint!(\n    \"({})\",\n    v.len()\n);\n"
+                )
+            )]
+        );
+        let section = section(&examples(SOURCE).unwrap());
+        assert!(section.starts_with("//! ## 示例\n//!\n"));
+        assert!(section.contains("//! `sum`：\n//!\n//! ```rust\n//! let v = vec![1, 2, 3];\n"));
+    }
+
+    #[test]
+    fn test_closing_paren() {
+        assert_eq!(closing_paren("')', '(') + 1"), Some(8));
+        assert_eq!(closing_paren("'\\'', '\\u{29}')"), Some(14));
+        assert_eq!(closing_paren("\"(\", &'a x) y"), Some(10));
+        let source = "// ANCHOR: c\nwriteln!(ctx, \"{}{}\", '(', ')')?;\n// ANCHOR_END: c\n";
+        assert_eq!(
+            examples(source).unwrap(),
+            [(
+                String::from("c"),
+                String::from("println!(\"{}{}\", '(', ')');\n")
+            )]
+        );
+    }
+
+    /// 同名的几段拼成一个片段，各自去掉缩进
+    #[test]
+    fn test_repeated_anchor() {
+        let source = "    // ANCHOR: f\n    f();\n    // ANCHOR_END: f\n\
+                      // ANCHOR: f\nfn f() {}\n// ANCHOR_END: f\n";
+        assert_eq!(
+            anchors(source).unwrap(),
+            [(String::from("f"), String::from("f();\n\nfn f() {}\n"))]
+        );
+    }
+
+    #[test]
+    fn test_bad_snippets() {
+        let uses_ctx = "// ANCHOR: a\nctx.section(\"x\")?;\n// ANCHOR_END: a\n";
+        assert_eq!(
+            examples(uses_ctx).unwrap_err(),
+            "snippet 'a' uses `ctx` and cannot run on its own"
+        );
+        assert!(anchors("// ANCHOR: a\nlet x = 1;\n").is_err());
+        assert!(anchors("// ANCHOR: a\n// ANCHOR: b\n").is_err());
+        assert!(anchors("// ANCHOR: a\n// ANCHOR_END: b\n").is_err());
+    }
+
+    #[test]
+    fn test_update() {
+        let source = "//! # Demo\n//!\n//! ## 运行\n//! `cargo run -- 03_demo`\n\nuse std::io;\n";
+        let updated = update(source, "//! ## 示例\n//! a\n");
+        assert_eq!(
+            updated,
+            "//! # Demo\n//!\n//! ## 运行\n//! `cargo run -- 03_demo`\n//!\n//! ## 示例\n//! a\n\nuse std::io;\n"
+        );
+        assert_eq!(
+            update(&updated, "//! ## 示例\n//! b\n"),
+            updated.replace("//! a\n", "//! b\n")
+        );
+        // 没有标出片段时去掉这一节
+        assert_eq!(update(&updated, ""), source);
+    }
+
+    /// 示例是最新的，并且没有原样写出填空练习中空白所在的那一行
+    #[test]
+    #[cfg_attr(
+        not(all(feature = "basics", feature = "advanced")),
+        ignore = "needs every lesson"
+    )]
+    fn test_lessons_are_up_to_date() {
+        let (_, entries) = curriculum::read().unwrap();
+        for entry in &entries {
+            let source = fs::read_to_string(entry.file()).unwrap();
+            let examples = examples(&source).unwrap();
+            assert_eq!(
+                update(&source, &section(&examples)),
+                source,
+                "the examples in {} are stale, run `cargo run -- update-doctests`",
+                entry.id()
+            );
+            let lines: Vec<&str> = examples
+                .iter()
+                .flat_map(|(_, code)| code.lines().map(str::trim))
+                .collect();
+            for koan in lessons::select(&entry.id()).unwrap()[0].lesson.koans() {
+                let solved = koan.solved();
+                let blanks = koan.code.lines().zip(solved.lines());
+                for (_, answer) in blanks.filter(|(line, _)| line.contains("__")) {
+                    assert!(
+                        !lines.contains(&answer.trim()),
+                        "{}: the examples give away koan '{}': {}",
+                        entry.id(),
+                        koan.name,
+                        answer.trim()
+                    );
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,189 @@
+//! 命令行运行器的错误类型
+//!
+//! 选择、运行 lesson 以及加载配置时的失败都归入 [`RunnerError`]，
+//! 命令行根据它统一得到错误消息与进程退出码。
+
+use crate::lesson::LessonError;
+use std::error::Error;
+use std::fmt;
+use std::io;
+
+/// 运行器错误
+#[derive(Debug)]
+pub enum RunnerError {
+    /// 选择器没有匹配到任何 lesson
+    NotFound(String),
+    /// 选中的 lesson 所属的 feature 没有启用
+    Unavailable { lesson: String, feature: String },
+    /// 选择器同时匹配到多个 lesson
+    Ambiguous {
+        selector: String,
+        candidates: Vec<String>,
+    },
+    /// lesson 返回了错误
+    LessonFailed { lesson: String, error: LessonError },
+    /// lesson 运行时 panic
+    LessonPanicked { lesson: String, message: String },
+    /// 批量运行时有 lesson 失败
+    Failed(Vec<String>),
+    /// 命令行用法错误
+    Usage(String),
+    /// 读写文件或启动进程失败
+    Io(io::Error),
+    /// 配置文件、环境变量或课程注册表无效
+    Config(String),
+}
+
+impl RunnerError {
+    /// 各退出码的含义，与 [`exit_code`](Self::exit_code) 一致
+    pub const EXIT_CODES: &[(i32, &str)] = &[
+        (0, "成功"),
+        (1, "lesson 运行失败"),
+        (2, "找不到或有歧义的选择器、lesson 未编译进来、用法错误"),
+        (74, "读写文件或启动进程失败"),
+        (78, "配置文件、环境变量或课程注册表无效"),
+        (101, "lesson panic"),
+    ];
+
+    /// 进程退出码：1 表示 lesson 失败，2 表示用法或选择器错误，
+    /// 101 与 Rust 的 panic 退出码一致，其余沿用 sysexits 约定
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            RunnerError::LessonFailed { .. } | RunnerError::Failed(_) => 1,
+            RunnerError::NotFound(_)
+            | RunnerError::Unavailable { .. }
+            | RunnerError::Ambiguous { .. }
+            | RunnerError::Usage(_) => 2,
+            RunnerError::LessonPanicked { .. } => 101,
+            RunnerError::Io(_) => 74,
+            RunnerError::Config(_) => 78,
+        }
+    }
+
+    /// 是否应当在错误后附上用法说明
+    pub fn shows_usage(&self) -> bool {
+        matches!(
+            self,
+            RunnerError::NotFound(_) | RunnerError::Ambiguous { .. } | RunnerError::Usage(_)
+        )
+    }
+}
+
+impl fmt::Display for RunnerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RunnerError::NotFound(sel) => write!(f, "Lesson '{}' not found", sel),
+            RunnerError::Unavailable { lesson, feature } => write!(
+                f,
+                "Lesson '{}' is not compiled in, rebuild with `--features {}`",
+                lesson, feature
+            ),
+            RunnerError::Ambiguous {
+                selector,
+                candidates,
+            } => write!(
+                f,
+                "Lesson '{}' is ambiguous: {}",
+                selector,
+                candidates.join(", ")
+            ),
+            RunnerError::LessonFailed { lesson, error } => {
+                write!(f, "Lesson '{}' failed: {}", lesson, error.report())
+            }
+            RunnerError::LessonPanicked { lesson, message } => {
+                write!(f, "Lesson '{}' panicked: {}", lesson, message)
+            }
+            RunnerError::Failed(lessons) => write!(
+                f,
+                "{} lesson(s) failed: {}",
+                lessons.len(),
+                lessons.join(", ")
+            ),
+            RunnerError::Usage(msg) | RunnerError::Config(msg) => f.write_str(msg),
+            RunnerError::Io(e) => write!(f, "I/O error: {}", e),
+        }
+    }
+}
+
+impl Error for RunnerError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            RunnerError::LessonFailed { error, .. } => Some(error),
+            RunnerError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for RunnerError {
+    fn from(err: io::Error) -> Self {
+        RunnerError::Io(err)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exit_codes() {
+        assert_eq!(RunnerError::NotFound(String::from("x")).exit_code(), 2);
+        assert_eq!(
+            RunnerError::Failed(vec![String::from("01_a")]).exit_code(),
+            1
+        );
+        let panicked = RunnerError::LessonPanicked {
+            lesson: String::from("01_a"),
+            message: String::from("boom"),
+        };
+        assert_eq!(panicked.exit_code(), 101);
+        assert!(!panicked.shows_usage());
+        assert!(RunnerError::Usage(String::from("bad")).shows_usage());
+
+        let errors = [
+            RunnerError::Failed(Vec::new()),
+            RunnerError::Usage(String::new()),
+            panicked,
+            RunnerError::Io(io::Error::other("x")),
+            RunnerError::Config(String::new()),
+        ];
+        for err in errors {
+            assert!(
+                RunnerError::EXIT_CODES
+                    .iter()
+                    .any(|(code, _)| *code == err.exit_code()),
+                "{}",
+                err
+            );
+        }
+    }
+
+    #[test]
+    fn test_messages() {
+        let err = RunnerError::Ambiguous {
+            selector: String::from("s"),
+            candidates: vec![String::from("08_slices"), String::from("09_structs")],
+        };
+        assert_eq!(
+            err.to_string(),
+            "Lesson 's' is ambiguous: 08_slices, 09_structs"
+        );
+        let err = RunnerError::LessonFailed {
+            lesson: String::from("01_a"),
+            error: LessonError::Failed(String::from("wrong")),
+        };
+        assert_eq!(err.to_string(), "Lesson '01_a' failed: [failed] wrong");
+        assert!(err.source().is_some());
+
+        let err = RunnerError::Unavailable {
+            lesson: String::from("20_http"),
+            feature: String::from("advanced"),
+        };
+        assert_eq!(
+            err.to_string(),
+            "Lesson '20_http' is not compiled in, rebuild with `--features advanced`"
+        );
+        assert_eq!(err.exit_code(), 2);
+        assert!(!err.shows_usage());
+    }
+}
@@ -0,0 +1,176 @@
+//! 机器可读的事件流（`--format jsonl`）
+//!
+//! 每行一个 JSON 对象，`event` 字段区分类型：
+//!
+//! ```text
+//! {"event":"lesson_started","schema_version":1,"lesson":"06_ownership","number":6,"title":"所有权基础"}
+//! {"event":"section","lesson":"06_ownership","title":"所有权基础"}
+//! {"event":"print","lesson":"06_ownership","text":"Stack values: x=5, y=5"}
+//! {"event":"lesson_finished","lesson":"06_ownership","status":"ok","duration_ms":3}
+//! ```
+//!
+//! lesson 输出按行转换为 `print` 事件，小节标题转换为 `section` 事件，
+//! 编辑器或网页前端可以据此构建更丰富的界面。事件的类型与版本约定见 [`schema`](crate::schema)。
+
+use crate::cli::Options;
+use crate::error::RunnerError;
+use crate::i18n;
+use crate::lesson::{EventSink, Lesson, LessonContext};
+use crate::lessons;
+use crate::schema::{Event, SCHEMA_VERSION, Status};
+use crate::utils::deterministic;
+use std::borrow::Cow;
+use std::io::{self, Write};
+use std::time::Instant;
+
+/// 把 lesson 输出转换为事件：完整的一行对应一个 `print` 事件
+pub struct EventWriter<W: Write> {
+    lesson: String,
+    line: Vec<u8>,
+    out: W,
+}
+
+impl<W: Write> EventWriter<W> {
+    pub fn new(lesson: &str, out: W) -> Self {
+        EventWriter {
+            lesson: lesson.to_string(),
+            line: Vec::new(),
+            out,
+        }
+    }
+
+    pub fn emit(&mut self, event: &Event) -> io::Result<()> {
+        writeln!(self.out, "{}", event.to_json())
+    }
+
+    /// 把缓冲中不完整的最后一行作为 `print` 事件输出
+    pub fn finish_line(&mut self) -> io::Result<()> {
+        if self.line.is_empty() {
+            return Ok(());
+        }
+        let line = std::mem::take(&mut self.line);
+        let text = String::from_utf8_lossy(&line).into_owned();
+        let lesson = self.lesson.clone();
+        self.emit(&Event::Print {
+            lesson: Cow::Borrowed(&lesson),
+            text: Cow::Owned(text),
+        })
+    }
+}
+
+impl<W: Write> Write for EventWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        for &byte in buf {
+            if byte == b'\n' {
+                let line = std::mem::take(&mut self.line);
+                let text = String::from_utf8_lossy(&line).into_owned();
+                let lesson = self.lesson.clone();
+                self.emit(&Event::Print {
+                    lesson: Cow::Borrowed(&lesson),
+                    text: Cow::Owned(text),
+                })?;
+            } else {
+                self.line.push(byte);
+            }
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.out.flush()
+    }
+}
+
+impl<W: Write> EventSink for EventWriter<W> {
+    /// 先输出尚未换行的内容，再输出小节事件
+    fn section(&mut self, title: &str) -> io::Result<()> {
+        self.finish_line()?;
+        let lesson = self.lesson.clone();
+        self.emit(&Event::Section {
+            lesson: Cow::Borrowed(&lesson),
+            title: Cow::Borrowed(title),
+        })
+    }
+}
+
+/// 以事件流形式运行一个 lesson：开始事件、输出事件、结束事件
+pub fn run_lesson(
+    lesson: &dyn Lesson,
+    opts: &Options,
+    out: &mut dyn Write,
+) -> Result<(), RunnerError> {
+    let meta = lesson.meta();
+    let id = meta.id();
+    let mut writer = EventWriter::new(&id, out);
+    writer.emit(&Event::LessonStarted {
+        schema_version: SCHEMA_VERSION,
+        lesson: Cow::Borrowed(&id),
+        number: meta.number,
+        title: Cow::Borrowed(i18n::title(&meta, opts.language)),
+    })?;
+
+    let start = Instant::now();
+    let result = {
+        let mut ctx = LessonContext::with_events(&mut writer)
+            .with_verbosity(opts.verbosity)
+            .with_language(opts.language);
+        lessons::run_guarded(lesson, &mut ctx)
+    };
+    writer.finish_line()?;
+    let duration_ms = if deterministic::is_enabled() {
+        0
+    } else {
+        start.elapsed().as_millis() as u64
+    };
+    let status = match &result {
+        Ok(()) => Status::Ok,
+        Err(RunnerError::LessonPanicked { .. }) => Status::Panicked,
+        Err(_) => Status::Failed,
+    };
+    writer.emit(&Event::LessonFinished {
+        lesson: Cow::Borrowed(&id),
+        status,
+        duration_ms,
+        error: result.as_ref().err().map(ToString::to_string),
+    })?;
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_writer_emits_lines_and_sections() {
+        let mut out = Vec::new();
+        let mut writer = EventWriter::new("01_a", &mut out);
+        write!(writer, "one\ntw").unwrap();
+        writer.section("S").unwrap();
+        writeln!(writer, "three").unwrap();
+        let text = String::from_utf8(out).unwrap();
+        let lines: Vec<_> = text.lines().collect();
+        assert_eq!(
+            lines,
+            [
+                r#"{"event":"print","lesson":"01_a","text":"one"}"#,
+                r#"{"event":"print","lesson":"01_a","text":"tw"}"#,
+                r#"{"event":"section","lesson":"01_a","title":"S"}"#,
+                r#"{"event":"print","lesson":"01_a","text":"three"}"#,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_finished_event() {
+        let event = Event::LessonFinished {
+            lesson: Cow::Borrowed("01_a"),
+            status: Status::Failed,
+            duration_ms: 5,
+            error: Some(String::from("boom")),
+        };
+        assert_eq!(
+            event.to_json(),
+            r#"{"event":"lesson_finished","lesson":"01_a","status":"failed","duration_ms":5,"error":"boom"}"#
+        );
+    }
+}
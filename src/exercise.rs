@@ -0,0 +1,264 @@
+//! 练习：`exercise <lesson>`
+//!
+//! 每个 lesson 附有一个练习文件（所属 lesson crate 的 `src/exercises/<slug>.rs`），其中的函数体是
+//! `todo!()`，由学习者补全。检查写在 lesson 模块的 `EXERCISE` 中，练习文件里看不到；
+//! 运行时逐项检查，像 rustlings 一样报告每项是否通过，并把结果记入学习进度（见 [`progress`](crate::progress)）。
+//! 修改练习文件后重新运行命令即可，`cargo run` 会先重新编译。
+//!
+//! 卡住时用 `hint <lesson>` 依次查看由浅入深的提示，`hint <lesson> <n>` 直接查看第 `n` 条。
+//! 完成之前看过的提示条数记入进度，完成后的得分随之降低（见 [`ExerciseState::score`]）。
+//!
+//! `solution <lesson>` 显示带语法高亮的参考答案。参考答案放在仓库根目录的 `solutions/` 中，
+//! 默认不参与编译；还没尝试过练习时先确认，完成之前看过答案的练习得 0 分。
+
+use crate::curriculum;
+use crate::error::RunnerError;
+use crate::highlight;
+use crate::lesson::Lesson;
+use crate::lesson::{Check, Language};
+use crate::lessons;
+use crate::progress::{ExerciseState, Progress};
+use crate::utils::{fmt, io};
+use std::fs;
+use std::io::Write;
+use std::panic;
+
+/// 检查选中的每个 lesson 的练习
+pub fn run(
+    sel: &str,
+    progress: &mut Progress,
+    lang: Language,
+    out: &mut dyn Write,
+) -> Result<(), RunnerError> {
+    // 只用来显示练习文件的位置，不在源码树中运行时没有
+    let entries = curriculum::read()
+        .map(|(_, entries)| entries)
+        .unwrap_or_default();
+    for lesson in lessons::resolve(sel)? {
+        let meta = lesson.meta();
+        let checks = lesson.exercise();
+        if checks.is_empty() {
+            writeln!(out, "{} has no exercise yet", meta.id())?;
+            continue;
+        }
+        writeln!(out, "{}", meta.header(lang))?;
+        if let Some(entry) = entries.iter().find(|e| e.id() == meta.id()) {
+            let file = entry.exercise_file();
+            let file = file.strip_prefix(curriculum::root()).unwrap_or(&file);
+            writeln!(
+                out,
+                "{}",
+                fmt::dim(&format!("Exercise: {}", file.display()))
+            )?;
+        }
+        let mut passed = Vec::new();
+        for (check, result) in checks.iter().zip(check_all(&checks)) {
+            match result {
+                Ok(()) => {
+                    writeln!(out, "  {} {}", fmt::text("✓"), check.name)?;
+                    passed.push(check.name.to_string());
+                }
+                Err(message) => write!(out, "{}", failure(check.name, &message))?,
+            }
+        }
+        let count = passed.len();
+        progress.record_exercise(&meta.id(), passed, checks.len())?;
+        if count == checks.len() {
+            let state = progress.exercise(&meta.id()).cloned().unwrap_or_default();
+            writeln!(out, "\nAll {} checks passed, {}", count, score(&state))?;
+        } else {
+            writeln!(
+                out,
+                "\n{} / {} checks passed, edit the exercise and run `exercise {slug}` again \
+                 (stuck? try `hint {slug}`)",
+                count,
+                checks.len(),
+                slug = meta.slug
+            )?;
+        }
+    }
+    Ok(())
+}
+
+/// `hint <lesson> [n]`，`args` 不含 `hint` 本身；不给 `n` 时显示下一条还没看过的提示
+pub fn hint(
+    args: &[String],
+    progress: &mut Progress,
+    out: &mut dyn Write,
+) -> Result<(), RunnerError> {
+    let (Some(sel), None) = (args.first(), args.get(2)) else {
+        return Err(RunnerError::Usage(String::from("Usage: hint <lesson> [n]")));
+    };
+    let lesson = single(sel, "hints")?;
+    let id = lesson.meta().id();
+    let hints = lesson.hints();
+    if hints.is_empty() {
+        writeln!(out, "{} has no hints", id)?;
+        return Ok(());
+    }
+    let used = progress.exercise(&id).map_or(0, |s| s.hints);
+    let n = match args.get(1) {
+        Some(n) => n
+            .parse()
+            .ok()
+            .filter(|n| (1..=hints.len()).contains(n))
+            .ok_or_else(|| {
+                RunnerError::Usage(format!("Hint number must be between 1 and {}", hints.len()))
+            })?,
+        None if used >= hints.len() => {
+            writeln!(out, "All {} hints shown, the last one was:", hints.len())?;
+            hints.len()
+        }
+        None => used + 1,
+    };
+    progress.use_hint(&id, n)?;
+    writeln!(out, "Hint {}/{}: {}", n, hints.len(), hints[n - 1])?;
+    Ok(())
+}
+
+/// `solution <lesson>`：显示参考答案；还没尝试过练习时先确认
+pub fn solution(
+    sel: &str,
+    progress: &mut Progress,
+    out: &mut dyn Write,
+) -> Result<(), RunnerError> {
+    let id = single(sel, "solutions")?.meta().id();
+    let (_, entries) = curriculum::read()?;
+    let entry = entries
+        .iter()
+        .find(|e| e.id() == id)
+        .ok_or_else(|| RunnerError::Config(format!("{} is missing from curriculum.toml", id)))?;
+    let file = entry.solution_file();
+    let code = fs::read_to_string(&file)
+        .map_err(|e| RunnerError::Config(format!("Cannot read {}: {}", file.display(), e)))?;
+    let state = progress.exercise(&id).cloned().unwrap_or_default();
+    if state.attempts == 0
+        && !io::confirm("You haven't tried this exercise yet. Show the solution anyway?")?
+    {
+        return Ok(());
+    }
+    if !state.is_solved() && !state.revealed {
+        writeln!(
+            out,
+            "Viewing the solution before solving the exercise sets its score to 0"
+        )?;
+    }
+    progress.reveal_solution(&id)?;
+    let file = file.strip_prefix(curriculum::root()).unwrap_or(&file);
+    writeln!(
+        out,
+        "{}",
+        fmt::dim(&format!("Solution: {}", file.display()))
+    )?;
+    write!(out, "{}", highlight::rust(&code))?;
+    Ok(())
+}
+
+/// 提示与参考答案只属于一个练习：选择器必须恰好选中一个 lesson
+fn single(sel: &str, what: &str) -> Result<&'static dyn Lesson, RunnerError> {
+    match lessons::resolve(sel)?.as_slice() {
+        [lesson] => Ok(*lesson),
+        _ => Err(RunnerError::Usage(format!(
+            "'{}' selects several lessons, {} belong to a single exercise",
+            sel, what
+        ))),
+    }
+}
+
+/// `score 75 / 100 (1 hint used)`
+fn score(state: &ExerciseState) -> String {
+    let hints = match state.hints {
+        _ if state.revealed => String::from("solution viewed"),
+        0 => String::from("no hints used"),
+        1 => String::from("1 hint used"),
+        n => format!("{} hints used", n),
+    };
+    format!("score {} / 100 ({})", state.score().unwrap_or(0), hints)
+}
+
+/// 逐项运行检查，panic 即失败，取出其消息；检查期间不打印默认的 panic 信息
+pub(crate) fn check_all(checks: &[Check]) -> Vec<Result<(), String>> {
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let results = checks
+        .iter()
+        .map(|c| panic::catch_unwind(c.check).map_err(|p| lessons::panic_message(&*p)))
+        .collect();
+    panic::set_hook(hook);
+    results
+}
+
+/// `  ✗ name: message`，多行消息（如 `assert_eq!` 的 left / right）缩进对齐
+pub(crate) fn failure(name: &str, message: &str) -> String {
+    let mut lines = message.lines();
+    let mut out = format!(
+        "  {} {}: {}\n",
+        fmt::text("✗"),
+        name,
+        lines.next().unwrap_or_default()
+    );
+    for line in lines {
+        out.push_str(&format!("      {}\n", line));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn unfinished() {
+        todo!()
+    }
+
+    fn wrong() {
+        assert_eq!(1 + 1, 3);
+    }
+
+    #[test]
+    fn test_check_all() {
+        let checks = [
+            Check::new("done", || ()),
+            Check::new("unfinished", unfinished),
+            Check::new("wrong", wrong),
+        ];
+        let results = check_all(&checks);
+        assert_eq!(results[0], Ok(()));
+        assert_eq!(results[1], Err(String::from("not yet implemented")));
+        assert!(results[2].as_ref().unwrap_err().contains("left: 2"));
+    }
+
+    #[test]
+    fn test_score() {
+        let mut state = ExerciseState {
+            hints: 1,
+            solved: Some(crate::utils::time::Timestamp::from_unix(0)),
+            ..ExerciseState::default()
+        };
+        assert_eq!(score(&state), "score 75 / 100 (1 hint used)");
+        state.hints = 0;
+        assert_eq!(score(&state), "score 100 / 100 (no hints used)");
+        state.revealed = true;
+        assert_eq!(score(&state), "score 0 / 100 (solution viewed)");
+    }
+
+    /// 用参考答案代替练习文件编译时，每个 lesson 的检查都应通过：`cargo test --features solutions`
+    #[cfg(feature = "solutions")]
+    #[test]
+    fn test_solutions_pass() {
+        for lesson in lessons::all() {
+            let checks = lesson.exercise();
+            for (check, result) in checks.iter().zip(check_all(&checks)) {
+                assert_eq!(result, Ok(()), "{}: {}", lesson.meta().id(), check.name);
+            }
+        }
+    }
+
+    #[test]
+    fn test_failure() {
+        assert_eq!(
+            failure("sum", "assertion failed\n  left: 0\n right: 10"),
+            "  ✗ sum: assertion failed\n        left: 0\n       right: 10\n"
+        );
+    }
+}
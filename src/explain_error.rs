@@ -0,0 +1,157 @@
+//! 编译错误讲解：`explain-error [code]`
+//!
+//! 学习者真正会碰到的是编译器的报错。`explain-error E0382` 先显示 `rustc --explain` 的官方说明，
+//! 再列出各 lesson 中会报这个错的常见坑（按 [`Pitfall::error`] 关联）：代码、原因与怎样改，
+//! 以及回到哪个 lesson 复习。错误码可以省略 `E` 与前导零，例如 `382`。
+//! 不带参数时列出 lesson 覆盖到的全部错误码。
+
+use crate::error::RunnerError;
+use crate::highlight;
+use crate::i18n;
+use crate::lesson::{Language, LessonMeta, Pitfall};
+use crate::lessons;
+use crate::utils::fmt;
+use std::collections::BTreeMap;
+use std::env;
+use std::io::Write;
+use std::process::Command;
+
+pub fn run(code: Option<&str>, lang: Language, out: &mut dyn Write) -> Result<(), RunnerError> {
+    let index = index();
+    let Some(code) = code else {
+        return list(&index, out);
+    };
+    let code = normalize(code).ok_or_else(|| {
+        RunnerError::Usage(format!(
+            "'{}' is not an error code, expected something like E0382",
+            code
+        ))
+    })?;
+    let rustc = env::var("RUSTC").unwrap_or_else(|_| String::from("rustc"));
+    let output = Command::new(&rustc)
+        .args(["--explain", &code])
+        .output()
+        .map_err(|e| {
+            RunnerError::Config(format!(
+                "cannot run '{}': {} (explain-error needs a Rust toolchain)",
+                rustc, e
+            ))
+        })?;
+    if !output.status.success() {
+        return Err(RunnerError::Usage(format!(
+            "{} is not a rustc error code this toolchain knows",
+            code
+        )));
+    }
+    write!(out, "{}", String::from_utf8_lossy(&output.stdout))?;
+
+    let related: Vec<&(LessonMeta, Pitfall)> = index
+        .iter()
+        .filter(|(_, p)| p.error == Some(code.as_str()))
+        .collect();
+    if related.is_empty() {
+        writeln!(
+            out,
+            "\n{}",
+            fmt::dim(&format!(
+                "No lesson covers {} yet, run `explain-error` to list the codes that are covered",
+                code
+            ))
+        )?;
+        return Ok(());
+    }
+    writeln!(
+        out,
+        "\n{}",
+        fmt::text(&format!("══ {} in the lessons ══", code))
+    )?;
+    for (meta, pitfall) in related {
+        writeln!(
+            out,
+            "\n{}",
+            fmt::text(&format!("── {} {} ──", meta.id(), pitfall.name))
+        )?;
+        write!(out, "{}", highlight::rust(pitfall.code))?;
+        writeln!(out, "Why: {}", pitfall.explanation)?;
+        writeln!(
+            out,
+            "{}",
+            fmt::dim(&format!(
+                "Review {} ({}), `pitfalls {}` shows the full compiler output",
+                meta.id(),
+                i18n::title(meta, lang),
+                meta.slug
+            ))
+        )?;
+    }
+    Ok(())
+}
+
+/// 标明了错误码的常见坑及其所属 lesson，按 lesson 编号排列
+pub(crate) fn index() -> Vec<(LessonMeta, Pitfall)> {
+    let mut index: Vec<(LessonMeta, Pitfall)> = lessons::all()
+        .iter()
+        .filter(|l| !l.meta().deprecated)
+        .flat_map(|l| l.pitfalls().into_iter().map(move |p| (l.meta(), p)))
+        .filter(|(_, p)| p.error.is_some())
+        .collect();
+    index.sort_by_key(|(meta, _)| meta.number);
+    index
+}
+
+/// 每个错误码一行，后面是涉及它的 lesson
+fn list(index: &[(LessonMeta, Pitfall)], out: &mut dyn Write) -> Result<(), RunnerError> {
+    let mut codes: BTreeMap<&str, Vec<String>> = BTreeMap::new();
+    for (meta, pitfall) in index {
+        let ids = codes.entry(pitfall.error.unwrap_or_default()).or_default();
+        if !ids.contains(&meta.id()) {
+            ids.push(meta.id());
+        }
+    }
+    for (code, ids) in &codes {
+        writeln!(out, "{}  {}", fmt::text(code), ids.join(", "))?;
+    }
+    writeln!(
+        out,
+        "\n{} error codes covered, run `explain-error <code>` for rustc's explanation and the lesson commentary",
+        codes.len()
+    )?;
+    Ok(())
+}
+
+/// `E0382`、`e0382`、`0382`、`382` 都规范成 `E0382`
+fn normalize(code: &str) -> Option<String> {
+    let digits = code.strip_prefix(['E', 'e']).unwrap_or(code);
+    if digits.is_empty() || digits.len() > 4 || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    Some(format!("E{:0>4}", digits))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(normalize("E0382").as_deref(), Some("E0382"));
+        assert_eq!(normalize("e0382").as_deref(), Some("E0382"));
+        assert_eq!(normalize("382").as_deref(), Some("E0382"));
+        assert_eq!(normalize("E4").as_deref(), Some("E0004"));
+        assert_eq!(normalize("E"), None);
+        assert_eq!(normalize("E03820"), None);
+        assert_eq!(normalize("borrow"), None);
+    }
+
+    #[test]
+    fn test_index() {
+        let index = index();
+        assert!(
+            index
+                .iter()
+                .any(|(m, p)| m.slug == "ownership" && p.error == Some("E0382"))
+        );
+        assert!(index.iter().all(|(_, p)| p.error.is_some()));
+        assert!(index.is_sorted_by_key(|(m, _)| m.number));
+    }
+}
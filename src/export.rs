@@ -0,0 +1,499 @@
+//! 导出：`export --format anki|json|po [file]`、`export <lesson> --format md [file]` 与 `export --format mdbook|html|ipynb [dir]`
+//!
+//! `--format anki` 把每个 lesson 模块注释中的 `## 要点`、`## 常见坑` 与 `QUIZ` 中的测验题导出为
+//! Anki 可直接导入的 TSV：要点与常见坑各是一张“有哪些？”的卡片，每道测验题一张。文件头写明分隔符、
+//! HTML 与标签列，导入时不用再手动设置；再次导入时按正面去重，相当于更新已有卡片。
+//! 模块注释只在源码中，不在源码树中运行时只导出测验题。
+//!
+//! `--format md` 把一个 lesson 整理成一篇适合分享或打印的 Markdown 讲义：模块注释原样作为正文，
+//! 接着是代码（与 [`share`](crate::share) 一样去掉测验、练习与测试）和按 `--lang` 运行一遍的输出，
+//! 各放进一个代码块。指定小节时输出只包含这一节。
+//!
+//! `--format mdbook [dir]` 把整个课程生成为 mdBook（默认在 `book/` 中）：`src/SUMMARY.md` 按章节列出
+//! 全部 lesson，每个 lesson 一章，内容与 `--format md` 的讲义相同；`mdbook serve book` 即可在浏览器中阅读，
+//! `mdbook build book` 生成可以发布的静态网站。`md` 与 `mdbook` 都需要在源码树中运行。
+//!
+//! `--format html [dir]` 不依赖 mdBook，直接生成独立的静态网页，见 [`site`]；
+//! `--format ipynb [dir]` 为每个 lesson 生成一个可以逐节运行的 Jupyter notebook，见 [`notebook`]。
+//!
+//! `--format json [file]` 输出整个课程的目录：每个 lesson 的编号、slug、标题（按 `--lang`）、章节、难度、
+//! 预计时长、标签、前置 lesson、小节与测验题数量等，供外部的看板或编辑器插件使用。不需要源码树。
+//!
+//! `--format po [file]` 把界面与 lesson 文本导出为按 `--lang` 翻译的 gettext PO 文件，见 [`translations`]。
+
+use crate::cli::Format;
+use crate::curriculum::{self, Entry};
+use crate::error::RunnerError;
+use crate::i18n;
+use crate::lesson::{Chapter, Language, LessonMeta};
+use crate::lessons::{self, Selected};
+use crate::notebook;
+use crate::report::{self, RunOptions};
+use crate::schema::{Catalog, CatalogLesson, CatalogSection, SCHEMA_VERSION};
+use crate::share;
+use crate::site;
+use crate::transcript;
+use crate::translations;
+use std::fs;
+use std::io::Write;
+use std::path::Path;
+
+/// `--format mdbook` 不给目录时生成到这里
+const BOOK_DIR: &str = "book";
+
+/// mdBook 的配置；`{lang}` 在生成时替换
+const BOOK_TOML: &str = r#"[book]
+title = "Rust Learn"
+language = "{lang}"
+src = "src"
+
+[output.html]
+"#;
+
+/// 一张卡片；正反面都是 HTML
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Card {
+    front: String,
+    back: String,
+    tags: String,
+}
+
+pub fn run(
+    format: Format,
+    args: &[String],
+    lang: Language,
+    out: &mut dyn Write,
+) -> Result<(), RunnerError> {
+    let (text, summary) = match (format, args) {
+        (Format::Anki, [] | [_]) => {
+            let cards = cards();
+            (tsv(&cards), format!("{} cards", cards.len()))
+        }
+        (Format::Markdown, [sel] | [sel, _]) => {
+            let selected = lessons::select(sel)?;
+            let [selected] = selected.as_slice() else {
+                return Err(RunnerError::Usage(format!(
+                    "'{}' selects several lessons, export one lesson or section at a time",
+                    sel
+                )));
+            };
+            let (_, entries) = curriculum::read()?;
+            (handout(selected, &entries, lang)?, selected.id())
+        }
+        (Format::MdBook, [] | [_]) => {
+            return book(args.first().map_or(BOOK_DIR, String::as_str), lang, out);
+        }
+        (Format::Json, [] | [_]) => (
+            catalog_json(lang),
+            format!("{} lessons", lessons::all().len()),
+        ),
+        (Format::Html, [] | [_]) => {
+            return site::run(args.first().map_or(site::DIR, String::as_str), lang, out);
+        }
+        (Format::Po, [] | [_]) => {
+            let (text, count) = translations::po(lang);
+            (text, format!("{} strings", count))
+        }
+        (Format::Ipynb, [] | [_]) => {
+            return notebook::run(
+                args.first().map_or(notebook::DIR, String::as_str),
+                lang,
+                out,
+            );
+        }
+        _ => {
+            return Err(RunnerError::Usage(String::from(
+                "Usage: export --format anki|json|po [file] | export <lesson> --format md [file] | export --format mdbook|html|ipynb [dir]",
+            )));
+        }
+    };
+    let file = match format {
+        Format::Markdown => args.get(1),
+        _ => args.first(),
+    };
+    match file {
+        Some(file) => {
+            fs::write(file, text)?;
+            writeln!(out, "Exported {} to {}", summary, file)?;
+        }
+        None => write!(out, "{}", text)?,
+    }
+    Ok(())
+}
+
+fn cards() -> Vec<Card> {
+    let entries = curriculum::read()
+        .map(|(_, entries)| entries)
+        .unwrap_or_default();
+    let mut cards = Vec::new();
+    for lesson in lessons::all() {
+        let meta = lesson.meta();
+        let id = meta.id();
+        let title = format!("{} {}", id, meta.title);
+        let docs = entries
+            .iter()
+            .find(|e| e.id() == id)
+            .and_then(|e| fs::read_to_string(e.file()).ok())
+            .unwrap_or_default();
+        for (heading, question, tag) in [
+            ("## 要点", "要点有哪些？", "key-points"),
+            ("## 常见坑", "常见坑有哪些？", "pitfalls"),
+        ] {
+            let items = bullets(&docs, heading);
+            if items.is_empty() {
+                continue;
+            }
+            let list: String = items
+                .iter()
+                .map(|b| format!("<li>{}</li>", html(b)))
+                .collect();
+            cards.push(Card {
+                front: format!("{}<br>{}", html(&title), question),
+                back: format!("<ul>{}</ul>", list),
+                tags: format!("rust-learn {} {}", id, tag),
+            });
+        }
+        for q in lesson.quiz() {
+            let choices: String = q
+                .choices
+                .iter()
+                .enumerate()
+                .map(|(i, c)| format!("<br>{}) {}", i + 1, html(c)))
+                .collect();
+            cards.push(Card {
+                front: format!("{}<br>{}{}", html(&title), html(q.prompt), choices),
+                back: format!(
+                    "{}) {}<br>{}",
+                    q.answer + 1,
+                    html(q.choices[q.answer]),
+                    html(q.explanation)
+                ),
+                tags: format!("rust-learn {} quiz", id),
+            });
+        }
+    }
+    cards
+}
+
+/// `--format json` 输出的课程目录；网页版（`rust-learn-web`）的 `list_lessons()` 也返回它
+pub fn catalog_json(lang: Language) -> String {
+    let text = serde_json::to_string_pretty(&catalog(lang)).expect("the catalog always serializes");
+    format!("{}\n", text)
+}
+
+fn catalog(lang: Language) -> Catalog {
+    let lessons = lessons::all()
+        .iter()
+        .map(|lesson| {
+            let meta = lesson.meta();
+            CatalogLesson {
+                id: meta.id(),
+                number: meta.number,
+                slug: meta.slug.to_string(),
+                title: i18n::title(&meta, lang).to_string(),
+                chapter: meta.chapter.title().to_string(),
+                difficulty: meta.difficulty.to_string().to_lowercase(),
+                minutes: meta.minutes,
+                tags: meta.tags.iter().map(|t| t.to_string()).collect(),
+                aliases: meta.aliases.iter().map(|a| a.to_string()).collect(),
+                prerequisites: lessons::prerequisites(&meta)
+                    .iter()
+                    .map(LessonMeta::id)
+                    .collect(),
+                sections: lesson
+                    .sections()
+                    .iter()
+                    .map(|s| CatalogSection {
+                        name: s.name.to_string(),
+                        title: s.title.to_string(),
+                    })
+                    .collect(),
+                quiz: lesson.quiz().len(),
+                exercises: lesson.exercise().len(),
+                deprecated: meta.deprecated,
+                replaced_by: meta.replaced_by.map(String::from),
+            }
+        })
+        .collect();
+    Catalog {
+        schema_version: SCHEMA_VERSION,
+        lessons,
+    }
+}
+
+/// 在 `dir` 中生成 mdBook：`book.toml`、`src/SUMMARY.md` 与每个 lesson 一章；已有的同名文件会被覆盖
+fn book(dir: &str, lang: Language, out: &mut dyn Write) -> Result<(), RunnerError> {
+    let (_, entries) = curriculum::read()?;
+    let metas: Vec<LessonMeta> = lessons::all()
+        .iter()
+        .map(|l| l.meta())
+        .filter(|m| !m.deprecated)
+        .collect();
+    let src = Path::new(dir).join("src");
+    fs::create_dir_all(&src)?;
+    fs::write(
+        Path::new(dir).join("book.toml"),
+        BOOK_TOML.replace("{lang}", lang.code()),
+    )?;
+    fs::write(src.join("SUMMARY.md"), summary(&metas, lang))?;
+    for lesson in lessons::all() {
+        let meta = lesson.meta();
+        if meta.deprecated {
+            continue;
+        }
+        let selected = Selected {
+            lesson: lesson.as_ref(),
+            section: None,
+        };
+        fs::write(
+            src.join(format!("{}.md", meta.id())),
+            handout(&selected, &entries, lang)?,
+        )?;
+    }
+    writeln!(out, "Exported {} lessons to {}", metas.len(), dir)?;
+    writeln!(out, "Run `mdbook serve {}` to browse it", dir)?;
+    Ok(())
+}
+
+/// mdBook 的目录：每个章节是一部分，lesson 按编号排列
+fn summary(metas: &[LessonMeta], lang: Language) -> String {
+    let mut out = String::from("# Summary\n");
+    for chapter in Chapter::ALL {
+        let chapter_metas: Vec<_> = metas.iter().filter(|m| m.chapter == chapter).collect();
+        if chapter_metas.is_empty() {
+            continue;
+        }
+        out.push_str(&format!("\n# {}\n\n", chapter));
+        for meta in chapter_metas {
+            out.push_str(&format!(
+                "- [{}. {}]({}.md)\n",
+                meta.number,
+                i18n::title(meta, lang),
+                meta.id()
+            ));
+        }
+    }
+    out
+}
+
+/// 一个 lesson（或一节）的 Markdown 讲义
+fn handout(selected: &Selected, entries: &[Entry], lang: Language) -> Result<String, RunnerError> {
+    Ok(markdown(&Material::read(selected, entries, lang)?))
+}
+
+/// 讲义的素材：正文、代码与输出
+pub(crate) struct Material {
+    /// 模块注释去掉 `//!` 后的 Markdown
+    pub docs: String,
+    /// 去掉测验、练习与测试的代码
+    pub code: String,
+    /// 按 `--lang` 运行一遍的输出；失败时末尾是错误消息
+    pub output: String,
+    /// 源码原文，生成可以独立运行的代码时用（见 [`share::code`]）
+    pub source: String,
+}
+
+impl Material {
+    /// 读取选中的 lesson 的源码（按大纲 `entries` 找到）并运行一遍
+    pub(crate) fn read(
+        selected: &Selected,
+        entries: &[Entry],
+        lang: Language,
+    ) -> Result<Material, RunnerError> {
+        let mut material = Material::source(selected, entries)?;
+        let report = report::run(
+            &selected.id(),
+            RunOptions {
+                language: lang,
+                ..RunOptions::default()
+            },
+        )?;
+        material.output = report.output;
+        if let Some(error) = report.error {
+            material.output.push_str(&format!("\n{}\n", error));
+        }
+        Ok(material)
+    }
+
+    /// 只读取正文与代码，不运行，输出为空
+    pub(crate) fn source(selected: &Selected, entries: &[Entry]) -> Result<Material, RunnerError> {
+        let id = selected.lesson.meta().id();
+        let entry = entries.iter().find(|e| e.id() == id).ok_or_else(|| {
+            RunnerError::Config(format!("{} is missing from curriculum.toml", id))
+        })?;
+        let source = fs::read_to_string(entry.file())?;
+        Ok(Material::new(&source, String::new()))
+    }
+
+    fn new(source: &str, output: String) -> Material {
+        let (docs, code) = share::split(source);
+        let docs: String = docs
+            .lines()
+            .map(|l| {
+                let l = l.trim_start_matches("//!");
+                format!("{}\n", l.strip_prefix(' ').unwrap_or(l))
+            })
+            .collect();
+        Material {
+            docs: format!("{}\n", docs.trim_end()),
+            code,
+            output,
+            source: source.to_string(),
+        }
+    }
+}
+
+/// 正文后面是代码与输出
+fn markdown(material: &Material) -> String {
+    format!(
+        "{}\n## 代码\n\n{}\n## 输出\n\n{}",
+        material.docs,
+        transcript::fenced("rust", &material.code),
+        transcript::fenced("text", &material.output)
+    )
+}
+
+/// 模块注释中某一节的条目；条目的续行接在上一条后面，中文之间不加空格，代码块不算在内
+fn bullets(source: &str, heading: &str) -> Vec<String> {
+    let docs = source
+        .lines()
+        .map_while(|l| l.strip_prefix("//!"))
+        .map(str::trim);
+    let mut items: Vec<String> = Vec::new();
+    let (mut inside, mut code) = (false, false);
+    for line in docs {
+        if line.starts_with("```") {
+            code = !code;
+        } else if code {
+            continue;
+        } else if line.starts_with("## ") {
+            inside = line == heading;
+        } else if !inside || line.is_empty() {
+            continue;
+        } else if let Some(item) = line.strip_prefix("- ") {
+            items.push(item.to_string());
+        } else if let Some(last) = items.last_mut() {
+            if last.ends_with(|c: char| c.is_ascii()) && line.starts_with(|c: char| c.is_ascii()) {
+                last.push(' ');
+            }
+            last.push_str(line);
+        }
+    }
+    items
+}
+
+/// 转义 HTML，`` `code` `` 换成 `<code>`
+pub(crate) fn html(text: &str) -> String {
+    let escaped = text
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace(['\t', '\n'], " ");
+    let mut out = String::new();
+    for (i, part) in escaped.split('`').enumerate() {
+        if i % 2 == 1 {
+            out.push_str(&format!("<code>{}</code>", part));
+        } else {
+            out.push_str(part);
+        }
+    }
+    out
+}
+
+/// Anki 的纯文本导入格式：`#` 开头的文件头之后每行一张卡片，字段用制表符分隔
+fn tsv(cards: &[Card]) -> String {
+    let mut out = String::from("#separator:tab\n#html:true\n#tags column:3\n#deck:Rust Learn\n");
+    for card in cards {
+        out.push_str(&format!("{}\t{}\t{}\n", card.front, card.back, card.tags));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DOCS: &str = "//! # Demo\n//!\n//! ## 要点\n//! - 默认不可变\n//! - 用 `mut`\n//!   `let mut x`，\n//!   之后可以修改\n\
+                        //!\n//! ```rust\n//! let x = 1;\n//! - 1;\n//! ```\n//!\n//! ## 常见坑\n//! - 忘记 `mut`\n//!\n//! ## 运行\n//! `cargo run -- 02_demo`\n\n\
+                        //! - 不是模块注释\n";
+
+    #[test]
+    fn test_bullets() {
+        assert_eq!(
+            bullets(DOCS, "## 要点"),
+            ["默认不可变", "用 `mut` `let mut x`，之后可以修改"]
+        );
+        assert_eq!(bullets(DOCS, "## 常见坑"), ["忘记 `mut`"]);
+        assert!(bullets(DOCS, "## 运行").is_empty());
+    }
+
+    #[test]
+    fn test_html() {
+        assert_eq!(
+            html("`Vec<T>` & <b>"),
+            "<code>Vec&lt;T&gt;</code> &amp; &lt;b&gt;"
+        );
+    }
+
+    #[test]
+    fn test_markdown() {
+        let source = format!(
+            "{}use crate::lesson::{{self, LessonContext}};\n\npub const QUIZ: &[Question] = &[];\n\nfn demo() {{}}\n",
+            DOCS
+        );
+        let text = markdown(&Material::new(&source, String::from("\x1b[1m1\x1b[0m\n")));
+        assert!(text.starts_with("# Demo\n\n## 要点\n- 默认不可变\n"));
+        assert!(text.contains(
+            "\n## 代码\n\n```rust\nuse crate::lesson::LessonContext;\n\nfn demo() {}\n```\n"
+        ));
+        assert!(text.ends_with("\n## 输出\n\n```text\n1\n```\n"));
+        assert!(!text.contains("QUIZ"));
+    }
+
+    #[test]
+    fn test_catalog() {
+        let catalog = catalog(Language::Zh);
+        assert_eq!(catalog.lessons.len(), lessons::all().len());
+        let json: serde_json::Value = serde_json::to_value(&catalog).unwrap();
+        assert_eq!(json["schema_version"], SCHEMA_VERSION);
+        let first = &json["lessons"][0];
+        assert_eq!(first["number"], 1);
+        assert_eq!(first["id"], lessons::all()[0].meta().id());
+        assert!(first["difficulty"].is_string());
+        assert!(first["sections"].is_array());
+        assert!(first.get("replaced_by").is_none());
+        assert!(
+            json["lessons"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .any(|l| l["prerequisites"].as_array().is_some_and(|p| !p.is_empty()))
+        );
+    }
+
+    #[test]
+    fn test_summary() {
+        let metas: Vec<LessonMeta> = lessons::all().iter().map(|l| l.meta()).collect();
+        let text = summary(&metas, Language::Zh);
+        assert!(text.starts_with("# Summary\n\n# Basics\n\n- [1. "));
+        let first = &metas[0];
+        assert!(text.contains(&format!("]({}.md)\n", first.id())));
+        assert_eq!(text.matches("\n- [").count(), metas.len());
+    }
+
+    #[test]
+    fn test_cards() {
+        let cards = cards();
+        assert!(cards.iter().all(|c| !c.front.contains(['\t', '\n'])));
+        let fronts: std::collections::BTreeSet<_> = cards.iter().map(|c| &c.front).collect();
+        assert_eq!(fronts.len(), cards.len());
+        assert!(
+            cards
+                .iter()
+                .any(|c| c.tags.ends_with("key-points") && c.back.starts_with("<ul><li>"))
+        );
+        let text = tsv(&cards[..1]);
+        assert!(text.starts_with("#separator:tab\n"));
+        assert_eq!(text.lines().last().unwrap().split('\t').count(), 3);
+    }
+}
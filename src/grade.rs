@@ -0,0 +1,200 @@
+//! 成绩：把测验与练习汇总成每个 lesson 的分数与总评
+//!
+//! 每个 lesson 的分数是测验最好成绩（换算成百分制）与练习得分（见 [`ExerciseState::score`]）的平均，
+//! 做过其中一项而另一项没做或没完成时，后者按 0 分计。两项都没碰过的 lesson 不计入总评。
+//! 总评是已评分 lesson 的平均分与对应的等级，由 `stats` 显示，`stats export` 导出为 CSV。
+//!
+//! [`ExerciseState::score`]: crate::progress::ExerciseState::score
+
+use crate::lesson::LessonMeta;
+use crate::progress::Progress;
+
+/// 测验最好成绩达到这个分数（B 及以上）的 lesson 在 `path --adaptive` 中视为已掌握，可以跳过
+pub const MASTERY: u32 = 80;
+
+/// 一个 lesson 的成绩；各项为百分制，没做过的为 `None`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LessonGrade {
+    pub id: String,
+    pub title: &'static str,
+    /// 测验最好成绩
+    pub quiz: Option<u32>,
+    /// 练习得分；做过但还没完成时为 0
+    pub exercise: Option<u32>,
+}
+
+impl LessonGrade {
+    /// 两项的平均，没做的一项按 0 分计；两项都没做时为 `None`
+    pub fn score(&self) -> Option<u32> {
+        if self.quiz.is_none() && self.exercise.is_none() {
+            return None;
+        }
+        Some((self.quiz.unwrap_or(0) + self.exercise.unwrap_or(0)).div_ceil(2))
+    }
+}
+
+/// 全部已评分 lesson 的汇总
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Summary {
+    pub graded: usize,
+    pub total: usize,
+    /// 已评分 lesson 的平均分；一个都没有时为 `None`
+    pub average: Option<u32>,
+}
+
+/// 按大纲顺序计算每个 lesson 的成绩
+pub fn lessons(progress: &Progress, metas: &[LessonMeta]) -> Vec<LessonGrade> {
+    metas
+        .iter()
+        .map(|meta| {
+            let id = meta.id();
+            let quiz = progress
+                .quiz_scores(&id)
+                .iter()
+                .filter(|q| q.total > 0)
+                .map(|q| percent(q.correct, q.total))
+                .max();
+            let exercise = progress
+                .exercise(&id)
+                .filter(|s| s.attempts > 0)
+                .map(|s| s.score().unwrap_or(0));
+            LessonGrade {
+                id,
+                title: meta.title,
+                quiz,
+                exercise,
+            }
+        })
+        .collect()
+}
+
+pub fn summarize(grades: &[LessonGrade]) -> Summary {
+    let scores: Vec<u32> = grades.iter().filter_map(LessonGrade::score).collect();
+    let average = (!scores.is_empty()).then(|| {
+        let sum: u32 = scores.iter().sum();
+        (sum + scores.len() as u32 / 2) / scores.len() as u32
+    });
+    Summary {
+        graded: scores.len(),
+        total: grades.len(),
+        average,
+    }
+}
+
+/// 百分制分数对应的等级
+pub fn letter(score: u32) -> char {
+    match score {
+        90.. => 'A',
+        80..=89 => 'B',
+        70..=79 => 'C',
+        60..=69 => 'D',
+        _ => 'F',
+    }
+}
+
+/// 四舍五入的百分比
+fn percent(correct: usize, total: usize) -> u32 {
+    ((correct * 100 + total / 2) / total) as u32
+}
+
+/// 导出用的 CSV：每个 lesson 一行，没做过的项留空，最后一行是总评
+pub fn csv(grades: &[LessonGrade]) -> String {
+    let cell = |n: Option<u32>| n.map(|n| n.to_string()).unwrap_or_default();
+    let mut out = String::from("lesson,title,quiz,exercise,score,grade\n");
+    for g in grades {
+        let score = g.score();
+        out.push_str(&format!(
+            "{},{},{},{},{},{}\n",
+            g.id,
+            quote(g.title),
+            cell(g.quiz),
+            cell(g.exercise),
+            cell(score),
+            score.map(letter).map(String::from).unwrap_or_default()
+        ));
+    }
+    let summary = summarize(grades);
+    out.push_str(&format!(
+        "overall,{},,,{},{}\n",
+        quote(&format!(
+            "{} / {} lessons graded",
+            summary.graded, summary.total
+        )),
+        cell(summary.average),
+        summary
+            .average
+            .map(letter)
+            .map(String::from)
+            .unwrap_or_default()
+    ));
+    out
+}
+
+/// 含逗号、引号或换行的字段加上引号
+pub(crate) fn quote(field: &str) -> String {
+    if field.contains([',', '"', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grade(id: &str, quiz: Option<u32>, exercise: Option<u32>) -> LessonGrade {
+        LessonGrade {
+            id: id.to_string(),
+            title: "标题, 副标题",
+            quiz,
+            exercise,
+        }
+    }
+
+    #[test]
+    fn test_score() {
+        assert_eq!(grade("a", Some(80), Some(75)).score(), Some(78));
+        assert_eq!(grade("a", Some(100), None).score(), Some(50));
+        assert_eq!(grade("a", None, None).score(), None);
+    }
+
+    #[test]
+    fn test_summarize() {
+        let grades = [
+            grade("a", Some(100), Some(100)),
+            grade("b", Some(60), Some(0)),
+            grade("c", None, None),
+        ];
+        assert_eq!(
+            summarize(&grades),
+            Summary {
+                graded: 2,
+                total: 3,
+                average: Some(65),
+            }
+        );
+        assert_eq!(summarize(&grades[2..]).average, None);
+    }
+
+    #[test]
+    fn test_letter() {
+        assert_eq!(letter(100), 'A');
+        assert_eq!(letter(89), 'B');
+        assert_eq!(letter(60), 'D');
+        assert_eq!(letter(59), 'F');
+        assert_eq!(percent(2, 3), 67);
+    }
+
+    #[test]
+    fn test_csv() {
+        let text = csv(&[grade("01_a", Some(80), None), grade("02_b", None, None)]);
+        assert_eq!(
+            text,
+            "lesson,title,quiz,exercise,score,grade\n\
+             01_a,\"标题, 副标题\",80,,40,F\n\
+             02_b,\"标题, 副标题\",,,,\n\
+             overall,1 / 2 lessons graded,,,40,F\n"
+        );
+    }
+}
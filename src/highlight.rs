@@ -0,0 +1,316 @@
+//! Rust 语法高亮：终端中用 ANSI 颜色，导出的网页中用 HTML
+//!
+//! 只做词法层面的着色：关键字、字符串与字符、注释、数字、宏调用、大写开头的类型名与生命周期。
+//! 不解析语法，遇到原始字符串等少见写法时最多颜色不准，文本本身原样保留。
+//! 未开启颜色或使用 plain 主题时不着色。
+//!
+//! 开启 `syntect` feature 时终端中改用 [syntect](https://docs.rs/syntect) 按 Sublime Text 的语法定义着色，
+//! 能正确处理原始字符串、属性、嵌套泛型等写法，配色随 `--theme` 选择；导出的 HTML 仍用内置的词法着色。
+
+use crate::utils::fmt::{self, Theme};
+
+const KEYWORDS: &[&str] = &[
+    "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum", "extern",
+    "false", "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub",
+    "ref", "return", "self", "Self", "static", "struct", "super", "trait", "true", "type",
+    "unsafe", "use", "where", "while",
+];
+
+/// 着色的记号种类
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    Keyword,
+    Str,
+    Comment,
+    Number,
+    Macro,
+    Type,
+    Lifetime,
+}
+
+impl Kind {
+    /// 终端中的 ANSI 颜色
+    fn ansi(self) -> &'static str {
+        match self {
+            Kind::Keyword => "35",
+            Kind::Str => "32",
+            Kind::Comment => "90",
+            Kind::Number | Kind::Lifetime => "33",
+            Kind::Macro => "36",
+            Kind::Type => "34",
+        }
+    }
+
+    /// HTML 中 `<span>` 的 class，样式见 [`CSS`]
+    fn class(self) -> &'static str {
+        match self {
+            Kind::Keyword => "kw",
+            Kind::Str => "str",
+            Kind::Comment => "com",
+            Kind::Number => "num",
+            Kind::Macro => "mac",
+            Kind::Type => "ty",
+            Kind::Lifetime => "lt",
+        }
+    }
+}
+
+/// [`html`] 输出的 class 对应的样式，与终端中的配色一致
+pub const CSS: &str = ".kw { color: #a626a4; } .str { color: #50a14f; } .com { color: #8e8e8e; } \
+.num, .lt { color: #c18401; } .mac { color: #0184bc; } .ty { color: #4078f2; }";
+
+/// 给一段 Rust 源码着色
+pub fn rust(code: &str) -> String {
+    if !fmt::is_color() || fmt::theme() == Theme::Plain {
+        return code.to_string();
+    }
+    #[cfg(feature = "syntect")]
+    {
+        syntax::paint(code, fmt::theme())
+    }
+    #[cfg(not(feature = "syntect"))]
+    {
+        paint(code)
+    }
+}
+
+/// 着色为 HTML：转义后用带 class 的 `<span>` 包住各个记号，放进 `<pre>` 即可显示
+pub fn html(code: &str) -> String {
+    let mut out = String::with_capacity(code.len() * 2);
+    for (kind, token) in tokens(code) {
+        let token = escape(&token);
+        match kind {
+            Some(kind) => out.push_str(&format!(
+                "<span class=\"{}\">{}</span>",
+                kind.class(),
+                token
+            )),
+            None => out.push_str(&token),
+        }
+    }
+    out
+}
+
+fn paint(code: &str) -> String {
+    let mut out = String::with_capacity(code.len() * 2);
+    for (kind, token) in tokens(code) {
+        match kind {
+            Some(kind) => out.push_str(&format!("\x1b[{}m{}\x1b[0m", kind.ansi(), token)),
+            None => out.push_str(&token),
+        }
+    }
+    out
+}
+
+/// 用 syntect 着色，语法与配色在第一次使用时加载
+#[cfg(feature = "syntect")]
+mod syntax {
+    use super::Theme;
+    use std::sync::LazyLock;
+    use syntect::easy::HighlightLines;
+    use syntect::highlighting::ThemeSet;
+    use syntect::parsing::SyntaxSet;
+    use syntect::util::{LinesWithEndings, as_24_bit_terminal_escaped};
+
+    static SYNTAXES: LazyLock<SyntaxSet> = LazyLock::new(SyntaxSet::load_defaults_newlines);
+    static THEMES: LazyLock<ThemeSet> = LazyLock::new(ThemeSet::load_defaults);
+
+    /// `--theme` 对应的 syntect 配色，都是深色背景的
+    pub(super) fn theme_name(theme: Theme) -> &'static str {
+        match theme {
+            Theme::Plain | Theme::Classic => "base16-ocean.dark",
+            Theme::Fancy => "base16-mocha.dark",
+            Theme::HighContrast => "base16-eighties.dark",
+        }
+    }
+
+    /// 着色失败（语法定义出错）时退回内置的词法着色
+    pub(super) fn paint(code: &str, theme: Theme) -> String {
+        let syntax = SYNTAXES
+            .find_syntax_by_extension("rs")
+            .expect("the default syntaxes include Rust");
+        let mut lines = HighlightLines::new(syntax, &THEMES.themes[theme_name(theme)]);
+        let mut out = String::with_capacity(code.len() * 4);
+        for line in LinesWithEndings::from(code) {
+            match lines.highlight_line(line, &SYNTAXES) {
+                Ok(ranges) => out.push_str(&as_24_bit_terminal_escaped(&ranges, false)),
+                Err(_) => return super::paint(code),
+            }
+        }
+        out.push_str("\x1b[0m");
+        out
+    }
+}
+
+/// 转义 HTML 中的特殊字符
+pub fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// 切分成记号，相邻的不着色字符各自成为一项；拼起来就是原文
+fn tokens(code: &str) -> Vec<(Option<Kind>, String)> {
+    let chars: Vec<char> = code.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        let start = i;
+        let kind = if c == '/' && chars.get(i + 1) == Some(&'/') {
+            i = find(&chars, i, |c| c == '\n');
+            Some(Kind::Comment)
+        } else if c == '/' && chars.get(i + 1) == Some(&'*') {
+            i += 2;
+            while i < chars.len() && !(chars[i] == '*' && chars.get(i + 1) == Some(&'/')) {
+                i += 1;
+            }
+            i = (i + 2).min(chars.len());
+            Some(Kind::Comment)
+        } else if c == '"' {
+            i = quoted(&chars, i, '"');
+            Some(Kind::Str)
+        } else if c == '\'' {
+            // `'a'`、`'\n'` 是字符，`'a` 后面不是引号时是生命周期
+            match (chars.get(i + 1), chars.get(i + 2)) {
+                (Some('\\'), _) | (Some(_), Some('\'')) => {
+                    i = quoted(&chars, i, '\'');
+                    Some(Kind::Str)
+                }
+                (Some(c), _) if is_ident_start(*c) => {
+                    i = find(&chars, i + 1, |c| !is_ident(c));
+                    Some(Kind::Lifetime)
+                }
+                _ => {
+                    i += 1;
+                    None
+                }
+            }
+        } else if c.is_ascii_digit() {
+            i += 1;
+            while i < chars.len() {
+                let digit_after = chars.get(i + 1).is_some_and(char::is_ascii_digit);
+                if is_ident(chars[i]) || (chars[i] == '.' && digit_after) {
+                    i += 1;
+                } else {
+                    break;
+                }
+            }
+            Some(Kind::Number)
+        } else if is_ident_start(c) {
+            i = find(&chars, i, |c| !is_ident(c));
+            let word: String = chars[start..i].iter().collect();
+            if chars.get(i) == Some(&'!') && chars.get(i + 1) != Some(&'=') {
+                i += 1;
+                Some(Kind::Macro)
+            } else if KEYWORDS.contains(&word.as_str()) {
+                Some(Kind::Keyword)
+            } else if c.is_ascii_uppercase() {
+                Some(Kind::Type)
+            } else {
+                None
+            }
+        } else {
+            i += 1;
+            None
+        };
+        tokens.push((kind, chars[start..i].iter().collect()));
+    }
+    tokens
+}
+
+/// 从 `from` 开始第一个满足条件的位置，没有时为末尾
+fn find(chars: &[char], from: usize, pred: impl Fn(char) -> bool) -> usize {
+    chars[from..]
+        .iter()
+        .position(|&c| pred(c))
+        .map_or(chars.len(), |n| from + n)
+}
+
+/// 跳过从 `from` 开始、以 `quote` 结束的字符串或字符字面量（含转义），返回其后的位置
+fn quoted(chars: &[char], from: usize, quote: char) -> usize {
+    let mut i = from + 1;
+    while i < chars.len() {
+        match chars[i] {
+            '\\' => i += 2,
+            c if c == quote => return i + 1,
+            _ => i += 1,
+        }
+    }
+    chars.len()
+}
+
+fn is_ident_start(c: char) -> bool {
+    c.is_alphabetic() || c == '_'
+}
+
+fn is_ident(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn strip(s: &str) -> String {
+        let mut out = String::new();
+        let mut chars = s.chars();
+        while let Some(c) = chars.next() {
+            if c == '\x1b' {
+                chars.by_ref().find(|&c| c == 'm');
+            } else {
+                out.push(c);
+            }
+        }
+        out
+    }
+
+    #[test]
+    fn test_paint() {
+        assert_eq!(
+            paint("let x: Vec<u8> = vec![1];"),
+            "\x1b[35mlet\x1b[0m x: \x1b[34mVec\x1b[0m<u8> = \x1b[36mvec!\x1b[0m[\x1b[33m1\x1b[0m];"
+        );
+        assert_eq!(
+            paint("\"a \\\" b\" // 注释"),
+            "\x1b[32m\"a \\\" b\"\x1b[0m \x1b[90m// 注释\x1b[0m"
+        );
+        assert_eq!(
+            paint("fn f<'a>(c: char) { 'x' }"),
+            "\x1b[35mfn\x1b[0m f<\x1b[33m'a\x1b[0m>(c: char) { \x1b[32m'x'\x1b[0m }"
+        );
+        // 小数点后不是数字时属于方法调用
+        assert_eq!(paint("1.5"), "\x1b[33m1.5\x1b[0m");
+        assert_eq!(paint("1.max(2)"), "\x1b[33m1\x1b[0m.max(\x1b[33m2\x1b[0m)");
+        // `a != b` 不是宏
+        assert_eq!(paint("a != b"), "a != b");
+    }
+
+    #[test]
+    fn test_html() {
+        assert_eq!(
+            html("let v: Vec<&str> = vec![];"),
+            "<span class=\"kw\">let</span> v: <span class=\"ty\">Vec</span>&lt;&amp;str&gt; = \
+             <span class=\"mac\">vec!</span>[];"
+        );
+    }
+
+    #[test]
+    fn test_paint_keeps_text() {
+        let code = "/* 块注释\n */\nimpl Shape for Circle {\n    fn area(&self) -> f64 { 3.14 * self.r }\n}\n'";
+        assert_eq!(strip(&paint(code)), code);
+    }
+
+    #[cfg(feature = "syntect")]
+    #[test]
+    fn test_syntect() {
+        let code =
+            "let s = r#\"raw \"quoted\"\"#;\n#[derive(Debug)]\nstruct P<T: Into<Vec<u8>>>(T);\n";
+        for theme in Theme::ALL {
+            let painted = syntax::paint(code, theme);
+            assert!(painted.contains("\x1b[38;2;"), "{}", theme.name());
+            assert_eq!(strip(&painted), code);
+        }
+    }
+}
@@ -0,0 +1,179 @@
+//! JUnit XML 报告：`all` 与 `check` 的 `--junit PATH`
+//!
+//! CI 系统与 IDE 大多能直接显示 JUnit 风格的 XML 报告。`all --junit report.xml` 把每个 lesson
+//! 记为一个测试用例（失败时附上错误信息），`check <lesson> --junit report.xml` 每个 lesson 一个
+//! 测试套件，其中每个单元测试一个用例，被忽略的记为 skipped。
+//!
+//! 输出中的 ANSI 转义序列会被去掉，XML 中不允许的控制字符也一并去掉。
+
+use crate::transcript;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::time::Duration;
+
+/// 一个测试套件，例如一次 `all`，或者 `check` 中的一个 lesson
+#[derive(Debug, Clone, PartialEq)]
+pub struct Suite {
+    pub name: String,
+    pub cases: Vec<Case>,
+}
+
+/// 一个测试用例
+#[derive(Debug, Clone, PartialEq)]
+pub struct Case {
+    pub name: String,
+    /// 耗时；不知道时不写
+    pub time: Option<Duration>,
+    pub outcome: Outcome,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Passed,
+    /// 失败，附带错误信息
+    Failed(String),
+    Skipped,
+}
+
+impl Suite {
+    fn count(&self, pred: impl Fn(&Outcome) -> bool) -> usize {
+        self.cases.iter().filter(|c| pred(&c.outcome)).count()
+    }
+
+    fn time(&self) -> Option<Duration> {
+        self.cases.iter().map(|c| c.time).sum()
+    }
+}
+
+/// 把报告写到 `path`
+pub fn write(path: &Path, suites: &[Suite]) -> io::Result<()> {
+    fs::write(path, render(suites))
+}
+
+fn render(suites: &[Suite]) -> String {
+    let total = |pred: fn(&Outcome) -> bool| suites.iter().map(|s| s.count(pred)).sum::<usize>();
+    let mut out = format!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+         <testsuites name=\"rust-learn\" tests=\"{}\" failures=\"{}\" skipped=\"{}\">\n",
+        total(|_| true),
+        total(|o| matches!(o, Outcome::Failed(_))),
+        total(|o| *o == Outcome::Skipped)
+    );
+    for suite in suites {
+        out.push_str(&format!(
+            "  <testsuite name=\"{}\" tests=\"{}\" failures=\"{}\" skipped=\"{}\"{}>\n",
+            escape(&suite.name),
+            suite.cases.len(),
+            suite.count(|o| matches!(o, Outcome::Failed(_))),
+            suite.count(|o| *o == Outcome::Skipped),
+            time(suite.time())
+        ));
+        for case in &suite.cases {
+            let open = format!(
+                "    <testcase classname=\"{}\" name=\"{}\"{}",
+                escape(&suite.name),
+                escape(&case.name),
+                time(case.time)
+            );
+            match &case.outcome {
+                Outcome::Passed => out.push_str(&format!("{}/>\n", open)),
+                Outcome::Skipped => {
+                    out.push_str(&format!("{}>\n      <skipped/>\n    </testcase>\n", open))
+                }
+                Outcome::Failed(message) => {
+                    let message = escape(message.trim());
+                    let summary = message.lines().next().unwrap_or_default();
+                    out.push_str(&format!(
+                        "{}>\n      <failure message=\"{}\">{}</failure>\n    </testcase>\n",
+                        open, summary, message
+                    ));
+                }
+            }
+        }
+        out.push_str("  </testsuite>\n");
+    }
+    out.push_str("</testsuites>\n");
+    out
+}
+
+/// ` time="秒"` 属性；不知道耗时时为空
+fn time(time: Option<Duration>) -> String {
+    time.map_or_else(String::new, |t| format!(" time=\"{:.3}\"", t.as_secs_f64()))
+}
+
+/// 转义 XML 特殊字符，去掉 ANSI 转义序列与 XML 不允许的控制字符
+fn escape(text: &str) -> String {
+    transcript::plain(text)
+        .chars()
+        .filter(|&c| !c.is_control() || matches!(c, '\n' | '\t'))
+        .collect::<String>()
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render() {
+        let suites = [Suite {
+            name: String::from("06_ownership"),
+            cases: vec![
+                Case {
+                    name: String::from("test_move"),
+                    time: Some(Duration::from_millis(12)),
+                    outcome: Outcome::Passed,
+                },
+                Case {
+                    name: String::from("test_clone"),
+                    time: None,
+                    outcome: Outcome::Failed(String::from(
+                        "\x1b[31massertion failed\x1b[0m: a < b\n  at src/x.rs",
+                    )),
+                },
+                Case {
+                    name: String::from("test_slow"),
+                    time: None,
+                    outcome: Outcome::Skipped,
+                },
+            ],
+        }];
+        assert_eq!(
+            render(&suites),
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+             <testsuites name=\"rust-learn\" tests=\"3\" failures=\"1\" skipped=\"1\">\n\
+             \x20 <testsuite name=\"06_ownership\" tests=\"3\" failures=\"1\" skipped=\"1\">\n\
+             \x20   <testcase classname=\"06_ownership\" name=\"test_move\" time=\"0.012\"/>\n\
+             \x20   <testcase classname=\"06_ownership\" name=\"test_clone\">\n\
+             \x20     <failure message=\"assertion failed: a &lt; b\">assertion failed: a &lt; b\n  at src/x.rs</failure>\n\
+             \x20   </testcase>\n\
+             \x20   <testcase classname=\"06_ownership\" name=\"test_slow\">\n\
+             \x20     <skipped/>\n\
+             \x20   </testcase>\n\
+             \x20 </testsuite>\n\
+             </testsuites>\n"
+        );
+    }
+
+    #[test]
+    fn test_suite_time() {
+        let case = |ms: Option<u64>| Case {
+            name: String::new(),
+            time: ms.map(Duration::from_millis),
+            outcome: Outcome::Passed,
+        };
+        let suite = |cases| Suite {
+            name: String::new(),
+            cases,
+        };
+        assert_eq!(
+            suite(vec![case(Some(1)), case(Some(2))]).time(),
+            Some(Duration::from_millis(3))
+        );
+        assert_eq!(suite(vec![case(Some(1)), case(None)]).time(), None);
+    }
+}
@@ -0,0 +1,201 @@
+//! 型（kata）：`kata [list [difficulty]] | <name> | <difficulty>`
+//!
+//! kata 是独立于 lesson 的小型 TDD 练习（见 `katas` crate）：学习者补全 `crates/katas/src/drills/<name>.rs`，
+//! `kata <name>` 像 [`exercise`](crate::exercise) 一样在进程内逐项运行测试套件并报告结果，
+//! 通过情况记入学习进度。`kata <difficulty>` 选出该难度中第一个还没完成的 kata，
+//! 不带参数或 `kata list [difficulty]` 列出全部 kata 及完成情况。
+
+use crate::curriculum;
+use crate::error::RunnerError;
+use crate::exercise;
+use crate::lesson::Difficulty;
+use crate::progress::Progress;
+use crate::utils::fmt;
+use rust_learn_core::kata::Kata;
+use std::io::Write;
+
+/// `kata ...`，`args` 不含 `kata` 本身
+pub fn run(
+    args: &[String],
+    progress: &mut Progress,
+    out: &mut dyn Write,
+) -> Result<(), RunnerError> {
+    let usage = || {
+        RunnerError::Usage(String::from(
+            "Usage: kata [list [difficulty]] | kata <name> | kata <difficulty>",
+        ))
+    };
+    match args {
+        [] => list(None, progress, out),
+        [cmd, rest @ ..] if cmd == "list" => match rest {
+            [] => list(None, progress, out),
+            [level] => list(Some(difficulty(level).ok_or_else(usage)?), progress, out),
+            _ => Err(usage()),
+        },
+        [sel] => match difficulty(sel) {
+            Some(level) => match next(katas::ALL, level, progress) {
+                Some(kata) => check(kata, progress, out),
+                None => {
+                    writeln!(out, "Every {} kata is solved 🎉", level)?;
+                    Ok(())
+                }
+            },
+            None => check(find(sel)?, progress, out),
+        },
+        _ => Err(usage()),
+    }
+}
+
+/// 不区分大小写的难度名，例如 `beginner`
+fn difficulty(s: &str) -> Option<Difficulty> {
+    Difficulty::ALL
+        .into_iter()
+        .find(|d| d.to_string().eq_ignore_ascii_case(s))
+}
+
+fn find(name: &str) -> Result<&'static Kata, RunnerError> {
+    katas::ALL.iter().find(|k| k.name == name).ok_or_else(|| {
+        RunnerError::Usage(format!(
+            "Unknown kata '{}', run `kata list` to see them all",
+            name
+        ))
+    })
+}
+
+/// 该难度中第一个还没完成的 kata
+fn next<'a>(all: &'a [Kata], level: Difficulty, progress: &Progress) -> Option<&'a Kata> {
+    all.iter()
+        .filter(|k| k.difficulty == level)
+        .find(|k| progress.kata(k.name).is_none_or(|s| s.solved.is_none()))
+}
+
+fn list(
+    level: Option<Difficulty>,
+    progress: &Progress,
+    out: &mut dyn Write,
+) -> Result<(), RunnerError> {
+    let shown: Vec<&Kata> = katas::ALL
+        .iter()
+        .filter(|k| level.is_none_or(|l| k.difficulty == l))
+        .collect();
+    let mut solved = 0;
+    for kata in &shown {
+        let state = progress.kata(kata.name);
+        let (mark, detail) = match state {
+            Some(s) if s.solved.is_some() => {
+                solved += 1;
+                ("✓", String::new())
+            }
+            Some(s) => (" ", format!("  {} / {} passing", s.passed, s.total)),
+            None => (" ", String::new()),
+        };
+        writeln!(
+            out,
+            "{} {:<16} {} {}{}",
+            fmt::text(mark),
+            kata.name,
+            fmt::text(kata.difficulty.stars()),
+            kata.title,
+            fmt::dim(&detail)
+        )?;
+    }
+    writeln!(
+        out,
+        "\n{} / {} solved, run `kata <name>` to start one",
+        solved,
+        shown.len()
+    )?;
+    Ok(())
+}
+
+/// 运行一个 kata 的测试套件并记录结果
+fn check(kata: &Kata, progress: &mut Progress, out: &mut dyn Write) -> Result<(), RunnerError> {
+    writeln!(
+        out,
+        "{} {} ({})",
+        fmt::text(kata.difficulty.stars()),
+        kata.title,
+        kata.difficulty
+    )?;
+    writeln!(out, "{}", kata.task)?;
+    let file = curriculum::root()
+        .join("crates/katas/src/drills")
+        .join(format!("{}.rs", kata.name));
+    if file.exists() {
+        let file = file.strip_prefix(curriculum::root()).unwrap_or(&file);
+        writeln!(out, "{}", fmt::dim(&format!("Kata: {}", file.display())))?;
+    }
+    writeln!(out)?;
+    let mut passed = 0;
+    for (test, result) in kata.tests.iter().zip(exercise::check_all(kata.tests)) {
+        match result {
+            Ok(()) => {
+                writeln!(out, "  {} {}", fmt::text("✓"), test.name)?;
+                passed += 1;
+            }
+            Err(message) => write!(out, "{}", exercise::failure(test.name, &message))?,
+        }
+    }
+    progress.record_kata(kata.name, passed, kata.tests.len())?;
+    if passed == kata.tests.len() {
+        writeln!(out, "\nAll {} tests passed", passed)?;
+    } else {
+        writeln!(
+            out,
+            "\n{} / {} tests passed, make the next one pass and run `kata {}` again",
+            passed,
+            kata.tests.len(),
+            kata.name
+        )?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_difficulty() {
+        assert_eq!(difficulty("beginner"), Some(Difficulty::Beginner));
+        assert_eq!(difficulty("ADVANCED"), Some(Difficulty::Advanced));
+        assert_eq!(difficulty("fizzbuzz"), None);
+        // kata 的名字不能与难度名冲突，否则 `kata <name>` 会被当成难度
+        assert!(katas::ALL.iter().all(|k| difficulty(k.name).is_none()));
+    }
+
+    #[test]
+    fn test_next() {
+        let mut progress = Progress::default();
+        let first = next(katas::ALL, Difficulty::Beginner, &progress).unwrap();
+        assert_eq!(first.name, katas::ALL[0].name);
+        progress
+            .record_kata(first.name, 1, first.tests.len())
+            .unwrap();
+        assert_eq!(
+            next(katas::ALL, Difficulty::Beginner, &progress).map(|k| k.name),
+            Some(first.name)
+        );
+        for kata in katas::ALL {
+            progress.record_kata(kata.name, 1, 1).unwrap();
+        }
+        assert!(next(katas::ALL, Difficulty::Beginner, &progress).is_none());
+    }
+
+    #[test]
+    fn test_find() {
+        assert_eq!(find("fizzbuzz").unwrap().name, "fizzbuzz");
+        assert!(matches!(find("nope"), Err(RunnerError::Usage(_))));
+    }
+
+    /// 参考答案应通过每个 kata 的全部测试：`cargo test --features solutions`
+    #[cfg(feature = "solutions")]
+    #[test]
+    fn test_solutions_pass() {
+        for kata in katas::ALL {
+            for (test, result) in kata.tests.iter().zip(exercise::check_all(kata.tests)) {
+                assert_eq!(result, Ok(()), "{}: {}", kata.name, test.name);
+            }
+        }
+    }
+}
@@ -0,0 +1,210 @@
+//! 填空练习工作区：`generate-koans <dir>`
+//!
+//! 把各 lesson 的 `KOANS` 写成一个独立的 cargo 项目：`koans/` 下每个 lesson 一个文件，空白处是 `__`；
+//! `src/main.rs` 是检查程序，按顺序用 `rustc --test` 编译并运行每个文件，停在第一个没通过的文件上。
+//! 生成的项目不依赖本仓库，可以拷到别处练习。
+
+use crate::error::RunnerError;
+use crate::lesson::{Koan, LessonMeta};
+use crate::lessons;
+use std::fs;
+use std::io::Write;
+use std::path::Path;
+
+/// koan 文件按这个 edition 编译；与本仓库无关，选兼容面最广的
+const EDITION: &str = "2021";
+
+const CARGO_TOML: &str = r#"[package]
+name = "rust-learn-koans"
+version = "0.1.0"
+edition = "{edition}"
+
+# 独立项目，即使生成在别的 workspace 里也不会被并入
+[workspace]
+"#;
+
+const CHECKER: &str = r#"//! 依次检查 `koans/` 中的文件：用 `rustc --test` 编译并运行，停在第一个没通过的文件上
+//!
+//! `cargo run` 检查全部文件，`cargo run -- <name>` 只检查文件名包含 `<name>` 的文件。
+
+use std::env;
+use std::fs;
+use std::path::Path;
+use std::process::{self, Command};
+
+fn main() {
+    let filter = env::args().nth(1).unwrap_or_default();
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let out = root.join("target").join("koans");
+    fs::create_dir_all(&out).expect("cannot create target/koans");
+    let mut files: Vec<_> = fs::read_dir(root.join("koans"))
+        .expect("cannot read koans/")
+        .filter_map(|entry| Some(entry.ok()?.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext == "rs"))
+        .filter(|path| {
+            path.file_name()
+                .is_some_and(|name| name.to_string_lossy().contains(&filter))
+        })
+        .collect();
+    files.sort();
+    let rustc = env::var("RUSTC").unwrap_or_else(|_| String::from("rustc"));
+    for (done, file) in files.iter().enumerate() {
+        let name = file.file_stem().unwrap_or_default().to_string_lossy();
+        let bin = out.join(name.as_ref());
+        let compiled = Command::new(&rustc)
+            .args(["--edition", "{edition}", "--test", "-A", "warnings", "-o"])
+            .arg(&bin)
+            .arg(file)
+            .output()
+            .expect("cannot run rustc");
+        if !compiled.status.success() {
+            fail(done, files.len(), &name, "does not compile yet", &compiled.stderr);
+        }
+        let tested = Command::new(&bin)
+            .arg("-q")
+            .output()
+            .expect("cannot run the koan tests");
+        if !tested.status.success() {
+            fail(done, files.len(), &name, "compiles but its tests fail", &tested.stdout);
+        }
+        println!("✓ {}", name);
+    }
+    println!("\nAll {} koan files pass", files.len());
+}
+
+fn fail(done: usize, total: usize, name: &str, problem: &str, output: &[u8]) -> ! {
+    eprintln!("✗ koans/{}.rs {}:\n", name, problem);
+    eprintln!("{}", String::from_utf8_lossy(output));
+    eprintln!(
+        "{} / {} files pass, fill in the blanks (`__`) in koans/{}.rs and run `cargo run` again",
+        done, total, name
+    );
+    process::exit(1);
+}
+"#;
+
+/// 在 `dir` 中生成工作区；`dir` 已存在且不为空时拒绝覆盖
+pub fn run(dir: &str, out: &mut dyn Write) -> Result<(), RunnerError> {
+    let root = Path::new(dir);
+    if root.exists() && fs::read_dir(root)?.next().is_some() {
+        return Err(RunnerError::Usage(format!(
+            "{} already exists and is not empty",
+            dir
+        )));
+    }
+    fs::create_dir_all(root.join("koans"))?;
+    fs::create_dir_all(root.join("src"))?;
+    fs::write(
+        root.join("Cargo.toml"),
+        CARGO_TOML.replace("{edition}", EDITION),
+    )?;
+    fs::write(root.join(".gitignore"), "/target\n")?;
+    fs::write(
+        root.join("src").join("main.rs"),
+        CHECKER.replace("{edition}", EDITION),
+    )?;
+    let (mut files, mut count) = (0, 0);
+    for lesson in lessons::all() {
+        let koans = lesson.koans();
+        if koans.is_empty() {
+            continue;
+        }
+        let meta = lesson.meta();
+        let file = root.join("koans").join(format!("{}.rs", meta.id()));
+        fs::write(file, render(&meta, &koans, false))?;
+        files += 1;
+        count += koans.len();
+    }
+    writeln!(
+        out,
+        "Created {} koans in {} files under {}",
+        count, files, dir
+    )?;
+    writeln!(
+        out,
+        "Fill in the blanks (`__`), then run `cargo run` there to check them in order"
+    )?;
+    Ok(())
+}
+
+/// 一个 lesson 的 koan 文件；`solved` 时填上答案，用来检查 koan 本身
+fn render(meta: &LessonMeta, koans: &[Koan], solved: bool) -> String {
+    let mut out = format!(
+        "//! # {} — {}\n//!\n\
+         //! 把每个 `__` 换成正确的代码，让这个文件编译并通过测试；在项目根目录运行 `cargo run` 检查。\n",
+        meta.id(),
+        meta.title
+    );
+    for koan in koans {
+        let code = if solved {
+            koan.solved()
+        } else {
+            koan.code.to_string()
+        };
+        out.push_str(&format!("\n// koan: {}\n{}", koan.name, code));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+    use std::process::Command;
+    use std::thread;
+
+    #[test]
+    fn test_render() {
+        let meta = lessons::all()[0].meta();
+        let koans = [Koan::new(
+            "sum",
+            "#[test]\nfn sum() {\n    assert_eq!(1 + 1, __);\n}\n",
+            &["2"],
+        )];
+        let text = render(&meta, &koans, false);
+        assert!(text.starts_with(&format!("//! # {} — {}\n", meta.id(), meta.title)));
+        assert!(
+            text.ends_with("\n// koan: sum\n#[test]\nfn sum() {\n    assert_eq!(1 + 1, __);\n}\n")
+        );
+        assert!(render(&meta, &koans, true).contains("assert_eq!(1 + 1, 2);"));
+    }
+
+    /// 填上答案后，每个 lesson 的 koan 文件都能编译并通过测试
+    #[test]
+    fn test_koans_pass_with_answers() {
+        let dir = env::temp_dir().join(format!("rust-learn-koans-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let rustc = env::var("RUSTC").unwrap_or_else(|_| String::from("rustc"));
+        thread::scope(|scope| {
+            for lesson in lessons::all() {
+                let (dir, rustc) = (&dir, &rustc);
+                scope.spawn(move || {
+                    let meta = lesson.meta();
+                    let file = dir.join(format!("{}.rs", meta.id()));
+                    let bin = dir.join(meta.id());
+                    fs::write(&file, render(&meta, &lesson.koans(), true)).unwrap();
+                    let compiled = Command::new(rustc)
+                        .args(["--edition", EDITION, "--test", "-A", "warnings", "-o"])
+                        .arg(&bin)
+                        .arg(&file)
+                        .output()
+                        .unwrap();
+                    assert!(
+                        compiled.status.success(),
+                        "{}: {}",
+                        meta.id(),
+                        String::from_utf8_lossy(&compiled.stderr)
+                    );
+                    let tested = Command::new(&bin).arg("-q").output().unwrap();
+                    assert!(
+                        tested.status.success(),
+                        "{}: {}",
+                        meta.id(),
+                        String::from_utf8_lossy(&tested.stdout)
+                    );
+                });
+            }
+        });
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -2,132 +2,1559 @@
 //!
 //! 统一管理所有 lesson 模块，提供 list 和运行功能
 
+use crate::cli::{Format, Options};
+use crate::error::RunnerError;
+use crate::events;
+use crate::grade;
+use crate::i18n;
+use crate::lesson::{
+    self, Chapter, Check, Koan, Language, Lesson, LessonContext, LessonMeta, Question, Section,
+    Verbosity,
+};
+// 只在注册 lesson 的宏展开中用到，一个 lesson 都没有编译进来时未被使用
+#[allow(unused_imports)]
+use crate::lesson::{Difficulty, Pitfall};
+use crate::pack;
+use crate::progress::Progress;
+use crate::quiz::QUIZ_LENGTH;
+use crate::utils::{fmt, io};
+use std::any::Any;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::io::{BufRead, Write};
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::{LazyLock, PoisonError, RwLock};
+use std::time::{Duration, Instant};
+
 macro_rules! register_lessons {
-    ($($num:literal, $slug:ident, $title:expr, $path:ident),+ $(,)?) => {
+    ($(
+        $num:literal => $slug:ident {
+            krate: $krate:ident,
+            aliases: [$($alias:literal),* $(,)?],
+            title: $title:expr,
+            chapter: $chapter:ident,
+            difficulty: $difficulty:ident,
+            minutes: $minutes:expr,
+            tags: [$($tag:expr),* $(,)?],
+            requires: [$($req:ident),* $(,)?],
+            deprecated: $deprecated:literal,
+            replaced_by: $replaced_by:expr $(,)?
+        }
+    ),* $(,)?) => {
         $(
-            pub mod $path;
-        )+
+            pub use $krate::$slug;
+        )*
 
-        pub struct Lesson {
-            pub number: usize,
-            pub slug: &'static str,
-            pub title: &'static str,
-            pub run: fn(),
-        }
+        /// 注册表条目：每个 lesson 编号对应一个独立类型，各自实现 [`Lesson`]
+        pub struct Entry<const N: usize>;
 
-        pub fn all() -> Vec<Lesson> {
-            vec![
-                $(
-                    Lesson {
+        $(
+            impl Lesson for Entry<$num> {
+                fn meta(&self) -> LessonMeta {
+                    LessonMeta {
                         number: $num,
                         slug: stringify!($slug),
+                        aliases: &[$($alias),*],
                         title: $title,
-                        run: $path::run,
+                        chapter: Chapter::$chapter,
+                        difficulty: Difficulty::$difficulty,
+                        minutes: $minutes,
+                        tags: &[$($tag),*],
+                        prerequisites: &[$(stringify!($req)),*],
+                        deprecated: $deprecated,
+                        replaced_by: $replaced_by,
                     }
-                ),+
-            ]
+                }
+
+                fn run(&self, ctx: &mut LessonContext) -> lesson::Result {
+                    $slug::run(ctx)
+                }
+
+                fn sections(&self) -> Vec<Section> {
+                    $slug::SECTIONS.to_vec()
+                }
+
+                fn quiz(&self) -> Vec<Question> {
+                    $slug::QUIZ.to_vec()
+                }
+
+                fn exercise(&self) -> Vec<Check> {
+                    $slug::EXERCISE.to_vec()
+                }
+
+                fn hints(&self) -> Vec<&'static str> {
+                    $slug::HINTS.to_vec()
+                }
+
+                fn koans(&self) -> Vec<Koan> {
+                    $slug::KOANS.to_vec()
+                }
+
+                fn pitfalls(&self) -> Vec<Pitfall> {
+                    $slug::PITFALLS.to_vec()
+                }
+            }
+        )*
+
+        // 手写或生成的注册表都在编译期检查：编号递增且不重复，slug 不重复
+        const _: () = check_registry(&[$($num),*], &[$(stringify!($slug)),*]);
+
+        /// 本 crate 内置的 lesson（只包含已启用 feature 的部分）
+        pub fn builtin() -> Vec<Box<dyn Lesson>> {
+            vec![$(Box::new(Entry::<$num>)),*]
         }
+    };
+}
 
-        pub fn list() {
-            for l in all() {
-                println!("{:02}  {:<24} {}", l.number, l.slug, l.title);
+/// 注册表的编译期检查，违反时以编译错误报告
+///
+/// 未启用的 feature 会在编号中留下空缺，因此这里只要求严格递增；
+/// 编号连续性由 build.rs 对完整大纲检查。
+const fn check_registry(numbers: &[usize], slugs: &[&str]) {
+    let mut i = 1;
+    while i < numbers.len() {
+        assert!(
+            numbers[i] > numbers[i - 1],
+            "register_lessons!: lesson numbers must be unique and in ascending order"
+        );
+        i += 1;
+    }
+    let mut i = 0;
+    while i < slugs.len() {
+        let mut j = 0;
+        while j < i {
+            assert!(
+                !str_eq(slugs[i], slugs[j]),
+                "register_lessons!: duplicate lesson slug"
+            );
+            j += 1;
+        }
+        i += 1;
+    }
+}
+
+const fn str_eq(a: &str, b: &str) -> bool {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < a.len() {
+        if a[i] != b[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// 注册表：首次使用时收集一次，之后一直复用
+static REGISTRY: LazyLock<Vec<Box<dyn Lesson>>> = LazyLock::new(|| {
+    let mut lessons = builtin();
+    for pack in pack::packs() {
+        lessons.extend((pack.lessons)());
+    }
+    lessons.sort_by_key(|l| l.meta().number);
+    lessons
+});
+
+/// 全部 lesson：内置 lesson 加上已链接的 lesson 包，按编号排序
+pub fn all() -> &'static [Box<dyn Lesson>] {
+    &REGISTRY
+}
+
+/// 所属 feature 未启用、没有编译进来的 lesson，由 build.rs 生成（见 [`UNAVAILABLE`]）
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Unavailable {
+    pub number: usize,
+    pub slug: &'static str,
+    pub aliases: &'static [&'static str],
+    pub title: &'static str,
+    pub chapter: Chapter,
+    /// 需要启用的 Cargo feature
+    pub feature: &'static str,
+}
+
+impl Unavailable {
+    pub fn id(&self) -> String {
+        format!("{:02}_{}", self.number, self.slug)
+    }
+
+    fn matches(&self, sel: &str) -> bool {
+        sel == self.id()
+            || sel.parse() == Ok(self.number)
+            || std::iter::once(self.slug)
+                .chain(self.aliases.iter().copied())
+                .any(|name| name == sel)
+    }
+
+    fn error(&self) -> RunnerError {
+        RunnerError::Unavailable {
+            lesson: self.id(),
+            feature: self.feature.to_string(),
+        }
+    }
+}
+
+/// 按章节列出全部 lesson；未编译的 lesson 弱化显示并注明所需的 feature
+pub fn list(progress: &Progress, lang: Language, out: &mut dyn Write) -> Result<(), RunnerError> {
+    // (编号, 章节, 是否弱化, 各列)
+    let mut rows: Vec<(usize, Chapter, bool, Vec<String>)> = all()
+        .iter()
+        .map(|l| {
+            let meta = l.meta();
+            let mark = if progress.is_complete(&meta.id()) {
+                "✓"
+            } else {
+                " "
+            };
+            let mut cells = vec![
+                fmt::text(mark).into_owned(),
+                format!("{:02}", meta.number),
+                meta.slug.to_string(),
+                i18n::title(&meta, lang).to_string(),
+                fmt::text(meta.difficulty.stars()).into_owned(),
+                format!("{:>2} min", meta.minutes),
+            ];
+            if meta.deprecated {
+                cells.push(deprecation(&meta));
             }
+            (meta.number, meta.chapter, meta.deprecated, cells)
+        })
+        .collect();
+    rows.extend(UNAVAILABLE.iter().map(|u| {
+        let cells = vec![
+            String::from(" "),
+            format!("{:02}", u.number),
+            u.slug.to_string(),
+            i18n::lesson_title(u.slug, u.title, lang).to_string(),
+            format!("(requires --features {})", u.feature),
+        ];
+        (u.number, u.chapter, true, cells)
+    }));
+    rows.sort_by_key(|(number, _, _, _)| *number);
+    let cells: Vec<Vec<String>> = rows.iter().map(|(_, _, _, cells)| cells.clone()).collect();
+
+    let mut chapter = None;
+    for ((_, row_chapter, dim, _), line) in rows.iter().zip(fmt::table(&cells)) {
+        if chapter != Some(*row_chapter) {
+            if chapter.is_some() {
+                writeln!(out)?;
+            }
+            writeln!(out, "[{}]", row_chapter)?;
+            chapter = Some(*row_chapter);
+        }
+        writeln!(out, "{}", if *dim { fmt::dim(&line) } else { line })?;
+    }
+    Ok(())
+}
+
+/// 取代已弃用 lesson 的新 lesson
+fn replacement(meta: &LessonMeta) -> Option<LessonMeta> {
+    INDEX.by_slug(meta.replaced_by?).copied()
+}
+
+/// 弃用说明，例如 `(deprecated → 07_borrowing)`
+fn deprecation(meta: &LessonMeta) -> String {
+    match replacement(meta) {
+        Some(new) => fmt::text(&format!("(deprecated → {})", new.id())).into_owned(),
+        None => String::from("(deprecated)"),
+    }
+}
+
+/// 按章节显示目录：每章的 lesson 数与预计总时长
+pub fn toc(lang: Language, out: &mut dyn Write) -> Result<(), RunnerError> {
+    let lessons: Vec<_> = all().iter().map(|l| l.meta()).collect();
+    for (i, chapter) in Chapter::ALL.into_iter().enumerate() {
+        let metas: Vec<_> = lessons.iter().filter(|m| m.chapter == chapter).collect();
+        if metas.is_empty() {
+            continue;
+        }
+        let minutes: u32 = metas.iter().map(|m| m.minutes).sum();
+        writeln!(
+            out,
+            "{}. {} ({} lessons, ~{} min)",
+            i + 1,
+            chapter,
+            metas.len(),
+            minutes
+        )?;
+        for meta in metas {
+            writeln!(out, "     {:<22} {}", meta.id(), i18n::title(meta, lang))?;
+        }
+    }
+    Ok(())
+}
+
+/// 显示 lesson 的详细信息与笔记，已完成的小节标记 ✓
+pub fn info(
+    sel: &str,
+    progress: &Progress,
+    lang: Language,
+    out: &mut dyn Write,
+) -> Result<(), RunnerError> {
+    for l in resolve(sel)? {
+        let meta = l.meta();
+        let ids = |slugs: Vec<LessonMeta>| {
+            let ids: Vec<_> = slugs.iter().map(LessonMeta::id).collect();
+            if ids.is_empty() {
+                String::from("-")
+            } else {
+                ids.join(", ")
+            }
+        };
+        writeln!(
+            out,
+            "{}",
+            fmt::text(&format!("{} — {}", meta.id(), i18n::title(&meta, lang)))
+        )?;
+        let chapter = meta.chapter.to_string();
+        let difficulty = format!("{} {}", meta.difficulty, fmt::text(meta.difficulty.stars()));
+        let time = format!("~{} min", meta.minutes);
+        let tags = meta.tags.join(", ");
+        let aliases = meta.aliases.join(", ");
+        let sections: Vec<_> = l
+            .sections()
+            .iter()
+            .map(|s| match progress.is_complete(&section_id(&meta, s)) {
+                true => format!("{} {}", s.name, fmt::text("✓")),
+                false => s.name.to_string(),
+            })
+            .collect();
+        let sections = sections.join(", ");
+        let (requires, see_also) = (ids(prerequisites(&meta)), ids(related(&meta)));
+        let status = deprecation(&meta);
+        let run = format!("cargo run -- {}", meta.id());
+        let mut rows = vec![
+            ("Chapter:", chapter.as_str()),
+            ("Difficulty:", &difficulty),
+            ("Time:", &time),
+            ("Tags:", &tags),
+        ];
+        if !meta.aliases.is_empty() {
+            rows.push(("Aliases:", &aliases));
+        }
+        if !sections.is_empty() {
+            rows.push(("Sections:", &sections));
+        }
+        rows.extend([("Requires:", requires.as_str()), ("See also:", &see_also)]);
+        let best = progress
+            .quiz_scores(&meta.id())
+            .iter()
+            .max_by_key(|q| q.correct)
+            .map(|q| format!(", best {} / {}", q.correct, q.total))
+            .unwrap_or_default();
+        let challenge = progress
+            .best_challenge(&meta.id())
+            .map(|d| format!(", challenge {:.1}s", d.as_secs_f64()))
+            .unwrap_or_default();
+        let quiz = format!(
+            "{} of {} questions per quiz{}{}",
+            l.quiz().len().min(QUIZ_LENGTH),
+            l.quiz().len(),
+            best,
+            challenge
+        );
+        if !l.quiz().is_empty() {
+            rows.push(("Quiz:", &quiz));
+        }
+        let checks = l.exercise().len();
+        let exercise = match progress.exercise(&meta.id()) {
+            Some(state) => match (state.solved, state.score()) {
+                (Some(at), Some(score)) => format!(
+                    "{} checks, solved {}, score {} / 100",
+                    checks,
+                    &at.to_string()[..10],
+                    score
+                ),
+                _ => format!("{} checks, {} passed", checks, state.passed.len()),
+            },
+            None => format!("{} checks, not started", checks),
+        };
+        if checks > 0 {
+            rows.push(("Exercise:", &exercise));
         }
+        let tests = progress
+            .tests_passed(&meta.id())
+            .map(|at| format!("passed {}", &at.to_string()[..10]))
+            .unwrap_or_default();
+        if !tests.is_empty() {
+            rows.push(("Tests:", &tests));
+        }
+        let confidence = progress
+            .confidence(&meta.id())
+            .map(|c| format!("{} / 5, rated {}", c.level, &c.at.to_string()[..10]))
+            .unwrap_or_default();
+        if !confidence.is_empty() {
+            rows.push(("Confidence:", &confidence));
+        }
+        if meta.deprecated {
+            rows.push(("Status:", &status));
+        }
+        rows.push(("Run:", &run));
+        write!(out, "{}", fmt::kv_table(&rows))?;
+        let notes = progress.notes(&meta.id());
+        if !notes.is_empty() {
+            writeln!(out, "\nNotes:")?;
+            write!(out, "{}", crate::notes::render(notes))?;
+        }
+    }
+    Ok(())
+}
+
+/// 按前置关系输出推荐学习顺序，已完成的 lesson 标记 ✓
+pub fn path(progress: &Progress, lang: Language, out: &mut dyn Write) -> Result<(), RunnerError> {
+    let order = learning_path().map_err(RunnerError::Config)?;
+    for (i, meta) in order.iter().enumerate() {
+        let mark = if progress.is_complete(&meta.id()) {
+            "✓"
+        } else {
+            " "
+        };
+        writeln!(
+            out,
+            "{:>2}. {} {:<22} {}",
+            i + 1,
+            fmt::text(mark),
+            meta.id(),
+            i18n::title(meta, lang)
+        )?;
+    }
+    let done = order
+        .iter()
+        .filter(|m| progress.is_complete(&m.id()))
+        .count();
+    writeln!(
+        out,
+        "\n{}",
+        i18n::tr_with(
+            lang,
+            "ui.completed",
+            &[
+                ("done", &done.to_string()),
+                ("total", &order.len().to_string())
+            ]
+        )
+    )?;
+    Ok(())
+}
+
+/// 个性化学习顺序：推荐顺序中去掉已完成的 lesson，测验已掌握的单独列出
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Plan {
+    /// 没完成但分班测验判定可以跳过、或测验最好成绩达到 [`grade::MASTERY`] 的 lesson 与其测验最好成绩
+    pub tested_out: Vec<(LessonMeta, Option<u32>)>,
+    /// 剩下要学的 lesson，保持前置关系的顺序
+    pub remaining: Vec<LessonMeta>,
+    pub complete: usize,
+}
+
+/// 按推荐顺序 `order` 与学习进度排出个性化的学习顺序；已弃用的 lesson 不再排入
+pub fn plan(order: &[LessonMeta], progress: &Progress) -> Plan {
+    let mut plan = Plan {
+        tested_out: Vec::new(),
+        remaining: Vec::new(),
+        complete: 0,
+    };
+    for (meta, g) in order.iter().zip(grade::lessons(progress, order)) {
+        if progress.is_complete(&g.id) {
+            plan.complete += 1;
+        } else if meta.deprecated {
+            continue;
+        } else if progress.tested_out(&g.id).is_some()
+            || g.quiz.is_some_and(|q| q >= grade::MASTERY)
+        {
+            plan.tested_out.push((*meta, g.quiz));
+        } else {
+            plan.remaining.push(*meta);
+        }
+    }
+    plan
+}
+
+/// `path --adaptive`：跳过已完成与测验已掌握的 lesson，输出剩下的学习顺序
+pub fn adaptive_path(
+    progress: &Progress,
+    lang: Language,
+    out: &mut dyn Write,
+) -> Result<(), RunnerError> {
+    let order = learning_path().map_err(RunnerError::Config)?;
+    let plan = plan(&order, progress);
+    if !plan.tested_out.is_empty() {
+        writeln!(
+            out,
+            "Tested out (by placement or quiz {}% or better):",
+            grade::MASTERY
+        )?;
+        for (meta, quiz) in &plan.tested_out {
+            writeln!(
+                out,
+                "    {} {:<22} {} {}",
+                fmt::text("↷"),
+                meta.id(),
+                i18n::title(meta, lang),
+                fmt::dim(&quiz.map_or(String::from("placement"), |q| format!("quiz {}%", q)))
+            )?;
+        }
+        writeln!(out)?;
+    }
+    if plan.remaining.is_empty() {
+        writeln!(out, "Nothing left to learn 🎉")?;
+    } else {
+        writeln!(out, "Your path:")?;
+        for (i, meta) in plan.remaining.iter().enumerate() {
+            writeln!(
+                out,
+                "{:>2}. {:<22} {}",
+                i + 1,
+                meta.id(),
+                i18n::title(meta, lang)
+            )?;
+        }
+    }
+    writeln!(
+        out,
+        "\n{} lessons left ({} complete, {} tested out)",
+        plan.remaining.len(),
+        plan.complete,
+        plan.tested_out.len()
+    )?;
+    Ok(())
+}
 
-        pub fn run_selected(sel: &str) -> Result<(), String> {
-            let lessons = all();
-            // 支持数字或 slug
-            if let Ok(n) = sel.parse::<usize>() {
-                if let Some(l) = lessons.iter().find(|l| l.number == n) {
-                    (l.run)();
-                    return Ok(());
+/// 运行选中的 lesson，成功后记入学习进度
+///
+/// 除非 `--no-related` 或 `--quiet`，前置 lesson 未完成时会给出提醒，
+/// 只选中一个时结尾给出相关 lesson 建议。`--format jsonl` 时标准输出只有事件流。
+/// `--step` 时分节的 lesson 逐节运行，每节之后询问是否继续；
+/// `--confidence` 时每个 lesson 运行完后请学习者自评掌握程度。
+pub fn run_selected(
+    sel: &str,
+    progress: &mut Progress,
+    opts: &Options,
+    out: &mut dyn Write,
+) -> Result<(), RunnerError> {
+    let picked = select(sel)?;
+    let text = opts.format == Format::Text && opts.verbosity > Verbosity::Quiet;
+    let hints = !opts.no_related && text;
+    let headers = picked.len() > 1 && text;
+    for l in &picked {
+        let meta = l.meta();
+        if headers {
+            writeln!(out, "{}", meta.header(opts.language))?;
+        }
+        if meta.deprecated {
+            match replacement(&meta) {
+                Some(new) => eprintln!(
+                    "Note: {} is deprecated, see {} instead",
+                    meta.id(),
+                    new.id()
+                ),
+                None => eprintln!("Note: {} is deprecated", meta.id()),
+            }
+        }
+        let missing: Vec<_> = prerequisites(&meta)
+            .iter()
+            .map(LessonMeta::id)
+            .filter(|id| !progress.is_complete(id))
+            .collect();
+        if hints && !missing.is_empty() {
+            eprintln!(
+                "{}",
+                i18n::tr_with(
+                    opts.language,
+                    "ui.builds_on",
+                    &[("lesson", &meta.id()), ("missing", &missing.join(", "))]
+                )
+            );
+        }
+        let start = Instant::now();
+        if opts.step && text && l.section.is_none() && !l.lesson.sections().is_empty() {
+            // 逐节运行的耗时包括阅读与确认的时间
+            let finished = run_steps(l.lesson, progress, opts, out);
+            record_run(progress, &l.id(), start.elapsed());
+            if finished? {
+                if opts.confidence {
+                    assess(progress, &meta.id(), opts.language);
                 }
+                continue;
             }
-            if let Some(l) = lessons.iter().find(|l| l.slug == sel) {
-                (l.run)();
-                return Ok(());
+            break;
+        }
+        let result = run_one(l, opts, out);
+        log::debug!("{} finished in {:?}", l.id(), start.elapsed());
+        record_run(progress, &l.id(), start.elapsed());
+        result?;
+        record(progress, l);
+        if opts.confidence && text && l.section.is_none() {
+            assess(progress, &meta.id(), opts.language);
+        }
+    }
+    // 只运行单个 lesson 时给出延伸阅读建议
+    if let [l] = picked.as_slice()
+        && hints
+    {
+        let ids: Vec<_> = related(&l.lesson.meta())
+            .iter()
+            .map(LessonMeta::id)
+            .collect();
+        if !ids.is_empty() {
+            writeln!(
+                out,
+                "\n{}",
+                i18n::tr_with(
+                    opts.language,
+                    "ui.see_also",
+                    &[("lessons", &ids.join(", "))]
+                )
+            )?;
+        }
+    }
+    Ok(())
+}
+
+/// 逐节运行，每节之后询问是否继续；返回是否运行完了全部小节
+fn run_steps(
+    lesson: &dyn Lesson,
+    progress: &mut Progress,
+    opts: &Options,
+    out: &mut dyn Write,
+) -> Result<bool, RunnerError> {
+    for (i, section) in lesson.sections().into_iter().enumerate() {
+        let next = i18n::tr_with(opts.language, "ui.step_next", &[("section", section.title)]);
+        if i > 0 && !io::confirm(&format!("\n{}", next))? {
+            return Ok(false);
+        }
+        let step = Selected {
+            lesson,
+            section: Some(section),
+        };
+        run_one(&step, opts, out)?;
+        record(progress, &step);
+    }
+    Ok(true)
+}
+
+/// 询问自评并记入学习进度；跳过或输入结束时不记录，保存失败只给出警告
+fn assess(progress: &mut Progress, id: &str, lang: Language) {
+    match ask_confidence(&mut io::Prompter::stdin(), id, lang) {
+        Ok(Some(level)) => {
+            if let Err(e) = progress.record_confidence(id, level) {
+                eprintln!("Warning: cannot save progress: {}", e);
             }
-            Err(format!("Lesson '{}' not found", sel))
         }
-    };
+        Ok(None) => {}
+        Err(e) => eprintln!("Warning: cannot read answer: {}", e),
+    }
+}
+
+/// 读取 1–5 的自评，直接回车或输入结束时为 `None`，其他输入重新提问
+fn ask_confidence<R: BufRead, W: Write>(
+    prompter: &mut io::Prompter<R, W>,
+    id: &str,
+    lang: Language,
+) -> std::io::Result<Option<u8>> {
+    let question = i18n::tr_with(lang, "ui.confidence", &[("lesson", id)]);
+    loop {
+        let answer = match prompter.prompt(&format!("\n{} ", question)) {
+            Ok(answer) => answer,
+            Err(e) if e.kind() == std::io::ErrorKind::UnexpectedEof => return Ok(None),
+            Err(e) => return Err(e),
+        };
+        match answer.parse::<u8>() {
+            _ if answer.is_empty() => return Ok(None),
+            Ok(level @ 1..=5) => return Ok(Some(level)),
+            _ => prompter.say(&i18n::tr(lang, "ui.confidence_invalid"))?,
+        }
+    }
+}
+
+/// 运行成功后记入学习进度，保存失败只给出警告
+fn record(progress: &mut Progress, picked: &Selected<impl Lesson>) {
+    if let Err(e) = mark_complete(progress, picked) {
+        eprintln!("Warning: cannot save progress: {}", e);
+    }
+}
+
+/// 记下运行日期与耗时（无论成败），供 `stats` 统计；保存失败只给出警告
+pub fn record_run(progress: &mut Progress, id: &str, elapsed: Duration) {
+    if let Err(e) = progress.record_run(id, elapsed) {
+        eprintln!("Warning: cannot save progress: {}", e);
+    }
+}
+
+/// 一个 lesson 的全部小节都完成后，lesson 本身也算完成
+fn mark_complete(progress: &mut Progress, picked: &Selected<impl Lesson>) -> std::io::Result<()> {
+    let meta = picked.lesson.meta();
+    progress.complete(&picked.id())?;
+    if picked.section.is_some()
+        && picked
+            .lesson
+            .sections()
+            .iter()
+            .all(|s| progress.is_complete(&section_id(&meta, s)))
+    {
+        progress.complete(&meta.id())?;
+    }
+    Ok(())
+}
+
+/// `complete <lesson>`：不运行，直接把选中的 lesson 或小节标记为已完成
+pub fn complete(
+    sel: &str,
+    progress: &mut Progress,
+    out: &mut dyn Write,
+) -> Result<(), RunnerError> {
+    for l in select(sel)? {
+        let done = progress.is_complete(&l.id());
+        mark_complete(progress, &l)?;
+        let at = progress.completed_at(&l.id()).map(|t| t.to_string());
+        match (done, at) {
+            (true, Some(at)) => writeln!(out, "{} already completed at {} UTC", l.id(), at)?,
+            _ => writeln!(out, "{} {} marked as completed", fmt::text("✓"), l.id())?,
+        }
+    }
+    Ok(())
+}
+
+/// 按 `--format` 把单个 lesson 的输出写到 `out`
+pub fn run_one(
+    lesson: &dyn Lesson,
+    opts: &Options,
+    out: &mut dyn Write,
+) -> Result<(), RunnerError> {
+    match opts.format {
+        Format::Text => run_guarded(lesson, &mut opts.context(out)),
+        Format::Jsonl => events::run_lesson(lesson, opts, out),
+        Format::Anki
+        | Format::Markdown
+        | Format::MdBook
+        | Format::Html
+        | Format::Json
+        | Format::Ipynb
+        | Format::Po
+        | Format::Csv => Err(RunnerError::Usage(String::from(
+            "--format anki, md, mdbook, html, json, ipynb and po only apply to export, csv to stats",
+        ))),
+    }
+}
+
+/// 运行单个 lesson，把返回的错误与 panic 都转换为 [`RunnerError`]
+pub fn run_guarded(lesson: &dyn Lesson, ctx: &mut LessonContext) -> Result<(), RunnerError> {
+    let id = || lesson.meta().id();
+    match panic::catch_unwind(AssertUnwindSafe(|| lesson.run(ctx))) {
+        Ok(Ok(())) => Ok(()),
+        Ok(Err(error)) => Err(RunnerError::LessonFailed {
+            lesson: id(),
+            error,
+        }),
+        Err(payload) => Err(RunnerError::LessonPanicked {
+            lesson: id(),
+            message: panic_message(&*payload),
+        }),
+    }
+}
+
+/// `catch_unwind` 得到的 panic 消息
+pub fn panic_message(payload: &(dyn Any + Send)) -> String {
+    payload
+        .downcast_ref::<&str>()
+        .map(|s| s.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| String::from("unknown panic"))
+}
+
+/// 选择器选中的一项：整个 lesson，或其中的一节
+pub struct Selected<L = &'static dyn Lesson> {
+    pub lesson: L,
+    pub section: Option<Section>,
+}
+
+impl<L: Lesson> Selected<L> {
+    /// 进度记录中的标识，例如 `06_ownership` 或 `16_iterators_closures:closure_capture`
+    pub fn id(&self) -> String {
+        let meta = self.lesson.meta();
+        match &self.section {
+            Some(section) => section_id(&meta, section),
+            None => meta.id(),
+        }
+    }
+}
+
+impl<L: Lesson> Lesson for Selected<L> {
+    fn meta(&self) -> LessonMeta {
+        self.lesson.meta()
+    }
+
+    fn run(&self, ctx: &mut LessonContext) -> lesson::Result {
+        match &self.section {
+            Some(section) => section.run_in(ctx),
+            None => self.lesson.run(ctx),
+        }
+    }
+
+    fn sections(&self) -> Vec<Section> {
+        match self.section {
+            Some(section) => vec![section],
+            None => self.lesson.sections(),
+        }
+    }
+
+    fn quiz(&self) -> Vec<Question> {
+        self.lesson.quiz()
+    }
+
+    fn exercise(&self) -> Vec<Check> {
+        self.lesson.exercise()
+    }
+
+    fn hints(&self) -> Vec<&'static str> {
+        self.lesson.hints()
+    }
+
+    fn koans(&self) -> Vec<Koan> {
+        self.lesson.koans()
+    }
+
+    fn pitfalls(&self) -> Vec<Pitfall> {
+        self.lesson.pitfalls()
+    }
+}
+
+fn section_id(meta: &LessonMeta, section: &Section) -> String {
+    format!("{}:{}", meta.id(), section.name)
+}
+
+/// 选择器的各种写法：例子与说明
+pub const SELECTORS: &[(&str, &str)] = &[
+    ("6", "编号"),
+    ("ownership, 06_ownership", "slug，或带编号的完整 id"),
+    ("own", "别名，在 curriculum.toml 或配置的 [aliases] 中声明"),
+    ("owner", "唯一的 slug 前缀"),
+    ("1-5", "编号区间"),
+    ("16_iterators_closures:closure_capture", "lesson 中的一节"),
+    ("1-3,09_structs", "逗号分隔的组合"),
+];
+
+/// 配置文件 `[aliases]` 中的别名，启动时由 [`set_aliases`] 设定
+static CONFIG_ALIASES: RwLock<BTreeMap<String, String>> = RwLock::new(BTreeMap::new());
+
+/// 设定配置中的别名；之后所有接受选择器的命令都按逗号分隔的每一部分展开它们
+pub fn set_aliases(aliases: BTreeMap<String, String>) {
+    *CONFIG_ALIASES
+        .write()
+        .unwrap_or_else(PoisonError::into_inner) = aliases;
+}
+
+/// 把选择器中是别名的部分换成别名的值，`别名:小节` 中的 lesson 部分同样替换；只展开一层
+fn expand_aliases(sel: &str, aliases: &BTreeMap<String, String>) -> String {
+    sel.split(',')
+        .map(str::trim)
+        .map(|part| match part.split_once(':') {
+            Some((lesson, section)) => match aliases.get(lesson) {
+                Some(value) => format!("{}:{}", value, section),
+                None => part.to_string(),
+            },
+            None => aliases
+                .get(part)
+                .cloned()
+                .unwrap_or_else(|| part.to_string()),
+        })
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// 解析选择器，写法见 [`SELECTORS`]。
+///
+/// 结果按选择器中出现的顺序排列，重复选中的项只保留第一次。
+pub fn select(sel: &str) -> Result<Vec<Selected>, RunnerError> {
+    let sel = &expand_aliases(
+        sel,
+        &CONFIG_ALIASES
+            .read()
+            .unwrap_or_else(PoisonError::into_inner),
+    );
+    let mut picked = Vec::new();
+    for part in sel.split(',').map(str::trim) {
+        if let Some((start, end)) = part.split_once('-')
+            && let (Ok(start), Ok(end)) = (start.parse::<usize>(), end.parse::<usize>())
+        {
+            let range = INDEX.range(start, end);
+            if range.is_empty() {
+                return Err(RunnerError::NotFound(part.to_string()));
+            }
+            picked.extend(range.into_iter().map(|i| (i, None)));
+            continue;
+        }
+        match part.split_once(':') {
+            Some((lesson, section)) => picked.push((INDEX.find(lesson)?, Some(section))),
+            None => picked.push((INDEX.find(part)?, None)),
+        }
+    }
+    let mut seen = BTreeSet::new();
+    picked.retain(|&pick| seen.insert(pick));
+
+    let mut selected = Vec::with_capacity(picked.len());
+    for (i, name) in picked {
+        let lesson = all()[i].as_ref();
+        let section = match name {
+            Some(name) => Some(
+                lesson
+                    .sections()
+                    .into_iter()
+                    .find(|s| s.name == name)
+                    .ok_or_else(|| {
+                        RunnerError::NotFound(format!("{}:{}", lesson.meta().id(), name))
+                    })?,
+            ),
+            None => None,
+        };
+        selected.push(Selected { lesson, section });
+    }
+    log::debug!(
+        "selector '{}' -> [{}]",
+        sel,
+        selected
+            .iter()
+            .map(Selected::id)
+            .collect::<Vec<_>>()
+            .join(", ")
+    );
+    Ok(selected)
+}
+
+/// 同 [`select`]，但只关心选中了哪些 lesson
+pub fn resolve(sel: &str) -> Result<Vec<&'static dyn Lesson>, RunnerError> {
+    let mut numbers = BTreeSet::new();
+    Ok(select(sel)?
+        .into_iter()
+        .filter(|s| numbers.insert(s.lesson.meta().number))
+        .map(|s| s.lesson)
+        .collect())
 }
 
-// —— 在这里登记全部 lesson ——
-register_lessons!(
-    1,
-    hello_world,
-    "Hello, world & Project Layout",
-    hello_world,
-    2,
-    variables,
-    "Variables & Mutability",
-    variables,
-    3,
-    types,
-    "Scalar & Compound Types",
-    types,
-    4,
-    functions,
-    "Functions & Parameters",
-    functions,
-    5,
-    control_flow,
-    "if / loop / while / match",
-    control_flow,
-    6,
-    ownership,
-    "Ownership Basics",
-    ownership,
-    7,
-    borrowing,
-    "Borrowing & References",
-    borrowing,
-    8,
-    slices,
-    "String & Array Slices",
-    slices,
-    9,
-    structs,
-    "Structs & Update Syntax",
-    structs,
-    10,
-    enums_matching,
-    "Enums & Pattern Matching",
-    enums_matching,
-    11,
-    methods_assoc_fn,
-    "Methods & Associated Fns",
-    methods_assoc_fn,
-    12,
-    generics,
-    "Generics",
-    generics,
-    13,
-    traits,
-    "Traits & Trait Bounds",
-    traits,
-    14,
-    lifetimes,
-    "Lifetimes Basics",
-    lifetimes,
-    15,
-    collections,
-    "Vec / String / HashMap",
-    collections,
-    16,
-    iterators_closures,
-    "Iterators & Closures",
-    iterators_closures,
-    17,
-    error_handling,
-    "Result / Option / ? operator",
-    error_handling,
-    18,
-    modules_crates,
-    "Modules / Crates / Paths",
-    modules_crates,
-    19,
-    macros_basics,
-    "Macros Basics",
-    macros_basics,
-);
+/// 相关 lesson 关系表：slug → 学完后推荐继续阅读的 slug
+const RELATED: &[(&str, &[&str])] = &[
+    ("hello_world", &["variables", "functions"]),
+    ("variables", &["types", "control_flow"]),
+    ("types", &["variables", "collections"]),
+    ("functions", &["control_flow", "methods_assoc_fn"]),
+    ("control_flow", &["enums_matching", "iterators_closures"]),
+    ("ownership", &["borrowing", "slices"]),
+    ("borrowing", &["ownership", "slices", "lifetimes"]),
+    ("slices", &["borrowing", "collections"]),
+    ("structs", &["methods_assoc_fn", "enums_matching"]),
+    ("enums_matching", &["control_flow", "error_handling"]),
+    ("methods_assoc_fn", &["structs", "traits"]),
+    ("generics", &["traits", "collections"]),
+    ("traits", &["generics", "lifetimes"]),
+    ("lifetimes", &["borrowing", "traits"]),
+    ("collections", &["slices", "iterators_closures"]),
+    ("iterators_closures", &["collections", "traits"]),
+    ("error_handling", &["enums_matching", "traits"]),
+    ("modules_crates", &["macros_basics"]),
+    ("macros_basics", &["modules_crates", "generics"]),
+];
+
+/// 查询与某个 lesson 相关的其他 lesson（按关系表中的顺序）
+pub fn related(lesson: &LessonMeta) -> Vec<LessonMeta> {
+    RELATED
+        .iter()
+        .find(|(slug, _)| *slug == lesson.slug)
+        .map(|(_, slugs)| {
+            slugs
+                .iter()
+                .filter_map(|s| INDEX.by_slug(s).copied())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// 某个 lesson 声明的前置 lesson
+pub fn prerequisites(lesson: &LessonMeta) -> Vec<LessonMeta> {
+    lesson
+        .prerequisites
+        .iter()
+        .filter_map(|s| INDEX.by_slug(s).copied())
+        .collect()
+}
+
+/// 推荐学习顺序：前置 lesson 总排在前面，可同时学习的按编号先后
+///
+/// 前置 lesson 不存在或前置关系成环时返回错误。
+pub fn learning_path() -> Result<Vec<LessonMeta>, String> {
+    sort_by_prerequisites(&INDEX.metas)
+}
+
+/// 启动时检查注册表：编号、slug 与别名唯一（lesson 包可能与内置 lesson 冲突），前置关系图无环
+pub fn validate() -> Result<(), String> {
+    check_unique(&INDEX.metas)?;
+    sort_by_prerequisites(&INDEX.metas).map(|_| ())
+}
+
+fn check_unique(metas: &[LessonMeta]) -> Result<(), String> {
+    for (i, meta) in metas.iter().enumerate() {
+        if let Some(other) = metas[..i]
+            .iter()
+            .find(|m| m.number == meta.number || m.slug == meta.slug)
+        {
+            return Err(format!(
+                "Lessons '{}' and '{}' share a number or slug",
+                other.id(),
+                meta.id()
+            ));
+        }
+        if let Some((other, name)) = metas[..i].iter().find_map(|m| {
+            m.names()
+                .find(|name| meta.names().any(|n| n == *name))
+                .map(|name| (m, name))
+        }) {
+            return Err(format!(
+                "Lessons '{}' and '{}' both use the name '{}'",
+                other.id(),
+                meta.id(),
+                name
+            ));
+        }
+    }
+    Ok(())
+}
+
+fn sort_by_prerequisites(metas: &[LessonMeta]) -> Result<Vec<LessonMeta>, String> {
+    for meta in metas {
+        if let Some(req) = meta
+            .prerequisites
+            .iter()
+            .find(|req| !metas.iter().any(|m| m.slug == **req))
+        {
+            return Err(format!(
+                "Lesson '{}' requires unknown lesson '{}'",
+                meta.id(),
+                req
+            ));
+        }
+    }
+    let mut done = BTreeSet::new();
+    let mut order = Vec::with_capacity(metas.len());
+    while order.len() < metas.len() {
+        // 每轮取编号最小的、前置已全部排好的 lesson
+        let next = metas
+            .iter()
+            .filter(|m| !done.contains(m.slug))
+            .filter(|m| m.prerequisites.iter().all(|req| done.contains(req)))
+            .min_by_key(|m| m.number);
+        let Some(meta) = next else {
+            let stuck: Vec<_> = metas
+                .iter()
+                .filter(|m| !done.contains(m.slug))
+                .map(LessonMeta::id)
+                .collect();
+            return Err(format!("Prerequisite cycle among: {}", stuck.join(", ")));
+        };
+        done.insert(meta.slug);
+        order.push(*meta);
+    }
+    Ok(order)
+}
+
+/// 注册表索引：编号与名字（id、slug、别名）到注册表下标的映射，首次查询时建立
+///
+/// 下标对应 [`all`] 的顺序。选择器的解析规则都在这里。
+struct Index {
+    metas: Vec<LessonMeta>,
+    unavailable: &'static [Unavailable],
+    by_number: HashMap<usize, usize>,
+    by_name: HashMap<String, usize>,
+}
+
+static INDEX: LazyLock<Index> =
+    LazyLock::new(|| Index::new(all().iter().map(|l| l.meta()).collect(), UNAVAILABLE));
+
+impl Index {
+    /// 重名时保留先出现的 lesson；重名本身由 [`validate`] 报告
+    fn new(metas: Vec<LessonMeta>, unavailable: &'static [Unavailable]) -> Index {
+        let mut by_number = HashMap::new();
+        let mut by_name = HashMap::new();
+        for (i, meta) in metas.iter().enumerate() {
+            by_number.entry(meta.number).or_insert(i);
+            by_name.entry(meta.id()).or_insert(i);
+            for name in meta.names() {
+                by_name.entry(name.to_string()).or_insert(i);
+            }
+        }
+        Index {
+            metas,
+            unavailable,
+            by_number,
+            by_name,
+        }
+    }
+
+    fn by_slug(&self, slug: &str) -> Option<&LessonMeta> {
+        self.by_name
+            .get(slug)
+            .map(|&i| &self.metas[i])
+            .filter(|m| m.slug == slug)
+    }
+
+    /// 编号在 `start..=end` 内的 lesson，按编号排列
+    fn range(&self, start: usize, end: usize) -> Vec<usize> {
+        (0..self.metas.len())
+            .filter(|&i| (start..=end).contains(&self.metas[i].number))
+            .collect()
+    }
+
+    /// 依次按编号、id / slug / 别名、唯一的 slug 前缀查找；
+    /// 精确指向未编译的 lesson 时返回提示所需 feature 的错误
+    fn find(&self, sel: &str) -> Result<usize, RunnerError> {
+        if let Some(&i) = sel
+            .parse::<usize>()
+            .ok()
+            .and_then(|n| self.by_number.get(&n))
+        {
+            return Ok(i);
+        }
+        if let Some(&i) = self.by_name.get(sel) {
+            return Ok(i);
+        }
+        if let Some(missing) = self.unavailable.iter().find(|u| u.matches(sel)) {
+            return Err(missing.error());
+        }
+        let matches: Vec<usize> = (0..self.metas.len())
+            .filter(|&i| !sel.is_empty() && self.metas[i].slug.starts_with(sel))
+            .collect();
+        match matches.as_slice() {
+            [] => Err(RunnerError::NotFound(sel.to_string())),
+            [i] => Ok(*i),
+            _ => Err(RunnerError::Ambiguous {
+                selector: sel.to_string(),
+                candidates: matches.iter().map(|&i| self.metas[i].id()).collect(),
+            }),
+        }
+    }
+}
+
+// 全部 lesson 由 build.rs 根据 curriculum.toml 生成
+include!(concat!(env!("OUT_DIR"), "/registry.rs"));
+
+/// 课程大纲原文（编译时嵌入），供导出等工具复用顺序与依赖数据
+pub const CURRICULUM: &str = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/curriculum.toml"));
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn numbers(sel: &str) -> Vec<usize> {
+        resolve(sel)
+            .unwrap()
+            .iter()
+            .map(|l| l.meta().number)
+            .collect()
+    }
+
+    #[test]
+    fn test_resolve_number_and_slug() {
+        assert_eq!(numbers("6"), vec![6]);
+        assert_eq!(numbers("ownership"), vec![6]);
+        assert_eq!(numbers("06_ownership"), vec![6]);
+    }
+
+    #[test]
+    fn test_resolve_range_and_list() {
+        assert_eq!(numbers("1-5"), vec![1, 2, 3, 4, 5]);
+        assert_eq!(numbers("1-2, 09_structs"), vec![1, 2, 9]);
+        assert_eq!(numbers("3,1-3"), vec![3, 1, 2]);
+    }
+
+    #[test]
+    #[cfg_attr(not(feature = "advanced"), ignore = "needs the full curriculum")]
+    fn test_related_table_is_consistent() {
+        let metas: Vec<_> = all().iter().map(|l| l.meta()).collect();
+        for (slug, targets) in RELATED {
+            assert!(
+                metas.iter().any(|m| m.slug == *slug),
+                "unknown slug {}",
+                slug
+            );
+            for target in *targets {
+                assert!(
+                    metas.iter().any(|m| m.slug == *target),
+                    "unknown slug {}",
+                    target
+                );
+                assert_ne!(slug, target);
+            }
+        }
+        let traits = resolve("13").unwrap()[0].meta();
+        let ids: Vec<_> = related(&traits).iter().map(LessonMeta::id).collect();
+        assert_eq!(ids, vec!["12_generics", "14_lifetimes"]);
+    }
+
+    #[test]
+    fn test_prerequisites_exist() {
+        for l in all() {
+            let meta = l.meta();
+            assert_eq!(
+                prerequisites(&meta).len(),
+                meta.prerequisites.len(),
+                "{} declares an unknown prerequisite",
+                meta.id()
+            );
+            assert!(!meta.tags.is_empty(), "{} has no tags", meta.id());
+            assert!(meta.minutes > 0);
+        }
+    }
+
+    #[test]
+    #[cfg_attr(not(feature = "advanced"), ignore = "needs the full curriculum")]
+    fn test_chapters_follow_lesson_order() {
+        let chapters: Vec<_> = all().iter().map(|l| l.meta().chapter).collect();
+        assert!(chapters.windows(2).all(|w| w[0] <= w[1]));
+        assert!(Chapter::ALL.iter().all(|c| chapters.contains(c)));
+    }
+
+    fn node(number: usize, slug: &'static str, requires: &'static [&'static str]) -> LessonMeta {
+        LessonMeta {
+            number,
+            slug,
+            aliases: &[],
+            title: slug,
+            chapter: Chapter::Basics,
+            difficulty: Difficulty::Beginner,
+            minutes: 10,
+            tags: &[],
+            prerequisites: requires,
+            deprecated: false,
+            replaced_by: None,
+        }
+    }
+
+    #[test]
+    fn test_learning_path_respects_prerequisites() {
+        let order = learning_path().unwrap();
+        assert_eq!(order.len(), all().len());
+        for (i, meta) in order.iter().enumerate() {
+            for req in meta.prerequisites {
+                assert!(order[..i].iter().any(|m| m.slug == *req));
+            }
+        }
+
+        let metas = [node(1, "a", &["b"]), node(2, "b", &[]), node(3, "c", &[])];
+        let slugs: Vec<_> = sort_by_prerequisites(&metas)
+            .unwrap()
+            .iter()
+            .map(|m| m.slug)
+            .collect();
+        assert_eq!(slugs, vec!["b", "a", "c"]);
+    }
+
+    #[test]
+    fn test_plan() {
+        let metas = [
+            node(1, "a", &[]),
+            node(2, "b", &["a"]),
+            node(3, "c", &["b"]),
+        ];
+        let mut progress = Progress::default();
+        progress.complete("01_a").unwrap();
+        progress.record_quiz("02_b", 5, 5).unwrap();
+        progress.record_quiz("03_c", 3, 5).unwrap();
+        let plan = super::plan(&metas, &progress);
+        assert_eq!(plan.complete, 1);
+        assert_eq!(plan.tested_out, vec![(metas[1], Some(100))]);
+        assert_eq!(plan.remaining, vec![metas[2]]);
+
+        progress.record_placement(&["03_c".to_string()]).unwrap();
+        let plan = super::plan(&metas, &progress);
+        assert_eq!(plan.tested_out[1], (metas[2], Some(60)));
+        assert!(plan.remaining.is_empty());
+    }
+
+    #[test]
+    fn test_duplicate_lessons_are_rejected() {
+        assert!(validate().is_ok());
+        let metas = [node(1, "a", &[]), node(2, "b", &[]), node(2, "c", &[])];
+        assert_eq!(
+            check_unique(&metas).unwrap_err(),
+            "Lessons '02_b' and '02_c' share a number or slug"
+        );
+        assert!(check_unique(&[node(1, "a", &[]), node(3, "a", &[])]).is_err());
+
+        let aliased = LessonMeta {
+            aliases: &["b"],
+            ..node(1, "a", &[])
+        };
+        assert_eq!(
+            check_unique(&[aliased, node(2, "b", &[])]).unwrap_err(),
+            "Lessons '01_a' and '02_b' both use the name 'b'"
+        );
+    }
+
+    #[test]
+    fn test_registry_check() {
+        check_registry(&[1, 2, 5], &["a", "b", "c"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "ascending order")]
+    fn test_registry_check_rejects_duplicate_numbers() {
+        check_registry(&[1, 2, 2], &["a", "b", "c"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "duplicate lesson slug")]
+    fn test_registry_check_rejects_duplicate_slugs() {
+        check_registry(&[1, 2, 3], &["a", "b", "a"]);
+    }
+
+    #[test]
+    fn test_invalid_prerequisite_graph() {
+        let cycle = [
+            node(1, "a", &["c"]),
+            node(2, "b", &["a"]),
+            node(3, "c", &["b"]),
+            node(4, "d", &[]),
+        ];
+        let err = sort_by_prerequisites(&cycle).unwrap_err();
+        assert_eq!(err, "Prerequisite cycle among: 01_a, 02_b, 03_c");
+
+        let unknown = [node(1, "a", &["zzz"])];
+        assert!(sort_by_prerequisites(&unknown).unwrap_err().contains("zzz"));
+    }
+
+    #[test]
+    fn test_resolve_not_found() {
+        assert!(resolve("nope").is_err());
+        assert!(resolve("90-99").is_err());
+        assert!(resolve("1,").is_err());
+    }
+
+    #[test]
+    #[cfg_attr(not(feature = "basics"), ignore = "needs the basics lessons")]
+    fn test_resolve_alias() {
+        assert_eq!(numbers("own"), [6]);
+        assert_eq!(numbers("errors,hello"), [17, 1]);
+    }
+
+    #[test]
+    fn test_resolve_slug_prefix() {
+        let index = Index::new(
+            vec![
+                node(8, "slices", &[]),
+                node(9, "structs", &[]),
+                node(6, "ownership", &[]),
+            ],
+            &[],
+        );
+        assert_eq!(index.find("own").unwrap(), 2);
+        assert_eq!(index.find("str").unwrap(), 1);
+        match index.find("s") {
+            Err(RunnerError::Ambiguous { candidates, .. }) => {
+                assert_eq!(candidates, ["08_slices", "09_structs"])
+            }
+            other => panic!("expected ambiguous selector, got {:?}", other),
+        }
+        assert!(matches!(index.find("x"), Err(RunnerError::NotFound(_))));
+    }
+
+    #[test]
+    fn test_expand_aliases() {
+        let aliases = BTreeMap::from([
+            (String::from("b5"), String::from("1-2")),
+            (String::from("own"), String::from("06_ownership")),
+            (String::from("loop"), String::from("own")),
+        ]);
+        assert_eq!(expand_aliases("b5", &aliases), "1-2");
+        assert_eq!(expand_aliases("3, b5,own", &aliases), "3,1-2,06_ownership");
+        assert_eq!(
+            expand_aliases("own:scope_drop", &aliases),
+            "06_ownership:scope_drop"
+        );
+        assert_eq!(expand_aliases("loop", &aliases), "own");
+        assert_eq!(expand_aliases("6", &aliases), "6");
+    }
+
+    #[test]
+    #[cfg_attr(not(feature = "basics"), ignore = "needs the basics lessons")]
+    fn test_select_section() {
+        let picked =
+            select("16:closure_capture, 16_iterators_closures:closure_capture, 16").unwrap();
+        let ids: Vec<_> = picked.iter().map(Selected::id).collect();
+        assert_eq!(
+            ids,
+            [
+                "16_iterators_closures:closure_capture",
+                "16_iterators_closures"
+            ]
+        );
+        assert_eq!(picked[0].sections().len(), 1);
+        assert_eq!(numbers("16:closures,16:closure_capture"), [16]);
+        assert!(matches!(select("16:nope"), Err(RunnerError::NotFound(_))));
+    }
+
+    #[test]
+    fn test_section_names_are_unique() {
+        for l in all() {
+            let names: Vec<_> = l.sections().iter().map(|s| s.name).collect();
+            let unique: BTreeSet<_> = names.iter().collect();
+            assert_eq!(unique.len(), names.len(), "{}", l.meta().id());
+            assert!(
+                names
+                    .iter()
+                    .all(|n| !n.is_empty() && !n.contains([':', ',']))
+            );
+        }
+    }
+
+    #[test]
+    fn test_every_lesson_has_a_quiz() {
+        for l in all() {
+            let quiz = l.quiz();
+            // 题库比每次提问的多，重做测验才会遇到不同的题
+            let bank = QUIZ_LENGTH + 1..=8;
+            assert!(bank.contains(&quiz.len()), "{}", l.meta().id());
+            for q in &quiz {
+                assert!(q.choices.len() >= 2 && q.answer < q.choices.len());
+                let unique: BTreeSet<_> = q.choices.iter().collect();
+                assert_eq!(unique.len(), q.choices.len(), "{}", q.prompt);
+            }
+        }
+    }
+
+    #[test]
+    fn test_every_lesson_has_an_exercise() {
+        for l in all() {
+            let checks = l.exercise();
+            assert!(checks.len() >= 2, "{}", l.meta().id());
+            let names: BTreeSet<_> = checks.iter().map(|c| c.name).collect();
+            assert_eq!(names.len(), checks.len(), "{}", l.meta().id());
+            assert_eq!(l.hints().len(), 3, "{}", l.meta().id());
+            assert!(l.koans().len() >= 2, "{}", l.meta().id());
+        }
+    }
+
+    #[test]
+    fn test_index_lookup() {
+        let aliased = LessonMeta {
+            aliases: &["own"],
+            ..node(6, "ownership", &[])
+        };
+        let index = Index::new(
+            vec![node(1, "hello", &[]), aliased, node(6, "dup", &[])],
+            &[],
+        );
+        assert_eq!(index.find("6").unwrap(), 1);
+        assert_eq!(index.find("01_hello").unwrap(), 0);
+        assert_eq!(index.find("own").unwrap(), 1);
+        assert_eq!(index.by_slug("ownership").unwrap().number, 6);
+        assert!(index.by_slug("own").is_none());
+        assert_eq!(index.range(2, 9), [1, 2]);
+    }
+
+    #[test]
+    fn test_unavailable_lesson() {
+        const MISSING: &[Unavailable] = &[Unavailable {
+            number: 20,
+            slug: "http_client",
+            aliases: &["http"],
+            title: "HTTP",
+            chapter: Chapter::Advanced,
+            feature: "advanced",
+        }];
+        let index = Index::new(vec![node(1, "hello", &[])], MISSING);
+        for sel in ["20", "20_http_client", "http_client", "http"] {
+            match index.find(sel) {
+                Err(RunnerError::Unavailable { lesson, feature }) => {
+                    assert_eq!(
+                        (lesson.as_str(), feature.as_str()),
+                        ("20_http_client", "advanced")
+                    )
+                }
+                other => panic!("expected unavailable lesson for {}, got {:?}", sel, other),
+            }
+        }
+        assert!(matches!(index.find("htt"), Err(RunnerError::NotFound(_))));
+    }
+
+    struct Sectioned;
+
+    impl Lesson for Sectioned {
+        fn meta(&self) -> LessonMeta {
+            node(98, "sectioned", &[])
+        }
+
+        fn run(&self, ctx: &mut LessonContext) -> lesson::Result {
+            lesson::run_sections(ctx, &self.sections())
+        }
+
+        fn sections(&self) -> Vec<Section> {
+            let noop = |_: &mut LessonContext| Ok(());
+            vec![Section::new("a", "A", noop), Section::new("b", "B", noop)]
+        }
+    }
+
+    #[test]
+    #[cfg_attr(not(feature = "basics"), ignore = "needs the basics lessons")]
+    fn test_selected_forwards_pitfalls() {
+        for sel in ["6", "6:scope_drop"] {
+            let picked = select(sel).unwrap();
+            assert_eq!(
+                picked[0].pitfalls().len(),
+                picked[0].lesson.pitfalls().len()
+            );
+            assert!(!picked[0].pitfalls().is_empty());
+        }
+    }
+
+    #[test]
+    fn test_section_progress() {
+        let mut progress = Progress::default();
+        let pick = |name| Selected {
+            lesson: &Sectioned,
+            section: Sectioned.sections().into_iter().find(|s| s.name == name),
+        };
+        record(&mut progress, &pick("a"));
+        assert!(progress.is_complete("98_sectioned:a"));
+        assert!(!progress.is_complete("98_sectioned"));
+        record(&mut progress, &pick("b"));
+        assert!(progress.is_complete("98_sectioned"));
+    }
+
+    #[test]
+    fn test_ask_confidence() {
+        let ask = |input: &str| {
+            let mut output = Vec::new();
+            let mut prompter = io::Prompter::new(input.as_bytes(), &mut output);
+            let level = ask_confidence(&mut prompter, "06_ownership", Language::En).unwrap();
+            (level, String::from_utf8(output).unwrap())
+        };
+        let (level, output) = ask("7\nthree\n3\n");
+        assert_eq!(level, Some(3));
+        assert!(output.starts_with("\nHow confident are you with 06_ownership (1-5"));
+        assert_eq!(
+            output.matches("Please enter a number from 1 to 5").count(),
+            2
+        );
+        assert_eq!(ask("\n5\n").0, None);
+        assert_eq!(ask("").0, None);
+    }
+
+    struct Panicking;
+
+    impl Lesson for Panicking {
+        fn meta(&self) -> LessonMeta {
+            node(99, "panicking", &[])
+        }
+
+        fn run(&self, _ctx: &mut LessonContext) -> lesson::Result {
+            panic!("boom")
+        }
+    }
+
+    #[test]
+    fn test_run_guarded_catches_panic() {
+        let mut out = Vec::new();
+        let err = run_guarded(&Panicking, &mut LessonContext::new(&mut out)).unwrap_err();
+        assert_eq!(err.to_string(), "Lesson '99_panicking' panicked: boom");
+        assert_eq!(err.exit_code(), 101);
+    }
+}
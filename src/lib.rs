@@ -0,0 +1,100 @@
+//! rust-learn：可运行的 Rust 入门课程
+//!
+//! 这是 workspace 中的运行器：注册表、命令行与各种运行方式都在这个库里，`main.rs` 只是命令行外壳；
+//! lesson 抽象在 `rust-learn-core` 中，lesson 本身按难度分在 `lessons-basics` / `lessons-advanced` 两个 crate。
+//! 测试、其他二进制或外部工具可以直接调用：
+//!
+//! ```no_run
+//! use rust_learn::report::RunOptions;
+//!
+//! let report = rust_learn::run_lesson("13_traits", RunOptions::default()).unwrap();
+//! println!("{} ({:?})", report.output, report.duration);
+//! ```
+
+pub mod app;
+pub mod badge;
+pub mod bench;
+pub mod bookmark;
+pub mod certificate;
+pub mod challenge;
+pub mod check;
+pub mod classroom;
+pub mod cli;
+pub mod config;
+pub mod coverage;
+pub mod curriculum;
+#[cfg(feature = "hot-reload")]
+pub mod dev;
+pub mod doctests;
+pub mod error;
+pub mod events;
+pub mod exercise;
+pub mod explain_error;
+pub mod export;
+pub mod grade;
+pub mod highlight;
+pub mod junit;
+pub mod kata;
+pub mod koans;
+pub mod lessons;
+pub mod lint;
+pub mod logging;
+pub mod manpage;
+pub mod mentor;
+pub mod notebook;
+pub mod notes;
+pub mod pack;
+pub mod pitfalls;
+pub mod placement;
+pub mod practice;
+pub mod progress;
+pub mod project;
+pub mod quiz;
+pub mod readme;
+pub mod recommend;
+pub mod record;
+pub mod renumber;
+pub mod repl;
+pub mod report;
+pub mod reset;
+pub mod runner;
+pub mod scaffold;
+pub mod schema;
+pub mod serve;
+pub mod share;
+pub mod share_output;
+pub mod show;
+pub mod site;
+pub mod stats;
+pub mod testing;
+pub mod today;
+pub mod transcript;
+pub mod translations;
+pub mod utils;
+
+// lesson 抽象、翻译与共用工具在 rust-learn-core 中，各 lesson crate 也依赖它
+pub use rust_learn_core::{i18n, lesson};
+
+use error::RunnerError;
+use report::{LessonReport, RunOptions};
+
+/// 运行选中的 lesson（选择器语法同命令行）并收集输出，见 [`report::run`]
+pub fn run_lesson(sel: &str, opts: RunOptions) -> Result<LessonReport, RunnerError> {
+    report::run(sel, opts)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_run_lesson() {
+        // `testing::run` 在时限内调用 `run_lesson`
+        let report = testing::run("13_traits", RunOptions::default()).unwrap();
+        assert!(report.is_ok());
+        assert!(!report.output.is_empty());
+        let err = testing::run("nope", RunOptions::default()).unwrap_err();
+        assert!(matches!(err, RunnerError::NotFound(_)));
+        assert_eq!(err.to_string(), "Lesson 'nope' not found");
+    }
+}
@@ -0,0 +1,170 @@
+//! 维护命令 `lint-lessons`：检查 lesson 文件是否符合完成标准
+//!
+//! 每个 lesson 文件必须有：
+//!
+//! - 以 `//! # 标题` 开头的模块注释，包含 `## 要点`、`## 常见坑`、`## 运行` 三节，
+//!   `## 运行` 下一行是 `` `cargo run -- <id>` ``，id 与大纲中的编号一致；
+//! - `pub fn run(ctx: &mut LessonContext) -> lesson::Result` 入口、测验题 `pub const QUIZ`、练习检查 `pub const EXERCISE`、提示 `pub const HINTS`、填空练习 `pub const KOANS` 与常见坑 `pub const PITFALLS`；
+//! - 至少一个 `#[test]`；
+//! - 没有残留由 `new` 生成的 `TODO`。
+//!
+//! 检查源码树中的全部 lesson（包括未启用 feature 的），有违规时以失败退出，
+//! CI 中与 `cargo clippy` 一起运行。
+
+use crate::curriculum::{self, Entry};
+use crate::error::RunnerError;
+use std::fs;
+use std::io::Write;
+
+const SECTIONS: &[&str] = &["## 要点", "## 常见坑", "## 运行"];
+const RUN_SIGNATURE: &str = "pub fn run(ctx: &mut LessonContext) -> lesson::Result";
+const DECLARATIONS: &[&str] = &[
+    "pub const QUIZ: &[Question]",
+    "pub const EXERCISE: &[Check]",
+    "pub const HINTS: &[&str]",
+    "pub const KOANS: &[Koan]",
+    "pub const PITFALLS: &[Pitfall]",
+];
+
+/// 检查全部 lesson，打印违规项
+pub fn run(out: &mut dyn Write) -> Result<(), RunnerError> {
+    let (_, lessons) = curriculum::read()?;
+    let mut failed = Vec::new();
+    for lesson in &lessons {
+        let path = lesson.file();
+        let text = fs::read_to_string(&path)?;
+        let violations = check(lesson, &text);
+        for violation in &violations {
+            let shown = path.strip_prefix(curriculum::root()).unwrap_or(&path);
+            writeln!(out, "{}: {}", shown.display(), violation)?;
+        }
+        if !violations.is_empty() {
+            failed.push(lesson.id());
+        }
+    }
+    if !failed.is_empty() {
+        return Err(RunnerError::Failed(failed));
+    }
+    writeln!(out, "{} lessons OK", lessons.len())?;
+    Ok(())
+}
+
+/// 检查一个 lesson 文件，返回违规说明
+fn check(lesson: &Entry, text: &str) -> Vec<String> {
+    let docs: Vec<&str> = text
+        .lines()
+        .take_while(|l| l.starts_with("//!"))
+        .map(|l| l.trim_start_matches("//!").trim())
+        .collect();
+    let mut violations = Vec::new();
+
+    if !docs.first().is_some_and(|l| l.starts_with("# ")) {
+        violations.push(String::from("module docs must start with `//! # <title>`"));
+    }
+    for section in SECTIONS {
+        if !docs.contains(section) {
+            violations.push(format!("missing `{}` section in module docs", section));
+        }
+    }
+    let expected = format!("`cargo run -- {}`", lesson.id());
+    if let Some(i) = docs.iter().position(|l| *l == "## 运行") {
+        match docs.get(i + 1) {
+            Some(line) if *line == expected => {}
+            Some(line) => {
+                violations.push(format!("`## 运行` shows {}, expected {}", line, expected))
+            }
+            None => violations.push(format!("`## 运行` must be followed by {}", expected)),
+        }
+    }
+    if !text.lines().any(|l| l.starts_with(RUN_SIGNATURE)) {
+        violations.push(format!("missing entry `{}`", RUN_SIGNATURE));
+    }
+    for declaration in DECLARATIONS {
+        if !text.lines().any(|l| l.starts_with(declaration)) {
+            violations.push(format!("missing `{}`", declaration));
+        }
+    }
+    if !text.lines().any(|l| l.trim() == "#[test]") {
+        violations.push(String::from("no `#[test]`"));
+    }
+    for (number, line) in (1..).zip(text.lines()) {
+        if line.contains("TODO") {
+            violations.push(format!("line {}: unfinished TODO", number));
+        }
+    }
+    violations
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GOOD: &str = "//! # Demo\n//!\n//! ## 要点\n//! - a\n//!\n//! ## 常见坑\n//! - b\n//!\n\
+                        //! ## 运行\n//! `cargo run -- 03_demo`\n\n\
+                        pub const QUIZ: &[Question] = &[];\n\n\
+                        pub const EXERCISE: &[Check] = &[];\n\n\
+                        pub const HINTS: &[&str] = &[];\n\n\
+                        pub const KOANS: &[Koan] = &[];\n\n\
+                        pub const PITFALLS: &[Pitfall] = &[];\n\n\
+                        pub fn run(ctx: &mut LessonContext) -> lesson::Result {\n    Ok(())\n}\n\n\
+                        #[cfg(test)]\nmod tests {\n    #[test]\n    fn t() {}\n}\n";
+
+    fn demo() -> Entry {
+        Entry {
+            number: 3,
+            slug: String::from("demo"),
+            aliases: Vec::new(),
+            chapter: String::from("Basics"),
+            book: Vec::new(),
+            feature: String::from("basics"),
+        }
+    }
+
+    #[test]
+    fn test_good_lesson() {
+        assert_eq!(check(&demo(), GOOD), Vec::<String>::new());
+    }
+
+    #[test]
+    fn test_violations() {
+        let text = GOOD
+            .replace("//! ## 常见坑\n", "")
+            .replace("03_demo", "02_demo")
+            .replace("#[test]", "// TODO")
+            .replace("pub fn run", "fn run")
+            .replace("pub const QUIZ", "const QUIZ")
+            .replace("pub const EXERCISE", "const EXERCISE")
+            .replace("pub const HINTS", "const HINTS")
+            .replace("pub const KOANS", "const KOANS")
+            .replace("pub const PITFALLS", "const PITFALLS");
+        assert_eq!(
+            check(&demo(), &text),
+            [
+                "missing `## 常见坑` section in module docs",
+                "`## 运行` shows `cargo run -- 02_demo`, expected `cargo run -- 03_demo`",
+                "missing entry `pub fn run(ctx: &mut LessonContext) -> lesson::Result`",
+                "missing `pub const QUIZ: &[Question]`",
+                "missing `pub const EXERCISE: &[Check]`",
+                "missing `pub const HINTS: &[&str]`",
+                "missing `pub const KOANS: &[Koan]`",
+                "missing `pub const PITFALLS: &[Pitfall]`",
+                "no `#[test]`",
+                "line 27: unfinished TODO",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_source_lessons_pass() {
+        let (_, lessons) = curriculum::read().unwrap();
+        for lesson in &lessons {
+            let text = fs::read_to_string(lesson.file()).unwrap();
+            assert_eq!(
+                check(lesson, &text),
+                Vec::<String>::new(),
+                "{}",
+                lesson.slug
+            );
+        }
+    }
+}
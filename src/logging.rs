@@ -0,0 +1,20 @@
+//! 运行器的诊断日志
+//!
+//! 基于 `log` + `env_logger`，由 `RUST_LOG` 控制，例如：
+//!
+//! ```text
+//! RUST_LOG=debug cargo run -- 1-3
+//! RUST_LOG=rust_learn::config=trace cargo run -- list
+//! ```
+//!
+//! 日志只写标准错误，不经过 lesson 输出（也不会进入 `--output` 记录文件与分页程序）。
+//! 未设置 `RUST_LOG` 时只显示警告及以上级别。
+
+use env_logger::Env;
+
+/// 初始化全局日志；重复调用时保持第一次的设置
+pub fn init() {
+    let _ = env_logger::Builder::from_env(Env::default().default_filter_or("warn"))
+        .format_timestamp_millis()
+        .try_init();
+}
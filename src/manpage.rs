@@ -0,0 +1,146 @@
+//! man 页：`generate-man [file]`
+//!
+//! 由命令行的定义（[`cli::COMMANDS`]、[`cli::OPTIONS`]、[`cli::EXAMPLES`]）、环境变量表
+//! [`config::ENV_VARS`]、选择器写法 [`lessons::SELECTORS`] 与退出码 [`RunnerError::EXIT_CODES`]
+//! 生成 roff 格式的 `cargo-learn(1)`，与命令行打印的用法说明始终一致。安装时放进 man 路径即可：
+//!
+//! ```text
+//! cargo learn generate-man ~/.local/share/man/man1/cargo-learn.1
+//! man cargo-learn
+//! ```
+
+use crate::cli;
+use crate::config;
+use crate::error::RunnerError;
+use crate::lessons;
+use std::fs;
+use std::io::Write;
+
+/// 安装后调用本程序的方式
+const PROG: &str = "cargo learn";
+
+pub fn run(file: Option<&str>, out: &mut dyn Write) -> Result<(), RunnerError> {
+    let page = render();
+    match file {
+        Some(file) => {
+            fs::write(file, page)?;
+            writeln!(out, "Wrote the man page to {}", file)?;
+        }
+        None => write!(out, "{}", page)?,
+    }
+    Ok(())
+}
+
+fn render() -> String {
+    let mut out = format!(
+        ".TH CARGO-LEARN 1 \"\" \"rust-learn {}\" \"User Commands\"\n",
+        env!("CARGO_PKG_VERSION")
+    );
+    out.push_str(".SH NAME\ncargo\\-learn \\- 交互式 Rust 课程的运行器\n");
+    out.push_str(".SH SYNOPSIS\n");
+    for usage in cli::COMMANDS {
+        out.push_str(&format!(
+            ".B {}\n.br\n",
+            escape(&usage.replace("{prog}", PROG))
+        ));
+    }
+    out.push_str(
+        ".SH DESCRIPTION\n\
+         按编号或名字运行 lesson，做测验与练习，记录学习进度。\
+         写成 \\fBcargo run \\-\\-\\fR 的命令只能在源码树中运行。\n",
+    );
+    out.push_str(".SH OPTIONS\n");
+    for (flags, description) in cli::OPTIONS {
+        out.push_str(&item(&format!("\\fB{}\\fR", escape(flags)), description));
+    }
+    out.push_str(".SH LESSON SELECTORS\n需要 \\fI<lesson>\\fR 的地方可以这样选择：\n");
+    for (form, description) in lessons::SELECTORS {
+        out.push_str(&item(&format!("\\fB{}\\fR", escape(form)), description));
+    }
+    out.push_str(".SH EXAMPLES\n");
+    for (args, description) in cli::EXAMPLES {
+        out.push_str(&item(
+            &format!("\\fB{} {}\\fR", escape(PROG), escape(args)),
+            description,
+        ));
+    }
+    out.push_str(".SH ENVIRONMENT\n覆盖配置文件，被命令行选项覆盖。\n");
+    for (name, description) in config::ENV_VARS {
+        out.push_str(&item(&format!("\\fB{}\\fR", escape(name)), description));
+    }
+    out.push_str(".SH FILES\n");
+    for (file, description) in [
+        ("~/.config/rust-learn/config.toml", "用户配置"),
+        ("./rust-learn.toml", "当前目录的配置，覆盖用户配置"),
+        (
+            "~/.local/share/rust-learn/progress.json",
+            "学习进度（遵循 XDG_DATA_HOME），其它学习档案在同一目录的 profiles/ 中",
+        ),
+    ] {
+        out.push_str(&item(&format!("\\fI{}\\fR", escape(file)), description));
+    }
+    out.push_str(".SH EXIT STATUS\n");
+    for (code, description) in RunnerError::EXIT_CODES {
+        out.push_str(&item(&code.to_string(), description));
+    }
+    out
+}
+
+/// 一个带缩进说明的条目
+fn item(tag: &str, description: &str) -> String {
+    format!(".TP\n{}\n{}\n", tag, escape(description))
+}
+
+/// 转义 roff：反斜杠、连字符，行首的 `.` 与 `'`
+fn escape(text: &str) -> String {
+    let text = text.replace('\\', "\\e").replace('-', "\\-");
+    if text.starts_with(['.', '\'']) {
+        format!("\\&{}", text)
+    } else {
+        text
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_escape() {
+        assert_eq!(escape("--jobs N"), "\\-\\-jobs N");
+        assert_eq!(escape(".hidden"), "\\&.hidden");
+        assert_eq!(escape("a\\b"), "a\\eb");
+    }
+
+    #[test]
+    fn test_render() {
+        let page = render();
+        assert!(page.starts_with(".TH CARGO-LEARN 1 "));
+        for section in [
+            "NAME",
+            "SYNOPSIS",
+            "OPTIONS",
+            "LESSON SELECTORS",
+            "EXIT STATUS",
+        ] {
+            assert!(
+                page.contains(&format!("\n.SH {}\n", section)),
+                "{}",
+                section
+            );
+        }
+        assert!(page.contains(".B cargo learn list\n"));
+        assert!(page.contains("\\fB\\-j, \\-\\-jobs N\\fR"));
+        assert!(page.contains(".TP\n101\n"));
+        // 每一行都不会被误当成 roff 请求
+        for line in page.lines() {
+            let request = line.split(' ').next().unwrap_or_default();
+            assert!(!line.starts_with('\''), "{}", line);
+            assert!(
+                !line.starts_with('.') || [".TH", ".SH", ".B", ".br", ".TP"].contains(&request),
+                "{}",
+                line
+            );
+        }
+    }
+}
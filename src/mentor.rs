@@ -0,0 +1,304 @@
+//! 导师模式：`mentor < snippet.rs`
+//!
+//! 把自己写的一小段代码从标准输入贴进来（以 EOF 结束，终端中按 Ctrl+D），在临时目录中作为单文件 crate
+//! 用 rustc 检查，原样显示编译器的报错，再把其中常见的错误码对应到该复习的 lesson 并给出一句提示，
+//! 例如移动后使用（E0382）对应 `06_ownership`。
+//!
+//! 没有 `fn main` 的片段会被包进 `main` 中，条目（`fn`、`struct` 等）写在函数体内同样合法。
+//! 只检查不运行（`--emit metadata`），警告不显示。提示表 [`TIPS`] 之外的错误码按 lesson 常见坑
+//! 标明的错误码（见 [`explain_error`](crate::explain_error)）找 lesson。
+
+use crate::error::RunnerError;
+use crate::explain_error;
+use crate::i18n;
+use crate::lesson::{Language, LessonMeta};
+use crate::lessons;
+use crate::utils::fmt;
+use std::env;
+use std::fs;
+use std::io::{self, IsTerminal, Read, Write};
+use std::path::Path;
+use std::process::{self, Command};
+
+/// 常见错误码 → (相关 lesson 的 slug, 一句提示)
+pub const TIPS: &[(&str, &[&str], &str)] = &[
+    (
+        "E0382",
+        &["ownership"],
+        "值被移动之后又被使用：需要两份时 `.clone()`，只是读取时借用 `&x`。",
+    ),
+    (
+        "E0499",
+        &["borrowing"],
+        "同一时刻只能有一个可变借用：缩短第一个 `&mut` 的使用范围，或者拆成两步。",
+    ),
+    (
+        "E0502",
+        &["borrowing", "slices"],
+        "不可变借用还在使用时又要可变借用：先用完读到的值（必要时复制出来），再修改。",
+    ),
+    (
+        "E0505",
+        &["borrowing", "ownership"],
+        "值在被借用期间被移走了：先用完借用，或者移动一份 `clone`。",
+    ),
+    (
+        "E0106",
+        &["lifetimes", "borrowing"],
+        "返回或存放引用时编译器需要知道它借自谁：标注生命周期参数，或者改为返回拥有所有权的值。",
+    ),
+    (
+        "E0597",
+        &["lifetimes"],
+        "被借用的值活得不够久：让值的作用域覆盖引用的全部使用，或者把值移进去。",
+    ),
+    (
+        "E0515",
+        &["lifetimes"],
+        "不能返回指向局部变量的引用：函数结束时它就被释放了，直接返回值本身。",
+    ),
+    (
+        "E0308",
+        &["types", "functions"],
+        "类型不匹配：Rust 不做隐式转换，检查函数签名、`as` 转换，以及末尾表达式是否多了分号。",
+    ),
+    (
+        "E0277",
+        &["traits", "generics"],
+        "类型没有实现需要的 trait：给泛型加上 trait 约束，或者为类型实现 / derive 它。",
+    ),
+    (
+        "E0599",
+        &["methods_assoc_fn", "traits"],
+        "这个类型上没有这个方法：检查拼写、`impl` 块，以及提供方法的 trait 是否已经 `use`。",
+    ),
+    (
+        "E0384",
+        &["variables"],
+        "变量默认不可变：需要重新赋值时声明成 `let mut`，或者用遮蔽 `let x = ...`。",
+    ),
+    (
+        "E0596",
+        &["variables", "borrowing"],
+        "对不可变的变量取了可变借用：把变量声明成 `let mut`。",
+    ),
+    (
+        "E0004",
+        &["enums_matching"],
+        "`match` 没有覆盖所有情况：补上缺少的分支，或者用 `_` 兜底。",
+    ),
+    (
+        "E0425",
+        &["variables", "modules_crates"],
+        "找不到这个名字：检查拼写与作用域，其它模块中的项要先 `use`。",
+    ),
+    (
+        "E0433",
+        &["modules_crates"],
+        "路径解析失败：检查模块路径与 `use` 声明，外部 crate 要先加依赖。",
+    ),
+    (
+        "E0603",
+        &["modules_crates"],
+        "项是私有的：在定义处加上 `pub`，或者改用公开的接口。",
+    ),
+];
+
+pub fn run(lang: Language, out: &mut dyn Write) -> Result<(), RunnerError> {
+    if io::stdin().is_terminal() {
+        eprintln!("Paste a snippet, then press Ctrl+D to check it");
+    }
+    let mut snippet = String::new();
+    io::stdin().read_to_string(&mut snippet)?;
+    if snippet.trim().is_empty() {
+        return Err(RunnerError::Usage(String::from(
+            "Usage: mentor < snippet.rs (paste the code on standard input)",
+        )));
+    }
+    let rustc = env::var("RUSTC").unwrap_or_else(|_| String::from("rustc"));
+    let dir = env::temp_dir().join(format!("rust-learn-mentor-{}", process::id()));
+    fs::create_dir_all(&dir)?;
+    let result = check(&rustc, &dir, &snippet);
+    let _ = fs::remove_dir_all(&dir);
+    let stderr = result.map_err(|e| {
+        RunnerError::Config(format!(
+            "cannot run '{}': {} (mentor needs a Rust toolchain)",
+            rustc, e
+        ))
+    })?;
+    let Some(stderr) = stderr else {
+        writeln!(out, "{}", fmt::text("✓ Compiles cleanly, nothing to fix"))?;
+        return Ok(());
+    };
+    write!(out, "{}", stderr)?;
+
+    let codes = error_codes(&stderr);
+    if codes.is_empty() {
+        writeln!(
+            out,
+            "\n{}",
+            fmt::dim("No error codes to map, read the messages above from the first one down")
+        )?;
+        return Ok(());
+    }
+    writeln!(out, "\n{}", fmt::text("── What to review ──"))?;
+    for code in codes {
+        let (tip, lessons) = advice(&code);
+        writeln!(out, "\n{} {}", fmt::text(&code), tip.unwrap_or(""))?;
+        if !lessons.is_empty() {
+            let see: Vec<String> = lessons
+                .iter()
+                .map(|m| format!("{} ({})", m.id(), i18n::title(m, lang)))
+                .collect();
+            writeln!(out, "  See {}", see.join(", "))?;
+        }
+        writeln!(
+            out,
+            "  {}",
+            fmt::dim(&format!("`explain-error {}` explains it in depth", code))
+        )?;
+    }
+    Ok(())
+}
+
+/// 在 `dir` 中检查片段；编译失败时返回编译器的报错，通过时返回 `None`
+fn check(rustc: &str, dir: &Path, snippet: &str) -> io::Result<Option<String>> {
+    fs::write(dir.join("snippet.rs"), wrap(snippet))?;
+    let output = Command::new(rustc)
+        .current_dir(dir)
+        .args([
+            "--edition",
+            "2024",
+            "--emit",
+            "metadata",
+            "--crate-name",
+            "snippet",
+            "-A",
+            "warnings",
+        ])
+        .arg(if fmt::is_color() {
+            "--color=always"
+        } else {
+            "--color=never"
+        })
+        .arg("snippet.rs")
+        .output()?;
+    if output.status.success() {
+        return Ok(None);
+    }
+    Ok(Some(String::from_utf8_lossy(&output.stderr).into_owned()))
+}
+
+/// 没有 `fn main` 的片段包进 `main`；加在第一行前面，报错中的行号不变
+fn wrap(snippet: &str) -> String {
+    if snippet.contains("fn main") {
+        return snippet.to_string();
+    }
+    format!("fn main() {{ {}\n}}\n", snippet.trim_end())
+}
+
+/// 报错中出现的错误码，按第一次出现的顺序、去重
+fn error_codes(stderr: &str) -> Vec<String> {
+    let mut codes: Vec<String> = Vec::new();
+    for part in stderr.split("error[").skip(1) {
+        let Some((code, _)) = part.split_once(']') else {
+            continue;
+        };
+        let code = code.to_string();
+        if code.starts_with('E') && !codes.contains(&code) {
+            codes.push(code);
+        }
+    }
+    codes
+}
+
+/// 错误码的提示与该复习的 lesson；不在 [`TIPS`] 中时按常见坑标明的错误码找 lesson
+fn advice(code: &str) -> (Option<&'static str>, Vec<LessonMeta>) {
+    let metas: Vec<LessonMeta> = lessons::all().iter().map(|l| l.meta()).collect();
+    match TIPS.iter().find(|(c, _, _)| *c == code) {
+        Some((_, slugs, tip)) => {
+            let lessons = slugs
+                .iter()
+                .filter_map(|slug| metas.iter().find(|m| m.slug == *slug).copied())
+                .collect();
+            (Some(*tip), lessons)
+        }
+        None => {
+            let mut lessons: Vec<LessonMeta> = Vec::new();
+            for (meta, pitfall) in explain_error::index() {
+                if pitfall.error == Some(code) && !lessons.contains(&meta) {
+                    lessons.push(meta);
+                }
+            }
+            (None, lessons)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wrap() {
+        assert_eq!(wrap("let x = 1;\n"), "fn main() { let x = 1;\n}\n");
+        let full = "fn main() {}\n";
+        assert_eq!(wrap(full), full);
+    }
+
+    #[test]
+    fn test_error_codes() {
+        let stderr = "error[E0382]: borrow of moved value: `s`\n\
+                      error[E0308]: mismatched types\n\
+                      error[E0382]: use of moved value\n\
+                      error: aborting due to 3 previous errors\n";
+        assert_eq!(error_codes(stderr), ["E0382", "E0308"]);
+        assert!(error_codes("error: cannot find macro `x`").is_empty());
+    }
+
+    /// 提示表中的 lesson 都在大纲中；没有编译进来的 lesson 以 `UNAVAILABLE` 中的记录为准
+    #[test]
+    fn test_tips_refer_to_lessons() {
+        let slugs: Vec<&str> = lessons::all()
+            .iter()
+            .map(|l| l.meta().slug)
+            .chain(lessons::UNAVAILABLE.iter().map(|u| u.slug))
+            .collect();
+        for (code, lessons, _) in TIPS {
+            assert!(!lessons.is_empty(), "{}", code);
+            for slug in *lessons {
+                assert!(slugs.contains(slug), "{}: {}", code, slug);
+            }
+        }
+    }
+
+    #[test]
+    fn test_advice() {
+        let (tip, lessons) = advice("E0382");
+        assert!(tip.is_some());
+        assert_eq!(lessons[0].slug, "ownership");
+        // 不在提示表中，按常见坑标明的错误码找到 lesson
+        let (tip, lessons) = advice("E0046");
+        assert!(tip.is_none());
+        assert!(lessons.iter().any(|m| m.slug == "traits"));
+        assert_eq!(advice("E9999"), (None, Vec::new()));
+    }
+
+    #[test]
+    fn test_check() {
+        let dir = env::temp_dir().join(format!("rust-learn-mentor-test-{}", process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let rustc = env::var("RUSTC").unwrap_or_else(|_| String::from("rustc"));
+        let stderr = check(
+            &rustc,
+            &dir,
+            "let s = String::new();\nlet t = s;\ns.len();\n",
+        )
+        .unwrap()
+        .unwrap();
+        assert!(stderr.contains("error[E0382]"), "{}", stderr);
+        assert!(stderr.contains("--> snippet.rs:3:"), "{}", stderr);
+        assert_eq!(check(&rustc, &dir, "let x = 1;").unwrap(), None);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
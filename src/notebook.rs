@@ -0,0 +1,159 @@
+//! Jupyter notebook：`export --format ipynb [dir]`
+//!
+//! 每个 lesson 生成一个 `<lesson>.ipynb`（默认在 `notebooks/` 中），使用 [evcxr](https://github.com/evcxr/evcxr)
+//! 的 Rust kernel。第一个单元格是模块注释（Markdown），第二个代码单元格定义 lesson 的全部代码：
+//! 与 [`share`](crate::share) 一样去掉测验、练习与测试，附上运行器的最小替身，讲解文本按 `--lang` 翻译。
+//! 之后每个小节一个标题与一个只运行这一节的代码单元格，可以逐节执行，改了上面的代码再重跑。
+//!
+//! ```text
+//! cargo install evcxr_jupyter && evcxr_jupyter --install
+//! jupyter lab notebooks
+//! ```
+//!
+//! 单元格里没有预先运行的输出，打开后从上往下执行即可。需要在源码树中运行。
+
+use crate::curriculum;
+use crate::error::RunnerError;
+use crate::export::Material;
+use crate::lesson::{Language, Lesson};
+use crate::lessons::{self, Selected};
+use crate::share;
+use crate::utils::fmt;
+use serde_json::{Value, json};
+use std::fs;
+use std::io::Write;
+use std::path::Path;
+
+/// 不给目录时生成到这里
+pub const DIR: &str = "notebooks";
+
+pub fn run(dir: &str, lang: Language, out: &mut dyn Write) -> Result<(), RunnerError> {
+    let (_, entries) = curriculum::read()?;
+    fs::create_dir_all(dir)?;
+    let mut count = 0;
+    for lesson in lessons::all().iter().filter(|l| !l.meta().deprecated) {
+        let selected = Selected {
+            lesson: lesson.as_ref(),
+            section: None,
+        };
+        let material = Material::source(&selected, &entries)?;
+        fs::write(
+            Path::new(dir).join(format!("{}.ipynb", selected.id())),
+            notebook(lesson.as_ref(), &material, lang),
+        )?;
+        count += 1;
+    }
+    writeln!(out, "Exported {} notebooks to {}", count, dir)?;
+    writeln!(
+        out,
+        "{}",
+        fmt::dim(&format!(
+            "Open them with the evcxr Jupyter kernel, e.g. `jupyter lab {}`",
+            dir
+        ))
+    )?;
+    Ok(())
+}
+
+/// nbformat 4 的 JSON
+fn notebook(lesson: &dyn Lesson, material: &Material, lang: Language) -> String {
+    let mut cells = vec![
+        markdown(&material.docs),
+        code(&share::code(&material.source, None, lang, false)),
+    ];
+    let sections = lesson.sections();
+    if sections.is_empty() {
+        cells.push(code("run(&mut lesson::LessonContext::stdout()).unwrap();"));
+    }
+    for (i, section) in sections.iter().enumerate() {
+        cells.push(markdown(&format!("### {}", section.title)));
+        cells.push(code(&format!(
+            "lesson::run_sections(&mut lesson::LessonContext::stdout(), &SECTIONS[{}..={}]).unwrap();",
+            i, i
+        )));
+    }
+    let notebook = json!({
+        "cells": cells,
+        "metadata": {
+            "kernelspec": {
+                "display_name": "Rust",
+                "language": "rust",
+                "name": "rust",
+            },
+            "language_info": {
+                "codemirror_mode": "rust",
+                "file_extension": ".rs",
+                "mimetype": "text/rust",
+                "name": "Rust",
+                "pygment_lexer": "rust",
+                "version": "",
+            },
+        },
+        "nbformat": 4,
+        "nbformat_minor": 4,
+    });
+    format!(
+        "{}\n",
+        serde_json::to_string_pretty(&notebook).expect("the notebook always serializes")
+    )
+}
+
+fn markdown(text: &str) -> Value {
+    json!({
+        "cell_type": "markdown",
+        "metadata": {},
+        "source": source(text),
+    })
+}
+
+fn code(text: &str) -> Value {
+    json!({
+        "cell_type": "code",
+        "execution_count": null,
+        "metadata": {},
+        "outputs": [],
+        "source": source(text),
+    })
+}
+
+/// 单元格内容按行存放，除最后一行外都带着换行符
+fn source(text: &str) -> Vec<&str> {
+    text.trim_end().split_inclusive('\n').collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_source() {
+        assert_eq!(source("# A\n\nb\n\n"), ["# A\n", "\n", "b"]);
+        assert!(source("").is_empty());
+    }
+
+    #[test]
+    fn test_notebook() {
+        let (_, entries) = curriculum::read().unwrap();
+        let selected = lessons::select("06_ownership").unwrap();
+        let [selected] = selected.as_slice() else {
+            panic!("one lesson");
+        };
+        let material = Material::source(selected, &entries).unwrap();
+        let text = notebook(selected.lesson, &material, Language::En);
+        let notebook: Value = serde_json::from_str(&text).unwrap();
+        assert_eq!(notebook["nbformat"], 4);
+        assert_eq!(notebook["metadata"]["kernelspec"]["name"], "rust");
+        let cells = notebook["cells"].as_array().unwrap();
+        assert_eq!(cells[0]["cell_type"], "markdown");
+        assert_eq!(cells[0]["source"][0], "# Ownership Basics\n");
+        let program = cells[1]["source"].as_array().unwrap();
+        assert!(program.iter().any(|l| l == "mod lesson {\n"));
+        assert!(!program.iter().any(|l| l.as_str().unwrap().contains("QUIZ")));
+        let sections = selected.lesson.sections();
+        assert_eq!(cells.len(), 2 + 2 * sections.len());
+        assert_eq!(
+            cells[3]["source"][0],
+            "lesson::run_sections(&mut lesson::LessonContext::stdout(), &SECTIONS[0..=0]).unwrap();"
+        );
+    }
+}
@@ -0,0 +1,131 @@
+//! 学习笔记：把运行器当作简单的学习日志
+//!
+//! `note <lesson> <text>` 给 lesson 追加一条带时间的笔记，引号可省略，其余参数会用空格连起来；
+//! `notes <lesson>` 列出某个 lesson 的笔记，只写 `notes` 时列出全部，`info` 也会附上笔记。
+//! 笔记与学习进度保存在同一个文件中（见 [`progress`](crate::progress)）。
+
+use crate::error::RunnerError;
+use crate::i18n;
+use crate::lesson::{Language, Lesson};
+use crate::lessons;
+use crate::progress::{Note, Progress};
+use crate::utils::fmt;
+use std::io::Write;
+
+/// `note <lesson> <text>...`，`args` 不含 `note` 本身
+pub fn add(
+    args: &[String],
+    progress: &mut Progress,
+    out: &mut dyn Write,
+) -> Result<(), RunnerError> {
+    let text = args.get(1..).unwrap_or_default().join(" ");
+    let (Some(sel), false) = (args.first(), text.trim().is_empty()) else {
+        return Err(RunnerError::Usage(String::from(
+            "Usage: note <lesson> <text>",
+        )));
+    };
+    let id = lesson_id(sel)?;
+    progress.add_note(&id, text.trim())?;
+    writeln!(
+        out,
+        "Added note to {} ({} total)",
+        id,
+        progress.notes(&id).len()
+    )?;
+    Ok(())
+}
+
+/// `notes [lesson]`
+pub fn show(
+    sel: Option<&str>,
+    progress: &Progress,
+    lang: Language,
+    out: &mut dyn Write,
+) -> Result<(), RunnerError> {
+    if let Some(sel) = sel {
+        let id = lesson_id(sel)?;
+        match progress.notes(&id) {
+            [] => writeln!(out, "No notes for {}", id)?,
+            notes => write!(out, "{}", render(notes))?,
+        }
+        return Ok(());
+    }
+    let mut any = false;
+    for (id, notes) in progress.all_notes() {
+        if any {
+            writeln!(out)?;
+        }
+        any = true;
+        let title = lessons::select(id)
+            .ok()
+            .and_then(|picked| Some(i18n::title(&picked.first()?.meta(), lang)))
+            .unwrap_or("(unavailable)");
+        writeln!(out, "{}", fmt::text(&format!("{} — {}", id, title)))?;
+        write!(out, "{}", render(notes))?;
+    }
+    if !any {
+        writeln!(out, "No notes yet, add one with `note <lesson> <text>`")?;
+    }
+    Ok(())
+}
+
+/// 笔记只记在 lesson 上：选择器必须恰好选中一个 lesson
+fn lesson_id(sel: &str) -> Result<String, RunnerError> {
+    match lessons::resolve(sel)?.as_slice() {
+        [lesson] => Ok(lesson.meta().id()),
+        _ => Err(RunnerError::Usage(format!(
+            "'{}' selects several lessons, notes belong to a single lesson",
+            sel
+        ))),
+    }
+}
+
+/// 每条笔记一行：`  2024-05-01 13:45  内容`，多行内容缩进对齐
+pub fn render(notes: &[Note]) -> String {
+    let mut out = String::new();
+    for note in notes {
+        // 精确到分钟：`2024-05-01 13:45`
+        let stamp = &note.at.to_string()[..16];
+        for (i, line) in note.text.lines().enumerate() {
+            if i == 0 {
+                out.push_str(&format!("  {}  {}\n", fmt::dim(stamp), line));
+            } else {
+                out.push_str(&format!("  {}  {}\n", " ".repeat(stamp.len()), line));
+            }
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::time::Timestamp;
+
+    #[test]
+    fn test_render() {
+        let notes = [
+            Note {
+                at: Timestamp::from_unix(951_827_696),
+                text: String::from("trait objects vs generics: revisit"),
+            },
+            Note {
+                at: Timestamp::from_unix(951_827_756),
+                text: String::from("dyn Trait\n需要对象安全"),
+            },
+        ];
+        assert_eq!(
+            render(&notes),
+            "  2000-02-29 12:34  trait objects vs generics: revisit\n\
+             \x20 2000-02-29 12:35  dyn Trait\n\
+             \x20                   需要对象安全\n"
+        );
+    }
+
+    #[test]
+    fn test_lesson_id() {
+        assert_eq!(lesson_id("traits").unwrap(), "13_traits");
+        assert_eq!(lesson_id("13:trait_bounds").unwrap(), "13_traits");
+        assert!(matches!(lesson_id("1-2"), Err(RunnerError::Usage(_))));
+    }
+}
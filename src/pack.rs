@@ -0,0 +1,52 @@
+//! 课外 lesson 包
+//!
+//! 其他 crate 可以用 [`lesson_pack!`](crate::lesson_pack) 注册额外的 lesson，
+//! 无需修改本 crate 的 `lessons/mod.rs`。注册基于 `inventory`：只要 lesson 包被链接进
+//! 最终的可执行文件，其中的 lesson 就会出现在 `list` / `info` / 运行命令中。
+//!
+//! ```ignore
+//! struct Ffi;
+//!
+//! impl Lesson for Ffi {
+//!     fn meta(&self) -> LessonMeta { /* number: 40, slug: "ffi", ... */ }
+//!     fn run(&self, ctx: &mut LessonContext) -> lesson::Result { /* ... */ }
+//! }
+//!
+//! rust_learn::lesson_pack!("systems", [Ffi]);
+//!
+//! fn main() {
+//!     rust_learn::app::main();
+//! }
+//! ```
+//!
+//! 完整示例见 `examples/lesson_pack.rs`。
+
+use crate::lesson::Lesson;
+
+pub use inventory;
+
+/// 一组来自其他 crate 的 lesson
+pub struct LessonPack {
+    pub name: &'static str,
+    pub lessons: fn() -> Vec<Box<dyn Lesson>>,
+}
+
+inventory::collect!(LessonPack);
+
+/// 已链接进当前可执行文件的全部 lesson 包
+pub fn packs() -> impl Iterator<Item = &'static LessonPack> {
+    inventory::iter::<LessonPack>.into_iter()
+}
+
+/// 注册一个 lesson 包：`lesson_pack!("名称", [LessonA, LessonB])`
+#[macro_export]
+macro_rules! lesson_pack {
+    ($name:expr, [$($lesson:expr),* $(,)?]) => {
+        $crate::pack::inventory::submit! {
+            $crate::pack::LessonPack {
+                name: $name,
+                lessons: || vec![$(Box::new($lesson) as Box<dyn $crate::lesson::Lesson>),*],
+            }
+        }
+    };
+}
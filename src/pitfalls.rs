@@ -0,0 +1,183 @@
+//! 编译不过的常见坑：`pitfalls <lesson>`
+//!
+//! 很多“常见坑”是编译错误，写不进能运行的示例。每个 lesson 在 `PITFALLS` 中收录几段这样的代码
+//! （见 [`Pitfall`]），这里逐段显示代码，用 rustc 现场检查（`--emit metadata`，不生成可执行文件），
+//! 原样展示编译器的报错，再解释为什么编译不过、应当怎样改。
+//!
+//! 报错来自当前工具链，与 lesson crate 中 trybuild 快照（`pitfalls/<slug>/<name>.stderr`）
+//! 的细节可能略有出入；trybuild 测试保证每段代码确实编译失败。
+
+use crate::error::RunnerError;
+use crate::highlight;
+use crate::lesson::{Language, Pitfall};
+use crate::lessons;
+use crate::utils::fmt;
+use std::env;
+use std::fs;
+use std::io::{self, Write};
+use std::path::Path;
+use std::process::{self, Command};
+
+/// 显示选中的每个 lesson 的常见坑
+pub fn run(sel: &str, lang: Language, out: &mut dyn Write) -> Result<(), RunnerError> {
+    let lessons = lessons::resolve(sel)?;
+    let rustc = env::var("RUSTC").unwrap_or_else(|_| String::from("rustc"));
+    let dir = env::temp_dir().join(format!("rust-learn-pitfalls-{}", process::id()));
+    fs::create_dir_all(&dir)?;
+    let result = lessons.iter().try_for_each(|lesson| {
+        let meta = lesson.meta();
+        let pitfalls = lesson.pitfalls();
+        if pitfalls.is_empty() {
+            writeln!(out, "{} has no pitfalls yet", meta.id())?;
+            return Ok(());
+        }
+        writeln!(out, "{}", meta.header(lang))?;
+        for (i, pitfall) in pitfalls.iter().enumerate() {
+            writeln!(
+                out,
+                "\n{}",
+                fmt::text(&format!(
+                    "── {}/{} {} ──",
+                    i + 1,
+                    pitfalls.len(),
+                    pitfall.name
+                ))
+            )?;
+            write!(out, "{}", highlight::rust(pitfall.code))?;
+            writeln!(
+                out,
+                "{}",
+                fmt::dim(&format!("\n$ rustc {}.rs", pitfall.name))
+            )?;
+            match compile(&rustc, &dir, pitfall) {
+                Ok(Some(diagnostics)) => write!(out, "{}", diagnostics)?,
+                Ok(None) => writeln!(
+                    out,
+                    "{}",
+                    fmt::dim("(compiles with this toolchain, the pitfall may be outdated)")
+                )?,
+                Err(e) => {
+                    return Err(RunnerError::Config(format!(
+                        "cannot run '{}': {} (pitfalls need a Rust toolchain)",
+                        rustc, e
+                    )));
+                }
+            }
+            writeln!(out, "Why: {}", pitfall.explanation)?;
+        }
+        Ok(())
+    });
+    let _ = fs::remove_dir_all(&dir);
+    result
+}
+
+/// 在 `dir` 中检查一段代码；编译失败时返回编译器的报错，通过时返回 `None`
+fn compile(rustc: &str, dir: &Path, pitfall: &Pitfall) -> io::Result<Option<String>> {
+    let file = format!("{}.rs", pitfall.name);
+    fs::write(dir.join(&file), pitfall.code)?;
+    // 在 `dir` 中以相对路径编译，报错里显示的是 `<name>.rs` 而不是临时目录
+    let output = Command::new(rustc)
+        .current_dir(dir)
+        .args(["--edition", "2024", "--emit", "metadata", "--crate-name"])
+        .arg(pitfall.name)
+        .arg(if fmt::is_color() {
+            "--color=always"
+        } else {
+            "--color=never"
+        })
+        .arg(&file)
+        .output()?;
+    if output.status.success() {
+        return Ok(None);
+    }
+    Ok(Some(String::from_utf8_lossy(&output.stderr).into_owned()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::curriculum;
+    use std::collections::BTreeSet;
+
+    #[test]
+    fn test_compile() {
+        let dir = env::temp_dir().join(format!("rust-learn-pitfalls-test-{}", process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let rustc = env::var("RUSTC").unwrap_or_else(|_| String::from("rustc"));
+        let moved = Pitfall::new(
+            "moved",
+            "fn main() {\n    let s = String::new();\n    let t = s;\n    println!(\"{}{}\", s, t);\n}\n",
+            "",
+        );
+        let stderr = compile(&rustc, &dir, &moved).unwrap().unwrap();
+        assert!(stderr.contains("error[E0382]"), "{}", stderr);
+        assert!(stderr.contains("--> moved.rs:4:"), "{}", stderr);
+        let fine = Pitfall::new("fine", "fn main() {}\n", "");
+        assert_eq!(compile(&rustc, &dir, &fine).unwrap(), None);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    /// `pitfalls/<slug>/` 中的每个文件都登记在 lesson 的 `PITFALLS` 中，名字与文件名一致
+    #[test]
+    fn test_pitfall_files_registered() {
+        let Ok((_, entries)) = curriculum::read() else {
+            return;
+        };
+        for lesson in lessons::all() {
+            let meta = lesson.meta();
+            let Some(entry) = entries.iter().find(|e| e.id() == meta.id()) else {
+                continue;
+            };
+            let dir = curriculum::root()
+                .join("crates")
+                .join(entry.lesson_crate())
+                .join("pitfalls")
+                .join(meta.slug);
+            let files: BTreeSet<String> = fs::read_dir(&dir)
+                .map(|dir| {
+                    dir.filter_map(|e| e.ok()?.file_name().into_string().ok())
+                        .filter_map(|name| name.strip_suffix(".rs").map(String::from))
+                        .collect()
+                })
+                .unwrap_or_default();
+            let registered: BTreeSet<String> = lesson
+                .pitfalls()
+                .iter()
+                .map(|p| p.name.to_string())
+                .collect();
+            assert_eq!(files, registered, "{}", meta.id());
+        }
+    }
+
+    /// 标明的错误码与 trybuild 快照中的报错一致，快照中有错误码的都要标明
+    #[test]
+    fn test_pitfall_error_codes() {
+        let Ok((_, entries)) = curriculum::read() else {
+            return;
+        };
+        for lesson in lessons::all() {
+            let meta = lesson.meta();
+            let Some(entry) = entries.iter().find(|e| e.id() == meta.id()) else {
+                continue;
+            };
+            for pitfall in lesson.pitfalls() {
+                let snapshot = curriculum::root()
+                    .join("crates")
+                    .join(entry.lesson_crate())
+                    .join("pitfalls")
+                    .join(meta.slug)
+                    .join(format!("{}.stderr", pitfall.name));
+                let stderr = fs::read_to_string(&snapshot).unwrap();
+                match pitfall.error {
+                    Some(code) => assert!(
+                        stderr.contains(&format!("error[{}]", code)),
+                        "{}: {}",
+                        meta.id(),
+                        pitfall.name
+                    ),
+                    None => assert!(!stderr.contains("error[E"), "{}", pitfall.name),
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,140 @@
+//! 分班测验：`placement`
+//!
+//! 不是零基础的学习者可以先做一次跨章节的测验：每章从不同 lesson 的题库中各抽一题，至多
+//! [`PER_CHAPTER`] 道。一章答对的比例达到 [`grade::MASTERY`] 时，这一章还没完成的 lesson 都判定为
+//! 可以跳过（tested out）并记入学习进度，`path --adaptive` 会跳过它们。最后给出建议的起点：
+//! 个性化学习顺序（见 [`lessons::plan`]）中的第一个 lesson。
+//!
+//! 每次分班测验的判定取代上一次的；判定不影响完成记录与各 lesson 的测验成绩。
+
+use crate::error::RunnerError;
+use crate::grade;
+use crate::i18n;
+use crate::lesson::{Chapter, Language, Question};
+use crate::lessons;
+use crate::progress::Progress;
+use crate::quiz::{self, Drawn};
+use crate::utils::io::Prompter;
+use crate::utils::rng::Rng;
+use crate::utils::{deterministic, fmt};
+use std::io::Write;
+
+/// 每章至多提问的题数
+pub const PER_CHAPTER: usize = 3;
+
+pub fn run(
+    progress: &mut Progress,
+    lang: Language,
+    out: &mut dyn Write,
+) -> Result<(), RunnerError> {
+    let order = lessons::learning_path().map_err(RunnerError::Config)?;
+    let mut prompter = Prompter::stdin();
+    let mut rng = Rng::new(deterministic::seed());
+    writeln!(
+        out,
+        "Placement quiz: up to {} questions per chapter. Score {}% or better in a chapter to skip its lessons.",
+        PER_CHAPTER,
+        grade::MASTERY
+    )?;
+    let mut results = Vec::new();
+    let mut skips = Vec::new();
+    for chapter in Chapter::ALL {
+        let banks: Vec<Vec<Question>> = lessons::all()
+            .iter()
+            .map(|l| (l.meta(), l.quiz()))
+            .filter(|(m, quiz)| m.chapter == chapter && !m.deprecated && !quiz.is_empty())
+            .map(|(_, quiz)| quiz)
+            .collect();
+        let questions = sample(&banks, &mut rng);
+        if questions.is_empty() {
+            continue;
+        }
+        writeln!(
+            out,
+            "\n{}",
+            fmt::text(&format!("── {} ──", chapter.title()))
+        )?;
+        let correct = quiz::ask(&questions, &mut prompter, lang)?;
+        let passed = is_mastered(correct, questions.len());
+        if passed {
+            skips.extend(
+                order
+                    .iter()
+                    .filter(|m| m.chapter == chapter && !m.deprecated)
+                    .map(|m| m.id())
+                    .filter(|id| !progress.is_complete(id)),
+            );
+        }
+        results.push((chapter, correct, questions.len(), passed));
+    }
+    if results.is_empty() {
+        writeln!(out, "No lesson has a quiz yet")?;
+        return Ok(());
+    }
+
+    writeln!(out, "\nResults:")?;
+    for (chapter, correct, total, passed) in &results {
+        writeln!(
+            out,
+            "  {:<16} {} / {}{}",
+            chapter.title(),
+            correct,
+            total,
+            fmt::text(if *passed { "  ✓ tested out" } else { "" })
+        )?;
+    }
+    progress.record_placement(&skips)?;
+    match lessons::plan(&order, progress).remaining.first() {
+        Some(meta) => writeln!(
+            out,
+            "\nStart with {} {}, run `path --adaptive` to see your whole path",
+            meta.id(),
+            i18n::title(meta, lang)
+        )?,
+        None => writeln!(out, "\nYou tested out of everything left 🎉")?,
+    }
+    Ok(())
+}
+
+/// 从不同的题库中各抽一题，至多 [`PER_CHAPTER`] 道
+fn sample(banks: &[Vec<Question>], rng: &mut Rng) -> Vec<Drawn> {
+    let mut picked: Vec<&Vec<Question>> = banks.iter().collect();
+    rng.shuffle(&mut picked);
+    picked.truncate(PER_CHAPTER);
+    picked
+        .into_iter()
+        .flat_map(|bank| quiz::draw(bank, 1, rng))
+        .collect()
+}
+
+/// 答对的比例是否达到 [`grade::MASTERY`]
+fn is_mastered(correct: usize, total: usize) -> bool {
+    total > 0 && correct * 100 >= grade::MASTERY as usize * total
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeSet;
+
+    #[test]
+    fn test_sample() {
+        let bank = |prompt| vec![Question::new(prompt, &["a", "b"], 0, "")];
+        let banks = [bank("1"), bank("2"), bank("3"), bank("4")];
+        let mut rng = Rng::new(7);
+        let drawn = sample(&banks, &mut rng);
+        assert_eq!(drawn.len(), PER_CHAPTER);
+        let prompts: BTreeSet<_> = drawn.iter().map(|d| d.prompt).collect();
+        assert_eq!(prompts.len(), PER_CHAPTER);
+        assert_eq!(sample(&banks[..1], &mut rng).len(), 1);
+        assert!(sample(&[], &mut rng).is_empty());
+    }
+
+    #[test]
+    fn test_is_mastered() {
+        assert!(is_mastered(3, 3));
+        assert!(!is_mastered(2, 3));
+        assert!(is_mastered(4, 5));
+        assert!(!is_mastered(0, 0));
+    }
+}
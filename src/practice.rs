@@ -0,0 +1,233 @@
+//! 练习模式：`practice [lesson]`
+//!
+//! 像 rustlings 一样带着学习者按顺序做练习：从第一个还没完成的练习开始（也可以指定 lesson），
+//! 在子进程中运行 `cargo run -- exercise <lesson>`，重新编译并检查；之后轮询各 lesson crate 的
+//! `src/exercises/`，一保存就再检查一次。全部检查通过后自动进入下一个还没完成的练习，
+//! 每次检查前显示全部练习的完成进度。Ctrl+C 退出。
+//!
+//! 检查结果由子进程记入进度（见 [`exercise`](crate::exercise)），这里重新读取进度文件判断是否完成。
+//! 练习文件只在源码树中存在，这个命令不能在安装后的 `cargo learn` 中使用。
+
+use crate::cli::Options;
+use crate::curriculum;
+use crate::error::RunnerError;
+use crate::lesson::LessonMeta;
+use crate::lessons;
+use crate::progress::Progress;
+use crate::utils::fmt;
+use std::env;
+use std::fs;
+use std::io::{self, Write};
+use std::path::PathBuf;
+use std::process::Command;
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+const POLL: Duration = Duration::from_millis(300);
+
+/// 进度条的格数
+const BAR_WIDTH: usize = 20;
+
+/// `practice [lesson]`，`args` 不含 `practice` 本身
+pub fn run(
+    args: &[String],
+    progress: &mut Progress,
+    opts: &Options,
+    out: &mut dyn Write,
+) -> Result<(), RunnerError> {
+    let (_, entries) = curriculum::read()?;
+    let mut exercises: Vec<LessonMeta> = lessons::all()
+        .iter()
+        .filter(|l| !l.exercise().is_empty())
+        .map(|l| l.meta())
+        .filter(|m| !m.deprecated && entries.iter().any(|e| e.id() == m.id()))
+        .collect();
+    exercises.sort_by_key(|m| m.number);
+    let mut current = match args {
+        [] => match next(&exercises, progress, exercises.len()) {
+            Some(i) => i,
+            None => {
+                writeln!(out, "All {} exercises are solved 🎉", exercises.len())?;
+                return Ok(());
+            }
+        },
+        [sel] => {
+            let id = match lessons::resolve(sel)?.as_slice() {
+                [lesson] => lesson.meta().id(),
+                _ => {
+                    return Err(RunnerError::Usage(format!(
+                        "'{}' selects several lessons, practice starts from one",
+                        sel
+                    )));
+                }
+            };
+            exercises
+                .iter()
+                .position(|m| m.id() == id)
+                .ok_or_else(|| RunnerError::Usage(format!("{} has no exercise", id)))?
+        }
+        _ => return Err(RunnerError::Usage(String::from("Usage: practice [lesson]"))),
+    };
+    let dirs: Vec<PathBuf> = curriculum::LESSON_CRATES
+        .iter()
+        .map(|krate| curriculum::lesson_src(krate).join("exercises"))
+        .collect();
+
+    loop {
+        let meta = exercises[current];
+        let solved = exercises.iter().filter(|m| is_solved(progress, m)).count();
+        writeln!(
+            out,
+            "\n{} {} / {} exercises solved",
+            fmt::text(&bar(solved, exercises.len())),
+            solved,
+            exercises.len()
+        )?;
+        let seen = latest_change(&dirs)?;
+        check(&meta, opts, out)?;
+        progress.reload();
+        if is_solved(progress, &meta) {
+            match next(&exercises, progress, current) {
+                Some(i) => {
+                    writeln!(
+                        out,
+                        "\n{} done, moving on to {}",
+                        meta.id(),
+                        exercises[i].id()
+                    )?;
+                    current = i;
+                    continue;
+                }
+                None => {
+                    writeln!(
+                        out,
+                        "\n{} {} / {} exercises solved",
+                        fmt::text(&bar(exercises.len(), exercises.len())),
+                        exercises.len(),
+                        exercises.len()
+                    )?;
+                    writeln!(out, "All exercises are solved 🎉")?;
+                    return Ok(());
+                }
+            }
+        }
+        writeln!(
+            out,
+            "{}",
+            fmt::dim("Save the exercise file to check again (Ctrl+C to quit)")
+        )?;
+        while latest_change(&dirs)? <= seen {
+            thread::sleep(POLL);
+        }
+    }
+}
+
+fn is_solved(progress: &Progress, meta: &LessonMeta) -> bool {
+    progress.exercise(&meta.id()).is_some_and(|s| s.is_solved())
+}
+
+/// `after` 之后第一个还没完成的练习，到末尾后从头找；`after` 越界时从头找
+fn next(exercises: &[LessonMeta], progress: &Progress, after: usize) -> Option<usize> {
+    let n = exercises.len();
+    let start = if after < n { after + 1 } else { 0 };
+    (0..n)
+        .map(|k| (start + k) % n)
+        .find(|&i| i != after && !is_solved(progress, &exercises[i]))
+}
+
+/// 在子进程中重新编译并检查一个练习；编译错误等输出直接显示给学习者
+fn check(meta: &LessonMeta, opts: &Options, out: &mut dyn Write) -> io::Result<()> {
+    let cargo = env::var("CARGO").unwrap_or_else(|_| String::from("cargo"));
+    let status = Command::new(cargo)
+        .current_dir(curriculum::root())
+        .args(["run", "--quiet", "--"])
+        .args(opts.child_args())
+        .args(["exercise", meta.slug])
+        .status()?;
+    if !status.success() {
+        writeln!(
+            out,
+            "{}",
+            fmt::dim(&format!("`exercise {}` exited with {}", meta.slug, status))
+        )?;
+    }
+    Ok(())
+}
+
+/// `[██████░░░░…]`，按完成比例填充
+fn bar(done: usize, total: usize) -> String {
+    let filled = (done * BAR_WIDTH).checked_div(total).unwrap_or(0);
+    format!("[{}{}]", "█".repeat(filled), "░".repeat(BAR_WIDTH - filled))
+}
+
+/// 各目录中文件的最近修改时间；目录不存在时跳过
+fn latest_change(dirs: &[PathBuf]) -> io::Result<SystemTime> {
+    let mut latest = SystemTime::UNIX_EPOCH;
+    for dir in dirs.iter().filter(|d| d.is_dir()) {
+        for entry in fs::read_dir(dir)? {
+            latest = latest.max(entry?.metadata()?.modified()?);
+        }
+    }
+    Ok(latest)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lesson::{Chapter, Difficulty};
+
+    fn meta(number: usize, slug: &'static str) -> LessonMeta {
+        LessonMeta {
+            number,
+            slug,
+            aliases: &[],
+            title: slug,
+            chapter: Chapter::Basics,
+            difficulty: Difficulty::Beginner,
+            minutes: 15,
+            tags: &[],
+            prerequisites: &[],
+            deprecated: false,
+            replaced_by: None,
+        }
+    }
+
+    #[test]
+    fn test_next() {
+        let exercises = [meta(1, "a"), meta(2, "b"), meta(3, "c")];
+        let mut progress = Progress::default();
+        assert_eq!(next(&exercises, &progress, exercises.len()), Some(0));
+        assert_eq!(next(&exercises, &progress, 0), Some(1));
+        progress.record_exercise("03_c", Vec::new(), 0).unwrap();
+        // c 已完成，从 b 往后找时绕回开头
+        assert_eq!(next(&exercises, &progress, 1), Some(0));
+        progress.record_exercise("01_a", Vec::new(), 0).unwrap();
+        assert_eq!(next(&exercises, &progress, 1), None);
+    }
+
+    #[test]
+    fn test_bar() {
+        assert_eq!(bar(0, 4), format!("[{}]", "░".repeat(BAR_WIDTH)));
+        assert_eq!(
+            bar(1, 4),
+            format!("[{}{}]", "█".repeat(5), "░".repeat(BAR_WIDTH - 5))
+        );
+        assert_eq!(bar(4, 4), format!("[{}]", "█".repeat(BAR_WIDTH)));
+        assert_eq!(bar(0, 0), format!("[{}]", "░".repeat(BAR_WIDTH)));
+    }
+
+    #[test]
+    fn test_latest_change() {
+        let dir = env::temp_dir().join(format!("rust-learn-practice-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let missing = dir.join("missing");
+        assert_eq!(
+            latest_change(std::slice::from_ref(&missing)).unwrap(),
+            SystemTime::UNIX_EPOCH
+        );
+        fs::write(dir.join("a.rs"), "").unwrap();
+        let changed = latest_change(&[dir.clone(), missing]).unwrap();
+        assert!(changed > SystemTime::UNIX_EPOCH);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
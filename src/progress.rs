@@ -0,0 +1,1216 @@
+//! 学习进度
+//!
+//! 成功运行过（或用 `complete` 手动标记）的 lesson 视为已完成，连同完成时间记录在
+//! `~/.local/share/rust-learn/progress.json`（遵循 `XDG_DATA_HOME`）中：
+//!
+//! ```json
+//! { "completed": { "06_ownership": "2024-05-01T13:45:00Z" } }
+//! ```
+//!
+//! 同一文件中还保存书签（`bookmarks`，见 [`bookmark`](crate::bookmark)）
+//! 、学习笔记（`notes`，见 [`notes`](crate::notes)）、每次运行的日期与耗时
+//! （`runs`，由 [`stats`](crate::stats) 统计）、测验成绩（`quizzes`，见 [`quiz`](crate::quiz)）
+//! 、练习的完成情况（`exercises`，见 [`exercise`](crate::exercise)）、计时挑战的最好用时
+//! （`challenges`，见 [`challenge`](crate::challenge)）、学完后的自评（`confidence`，
+//! 用 `--confidence` 开启）、kata 的完成情况（`katas`，见 [`kata`](crate::kata)）以及
+//! 分班测验判定可以跳过的 lesson（`tested_out`，见 [`placement`](crate::placement)）。
+//!
+//! 每次写入都在文件锁（`<文件名>.lock`）的保护下先重新读取文件、在最新的内容上修改，再整体替换，
+//! 并行运行的子进程或长时间运行的 `practice`、`serve` 不会互相覆盖记录。
+//! 旧版本逐行记录 id 的 `completed` 文件会在首次读取时导入，完成时间取文件的修改时间。
+//! 记录可以用 `reset` 有选择地清除（见 [`reset`](crate::reset)）。
+//!
+//! 多人共用一份源码时，每个学习档案（`--profile <name>`）的进度单独记录在数据目录的
+//! `profiles/<name>.json` 中，默认档案仍使用 `progress.json`（见 [`classroom`](crate::classroom)）。
+
+use crate::lesson::LessonMeta;
+use crate::utils::time::Timestamp;
+use serde::{Deserialize, Serialize};
+use std::collections::btree_map::Entry;
+use std::collections::{BTreeMap, BTreeSet};
+use std::env;
+use std::fs::{self, File};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, UNIX_EPOCH};
+
+/// 进度文件的内容
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct Store {
+    #[serde(default)]
+    completed: BTreeMap<String, Timestamp>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    bookmarks: Vec<Bookmark>,
+    /// lesson id → 按时间顺序的笔记
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    notes: BTreeMap<String, Vec<Note>>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    runs: Vec<Run>,
+    /// lesson id → 历次测验成绩
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    quizzes: BTreeMap<String, Vec<QuizScore>>,
+    /// lesson id → 练习的完成情况
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    exercises: BTreeMap<String, ExerciseState>,
+    /// lesson id → `check` 最近一次全部通过的时间
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    tests: BTreeMap<String, Timestamp>,
+    /// lesson id → 计时挑战全部答对的最短用时（毫秒）
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    challenges: BTreeMap<String, u64>,
+    /// lesson id → 最近一次自评
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    confidence: BTreeMap<String, Confidence>,
+    /// kata 名字 → 完成情况；kata 不属于任何 lesson，不参与 id 迁移
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    katas: BTreeMap<String, KataState>,
+    /// lesson id → 分班测验判定可以跳过的时间
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    tested_out: BTreeMap<String, Timestamp>,
+}
+
+/// 一个练习的完成情况
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExerciseState {
+    /// 运行检查的次数
+    pub attempts: u32,
+    /// 最近一次运行时通过的检查项
+    pub passed: Vec<String>,
+    /// 检查项总数
+    pub total: usize,
+    /// 第一次全部通过的时间
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub solved: Option<Timestamp>,
+    /// 完成之前看过的提示条数
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub hints: usize,
+    /// 完成之前是否看过参考答案
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub revealed: bool,
+}
+
+impl ExerciseState {
+    pub fn is_solved(&self) -> bool {
+        self.solved.is_some()
+    }
+
+    /// 完成后的得分：满分 100，每看一条提示扣 25 分，看过参考答案为 0 分；尚未完成时为 `None`
+    pub fn score(&self) -> Option<u32> {
+        self.solved?;
+        if self.revealed {
+            return Some(0);
+        }
+        Some(100u32.saturating_sub(25 * self.hints as u32))
+    }
+}
+
+/// 一个 kata 的完成情况
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KataState {
+    /// 运行测试的次数
+    pub attempts: u32,
+    /// 最近一次运行时通过的测试数
+    pub passed: usize,
+    pub total: usize,
+    /// 第一次全部通过的时间
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub solved: Option<Timestamp>,
+}
+
+fn is_zero(n: &usize) -> bool {
+    *n == 0
+}
+
+fn is_false(b: &bool) -> bool {
+    !*b
+}
+
+/// 一次测验的成绩
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct QuizScore {
+    pub at: Timestamp,
+    pub correct: usize,
+    pub total: usize,
+}
+
+/// 学完 lesson 后的自评：对掌握程度有多大把握，1（没把握）到 5（很有把握）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Confidence {
+    pub at: Timestamp,
+    pub level: u8,
+}
+
+/// 一次运行：开始时间、选中的 lesson（或小节）与耗时
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Run {
+    pub at: Timestamp,
+    pub id: String,
+    pub millis: u64,
+}
+
+/// 一条带时间的学习笔记
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Note {
+    pub at: Timestamp,
+    pub text: String,
+}
+
+/// 书签：想回头再看的 lesson 或小节
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Bookmark {
+    /// lesson id 或 `<lesson id>:<section>`
+    pub id: String,
+    pub added: Timestamp,
+}
+
+impl Store {
+    /// 读取进度文件；不是 JSON 对象时按旧版的逐行格式解析。文件不存在时返回 `None`，
+    /// JSON 损坏时报错（写入也会失败，不会覆盖原文件）
+    fn read(path: &Path) -> io::Result<Option<Store>> {
+        let text = match fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(e),
+        };
+        if text.trim_start().starts_with('{') {
+            return serde_json::from_str(&text)
+                .map(Some)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e));
+        }
+        let modified = fs::metadata(path)?
+            .modified()
+            .ok()
+            .and_then(|t| t.duration_since(UNIX_EPOCH).ok())
+            .map(|d| Timestamp::from_unix(d.as_secs() as i64))
+            .unwrap_or_else(Timestamp::now);
+        Ok(Some(Store::from_lines(&text, modified)))
+    }
+
+    /// 删除一个 lesson（连同其小节）除运行记录以外的全部记录
+    fn forget(&mut self, id: &str) {
+        self.completed.retain(|key, _| !belongs_to(key, id));
+        self.bookmarks.retain(|b| !belongs_to(&b.id, id));
+        self.notes.retain(|key, _| !belongs_to(key, id));
+        self.quizzes.retain(|key, _| !belongs_to(key, id));
+        self.exercises.remove(id);
+        self.tests.remove(id);
+        self.challenges.remove(id);
+        self.confidence.remove(id);
+        self.tested_out.remove(id);
+    }
+
+    /// 旧版格式：每行一个已完成的 id
+    fn from_lines(text: &str, at: Timestamp) -> Store {
+        let completed = text
+            .lines()
+            .map(str::trim)
+            .filter(|l| !l.is_empty())
+            .map(|id| (id.to_string(), at))
+            .collect();
+        Store {
+            completed,
+            ..Store::default()
+        }
+    }
+
+    /// 并入旧版文件中的完成记录：取并集，同一 id 保留较早的完成时间
+    fn merge(&mut self, other: Store) {
+        for (id, at) in other.completed {
+            self.completed
+                .entry(id)
+                .and_modify(|t| *t = (*t).min(at))
+                .or_insert(at);
+        }
+    }
+
+    /// 见 [`Progress::migrate`]；返回是否有改动
+    fn migrate(&mut self, metas: &[LessonMeta]) -> bool {
+        let completed = &self.completed;
+        let mut replacements: Vec<(String, Timestamp)> = metas
+            .iter()
+            .filter_map(|old| Some((completed.get(&old.id())?, old.replaced_by?)))
+            .filter_map(|(at, slug)| Some((metas.iter().find(|m| m.slug == slug)?.id(), *at)))
+            .collect();
+        replacements.extend(
+            completed
+                .iter()
+                .filter_map(|(done, at)| Some((current_id(metas, done)?, *at))),
+        );
+        let mut completed = false;
+        for (id, at) in replacements {
+            if let Entry::Vacant(entry) = self.completed.entry(id) {
+                log::debug!("migrating progress to {}", entry.key());
+                entry.insert(at);
+                completed = true;
+            }
+        }
+
+        let mut renamed = false;
+        for bookmark in &mut self.bookmarks {
+            if let Some(id) = current_id(metas, &bookmark.id) {
+                log::debug!("migrating bookmark {} to {}", bookmark.id, id);
+                bookmark.id = id;
+                renamed = true;
+            }
+        }
+        if renamed {
+            let mut seen = BTreeSet::new();
+            self.bookmarks.retain(|b| seen.insert(b.id.clone()));
+        }
+
+        let moved_notes = migrate_keys(&mut self.notes, metas, |n| n.at);
+        let moved_quizzes = migrate_keys(&mut self.quizzes, metas, |q| q.at);
+        let moved_exercises = migrate_latest(&mut self.exercises, metas);
+        let moved_tests = migrate_latest(&mut self.tests, metas);
+        let moved_challenges = migrate_latest(&mut self.challenges, metas);
+        let moved_confidence = migrate_latest(&mut self.confidence, metas);
+        let moved_tested_out = migrate_latest(&mut self.tested_out, metas);
+        completed
+            || renamed
+            || moved_notes
+            || moved_quizzes
+            || moved_exercises
+            || moved_tests
+            || moved_challenges
+            || moved_confidence
+            || moved_tested_out
+    }
+}
+
+/// 默认学习档案的名字，进度记在 `progress.json`（或配置的 `progress_file`）中
+pub const DEFAULT_PROFILE: &str = "default";
+
+#[derive(Debug, Default)]
+pub struct Progress {
+    /// 记录文件；找不到数据目录时为 `None`，进度只保存在内存中
+    path: Option<PathBuf>,
+    store: Store,
+}
+
+impl Progress {
+    /// 读取进度记录：`path` 为空时使用默认位置；文件不存在视为尚无进度
+    pub fn load(path: Option<&Path>) -> Progress {
+        if let Some(path) = path {
+            return Progress::at(path);
+        }
+        let Some(dir) = data_dir() else {
+            return Progress::default();
+        };
+        let path = dir.join("progress.json");
+        let mut progress = Progress::at(&path);
+        if !path.exists() {
+            progress.import(&dir.join("completed"));
+        }
+        progress
+    }
+
+    /// 读取学习档案 `name` 的进度；默认档案同 [`Progress::load`]
+    pub fn load_profile(name: &str, default_file: Option<&Path>) -> Progress {
+        if name == DEFAULT_PROFILE {
+            return Progress::load(default_file);
+        }
+        match data_dir() {
+            Some(dir) => Progress::at(&dir.join("profiles").join(format!("{}.json", name))),
+            None => Progress::default(),
+        }
+    }
+
+    pub fn at(path: &Path) -> Progress {
+        let store = Store::read(path).unwrap_or_else(|e| {
+            eprintln!(
+                "Warning: cannot read progress from {}: {}",
+                path.display(),
+                e
+            );
+            None
+        });
+        Progress {
+            path: Some(path.to_path_buf()),
+            store: store.unwrap_or_default(),
+        }
+    }
+
+    /// 重新读取记录文件，拿到子进程（例如 `practice` 启动的检查）写入的记录；
+    /// 读取失败时保留内存中的进度
+    pub fn reload(&mut self) {
+        if let Some(path) = &self.path
+            && let Ok(Some(store)) = Store::read(path)
+        {
+            self.store = store;
+        }
+    }
+
+    /// 导入旧版的进度文件并立即以新格式保存
+    fn import(&mut self, legacy: &Path) {
+        let Ok(Some(store)) = Store::read(legacy) else {
+            return;
+        };
+        log::debug!("importing progress from {}", legacy.display());
+        if let Err(e) = self.update(|current| current.merge(store)) {
+            eprintln!("Warning: cannot save progress: {}", e);
+        }
+    }
+
+    pub fn is_complete(&self, id: &str) -> bool {
+        self.store.completed.contains_key(id)
+    }
+
+    /// 完成时间；未完成时为 `None`
+    pub fn completed_at(&self, id: &str) -> Option<Timestamp> {
+        self.store.completed.get(id).copied()
+    }
+
+    /// 标记为已完成（记录当前时间）并保存；已完成的保留原来的时间
+    pub fn complete(&mut self, id: &str) -> io::Result<()> {
+        self.complete_at(id, Timestamp::now())
+    }
+
+    fn complete_at(&mut self, id: &str, at: Timestamp) -> io::Result<()> {
+        self.update(|store| {
+            store.completed.entry(id.to_string()).or_insert(at);
+        })
+    }
+
+    pub fn bookmarks(&self) -> &[Bookmark] {
+        &self.store.bookmarks
+    }
+
+    /// 添加书签并保存；返回是否新加（已有的不重复添加）
+    pub fn add_bookmark(&mut self, id: &str) -> io::Result<bool> {
+        self.update(|store| {
+            if store.bookmarks.iter().any(|b| b.id == id) {
+                return false;
+            }
+            store.bookmarks.push(Bookmark {
+                id: id.to_string(),
+                added: Timestamp::now(),
+            });
+            true
+        })
+    }
+
+    /// 删除书签并保存；返回是否存在
+    pub fn remove_bookmark(&mut self, id: &str) -> io::Result<bool> {
+        self.update(|store| {
+            let before = store.bookmarks.len();
+            store.bookmarks.retain(|b| b.id != id);
+            store.bookmarks.len() != before
+        })
+    }
+
+    /// lesson 的笔记，按记录时间排序
+    pub fn notes(&self, id: &str) -> &[Note] {
+        self.store
+            .notes
+            .get(id)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// 有笔记的 lesson 及其笔记，按 id 排序
+    pub fn all_notes(&self) -> impl Iterator<Item = (&str, &[Note])> {
+        self.store
+            .notes
+            .iter()
+            .map(|(id, notes)| (id.as_str(), notes.as_slice()))
+    }
+
+    /// 追加一条笔记（记录当前时间）并保存
+    pub fn add_note(&mut self, id: &str, text: &str) -> io::Result<()> {
+        let note = Note {
+            at: Timestamp::now(),
+            text: text.to_string(),
+        };
+        self.update(|store| store.notes.entry(id.to_string()).or_default().push(note))
+    }
+
+    /// lesson 的历次测验成绩，按时间排序
+    pub fn quiz_scores(&self, id: &str) -> &[QuizScore] {
+        self.store
+            .quizzes
+            .get(id)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// 记录一次测验成绩并保存
+    pub fn record_quiz(&mut self, id: &str, correct: usize, total: usize) -> io::Result<()> {
+        let score = QuizScore {
+            at: Timestamp::now(),
+            correct,
+            total,
+        };
+        self.update(|store| store.quizzes.entry(id.to_string()).or_default().push(score))
+    }
+
+    /// lesson 的练习完成情况；还没有运行过检查时为 `None`
+    pub fn exercise(&self, id: &str) -> Option<&ExerciseState> {
+        self.store.exercises.get(id)
+    }
+
+    /// 记录一次练习检查的结果并保存；全部通过时记下首次完成的时间
+    pub fn record_exercise(
+        &mut self,
+        id: &str,
+        passed: Vec<String>,
+        total: usize,
+    ) -> io::Result<()> {
+        self.update(|store| {
+            let state = store.exercises.entry(id.to_string()).or_default();
+            state.attempts += 1;
+            if passed.len() == total && state.solved.is_none() {
+                state.solved = Some(Timestamp::now());
+            }
+            state.passed = passed;
+            state.total = total;
+        })
+    }
+
+    /// 记录看过第 `n` 条提示（从 1 开始）并保存；练习完成之后再看提示不影响得分
+    pub fn use_hint(&mut self, id: &str, n: usize) -> io::Result<()> {
+        self.update(|store| {
+            let state = store.exercises.entry(id.to_string()).or_default();
+            if !state.is_solved() {
+                state.hints = state.hints.max(n);
+            }
+        })
+    }
+
+    /// 记录看过参考答案并保存；练习完成之后再看不影响得分
+    pub fn reveal_solution(&mut self, id: &str) -> io::Result<()> {
+        self.update(|store| {
+            let state = store.exercises.entry(id.to_string()).or_default();
+            if !state.is_solved() {
+                state.revealed = true;
+            }
+        })
+    }
+
+    /// `check` 最近一次全部通过的时间
+    pub fn tests_passed(&self, id: &str) -> Option<Timestamp> {
+        self.store.tests.get(id).copied()
+    }
+
+    /// 记录 lesson 的测试全部通过并保存
+    pub fn record_tests_passed(&mut self, id: &str) -> io::Result<()> {
+        let now = Timestamp::now();
+        self.update(|store| {
+            store.tests.insert(id.to_string(), now);
+        })
+    }
+
+    /// 计时挑战的最好用时
+    pub fn best_challenge(&self, id: &str) -> Option<Duration> {
+        self.store
+            .challenges
+            .get(id)
+            .map(|&ms| Duration::from_millis(ms))
+    }
+
+    /// 记录一次全部答对的挑战，返回是否刷新了最好用时
+    pub fn record_challenge(&mut self, id: &str, elapsed: Duration) -> io::Result<bool> {
+        let ms = elapsed.as_millis() as u64;
+        self.update(|store| {
+            if store.challenges.get(id).is_some_and(|&best| best <= ms) {
+                return false;
+            }
+            store.challenges.insert(id.to_string(), ms);
+            true
+        })
+    }
+
+    /// 最近一次自评
+    pub fn confidence(&self, id: &str) -> Option<Confidence> {
+        self.store.confidence.get(id).copied()
+    }
+
+    /// 记录自评（1–5）并保存，覆盖之前的自评
+    pub fn record_confidence(&mut self, id: &str, level: u8) -> io::Result<()> {
+        let confidence = Confidence {
+            at: Timestamp::now(),
+            level,
+        };
+        self.update(|store| {
+            store.confidence.insert(id.to_string(), confidence);
+        })
+    }
+
+    /// kata 的完成情况；还没有运行过时为 `None`
+    pub fn kata(&self, name: &str) -> Option<&KataState> {
+        self.store.katas.get(name)
+    }
+
+    /// 记录一次 kata 测试的结果并保存；全部通过时记下首次完成的时间
+    pub fn record_kata(&mut self, name: &str, passed: usize, total: usize) -> io::Result<()> {
+        self.update(|store| {
+            let state = store.katas.entry(name.to_string()).or_default();
+            state.attempts += 1;
+            if passed == total && state.solved.is_none() {
+                state.solved = Some(Timestamp::now());
+            }
+            state.passed = passed;
+            state.total = total;
+        })
+    }
+
+    /// 分班测验判定可以跳过的时间；没有判定过时为 `None`
+    pub fn tested_out(&self, id: &str) -> Option<Timestamp> {
+        self.store.tested_out.get(id).copied()
+    }
+
+    /// 记录一次分班测验的结果并保存：`ids` 取代之前判定可以跳过的全部 lesson
+    pub fn record_placement(&mut self, ids: &[String]) -> io::Result<()> {
+        let now = Timestamp::now();
+        self.update(|store| store.tested_out = ids.iter().map(|id| (id.clone(), now)).collect())
+    }
+
+    /// 运行记录，按记录顺序
+    pub fn runs(&self) -> &[Run] {
+        &self.store.runs
+    }
+
+    /// 记录一次运行（开始于 `elapsed` 之前）并保存
+    pub fn record_run(&mut self, id: &str, elapsed: Duration) -> io::Result<()> {
+        let now = Timestamp::now().to_unix();
+        let run = Run {
+            at: Timestamp::from_unix(now - elapsed.as_secs() as i64),
+            id: id.to_string(),
+            millis: elapsed.as_millis() as u64,
+        };
+        self.update(|store| store.runs.push(run))
+    }
+
+    /// 清除选中 lesson 及其小节的全部记录（完成、成绩、练习、笔记、书签等）并保存；
+    /// 运行记录是实际花掉的学习时间，保留不动
+    pub fn reset_lessons(&mut self, ids: &[String]) -> io::Result<()> {
+        self.update(|store| ids.iter().for_each(|id| store.forget(id)))
+    }
+
+    /// 清除测验成绩并保存；`ids` 为 `None` 时清除全部 lesson 的
+    pub fn reset_quizzes(&mut self, ids: Option<&[String]>) -> io::Result<()> {
+        self.update(|store| match ids {
+            Some(ids) => store
+                .quizzes
+                .retain(|key, _| !ids.iter().any(|id| belongs_to(key, id))),
+            None => store.quizzes.clear(),
+        })
+    }
+
+    /// 清除全部记录（包括运行记录）并保存
+    pub fn reset_all(&mut self) -> io::Result<()> {
+        self.update(|store| *store = Store::default())
+    }
+
+    /// 在文件锁的保护下重新读取文件，用 `edit` 修改读到的最新内容，写入临时文件后改名替换原文件；
+    /// 内存中的进度随之换成写入的内容。文件还不存在时在内存中的进度上修改
+    fn update<T>(&mut self, edit: impl FnOnce(&mut Store) -> T) -> io::Result<T> {
+        let Some(path) = &self.path else {
+            return Ok(edit(&mut self.store));
+        };
+        if let Some(dir) = path.parent().filter(|d| !d.as_os_str().is_empty()) {
+            fs::create_dir_all(dir)?;
+        }
+        // 锁在 `lock` 离开作用域、文件关闭时释放
+        let lock = File::create(path.with_extension("lock"))?;
+        lock.lock()?;
+        if let Some(current) = Store::read(path)? {
+            self.store = current;
+        }
+        let result = edit(&mut self.store);
+        let mut json = serde_json::to_string_pretty(&self.store).map_err(io::Error::other)?;
+        json.push('\n');
+        let tmp = path.with_extension(format!("tmp{}", std::process::id()));
+        fs::write(&tmp, json)?;
+        fs::rename(&tmp, path)?;
+        Ok(result)
+    }
+
+    /// 把已弃用 lesson 的完成记录迁移到取代它的 lesson，
+    /// 并把以别名（例如 `renumber` 之前的旧 id）记录的 lesson、小节、书签、笔记、测验成绩、练习、测试、挑战、自评与分班结果迁移到当前 id；
+    /// 迁移后的完成记录沿用原来的完成时间
+    pub fn migrate(&mut self, metas: &[LessonMeta]) -> io::Result<()> {
+        // 先在内存中的副本上检查，没有需要迁移的记录时不写文件
+        if self.store.migrate(metas) {
+            self.update(|store| {
+                store.migrate(metas);
+            })?;
+        }
+        Ok(())
+    }
+}
+
+/// 把以别名为键的记录并入当前 id，按时间排序；返回是否有改动
+fn migrate_keys<T>(
+    map: &mut BTreeMap<String, Vec<T>>,
+    metas: &[LessonMeta],
+    at: impl Fn(&T) -> Timestamp,
+) -> bool {
+    let moved: Vec<(String, String)> = map
+        .keys()
+        .filter_map(|old| Some((old.clone(), current_id(metas, old)?)))
+        .collect();
+    for (old, id) in &moved {
+        log::debug!("migrating {} records to {}", old, id);
+        let records = map.remove(old).unwrap_or_default();
+        let merged = map.entry(id.clone()).or_default();
+        merged.extend(records);
+        merged.sort_by_key(&at);
+    }
+    !moved.is_empty()
+}
+
+/// `key` 是 lesson `id` 本身或其中的小节（`<id>:<section>`）
+fn belongs_to(key: &str, id: &str) -> bool {
+    key.strip_prefix(id)
+        .is_some_and(|rest| rest.is_empty() || rest.starts_with(':'))
+}
+
+/// 把以别名为键的单条记录移到当前 id，新旧 id 下都有记录时保留新的；返回是否有改动
+fn migrate_latest<T>(map: &mut BTreeMap<String, T>, metas: &[LessonMeta]) -> bool {
+    let moved: Vec<(String, String)> = map
+        .keys()
+        .filter_map(|old| Some((old.clone(), current_id(metas, old)?)))
+        .collect();
+    for (old, id) in &moved {
+        if let Some(record) = map.remove(old) {
+            map.entry(id.clone()).or_insert(record);
+        }
+    }
+    !moved.is_empty()
+}
+
+/// 以别名记录的 id（如 `06_ownership:move`）对应的当前 id；不是别名时为 `None`
+fn current_id(metas: &[LessonMeta], id: &str) -> Option<String> {
+    metas.iter().find_map(|meta| {
+        meta.aliases.iter().find_map(|alias| {
+            let rest = id.strip_prefix(alias)?;
+            (rest.is_empty() || rest.starts_with(':')).then(|| format!("{}{}", meta.id(), rest))
+        })
+    })
+}
+
+/// 档案名只能由字母、数字、`-` 与 `_` 组成，直接用作文件名
+pub fn is_valid_profile(name: &str) -> bool {
+    !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+}
+
+/// 已有的学习档案：默认档案在前，其余按名字排序
+pub fn profiles() -> Vec<String> {
+    let mut names: Vec<String> = data_dir()
+        .and_then(|dir| fs::read_dir(dir.join("profiles")).ok())
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            let name = path.file_stem()?.to_str()?;
+            (path.extension()? == "json" && is_valid_profile(name)).then(|| name.to_string())
+        })
+        .filter(|name| name != DEFAULT_PROFILE)
+        .collect();
+    names.sort();
+    names.insert(0, DEFAULT_PROFILE.to_string());
+    names
+}
+
+fn data_dir() -> Option<PathBuf> {
+    env::var_os("XDG_DATA_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".local/share")))
+        .map(|dir| dir.join("rust-learn"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        env::temp_dir().join(format!("rust-learn-{}-{}", name, std::process::id()))
+    }
+
+    #[test]
+    fn test_complete_is_persisted() {
+        let dir = temp_dir("progress");
+        let path = dir.join("progress.json");
+        let mut progress = Progress::at(&path);
+        assert!(!progress.is_complete("06_ownership"));
+
+        let at = Timestamp::from_unix(951_827_696);
+        progress.complete_at("06_ownership", at).unwrap();
+        progress.complete("06_ownership").unwrap();
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            "{\n  \"completed\": {\n    \"06_ownership\": \"2000-02-29T12:34:56Z\"\n  }\n}\n"
+        );
+        assert_eq!(Progress::at(&path).completed_at("06_ownership"), Some(at));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_save_merges_other_writers() {
+        let dir = temp_dir("progress-merge");
+        let path = dir.join("progress.json");
+        let mut first = Progress::at(&path);
+        let mut second = Progress::at(&path);
+        first.complete("01_hello_world").unwrap();
+        second.complete("02_variables").unwrap();
+        let reloaded = Progress::at(&path);
+        assert!(reloaded.is_complete("01_hello_world"));
+        assert!(reloaded.is_complete("02_variables"));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_stale_handle_keeps_other_records() {
+        let dir = temp_dir("progress-stale");
+        let path = dir.join("progress.json");
+        let mut long_running = Progress::at(&path);
+        let mut other = Progress::at(&path);
+        other.add_note("06_ownership", "move 之后不能再用").unwrap();
+        other.record_quiz("06_ownership", 4, 5).unwrap();
+        other.add_bookmark("13_traits").unwrap();
+        other.record_confidence("06_ownership", 3).unwrap();
+        other.record_kata("fizzbuzz", 2, 2).unwrap();
+        long_running
+            .record_exercise("06_ownership", Vec::new(), 2)
+            .unwrap();
+        long_running
+            .add_note("06_ownership", "clone 是深拷贝")
+            .unwrap();
+
+        let reloaded = Progress::at(&path);
+        let notes: Vec<_> = reloaded
+            .notes("06_ownership")
+            .iter()
+            .map(|n| n.text.as_str())
+            .collect();
+        assert_eq!(notes, ["move 之后不能再用", "clone 是深拷贝"]);
+        assert_eq!(reloaded.quiz_scores("06_ownership").len(), 1);
+        assert_eq!(reloaded.bookmarks().len(), 1);
+        assert_eq!(reloaded.confidence("06_ownership").unwrap().level, 3);
+        assert!(reloaded.kata("fizzbuzz").unwrap().solved.is_some());
+        assert_eq!(reloaded.exercise("06_ownership").unwrap().attempts, 1);
+        // 写入之后，长时间运行的进程也看到了其他进程的记录
+        assert_eq!(long_running.quiz_scores("06_ownership").len(), 1);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_bookmarks() {
+        let dir = temp_dir("progress-bookmarks");
+        let path = dir.join("progress.json");
+        let mut progress = Progress::at(&path);
+        assert!(
+            progress
+                .add_bookmark("14_lifetimes:struct_lifetimes")
+                .unwrap()
+        );
+        assert!(progress.add_bookmark("13_traits").unwrap());
+        assert!(!progress.add_bookmark("13_traits").unwrap());
+        // 并行运行的子进程写入完成记录时保留书签
+        Progress::at(&path).complete("01_hello_world").unwrap();
+        progress.complete("02_variables").unwrap();
+
+        let mut reloaded = Progress::at(&path);
+        let ids: Vec<_> = reloaded.bookmarks().iter().map(|b| b.id.as_str()).collect();
+        assert_eq!(ids, ["14_lifetimes:struct_lifetimes", "13_traits"]);
+        assert!(reloaded.is_complete("01_hello_world"));
+        assert!(reloaded.remove_bookmark("13_traits").unwrap());
+        assert!(!reloaded.remove_bookmark("13_traits").unwrap());
+        assert_eq!(Progress::at(&path).bookmarks().len(), 1);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_notes() {
+        let dir = temp_dir("progress-notes");
+        let path = dir.join("progress.json");
+        let mut progress = Progress::at(&path);
+        progress
+            .add_note("13_traits", "trait objects vs generics: revisit")
+            .unwrap();
+        progress.add_note("13_traits", "dyn 需要对象安全").unwrap();
+        progress
+            .add_note("01_hello_world", "println! 是宏")
+            .unwrap();
+
+        let reloaded = Progress::at(&path);
+        let texts: Vec<_> = reloaded
+            .notes("13_traits")
+            .iter()
+            .map(|n| &n.text)
+            .collect();
+        assert_eq!(
+            texts,
+            ["trait objects vs generics: revisit", "dyn 需要对象安全"]
+        );
+        let ids: Vec<_> = reloaded.all_notes().map(|(id, _)| id).collect();
+        assert_eq!(ids, ["01_hello_world", "13_traits"]);
+        assert!(reloaded.notes("14_lifetimes").is_empty());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_quiz_scores() {
+        let dir = temp_dir("progress-quiz");
+        let path = dir.join("progress.json");
+        let mut progress = Progress::at(&path);
+        progress.record_quiz("07_borrowing", 2, 4).unwrap();
+        progress.record_quiz("07_borrowing", 4, 4).unwrap();
+        let scores: Vec<_> = Progress::at(&path)
+            .quiz_scores("07_borrowing")
+            .iter()
+            .map(|q| (q.correct, q.total))
+            .collect();
+        assert_eq!(scores, [(2, 4), (4, 4)]);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_record_exercise() {
+        let dir = temp_dir("progress-exercise");
+        let path = dir.join("progress.json");
+        let mut progress = Progress::at(&path);
+        assert!(progress.exercise("08_slices").is_none());
+        progress
+            .record_exercise("08_slices", vec![String::from("sum")], 3)
+            .unwrap();
+        assert!(!progress.exercise("08_slices").unwrap().is_solved());
+        let all = ["first_word", "sum", "middle"].map(String::from).to_vec();
+        progress
+            .record_exercise("08_slices", all.clone(), 3)
+            .unwrap();
+        let solved = progress.exercise("08_slices").unwrap().solved;
+        // 之后再有检查失败也保留首次完成的时间
+        progress
+            .record_exercise("08_slices", Vec::new(), 3)
+            .unwrap();
+
+        let state = Progress::at(&path).exercise("08_slices").unwrap().clone();
+        assert_eq!(state.attempts, 3);
+        assert!(state.passed.is_empty());
+        assert!(solved.is_some() && state.solved == solved);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_reload() {
+        let dir = temp_dir("progress-reload");
+        let path = dir.join("progress.json");
+        let mut progress = Progress::at(&path);
+        Progress::at(&path).complete("01_hello_world").unwrap();
+        assert!(!progress.is_complete("01_hello_world"));
+        progress.reload();
+        assert!(progress.is_complete("01_hello_world"));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_record_kata() {
+        let dir = temp_dir("progress-kata");
+        let path = dir.join("progress.json");
+        let mut progress = Progress::at(&path);
+        progress.record_kata("fizzbuzz", 3, 3).unwrap();
+        progress.record_kata("fizzbuzz", 1, 3).unwrap();
+        let state = Progress::at(&path).kata("fizzbuzz").unwrap().clone();
+        assert_eq!((state.attempts, state.passed, state.total), (2, 1, 3));
+        assert!(state.solved.is_some());
+        assert!(progress.kata("reverse_words").is_none());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_record_placement() {
+        let dir = temp_dir("progress-placement");
+        let path = dir.join("progress.json");
+        let mut progress = Progress::at(&path);
+        progress
+            .record_placement(&["01_hello_world".to_string(), "02_variables".to_string()])
+            .unwrap();
+        progress
+            .record_placement(&["02_variables".to_string()])
+            .unwrap();
+        let progress = Progress::at(&path);
+        assert!(progress.tested_out("01_hello_world").is_none());
+        assert!(progress.tested_out("02_variables").is_some());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_reset() {
+        let dir = temp_dir("progress-reset");
+        let path = dir.join("progress.json");
+        let mut progress = Progress::at(&path);
+        progress.complete("06_ownership").unwrap();
+        progress.complete("06_ownership:move").unwrap();
+        progress.complete("07_borrowing").unwrap();
+        progress.add_note("06_ownership", "move").unwrap();
+        progress.add_bookmark("06_ownership:move").unwrap();
+        progress.record_quiz("06_ownership", 1, 3).unwrap();
+        progress.record_quiz("07_borrowing", 2, 3).unwrap();
+        progress
+            .record_run("06_ownership", Duration::from_secs(60))
+            .unwrap();
+
+        progress.reset_quizzes(None).unwrap();
+        assert!(progress.quiz_scores("07_borrowing").is_empty());
+        progress.record_quiz("07_borrowing", 2, 3).unwrap();
+
+        progress
+            .reset_lessons(&["06_ownership".to_string()])
+            .unwrap();
+        // 重新读取文件：删除的完成记录没有被合并回来
+        let reloaded = Progress::at(&path);
+        assert!(!reloaded.is_complete("06_ownership"));
+        assert!(!reloaded.is_complete("06_ownership:move"));
+        assert!(reloaded.notes("06_ownership").is_empty());
+        assert!(reloaded.bookmarks().is_empty());
+        assert!(reloaded.is_complete("07_borrowing"));
+        assert_eq!(reloaded.quiz_scores("07_borrowing").len(), 1);
+        assert_eq!(reloaded.runs().len(), 1);
+
+        progress.reset_all().unwrap();
+        let reloaded = Progress::at(&path);
+        assert!(!reloaded.is_complete("07_borrowing"));
+        assert!(reloaded.runs().is_empty());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_belongs_to() {
+        assert!(belongs_to("06_ownership", "06_ownership"));
+        assert!(belongs_to("06_ownership:move", "06_ownership"));
+        assert!(!belongs_to("06_ownership_extra", "06_ownership"));
+        assert!(!belongs_to("07_borrowing", "06_ownership"));
+    }
+
+    #[test]
+    fn test_hints_lower_the_score() {
+        let mut progress = Progress::default();
+        progress.use_hint("08_slices", 2).unwrap();
+        progress.use_hint("08_slices", 1).unwrap();
+        assert_eq!(progress.exercise("08_slices").unwrap().hints, 2);
+        assert_eq!(progress.exercise("08_slices").unwrap().score(), None);
+
+        let all = ["first_word", "sum"].map(String::from).to_vec();
+        progress.record_exercise("08_slices", all, 2).unwrap();
+        // 完成之后再看提示不再扣分
+        progress.use_hint("08_slices", 3).unwrap();
+        assert_eq!(progress.exercise("08_slices").unwrap().score(), Some(50));
+
+        progress
+            .record_exercise("09_structs", Vec::new(), 0)
+            .unwrap();
+        assert_eq!(progress.exercise("09_structs").unwrap().score(), Some(100));
+
+        progress.reveal_solution("10_enums_matching").unwrap();
+        progress
+            .record_exercise("10_enums_matching", Vec::new(), 0)
+            .unwrap();
+        assert_eq!(
+            progress.exercise("10_enums_matching").unwrap().score(),
+            Some(0)
+        );
+    }
+
+    #[test]
+    fn test_runs_are_merged() {
+        let dir = temp_dir("progress-runs");
+        let path = dir.join("progress.json");
+        let mut first = Progress::at(&path);
+        let mut second = Progress::at(&path);
+        first
+            .record_run("01_hello_world", Duration::from_millis(1500))
+            .unwrap();
+        second
+            .record_run("02_variables", Duration::from_millis(20))
+            .unwrap();
+        first
+            .record_run("01_hello_world", Duration::from_millis(1500))
+            .unwrap();
+
+        let reloaded = Progress::at(&path);
+        let runs: Vec<_> = reloaded
+            .runs()
+            .iter()
+            .map(|r| (r.id.as_str(), r.millis))
+            .collect();
+        assert_eq!(
+            runs,
+            [
+                ("01_hello_world", 1500),
+                ("02_variables", 20),
+                ("01_hello_world", 1500)
+            ]
+        );
+        let mut memory = Progress::default();
+        memory.record_run("01_hello_world", Duration::ZERO).unwrap();
+        assert_eq!(memory.runs().len(), 1);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_legacy_lines() {
+        let dir = temp_dir("progress-legacy");
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("completed");
+        fs::write(&path, "06_ownership\n\n07_borrowing:rules\n").unwrap();
+        let mut progress = Progress::at(&path);
+        assert!(progress.is_complete("06_ownership"));
+        assert!(progress.is_complete("07_borrowing:rules"));
+        assert!(progress.completed_at("06_ownership").is_some());
+        // 下次写入时改存为 JSON
+        progress.complete("08_references").unwrap();
+        assert!(fs::read_to_string(&path).unwrap().starts_with('{'));
+        assert!(Progress::at(&path).is_complete("06_ownership"));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_best_challenge() {
+        let mut progress = Progress::default();
+        assert_eq!(progress.best_challenge("06_ownership"), None);
+        assert!(
+            progress
+                .record_challenge("06_ownership", Duration::from_secs(30))
+                .unwrap()
+        );
+        assert!(
+            !progress
+                .record_challenge("06_ownership", Duration::from_secs(40))
+                .unwrap()
+        );
+        assert!(
+            progress
+                .record_challenge("06_ownership", Duration::from_secs(25))
+                .unwrap()
+        );
+        assert_eq!(
+            progress.best_challenge("06_ownership"),
+            Some(Duration::from_secs(25))
+        );
+    }
+
+    #[test]
+    fn test_profile_names() {
+        assert!(is_valid_profile("alice"));
+        assert!(is_valid_profile("group-2_bob"));
+        assert!(!is_valid_profile(""));
+        assert!(!is_valid_profile("../alice"));
+        assert!(!is_valid_profile("bob smith"));
+    }
+
+    #[test]
+    fn test_corrupt_file_is_not_overwritten() {
+        let dir = temp_dir("progress-corrupt");
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("progress.json");
+        fs::write(&path, "{\"completed\": ").unwrap();
+        let mut progress = Progress::at(&path);
+        assert!(progress.complete("06_ownership").is_err());
+        assert_eq!(fs::read_to_string(&path).unwrap(), "{\"completed\": ");
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_migrate_deprecated_lesson() {
+        let meta = |number, slug, replaced_by: Option<&'static str>| LessonMeta {
+            number,
+            slug,
+            aliases: &[],
+            title: slug,
+            chapter: crate::lesson::Chapter::Basics,
+            difficulty: crate::lesson::Difficulty::Beginner,
+            minutes: 10,
+            tags: &[],
+            prerequisites: &[],
+            deprecated: replaced_by.is_some(),
+            replaced_by,
+        };
+        let metas = [meta(1, "old", Some("new")), meta(2, "new", None)];
+        let mut progress = Progress::default();
+        progress.migrate(&metas).unwrap();
+        assert!(!progress.is_complete("02_new"));
+
+        let at = Timestamp::from_unix(0);
+        progress.complete_at("01_old", at).unwrap();
+        progress.migrate(&metas).unwrap();
+        assert_eq!(progress.completed_at("02_new"), Some(at));
+    }
+
+    #[test]
+    fn test_migrate_renumbered_lesson() {
+        let meta = LessonMeta {
+            number: 7,
+            slug: "ownership",
+            aliases: &["own", "06_ownership"],
+            title: "ownership",
+            chapter: crate::lesson::Chapter::Ownership,
+            difficulty: crate::lesson::Difficulty::Intermediate,
+            minutes: 25,
+            tags: &[],
+            prerequisites: &[],
+            deprecated: false,
+            replaced_by: None,
+        };
+        let mut progress = Progress::default();
+        progress.complete("06_ownership").unwrap();
+        progress.complete("06_ownership:move").unwrap();
+        progress.complete("06_ownership_extra").unwrap();
+        progress.migrate(std::slice::from_ref(&meta)).unwrap();
+        assert!(progress.is_complete("07_ownership"));
+        assert!(progress.is_complete("07_ownership:move"));
+        assert!(!progress.is_complete("07_ownership_extra"));
+
+        progress.add_bookmark("06_ownership:move").unwrap();
+        progress.add_bookmark("07_ownership:move").unwrap();
+        progress.add_bookmark("own").unwrap();
+        progress.migrate(std::slice::from_ref(&meta)).unwrap();
+        let ids: Vec<_> = progress.bookmarks().iter().map(|b| b.id.as_str()).collect();
+        assert_eq!(ids, ["07_ownership:move", "07_ownership"]);
+
+        progress
+            .add_note("06_ownership", "move 之后不能再用")
+            .unwrap();
+        progress.add_note("07_ownership", "clone 是深拷贝").unwrap();
+        progress.migrate(std::slice::from_ref(&meta)).unwrap();
+        let mut notes: Vec<_> = progress
+            .notes("07_ownership")
+            .iter()
+            .map(|n| n.text.as_str())
+            .collect();
+        notes.sort();
+        assert_eq!(notes, ["clone 是深拷贝", "move 之后不能再用"]);
+
+        progress.record_quiz("06_ownership", 2, 3).unwrap();
+        progress.migrate(std::slice::from_ref(&meta)).unwrap();
+        assert_eq!(progress.quiz_scores("07_ownership")[0].correct, 2);
+        assert!(progress.notes("06_ownership").is_empty());
+
+        progress
+            .record_exercise("06_ownership", Vec::new(), 3)
+            .unwrap();
+        progress.migrate(std::slice::from_ref(&meta)).unwrap();
+        assert_eq!(progress.exercise("07_ownership").unwrap().attempts, 1);
+        assert!(progress.exercise("06_ownership").is_none());
+
+        progress.record_tests_passed("06_ownership").unwrap();
+        progress.migrate(std::slice::from_ref(&meta)).unwrap();
+        assert!(progress.tests_passed("07_ownership").is_some());
+        assert!(progress.tests_passed("06_ownership").is_none());
+
+        progress.record_confidence("06_ownership", 2).unwrap();
+        progress.migrate(std::slice::from_ref(&meta)).unwrap();
+        assert_eq!(progress.confidence("07_ownership").unwrap().level, 2);
+        assert!(progress.confidence("06_ownership").is_none());
+
+        progress
+            .record_placement(&["06_ownership".to_string()])
+            .unwrap();
+        progress.migrate(std::slice::from_ref(&meta)).unwrap();
+        assert!(progress.tested_out("07_ownership").is_some());
+        assert!(progress.tested_out("06_ownership").is_none());
+    }
+}
@@ -0,0 +1,156 @@
+//! 导出为独立的 cargo 项目：`scaffold <lesson>[:<section>] <dir>`
+//!
+//! 在 `<dir>` 中生成一个最小的 cargo 项目，`src/main.rs` 是 lesson 的代码与单元测试，
+//! 末尾附上与 [`share`](crate::share) 相同的运行器替身，讲解文本包括中英两种语言，直接运行时按 `--lang` 选择。
+//! `cargo run` 运行 lesson（指定小节时只运行这一节），`cargo test` 运行 lesson 自带的测试，
+//! 随便修改都不会影响课程本身。`Cargo.toml` 中有一个空的 `[workspace]`，放在课程源码树里也能单独构建。
+//! 需要在源码树中运行。
+
+use crate::error::RunnerError;
+use crate::lesson::Language;
+use crate::lessons;
+use crate::share;
+use crate::utils::fmt;
+use std::fs;
+use std::io::{self, Write};
+use std::path::Path;
+
+/// `{name}` 在生成时替换
+const CARGO_TOML: &str = r#"[package]
+name = "{name}"
+version = "0.1.0"
+edition = "2024"
+
+# 独立于课程的 workspace
+[workspace]
+
+[dependencies]
+"#;
+
+pub fn run(sel: &str, dir: &str, lang: Language, out: &mut dyn Write) -> Result<(), RunnerError> {
+    let selected = lessons::select(sel)?;
+    let [selected] = selected.as_slice() else {
+        return Err(RunnerError::Usage(format!(
+            "'{}' selects several lessons, scaffold one lesson or section at a time",
+            sel
+        )));
+    };
+    let dir = Path::new(dir);
+    if dir
+        .read_dir()
+        .is_ok_and(|mut entries| entries.next().is_some())
+    {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!("{} already exists and is not empty", dir.display()),
+        )
+        .into());
+    }
+    let main = share::program(selected, lang, true)?;
+    fs::create_dir_all(dir.join("src"))?;
+    fs::write(
+        dir.join("Cargo.toml"),
+        manifest(selected.lesson.meta().slug),
+    )?;
+    fs::write(dir.join("src").join("main.rs"), main)?;
+    writeln!(
+        out,
+        "Created a cargo project for {} in {}",
+        selected.id(),
+        dir.display()
+    )?;
+    writeln!(
+        out,
+        "{}",
+        fmt::dim(&format!(
+            "cd {} && cargo run   # `cargo test` runs the lesson's tests",
+            dir.display()
+        ))
+    )?;
+    Ok(())
+}
+
+/// 包名用 slug，下划线换成连字符
+fn manifest(slug: &str) -> String {
+    CARGO_TOML.replace("{name}", &slug.replace('_', "-"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+    use std::process::Command;
+    use std::thread;
+
+    #[test]
+    fn test_manifest() {
+        let manifest = manifest("iterators_closures");
+        assert!(manifest.starts_with("[package]\nname = \"iterators-closures\"\n"));
+        assert!(manifest.contains("\n[workspace]\n"));
+    }
+
+    #[test]
+    fn test_run() {
+        let dir = env::temp_dir().join(format!("rust-learn-project-{}", std::process::id()));
+        let dir_str = dir.to_str().unwrap();
+        let mut out = Vec::new();
+        run("16:closure_capture", dir_str, Language::Zh, &mut out).unwrap();
+        let printed = String::from_utf8(out).unwrap();
+        assert!(
+            printed
+                .starts_with("Created a cargo project for 16_iterators_closures:closure_capture")
+        );
+        let main = fs::read_to_string(dir.join("src/main.rs")).unwrap();
+        assert!(main.contains("const ONLY: Option<&str> = Some(\"closure_capture\");"));
+        assert!(main.contains("mod tests {"));
+        assert!(matches!(
+            run("16", dir_str, Language::Zh, &mut io::sink()),
+            Err(RunnerError::Io(_))
+        ));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    /// 每个编译进来的 lesson 生成的 `main.rs` 都能编译，自带的测试在替身上也能通过
+    #[test]
+    fn test_lesson_tests_pass() {
+        let dir = env::temp_dir().join(format!("rust-learn-project-tests-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let rustc = env::var("RUSTC").unwrap_or_else(|_| String::from("rustc"));
+        thread::scope(|scope| {
+            for lesson in lessons::all() {
+                let (dir, rustc) = (&dir, &rustc);
+                scope.spawn(move || {
+                    let id = lesson.meta().id();
+                    let selected = lessons::select(&id).unwrap();
+                    let file = dir.join(format!("{}.rs", id));
+                    fs::write(
+                        &file,
+                        share::program(&selected[0], Language::Zh, true).unwrap(),
+                    )
+                    .unwrap();
+                    let bin = dir.join(&id);
+                    let compiled = Command::new(rustc)
+                        .args(["--edition", "2024", "--test", "-D", "warnings", "-o"])
+                        .arg(&bin)
+                        .arg(&file)
+                        .output()
+                        .unwrap();
+                    assert!(
+                        compiled.status.success(),
+                        "{}: {}",
+                        id,
+                        String::from_utf8_lossy(&compiled.stderr)
+                    );
+                    let tested = Command::new(&bin).arg("-q").output().unwrap();
+                    assert!(
+                        tested.status.success(),
+                        "{}: {}",
+                        id,
+                        String::from_utf8_lossy(&tested.stdout)
+                    );
+                });
+            }
+        });
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
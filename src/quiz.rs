@@ -0,0 +1,220 @@
+//! 测验：`quiz <lesson>`
+//!
+//! lesson 文件中的 `QUIZ` 是题库，题目比每次测验提问的多：每次随机抽出 [`QUIZ_LENGTH`] 道、
+//! 选项顺序也打乱，重做测验考的是记忆而不是选项的位置。依次显示单选题，读取答案编号，
+//! 当场给出对错与解释，最后计分并把成绩记入学习进度（见 [`progress`](crate::progress)），
+//! `info` 中显示最好成绩。`--deterministic` 时随机种子固定，每次抽到的题与顺序都相同。
+
+use crate::error::RunnerError;
+use crate::i18n;
+use crate::lesson::{Language, Question};
+use crate::lessons;
+use crate::progress::Progress;
+use crate::utils::deterministic;
+use crate::utils::fmt;
+use crate::utils::io::Prompter;
+use crate::utils::rng::Rng;
+use std::io::{self, BufRead, Write};
+
+/// 每次测验提问的题数；题库不够时全部提问
+pub const QUIZ_LENGTH: usize = 3;
+
+/// 一次测验中实际提问的题：从题库抽出，选项已打乱
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Drawn {
+    pub prompt: &'static str,
+    pub choices: Vec<&'static str>,
+    /// 正确选项在打乱后的 `choices` 中的下标
+    pub answer: usize,
+    pub explanation: &'static str,
+}
+
+impl Drawn {
+    pub fn is_correct(&self, choice: usize) -> bool {
+        choice == self.answer
+    }
+}
+
+/// 从题库中随机抽出至多 `count` 道题，并打乱每道题的选项
+pub(crate) fn draw(bank: &[Question], count: usize, rng: &mut Rng) -> Vec<Drawn> {
+    let mut picked: Vec<&Question> = bank.iter().collect();
+    rng.shuffle(&mut picked);
+    picked.truncate(count);
+    picked
+        .into_iter()
+        .map(|q| {
+            let mut order: Vec<usize> = (0..q.choices.len()).collect();
+            rng.shuffle(&mut order);
+            Drawn {
+                prompt: q.prompt,
+                choices: order.iter().map(|&i| q.choices[i]).collect(),
+                answer: order.iter().position(|&i| i == q.answer).unwrap_or(0),
+                explanation: q.explanation,
+            }
+        })
+        .collect()
+}
+
+/// 对选中的每个 lesson 进行测验
+pub fn run(
+    sel: &str,
+    progress: &mut Progress,
+    lang: Language,
+    out: &mut dyn Write,
+) -> Result<(), RunnerError> {
+    let mut prompter = Prompter::stdin();
+    let mut rng = Rng::new(deterministic::seed());
+    for lesson in lessons::resolve(sel)? {
+        let meta = lesson.meta();
+        let questions = draw(&lesson.quiz(), QUIZ_LENGTH, &mut rng);
+        if questions.is_empty() {
+            writeln!(out, "{} has no quiz yet", meta.id())?;
+            continue;
+        }
+        writeln!(out, "{}", meta.header(lang))?;
+        let correct = ask(&questions, &mut prompter, lang)?;
+        progress.record_quiz(&meta.id(), correct, questions.len())?;
+        let best = progress
+            .quiz_scores(&meta.id())
+            .iter()
+            .map(|q| q.correct)
+            .max()
+            .unwrap_or(correct);
+        writeln!(
+            out,
+            "\n{}",
+            i18n::tr_with(
+                lang,
+                "ui.quiz_score",
+                &[
+                    ("correct", &correct.to_string()),
+                    ("total", &questions.len().to_string()),
+                    ("best", &best.to_string()),
+                ]
+            )
+        )?;
+    }
+    Ok(())
+}
+
+/// 逐题提问，返回答对的题数
+pub(crate) fn ask<R: BufRead, W: Write>(
+    questions: &[Drawn],
+    prompter: &mut Prompter<R, W>,
+    lang: Language,
+) -> io::Result<usize> {
+    let total = questions.len().to_string();
+    let mut correct = 0;
+    for (i, question) in questions.iter().enumerate() {
+        let n = (i + 1).to_string();
+        let prompt = i18n::tr_with(
+            lang,
+            "ui.quiz_question",
+            &[("n", &n), ("total", &total), ("prompt", question.prompt)],
+        );
+        prompter.say(&format!("\n{}", fmt::paragraph(&prompt, "", "")))?;
+        for (j, choice) in question.choices.iter().enumerate() {
+            prompter.say(&fmt::paragraph(choice, &format!("  {}) ", j + 1), "     "))?;
+        }
+        let answer = i18n::tr(lang, "ui.quiz_answer");
+        let choice = prompter.read_number(&answer, 1..=question.choices.len() as i64)?;
+        if question.is_correct(choice as usize - 1) {
+            correct += 1;
+            prompter.say(&fmt::text(&i18n::tr(lang, "ui.quiz_correct")))?;
+        } else {
+            let answer = format!(
+                "{}) {}",
+                question.answer + 1,
+                question.choices[question.answer]
+            );
+            prompter.say(&fmt::text(&i18n::tr_with(
+                lang,
+                "ui.quiz_wrong",
+                &[("answer", &answer)],
+            )))?;
+        }
+        prompter.say(&fmt::paragraph(question.explanation, "  ", "  "))?;
+    }
+    Ok(correct)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeSet;
+
+    const QUIZ: &[Question] = &[
+        Question::new("1 + 1 = ?", &["1", "2", "3"], 1, "加法"),
+        Question::new("2 * 3 = ?", &["5", "6"], 1, "乘法"),
+    ];
+
+    /// 不打乱，按题库原样提问
+    fn in_order(bank: &[Question]) -> Vec<Drawn> {
+        bank.iter()
+            .map(|q| Drawn {
+                prompt: q.prompt,
+                choices: q.choices.to_vec(),
+                answer: q.answer,
+                explanation: q.explanation,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_draw() {
+        let bank = [
+            QUIZ[0],
+            QUIZ[1],
+            Question::new("3 - 1 = ?", &["2", "1", "0", "3"], 0, "减法"),
+        ];
+        let mut rng = Rng::new(1);
+        let drawn = draw(&bank, 2, &mut rng);
+        assert_eq!(drawn.len(), 2);
+        for d in &drawn {
+            let q = bank.iter().find(|q| q.prompt == d.prompt).unwrap();
+            assert_eq!(d.choices[d.answer], q.choices[q.answer]);
+            let mut choices = d.choices.clone();
+            choices.sort();
+            let mut expected = q.choices.to_vec();
+            expected.sort();
+            assert_eq!(choices, expected);
+        }
+        assert_ne!(drawn[0].prompt, drawn[1].prompt);
+        // 同一个种子抽到同样的题
+        assert_eq!(draw(&bank, 2, &mut Rng::new(1)), drawn);
+        assert_eq!(draw(&bank, 5, &mut rng).len(), 3);
+        // 多抽几次，每道题、每个位置都会出现
+        let mut prompts = BTreeSet::new();
+        let mut positions = BTreeSet::new();
+        for _ in 0..50 {
+            for d in draw(&bank, 1, &mut rng) {
+                prompts.insert(d.prompt);
+                positions.insert(d.answer);
+            }
+        }
+        assert_eq!(prompts.len(), 3);
+        assert!(positions.len() >= 3);
+    }
+
+    #[test]
+    fn test_ask() {
+        let mut output = Vec::new();
+        let mut prompter = Prompter::new("2\n9\n1\n".as_bytes(), &mut output);
+        assert_eq!(
+            ask(&in_order(QUIZ), &mut prompter, Language::Zh).unwrap(),
+            1
+        );
+        let text = String::from_utf8(output).unwrap();
+        assert!(text.contains("第 1/2 题：1 + 1 = ?\n  1) 1\n  2) 2\n  3) 3\n"));
+        assert!(text.contains("✓ 回答正确\n  加法"));
+        assert!(text.contains("请输入 1 到 2 之间的整数"));
+        assert!(text.contains("✗ 回答错误，正确答案是 2) 6\n  乘法"));
+    }
+
+    #[test]
+    fn test_ask_input_closed() {
+        let mut prompter = Prompter::new("2\n".as_bytes(), Vec::new());
+        let err = ask(&in_order(QUIZ), &mut prompter, Language::En).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+    }
+}
@@ -0,0 +1,111 @@
+//! 维护命令 `update-readme [file]`：重新生成 README 中的 lesson 列表
+//!
+//! 列表按注册表生成（编号、slug、标题、难度与运行命令），写在 README 中的两个标记之间：
+//!
+//! ```text
+//! <!-- lessons:begin -->
+//! <!-- lessons:end -->
+//! ```
+//!
+//! 标记之外的内容保持不变。新增、改名或调整编号之后运行一次即可，单元测试会检查
+//! 源码树中的 README 与注册表一致。只列出本 crate 内置的 lesson，不包括 lesson 包；
+//! 需要以默认 feature（全部 lesson）构建。
+
+use crate::curriculum;
+use crate::error::RunnerError;
+use crate::i18n;
+use crate::lesson::{Language, LessonMeta};
+use crate::lessons;
+use std::fs;
+use std::io::Write;
+
+const BEGIN: &str = "<!-- lessons:begin -->";
+const END: &str = "<!-- lessons:end -->";
+
+/// 更新 `file`（默认是源码树中的 `README.md`）
+pub fn run(file: Option<&str>, out: &mut dyn Write) -> Result<(), RunnerError> {
+    let path = file.map_or_else(|| curriculum::root().join("README.md"), Into::into);
+    let text = fs::read_to_string(&path)?;
+    let metas: Vec<LessonMeta> = lessons::builtin().iter().map(|l| l.meta()).collect();
+    let updated = update(&text, &table(&metas))?;
+    if updated == text {
+        writeln!(out, "{} is up to date", path.display())?;
+    } else {
+        fs::write(&path, updated)?;
+        writeln!(
+            out,
+            "Updated the lesson table in {} ({} lessons)",
+            path.display(),
+            metas.len()
+        )?;
+    }
+    Ok(())
+}
+
+/// 把 `text` 中两个标记之间的内容换成 `table`
+fn update(text: &str, table: &str) -> Result<String, RunnerError> {
+    let missing = || {
+        RunnerError::Config(format!(
+            "README has no lesson table markers, add {} and {} where the table goes",
+            BEGIN, END
+        ))
+    };
+    let begin = text.find(BEGIN).ok_or_else(missing)? + BEGIN.len();
+    let end = begin + text[begin..].find(END).ok_or_else(missing)?;
+    Ok(format!("{}\n{}{}", &text[..begin], table, &text[end..]))
+}
+
+/// Markdown 表格，标题用中文译文（README 是中文的）
+fn table(metas: &[LessonMeta]) -> String {
+    let mut out = String::from(
+        "| 编号 | Lesson | 主题 | 难度 | 运行 |\n|------|--------|------|------|------|\n",
+    );
+    for meta in metas {
+        let mut title = i18n::title(meta, Language::Zh).replace('|', "\\|");
+        if meta.deprecated {
+            title.push_str("（已弃用）");
+        }
+        out.push_str(&format!(
+            "| {:02} | `{}` | {} | {} | `cargo run -- {}` |\n",
+            meta.number,
+            meta.slug,
+            title,
+            meta.difficulty,
+            meta.id()
+        ));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_update() {
+        let text = format!("# Title\n\n{}\nold\n{}\n\nmore\n", BEGIN, END);
+        let updated = update(&text, "| new |\n").unwrap();
+        assert_eq!(
+            updated,
+            format!("# Title\n\n{}\n| new |\n{}\n\nmore\n", BEGIN, END)
+        );
+        assert_eq!(update(&updated, "| new |\n").unwrap(), updated);
+        assert!(matches!(
+            update("# Title\n", ""),
+            Err(RunnerError::Config(_))
+        ));
+    }
+
+    /// 缺少 feature 时注册表不完整
+    #[cfg(all(feature = "basics", feature = "advanced"))]
+    #[test]
+    fn test_readme_is_up_to_date() {
+        let text = fs::read_to_string(curriculum::root().join("README.md")).unwrap();
+        let metas: Vec<LessonMeta> = lessons::builtin().iter().map(|l| l.meta()).collect();
+        assert_eq!(
+            update(&text, &table(&metas)).unwrap(),
+            text,
+            "the lesson table in README.md is stale, run `cargo run -- update-readme`"
+        );
+    }
+}
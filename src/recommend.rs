@@ -0,0 +1,257 @@
+//! 学习建议：`recommend`
+//!
+//! 综合前置关系、完成情况与测验成绩给出两条建议：
+//! - 接下来学什么：按推荐学习顺序（见 [`learning_path`](crate::lessons::learning_path)）第一个
+//!   还没完成、前置 lesson 都已完成的 lesson；
+//! - 先复习什么：已完成的 lesson 按掌握程度从低到高排，相同时最久没学过的在前。
+//!   掌握程度取测验最好成绩与自评（`--confidence`，1–5 分别折合 0、25、50、75、100 分）中较低的一项，
+//!   两项都没有的排最前。“学过”指运行、完成或做测验，取最近一次。
+
+use crate::error::RunnerError;
+use crate::grade;
+use crate::i18n;
+use crate::lesson::{Language, LessonMeta};
+use crate::lessons;
+use crate::progress::Progress;
+use crate::utils::fmt;
+use crate::utils::time::Timestamp;
+use std::io::Write;
+
+/// 最多列出的复习建议条数
+pub(crate) const REVIEWS: usize = 3;
+
+/// 一个待复习的 lesson
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Review {
+    pub meta: LessonMeta,
+    /// 测验最好成绩（百分制）
+    pub quiz: Option<u32>,
+    /// 自评，1–5
+    pub confidence: Option<u8>,
+    /// 最近一次学习的时间
+    pub studied: Option<Timestamp>,
+}
+
+impl Review {
+    /// 测验成绩与自评折合分数中较低的一项
+    fn mastery(&self) -> Option<u32> {
+        let confidence = self.confidence.map(|n| (u32::from(n) - 1) * 25);
+        match (self.quiz, confidence) {
+            (Some(quiz), Some(confidence)) => Some(quiz.min(confidence)),
+            (quiz, confidence) => quiz.or(confidence),
+        }
+    }
+}
+
+pub fn run(progress: &Progress, lang: Language, out: &mut dyn Write) -> Result<(), RunnerError> {
+    let order = lessons::learning_path().map_err(RunnerError::Config)?;
+    match next(&order, progress) {
+        Some(meta) => writeln!(
+            out,
+            "Next:   {:<22} {}",
+            meta.id(),
+            i18n::title(&meta, lang)
+        )?,
+        None => writeln!(out, "Next:   {}", fmt::text("every lesson is complete 🎉"))?,
+    }
+    let reviews = reviews(&order, progress);
+    if reviews.is_empty() {
+        writeln!(
+            out,
+            "Review: {}",
+            fmt::dim("nothing yet, complete a lesson first")
+        )?;
+        return Ok(());
+    }
+    show_reviews(&reviews[..reviews.len().min(REVIEWS)], lang, out)
+}
+
+/// 逐行列出复习建议，最后提示先测验第一个
+pub(crate) fn show_reviews(
+    reviews: &[Review],
+    lang: Language,
+    out: &mut dyn Write,
+) -> Result<(), RunnerError> {
+    for (i, review) in reviews.iter().enumerate() {
+        let quiz = review
+            .quiz
+            .map_or_else(|| String::from("no quiz yet"), |n| format!("quiz {}%", n));
+        let confidence = review
+            .confidence
+            .map(|n| format!(", confidence {} / 5", n))
+            .unwrap_or_default();
+        let studied = review
+            .studied
+            .map(|at| format!(", last studied {}", &at.to_string()[..10]))
+            .unwrap_or_default();
+        writeln!(
+            out,
+            "{} {:<22} {} {}",
+            if i == 0 { "Review:" } else { "       " },
+            review.meta.id(),
+            i18n::title(&review.meta, lang),
+            fmt::dim(&format!("({}{}{})", quiz, confidence, studied))
+        )?;
+    }
+    if let Some(first) = reviews.first() {
+        writeln!(
+            out,
+            "\n{}",
+            fmt::dim(&format!(
+                "Run `quiz {}` to check what you remember",
+                first.meta.slug
+            ))
+        )?;
+    }
+    Ok(())
+}
+
+/// 按学习顺序第一个可以开始的 lesson；已弃用的不推荐
+pub(crate) fn next(order: &[LessonMeta], progress: &Progress) -> Option<LessonMeta> {
+    order
+        .iter()
+        .filter(|m| !m.deprecated && !progress.is_complete(&m.id()))
+        .find(|m| {
+            m.prerequisites.iter().all(|req| {
+                order
+                    .iter()
+                    .any(|r| r.slug == *req && progress.is_complete(&r.id()))
+            })
+        })
+        .copied()
+}
+
+/// 已完成的 lesson，最该复习的在前
+pub(crate) fn reviews(order: &[LessonMeta], progress: &Progress) -> Vec<Review> {
+    let grades = grade::lessons(progress, order);
+    let mut reviews: Vec<Review> = order
+        .iter()
+        .zip(grades)
+        .filter(|(m, _)| progress.is_complete(&m.id()))
+        .map(|(meta, grade)| Review {
+            meta: *meta,
+            quiz: grade.quiz,
+            confidence: progress.confidence(&meta.id()).map(|c| c.level),
+            studied: studied(progress, &meta.id()),
+        })
+        .collect();
+    // `None` 排在 `Some` 之前：没有成绩与自评、没有学习记录的最先复习
+    reviews.sort_by_key(|r| (r.mastery(), r.studied));
+    reviews
+}
+
+/// 最近一次运行、完成或做测验的时间
+fn studied(progress: &Progress, id: &str) -> Option<Timestamp> {
+    let run = progress
+        .runs()
+        .iter()
+        .filter(|r| r.id == id)
+        .map(|r| r.at)
+        .max();
+    let quiz = progress.quiz_scores(id).iter().map(|q| q.at).max();
+    run.max(quiz).max(progress.completed_at(id))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lesson::{Chapter, Difficulty};
+
+    fn meta(
+        number: usize,
+        slug: &'static str,
+        prerequisites: &'static [&'static str],
+    ) -> LessonMeta {
+        LessonMeta {
+            number,
+            slug,
+            aliases: &[],
+            title: slug,
+            chapter: Chapter::Basics,
+            difficulty: Difficulty::Beginner,
+            minutes: 15,
+            tags: &[],
+            prerequisites,
+            deprecated: false,
+            replaced_by: None,
+        }
+    }
+
+    fn order() -> Vec<LessonMeta> {
+        vec![
+            meta(1, "a", &[]),
+            meta(2, "b", &["a"]),
+            meta(3, "c", &["b"]),
+            meta(4, "d", &[]),
+        ]
+    }
+
+    #[test]
+    fn test_next() {
+        let order = order();
+        let mut progress = Progress::default();
+        assert_eq!(next(&order, &progress).map(|m| m.slug), Some("a"));
+        progress.complete("02_b").unwrap();
+        // 跳过前置直接完成了 b，a 仍然是下一个
+        assert_eq!(next(&order, &progress).map(|m| m.slug), Some("a"));
+        progress.complete("01_a").unwrap();
+        assert_eq!(next(&order, &progress).map(|m| m.slug), Some("c"));
+        progress.complete("03_c").unwrap();
+        progress.complete("04_d").unwrap();
+        assert_eq!(next(&order, &progress), None);
+    }
+
+    #[test]
+    fn test_next_skips_blocked_and_deprecated() {
+        let mut order = order();
+        order[0].deprecated = true;
+        // a 已弃用又没完成：b、c 被挡住，只能先学 d
+        assert_eq!(
+            next(&order, &Progress::default()).map(|m| m.slug),
+            Some("d")
+        );
+    }
+
+    #[test]
+    fn test_reviews() {
+        let order = order();
+        let mut progress = Progress::default();
+        assert!(reviews(&order, &progress).is_empty());
+        for id in ["01_a", "02_b", "03_c", "04_d"] {
+            progress.complete(id).unwrap();
+        }
+        progress.record_quiz("01_a", 3, 4).unwrap();
+        progress.record_quiz("02_b", 1, 4).unwrap();
+        progress.record_quiz("03_c", 4, 4).unwrap();
+        let slugs: Vec<_> = reviews(&order, &progress)
+            .iter()
+            .map(|r| (r.meta.slug, r.quiz))
+            .collect();
+        assert_eq!(
+            slugs,
+            [
+                ("d", None),
+                ("b", Some(25)),
+                ("a", Some(75)),
+                ("c", Some(100))
+            ]
+        );
+
+        // 测验满分但自评没把握，排到 b 前面
+        progress.record_confidence("03_c", 1).unwrap();
+        progress.record_confidence("02_b", 5).unwrap();
+        let slugs: Vec<_> = reviews(&order, &progress)
+            .iter()
+            .map(|r| (r.meta.slug, r.mastery()))
+            .collect();
+        assert_eq!(
+            slugs,
+            [
+                ("d", None),
+                ("c", Some(0)),
+                ("b", Some(25)),
+                ("a", Some(75))
+            ]
+        );
+    }
+}
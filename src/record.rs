@@ -0,0 +1,202 @@
+//! 录制终端演示：`record <lesson>[:<section>] [file]`
+//!
+//! 把一次 lesson 运行写成 asciinema 的 `.cast` 文件（asciicast v2），默认是 `<lesson>.cast`，
+//! 可以用 `asciinema play` 回放，或者用 asciinema-player 嵌进网页作为讲解演示。
+//!
+//! lesson 在内存中运行（见 [`report`](crate::report)），运行本身几乎不花时间，
+//! 录像中的节奏是按输出排出来的：先“输入”命令，然后逐行显示输出，每个小节标题之前停顿一下，
+//! 观看时来得及读。着色与当前终端一致，`--color always` 可以强制录进颜色。
+
+use crate::cli::Options;
+use crate::error::RunnerError;
+use crate::i18n;
+use crate::lesson::Verbosity;
+use crate::lessons;
+use crate::report::{self, RunOptions};
+use crate::transcript;
+use crate::utils::time::Timestamp;
+use crate::utils::{deterministic, fmt};
+use serde::Serialize;
+use serde_json::json;
+use std::fs;
+use std::io::Write;
+
+/// 逐字输入命令时每个字的间隔（秒）
+const TYPING: f64 = 0.05;
+/// 每行输出之间的间隔
+const LINE: f64 = 0.12;
+/// 小节标题之前的停顿
+const SECTION: f64 = 1.2;
+/// 录像的最小宽度与高度（列、行）
+const MIN_WIDTH: usize = 80;
+const HEIGHT: usize = 24;
+
+pub fn run(args: &[String], opts: &Options, out: &mut dyn Write) -> Result<(), RunnerError> {
+    let (sel, file) = match args {
+        [sel] => (sel, None),
+        [sel, file] => (sel, Some(file.clone())),
+        _ => {
+            return Err(RunnerError::Usage(String::from(
+                "Usage: record <lesson>[:<section>] [file]",
+            )));
+        }
+    };
+    let selected = lessons::select(sel)?;
+    let [selected] = selected.as_slice() else {
+        return Err(RunnerError::Usage(format!(
+            "'{}' selects several lessons, record one lesson or section at a time",
+            sel
+        )));
+    };
+    let id = selected.id();
+    let report = report::run(
+        &id,
+        RunOptions {
+            verbosity: opts.verbosity,
+            language: opts.language,
+            ..RunOptions::default()
+        },
+    )?;
+    let mut output = String::new();
+    if opts.verbosity > Verbosity::Quiet {
+        output.push_str(&format!(
+            "{}\n",
+            selected.lesson.meta().header(opts.language)
+        ));
+    }
+    output.push_str(&report.output);
+    if let Some(error) = &report.error {
+        output.push_str(&format!("Error: {}\n", error));
+    }
+
+    let events = events(&format!("cargo learn {}", id), &output, &report.sections);
+    let title = format!(
+        "{} {}",
+        id,
+        i18n::title(&selected.lesson.meta(), opts.language)
+    );
+    let cast = cast(&title, &output, &events);
+    let file = file.unwrap_or_else(|| format!("{}.cast", id.replace(':', "-")));
+    fs::write(&file, cast)?;
+    let length = events.last().map_or(0.0, |(t, _)| *t);
+    writeln!(out, "Recorded {} to {} ({:.0} s)", id, file, length)?;
+    writeln!(
+        out,
+        "{}",
+        fmt::dim(&format!("Play it with `asciinema play {}`", file))
+    )?;
+    Ok(())
+}
+
+/// asciicast v2 的文件头
+#[derive(Debug, Serialize)]
+struct Header<'a> {
+    version: u8,
+    width: usize,
+    height: usize,
+    /// 录制时间；`--deterministic` 时不写
+    #[serde(skip_serializing_if = "Option::is_none")]
+    timestamp: Option<i64>,
+    title: &'a str,
+    env: Env,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "UPPERCASE")]
+struct Env {
+    term: &'static str,
+    shell: &'static str,
+}
+
+/// 排好时间的输出事件：`(秒, 文本)`；终端需要 `\r\n` 换行
+fn events(command: &str, output: &str, sections: &[String]) -> Vec<(f64, String)> {
+    let headers: Vec<String> = sections.iter().map(|t| fmt::section(t)).collect();
+    let mut events = vec![(0.0, String::from("$ "))];
+    let mut time = 0.5;
+    for c in command.chars() {
+        events.push((time, c.to_string()));
+        time += TYPING;
+    }
+    events.push((time, String::from("\r\n")));
+    time += 2.0 * LINE;
+    for line in output.lines() {
+        if headers.iter().any(|h| h == line) {
+            time += SECTION;
+        }
+        events.push((time, format!("{}\r\n", line)));
+        time += LINE;
+    }
+    events.push((time, String::from("$ ")));
+    events
+}
+
+/// asciicast v2：第一行是文件头，之后每行一个 `[时间, "o", 文本]`
+fn cast(title: &str, output: &str, events: &[(f64, String)]) -> String {
+    let width = output
+        .lines()
+        .map(|l| fmt::width(&transcript::plain(l)))
+        .max()
+        .unwrap_or(0)
+        .max(MIN_WIDTH);
+    let header = Header {
+        version: 2,
+        width,
+        height: HEIGHT,
+        timestamp: (!deterministic::is_enabled()).then(|| Timestamp::now().to_unix()),
+        title,
+        env: Env {
+            term: "xterm-256color",
+            shell: "/bin/sh",
+        },
+    };
+    let mut out = format!(
+        "{}\n",
+        serde_json::to_string(&header).expect("the header always serializes")
+    );
+    for (time, text) in events {
+        let time = (time * 1000.0).round() / 1000.0;
+        out.push_str(&format!("{}\n", json!([time, "o", text])));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_events() {
+        let sections = [String::from("Move")];
+        let output = format!("{}\nmoved\n", fmt::section("Move"));
+        let events = events("go", &output, &sections);
+        let texts: Vec<&str> = events.iter().map(|(_, t)| t.as_str()).collect();
+        assert_eq!(
+            texts[..4],
+            ["$ ", "g", "o", "\r\n"],
+            "the command is typed first"
+        );
+        assert!(texts[4].ends_with("\r\n") && texts[5] == "moved\r\n");
+        assert!(events.windows(2).all(|w| w[0].0 <= w[1].0));
+        // 小节标题之前停顿
+        assert!(events[4].0 - events[3].0 > SECTION);
+        assert!((events[5].0 - events[4].0 - LINE).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_cast() {
+        deterministic::enable();
+        let events = [
+            (0.0, String::from("$ ")),
+            (0.123_456, String::from("hi\r\n")),
+        ];
+        let cast = cast("01_a A", "\x1b[1mhi\x1b[0m\n", &events);
+        let lines: Vec<&str> = cast.lines().collect();
+        assert_eq!(
+            lines[0],
+            "{\"version\":2,\"width\":80,\"height\":24,\"title\":\"01_a A\",\
+             \"env\":{\"TERM\":\"xterm-256color\",\"SHELL\":\"/bin/sh\"}}"
+        );
+        assert_eq!(lines[1], "[0.0,\"o\",\"$ \"]");
+        assert_eq!(lines[2], "[0.123,\"o\",\"hi\\r\\n\"]");
+    }
+}
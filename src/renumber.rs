@@ -0,0 +1,298 @@
+//! 维护命令 `renumber`：调整 lesson 的编号
+//!
+//! `cargo run -- renumber <lesson> <number>` 把 lesson 移到新的编号，
+//! 两者之间的 lesson 依次顺延一位，并同步修改源码树中的：
+//!
+//! - `curriculum.toml` 的 `number`；旧 id（如 `06_ownership`）加入该 lesson 的 `aliases`，
+//!   旧的运行命令、配置中的别名和学习进度都继续有效；
+//! - 各 lesson 文件（`crates/lessons-*/src/<slug>.rs`）文档注释中的运行命令等处出现的 lesson id。
+//!
+//! README 中的 Lessons 列表用 `update-readme` 重新生成；README 其它地方与测试中的示例 id 不会自动修改，
+//! 旧 id 作为别名仍然可用，按需手动更新。
+//!
+//! 编号只写在大纲中，文件名、模块名与注册表都由 slug 决定，因此无需改名；
+//! 注册表在下次构建时由 build.rs 重新生成。插入新 lesson 时，先以最大编号把它
+//! 追加到大纲末尾，再用本命令移到想要的位置。
+
+use crate::curriculum::{self, Entry};
+use crate::error::RunnerError;
+use std::fs;
+use std::io::Write;
+
+/// 一个 lesson 的编号变化
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Move {
+    slug: String,
+    from: usize,
+    to: usize,
+}
+
+impl Move {
+    fn old_id(&self) -> String {
+        format!("{:02}_{}", self.from, self.slug)
+    }
+
+    fn new_id(&self) -> String {
+        format!("{:02}_{}", self.to, self.slug)
+    }
+}
+
+/// 修改源码树中的大纲与文档，打印每个 lesson 的编号变化
+pub fn run(sel: &str, to: &str, out: &mut dyn Write) -> Result<(), RunnerError> {
+    let to = to
+        .parse()
+        .map_err(|_| RunnerError::Usage(format!("Invalid lesson number '{}'", to)))?;
+    renumber(sel, to, None, out)
+}
+
+/// 把刚追加到大纲末尾的 lesson 移到 `to`；它还没有被使用过，不保留旧 id
+pub(crate) fn insert(slug: &str, to: usize, out: &mut dyn Write) -> Result<(), RunnerError> {
+    renumber(slug, to, Some(slug), out)
+}
+
+fn renumber(
+    sel: &str,
+    to: usize,
+    fresh: Option<&str>,
+    out: &mut dyn Write,
+) -> Result<(), RunnerError> {
+    let root = curriculum::root();
+    let (text, lessons) = curriculum::read()?;
+
+    let moves = plan(&lessons, sel, to)?;
+    if moves.is_empty() {
+        writeln!(out, "Nothing to renumber")?;
+        return Ok(());
+    }
+    fs::write(curriculum::path(), rewrite_curriculum(&text, &moves, fresh))?;
+    for path in lessons.iter().map(Entry::file) {
+        let text = fs::read_to_string(&path)?;
+        let replaced = replace_ids(&text, &moves);
+        if replaced != text {
+            fs::write(&path, replaced)?;
+            writeln!(
+                out,
+                "Updated {}",
+                path.strip_prefix(root).unwrap_or(&path).display()
+            )?;
+        }
+    }
+    for m in &moves {
+        writeln!(out, "  {} -> {}", m.old_id(), m.new_id())?;
+    }
+    Ok(())
+}
+
+/// 把选中的 lesson 移到编号 `to`，返回编号有变化的 lesson
+fn plan(lessons: &[Entry], sel: &str, to: usize) -> Result<Vec<Move>, RunnerError> {
+    let mut order: Vec<&Entry> = lessons.iter().collect();
+    order.sort_by_key(|l| l.number);
+    let from = order
+        .iter()
+        .position(|l| l.matches(sel))
+        .ok_or_else(|| RunnerError::NotFound(sel.to_string()))?;
+    if !(1..=order.len()).contains(&to) {
+        return Err(RunnerError::Usage(format!(
+            "Lesson number must be between 1 and {}",
+            order.len()
+        )));
+    }
+    let lesson = order.remove(from);
+    order.insert(to - 1, lesson);
+    Ok((1..)
+        .zip(order)
+        .filter(|(number, l)| l.number != *number)
+        .map(|(number, l)| Move {
+            slug: l.slug.clone(),
+            from: l.number,
+            to: number,
+        })
+        .collect())
+}
+
+/// 逐个 `[[lesson]]` 改写编号与别名，其余文本（注释、字段顺序）保持不变
+///
+/// `fresh` 指定的 lesson 不把旧 id 记为别名。
+fn rewrite_curriculum(text: &str, moves: &[Move], fresh: Option<&str>) -> String {
+    let mut out = Vec::new();
+    let mut block: Vec<String> = Vec::new();
+    for line in text.lines() {
+        if line.trim() == "[[lesson]]" {
+            out.append(&mut rewrite_block(std::mem::take(&mut block), moves, fresh));
+        }
+        block.push(line.to_string());
+    }
+    out.append(&mut rewrite_block(block, moves, fresh));
+    let mut text = out.join("\n");
+    text.push('\n');
+    text
+}
+
+fn rewrite_block(mut block: Vec<String>, moves: &[Move], fresh: Option<&str>) -> Vec<String> {
+    let slug = block.iter().find_map(|line| field::<String>(line, "slug"));
+    let Some(m) = moves.iter().find(|m| Some(&m.slug) == slug.as_ref()) else {
+        return block;
+    };
+    let mut aliases = Vec::new();
+    let mut aliases_at = None;
+    for (i, line) in block.iter_mut().enumerate() {
+        if field::<usize>(line, "number").is_some() {
+            *line = format!("number = {}", m.to);
+        } else if let Some(existing) = field::<Vec<String>>(line, "aliases") {
+            aliases = existing;
+            aliases_at = Some(i);
+        }
+    }
+    aliases.retain(|a| *a != m.new_id());
+    if fresh != Some(m.slug.as_str()) && !aliases.contains(&m.old_id()) {
+        aliases.push(m.old_id());
+    }
+    if aliases.is_empty() && aliases_at.is_none() {
+        return block;
+    }
+    let line = format!(
+        "aliases = [{}]",
+        aliases
+            .iter()
+            .map(|a| format!("{:?}", a))
+            .collect::<Vec<_>>()
+            .join(", ")
+    );
+    match aliases_at {
+        Some(i) => block[i] = line,
+        None => {
+            let slug_at = block
+                .iter()
+                .position(|l| field::<String>(l, "slug").is_some())
+                .expect("block has a slug");
+            block.insert(slug_at + 1, line);
+        }
+    }
+    block
+}
+
+/// 若该行是 `key = value`，解析出值
+fn field<T: serde::de::DeserializeOwned>(line: &str, key: &str) -> Option<T> {
+    let (name, _) = line.split_once('=')?;
+    if name.trim() != key {
+        return None;
+    }
+    let mut table: toml::Table = toml::from_str(line).ok()?;
+    table.remove(key)?.try_into().ok()
+}
+
+/// 把文本中完整出现的旧 id 替换为新 id，`06_ownership:closures` 这类写法也会替换
+fn replace_ids(text: &str, moves: &[Move]) -> String {
+    let is_word = |c: char| c.is_ascii_alphanumeric() || c == '_';
+    let mut text = text.to_string();
+    for m in moves {
+        let (old, new) = (m.old_id(), m.new_id());
+        let mut out = String::with_capacity(text.len());
+        let mut rest = text.as_str();
+        while let Some(at) = rest.find(&old) {
+            let (before, after) = (&rest[..at], &rest[at + old.len()..]);
+            let whole = !before
+                .chars()
+                .last()
+                .or_else(|| out.chars().last())
+                .is_some_and(is_word)
+                && !after.starts_with(is_word);
+            out.push_str(before);
+            out.push_str(if whole { &new } else { &old });
+            rest = after;
+        }
+        out.push_str(rest);
+        text = out;
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entries() -> Vec<Entry> {
+        ["hello", "vars", "types", "funcs"]
+            .iter()
+            .zip(1..)
+            .map(|(slug, number)| Entry {
+                number,
+                slug: slug.to_string(),
+                aliases: Vec::new(),
+                chapter: String::from("Basics"),
+                book: Vec::new(),
+                feature: String::from("basics"),
+            })
+            .collect()
+    }
+
+    fn moved(slug: &str, from: usize, to: usize) -> Move {
+        Move {
+            slug: slug.to_string(),
+            from,
+            to,
+        }
+    }
+
+    #[test]
+    fn test_plan() {
+        let lessons = entries();
+        assert_eq!(
+            plan(&lessons, "funcs", 2).unwrap(),
+            [
+                moved("funcs", 4, 2),
+                moved("vars", 2, 3),
+                moved("types", 3, 4)
+            ]
+        );
+        assert_eq!(
+            plan(&lessons, "1", 2).unwrap(),
+            [moved("vars", 2, 1), moved("hello", 1, 2)]
+        );
+        assert!(plan(&lessons, "03_types", 3).unwrap().is_empty());
+        assert!(matches!(
+            plan(&lessons, "nope", 1),
+            Err(RunnerError::NotFound(_))
+        ));
+        assert!(matches!(
+            plan(&lessons, "hello", 5),
+            Err(RunnerError::Usage(_))
+        ));
+    }
+
+    #[test]
+    fn test_rewrite_curriculum() {
+        let text = "# 大纲\n\n[[lesson]]\nnumber = 1\nslug = \"hello\"\naliases = [\"hi\"]\n\n\
+                    [[lesson]]\nnumber = 2\nslug = \"vars\"\ntitle = \"Vars\"\n";
+        let moves = [moved("vars", 2, 1), moved("hello", 1, 2)];
+        assert_eq!(
+            rewrite_curriculum(text, &moves, None),
+            "# 大纲\n\n[[lesson]]\nnumber = 2\nslug = \"hello\"\naliases = [\"hi\", \"01_hello\"]\n\n\
+             [[lesson]]\nnumber = 1\nslug = \"vars\"\naliases = [\"02_vars\"]\ntitle = \"Vars\"\n"
+        );
+        // 移回原来的编号时，不再把当前 id 当作别名
+        let back = "[[lesson]]\nnumber = 2\nslug = \"hello\"\naliases = [\"01_hello\"]\n";
+        assert_eq!(
+            rewrite_curriculum(back, &[moved("hello", 2, 1)], None),
+            "[[lesson]]\nnumber = 1\nslug = \"hello\"\naliases = [\"02_hello\"]\n"
+        );
+        // 新建的 lesson 没有旧 id 需要保留
+        let fresh = "[[lesson]]\nnumber = 3\nslug = \"new\"\n";
+        assert_eq!(
+            rewrite_curriculum(fresh, &[moved("new", 3, 1)], Some("new")),
+            "[[lesson]]\nnumber = 1\nslug = \"new\"\n"
+        );
+    }
+
+    #[test]
+    fn test_replace_ids() {
+        let moves = [moved("hello", 1, 2), moved("vars", 2, 1)];
+        assert_eq!(
+            replace_ids(
+                "`cargo run -- 01_hello`, 02_vars:shadowing, 101_hello, 01_hello_world",
+                &moves
+            ),
+            "`cargo run -- 02_hello`, 01_vars:shadowing, 101_hello, 01_hello_world"
+        );
+    }
+}
@@ -0,0 +1,264 @@
+//! 交互式代码片段 REPL（`cargo run -- repl`）
+//!
+//! 每输入一段代码，就把本次会话中累计的条目（`fn` / `struct` / `use` 等）
+//! 与语句拼成一个单文件 crate，在临时目录中用 `rustc` 编译运行：
+//! - 以 `;` 结尾的输入视为语句，编译成功后保留在会话中
+//! - 其余输入视为表达式，结果以 `{:?}` 打印，不会保留
+//! - 编译失败时直接显示编译器的诊断信息
+//!
+//! 适合在学完某个 lesson 后快速试验它演示过的写法的各种变体。
+
+use crate::error::RunnerError;
+use crate::utils::io::Prompter;
+use std::fs;
+use std::io::{self, BufRead, Write};
+use std::path::PathBuf;
+use std::process::Command;
+
+/// 区分历史语句输出与本次输出的标记
+const MARKER: &str = "\u{1}__rust_learn_repl__";
+
+const ITEM_PREFIXES: &[&str] = &[
+    "fn ",
+    "pub ",
+    "struct ",
+    "enum ",
+    "impl ",
+    "impl<",
+    "trait ",
+    "use ",
+    "mod ",
+    "const ",
+    "static ",
+    "type ",
+    "macro_rules!",
+    "#[",
+];
+
+#[derive(Debug, PartialEq)]
+enum Input {
+    Item,
+    Statement,
+    Expression,
+}
+
+#[derive(Default)]
+struct Session {
+    items: Vec<String>,
+    statements: Vec<String>,
+}
+
+impl Session {
+    /// 生成完整的程序源码；`snippet` 为本次输入
+    fn render(&self, snippet: &str, kind: &Input) -> String {
+        let mut items = self.items.join("\n");
+        let tail = match kind {
+            Input::Item => {
+                items.push('\n');
+                items.push_str(snippet);
+                String::new()
+            }
+            Input::Statement => snippet.to_string(),
+            Input::Expression => format!(
+                "let __value = {{ {} }};\n    \
+                 if format!(\"{{:?}}\", __value) != \"()\" {{ println!(\"{{:?}}\", __value); }}",
+                snippet
+            ),
+        };
+        // 会话中已有语句的输出位于标记之前，显示时会被跳过
+        format!(
+            "#![allow(unused)]\n{}\n\nfn main() {{\n    {}\n    println!(\"{}\");\n    {}\n}}\n",
+            items,
+            self.statements.join("\n    "),
+            MARKER.escape_default(),
+            tail
+        )
+    }
+}
+
+pub fn run(out: &mut dyn Write) -> Result<(), RunnerError> {
+    let version = Command::new("rustc")
+        .arg("--version")
+        .output()
+        .map_err(|e| io::Error::new(e.kind(), format!("rustc not available: {}", e)))?;
+    writeln!(
+        out,
+        "Rust snippet REPL ({}) — :help 查看命令，:quit 退出",
+        String::from_utf8_lossy(&version.stdout).trim()
+    )?;
+
+    let dir = std::env::temp_dir().join(format!("rust-learn-repl-{}", std::process::id()));
+    fs::create_dir_all(&dir)?;
+    let mut session = Session::default();
+    let mut input = Prompter::stdin();
+
+    while let Some(snippet) = read_snippet(&mut input) {
+        match snippet.as_str() {
+            "" => continue,
+            ":quit" | ":q" => break,
+            ":help" => print_help(out)?,
+            ":reset" => {
+                let kept = session.items.len() + session.statements.len();
+                if kept == 0 || input.confirm(&format!("Discard {} saved snippet(s)?", kept))? {
+                    session = Session::default();
+                    writeln!(out, "Session cleared.")?;
+                }
+            }
+            ":show" => writeln!(out, "{}", session.render("()", &Input::Expression))?,
+            _ => {
+                let kind = classify(&snippet);
+                match compile_and_run(&dir, &session.render(&snippet, &kind)) {
+                    Ok(output) => {
+                        write!(out, "{}", output)?;
+                        match kind {
+                            Input::Item => session.items.push(snippet),
+                            Input::Statement => session.statements.push(snippet),
+                            Input::Expression => {}
+                        }
+                    }
+                    Err(diagnostics) => writeln!(out, "{}", diagnostics.trim_end())?,
+                }
+            }
+        }
+    }
+    let _ = fs::remove_dir_all(&dir);
+    Ok(())
+}
+
+fn print_help(out: &mut dyn Write) -> Result<(), RunnerError> {
+    writeln!(
+        out,
+        "  <表达式>     编译运行并打印结果，如 `vec![1, 2, 3].len()`"
+    )?;
+    writeln!(
+        out,
+        "  <语句>;      以分号结尾，成功后保留在会话中，如 `let s = String::from(\"hi\");`"
+    )?;
+    writeln!(out, "  fn/struct/…  定义条目，保留在会话中")?;
+    writeln!(out, "  :show        查看当前会话生成的完整程序")?;
+    writeln!(out, "  :reset       清空会话")?;
+    writeln!(out, "  :quit        退出")?;
+    Ok(())
+}
+
+/// 读取一段输入；括号未闭合时继续读取下一行
+fn read_snippet<R: BufRead, W: Write>(input: &mut Prompter<R, W>) -> Option<String> {
+    let mut snippet = String::new();
+    loop {
+        let prompt = if snippet.is_empty() {
+            "rust> "
+        } else {
+            "...   "
+        };
+        let line = input.prompt(prompt).ok()?;
+        if !snippet.is_empty() {
+            snippet.push('\n');
+        }
+        snippet.push_str(&line);
+        if is_balanced(&snippet) {
+            return Some(snippet.trim().to_string());
+        }
+    }
+}
+
+fn classify(snippet: &str) -> Input {
+    if ITEM_PREFIXES.iter().any(|p| snippet.starts_with(p)) {
+        Input::Item
+    } else if snippet.ends_with(';') {
+        Input::Statement
+    } else {
+        Input::Expression
+    }
+}
+
+/// 粗略判断括号是否闭合（忽略字符串字面量中的括号）
+fn is_balanced(snippet: &str) -> bool {
+    let mut depth = 0i32;
+    let mut in_string = false;
+    let mut escaped = false;
+    for c in snippet.chars() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' if in_string => escaped = true,
+            '"' => in_string = !in_string,
+            '(' | '[' | '{' if !in_string => depth += 1,
+            ')' | ']' | '}' if !in_string => depth -= 1,
+            _ => {}
+        }
+    }
+    depth <= 0 && !in_string
+}
+
+/// 编译并运行程序；成功时返回标记之后的输出，失败时返回诊断信息
+fn compile_and_run(dir: &std::path::Path, source: &str) -> Result<String, String> {
+    let src = dir.join("main.rs");
+    let bin: PathBuf = dir.join(format!("snippet{}", std::env::consts::EXE_SUFFIX));
+    fs::write(&src, source).map_err(|e| e.to_string())?;
+
+    let compiled = Command::new("rustc")
+        .args(["--edition", "2024", "--color", "never", "-o"])
+        .arg(&bin)
+        .arg(&src)
+        .output()
+        .map_err(|e| e.to_string())?;
+    if !compiled.status.success() {
+        return Err(String::from_utf8_lossy(&compiled.stderr).into_owned());
+    }
+
+    let ran = Command::new(&bin).output().map_err(|e| e.to_string())?;
+    let stdout = String::from_utf8_lossy(&ran.stdout);
+    let fresh = stdout
+        .split_once(&format!("{}\n", MARKER))
+        .map(|(_, after)| after)
+        .unwrap_or(&stdout);
+    if ran.status.success() {
+        Ok(fresh.to_string())
+    } else {
+        Err(format!("{}{}", fresh, String::from_utf8_lossy(&ran.stderr)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_classify() {
+        assert_eq!(classify("fn sq(x: i32) -> i32 { x * x }"), Input::Item);
+        assert_eq!(classify("let v = vec![1, 2];"), Input::Statement);
+        assert_eq!(classify("v.len()"), Input::Expression);
+    }
+
+    #[test]
+    fn test_is_balanced() {
+        assert!(is_balanced("fn f() { 1 }"));
+        assert!(!is_balanced("fn f() {"));
+        assert!(is_balanced(r#"println!("{")"#));
+    }
+
+    #[test]
+    fn test_compile_and_run_snippets() {
+        let dir = std::env::temp_dir().join(format!("rust-learn-repl-test-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let mut session = Session::default();
+        session
+            .statements
+            .push(String::from("let v = vec![1, 2, 3]; println!(\"old\");"));
+
+        let output = compile_and_run(&dir, &session.render("v.len() * 2", &Input::Expression));
+        assert_eq!(output.unwrap(), "6\n");
+
+        let output = compile_and_run(
+            &dir,
+            &session.render("println!(\"new\");", &Input::Statement),
+        );
+        assert_eq!(output.unwrap(), "new\n");
+
+        let error = compile_and_run(
+            &dir,
+            &session.render("let n: i32 = \"x\";", &Input::Statement),
+        );
+        assert!(error.unwrap_err().contains("E0308"));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,383 @@
+//! 供其他程序调用的运行接口
+//!
+//! [`run`] 在内存中运行选中的 lesson，不读写学习进度、不访问终端，
+//! 把输出、耗时、运行过的小节与结果汇总为 [`LessonReport`]。除了完整的输出，
+//! 每一节的输出也分开保存在 [`LessonReport::captured`] 中，导出、比较输出或快照测试时
+//! 直接取用，不用为了某一节再运行一遍：
+//!
+//! ```no_run
+//! use rust_learn::report::RunOptions;
+//!
+//! let report = rust_learn::run_lesson("13_traits", RunOptions::default()).unwrap();
+//! assert!(report.is_ok());
+//! println!("{}", report.output);
+//! println!("{:?}", report.section_output("默认实现"));
+//! ```
+//!
+//! 网页等前端要在运行过程中显示进度时用 [`stream`]：每写完一行（以及每个小节开始时）就通过 channel 送出，
+//! 不必等整个 lesson 结束；返回的报告与 [`run`] 相同。
+
+use crate::error::RunnerError;
+use crate::lesson::{EventSink, Language, LessonContext, Verbosity};
+use crate::lessons;
+use crate::schema::Status;
+use crate::utils::fmt;
+use crate::utils::io::Scripted;
+use std::io::{self, Write};
+use std::sync::mpsc::Sender;
+use std::time::{Duration, Instant};
+
+/// 运行选项
+#[derive(Debug, Clone, Default)]
+pub struct RunOptions {
+    pub verbosity: Verbosity,
+    pub language: Language,
+    /// 交互演示读到的输入，每项一行；用完后视为输入结束
+    pub input: Vec<String>,
+    /// 随机种子：设定后 lesson 中的随机数可复现，HashMap 按键输出，见 [`LessonContext::with_seed`]
+    pub seed: Option<u64>,
+}
+
+/// 一次运行的结果
+#[derive(Debug, Clone)]
+pub struct LessonReport {
+    /// 运行过的 lesson（或小节）标识，按运行顺序
+    pub lessons: Vec<String>,
+    /// 全部输出
+    pub output: String,
+    /// 输出过的小节标题
+    pub sections: Vec<String>,
+    /// 按小节分开的输出，按运行顺序
+    pub captured: Vec<SectionOutput>,
+    /// 总耗时；在 wasm32-unknown-unknown 上总是零
+    pub duration: Duration,
+    pub status: Status,
+    /// 失败或 panic 时的错误消息
+    pub error: Option<String>,
+}
+
+/// 一段输出：一个小节的，或者 lesson 中第一个小节之前的
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SectionOutput {
+    /// 所属的 lesson（或小节）标识
+    pub lesson: String,
+    /// 小节标题；第一个小节之前的输出为 `None`
+    pub title: Option<String>,
+    /// 这一段的输出，不含小节标题行与小节之间的空行
+    pub output: String,
+}
+
+impl LessonReport {
+    pub fn is_ok(&self) -> bool {
+        self.status == Status::Ok
+    }
+
+    /// 标题为 `title` 的第一个小节的输出
+    pub fn section_output(&self, title: &str) -> Option<&str> {
+        self.captured
+            .iter()
+            .find(|c| c.title.as_deref() == Some(title))
+            .map(|c| c.output.as_str())
+    }
+}
+
+/// [`stream`] 在运行过程中送出的输出
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Streamed {
+    /// 开始一个小节，附标题
+    Section(String),
+    /// 一行输出，不含换行符
+    Line(String),
+}
+
+/// 记录输出，同时按小节切分；小节标题按文本输出的格式写进完整的输出中
+#[derive(Default)]
+struct Capture {
+    output: Vec<u8>,
+    captured: Vec<SectionOutput>,
+    /// 当前运行的 lesson 标识
+    lesson: String,
+    /// 流式运行时逐行送出输出；还没写完的一行留在 `pending` 中
+    tx: Option<Sender<Streamed>>,
+    pending: Vec<u8>,
+}
+
+impl Capture {
+    fn start(&mut self, lesson: String) {
+        self.lesson = lesson;
+        self.push(None);
+    }
+
+    fn push(&mut self, title: Option<String>) {
+        self.captured.push(SectionOutput {
+            lesson: self.lesson.clone(),
+            title,
+            output: String::new(),
+        });
+    }
+
+    /// 接收方已经不在时不再送出，运行照常进行
+    fn send(&mut self, streamed: Streamed) {
+        if let Some(tx) = &self.tx
+            && tx.send(streamed).is_err()
+        {
+            self.tx = None;
+        }
+    }
+
+    /// 送出 `pending` 中完整的行；`all` 时连同最后没有换行的部分
+    fn send_lines(&mut self, all: bool) {
+        while let Some(end) = self.pending.iter().position(|&b| b == b'\n') {
+            let line: Vec<u8> = self.pending.drain(..=end).collect();
+            let line = String::from_utf8_lossy(&line[..end]).into_owned();
+            self.send(Streamed::Line(line));
+        }
+        if all && !self.pending.is_empty() {
+            let line = String::from_utf8_lossy(&self.pending).into_owned();
+            self.pending.clear();
+            self.send(Streamed::Line(line));
+        }
+    }
+
+    /// 去掉没有输出的开头部分，每段末尾的空行留给下一个小节标题之前
+    fn finish(mut self) -> (String, Vec<SectionOutput>) {
+        self.send_lines(true);
+        self.captured
+            .retain(|c| c.title.is_some() || !c.output.is_empty());
+        for c in &mut self.captured {
+            c.output = c.output.trim_end_matches('\n').to_string();
+            if !c.output.is_empty() {
+                c.output.push('\n');
+            }
+        }
+        (
+            String::from_utf8_lossy(&self.output).into_owned(),
+            self.captured,
+        )
+    }
+}
+
+impl Write for Capture {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.output.extend_from_slice(buf);
+        if let Some(current) = self.captured.last_mut() {
+            current.output.push_str(&String::from_utf8_lossy(buf));
+        }
+        if self.tx.is_some() {
+            self.pending.extend_from_slice(buf);
+            self.send_lines(false);
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl EventSink for Capture {
+    fn section(&mut self, title: &str) -> io::Result<()> {
+        if !self.output.is_empty() {
+            self.output.push(b'\n');
+        }
+        writeln!(self.output, "{}", fmt::section(title))?;
+        self.push(Some(title.to_string()));
+        self.send_lines(true);
+        self.send(Streamed::Section(title.to_string()));
+        Ok(())
+    }
+}
+
+/// 按顺序运行选中的 lesson，遇到第一个失败即停止
+///
+/// 选择器无效时返回错误；lesson 本身失败或 panic 记录在报告的 `status` 中。
+pub fn run(sel: &str, opts: RunOptions) -> Result<LessonReport, RunnerError> {
+    run_with(sel, opts, Capture::default())
+}
+
+/// 同 [`run`]，运行过程中把输出逐行送到 `tx`
+///
+/// 通常在另一个线程中调用，当前线程从 channel 接收；运行结束时 `tx` 被丢弃，接收方随之结束。
+/// 接收方提前关闭不影响运行。
+pub fn stream(
+    sel: &str,
+    opts: RunOptions,
+    tx: Sender<Streamed>,
+) -> Result<LessonReport, RunnerError> {
+    run_with(
+        sel,
+        opts,
+        Capture {
+            tx: Some(tx),
+            ..Capture::default()
+        },
+    )
+}
+
+fn run_with(
+    sel: &str,
+    opts: RunOptions,
+    mut capture: Capture,
+) -> Result<LessonReport, RunnerError> {
+    let picked = lessons::select(sel)?;
+    let mut input = Scripted::new(opts.input);
+
+    let start = clock();
+    let mut ran = Vec::new();
+    let mut sections = Vec::new();
+    let mut failure = None;
+    for l in &picked {
+        ran.push(l.id());
+        capture.start(l.id());
+        let mut ctx = LessonContext::with_events(&mut capture)
+            .with_verbosity(opts.verbosity)
+            .with_language(opts.language)
+            .with_input(&mut input);
+        if let Some(seed) = opts.seed {
+            ctx = ctx.with_seed(seed);
+        }
+        let result = lessons::run_guarded(l, &mut ctx);
+        sections.extend_from_slice(ctx.sections());
+        if let Err(e) = result {
+            failure = Some(e);
+            break;
+        }
+    }
+    let duration = start.map_or(Duration::ZERO, |s| s.elapsed());
+    let (output, captured) = capture.finish();
+
+    let status = match &failure {
+        None => Status::Ok,
+        Some(RunnerError::LessonPanicked { .. }) => Status::Panicked,
+        Some(_) => Status::Failed,
+    };
+    Ok(LessonReport {
+        lessons: ran,
+        output,
+        sections,
+        captured,
+        duration,
+        status,
+        error: failure.map(|e| e.to_string()),
+    })
+}
+
+/// 开始计时；wasm32-unknown-unknown 上没有时钟（`Instant::now` 会 panic），在浏览器中运行时耗时记为零
+fn clock() -> Option<Instant> {
+    (!cfg!(all(target_arch = "wasm32", target_os = "unknown"))).then(Instant::now)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing;
+
+    #[test]
+    #[cfg_attr(not(feature = "basics"), ignore = "needs the basics lessons")]
+    fn test_report() {
+        let report = testing::run("6", RunOptions::default()).unwrap();
+        assert!(report.is_ok());
+        assert_eq!(report.lessons, ["06_ownership"]);
+        assert_eq!(report.sections, ["所有权基础", "作用域与丢弃", "栈 vs 堆"]);
+        assert!(report.output.starts_with("=== 所有权基础 ===\n"));
+        assert_eq!(report.error, None);
+
+        let report = testing::run("16:closure_capture,1", RunOptions::default()).unwrap();
+        assert_eq!(
+            report.lessons,
+            ["16_iterators_closures:closure_capture", "01_hello_world"]
+        );
+        assert_eq!(report.sections, ["闭包捕获"]);
+        assert_eq!(report.captured[0].lesson, report.lessons[0]);
+        // hello_world 没有小节，整个输出是一段
+        let last = report.captured.last().unwrap();
+        assert_eq!(
+            (last.lesson.as_str(), last.title.as_deref()),
+            ("01_hello_world", None)
+        );
+    }
+
+    #[test]
+    #[cfg_attr(not(feature = "basics"), ignore = "needs the basics lessons")]
+    fn test_captured_sections() {
+        let report = testing::run("6", RunOptions::default()).unwrap();
+        let titles: Vec<_> = report
+            .captured
+            .iter()
+            .map(|c| c.title.as_deref().unwrap())
+            .collect();
+        assert_eq!(titles, report.sections);
+        // 各节的输出与小节标题拼起来就是完整的输出
+        let joined: Vec<String> = report
+            .captured
+            .iter()
+            .map(|c| {
+                format!(
+                    "{}\n{}",
+                    fmt::section(c.title.as_deref().unwrap()),
+                    c.output
+                )
+            })
+            .collect();
+        assert_eq!(joined.join("\n"), report.output);
+        let single = testing::run("6:scope_drop", RunOptions::default()).unwrap();
+        assert_eq!(
+            report.section_output(&single.sections[0]),
+            Some(single.captured[0].output.as_str())
+        );
+    }
+
+    #[test]
+    #[cfg_attr(not(feature = "basics"), ignore = "needs the basics lessons")]
+    fn test_report_options() {
+        let opts = RunOptions {
+            language: Language::En,
+            verbosity: Verbosity::Verbose,
+            ..RunOptions::default()
+        };
+        let report = testing::run("hello", opts).unwrap();
+        assert!(report.output.contains("Project layout:"));
+        let opts = RunOptions {
+            seed: Some(7),
+            ..RunOptions::default()
+        };
+        let report = testing::run("collections", opts).unwrap();
+        assert!(
+            report
+                .output
+                .contains(r#"Word count: {"hello": 1, "wonderful": 1, "world": 2}"#)
+        );
+    }
+
+    #[test]
+    #[cfg_attr(not(feature = "basics"), ignore = "needs the basics lessons")]
+    fn test_stream() {
+        let (tx, rx) = std::sync::mpsc::channel();
+        let report = testing::stream("6", RunOptions::default(), tx).unwrap();
+        let streamed: Vec<Streamed> = rx.iter().collect();
+        assert_eq!(streamed[0], Streamed::Section(String::from("所有权基础")));
+        // 按文本输出的格式拼回去，与完整的输出一致
+        let mut text = String::new();
+        for s in &streamed {
+            match s {
+                Streamed::Section(title) => {
+                    if !text.is_empty() {
+                        text.push('\n');
+                    }
+                    text.push_str(&format!("{}\n", fmt::section(title)));
+                }
+                Streamed::Line(line) => text.push_str(&format!("{}\n", line)),
+            }
+        }
+        assert_eq!(text, report.output);
+
+        // 接收方提前关闭也能运行完
+        let (tx, rx) = std::sync::mpsc::channel();
+        drop(rx);
+        assert!(
+            testing::stream("6", RunOptions::default(), tx)
+                .unwrap()
+                .is_ok()
+        );
+    }
+}
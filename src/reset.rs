@@ -0,0 +1,135 @@
+//! 重置学习进度：`reset --lesson <lesson> [--quizzes] | --quizzes | --all`
+//!
+//! 不用手动编辑或删除进度文件，就能有选择地清除当前学习档案的记录：
+//! - `--lesson <lesson>`：选中 lesson（支持编号、区间等选择器）的完成、小节、测验成绩、练习、笔记、书签等记录；
+//! - `--quizzes`：全部测验成绩，与 `--lesson` 一起用时只清除选中 lesson 的；
+//! - `--all`：整个档案的全部记录，包括运行记录。
+//!
+//! 清除前要求确认，输入结束（例如管道）视为取消。清除只针对当前档案（`--profile`）。
+
+use crate::cli::ResetScope;
+use crate::error::RunnerError;
+use crate::lessons;
+use crate::progress::Progress;
+use crate::utils::io;
+use std::io::Write;
+
+/// `reset`，`args` 不含 `reset` 本身；清除范围由选项 `scope` 给出
+pub fn run(
+    args: &[String],
+    scope: &ResetScope,
+    progress: &mut Progress,
+    profile: &str,
+    out: &mut dyn Write,
+) -> Result<(), RunnerError> {
+    let usage = || {
+        RunnerError::Usage(String::from(
+            "Usage: reset --lesson <lesson> [--quizzes] | reset --quizzes | reset --all",
+        ))
+    };
+    if !args.is_empty() {
+        return Err(usage());
+    }
+    let ids = match &scope.lesson {
+        Some(sel) => Some(
+            lessons::resolve(sel)?
+                .iter()
+                .map(|l| l.meta().id())
+                .collect::<Vec<_>>(),
+        ),
+        None => None,
+    };
+    let action = Action::from_scope(scope, ids).ok_or_else(usage)?;
+    if !io::confirm(&format!(
+        "Clear {} in profile '{}'? This cannot be undone.",
+        action.describe(),
+        profile
+    ))? {
+        writeln!(out, "Nothing changed")?;
+        return Ok(());
+    }
+    match &action {
+        Action::All => progress.reset_all()?,
+        Action::Quizzes(ids) => progress.reset_quizzes(ids.as_deref())?,
+        Action::Lessons(ids) => progress.reset_lessons(ids)?,
+    }
+    writeln!(out, "Cleared {}", action.describe())?;
+    Ok(())
+}
+
+/// 要清除的记录
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Action {
+    All,
+    /// 测验成绩；`None` 表示全部 lesson 的
+    Quizzes(Option<Vec<String>>),
+    /// 这些 lesson 的全部记录
+    Lessons(Vec<String>),
+}
+
+impl Action {
+    /// 由选项与 `--lesson` 选中的 lesson id 决定；组合不合法时为 `None`
+    fn from_scope(scope: &ResetScope, ids: Option<Vec<String>>) -> Option<Action> {
+        match (scope.all, scope.quizzes, ids) {
+            (true, false, None) => Some(Action::All),
+            (false, true, ids) => Some(Action::Quizzes(ids)),
+            (false, false, Some(ids)) => Some(Action::Lessons(ids)),
+            _ => None,
+        }
+    }
+
+    fn describe(&self) -> String {
+        match self {
+            Action::All => String::from("all progress"),
+            Action::Quizzes(None) => String::from("all quiz scores"),
+            Action::Quizzes(Some(ids)) => format!("the quiz scores of {}", ids.join(", ")),
+            Action::Lessons(ids) => format!(
+                "completion, scores, notes and bookmarks of {}",
+                ids.join(", ")
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_scope() {
+        let scope = |lesson: bool, quizzes, all| ResetScope {
+            lesson: lesson.then(|| String::from("6")),
+            quizzes,
+            all,
+        };
+        let ids = || Some(vec![String::from("06_ownership")]);
+        assert_eq!(
+            Action::from_scope(&scope(false, false, true), None),
+            Some(Action::All)
+        );
+        assert_eq!(
+            Action::from_scope(&scope(false, true, false), None),
+            Some(Action::Quizzes(None))
+        );
+        assert_eq!(
+            Action::from_scope(&scope(true, true, false), ids()),
+            Some(Action::Quizzes(ids()))
+        );
+        assert_eq!(
+            Action::from_scope(&scope(true, false, false), ids()),
+            Some(Action::Lessons(ids().unwrap()))
+        );
+        // 什么都没选，或者 `--all` 与其它选项混用
+        assert_eq!(Action::from_scope(&scope(false, false, false), None), None);
+        assert_eq!(Action::from_scope(&scope(true, false, true), ids()), None);
+        assert_eq!(Action::from_scope(&scope(false, true, true), None), None);
+    }
+
+    #[test]
+    fn test_describe() {
+        assert_eq!(
+            Action::Quizzes(Some(vec![String::from("06_ownership")])).describe(),
+            "the quiz scores of 06_ownership"
+        );
+    }
+}
@@ -0,0 +1,166 @@
+//! Lesson 批量运行器
+//!
+//! `all` 命令按编号顺序运行全部 lesson。`--jobs N` 大于 1 时，每个 lesson
+//! 在独立子进程中执行（复用当前可执行文件），输出先缓冲，再按编号顺序打印，
+//! 因此并行运行的结果与串行运行完全一致。
+//!
+//! `--junit PATH` 时把每个 lesson 记为一个测试用例，写出 JUnit XML 报告，见 [`crate::junit`]。
+
+use crate::cli::{Format, Options};
+use crate::error::RunnerError;
+use crate::junit::{self, Case, Outcome, Suite};
+use crate::lesson::{LessonMeta, Verbosity};
+use crate::lessons;
+use crate::progress::Progress;
+use std::collections::BTreeMap;
+use std::io::{self, Write};
+use std::process::{Command, Output};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::time::{Duration, Instant};
+use std::{env, thread};
+
+/// 默认并行度：可用的 CPU 核数
+pub fn default_jobs() -> usize {
+    thread::available_parallelism()
+        .map(|n| n.get())
+        .unwrap_or(1)
+}
+
+/// 运行全部 lesson；串行时在本进程内记录进度，并行时由各子进程自行记录
+pub fn run_all(
+    jobs: usize,
+    opts: &Options,
+    progress: &mut Progress,
+    out: &mut dyn Write,
+) -> Result<(), RunnerError> {
+    let lessons = lessons::all();
+    let mut cases = Vec::new();
+    log::debug!("running {} lessons with {} job(s)", lessons.len(), jobs);
+    if jobs <= 1 {
+        for l in lessons {
+            let meta = l.meta();
+            if opts.format == Format::Text && opts.verbosity > Verbosity::Quiet {
+                writeln!(out, "{}", meta.header(opts.language))?;
+            }
+            let start = Instant::now();
+            let result = lessons::run_one(l.as_ref(), opts, out);
+            log::debug!("lesson {} finished in {:?}", meta.id(), start.elapsed());
+            lessons::record_run(progress, &meta.id(), start.elapsed());
+            let outcome = match result {
+                Ok(()) => {
+                    if let Err(e) = progress.complete(&meta.id()) {
+                        eprintln!("Warning: cannot save progress: {}", e);
+                    }
+                    Outcome::Passed
+                }
+                Err(e) => {
+                    eprintln!("Error: {}", e);
+                    Outcome::Failed(e.to_string())
+                }
+            };
+            cases.push(case(&meta, start.elapsed(), outcome));
+        }
+        return summarize(cases, opts);
+    }
+
+    let exe = env::current_exe()?;
+    let child_args = opts.child_args();
+    let next = AtomicUsize::new(0);
+    let (tx, rx) = mpsc::channel();
+
+    thread::scope(|s| {
+        for _ in 0..jobs.min(lessons.len()) {
+            let tx = tx.clone();
+            let (next, lessons, exe, child_args) = (&next, &lessons, &exe, &child_args);
+            s.spawn(move || {
+                // 工作线程不断领取下一个 lesson，直到全部领完
+                while let Some(l) = lessons.get(next.fetch_add(1, Ordering::Relaxed)) {
+                    let meta = l.meta();
+                    let start = Instant::now();
+                    let output = Command::new(exe).args(child_args).arg(meta.id()).output();
+                    log::debug!("lesson {} finished in {:?}", meta.id(), start.elapsed());
+                    if tx.send((meta.number, (output, start.elapsed()))).is_err() {
+                        break;
+                    }
+                }
+            });
+        }
+        drop(tx);
+
+        // 先完成的结果暂存，等前面的 lesson 都打印后再按顺序输出
+        let mut pending = BTreeMap::new();
+        let mut order = lessons.iter().peekable();
+        for (number, output) in rx {
+            pending.insert(number, output);
+            while let Some((output, elapsed)) =
+                order.peek().and_then(|l| pending.remove(&l.meta().number))
+            {
+                let meta = order.next().expect("peeked lesson").meta();
+                let outcome = print_output(&meta, output, opts, out);
+                cases.push(case(&meta, elapsed, outcome));
+            }
+        }
+    });
+    summarize(cases, opts)
+}
+
+fn case(meta: &LessonMeta, elapsed: Duration, outcome: Outcome) -> Case {
+    Case {
+        name: meta.id(),
+        time: Some(elapsed),
+        outcome,
+    }
+}
+
+/// 按需写出 JUnit 报告；有 lesson 失败时返回错误
+fn summarize(cases: Vec<Case>, opts: &Options) -> Result<(), RunnerError> {
+    let failed: Vec<String> = cases
+        .iter()
+        .filter(|c| matches!(c.outcome, Outcome::Failed(_)))
+        .map(|c| c.name.clone())
+        .collect();
+    if let Some(path) = &opts.junit {
+        let suite = Suite {
+            name: String::from("lessons"),
+            cases,
+        };
+        junit::write(path, &[suite])?;
+        eprintln!("JUnit report written to {}", path.display());
+    }
+    if failed.is_empty() {
+        Ok(())
+    } else {
+        Err(RunnerError::Failed(failed))
+    }
+}
+
+/// 打印单个 lesson 的缓冲输出；失败时结果中带上子进程的错误输出
+fn print_output(
+    meta: &LessonMeta,
+    output: io::Result<Output>,
+    opts: &Options,
+    out: &mut dyn Write,
+) -> Outcome {
+    if opts.format == Format::Text && opts.verbosity > Verbosity::Quiet {
+        let _ = writeln!(out, "{}", meta.header(opts.language));
+    }
+    match output {
+        Ok(output) => {
+            // 管道被关闭（例如 `| head`）时忽略写入错误
+            let _ = out.write_all(&output.stdout);
+            let _ = io::stderr().write_all(&output.stderr);
+            if output.status.success() {
+                Outcome::Passed
+            } else {
+                let stderr = String::from_utf8_lossy(&output.stderr);
+                Outcome::Failed(format!("{} ({})", stderr.trim(), output.status))
+            }
+        }
+        Err(e) => {
+            let message = format!("Failed to start lesson {}: {}", meta.id(), e);
+            eprintln!("{}", message);
+            Outcome::Failed(message)
+        }
+    }
+}
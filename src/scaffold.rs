@@ -0,0 +1,374 @@
+//! 维护命令 `new`：生成新 lesson 的骨架
+//!
+//! `cargo run -- new <slug> [number]` 按模板在 lesson crate 中创建 `src/<slug>.rs`
+//! （带 要点 / 常见坑 / 运行 的文档注释、`SECTIONS`、`QUIZ`、`EXERCISE`、`HINTS`、`KOANS` 与 `PITFALLS`、`run()` 与测试模块）并在其 `lib.rs` 中声明，
+//! 同时创建练习文件 `src/exercises/<slug>.rs` 与参考答案 `solutions/<crate>/<slug>.rs` 并分别在 `exercises.rs`、
+//! `mod.rs` 中声明，再在 `curriculum.toml` 末尾追加对应的 `[[lesson]]`，再用 [`renumber`](crate::renumber)
+//! 移到 `number`（默认排在最后）。章节与 feature 沿用排在它前面的 lesson，前置 lesson 也设为它。
+//!
+//! 生成后即可编译运行，标题、要点与示例中的 `TODO` 由作者补全，补全前 `lint-lessons` 会报告它们。
+
+use crate::curriculum::{self, Entry};
+use crate::error::RunnerError;
+use crate::renumber;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+
+const TEMPLATE: &str = r##"//! # {title}
+//!
+//! 目标：TODO 用一句话说明本课要掌握什么
+//!
+//! ## 要点
+//! - TODO
+//!
+//! ## 常见坑
+//! - TODO
+//!
+//! ## 运行
+//! `cargo run -- {id}`
+
+use crate::exercises::{slug} as exercise;
+use crate::lesson::{self, Check, Koan, LessonContext, Pitfall, Question, Section};
+use std::io::Write;
+
+pub const SECTIONS: &[Section] = &[Section::new("basics", "基本用法", demo_basics)];
+
+// TODO 写 4–8 道题，每次测验随机抽出其中 3 道
+pub const QUIZ: &[Question] = &[
+    Question::new("TODO 题目", &["TODO 正确选项", "TODO 干扰项"], 0, "TODO 解释"),
+    Question::new("TODO 题目", &["TODO 正确选项", "TODO 干扰项"], 0, "TODO 解释"),
+    Question::new("TODO 题目", &["TODO 正确选项", "TODO 干扰项"], 0, "TODO 解释"),
+    Question::new("TODO 题目", &["TODO 正确选项", "TODO 干扰项"], 0, "TODO 解释"),
+];
+
+pub const EXERCISE: &[Check] = &[
+    Check::new("first", check_first),
+    Check::new("second", check_second),
+];
+
+pub const HINTS: &[&str] = &["TODO 方向性的提示", "TODO 更具体的提示", "TODO 接近答案的提示"];
+
+// TODO 从示例改写 2 段 koan，空白写成 `__`，答案依次列出
+pub const KOANS: &[Koan] = &[Koan::new(
+    "demo",
+    r#"#[test]
+fn demo() {
+    assert_eq!(2 + 3, __);
+}
+"#,
+    &["5"],
+)];
+
+// 编译不过的常见坑放在 lesson crate 的 `pitfalls/{slug}/<name>.rs` 中，用 `include_str!` 引入，
+// 再运行 `TRYBUILD=overwrite cargo test --test pitfalls` 记下编译器的报错；报错有错误码时用 `.error("E0308")` 标明
+pub const PITFALLS: &[Pitfall] = &[];
+
+pub fn run(ctx: &mut LessonContext) -> lesson::Result {
+    lesson::run_sections(ctx, SECTIONS)
+}
+
+fn demo_basics(ctx: &mut LessonContext) -> lesson::Result {
+    writeln!(ctx, "[{title}] demo output: {}", demo(2, 3))?;
+    Ok(())
+}
+
+fn demo(a: i32, b: i32) -> i32 {
+    a + b
+}
+
+// TODO 按练习文件中的函数写检查
+fn check_first() {
+    assert_eq!(exercise::first(), 1);
+}
+
+fn check_second() {
+    assert_eq!(exercise::second(), 2);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_demo() {
+        assert_eq!(demo(2, 3), 5);
+    }
+
+    #[test]
+    fn test_output() {
+        let mut out = Vec::new();
+        run(&mut LessonContext::new(&mut out)).unwrap();
+        assert!(String::from_utf8(out).unwrap().contains("demo output: 5"));
+    }
+}
+"##;
+
+/// 练习文件模板，与 lesson 文件一同生成
+const EXERCISE_TEMPLATE: &str = r#"//! # 练习：{title}
+//!
+//! 把每个 `todo!()` 换成实现，然后运行 `cargo run -- exercise {slug}` 检查。
+
+/// TODO 说明要实现什么
+pub fn first() -> i32 {
+    todo!()
+}
+
+/// TODO 说明要实现什么
+pub fn second() -> i32 {
+    todo!()
+}
+"#;
+
+/// 参考答案模板，通过模板 lesson 中的两项检查
+const SOLUTION_TEMPLATE: &str = r#"//! # 参考答案：{title}
+
+pub fn first() -> i32 {
+    1
+}
+
+pub fn second() -> i32 {
+    2
+}
+"#;
+
+/// 创建 lesson 文件、练习文件与参考答案并登记到大纲
+pub fn run(slug: &str, number: Option<&str>, out: &mut dyn Write) -> Result<(), RunnerError> {
+    let (_, lessons) = curriculum::read()?;
+    check_slug(slug, &lessons)?;
+    if let Some(file) = curriculum::LESSON_CRATES
+        .iter()
+        .flat_map(|name| {
+            let src = curriculum::lesson_src(name);
+            let solutions = curriculum::root().join("solutions").join(name);
+            [src.clone(), src.join("exercises"), solutions]
+                .map(|dir| dir.join(format!("{}.rs", slug)))
+        })
+        .find(|file| file.exists())
+    {
+        return Err(RunnerError::Usage(format!(
+            "{} already exists",
+            file.display()
+        )));
+    }
+    let end = lessons.len() + 1;
+    let to = match number {
+        Some(n) => n
+            .parse()
+            .ok()
+            .filter(|n| (1..=end).contains(n))
+            .ok_or_else(|| {
+                RunnerError::Usage(format!("Lesson number must be between 1 and {}", end))
+            })?,
+        None => end,
+    };
+    // 插到最前面时没有前一个 lesson，章节与 feature 取原来的第一个
+    let previous = to.checked_sub(2).and_then(|i| lessons.get(i));
+    let neighbour = previous
+        .or(lessons.first())
+        .ok_or_else(|| RunnerError::Config(String::from("curriculum.toml has no lessons")))?;
+    let entry = Entry {
+        number: end,
+        slug: slug.to_string(),
+        aliases: Vec::new(),
+        chapter: neighbour.chapter.clone(),
+        book: Vec::new(),
+        feature: neighbour.feature.clone(),
+    };
+
+    fs::write(
+        entry.file(),
+        render(&Entry {
+            number: to,
+            ..entry.clone()
+        }),
+    )?;
+    fs::write(
+        entry.exercise_file(),
+        render_with(EXERCISE_TEMPLATE, &entry),
+    )?;
+    fs::write(
+        entry.solution_file(),
+        render_with(SOLUTION_TEMPLATE, &entry),
+    )?;
+    let src = curriculum::lesson_src(entry.lesson_crate());
+    let solutions = entry.solution_file().with_file_name("mod.rs");
+    for module in [src.join("lib.rs"), src.join("exercises.rs"), solutions] {
+        fs::write(&module, declare_module(&fs::read_to_string(&module)?, slug))?;
+    }
+    let mut toml = OpenOptions::new().append(true).open(curriculum::path())?;
+    write!(
+        toml,
+        "{}",
+        curriculum_entry(&entry, previous.map(|p| p.slug.as_str()))
+    )?;
+    writeln!(
+        out,
+        "Created crates/{0}/src/{1}.rs, crates/{0}/src/exercises/{1}.rs and solutions/{0}/{1}.rs",
+        entry.lesson_crate(),
+        slug
+    )?;
+    if to != end {
+        renumber::insert(slug, to, out)?;
+    }
+    writeln!(out, "Added {:02}_{} to curriculum.toml", to, slug)?;
+    Ok(())
+}
+
+/// slug 同时是文件名与模块名：小写字母开头，只含小写字母、数字和下划线，且未被占用
+fn check_slug(slug: &str, lessons: &[Entry]) -> Result<(), RunnerError> {
+    let valid = slug.starts_with(|c: char| c.is_ascii_lowercase())
+        && slug
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_');
+    if !valid || slug == "mod" {
+        return Err(RunnerError::Usage(format!(
+            "Invalid slug '{}': use lowercase letters, digits and '_', e.g. smart_pointers",
+            slug
+        )));
+    }
+    if let Some(taken) = lessons.iter().find(|l| l.matches(slug)) {
+        return Err(RunnerError::Usage(format!(
+            "'{}' is already used by lesson {}",
+            slug,
+            taken.id()
+        )));
+    }
+    Ok(())
+}
+
+/// 在 `lib.rs` 等模块列表中按字母顺序插入 `pub mod <slug>;`
+fn declare_module(lib: &str, slug: &str) -> String {
+    let mut lines: Vec<&str> = lib.lines().collect();
+    let declaration = format!("pub mod {};", slug);
+    let modules = || {
+        lines
+            .iter()
+            .enumerate()
+            .filter(|(_, l)| l.starts_with("pub mod "))
+    };
+    let at = modules()
+        .find(|(_, l)| **l > declaration.as_str())
+        .or_else(|| modules().next_back())
+        .map(|(i, l)| if *l > declaration.as_str() { i } else { i + 1 })
+        .unwrap_or(lines.len());
+    lines.insert(at, &declaration);
+    let mut text = lines.join("\n");
+    text.push('\n');
+    text
+}
+
+/// `smart_pointers` → `Smart Pointers`
+fn title(slug: &str) -> String {
+    slug.split('_')
+        .filter(|w| !w.is_empty())
+        .map(|w| {
+            let mut chars = w.chars();
+            chars
+                .next()
+                .map(|c| c.to_ascii_uppercase().to_string() + chars.as_str())
+                .unwrap_or_default()
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn render(entry: &Entry) -> String {
+    render_with(TEMPLATE, entry)
+}
+
+fn render_with(template: &str, entry: &Entry) -> String {
+    template
+        .replace("{title}", &title(&entry.slug))
+        .replace("{id}", &entry.id())
+        .replace("{slug}", &entry.slug)
+}
+
+fn curriculum_entry(entry: &Entry, previous: Option<&str>) -> String {
+    format!(
+        "\n[[lesson]]\nnumber = {}\nslug = {:?}\ntitle = {:?}\nchapter = {:?}\n\
+         difficulty = \"Beginner\"\nminutes = 15\ntags = [{:?}]\nrequires = [{}]\nbook = []\nfeature = {:?}\n",
+        entry.number,
+        entry.slug,
+        title(&entry.slug),
+        entry.chapter,
+        entry.slug,
+        previous.map(|p| format!("{:?}", p)).unwrap_or_default(),
+        entry.feature
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(number: usize, slug: &str) -> Entry {
+        Entry {
+            number,
+            slug: slug.to_string(),
+            aliases: vec![String::from("rc")],
+            chapter: String::from("Advanced"),
+            book: Vec::new(),
+            feature: String::from("advanced"),
+        }
+    }
+
+    #[test]
+    fn test_check_slug() {
+        let lessons = [entry(1, "smart_pointers")];
+        assert!(check_slug("async_basics", &lessons).is_ok());
+        for bad in [
+            "Smart",
+            "1st",
+            "",
+            "a-b",
+            "mod",
+            "smart_pointers",
+            "rc",
+            "1",
+        ] {
+            assert!(check_slug(bad, &lessons).is_err(), "{}", bad);
+        }
+    }
+
+    #[test]
+    fn test_declare_module() {
+        let lib = "//! docs\n\nuse x;\n\npub mod alpha;\npub mod gamma;\n";
+        assert_eq!(
+            declare_module(lib, "beta"),
+            "//! docs\n\nuse x;\n\npub mod alpha;\npub mod beta;\npub mod gamma;\n"
+        );
+        assert!(declare_module(lib, "zeta").ends_with("pub mod gamma;\npub mod zeta;\n"));
+        assert!(declare_module("//! empty\n", "a").ends_with("//! empty\npub mod a;\n"));
+    }
+
+    #[test]
+    fn test_title() {
+        assert_eq!(title("smart_pointers"), "Smart Pointers");
+        assert_eq!(title("async2__io"), "Async2 Io");
+    }
+
+    #[test]
+    fn test_render() {
+        let text = render(&entry(20, "smart_pointers"));
+        assert!(text.starts_with("//! # Smart Pointers\n"));
+        assert!(text.contains("//! `cargo run -- 20_smart_pointers`"));
+        assert!(text.contains("\"[Smart Pointers] demo output: {}\""));
+        assert!(!text.contains("{id}"));
+        assert!(text.contains("use crate::exercises::smart_pointers as exercise;"));
+        let exercise = render_with(EXERCISE_TEMPLATE, &entry(20, "smart_pointers"));
+        assert!(exercise.contains("`cargo run -- exercise smart_pointers`"));
+        let solution = render_with(SOLUTION_TEMPLATE, &entry(20, "smart_pointers"));
+        assert!(solution.starts_with("//! # 参考答案：Smart Pointers\n"));
+    }
+
+    #[test]
+    fn test_curriculum_entry() {
+        let text = curriculum_entry(&entry(20, "smart_pointers"), Some("macros_basics"));
+        let lessons = curriculum::parse(&text).unwrap();
+        assert_eq!(lessons[0].id(), "20_smart_pointers");
+        assert_eq!(lessons[0].chapter, "Advanced");
+        assert!(text.contains("requires = [\"macros_basics\"]"));
+        let first = curriculum_entry(&entry(1, "intro"), None);
+        assert!(first.contains("requires = []"));
+    }
+}
@@ -0,0 +1,140 @@
+//! 机器可读输出的数据模型与版本
+//!
+//! `--format jsonl` 的事件流（见 [`events`](crate::events)）与 `export --format json` 的课程目录
+//! （网页版的 `list_lessons()` 也返回它）都按这里的类型序列化，并带有 `schema_version`：
+//! 目录在顶层，事件流在每个 lesson 的 `lesson_started` 事件中。外部程序可以用这些类型直接反序列化。
+//!
+//! 兼容性约定：
+//!
+//! - 新增字段、新增事件类型、可选字段开始出现，都不改变版本号。使用方应当忽略不认识的字段与事件
+//!   （serde 默认如此），不要依赖字段的顺序。
+//! - 删除或改名字段、改变字段的类型或含义、改变枚举值的拼写，版本号加一，并在 README 中说明变化。
+//! - 同一个版本号下的输出可以放心地被同一个解析器读取。
+
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+
+/// 当前的输出格式版本
+pub const SCHEMA_VERSION: u32 = 1;
+
+/// 事件流中的一条事件
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum Event<'a> {
+    LessonStarted {
+        schema_version: u32,
+        #[serde(borrow)]
+        lesson: Cow<'a, str>,
+        number: usize,
+        #[serde(borrow)]
+        title: Cow<'a, str>,
+    },
+    Section {
+        #[serde(borrow)]
+        lesson: Cow<'a, str>,
+        #[serde(borrow)]
+        title: Cow<'a, str>,
+    },
+    Print {
+        #[serde(borrow)]
+        lesson: Cow<'a, str>,
+        #[serde(borrow)]
+        text: Cow<'a, str>,
+    },
+    LessonFinished {
+        #[serde(borrow)]
+        lesson: Cow<'a, str>,
+        status: Status,
+        duration_ms: u64,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        error: Option<String>,
+    },
+}
+
+impl Event<'_> {
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("events always serialize")
+    }
+}
+
+/// lesson 的运行结果
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Status {
+    Ok,
+    Failed,
+    Panicked,
+}
+
+/// `export --format json` 输出的课程目录
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Catalog {
+    pub schema_version: u32,
+    pub lessons: Vec<CatalogLesson>,
+}
+
+/// 目录中的一个 lesson
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CatalogLesson {
+    pub id: String,
+    pub number: usize,
+    pub slug: String,
+    /// 按 `--lang` 翻译的标题
+    pub title: String,
+    pub chapter: String,
+    /// `beginner`、`intermediate` 或 `advanced`
+    pub difficulty: String,
+    pub minutes: u32,
+    pub tags: Vec<String>,
+    pub aliases: Vec<String>,
+    /// 前置 lesson 的 id
+    pub prerequisites: Vec<String>,
+    pub sections: Vec<CatalogSection>,
+    /// 测验题数量
+    pub quiz: usize,
+    /// 练习检查的数量
+    pub exercises: usize,
+    pub deprecated: bool,
+    /// 取代它的 lesson 的 slug，只在已弃用时出现
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub replaced_by: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CatalogSection {
+    pub name: String,
+    pub title: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_event_round_trip() {
+        let event = Event::LessonStarted {
+            schema_version: SCHEMA_VERSION,
+            lesson: Cow::Borrowed("06_ownership"),
+            number: 6,
+            title: Cow::Borrowed("所有权 \"基础\""),
+        };
+        let json = serde_json::to_string(&event).unwrap();
+        assert!(json.starts_with(r#"{"event":"lesson_started","schema_version":1,"#));
+        assert_eq!(serde_json::from_str::<Event>(&json).unwrap(), event);
+    }
+
+    #[test]
+    fn test_unknown_fields_are_ignored() {
+        let json = r#"{"event":"print","lesson":"01_a","text":"hi","color":"red"}"#;
+        assert_eq!(
+            serde_json::from_str::<Event>(json).unwrap(),
+            Event::Print {
+                lesson: Cow::Borrowed("01_a"),
+                text: Cow::Borrowed("hi"),
+            }
+        );
+        let json = r#"{"schema_version":1,"lessons":[],"generated_by":"rust-learn"}"#;
+        let catalog: Catalog = serde_json::from_str(json).unwrap();
+        assert_eq!(catalog.schema_version, SCHEMA_VERSION);
+    }
+}
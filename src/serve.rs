@@ -0,0 +1,279 @@
+//! 本地网页服务：`serve [addr]`
+//!
+//! 在本机启动一个小型 HTTP 服务（默认 `127.0.0.1:8000`），页面与 `export --format html` 生成的静态网站相同：
+//! 目录页按章节列出 lesson，每个 lesson 一页，包括讲解与高亮的代码。不同的是输出一节是一个“运行”按钮，
+//! 点击后服务端在内存中运行 lesson（见 [`report::stream`]），输出每产生一行就传回页面，
+//! 运行时间长的 lesson 也能看到进度，适合课堂上投影演示。
+//!
+//! 路径：`/` 目录页、`/<id>.html` lesson 页面、`/run/<lesson>[:<section>]` 运行并以纯文本返回输出。
+//! 每个连接一个线程，只处理 `GET`。需要在源码树中运行；默认只监听本机，不要暴露到公网。
+
+use crate::curriculum::{self, Entry};
+use crate::error::RunnerError;
+use crate::export::Material;
+use crate::lesson::{Language, LessonMeta};
+use crate::lessons::{self, Selected};
+use crate::report::{self, RunOptions, Streamed};
+use crate::site;
+use crate::transcript;
+use crate::utils::fmt;
+use std::io::{self, BufRead, BufReader, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::{Arc, mpsc};
+use std::thread;
+
+/// 不给地址时监听这里
+const ADDR: &str = "127.0.0.1:8000";
+
+/// 运行按钮：取回 `/run/<id>` 的输出，边收边显示
+const RUN_BUTTON: &str = "<p><button id=\"run\">▶ Run</button></p>\n\
+<pre class=\"output\"><code id=\"output\"></code></pre>\n\
+<script>\n\
+document.getElementById(\"run\").onclick = async (e) => {\n\
+  const out = document.getElementById(\"output\");\n\
+  e.target.disabled = true;\n\
+  out.textContent = \"\";\n\
+  const res = await fetch(\"/run/{id}\");\n\
+  const reader = res.body.getReader();\n\
+  const decoder = new TextDecoder();\n\
+  for (;;) {\n\
+    const { done, value } = await reader.read();\n\
+    if (done) break;\n\
+    out.textContent += decoder.decode(value, { stream: true });\n\
+  }\n\
+  e.target.disabled = false;\n\
+};\n\
+</script>\n";
+
+/// 服务期间不变的内容
+struct Site {
+    metas: Vec<LessonMeta>,
+    entries: Vec<Entry>,
+    lang: Language,
+}
+
+/// 一个响应
+#[derive(Debug, PartialEq, Eq)]
+enum Response {
+    Html(String),
+    /// 运行 lesson，输出以纯文本分块传回
+    Run(String),
+    NotFound,
+    MethodNotAllowed,
+}
+
+pub fn run(addr: Option<&str>, lang: Language, out: &mut dyn Write) -> Result<(), RunnerError> {
+    let (_, entries) = curriculum::read()?;
+    let metas: Vec<LessonMeta> = lessons::all()
+        .iter()
+        .map(|l| l.meta())
+        .filter(|m| !m.deprecated)
+        .collect();
+    let addr = addr.unwrap_or(ADDR);
+    let listener = TcpListener::bind(addr)?;
+    writeln!(
+        out,
+        "Serving {} lessons on http://{} (Ctrl-C to stop)",
+        metas.len(),
+        listener.local_addr()?
+    )?;
+    let site = Arc::new(Site {
+        metas,
+        entries,
+        lang,
+    });
+    for stream in listener.incoming() {
+        let stream = match stream {
+            Ok(stream) => stream,
+            Err(e) => {
+                log::warn!("cannot accept a connection: {}", e);
+                continue;
+            }
+        };
+        let site = Arc::clone(&site);
+        thread::spawn(move || {
+            if let Err(e) = handle(stream, &site) {
+                log::warn!("request failed: {}", e);
+            }
+        });
+    }
+    Ok(())
+}
+
+fn handle(mut stream: TcpStream, site: &Site) -> io::Result<()> {
+    let mut request = String::new();
+    let mut reader = BufReader::new(&stream);
+    reader.read_line(&mut request)?;
+    // 请求头用不到，读完即可
+    let mut line = String::new();
+    while reader.read_line(&mut line)? > 2 {
+        line.clear();
+    }
+    let mut parts = request.split_whitespace();
+    let (method, path) = (
+        parts.next().unwrap_or_default(),
+        parts.next().unwrap_or("/"),
+    );
+    log::info!("{} {}", method, path);
+    match route(site, method, path) {
+        Response::Html(body) => respond(&mut stream, "200 OK", "text/html", &body),
+        Response::Run(sel) => run_lesson(&mut stream, &sel, site.lang),
+        Response::NotFound => respond(&mut stream, "404 Not Found", "text/plain", "Not found\n"),
+        Response::MethodNotAllowed => respond(
+            &mut stream,
+            "405 Method Not Allowed",
+            "text/plain",
+            "Only GET is supported\n",
+        ),
+    }
+}
+
+fn route(site: &Site, method: &str, path: &str) -> Response {
+    if method != "GET" {
+        return Response::MethodNotAllowed;
+    }
+    let path = path.split('?').next().unwrap_or_default();
+    if path == "/" || path == "/index.html" {
+        return Response::Html(site::index(&site.metas, site.lang));
+    }
+    if let Some(sel) = path.strip_prefix("/run/") {
+        return Response::Run(sel.replace("%3A", ":").replace("%3a", ":"));
+    }
+    let name = path.trim_start_matches('/');
+    let Some(i) = site.metas.iter().position(|m| site::page_name(m) == name) else {
+        return Response::NotFound;
+    };
+    let meta = &site.metas[i];
+    let selected = Selected {
+        lesson: lessons::all()
+            .iter()
+            .find(|l| l.meta().id() == meta.id())
+            .expect("served lessons come from the registry")
+            .as_ref(),
+        section: None,
+    };
+    match Material::source(&selected, &site.entries) {
+        Ok(material) => {
+            let neighbours = (
+                i.checked_sub(1).map(|i| &site.metas[i]),
+                site.metas.get(i + 1),
+            );
+            Response::Html(site::lesson_page(
+                meta,
+                &material,
+                neighbours,
+                &RUN_BUTTON.replace("{id}", &meta.id()),
+                site.lang,
+            ))
+        }
+        Err(e) => {
+            log::warn!("cannot read {}: {}", meta.id(), e);
+            Response::NotFound
+        }
+    }
+}
+
+fn respond(stream: &mut TcpStream, status: &str, kind: &str, body: &str) -> io::Result<()> {
+    write!(
+        stream,
+        "HTTP/1.1 {}\r\nContent-Type: {}; charset=utf-8\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        kind,
+        body.len(),
+        body
+    )
+}
+
+/// 在另一个线程中运行 lesson，输出每产生一行就作为一块传回
+fn run_lesson(stream: &mut TcpStream, sel: &str, lang: Language) -> io::Result<()> {
+    if let Err(e) = lessons::select(sel) {
+        return respond(stream, "404 Not Found", "text/plain", &format!("{}\n", e));
+    }
+    let (tx, rx) = mpsc::channel();
+    let sel = sel.to_string();
+    let runner = thread::spawn(move || {
+        report::stream(
+            &sel,
+            RunOptions {
+                language: lang,
+                ..RunOptions::default()
+            },
+            tx,
+        )
+    });
+    write!(
+        stream,
+        "HTTP/1.1 200 OK\r\nContent-Type: text/plain; charset=utf-8\r\n\
+         Transfer-Encoding: chunked\r\nConnection: close\r\n\r\n"
+    )?;
+    let mut first = true;
+    for streamed in rx {
+        let text = match streamed {
+            Streamed::Section(title) if first => format!("{}\n", fmt::section(&title)),
+            Streamed::Section(title) => format!("\n{}\n", fmt::section(&title)),
+            Streamed::Line(line) => format!("{}\n", line),
+        };
+        first = false;
+        // 页面关掉时写入失败，lesson 在后台运行完
+        chunk(stream, &transcript::plain(&text))?;
+    }
+    match runner.join() {
+        Ok(Ok(report)) => {
+            if let Some(error) = &report.error {
+                chunk(stream, &format!("\nError: {}\n", error))?;
+            }
+        }
+        Ok(Err(e)) => chunk(stream, &format!("\nError: {}\n", e))?,
+        Err(_) => chunk(stream, "\nError: the lesson panicked\n")?,
+    }
+    stream.write_all(b"0\r\n\r\n")
+}
+
+/// 分块传输编码中的一块；空的块会被当成结束，跳过
+fn chunk(stream: &mut impl Write, text: &str) -> io::Result<()> {
+    if text.is_empty() {
+        return Ok(());
+    }
+    write!(stream, "{:x}\r\n{}\r\n", text.len(), text)?;
+    stream.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn site() -> Site {
+        Site {
+            metas: lessons::all().iter().map(|l| l.meta()).collect(),
+            entries: curriculum::read().unwrap().1,
+            lang: Language::Zh,
+        }
+    }
+
+    #[test]
+    fn test_route() {
+        let site = site();
+        let Response::Html(index) = route(&site, "GET", "/") else {
+            panic!("the index is a page");
+        };
+        assert!(index.contains(&format!("href=\"{}\"", site::page_name(&site.metas[0]))));
+        let Response::Html(page) = route(&site, "GET", "/06_ownership.html?x=1") else {
+            panic!("lessons have pages");
+        };
+        assert!(page.contains("fetch(\"/run/06_ownership\")"));
+        assert_eq!(
+            route(&site, "GET", "/run/16%3Aclosure_capture"),
+            Response::Run(String::from("16:closure_capture"))
+        );
+        assert_eq!(route(&site, "GET", "/nope.html"), Response::NotFound);
+        assert_eq!(route(&site, "POST", "/"), Response::MethodNotAllowed);
+    }
+
+    #[test]
+    fn test_chunk() {
+        let mut out = Vec::new();
+        chunk(&mut out, "所有权\n").unwrap();
+        chunk(&mut out, "").unwrap();
+        assert_eq!(String::from_utf8(out).unwrap(), "a\r\n所有权\n\r\n");
+    }
+}
@@ -0,0 +1,589 @@
+//! 分享到 Rust Playground：`share <lesson>[:<section>]`
+//!
+//! 把 lesson 文件整理成一个独立的程序：去掉测验、练习、提示、填空练习与单元测试，
+//! 末尾附上运行器的最小替身（`LessonContext`、`Section` 等），示例代码不用修改就能编译；
+//! 讲解文本按 `--lang` 取出译文一并写入。整理好的代码编码进 play.rust-lang.org 的链接，
+//! 在浏览器中打开即可运行与修改。指定小节时只运行这一节。
+
+use crate::curriculum;
+use crate::error::RunnerError;
+use crate::i18n;
+use crate::lesson::Language;
+use crate::lessons::{self, Selected};
+use std::fs;
+use std::io::Write;
+
+const PLAYGROUND: &str = "https://play.rust-lang.org/?version=stable&mode=debug&edition=2024";
+
+/// lesson 代码用到的运行器接口的替身；`{only}` 与 `{texts}` 在生成时替换
+const SHIM: &str = r#"
+// ---- 以下是 rust-learn 运行器的最小替身，让上面的 lesson 代码不经修改即可运行 ----
+
+fn main() {
+    if let Err(e) = run(&mut lesson::LessonContext::stdout()) {
+        eprintln!("{}", e.report());
+    }
+}
+
+mod lesson {
+    use std::collections::{BTreeMap, HashMap};
+    use std::error::Error;
+    use std::fmt;
+    use std::io::{self, Write};
+
+    /// 只运行这一节；`None` 时运行全部小节
+    const ONLY: Option<&str> = {only};
+
+    /// 直接运行时的讲解语言
+    const LANGUAGE: Language = Language::{lang};
+
+    /// 讲解文本：键、中文、英文
+    const TEXTS: &[(&str, &str, &str)] = &[{texts}];
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+    pub enum Verbosity {
+        Quiet,
+        #[default]
+        Normal,
+        Verbose,
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+    pub enum Language {
+        #[default]
+        Zh,
+        En,
+    }
+
+    pub type Result<T = ()> = std::result::Result<T, LessonError>;
+
+    #[derive(Debug)]
+    pub enum LessonError {
+        Io(io::Error),
+        Failed(String),
+        Other(Box<dyn Error + Send + Sync>),
+    }
+
+    impl LessonError {
+        pub fn other(err: impl Error + Send + Sync + 'static) -> Self {
+            LessonError::Other(Box::new(err))
+        }
+
+        pub fn kind(&self) -> &'static str {
+            match self {
+                LessonError::Io(_) => "io",
+                LessonError::Failed(_) => "failed",
+                LessonError::Other(_) => "error",
+            }
+        }
+
+        pub fn report(&self) -> String {
+            let mut report = format!("[{}] {}", self.kind(), self);
+            let mut source = self.source();
+            while let Some(cause) = source {
+                report.push_str(&format!("\n  caused by: {}", cause));
+                source = cause.source();
+            }
+            report
+        }
+    }
+
+    impl fmt::Display for LessonError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self {
+                LessonError::Io(e) => write!(f, "I/O error: {}", e),
+                LessonError::Failed(reason) => f.write_str(reason),
+                LessonError::Other(e) => write!(f, "{}", e),
+            }
+        }
+    }
+
+    impl Error for LessonError {
+        fn source(&self) -> Option<&(dyn Error + 'static)> {
+            match self {
+                LessonError::Io(e) => e.source(),
+                LessonError::Failed(_) => None,
+                LessonError::Other(e) => e.source(),
+            }
+        }
+    }
+
+    impl From<io::Error> for LessonError {
+        fn from(err: io::Error) -> Self {
+            LessonError::Io(err)
+        }
+    }
+
+    impl From<String> for LessonError {
+        fn from(reason: String) -> Self {
+            LessonError::Failed(reason)
+        }
+    }
+
+    impl From<&str> for LessonError {
+        fn from(reason: &str) -> Self {
+            LessonError::Failed(reason.to_string())
+        }
+    }
+
+    /// 输出写到标准输出，单元测试中写到 `Vec<u8>` 等
+    pub struct LessonContext<'a> {
+        out: Option<&'a mut dyn Write>,
+        verbosity: Verbosity,
+        language: Language,
+    }
+
+    impl Write for LessonContext<'_> {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            match &mut self.out {
+                Some(out) => out.write(buf),
+                None => io::stdout().write(buf),
+            }
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            match &mut self.out {
+                Some(out) => out.flush(),
+                None => io::stdout().flush(),
+            }
+        }
+    }
+
+    impl<'a> LessonContext<'a> {
+        pub fn new(out: &'a mut dyn Write) -> Self {
+            LessonContext {
+                out: Some(out),
+                verbosity: Verbosity::default(),
+                language: Language::default(),
+            }
+        }
+
+        /// 直接运行时的上下文：写到标准输出，显示全部讲解
+        pub fn stdout() -> LessonContext<'static> {
+            LessonContext {
+                out: None,
+                verbosity: Verbosity::Verbose,
+                language: LANGUAGE,
+            }
+        }
+
+        pub fn with_verbosity(mut self, verbosity: Verbosity) -> Self {
+            self.verbosity = verbosity;
+            self
+        }
+
+        pub fn with_language(mut self, language: Language) -> Self {
+            self.language = language;
+            self
+        }
+
+        pub fn section(&mut self, title: &str) -> io::Result<()> {
+            writeln!(self, "\n--- {} ---", title)
+        }
+
+        pub fn is_verbose(&self) -> bool {
+            self.verbosity >= Verbosity::Verbose
+        }
+
+        pub fn is_quiet(&self) -> bool {
+            self.verbosity <= Verbosity::Quiet
+        }
+
+        /// HashMap 按键排序后输出，结果可复现
+        pub fn map<'m, K: Ord, V>(&self, map: &'m HashMap<K, V>) -> BTreeMap<&'m K, &'m V> {
+            map.iter().collect()
+        }
+
+        pub fn entries<'m, K: Ord, V>(&self, map: &'m HashMap<K, V>) -> Vec<(&'m K, &'m V)> {
+            map.iter().collect::<BTreeMap<_, _>>().into_iter().collect()
+        }
+
+        pub fn kv_table(&mut self, rows: &[(&str, &str)]) -> io::Result<()> {
+            for (key, value) in rows {
+                writeln!(self, "  {:<14} {}", key, value)?;
+            }
+            Ok(())
+        }
+
+        pub fn tr(&self, key: &str) -> String {
+            TEXTS
+                .iter()
+                .find(|(k, _, _)| *k == key)
+                .map_or(key, |(_, zh, en)| match self.language {
+                    Language::Zh => zh,
+                    Language::En => en,
+                })
+                .to_string()
+        }
+
+        pub fn tr_with(&self, key: &str, args: &[(&str, &str)]) -> String {
+            let mut text = self.tr(key);
+            for (name, value) in args {
+                text = text.replace(&format!("{{{}}}", name), value);
+            }
+            text
+        }
+    }
+
+    pub struct Section {
+        name: &'static str,
+        title: &'static str,
+        run: fn(&mut LessonContext) -> Result,
+    }
+
+    impl Section {
+        pub const fn new(
+            name: &'static str,
+            title: &'static str,
+            run: fn(&mut LessonContext) -> Result,
+        ) -> Self {
+            Section { name, title, run }
+        }
+    }
+
+    pub fn run_sections(ctx: &mut LessonContext, sections: &[Section]) -> Result {
+        for section in sections {
+            if ONLY.is_none_or(|only| only == section.name) {
+                ctx.section(section.title)?;
+                (section.run)(ctx)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+mod utils {
+    pub mod fmt {
+        pub fn text(s: &str) -> String {
+            s.to_string()
+        }
+    }
+}
+"#;
+
+/// 只属于运行器的声明，分享时去掉；性质测试依赖 proptest，独立的程序中没有，也一并去掉
+const DROPPED: &[&str] = &[
+    "pub const QUIZ",
+    "pub const EXERCISE",
+    "pub const HINTS",
+    "pub const KOANS",
+    "pub const PITFALLS",
+    "fn check_",
+    "use crate::exercises",
+    "mod properties",
+];
+
+/// 替身提供的 `crate::lesson` 中的名字；`lesson` 模块本身就在 crate 根，不用再导入 `self`
+const PROVIDED: &[&str] = &[
+    "Language",
+    "LessonContext",
+    "LessonError",
+    "Section",
+    "Verbosity",
+];
+
+pub fn run(sel: &str, lang: Language, out: &mut dyn Write) -> Result<(), RunnerError> {
+    let selected = lessons::select(sel)?;
+    let [selected] = selected.as_slice() else {
+        return Err(RunnerError::Usage(format!(
+            "'{}' selects several lessons, share one lesson or section at a time",
+            sel
+        )));
+    };
+    let code = program(selected, lang, false)?;
+    writeln!(out, "{}&code={}", PLAYGROUND, encode(&code))?;
+    Ok(())
+}
+
+/// 选中的 lesson（或小节）整理成的独立程序，见 [`snippet`]；需要在源码树中运行
+pub(crate) fn program(
+    selected: &Selected,
+    lang: Language,
+    tests: bool,
+) -> Result<String, RunnerError> {
+    let id = selected.lesson.meta().id();
+    let (_, entries) = curriculum::read()?;
+    let entry = entries
+        .iter()
+        .find(|e| e.id() == id)
+        .ok_or_else(|| RunnerError::Config(format!("{} is missing from curriculum.toml", id)))?;
+    let source = fs::read_to_string(entry.file())?;
+    Ok(snippet(
+        &source,
+        selected.section.map(|s| s.name),
+        lang,
+        tests,
+    ))
+}
+
+/// 把 lesson 源码整理成可以独立运行的程序；`tests` 为真时保留单元测试
+fn snippet(source: &str, only: Option<&str>, lang: Language, tests: bool) -> String {
+    let (docs, _) = split(source);
+    // `#![allow]` 要在模块注释之后、所有条目之前
+    format!(
+        "{}#![allow(unused_imports, unused_macros, dead_code, non_local_definitions)]\n\n{}",
+        docs,
+        code(source, only, lang, tests)
+    )
+}
+
+/// 去掉模块注释的代码，末尾接上运行器的替身；`only` 指定只运行的小节
+pub(crate) fn code(source: &str, only: Option<&str>, lang: Language, tests: bool) -> String {
+    let (_, body) = split_with(source, tests);
+    let mut texts: String = text_keys(source)
+        .iter()
+        .map(|key| {
+            format!(
+                "\n    ({:?}, {:?}, {:?}),",
+                key,
+                i18n::tr(Language::Zh, key),
+                i18n::tr(Language::En, key)
+            )
+        })
+        .collect();
+    if !texts.is_empty() {
+        texts.push('\n');
+    }
+    let only = only.map_or_else(|| String::from("None"), |name| format!("Some({:?})", name));
+    format!(
+        "{}\n{}",
+        body.trim_end(),
+        SHIM.replace("{only}", &only)
+            .replace("{lang}", variant(lang))
+            .replace("{texts}", &texts)
+    )
+}
+
+/// 替身中 `Language` 的成员名
+fn variant(lang: Language) -> &'static str {
+    match lang {
+        Language::Zh => "Zh",
+        Language::En => "En",
+    }
+}
+
+/// 拆成模块注释与代码；代码中去掉只属于运行器的声明，`crate::lesson` 的导入只保留替身提供的名字
+pub(crate) fn split(source: &str) -> (String, String) {
+    split_with(source, false)
+}
+
+/// 同 [`split`]；`tests` 为真时保留单元测试
+fn split_with(source: &str, tests: bool) -> (String, String) {
+    let (mut docs, mut body) = (String::new(), String::new());
+    for item in items(source) {
+        if item.starts_with("//!") {
+            docs.push_str(&item);
+            continue;
+        }
+        let head = item
+            .lines()
+            .find(|l| !l.starts_with("#[") && !l.starts_with("//") && !l.trim().is_empty())
+            .unwrap_or_default();
+        if DROPPED.iter().any(|d| head.starts_with(d)) || (!tests && head.starts_with("mod tests"))
+        {
+            continue;
+        }
+        if head.starts_with("use crate::lesson::") {
+            body.push_str(&lesson_imports(head));
+            continue;
+        }
+        // 去掉被删除的声明之间多出来的空行
+        if item.trim().is_empty() && (body.is_empty() || body.ends_with("\n\n")) {
+            continue;
+        }
+        body.push_str(&item);
+    }
+    (docs, body)
+}
+
+/// 按顶层条目切分源码，条目前的属性与注释算在条目里；模块注释与条目之间的空行各自成段。
+/// 依赖 rustfmt 的格式：条目从第 0 列开始，以第 0 列的 `}`、`];` 或分号结束；
+/// 原始字符串（填空练习的代码）中的行不参与判断。
+fn items(source: &str) -> Vec<String> {
+    let mut items = Vec::new();
+    let mut current = String::new();
+    let mut raw = false;
+    for line in source.lines() {
+        if !raw && (line.starts_with("//!") || (line.trim().is_empty() && current.is_empty())) {
+            items.push(format!("{}\n", line));
+            continue;
+        }
+        current.push_str(line);
+        current.push('\n');
+        raw = in_raw_string(line, raw);
+        let top = !line.is_empty() && !line.starts_with([' ', '\t', '#', '/']);
+        let closes = line.ends_with(';')
+            || (line.ends_with('}') && line.matches('{').count() <= line.matches('}').count());
+        if !raw && top && closes {
+            items.push(std::mem::take(&mut current));
+        }
+    }
+    if !current.is_empty() {
+        items.push(current);
+    }
+    items
+}
+
+/// 这一行结束时是否在 `r#"…"#` 之中；`inside` 是行首的状态
+fn in_raw_string(line: &str, mut inside: bool) -> bool {
+    let mut rest = line;
+    loop {
+        let (pattern, next) = if inside {
+            ("\"#", false)
+        } else {
+            ("r#\"", true)
+        };
+        match rest.find(pattern) {
+            Some(i) => {
+                rest = &rest[i + pattern.len()..];
+                inside = next;
+            }
+            None => return inside,
+        }
+    }
+}
+
+/// 只保留替身提供的名字，例如 `use crate::lesson::{self, Check, LessonContext, Section};` →
+/// `use crate::lesson::{LessonContext, Section};`
+fn lesson_imports(line: &str) -> String {
+    let list = line
+        .trim_start_matches("use crate::lesson::")
+        .trim_end_matches(';')
+        .trim_matches(['{', '}']);
+    let kept: Vec<&str> = list
+        .split(',')
+        .map(str::trim)
+        .filter(|name| PROVIDED.contains(name))
+        .collect();
+    match kept.as_slice() {
+        [] => String::new(),
+        [name] => format!("use crate::lesson::{};\n", name),
+        names => format!("use crate::lesson::{{{}}};\n", names.join(", ")),
+    }
+}
+
+/// 源码中出现的讲解文本 key（`"lesson.` 开头的字符串），按出现顺序去重
+fn text_keys(source: &str) -> Vec<&str> {
+    let mut keys = Vec::new();
+    for (start, _) in source.match_indices("\"lesson.") {
+        let rest = &source[start + 1..];
+        if let Some(end) = rest.find('"') {
+            let key = &rest[..end];
+            if !keys.contains(&key) {
+                keys.push(key);
+            }
+        }
+    }
+    keys
+}
+
+/// 百分号编码：除了不保留字符以外的字节都写成 `%XX`
+fn encode(text: &str) -> String {
+    let mut out = String::with_capacity(text.len() * 3);
+    for byte in text.bytes() {
+        if byte.is_ascii_alphanumeric() || b"-_.~".contains(&byte) {
+            out.push(byte as char);
+        } else {
+            out.push_str(&format!("%{:02X}", byte));
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+    use std::process::Command;
+    use std::thread;
+
+    const SOURCE: &str = "//! # Demo\n//!\n//! 说明\n\n\
+                          use crate::exercises::demo as exercise;\n\
+                          use crate::lesson::{self, Check, Koan, LessonContext, Question, Section};\n\
+                          use std::io::Write;\n\n\
+                          #[derive(Debug)]\nstruct Unit;\n\n\
+                          pub const SECTIONS: &[Section] = &[Section::new(\"a\", \"A\", demo_a)];\n\n\
+                          pub const QUIZ: &[Question] = &[];\n\n\
+                          pub const KOANS: &[Koan] = &[Koan::new(\n    \"k\",\n    r#\"struct Hidden {\n}\n\n#[test]\nfn k() {}\n\"#,\n    &[],\n)];\n\n\
+                          pub fn run(ctx: &mut LessonContext) -> lesson::Result {\n    lesson::run_sections(ctx, SECTIONS)\n}\n\n\
+                          fn demo_a(ctx: &mut LessonContext) -> lesson::Result {\n    writeln!(ctx, \"{:?}\", Unit)?;\n\n    Ok(())\n}\n\n\
+                          fn check_a() {\n    assert!(true);\n}\n\n\
+                          #[cfg(test)]\nmod tests {\n    #[test]\n    fn t() {}\n}\n";
+
+    #[test]
+    fn test_snippet() {
+        let code = snippet(SOURCE, Some("a"), Language::Zh, false);
+        assert!(code.starts_with(
+            "//! # Demo\n//!\n//! 说明\n#![allow(unused_imports, unused_macros, dead_code, non_local_definitions)]\n\n\
+             use crate::lesson::{LessonContext, Section};\nuse std::io::Write;\n\n\
+             #[derive(Debug)]\nstruct Unit;\n\npub const SECTIONS"
+        ));
+        assert!(code.contains("    writeln!(ctx, \"{:?}\", Unit)?;\n\n    Ok(())\n}\n\n// ---- "));
+        for dropped in [
+            "QUIZ",
+            "KOANS",
+            "check_a",
+            "exercises",
+            "mod tests",
+            "Hidden",
+        ] {
+            assert!(!code.contains(dropped), "{}", dropped);
+        }
+        assert!(code.contains("const ONLY: Option<&str> = Some(\"a\");"));
+        assert!(code.contains("const LANGUAGE: Language = Language::Zh;"));
+        assert!(code.contains("const TEXTS: &[(&str, &str, &str)] = &[];"));
+    }
+
+    #[test]
+    fn test_helpers() {
+        assert_eq!(
+            lesson_imports("use crate::lesson::{self, Check, LessonContext, Section};"),
+            "use crate::lesson::{LessonContext, Section};\n"
+        );
+        assert_eq!(
+            lesson_imports("use crate::lesson::{self, Question, Verbosity};"),
+            "use crate::lesson::Verbosity;\n"
+        );
+        assert_eq!(lesson_imports("use crate::lesson::{self, Check};"), "");
+        assert_eq!(
+            text_keys("ctx.tr(\"lesson.a.x\"); ctx.tr(\"lesson.a.x\"); tr(\"lesson.a.y\")"),
+            ["lesson.a.x", "lesson.a.y"]
+        );
+        assert_eq!(encode("a b/é"), "a%20b%2F%C3%A9");
+    }
+
+    /// 每个 lesson 整理出的代码都能独立编译
+    #[test]
+    fn test_snippets_compile() {
+        let Ok((_, entries)) = curriculum::read() else {
+            return;
+        };
+        let dir = env::temp_dir().join(format!("rust-learn-share-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let rustc = env::var("RUSTC").unwrap_or_else(|_| String::from("rustc"));
+        thread::scope(|scope| {
+            for entry in &entries {
+                let (dir, rustc) = (&dir, &rustc);
+                scope.spawn(move || {
+                    let source = fs::read_to_string(entry.file()).unwrap();
+                    let file = dir.join(format!("{}.rs", entry.id()));
+                    fs::write(&file, snippet(&source, None, Language::Zh, false)).unwrap();
+                    let compiled = Command::new(rustc)
+                        .args(["--edition", "2024", "-D", "warnings", "--emit", "metadata"])
+                        .arg("--out-dir")
+                        .arg(dir)
+                        .arg(&file)
+                        .output()
+                        .unwrap();
+                    assert!(
+                        compiled.status.success(),
+                        "{}: {}",
+                        entry.id(),
+                        String::from_utf8_lossy(&compiled.stderr)
+                    );
+                });
+            }
+        });
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,282 @@
+//! 分享运行输出：`share-output <lesson>[:<section>]`
+//!
+//! 在内存中运行一遍 lesson（见 [`report`](crate::report)），把去掉颜色的输出连同版本、平台与 `--lang`
+//! 上传，打印一个链接，在群里问“为什么我的输出不一样？”时贴出来即可。
+//!
+//! 设置了 `RUST_LEARN_GIST_TOKEN`（需要 gist 权限）时上传为不公开的 GitHub gist，
+//! 否则上传到 [paste.rs](https://paste.rs)，任何拿到链接的人都能看到。只认这一个变量，
+//! 不会拿环境中通用的 `GITHUB_TOKEN` 去上传。上传前先显示要传到哪里并请求确认，`--yes` 跳过确认；
+//! 输入结束（例如管道）视为取消。上传通过 `curl` 完成，token 经标准输入传给 `curl`，不出现在命令行参数中。
+
+use crate::cli::Options;
+use crate::error::RunnerError;
+use crate::lesson::Language;
+use crate::lessons;
+use crate::report::{self, RunOptions};
+use crate::transcript;
+use crate::utils;
+use serde_json::{Value, json};
+use std::env;
+use std::fs;
+use std::io::{self, Write};
+use std::process::{self, Command, Stdio};
+
+const GIST_API: &str = "https://api.github.com/gists";
+const PASTE: &str = "https://paste.rs/";
+
+/// 上传到 gist 所用的 token
+const TOKEN_VAR: &str = "RUST_LEARN_GIST_TOKEN";
+
+/// 上传到哪里
+#[derive(Debug, PartialEq, Eq)]
+enum Service {
+    Gist { token: String },
+    Paste,
+}
+
+/// 一次上传请求
+#[derive(Debug)]
+struct Request {
+    url: &'static str,
+    /// 每行一个，经标准输入传给 `curl`
+    headers: String,
+    body: String,
+}
+
+pub fn run(sel: &str, opts: &Options, out: &mut dyn Write) -> Result<(), RunnerError> {
+    let selected = lessons::select(sel)?;
+    let [selected] = selected.as_slice() else {
+        return Err(RunnerError::Usage(format!(
+            "'{}' selects several lessons, share the output of one lesson or section at a time",
+            sel
+        )));
+    };
+    let id = selected.id();
+    let report = report::run(
+        &id,
+        RunOptions {
+            verbosity: opts.verbosity,
+            language: opts.language,
+            ..RunOptions::default()
+        },
+    )?;
+    let mut output = report.output;
+    if let Some(error) = &report.error {
+        output.push_str(&format!("Error: {}\n", error));
+    }
+    let text = document(&id, opts.language, &output);
+    let service = service(|name| env::var(name).ok());
+    writeln!(
+        out,
+        "The output of {} ({} lines) will be uploaded to {}",
+        id,
+        text.lines().count(),
+        service.destination()
+    )?;
+    if !opts.yes && !utils::io::confirm("Upload it?")? {
+        writeln!(out, "Nothing uploaded")?;
+        return Ok(());
+    }
+    let url = upload(&service, &id, &text)?;
+    writeln!(out, "{}", url)?;
+    Ok(())
+}
+
+/// 从环境变量中选择服务；`var` 便于测试
+fn service(var: impl Fn(&str) -> Option<String>) -> Service {
+    var(TOKEN_VAR)
+        .filter(|token| !token.trim().is_empty())
+        .map_or(Service::Paste, |token| Service::Gist {
+            token: token.trim().to_string(),
+        })
+}
+
+impl Service {
+    /// 上传前显示给用户的去向
+    fn destination(&self) -> &'static str {
+        match self {
+            Service::Gist { .. } => "a secret GitHub gist (anyone with the link can see it)",
+            Service::Paste => "https://paste.rs/ (public, anyone with the link can see it)",
+        }
+    }
+}
+
+/// 上传的内容：环境信息、命令与输出
+fn document(id: &str, lang: Language, output: &str) -> String {
+    format!(
+        "rust-learn {} · {} {} · --lang {}\n$ cargo learn {}\n\n{}",
+        env!("CARGO_PKG_VERSION"),
+        env::consts::OS,
+        env::consts::ARCH,
+        lang.code(),
+        id,
+        transcript::plain(output)
+    )
+}
+
+fn request(service: &Service, id: &str, text: &str) -> Request {
+    match service {
+        Service::Gist { token } => Request {
+            url: GIST_API,
+            headers: format!(
+                "Authorization: Bearer {}\nAccept: application/vnd.github+json\n\
+                 Content-Type: application/json\nUser-Agent: rust-learn\n",
+                token
+            ),
+            body: json!({
+                "description": format!("rust-learn {} output", id),
+                "public": false,
+                "files": { format!("{}.txt", id): { "content": text } },
+            })
+            .to_string(),
+        },
+        Service::Paste => Request {
+            url: PASTE,
+            headers: String::from("Content-Type: text/plain; charset=utf-8\n"),
+            body: text.to_string(),
+        },
+    }
+}
+
+/// 从响应中取出链接
+fn parse_url(service: &Service, response: &str) -> Result<String, String> {
+    match service {
+        Service::Gist { .. } => {
+            let value: Value = serde_json::from_str(response)
+                .map_err(|e| format!("unexpected response from GitHub: {}", e))?;
+            value["html_url"].as_str().map(String::from).ok_or_else(|| {
+                match value["message"].as_str() {
+                    Some(message) => format!("GitHub refused the gist: {}", message),
+                    None => String::from("GitHub returned no gist URL"),
+                }
+            })
+        }
+        Service::Paste => {
+            let url = response.trim();
+            if url.starts_with("https://") {
+                Ok(url.to_string())
+            } else {
+                Err(format!("unexpected response from paste.rs: {}", url))
+            }
+        }
+    }
+}
+
+fn upload(service: &Service, id: &str, text: &str) -> Result<String, RunnerError> {
+    let request = request(service, id, text);
+    // 请求体放在临时文件中，请求头（包括 token）占用标准输入
+    let file = env::temp_dir().join(format!("rust-learn-share-{}.txt", process::id()));
+    fs::write(&file, &request.body)?;
+    let args = [
+        String::from("-sS"),
+        String::from("--fail-with-body"),
+        String::from("-H"),
+        String::from("@-"),
+        String::from("--data-binary"),
+        format!("@{}", file.display()),
+        request.url.to_string(),
+    ];
+    let result = curl(&args, &request.headers);
+    let _ = fs::remove_file(&file);
+    let response = result?;
+    Ok(parse_url(service, &response).map_err(io::Error::other)?)
+}
+
+fn curl(args: &[String], stdin: &str) -> Result<String, RunnerError> {
+    let mut child = Command::new("curl")
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| {
+            io::Error::other(format!("cannot run curl, which is needed to upload: {}", e))
+        })?;
+    child
+        .stdin
+        .take()
+        .expect("stdin is piped")
+        .write_all(stdin.as_bytes())?;
+    let output = child.wait_with_output()?;
+    let stdout = String::from_utf8_lossy(&output.stdout).into_owned();
+    if output.status.success() {
+        Ok(stdout)
+    } else {
+        Err(io::Error::other(format!(
+            "upload failed: {}{}",
+            String::from_utf8_lossy(&output.stderr).trim(),
+            if stdout.is_empty() {
+                String::new()
+            } else {
+                format!("\n{}", stdout.trim())
+            }
+        ))
+        .into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_service() {
+        assert_eq!(service(|_| None), Service::Paste);
+        assert_eq!(service(|_| Some(String::from("  "))), Service::Paste);
+        assert_eq!(
+            service(|name| (name == "RUST_LEARN_GIST_TOKEN").then(|| String::from("t\n"))),
+            Service::Gist {
+                token: String::from("t")
+            }
+        );
+        // 通用的 GitHub token 不会被拿来上传
+        assert_eq!(
+            service(|name| (name == "GITHUB_TOKEN").then(|| String::from("t"))),
+            Service::Paste
+        );
+    }
+
+    #[test]
+    fn test_request() {
+        let gist = Service::Gist {
+            token: String::from("secret"),
+        };
+        let sent = request(&gist, "06_ownership", "moved\n");
+        assert_eq!(sent.url, GIST_API);
+        assert!(sent.headers.starts_with("Authorization: Bearer secret\n"));
+        let body: Value = serde_json::from_str(&sent.body).unwrap();
+        assert_eq!(body["public"], false);
+        assert_eq!(body["files"]["06_ownership.txt"]["content"], "moved\n");
+
+        let paste = request(&Service::Paste, "06_ownership", "moved\n");
+        assert_eq!(paste.url, PASTE);
+        assert_eq!(paste.body, "moved\n");
+    }
+
+    #[test]
+    fn test_parse_url() {
+        let gist = Service::Gist {
+            token: String::new(),
+        };
+        assert_eq!(
+            parse_url(&gist, r#"{"html_url": "https://gist.github.com/abc"}"#).unwrap(),
+            "https://gist.github.com/abc"
+        );
+        assert_eq!(
+            parse_url(&gist, r#"{"message": "Bad credentials"}"#).unwrap_err(),
+            "GitHub refused the gist: Bad credentials"
+        );
+        assert_eq!(
+            parse_url(&Service::Paste, "https://paste.rs/Xyz\n").unwrap(),
+            "https://paste.rs/Xyz"
+        );
+        assert!(parse_url(&Service::Paste, "error").is_err());
+    }
+
+    #[test]
+    fn test_document() {
+        let text = document("06_ownership", Language::Zh, "\x1b[1mmoved\x1b[0m\n");
+        assert!(text.starts_with("rust-learn ") && text.contains(" · --lang zh\n"));
+        assert!(text.ends_with("\n$ cargo learn 06_ownership\n\nmoved\n"));
+    }
+}
@@ -0,0 +1,89 @@
+//! 查看 lesson 代码：`show <lesson>[:<section>] [--copy]`
+//!
+//! 显示与 [`share`](crate::share) 相同的独立程序：去掉测验、练习与测试，附上运行器的最小替身，
+//! 讲解文本按 `--lang` 取出译文，指定小节时只运行这一节。终端中按 `--theme` 着色，可以配合 `--pager` 翻看。
+//!
+//! `--copy` 不显示，改为把代码放到系统剪贴板上，直接粘贴进 Playground 或编辑器。剪贴板由
+//! [arboard](https://docs.rs/arboard) 提供，需要开启 `clipboard` feature。需要在源码树中运行。
+
+use crate::error::RunnerError;
+use crate::highlight;
+use crate::lesson::Language;
+use crate::lessons;
+use crate::share;
+use crate::utils::fmt;
+use std::io::Write;
+
+pub fn run(sel: &str, copy: bool, lang: Language, out: &mut dyn Write) -> Result<(), RunnerError> {
+    let selected = lessons::select(sel)?;
+    let [selected] = selected.as_slice() else {
+        return Err(RunnerError::Usage(format!(
+            "'{}' selects several lessons, show one lesson or section at a time",
+            sel
+        )));
+    };
+    let code = share::program(selected, lang, false)?;
+    if copy {
+        clipboard::copy(&code)?;
+        writeln!(
+            out,
+            "Copied {} ({} lines) to the clipboard",
+            selected.id(),
+            code.lines().count()
+        )?;
+        writeln!(
+            out,
+            "{}",
+            fmt::dim("Paste it into https://play.rust-lang.org or a `src/main.rs`")
+        )?;
+    } else {
+        write!(out, "{}", highlight::rust(&code))?;
+    }
+    Ok(())
+}
+
+#[cfg(feature = "clipboard")]
+mod clipboard {
+    use std::io;
+
+    pub fn copy(text: &str) -> io::Result<()> {
+        let mut clipboard = arboard::Clipboard::new().map_err(io::Error::other)?;
+        clipboard.set_text(text).map_err(io::Error::other)
+    }
+}
+
+#[cfg(not(feature = "clipboard"))]
+mod clipboard {
+    use crate::error::RunnerError;
+
+    pub fn copy(_text: &str) -> Result<(), RunnerError> {
+        Err(RunnerError::Config(String::from(
+            "--copy needs the clipboard feature, reinstall with `cargo install --path . --features clipboard`",
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_show() {
+        assert!(matches!(
+            run("1-2", false, Language::Zh, &mut Vec::new()),
+            Err(RunnerError::Usage(_))
+        ));
+        let selected = lessons::select("16:closure_capture").unwrap();
+        let code = share::program(&selected[0], Language::Zh, false).unwrap();
+        assert!(code.contains("const ONLY: Option<&str> = Some(\"closure_capture\");"));
+    }
+
+    #[cfg(not(feature = "clipboard"))]
+    #[test]
+    fn test_copy_needs_feature() {
+        assert!(matches!(
+            run("6", true, Language::Zh, &mut Vec::new()),
+            Err(RunnerError::Config(_))
+        ));
+    }
+}
@@ -0,0 +1,260 @@
+//! 静态网站：`export --format html [dir]`
+//!
+//! 把课程目录与每个 lesson 生成为一组静态 HTML 页面（默认在 `site/` 中）：`index.html` 按章节列出
+//! 全部 lesson，每个 lesson 一页，包括讲解、高亮的代码与按 `--lang` 运行一遍的输出，页首页尾有上一课、
+//! 下一课的链接。样式内联在每个页面中，不引用任何外部资源，不用启动服务器，
+//! 直接用浏览器打开 `site/index.html` 即可阅读。需要在源码树中运行。
+
+use crate::curriculum;
+use crate::error::RunnerError;
+use crate::export::{self, Material};
+use crate::highlight;
+use crate::i18n;
+use crate::lesson::{Chapter, Language, LessonMeta};
+use crate::lessons::{self, Selected};
+use crate::transcript;
+use std::fs;
+use std::io::Write;
+use std::path::Path;
+
+/// 不给目录时生成到这里
+pub const DIR: &str = "site";
+
+const STYLE: &str =
+    "body { font-family: -apple-system, \"Segoe UI\", \"PingFang SC\", sans-serif; \
+line-height: 1.6; color: #24292f; background: #fafafa; }
+main { max-width: 52em; margin: 2em auto; padding: 0 1.5em; }
+nav { display: flex; justify-content: space-between; margin: 1em 0; font-size: 0.9em; }
+a { color: #b7410e; }
+h1 { border-bottom: 2px solid #b7410e; padding-bottom: 0.2em; }
+code { background: #eee; padding: 0.1em 0.3em; border-radius: 3px; }
+pre { background: #fff; border: 1px solid #ddd; padding: 1em; overflow-x: auto; line-height: 1.4; }
+pre code { background: none; padding: 0; }
+pre.output { background: #1e1e1e; color: #ddd; }
+.meta { color: #777; }
+ol.lessons li { margin: 0.2em 0; }
+button { font: inherit; padding: 0.3em 1.2em; cursor: pointer; }
+@media print { nav { display: none; } pre { white-space: pre-wrap; } }";
+
+pub fn run(dir: &str, lang: Language, out: &mut dyn Write) -> Result<(), RunnerError> {
+    let (_, entries) = curriculum::read()?;
+    let picked: Vec<Selected> = lessons::all()
+        .iter()
+        .filter(|l| !l.meta().deprecated)
+        .map(|l| Selected {
+            lesson: l.as_ref(),
+            section: None,
+        })
+        .collect();
+    let metas: Vec<LessonMeta> = picked.iter().map(|s| s.lesson.meta()).collect();
+    fs::create_dir_all(dir)?;
+    fs::write(Path::new(dir).join("index.html"), index(&metas, lang))?;
+    for (i, selected) in picked.iter().enumerate() {
+        let material = Material::read(selected, &entries, lang)?;
+        let neighbours = (i.checked_sub(1).map(|i| &metas[i]), metas.get(i + 1));
+        fs::write(
+            Path::new(dir).join(page_name(&metas[i])),
+            lesson_page(
+                &metas[i],
+                &material,
+                neighbours,
+                &output_block(&material.output),
+                lang,
+            ),
+        )?;
+    }
+    writeln!(out, "Exported {} lessons to {}", metas.len(), dir)?;
+    writeln!(
+        out,
+        "Open {} in a browser",
+        Path::new(dir).join("index.html").display()
+    )?;
+    Ok(())
+}
+
+pub(crate) fn page_name(meta: &LessonMeta) -> String {
+    format!("{}.html", meta.id())
+}
+
+/// 完整的页面，样式内联
+fn page(title: &str, body: &str, lang: Language) -> String {
+    format!(
+        "<!DOCTYPE html>\n<html lang=\"{}\">\n<head>\n<meta charset=\"utf-8\">\n\
+         <meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">\n\
+         <title>{}</title>\n<style>\n{}\n{}\n</style>\n</head>\n<body>\n<main>\n{}</main>\n</body>\n</html>\n",
+        lang.code(),
+        highlight::escape(title),
+        STYLE,
+        highlight::CSS,
+        body
+    )
+}
+
+/// 课程目录：按章节列出 lesson、难度与预计时长
+pub(crate) fn index(metas: &[LessonMeta], lang: Language) -> String {
+    let mut body = String::from("<h1>Rust Learn</h1>\n");
+    for chapter in Chapter::ALL {
+        let chapter_metas: Vec<_> = metas.iter().filter(|m| m.chapter == chapter).collect();
+        if chapter_metas.is_empty() {
+            continue;
+        }
+        body.push_str(&format!(
+            "<h2>{}</h2>\n<ol class=\"lessons\">\n",
+            highlight::escape(chapter.title())
+        ));
+        for meta in chapter_metas {
+            body.push_str(&format!(
+                "<li value=\"{}\">{} <span class=\"meta\">{} · ~{} min</span></li>\n",
+                meta.number,
+                link(meta, lang),
+                meta.difficulty,
+                meta.minutes
+            ));
+        }
+        body.push_str("</ol>\n");
+    }
+    page("Rust Learn", &body, lang)
+}
+
+/// 一个 lesson 的页面；`neighbours` 是上一课与下一课，`output` 是“输出”一节的 HTML
+pub(crate) fn lesson_page(
+    meta: &LessonMeta,
+    material: &Material,
+    neighbours: (Option<&LessonMeta>, Option<&LessonMeta>),
+    output: &str,
+    lang: Language,
+) -> String {
+    let (prev, next) = neighbours;
+    let nav = format!(
+        "<nav><span>{}</span><a href=\"index.html\">Contents</a><span>{}</span></nav>\n",
+        prev.map_or_else(String::new, |m| format!("← {}", link(m, lang))),
+        next.map_or_else(String::new, |m| format!("{} →", link(m, lang)))
+    );
+    let body = format!(
+        "{nav}<p class=\"meta\">{} · {} · ~{} min</p>\n{}\
+         <h2>代码</h2>\n<pre><code>{}</code></pre>\n\
+         <h2>输出</h2>\n{}{nav}",
+        highlight::escape(meta.chapter.title()),
+        meta.difficulty,
+        meta.minutes,
+        docs(&material.docs),
+        highlight::html(material.code.trim_end()),
+        output,
+    );
+    page(
+        &format!("{} {}", meta.id(), i18n::title(meta, lang)),
+        &body,
+        lang,
+    )
+}
+
+/// 运行一遍的输出，去掉 ANSI 转义序列
+fn output_block(output: &str) -> String {
+    format!(
+        "<pre class=\"output\"><code>{}</code></pre>\n",
+        highlight::escape(transcript::plain(output).trim_matches('\n'))
+    )
+}
+
+fn link(meta: &LessonMeta, lang: Language) -> String {
+    format!(
+        "<a href=\"{}\">{}. {}</a>",
+        page_name(meta),
+        meta.number,
+        highlight::escape(i18n::title(meta, lang))
+    )
+}
+
+/// 模块注释的 Markdown 转成 HTML；只支持 lesson 中用到的标题、列表、段落与行内代码。
+/// 列表项与段落的续行接在前一行后面，中文之间不加空格
+fn docs(markdown: &str) -> String {
+    let mut out = String::new();
+    let mut items: Vec<String> = Vec::new();
+    let mut paragraph = String::new();
+    let flush = |out: &mut String, items: &mut Vec<String>, paragraph: &mut String| {
+        if !items.is_empty() {
+            out.push_str("<ul>\n");
+            for item in items.drain(..) {
+                out.push_str(&format!("<li>{}</li>\n", export::html(&item)));
+            }
+            out.push_str("</ul>\n");
+        }
+        if !paragraph.is_empty() {
+            out.push_str(&format!("<p>{}</p>\n", export::html(paragraph)));
+            paragraph.clear();
+        }
+    };
+    for line in markdown.lines() {
+        let trimmed = line.trim();
+        if let Some(heading) = trimmed.strip_prefix("## ") {
+            flush(&mut out, &mut items, &mut paragraph);
+            out.push_str(&format!("<h2>{}</h2>\n", export::html(heading)));
+        } else if let Some(heading) = trimmed.strip_prefix("# ") {
+            flush(&mut out, &mut items, &mut paragraph);
+            out.push_str(&format!("<h1>{}</h1>\n", export::html(heading)));
+        } else if trimmed.is_empty() {
+            flush(&mut out, &mut items, &mut paragraph);
+        } else if let Some(item) = trimmed.strip_prefix("- ") {
+            if !paragraph.is_empty() {
+                flush(&mut out, &mut Vec::new(), &mut paragraph);
+            }
+            items.push(item.to_string());
+        } else if let Some(last) = items.last_mut() {
+            join(last, trimmed);
+        } else {
+            join(&mut paragraph, trimmed);
+        }
+    }
+    flush(&mut out, &mut items, &mut paragraph);
+    out
+}
+
+fn join(text: &mut String, line: &str) {
+    if text.ends_with(|c: char| c.is_ascii()) && line.starts_with(|c: char| c.is_ascii()) {
+        text.push(' ');
+    }
+    text.push_str(line);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_docs() {
+        let markdown = "# Demo\n\n目标：理解 `mut`\n\n## 要点\n- 默认不可变\n- 用 `mut`\n  `let mut x`，\n  之后可以修改\n\n\
+                        ## 运行\n`cargo run -- 02_demo`\n";
+        assert_eq!(
+            docs(markdown),
+            "<h1>Demo</h1>\n<p>目标：理解 <code>mut</code></p>\n<h2>要点</h2>\n\
+             <ul>\n<li>默认不可变</li>\n<li>用 <code>mut</code> <code>let mut x</code>，之后可以修改</li>\n</ul>\n\
+             <h2>运行</h2>\n<p><code>cargo run -- 02_demo</code></p>\n"
+        );
+    }
+
+    #[test]
+    fn test_pages() {
+        let metas: Vec<LessonMeta> = lessons::all().iter().map(|l| l.meta()).collect();
+        let index = index(&metas, Language::Zh);
+        assert!(index.starts_with("<!DOCTYPE html>\n<html lang=\"zh\">"));
+        assert_eq!(index.matches("<li value=").count(), metas.len());
+        assert!(!index.contains("http"));
+
+        let material = Material {
+            docs: String::from("# Demo\n"),
+            code: String::from("let v: Vec<u8> = vec![];\n"),
+            output: String::from("\x1b[1m<ok>\x1b[0m\n"),
+            source: String::new(),
+        };
+        let page = lesson_page(
+            &metas[1],
+            &material,
+            (Some(&metas[0]), None),
+            &output_block(&material.output),
+            Language::Zh,
+        );
+        assert!(page.contains(&format!("← <a href=\"{}\">", page_name(&metas[0]))));
+        assert!(page.contains("<span class=\"ty\">Vec</span>&lt;u8&gt;"));
+        assert!(page.contains("<pre class=\"output\"><code>&lt;ok&gt;</code></pre>"));
+    }
+}
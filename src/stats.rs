@@ -0,0 +1,434 @@
+//! 学习统计：`stats` 命令
+//!
+//! 根据进度文件中的运行记录与完成记录，显示连续学习天数、累计学习时间与每周完成的 lesson 数。
+//! 日期按 UTC 计算；今天还没运行过时，截至昨天的连续天数仍然算数。
+//!
+//! 做过测验或练习后还显示总评与每个 lesson 的分数（见 [`grade`](crate::grade)），
+//! 有自评（`--confidence`）时显示平均把握与没把握（1–2）的 lesson；
+//! `stats export [file]` 把成绩导出为 CSV，不给文件时写到标准输出。
+//!
+//! `stats --format csv [file]` 导出每个 lesson 的完整学习记录：完成时间、测验与练习的分数和尝试次数、
+//! 运行次数与累计用时（包括单独运行的小节），便于老师或自学者在电子表格中分析。
+
+use crate::cli::Format;
+use crate::error::RunnerError;
+use crate::grade;
+use crate::lesson::LessonMeta;
+use crate::progress::{Progress, Run};
+use crate::utils::fmt;
+use crate::utils::time::Timestamp;
+use std::collections::BTreeSet;
+use std::fs;
+use std::io::Write;
+use std::time::Duration;
+
+/// 显示最近几周的完成数
+const WEEKS: i64 = 4;
+
+#[derive(Debug, PartialEq, Eq)]
+struct Stats {
+    /// 截至今天（或昨天）的连续学习天数
+    streak: usize,
+    longest: usize,
+    /// 有运行记录的天数
+    days: usize,
+    runs: usize,
+    total: Duration,
+    /// 已完成的 lesson 数（不含单独完成的小节）与它们的预计学时（分钟）
+    completed: usize,
+    estimated: u32,
+    /// 最近 [`WEEKS`] 周每周完成的 lesson 数：(周一的日期, 数量)，最早的在前
+    weeks: Vec<(Timestamp, usize)>,
+}
+
+/// `stats [export [file]] | --format csv [file]`，`args` 不含 `stats` 本身
+pub fn run(
+    args: &[String],
+    format: Format,
+    progress: &Progress,
+    metas: &[LessonMeta],
+    out: &mut dyn Write,
+) -> Result<(), RunnerError> {
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+    match (format, args.as_slice()) {
+        (Format::Text, []) => show(progress, metas, out),
+        (Format::Text, ["export"]) => {
+            write!(out, "{}", grade::csv(&grade::lessons(progress, metas)))?;
+            Ok(())
+        }
+        (Format::Text, ["export", file]) => {
+            fs::write(file, grade::csv(&grade::lessons(progress, metas)))?;
+            writeln!(out, "Exported scores to {}", file)?;
+            Ok(())
+        }
+        (Format::Csv, []) => {
+            write!(out, "{}", csv(progress, metas))?;
+            Ok(())
+        }
+        (Format::Csv, [file]) => {
+            fs::write(file, csv(progress, metas))?;
+            writeln!(
+                out,
+                "Exported statistics of {} lessons to {}",
+                metas.len(),
+                file
+            )?;
+            Ok(())
+        }
+        _ => Err(RunnerError::Usage(String::from(
+            "Usage: stats, stats export [file], stats --format csv [file]",
+        ))),
+    }
+}
+
+/// 每个 lesson 一行的学习记录；用时以秒计，小节的运行计入所属的 lesson
+fn csv(progress: &Progress, metas: &[LessonMeta]) -> String {
+    let cell = |n: Option<u32>| n.map(|n| n.to_string()).unwrap_or_default();
+    let mut out = String::from(
+        "lesson,title,completed,quiz,quiz_attempts,exercise,exercise_attempts,score,runs,seconds\n",
+    );
+    for g in grade::lessons(progress, metas) {
+        let runs: Vec<&Run> = progress
+            .runs()
+            .iter()
+            .filter(|r| r.id.split(':').next() == Some(g.id.as_str()))
+            .collect();
+        let millis: u64 = runs.iter().map(|r| r.millis).sum();
+        out.push_str(&format!(
+            "{},{},{},{},{},{},{},{},{},{}\n",
+            g.id,
+            grade::quote(g.title),
+            progress
+                .completed_at(&g.id)
+                .map(|at| at.rfc3339())
+                .unwrap_or_default(),
+            cell(g.quiz),
+            progress.quiz_scores(&g.id).len(),
+            cell(g.exercise),
+            progress.exercise(&g.id).map_or(0, |s| s.attempts),
+            cell(g.score()),
+            runs.len(),
+            millis.div_ceil(1000)
+        ));
+    }
+    out
+}
+
+fn show(progress: &Progress, metas: &[LessonMeta], out: &mut dyn Write) -> Result<(), RunnerError> {
+    let stats = compute(progress, metas, day(Timestamp::now()));
+    let grades = grade::lessons(progress, metas);
+    let summary = grade::summarize(&grades);
+    if stats.runs == 0 && stats.completed == 0 && summary.graded == 0 {
+        writeln!(out, "No study history yet, run a lesson to get started")?;
+        return Ok(());
+    }
+    let days = |n: usize| {
+        if n == 1 {
+            "1 day".into()
+        } else {
+            format!("{} days", n)
+        }
+    };
+    let streak = format!("{} (longest {})", days(stats.streak), days(stats.longest));
+    let time = format!(
+        "{} in {} runs on {}",
+        hours(stats.total),
+        stats.runs,
+        days(stats.days)
+    );
+    let completed = format!(
+        "{} / {} lessons (~{} estimated)",
+        stats.completed,
+        metas.len(),
+        hours(Duration::from_secs(u64::from(stats.estimated) * 60))
+    );
+    let mut rows = vec![
+        ("Streak:", streak),
+        ("Study time:", time),
+        ("Completed:", completed),
+    ];
+    if let Some(average) = summary.average {
+        rows.push((
+            "Score:",
+            format!(
+                "{} / 100, grade {} ({} / {} lessons graded)",
+                average,
+                grade::letter(average),
+                summary.graded,
+                summary.total
+            ),
+        ));
+    }
+    if let Some(confidence) = confidence(progress, metas) {
+        rows.push(("Confidence:", confidence));
+    }
+    let rows: Vec<(&str, &str)> = rows.iter().map(|(k, v)| (*k, v.as_str())).collect();
+    write!(out, "{}", fmt::kv_table(&rows))?;
+    writeln!(out, "\nLessons per week:")?;
+    for (monday, count) in &stats.weeks {
+        writeln!(
+            out,
+            "  {}  {:<10} {}",
+            &monday.to_string()[..10],
+            fmt::text(&"█".repeat(*count)),
+            count
+        )?;
+    }
+    if summary.graded > 0 {
+        writeln!(out, "\nScores (quiz / exercise → lesson):")?;
+        let cell = |n: Option<u32>| n.map_or_else(|| String::from("-"), |n| n.to_string());
+        for g in &grades {
+            let Some(score) = g.score() else { continue };
+            writeln!(
+                out,
+                "  {:<24} {:>3} / {:>3} → {:>3} {}",
+                g.id,
+                cell(g.quiz),
+                cell(g.exercise),
+                score,
+                grade::letter(score)
+            )?;
+        }
+    }
+    Ok(())
+}
+
+/// 自评汇总：平均把握与自评 1–2 的 lesson；没有自评时为 `None`
+fn confidence(progress: &Progress, metas: &[LessonMeta]) -> Option<String> {
+    let rated: Vec<(String, u8)> = metas
+        .iter()
+        .filter_map(|m| Some((m.id(), progress.confidence(&m.id())?.level)))
+        .collect();
+    if rated.is_empty() {
+        return None;
+    }
+    let sum: u32 = rated.iter().map(|(_, level)| u32::from(*level)).sum();
+    let mut text = format!(
+        "{:.1} / 5 over {} lesson{}",
+        f64::from(sum) / rated.len() as f64,
+        rated.len(),
+        if rated.len() == 1 { "" } else { "s" }
+    );
+    let unsure: Vec<&str> = rated
+        .iter()
+        .filter(|(_, level)| *level <= 2)
+        .map(|(id, _)| id.as_str())
+        .collect();
+    if !unsure.is_empty() {
+        text.push_str(&format!(", unsure about {}", unsure.join(", ")));
+    }
+    Some(text)
+}
+
+fn compute(progress: &Progress, metas: &[LessonMeta], today: i64) -> Stats {
+    let run_days: BTreeSet<i64> = progress.runs().iter().map(|r| day(r.at)).collect();
+    let (mut longest, mut current, mut previous) = (0, 0, None);
+    for &d in &run_days {
+        current = if previous == Some(d - 1) {
+            current + 1
+        } else {
+            1
+        };
+        longest = longest.max(current);
+        previous = Some(d);
+    }
+    // 最后一次学习是今天或昨天时，连续天数还没有中断
+    let streak = match previous {
+        Some(last) if last >= today - 1 => current,
+        _ => 0,
+    };
+
+    let done: Vec<&LessonMeta> = metas
+        .iter()
+        .filter(|m| progress.is_complete(&m.id()))
+        .collect();
+    let this_week = week(today);
+    let weeks = (this_week - WEEKS + 1..=this_week)
+        .map(|w| {
+            let count = done
+                .iter()
+                .filter_map(|m| progress.completed_at(&m.id()))
+                .filter(|at| week(day(*at)) == w)
+                .count();
+            (Timestamp::from_unix(monday(w) * 86_400), count)
+        })
+        .collect();
+
+    Stats {
+        streak,
+        longest,
+        days: run_days.len(),
+        runs: progress.runs().len(),
+        total: progress
+            .runs()
+            .iter()
+            .map(|r| Duration::from_millis(r.millis))
+            .sum(),
+        completed: done.len(),
+        estimated: done.iter().map(|m| m.minutes).sum(),
+        weeks,
+    }
+}
+
+/// 截至 `today`（或昨天）的连续学习天数
+pub(crate) fn streak(progress: &Progress, today: i64) -> usize {
+    compute(progress, &[], today).streak
+}
+
+/// 距 1970-01-01 的天数
+pub(crate) fn day(at: Timestamp) -> i64 {
+    at.to_unix().div_euclid(86_400)
+}
+
+/// 以周一为一周的开始；1970-01-01 是周四
+fn week(day: i64) -> i64 {
+    (day + 3).div_euclid(7)
+}
+
+fn monday(week: i64) -> i64 {
+    week * 7 - 3
+}
+
+/// `1h 05m`、`12m 30s`、`45s`
+pub(crate) fn hours(d: Duration) -> String {
+    let secs = d.as_secs();
+    match (secs / 3600, secs % 3600 / 60, secs % 60) {
+        (0, 0, s) => format!("{}s", s),
+        (0, m, s) => format!("{}m {:02}s", m, s),
+        (h, m, _) => format!("{}h {:02}m", h, m),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lesson::{Chapter, Difficulty};
+    use std::env;
+    use std::fs;
+
+    fn meta(number: usize, slug: &'static str) -> LessonMeta {
+        LessonMeta {
+            number,
+            slug,
+            aliases: &[],
+            title: slug,
+            chapter: Chapter::Basics,
+            difficulty: Difficulty::Beginner,
+            minutes: 15,
+            tags: &[],
+            prerequisites: &[],
+            deprecated: false,
+            replaced_by: None,
+        }
+    }
+
+    #[test]
+    fn test_week() {
+        // 1970-01-05 是周一
+        assert_eq!(week(3), 0);
+        assert_eq!(week(4), 1);
+        assert_eq!(monday(week(10)), 4);
+        assert_eq!(monday(week(-1)), -3);
+    }
+
+    #[test]
+    fn test_confidence() {
+        let metas = [meta(1, "a"), meta(2, "b"), meta(3, "c")];
+        let mut progress = Progress::default();
+        assert_eq!(confidence(&progress, &metas), None);
+        progress.record_confidence("01_a", 4).unwrap();
+        progress.record_confidence("03_c", 2).unwrap();
+        progress.record_confidence("09_gone", 1).unwrap();
+        assert_eq!(
+            confidence(&progress, &metas).as_deref(),
+            Some("3.0 / 5 over 2 lessons, unsure about 03_c")
+        );
+    }
+
+    #[test]
+    fn test_csv() {
+        let metas = [
+            meta(1, "a"),
+            LessonMeta {
+                title: "b, c",
+                ..meta(2, "b")
+            },
+        ];
+        let mut progress = Progress::default();
+        progress.record_quiz("01_a", 2, 4).unwrap();
+        progress.record_quiz("01_a", 3, 4).unwrap();
+        progress
+            .record_run("01_a", Duration::from_millis(1500))
+            .unwrap();
+        progress
+            .record_run("01_a:x", Duration::from_millis(2000))
+            .unwrap();
+        progress.record_run("02_b", Duration::from_secs(9)).unwrap();
+        assert_eq!(
+            csv(&progress, &metas),
+            "lesson,title,completed,quiz,quiz_attempts,exercise,exercise_attempts,score,runs,seconds\n\
+             01_a,a,,75,2,,0,38,2,4\n\
+             02_b,\"b, c\",,,0,,0,,1,9\n"
+        );
+    }
+
+    #[test]
+    fn test_hours() {
+        assert_eq!(hours(Duration::from_millis(45_900)), "45s");
+        assert_eq!(hours(Duration::from_secs(750)), "12m 30s");
+        assert_eq!(hours(Duration::from_secs(3900)), "1h 05m");
+    }
+
+    #[test]
+    fn test_compute() {
+        let dir = env::temp_dir().join(format!("rust-learn-stats-{}", std::process::id()));
+        let path = dir.join("progress.json");
+        let today = 20_000;
+        let at = |day: i64| Timestamp::from_unix(day * 86_400 + 3600).rfc3339();
+        let text = format!(
+            r#"{{
+                "completed": {{ "01_a": "{}", "02_b": "{}", "02_b:x": "{}" }},
+                "runs": [
+                    {{ "at": "{}", "id": "01_a", "millis": 60000 }},
+                    {{ "at": "{}", "id": "01_a", "millis": 30000 }},
+                    {{ "at": "{}", "id": "02_b", "millis": 1500 }},
+                    {{ "at": "{}", "id": "02_b", "millis": 500 }},
+                    {{ "at": "{}", "id": "03_c", "millis": 1000 }}
+                ]
+            }}"#,
+            at(today - 1),
+            at(today - 8),
+            at(today - 8),
+            at(today - 10),
+            at(today - 9),
+            at(today - 8),
+            at(today - 1),
+            at(today),
+        );
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(&path, text).unwrap();
+        let progress = Progress::at(&path);
+        let metas = [meta(1, "a"), meta(2, "b"), meta(3, "c")];
+        let stats = compute(&progress, &metas, today);
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(stats.streak, 2);
+        assert_eq!(stats.longest, 3);
+        assert_eq!(stats.days, 5);
+        assert_eq!(stats.runs, 5);
+        assert_eq!(stats.total, Duration::from_secs(93));
+        assert_eq!(stats.completed, 2);
+        assert_eq!(stats.estimated, 30);
+        let counts: Vec<_> = stats.weeks.iter().map(|(_, n)| *n).collect();
+        // 第 20000 天是周五：today - 1 在本周，today - 8 在上周
+        assert_eq!(counts, [0, 0, 1, 1]);
+        assert_eq!(
+            stats.weeks[3].0,
+            Timestamp::from_unix(monday(week(today)) * 86_400)
+        );
+
+        // 没有记录，或最近两天都没有运行时，连续天数为零
+        assert_eq!(compute(&Progress::default(), &metas, today).streak, 0);
+        assert_eq!(compute(&progress, &metas, today + 2).streak, 0);
+    }
+}
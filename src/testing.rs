@@ -0,0 +1,226 @@
+//! 测试辅助：带时限地运行 lesson，断言 lesson 的输出（[`assert_lesson_output!`](crate::assert_lesson_output)）
+//!
+//! lesson 模块里的单元测试只能调用私有的辅助函数，看不到学习者实际看到的输出。这里通过
+//! [`run`] 在内存中运行整个 lesson（或其中一节），再逐条检查输出，可以写在 `tests/` 下的集成测试中：
+//!
+//! ```
+//! rust_learn::assert_lesson_output!("06_ownership", contains "Cloned:", section "栈 vs 堆");
+//! ```
+//!
+//! 运行时设定了随机种子，HashMap 按键输出，断言不会因为遍历顺序时好时坏。
+//! 不满足的条件全部列出后才失败，并附上完整的输出。
+//!
+//! 测试中运行 lesson 都应当经过 [`run`]（逐行接收输出时用 [`stream`]）：它在另一个线程中运行，超过 [`TIMEOUT`] 还没结束就判定失败，
+//! 不小心写出死循环的 lesson 会让测试报出超时，而不是让 `cargo test` 一直挂着。
+//! 机器较慢时用环境变量 `RUST_LEARN_TEST_TIMEOUT`（秒）放宽。
+
+use crate::error::RunnerError;
+use crate::report::{self, LessonReport, RunOptions, Streamed};
+use crate::utils::deterministic::FIXED_SEED;
+use std::env;
+use std::panic;
+use std::sync::mpsc::{self, RecvTimeoutError, Sender};
+use std::thread;
+use std::time::Duration;
+
+/// 单次运行的默认时限；正常的 lesson 在几毫秒内就能跑完
+pub const TIMEOUT: Duration = Duration::from_secs(30);
+
+/// 覆盖 [`TIMEOUT`] 的环境变量，单位为秒
+const TIMEOUT_VAR: &str = "RUST_LEARN_TEST_TIMEOUT";
+
+/// 同 [`run_lesson`](crate::run_lesson)，超过时限还没结束时 panic
+#[track_caller]
+pub fn run(sel: &str, opts: RunOptions) -> Result<LessonReport, RunnerError> {
+    let owned = sel.to_string();
+    watchdog(sel, timeout(), move || crate::run_lesson(&owned, opts))
+}
+
+/// 同 [`report::stream`]，超过时限还没结束时 panic
+#[track_caller]
+pub fn stream(
+    sel: &str,
+    opts: RunOptions,
+    tx: Sender<Streamed>,
+) -> Result<LessonReport, RunnerError> {
+    let owned = sel.to_string();
+    watchdog(sel, timeout(), move || report::stream(&owned, opts, tx))
+}
+
+fn timeout() -> Duration {
+    env::var(TIMEOUT_VAR)
+        .ok()
+        .and_then(|s| s.parse().ok())
+        .map_or(TIMEOUT, Duration::from_secs)
+}
+
+/// 在新线程中执行 `f` 并等待结果；超时则 panic，`f` 中的 panic 原样传回。
+/// 超时的线程无法强行结束，它会在测试进程退出时随之结束
+#[track_caller]
+fn watchdog<T, F>(name: &str, timeout: Duration, f: F) -> T
+where
+    T: Send + 'static,
+    F: FnOnce() -> T + Send + 'static,
+{
+    let (tx, rx) = mpsc::channel();
+    let handle = thread::Builder::new()
+        .name(format!("watchdog: {}", name))
+        .spawn(move || {
+            // 接收方已经超时放弃时发送失败，不用处理
+            let _ = tx.send(f());
+        })
+        .expect("failed to spawn a test thread");
+    match rx.recv_timeout(timeout) {
+        Ok(value) => value,
+        Err(RecvTimeoutError::Timeout) => panic!(
+            "'{}' did not finish within {:?}, is there an infinite loop? (set {} to allow more seconds)",
+            name, timeout, TIMEOUT_VAR
+        ),
+        Err(RecvTimeoutError::Disconnected) => match handle.join() {
+            Err(payload) => panic::resume_unwind(payload),
+            Ok(()) => unreachable!("the thread exits only after sending"),
+        },
+    }
+}
+
+/// 对输出的一条要求
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Expect<'a> {
+    /// 输出中包含这段文字
+    Contains(&'a str),
+    /// 输出中不包含这段文字
+    Excludes(&'a str),
+    /// 输出过这个标题的小节
+    Section(&'a str),
+}
+
+impl Expect<'_> {
+    /// 不满足时的说明
+    fn failure(&self, report: &LessonReport) -> Option<String> {
+        match *self {
+            Expect::Contains(text) if !report.output.contains(text) => {
+                Some(format!("expected the output to contain {:?}", text))
+            }
+            Expect::Excludes(text) if report.output.contains(text) => {
+                Some(format!("expected the output not to contain {:?}", text))
+            }
+            Expect::Section(title) if !report.sections.iter().any(|s| s == title) => Some(format!(
+                "expected a section titled {:?}, found [{}]",
+                title,
+                report.sections.join(", ")
+            )),
+            _ => None,
+        }
+    }
+}
+
+/// 运行 `sel` 选中的 lesson，检查它正常结束并满足全部 `expects`，否则 panic
+#[track_caller]
+pub fn check(sel: &str, expects: &[Expect]) {
+    let opts = RunOptions {
+        seed: Some(FIXED_SEED),
+        ..RunOptions::default()
+    };
+    let report = match run(sel, opts) {
+        Ok(report) => report,
+        Err(e) => panic!("cannot run '{}': {}", sel, e),
+    };
+    if !report.is_ok() {
+        panic!(
+            "'{}' failed: {}\n--- output ---\n{}",
+            sel,
+            report.error.as_deref().unwrap_or_default(),
+            report.output
+        );
+    }
+    let failures: Vec<String> = expects.iter().filter_map(|e| e.failure(&report)).collect();
+    if !failures.is_empty() {
+        panic!(
+            "{} of {} expectations failed for '{}':\n  {}\n--- output ---\n{}",
+            failures.len(),
+            expects.len(),
+            sel,
+            failures.join("\n  "),
+            report.output
+        );
+    }
+}
+
+/// 运行 lesson 并断言输出，见 [`testing`](crate::testing)
+///
+/// 第一个参数是选择器（写法同命令行，例如 `"06_ownership"`、`"16:closure_capture"`），
+/// 之后是任意条 `contains "文字"`、`excludes "文字"`、`section "小节标题"`，用逗号分隔。
+#[macro_export]
+macro_rules! assert_lesson_output {
+    (@expect contains $text:expr) => {
+        $crate::testing::Expect::Contains($text)
+    };
+    (@expect excludes $text:expr) => {
+        $crate::testing::Expect::Excludes($text)
+    };
+    (@expect section $text:expr) => {
+        $crate::testing::Expect::Section($text)
+    };
+    ($sel:expr $(, $kind:ident $text:expr)* $(,)?) => {
+        $crate::testing::check(
+            $sel,
+            &[$($crate::assert_lesson_output!(@expect $kind $text)),*],
+        )
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg_attr(not(feature = "basics"), ignore = "needs the basics lessons")]
+    fn test_passing_expectations() {
+        crate::assert_lesson_output!(
+            "15_collections",
+            section "HashMap 哈希映射",
+            contains r#"Word count: {"hello": 1, "wonderful": 1, "world": 2}"#,
+            excludes "panicked",
+        );
+        crate::assert_lesson_output!("16:closure_capture");
+    }
+
+    #[test]
+    #[cfg_attr(not(feature = "basics"), ignore = "needs the basics lessons")]
+    fn test_failures_are_listed() {
+        let err = panic::catch_unwind(|| {
+            crate::assert_lesson_output!(
+                "06_ownership",
+                contains "Cloned:",
+                contains "no such line",
+                section "不存在的小节",
+            )
+        })
+        .unwrap_err();
+        let message = err.downcast_ref::<String>().unwrap();
+        assert!(message.starts_with("2 of 3 expectations failed for '06_ownership':\n"));
+        assert!(message.contains("expected the output to contain \"no such line\""));
+        assert!(message.contains("found [所有权基础, 作用域与丢弃, 栈 vs 堆]"));
+        assert!(message.contains("--- output ---\n"));
+    }
+
+    #[test]
+    fn test_watchdog() {
+        assert_eq!(watchdog("quick", TIMEOUT, || 42), 42);
+        let err = panic::catch_unwind(|| {
+            watchdog("spin", Duration::from_millis(50), || {
+                loop {
+                    thread::sleep(Duration::from_millis(10));
+                }
+            })
+        })
+        .unwrap_err();
+        let message = err.downcast_ref::<String>().unwrap();
+        assert!(
+            message.starts_with("'spin' did not finish within 50ms, is there an infinite loop?")
+        );
+        let err =
+            panic::catch_unwind(|| watchdog("boom", TIMEOUT, || panic!("lesson bug"))).unwrap_err();
+        assert_eq!(err.downcast_ref::<&str>(), Some(&"lesson bug"));
+    }
+}
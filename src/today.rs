@@ -0,0 +1,122 @@
+//! 每日学习：`today`
+//!
+//! 每天学一点的入口：显示连续学习天数（见 [`stats`](crate::stats)）、今天的复习清单与接下来要学的
+//! lesson（与 [`recommend`](crate::recommend) 的建议相同），然后询问是否马上开始学。
+//! 复习清单是最该复习的几个 lesson 中今天还没学过的，运行或做过测验后就从清单上去掉。
+
+use crate::cli::Options;
+use crate::error::RunnerError;
+use crate::i18n;
+use crate::lesson::LessonMeta;
+use crate::lessons;
+use crate::progress::Progress;
+use crate::recommend::{self, Review};
+use crate::stats;
+use crate::utils::time::Timestamp;
+use crate::utils::{fmt, io};
+use std::io::Write;
+
+pub fn run(
+    progress: &mut Progress,
+    opts: &Options,
+    out: &mut dyn Write,
+) -> Result<(), RunnerError> {
+    let lang = opts.language;
+    let order = lessons::learning_path().map_err(RunnerError::Config)?;
+    let today = stats::day(Timestamp::now());
+    let studied_today = progress.runs().iter().any(|r| stats::day(r.at) == today);
+    writeln!(
+        out,
+        "Streak: {}",
+        streak(stats::streak(progress, today), studied_today)
+    )?;
+    let queue = queue(&order, progress, today);
+    if queue.is_empty() {
+        writeln!(out, "Review: {}", fmt::dim("nothing due today"))?;
+    } else {
+        recommend::show_reviews(&queue, lang, out)?;
+    }
+    let Some(meta) = recommend::next(&order, progress) else {
+        writeln!(out, "Next:   {}", fmt::text("every lesson is complete 🎉"))?;
+        return Ok(());
+    };
+    writeln!(
+        out,
+        "Next:   {:<22} {}",
+        meta.id(),
+        i18n::title(&meta, lang)
+    )?;
+    if io::confirm(&format!("\nStart {} now?", meta.id()))? {
+        lessons::run_selected(&meta.id(), progress, opts, out)?;
+    }
+    Ok(())
+}
+
+/// 今天的复习清单：最该复习的几个 lesson 中今天（`today`，距 1970-01-01 的天数）还没学过的
+fn queue(order: &[LessonMeta], progress: &Progress, today: i64) -> Vec<Review> {
+    recommend::reviews(order, progress)
+        .into_iter()
+        .filter(|r| r.studied.is_none_or(|at| stats::day(at) < today))
+        .take(recommend::REVIEWS)
+        .collect()
+}
+
+fn streak(days: usize, studied_today: bool) -> String {
+    let days_text = if days == 1 {
+        String::from("1 day")
+    } else {
+        format!("{} days", days)
+    };
+    match (days, studied_today) {
+        (0, _) => String::from("none yet, run a lesson today to start one"),
+        (_, true) => format!("{}, studied today ✓", days_text),
+        (_, false) => format!("{}, study today to keep it going", days_text),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lesson::{Chapter, Difficulty};
+
+    fn meta(number: usize, slug: &'static str) -> LessonMeta {
+        LessonMeta {
+            number,
+            slug,
+            aliases: &[],
+            title: slug,
+            chapter: Chapter::Basics,
+            difficulty: Difficulty::Beginner,
+            minutes: 10,
+            tags: &[],
+            prerequisites: &[],
+            deprecated: false,
+            replaced_by: None,
+        }
+    }
+
+    #[test]
+    fn test_queue() {
+        let order = [meta(1, "a"), meta(2, "b")];
+        let mut progress = Progress::default();
+        progress.complete("01_a").unwrap();
+        let today = stats::day(Timestamp::now());
+        // 今天刚学完的不用再复习，明天才进入清单
+        assert!(queue(&order, &progress, today).is_empty());
+        let ids: Vec<_> = queue(&order, &progress, today + 1)
+            .iter()
+            .map(|r| r.meta.id())
+            .collect();
+        assert_eq!(ids, ["01_a"]);
+    }
+
+    #[test]
+    fn test_streak() {
+        assert_eq!(
+            streak(0, false),
+            "none yet, run a lesson today to start one"
+        );
+        assert_eq!(streak(1, true), "1 day, studied today ✓");
+        assert_eq!(streak(3, false), "3 days, study today to keep it going");
+    }
+}
@@ -0,0 +1,136 @@
+//! 会话记录：`--transcript`
+//!
+//! 打开后（命令行 `--transcript` 或配置项 `transcript = true`），本次运行的全部输出——lesson 的输出、
+//! 测验题目与输入的答案、成绩——都先记在内存中，退出时整理成 Markdown 追加到当前目录下的
+//! `sessions/YYYY-MM-DD.md`（日期按 UTC）：每天一个文件，每次运行一节，自动积累成学习日志。
+//! 与进度一样按学习档案分开，`--profile alice` 的记录在 `sessions/alice/` 中。
+//!
+//! 与 `--output` 原样记录每次运行不同，这里去掉着色用的 ANSI 转义序列，输出放进代码块，
+//! 同一天的记录合并在一起。`practice` 等在子进程中运行的命令，子进程直接显示的输出不会被记录。
+
+use crate::progress;
+use crate::utils::output;
+use crate::utils::time::Timestamp;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+/// 会话记录所在的目录，相对于当前目录
+pub const DIR: &str = "sessions";
+
+/// 学习档案 `profile` 的会话记录目录；默认档案直接用 [`DIR`]
+pub fn dir(profile: &str) -> PathBuf {
+    if profile == progress::DEFAULT_PROFILE {
+        PathBuf::from(DIR)
+    } else {
+        Path::new(DIR).join(profile)
+    }
+}
+
+/// 开始记录之后的输出
+pub fn start() {
+    output::start_transcript();
+}
+
+/// 停止记录，把本次运行（命令为 `command`，开始于 `started`）的输出追加到 `dir` 中当天的文件；
+/// 返回文件路径，没有开始记录或没有任何输出时不写文件
+pub fn finish(dir: &Path, command: &[String], started: Timestamp) -> io::Result<Option<PathBuf>> {
+    match output::take_transcript() {
+        Some(text) if !text.trim().is_empty() => append(dir, command, started, &text).map(Some),
+        _ => Ok(None),
+    }
+}
+
+fn append(dir: &Path, command: &[String], started: Timestamp, text: &str) -> io::Result<PathBuf> {
+    let stamp = started.to_string();
+    let (date, time) = stamp.split_at(10);
+    let path = dir.join(format!("{}.md", date));
+    fs::create_dir_all(dir)?;
+    let mut file = OpenOptions::new().create(true).append(true).open(&path)?;
+    let mut section = String::new();
+    if file.metadata()?.len() == 0 {
+        section.push_str(&format!("# Study log {}\n", date));
+    }
+    section.push_str(&format!(
+        "\n## {} UTC `{}`\n\n{}",
+        time.trim(),
+        command.join(" "),
+        render(text)
+    ));
+    file.write_all(section.as_bytes())?;
+    Ok(path)
+}
+
+/// 本次运行的输出放进 `text` 代码块
+fn render(text: &str) -> String {
+    fenced("text", text)
+}
+
+/// 去掉 ANSI 转义序列后放进标明语言 `info` 的代码块；内容中本身有 ``` 时用更长的围栏
+pub(crate) fn fenced(info: &str, text: &str) -> String {
+    let plain = plain(text);
+    let mut fence = String::from("```");
+    while plain.contains(&fence) {
+        fence.push('`');
+    }
+    format!(
+        "{}{}\n{}\n{}\n",
+        fence,
+        info,
+        plain.trim_start_matches('\n').trim_end(),
+        fence
+    )
+}
+
+/// 去掉 ANSI 转义序列与 `\r`
+pub(crate) fn plain(text: &str) -> String {
+    let mut plain = String::with_capacity(text.len());
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            chars.by_ref().find(|c| c.is_ascii_alphabetic());
+        } else if c != '\r' {
+            plain.push(c);
+        }
+    }
+    plain
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render() {
+        assert_eq!(
+            render("\n\x1b[1;32m✓\x1b[0m done\r\n\n"),
+            "```text\n✓ done\n```\n"
+        );
+        assert_eq!(render("```rust\n```"), "````text\n```rust\n```\n````\n");
+    }
+
+    #[test]
+    fn test_dir() {
+        assert_eq!(dir(progress::DEFAULT_PROFILE), Path::new("sessions"));
+        assert_eq!(dir("alice"), Path::new("sessions/alice"));
+    }
+
+    #[test]
+    fn test_append() {
+        let dir =
+            std::env::temp_dir().join(format!("rust-learn-transcript-{}", std::process::id()));
+        let started = Timestamp::from_unix(86_400 + 3_600);
+        let command = vec![String::from("quiz"), String::from("6")];
+        let path = append(&dir, &command, started, "Q1\n").unwrap();
+        assert_eq!(path, dir.join("1970-01-02.md"));
+        append(&dir, &command, started, "Q2\n").unwrap();
+        let text = fs::read_to_string(&path).unwrap();
+        assert_eq!(
+            text,
+            "# Study log 1970-01-02\n\
+             \n## 01:00:00 UTC `quiz 6`\n\n```text\nQ1\n```\n\
+             \n## 01:00:00 UTC `quiz 6`\n\n```text\nQ2\n```\n"
+        );
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,384 @@
+//! 翻译文件：`export --format po [file]` 与 `import-translations <file>`
+//!
+//! 界面与 lesson 正文中需要翻译的文本都在 `locales/<code>.toml` 中（见 [`i18n`](crate::i18n)），
+//! 以中文文本包为准。`export --format po --lang en` 把全部文本导出为 gettext 的 PO 文件：
+//! `msgctxt` 是键，`msgid` 是中文原文，`msgstr` 是已有的英文译文（没有时为空）。`--lang zh` 导出的是
+//! 所有译文为空的模板，用来开始一种新的语言。PO 文件可以用 Poedit、Weblate 等工具翻译，不需要改动 Rust 代码。
+//!
+//! `import-translations` 读取翻译好的 PO 文件，按文件头中的 `Language` 写回 `locales/<code>.toml`：
+//! 译文为空或标记为 fuzzy 的条目跳过，文本包中已有而 PO 中没有的键保留。译文中的 `{name}` 占位符
+//! 必须与原文一致。新语言的文本包写好后，还要在 `Language` 中加上它才会被编译进来。需要在源码树中运行。
+
+use crate::curriculum;
+use crate::error::RunnerError;
+use crate::i18n;
+use crate::lesson::Language;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::io::Write;
+use std::mem;
+
+/// PO 文件中的一条翻译
+#[derive(Debug, Default, PartialEq, Eq)]
+struct Message {
+    context: String,
+    id: String,
+    text: String,
+    fuzzy: bool,
+}
+
+/// 导出 `lang` 的 PO 文件；中文导出为空白模板
+pub fn po(lang: Language) -> (String, usize) {
+    let mut out = String::from("# rust-learn translations\n");
+    if lang == Language::default() {
+        out.push_str("# Template: set the Language header and fill in every msgstr\n");
+    }
+    out.push_str("msgid \"\"\nmsgstr \"\"\n\"Content-Type: text/plain; charset=UTF-8\\n\"\n");
+    if lang != Language::default() {
+        out.push_str(&format!("\"Language: {}\\n\"\n", lang.code()));
+    }
+    let mut count = 0;
+    for (key, source) in i18n::entries(Language::default()) {
+        let text = if lang == Language::default() {
+            ""
+        } else {
+            i18n::lookup(lang, key).unwrap_or_default()
+        };
+        out.push_str(&format!(
+            "\nmsgctxt {}\nmsgid {}\nmsgstr {}\n",
+            quote(key),
+            quote(source),
+            quote(text)
+        ));
+        count += 1;
+    }
+    (out, count)
+}
+
+pub fn import(file: &str, out: &mut dyn Write) -> Result<(), RunnerError> {
+    let text = fs::read_to_string(file)?;
+    let (code, messages) = parse(&text)?;
+    if code == Language::default().code() {
+        return Err(RunnerError::Config(format!(
+            "{} is a {} translation, but {} is the source language, edit locales/{}.toml directly",
+            file, code, code, code
+        )));
+    }
+    let path = curriculum::root()
+        .join("locales")
+        .join(format!("{}.toml", code));
+    let existing = fs::read_to_string(&path).unwrap_or_default();
+    let mut bundle: BTreeMap<String, String> = match existing.parse::<toml::Table>() {
+        Ok(table) => flatten(&table),
+        Err(e) => {
+            return Err(RunnerError::Config(format!(
+                "invalid {}: {}",
+                path.display(),
+                e
+            )));
+        }
+    };
+    let sources: BTreeMap<&str, &str> = i18n::entries(Language::default()).collect();
+    let (mut imported, mut skipped) = (0, 0);
+    for message in messages {
+        let Some(source) = sources.get(message.context.as_str()) else {
+            eprintln!(
+                "Warning: skipping '{}', which is no longer in locales/zh.toml",
+                message.context
+            );
+            skipped += 1;
+            continue;
+        };
+        if message.text.is_empty() || message.fuzzy {
+            skipped += 1;
+            continue;
+        }
+        if placeholders(source) != placeholders(&message.text) {
+            return Err(RunnerError::Config(format!(
+                "{}: the translation of '{}' must use the placeholders of \"{}\"",
+                file, message.context, source
+            )));
+        }
+        bundle.insert(message.context, message.text);
+        imported += 1;
+    }
+    fs::write(&path, render(&header(&existing, &code), &bundle))?;
+    writeln!(
+        out,
+        "Imported {} translations into {} ({} skipped)",
+        imported,
+        path.display(),
+        skipped
+    )?;
+    if code.parse::<Language>().is_err() {
+        writeln!(
+            out,
+            "locales/{}.toml is not compiled in yet, add the language to `Language` in crates/core/src/lesson.rs and crates/core/src/i18n.rs",
+            code
+        )?;
+    }
+    Ok(())
+}
+
+/// 解析 PO 文件：返回文件头中的语言与各条翻译（不含文件头）
+fn parse(text: &str) -> Result<(String, Vec<Message>), RunnerError> {
+    let invalid = |n: usize, why: &str| RunnerError::Config(format!("line {}: {}", n + 1, why));
+    let mut messages = Vec::new();
+    let mut current = Message::default();
+    // 续行接在哪个字段后面
+    let mut field: Option<&str> = None;
+    // 下一条是否标记为 fuzzy
+    let mut fuzzy = false;
+    let mut language = None;
+    let mut finish = |message: Message| {
+        if message.id.is_empty() {
+            // 文件头
+            language = message
+                .text
+                .lines()
+                .find_map(|l| l.strip_prefix("Language:"))
+                .map(|l| l.trim().to_string());
+        } else {
+            messages.push(message);
+        }
+    };
+    for (n, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        if let Some(flags) = line.strip_prefix("#,") {
+            fuzzy = flags.split(',').any(|f| f.trim() == "fuzzy");
+            continue;
+        }
+        if line.starts_with('#') {
+            continue;
+        }
+        let (name, value) = match line.split_once(' ') {
+            Some((name @ ("msgctxt" | "msgid" | "msgstr"), value)) => (Some(name), value),
+            _ if line.starts_with('"') => (None, line),
+            _ => {
+                return Err(invalid(
+                    n,
+                    "expected msgctxt, msgid, msgstr or a quoted string",
+                ));
+            }
+        };
+        let value = unquote(value).ok_or_else(|| invalid(n, "malformed string"))?;
+        if let Some(name) = name {
+            // 新条目从 msgctxt 开始，没有 msgctxt 的从 msgid 开始
+            if name == "msgctxt" || (name == "msgid" && field != Some("msgctxt")) {
+                if field.is_some() {
+                    finish(mem::take(&mut current));
+                }
+                current.fuzzy = mem::take(&mut fuzzy);
+            }
+            field = Some(name);
+        }
+        match field {
+            Some("msgctxt") => current.context.push_str(&value),
+            Some("msgid") => current.id.push_str(&value),
+            Some("msgstr") => current.text.push_str(&value),
+            _ => return Err(invalid(n, "string outside of an entry")),
+        }
+    }
+    if field.is_some() {
+        finish(current);
+    }
+    let language = language.filter(|l| !l.is_empty()).ok_or_else(|| {
+        RunnerError::Config(String::from(
+            "the PO file has no Language header, e.g. \"Language: en\\n\"",
+        ))
+    })?;
+    if !language
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+    {
+        return Err(RunnerError::Config(format!(
+            "invalid Language header '{}'",
+            language
+        )));
+    }
+    Ok((language, messages))
+}
+
+/// 带引号与转义的 PO 字符串
+fn quote(text: &str) -> String {
+    let mut out = String::from("\"");
+    for c in text.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\t' => out.push_str("\\t"),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+fn unquote(text: &str) -> Option<String> {
+    let inner = text.strip_prefix('"')?.strip_suffix('"')?;
+    let mut out = String::new();
+    let mut chars = inner.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => out.push(match chars.next()? {
+                'n' => '\n',
+                't' => '\t',
+                c @ ('"' | '\\') => c,
+                _ => return None,
+            }),
+            '"' => return None,
+            c => out.push(c),
+        }
+    }
+    Some(out)
+}
+
+/// 文本中的 `{name}` 占位符
+fn placeholders(text: &str) -> BTreeSet<&str> {
+    text.split('{')
+        .skip(1)
+        .filter_map(|part| part.split_once('}').map(|(name, _)| name))
+        .collect()
+}
+
+/// 嵌套的表展平成 `a.b.c` 形式的键
+fn flatten(table: &toml::Table) -> BTreeMap<String, String> {
+    fn walk(prefix: &str, table: &toml::Table, out: &mut BTreeMap<String, String>) {
+        for (key, value) in table {
+            let key = if prefix.is_empty() {
+                key.clone()
+            } else {
+                format!("{}.{}", prefix, key)
+            };
+            match value {
+                toml::Value::Table(inner) => walk(&key, inner, out),
+                toml::Value::String(s) => {
+                    out.insert(key, s.clone());
+                }
+                _ => {}
+            }
+        }
+    }
+
+    let mut out = BTreeMap::new();
+    walk("", table, &mut out);
+    out
+}
+
+/// 保留原文件开头的注释，新文件写一个简短的说明
+fn header(existing: &str, code: &str) -> String {
+    let comments: String = existing
+        .lines()
+        .take_while(|l| l.starts_with('#') || l.trim().is_empty())
+        .map(|l| format!("{}\n", l))
+        .collect();
+    if comments.trim().is_empty() {
+        format!("# {} text, imported from a PO file\n", code)
+    } else {
+        format!("{}\n", comments.trim_end())
+    }
+}
+
+/// 按 `[ui]`、`[lesson.<slug>]` 等表分组写出；`[ui]` 在最前面
+fn render(header: &str, bundle: &BTreeMap<String, String>) -> String {
+    let mut tables: BTreeMap<(bool, &str), Vec<(&str, &str)>> = BTreeMap::new();
+    for (key, text) in bundle {
+        let (table, name) = key.rsplit_once('.').unwrap_or(("", key));
+        tables
+            .entry((table != "ui", table))
+            .or_default()
+            .push((name, text));
+    }
+    let mut out = header.to_string();
+    for ((_, table), entries) in tables {
+        out.push_str(&format!("\n[{}]\n", table));
+        for (name, text) in entries {
+            out.push_str(&format!("{} = {}\n", name, toml::Value::from(text)));
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_quote() {
+        let text = "say \"hi\"\\\n\tthere";
+        assert_eq!(quote(text), r#""say \"hi\"\\\n\tthere""#);
+        assert_eq!(unquote(&quote(text)).unwrap(), text);
+        assert_eq!(unquote(r#""a"b""#), None);
+        assert_eq!(unquote(r#""\q""#), None);
+    }
+
+    #[test]
+    fn test_po_round_trip() {
+        let (text, count) = po(Language::En);
+        assert_eq!(count, i18n::entries(Language::Zh).count());
+        assert!(text.contains("\"Language: en\\n\"\n"));
+        assert!(text.contains(
+            "msgctxt \"ui.see_also\"\nmsgid \"延伸阅读：{lessons}\"\nmsgstr \"See also: {lessons}\"\n"
+        ));
+        let (code, messages) = parse(&text).unwrap();
+        assert_eq!(code, "en");
+        assert_eq!(messages.len(), count);
+        assert!(messages.iter().all(|m| !m.fuzzy));
+
+        let (template, _) = po(Language::Zh);
+        assert!(template.contains("msgid \"延伸阅读：{lessons}\"\nmsgstr \"\"\n"));
+        assert!(matches!(parse(&template), Err(RunnerError::Config(_))));
+    }
+
+    #[test]
+    fn test_parse() {
+        let text = "msgid \"\"\nmsgstr \"\"\n\"Language: fr\\n\"\n\n\
+                    #, fuzzy\nmsgctxt \"ui.a\"\nmsgid \"甲\"\nmsgstr \"A\"\n\n\
+                    # comment\nmsgctxt \"ui.b\"\nmsgid \"\"\n\"乙\"\nmsgstr \"\"\n\"B\"\n\"b\"\n";
+        let (code, messages) = parse(text).unwrap();
+        assert_eq!(code, "fr");
+        assert_eq!(
+            messages,
+            [
+                Message {
+                    context: String::from("ui.a"),
+                    id: String::from("甲"),
+                    text: String::from("A"),
+                    fuzzy: true,
+                },
+                Message {
+                    context: String::from("ui.b"),
+                    id: String::from("乙"),
+                    text: String::from("Bb"),
+                    fuzzy: false,
+                },
+            ]
+        );
+        assert!(matches!(
+            parse("msgid \"\"\nmsgstr \"\"\n\"Language: fr\\n\"\nbogus\n"),
+            Err(RunnerError::Config(_))
+        ));
+    }
+
+    #[test]
+    fn test_render() {
+        let bundle = BTreeMap::from([
+            (
+                String::from("lesson.hello_world.greeting"),
+                String::from("Hi \"you\""),
+            ),
+            (String::from("ui.see_also"), String::from("See {lessons}")),
+        ]);
+        let text = render("# fr\n", &bundle);
+        assert_eq!(
+            text,
+            "# fr\n\n[ui]\nsee_also = \"See {lessons}\"\n\n[lesson.hello_world]\ngreeting = 'Hi \"you\"'\n"
+        );
+        assert_eq!(flatten(&text.parse().unwrap()), bundle);
+        assert_eq!(placeholders("{a} and {b}"), BTreeSet::from(["a", "b"]));
+    }
+}
@@ -0,0 +1,5 @@
+//! 工具模块
+//!
+//! 实现在 [`rust_learn_core::utils`] 中，与各 lesson crate 共用；这里重新导出。
+
+pub use rust_learn_core::utils::{deterministic, fmt, io, output, rng, time};
@@ -0,0 +1,49 @@
+//! lesson 行为测试：运行 lesson，检查学习者看到的输出
+//!
+//! 与 `tests/snapshots.rs` 逐字比对整段输出不同，这里只断言每课最关键的几行，
+//! 措辞调整不会让测试失败，演示的结论变了才会。写法见 [`rust_learn::testing`]。
+
+use rust_learn::assert_lesson_output;
+
+#[test]
+fn ownership_moves_and_clones() {
+    assert_lesson_output!(
+        "06_ownership",
+        section "所有权基础",
+        section "作用域与丢弃",
+        contains "Cloned: s3=world, s4=world",
+        contains "Out of scope (s was dropped)",
+    );
+}
+
+#[test]
+fn control_flow_covers_every_construct() {
+    assert_lesson_output!(
+        "05_control_flow",
+        section "if 表达式",
+        section "loop 循环",
+        section "while 循环",
+        section "for 循环",
+        section "match 模式匹配",
+        section "if let",
+    );
+}
+
+#[test]
+fn collections_print_maps_in_key_order() {
+    assert_lesson_output!(
+        "15_collections:hashmap",
+        contains r#"Scores: {"Blue": 10, "Yellow": 50}"#,
+        contains r#"After entry: {"Blue": 25, "Red": 30, "Yellow": 50}"#,
+    );
+}
+
+#[test]
+fn error_handling_reports_parse_errors() {
+    assert_lesson_output!(
+        "17_error_handling",
+        contains "Parse error: invalid digit found in string",
+        contains "Error: Invalid input: Cannot divide by zero",
+        excludes "panicked",
+    );
+}
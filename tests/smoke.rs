@@ -0,0 +1,43 @@
+//! 冒烟测试：按注册表逐个运行全部 lesson
+//!
+//! 通过 [`rust_learn::testing::run`] 在内存中运行，不读写学习进度、不访问终端，陷入死循环时报超时。每个 lesson 都必须
+//! 正常结束（没有返回错误，也没有 panic）并且有输出，中英两种语言各运行一遍。
+//! 新加入注册表的 lesson 自动被覆盖，不用在这里登记。
+
+use rust_learn::lesson::Language;
+use rust_learn::lessons;
+use rust_learn::report::RunOptions;
+
+#[test]
+fn every_lesson_runs_and_prints() {
+    let mut failures = Vec::new();
+    for lesson in lessons::all() {
+        let id = lesson.meta().id();
+        for language in [Language::Zh, Language::En] {
+            let opts = RunOptions {
+                language,
+                ..RunOptions::default()
+            };
+            match rust_learn::testing::run(&id, opts) {
+                Ok(report) if !report.is_ok() => failures.push(format!(
+                    "{} ({}): {}",
+                    id,
+                    language.code(),
+                    report.error.unwrap_or_default()
+                )),
+                Ok(report) if report.output.trim().is_empty() => {
+                    failures.push(format!("{} ({}): printed nothing", id, language.code()))
+                }
+                Ok(_) => {}
+                Err(e) => failures.push(format!("{} ({}): {}", id, language.code(), e)),
+            }
+        }
+    }
+    assert!(
+        failures.is_empty(),
+        "{} lesson runs failed:\n{}",
+        failures.len(),
+        failures.join("\n")
+    );
+    assert!(!lessons::all().is_empty(), "the registry is empty");
+}
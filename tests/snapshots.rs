@@ -0,0 +1,20 @@
+//! 快照测试：每个 lesson 的输出与 `tests/snapshots/` 中的快照一致
+//!
+//! 在确定性模式下运行（HashMap 按键排序、随机种子固定、按 80 列换行），同一份代码每次输出都相同。
+//! 演示的输出有意改变时，用 `INSTA_UPDATE=always cargo test --test snapshots` 或 `cargo insta review`
+//! 更新快照，连同代码一起提交，评审时就能看到学习者会看到的变化。
+
+use rust_learn::lessons;
+use rust_learn::report::RunOptions;
+use rust_learn::utils::deterministic;
+
+#[test]
+fn lesson_output_matches_snapshot() {
+    deterministic::enable();
+    for lesson in lessons::all() {
+        let id = lesson.meta().id();
+        let report = rust_learn::testing::run(&id, RunOptions::default()).unwrap();
+        assert!(report.is_ok(), "{}: {:?}", id, report.error);
+        insta::assert_snapshot!(id.clone(), report.output);
+    }
+}